@@ -26,6 +26,11 @@ pub (crate) mod hline;
 pub (crate) mod vline;
 pub (crate) mod vsplitter;
 pub (crate) mod hsplitter;
+pub (crate) mod stackpanel;
+pub (crate) mod wrappanel;
+pub (crate) mod grid;
+pub (crate) mod scrollviewer;
+pub (crate) mod wizard;
 pub (crate) mod datepicker;
 pub (crate) mod listbox;
 pub (crate) mod listview;
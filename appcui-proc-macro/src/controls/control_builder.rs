@@ -38,6 +38,14 @@ pub(crate) static CONTROL_NAMED_PARAMATERS: &[NamedParameter] = &[
     NamedParameter::new("d", "dock", ParamType::Dock),
     NamedParameter::new("pivot", "pivot", ParamType::Alignment),
     NamedParameter::new("p", "pivot", ParamType::Alignment),
+    // grid cell
+    NamedParameter::new("row", "row", ParamType::Integer),
+    NamedParameter::new("column", "column", ParamType::Integer),
+    NamedParameter::new("col", "column", ParamType::Integer),
+    NamedParameter::new("row-span", "row-span", ParamType::Integer),
+    NamedParameter::new("rowspan", "row-span", ParamType::Integer),
+    NamedParameter::new("column-span", "column-span", ParamType::Integer),
+    NamedParameter::new("colspan", "column-span", ParamType::Integer),
 ];
 
 pub(super) struct ControlBuilder<'a> {
@@ -403,6 +411,10 @@ impl<'a> ControlBuilder<'a> {
         self.parser.get_mut(name)?.get_i32()
     }
     #[inline(always)]
+    pub(super) fn get_size(&mut self, name: &str) -> Option<Size> {
+        self.parser.get_size(name)
+    }
+    #[inline(always)]
     pub(super) fn get_bool(&mut self, name: &str) -> Option<bool> {
         self.parser.get_mut(name)?.get_bool()
     }
@@ -0,0 +1,79 @@
+use super::control_builder::ControlBuilder;
+use crate::parameter_parser::*;
+use proc_macro::*;
+
+static POSILITIONAL_PARAMETERS: &[PositionalParameter] = &[];
+static NAMED_PARAMETERS: &[NamedParameter] = &[
+    NamedParameter::new("columns", "columns", ParamType::List),
+    NamedParameter::new("cols", "columns", ParamType::List),
+    NamedParameter::new("rows", "rows", ParamType::List),
+    NamedParameter::new("column-spacing", "column-spacing", ParamType::Integer),
+    NamedParameter::new("csp", "column-spacing", ParamType::Integer),
+    NamedParameter::new("row-spacing", "row-spacing", ParamType::Integer),
+    NamedParameter::new("rsp", "row-spacing", ParamType::Integer),
+    NamedParameter::new("padding", "padding", ParamType::Integer),
+];
+
+fn length(value: &str, param: &str) -> String {
+    let v = value.trim();
+    if v.eq_ignore_ascii_case("auto") || v.eq_ignore_ascii_case("a") {
+        return "grid::Length::Auto".to_string();
+    }
+    if let Some(weight) = v.strip_suffix('*') {
+        let weight = weight.trim();
+        if weight.is_empty() {
+            return "grid::Length::Star(1)".to_string();
+        }
+        if let Ok(w) = weight.parse::<u16>() {
+            return format!("grid::Length::Star({w})");
+        }
+    } else if let Ok(size) = v.parse::<u16>() {
+        return format!("grid::Length::Fixed({size})");
+    }
+    panic!("Invalid value '{value}' in the `{param}` list of a grid. Expecting `auto`, a fixed size (e.g. `10`) or a proportional size (e.g. `*` or `2*`) !");
+}
+
+fn lengths(cb: &mut ControlBuilder, param: &str) -> String {
+    let mut s = String::from("&[");
+    if cb.has_parameter(param) {
+        if let Some(list) = cb.get_list(param) {
+            for item in list.iter() {
+                s.push_str(&length(item.get_string(), param));
+                s.push(',');
+            }
+        } else {
+            panic!("Parameter `{param}` in grid must contain a list of sizes: {param}=[auto,10,*,2*] !");
+        }
+    }
+    s.push(']');
+    s
+}
+
+fn spacing(cb: &mut ControlBuilder, param: &str) -> i32 {
+    let value = cb.get_i32(param).unwrap_or(0);
+    if !(0..=u16::MAX as i32).contains(&value) {
+        panic!("Parameter `{param}` in grid must be a positive number (found: {value})");
+    }
+    value
+}
+
+pub(crate) fn create(input: TokenStream) -> TokenStream {
+    let mut cb = ControlBuilder::new("grid", input, POSILITIONAL_PARAMETERS, NAMED_PARAMETERS, true);
+    cb.init_control("Grid::new");
+    cb.add_layout();
+    let columns = lengths(&mut cb, "columns");
+    let rows = lengths(&mut cb, "rows");
+    cb.add(",");
+    cb.add(&columns);
+    cb.add(",");
+    cb.add(&rows);
+    cb.finish_control_initialization();
+    if cb.has_parameter("column-spacing") || cb.has_parameter("row-spacing") {
+        let column_spacing = spacing(&mut cb, "column-spacing");
+        let row_spacing = spacing(&mut cb, "row-spacing");
+        cb.add_line(format!("control.set_spacing({column_spacing},{row_spacing});").as_str());
+    }
+    super::stackpanel::add_padding(&mut cb);
+    cb.add_basecontrol_operations();
+    cb.into()
+}
@@ -345,6 +345,10 @@ fn validate_layout(params: &mut NamedParamsMap) {
     };
     // all are missing
     if !(lp.x || lp.y || lp.left || lp.top || lp.right || lp.bottom || lp.align || lp.pivot || lp.dock || lp.width || lp.height) {
+        // a control that only specifies its grid cell fills that cell
+        if params.contains("row") || params.contains("column") || params.contains("row-span") || params.contains("column-span") {
+            return;
+        }
         panic!("You need to provide one or some combination of the following parameters: 'x', 'y', 'width'/'w', 'height'/'h', 'left'/'l', 'top'/'t', 'right'/'r', 'bottom'/'b', 'align'/'a', 'dock'/'d' or 'pivot'/'p' !");
     }
     // same logic as the one from layout mode
@@ -413,6 +417,25 @@ fn add_number(output: &mut String, method: &'static str, key: &'static str, para
         output.push(')');
     }
 }
+fn add_size(output: &mut String, method: &'static str, key: &'static str, params: &mut NamedParamsMap) {
+    if let Some(v) = params.get_mut(key) {
+        if v.get_string().eq_ignore_ascii_case("auto") {
+            let _ = write!(output, "{method}(layout::Dimension::Auto)");
+            return;
+        }
+    }
+    add_number(output, method, key, params);
+}
+fn add_cell(output: &mut String, method: &'static str, key: &'static str, params: &mut NamedParamsMap) {
+    if let Some(v) = params.get_mut(key) {
+        match v.get_i32() {
+            Some(value) if (0..=u16::MAX as i32).contains(&value) => {
+                let _ = write!(output, "{method}({value})");
+            }
+            _ => panic!("Invalid value for parameter `{key}` -> expecting a positive number (e.g. {key}: 2) but got the following value: '{}')", v.get_string()),
+        }
+    }
+}
 fn add_alignment(output: &mut String, method: &'static str, enum_name: &'static str, key: &'static str, params: &mut NamedParamsMap) {
     if let Some(v) = params.get_mut(key) {
         let _ = write!(output, "{method}({enum_name}::");
@@ -439,8 +462,8 @@ pub(super) fn add_layout(output: &mut String, params: &mut NamedParamsMap) {
     output.push_str("LayoutBuilder::new()");
     add_number(output, ".x", "x", params);
     add_number(output, ".y", "y", params);
-    add_number(output, ".width", "width", params);
-    add_number(output, ".height", "height", params);
+    add_size(output, ".width", "width", params);
+    add_size(output, ".height", "height", params);
     add_number(output, ".left_anchor", "left", params);
     add_number(output, ".right_anchor", "right", params);
     add_number(output, ".top_anchor", "top", params);
@@ -448,6 +471,10 @@ pub(super) fn add_layout(output: &mut String, params: &mut NamedParamsMap) {
     add_alignment(output, ".alignment", "Alignment", "align", params);
     add_alignment(output, ".pivot", "Pivot", "pivot", params);
     add_dock(output, params);
+    add_cell(output, ".row", "row", params);
+    add_cell(output, ".column", "column", params);
+    add_cell(output, ".row_span", "row-span", params);
+    add_cell(output, ".column_span", "column-span", params);
     output.push_str(".build()");
 }
 
@@ -0,0 +1,27 @@
+use super::control_builder::ControlBuilder;
+use crate::parameter_parser::*;
+use proc_macro::*;
+
+static FLAGS: FlagsSignature = FlagsSignature::new(&["ScrollBars"]);
+
+static POSILITIONAL_PARAMETERS: &[PositionalParameter] = &[];
+static NAMED_PARAMETERS: &[NamedParameter] = &[
+    NamedParameter::new("flags", "flags", ParamType::Flags),
+    NamedParameter::new("canvas", "canvas", ParamType::Size),
+    NamedParameter::new("canvas-size", "canvas", ParamType::Size),
+    NamedParameter::new("padding", "padding", ParamType::Integer),
+];
+
+pub(crate) fn create(input: TokenStream) -> TokenStream {
+    let mut cb = ControlBuilder::new("scrollviewer", input, POSILITIONAL_PARAMETERS, NAMED_PARAMETERS, true);
+    cb.init_control("ScrollViewer::new");
+    cb.add_layout();
+    cb.add_flags_parameter("flags", "scrollviewer::Flags", &FLAGS);
+    cb.finish_control_initialization();
+    if let Some(size) = cb.get_size("canvas") {
+        cb.add_line(format!("control.set_canvas_size(Size::new({},{}));", size.width, size.height).as_str());
+    }
+    super::stackpanel::add_padding(&mut cb);
+    cb.add_basecontrol_operations();
+    cb.into()
+}
@@ -0,0 +1,40 @@
+use super::control_builder::ControlBuilder;
+use crate::parameter_parser::*;
+use proc_macro::*;
+
+static ORIENTATION: FlagsSignature = FlagsSignature::new(&["Horizontal", "Vertical"]);
+
+static POSILITIONAL_PARAMETERS: &[PositionalParameter] = &[PositionalParameter::new("orientation", ParamType::String)];
+static NAMED_PARAMETERS: &[NamedParameter] = &[
+    NamedParameter::new("orientation", "orientation", ParamType::String),
+    NamedParameter::new("o", "orientation", ParamType::String),
+    NamedParameter::new("spacing", "spacing", ParamType::Integer),
+    NamedParameter::new("sp", "spacing", ParamType::Integer),
+    NamedParameter::new("padding", "padding", ParamType::Integer),
+];
+
+pub(super) fn add_padding(cb: &mut ControlBuilder) {
+    if let Some(padding) = cb.get_i32("padding") {
+        if !(0..=255).contains(&padding) {
+            panic!("Padding must be a value between 0 and 255 (found: {padding})");
+        }
+        cb.add_line(format!("control.set_padding({padding},{padding},{padding},{padding});").as_str());
+    }
+}
+
+pub(crate) fn create(input: TokenStream) -> TokenStream {
+    let mut cb = ControlBuilder::new("stackpanel", input, POSILITIONAL_PARAMETERS, NAMED_PARAMETERS, true);
+    cb.init_control("StackPanel::new");
+    cb.add_layout();
+    cb.add_enum_parameter("orientation", "stackpanel::Orientation", &ORIENTATION, Some("Vertical"));
+    cb.finish_control_initialization();
+    if let Some(spacing) = cb.get_i32("spacing") {
+        if spacing < 0 {
+            panic!("Spacing can not be a negative number");
+        }
+        cb.add_line(format!("control.set_spacing({spacing});").as_str());
+    }
+    add_padding(&mut cb);
+    cb.add_basecontrol_operations();
+    cb.into()
+}
@@ -3,7 +3,7 @@ use crate::parameter_parser::*;
 use proc_macro::*;
 
 static TYPES: FlagsSignature = FlagsSignature::new(&["HiddenTabs", "OnTop", "OnBottom", "OnLeft"]);
-static FLAGS: FlagsSignature = FlagsSignature::new(&["TransparentBackground", "TabsBar"]);
+static FLAGS: FlagsSignature = FlagsSignature::new(&["TransparentBackground", "TabsBar", "CloseButtons", "Reorderable"]);
 
 static POSILITIONAL_PARAMETERS: &[PositionalParameter] = &[];
 static NAMED_PARAMETERS: &[NamedParameter] = &[
@@ -9,6 +9,7 @@ static NAMED_PARAMETERS: &[NamedParameter] = &[
     NamedParameter::new("text", "text", ParamType::String),
     NamedParameter::new("caption", "text", ParamType::String),
     NamedParameter::new("flags", "flags", ParamType::Flags),
+    NamedParameter::new("mask", "mask", ParamType::String),
 ];
 
 pub(crate) fn create(input: TokenStream) -> TokenStream {
@@ -18,6 +19,10 @@ pub(crate) fn create(input: TokenStream) -> TokenStream {
     cb.add_layout();
     cb.add_flags_parameter("flags", "textfield::Flags", &FLAGS);
     cb.finish_control_initialization();
+    if let Some(mask) = cb.get_value("mask") {
+        let line = format!("control.set_mask(textfield::Mask::new({mask:?}));");
+        cb.add_line(&line);
+    }
     cb.add_basecontrol_operations();
     cb.into()
 }
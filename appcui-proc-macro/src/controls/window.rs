@@ -6,6 +6,7 @@ static FLAGS: FlagsSignature = FlagsSignature::new(&[
     "Sizeable",
     "NoCloseButton",
     "FixedPosition",
+    "Dockable",
     "ErrorWindow",
     "NotifyWindow",
     "WarningWindow",
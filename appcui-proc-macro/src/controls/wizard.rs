@@ -0,0 +1,30 @@
+use super::control_builder::ControlBuilder;
+use crate::parameter_parser::*;
+use proc_macro::*;
+
+static FLAGS: FlagsSignature = FlagsSignature::new(&["HideStepIndicator"]);
+
+static POSILITIONAL_PARAMETERS: &[PositionalParameter] = &[];
+static NAMED_PARAMETERS: &[NamedParameter] = &[
+    NamedParameter::new("flags", "flags", ParamType::Flags),
+    NamedParameter::new("steps", "steps", ParamType::List),
+];
+
+pub(crate) fn create(input: TokenStream) -> TokenStream {
+    let mut cb = ControlBuilder::new("wizard", input, POSILITIONAL_PARAMETERS, NAMED_PARAMETERS, true);
+    cb.init_control("Wizard::new");
+    cb.add_layout();
+    cb.add_flags_parameter("flags", "wizard::Flags", &FLAGS);
+    cb.finish_control_initialization();
+    if let Some(l) = cb.get_list("steps") {
+        let mut v = Vec::with_capacity(l.len() + 1);
+        for item in l {
+            v.push(format!("control.add_step(\"{}\");", item.get_string()));
+        }
+        for line in v {
+            cb.add_line(line.as_str());
+        }
+    }
+    cb.add_basecontrol_operations();
+    cb.into()
+}
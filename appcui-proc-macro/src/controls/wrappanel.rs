@@ -0,0 +1,35 @@
+use super::control_builder::ControlBuilder;
+use crate::parameter_parser::*;
+use proc_macro::*;
+
+static ORIENTATION: FlagsSignature = FlagsSignature::new(&["Horizontal", "Vertical"]);
+
+static POSILITIONAL_PARAMETERS: &[PositionalParameter] = &[PositionalParameter::new("orientation", ParamType::String)];
+static NAMED_PARAMETERS: &[NamedParameter] = &[
+    NamedParameter::new("orientation", "orientation", ParamType::String),
+    NamedParameter::new("o", "orientation", ParamType::String),
+    NamedParameter::new("spacing", "spacing", ParamType::Integer),
+    NamedParameter::new("sp", "spacing", ParamType::Integer),
+    NamedParameter::new("line-spacing", "ls", ParamType::Integer),
+    NamedParameter::new("ls", "ls", ParamType::Integer),
+    NamedParameter::new("padding", "padding", ParamType::Integer),
+];
+
+pub(crate) fn create(input: TokenStream) -> TokenStream {
+    let mut cb = ControlBuilder::new("wrappanel", input, POSILITIONAL_PARAMETERS, NAMED_PARAMETERS, true);
+    cb.init_control("WrapPanel::new");
+    cb.add_layout();
+    cb.add_enum_parameter("orientation", "wrappanel::Orientation", &ORIENTATION, Some("Horizontal"));
+    cb.finish_control_initialization();
+    let spacing = cb.get_i32("spacing").unwrap_or(0);
+    let line_spacing = cb.get_i32("ls").unwrap_or(0);
+    if (spacing < 0) || (line_spacing < 0) {
+        panic!("Spacing can not be a negative number");
+    }
+    if (spacing != 0) || (line_spacing != 0) {
+        cb.add_line(format!("control.set_spacing({spacing},{line_spacing});").as_str());
+    }
+    super::stackpanel::add_padding(&mut cb);
+    cb.add_basecontrol_operations();
+    cb.into()
+}
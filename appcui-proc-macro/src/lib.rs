@@ -23,6 +23,9 @@ extern crate proc_macro;
 /// * OnDefaultAction
 /// * OnResize
 /// * OnFocus
+/// * OnMeasure
+/// * OnSessionState
+/// * OnChildEvent
 /// 
 /// and the **events** parameter is a list of events that could be received by the new control:
 /// * CommandBarEvents
@@ -75,6 +78,9 @@ pub fn CustomControl(args: TokenStream, input: TokenStream) -> TokenStream {
     config.set(AppCUITrait::OnMouseEvent, TraitImplementation::Default);
     config.set(AppCUITrait::OnSiblingSelected, TraitImplementation::Default);
     config.set(AppCUITrait::OnThemeChanged, TraitImplementation::Default);
+    config.set(AppCUITrait::OnMeasure, TraitImplementation::Default);
+    config.set(AppCUITrait::OnSessionState, TraitImplementation::Default);
+    config.set(AppCUITrait::OnChildEvent, TraitImplementation::Default);
 
     // control events
     config.set(AppCUITrait::ButtonEvents, TraitImplementation::DefaultNonOverwritable);
@@ -95,6 +101,8 @@ pub fn CustomControl(args: TokenStream, input: TokenStream) -> TokenStream {
     config.set(AppCUITrait::ComboBoxEvents, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::GenericDropDownListEvents, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::GenericNumericSelectorEvents, TraitImplementation::DefaultNonOverwritable);
+    config.set(AppCUITrait::GenericSliderEvents, TraitImplementation::DefaultNonOverwritable);
+    config.set(AppCUITrait::GenericRangeSliderEvents, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::DatePickerEvents, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::ListBoxEvents, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::GenericListViewEvents, TraitImplementation::DefaultNonOverwritable);
@@ -103,6 +111,11 @@ pub fn CustomControl(args: TokenStream, input: TokenStream) -> TokenStream {
     config.set(AppCUITrait::MarkdownEvents, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::GenericBackgroundTaskEvents, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::AccordionEvents, TraitImplementation::DefaultNonOverwritable);
+    config.set(AppCUITrait::WizardEvents, TraitImplementation::DefaultNonOverwritable);
+    config.set(AppCUITrait::TerminalEvents, TraitImplementation::DefaultNonOverwritable);
+    config.set(AppCUITrait::TimePickerEvents, TraitImplementation::DefaultNonOverwritable);
+    config.set(AppCUITrait::DateTimePickerEvents, TraitImplementation::DefaultNonOverwritable);
+    config.set(AppCUITrait::DateRangePickerEvents, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::TabEvents, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::CharPickerEvents, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::GenericGraphViewEvents, TraitImplementation::DefaultNonOverwritable);
@@ -112,6 +125,7 @@ pub fn CustomControl(args: TokenStream, input: TokenStream) -> TokenStream {
 
     // timer events
     config.set(AppCUITrait::TimerEvents, TraitImplementation::Default);
+    config.set(AppCUITrait::ToastEvents, TraitImplementation::Default);
 
 
     // desktop
@@ -141,6 +155,9 @@ pub fn CustomContainer(args: TokenStream, input: TokenStream) -> TokenStream {
     config.set(AppCUITrait::OnMouseEvent, TraitImplementation::Default);
     config.set(AppCUITrait::OnSiblingSelected, TraitImplementation::Default);
     config.set(AppCUITrait::OnThemeChanged, TraitImplementation::Default);
+    config.set(AppCUITrait::OnMeasure, TraitImplementation::Default);
+    config.set(AppCUITrait::OnSessionState, TraitImplementation::Default);
+    config.set(AppCUITrait::OnChildEvent, TraitImplementation::Default);
 
     // control events
     config.set(AppCUITrait::ButtonEvents, TraitImplementation::DefaultNonOverwritable);
@@ -161,6 +178,8 @@ pub fn CustomContainer(args: TokenStream, input: TokenStream) -> TokenStream {
     config.set(AppCUITrait::ComboBoxEvents, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::GenericDropDownListEvents, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::GenericNumericSelectorEvents, TraitImplementation::DefaultNonOverwritable);
+    config.set(AppCUITrait::GenericSliderEvents, TraitImplementation::DefaultNonOverwritable);
+    config.set(AppCUITrait::GenericRangeSliderEvents, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::DatePickerEvents, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::ListBoxEvents, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::GenericListViewEvents, TraitImplementation::DefaultNonOverwritable);
@@ -169,6 +188,11 @@ pub fn CustomContainer(args: TokenStream, input: TokenStream) -> TokenStream {
     config.set(AppCUITrait::MarkdownEvents, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::GenericBackgroundTaskEvents, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::AccordionEvents, TraitImplementation::DefaultNonOverwritable);
+    config.set(AppCUITrait::WizardEvents, TraitImplementation::DefaultNonOverwritable);
+    config.set(AppCUITrait::TerminalEvents, TraitImplementation::DefaultNonOverwritable);
+    config.set(AppCUITrait::TimePickerEvents, TraitImplementation::DefaultNonOverwritable);
+    config.set(AppCUITrait::DateTimePickerEvents, TraitImplementation::DefaultNonOverwritable);
+    config.set(AppCUITrait::DateRangePickerEvents, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::TabEvents, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::CharPickerEvents, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::GenericGraphViewEvents, TraitImplementation::DefaultNonOverwritable);
@@ -178,6 +202,7 @@ pub fn CustomContainer(args: TokenStream, input: TokenStream) -> TokenStream {
 
     // timer events
     config.set(AppCUITrait::TimerEvents, TraitImplementation::Default);
+    config.set(AppCUITrait::ToastEvents, TraitImplementation::Default);
 
 
     // desktop
@@ -232,6 +257,9 @@ pub fn Window(args: TokenStream, input: TokenStream) -> TokenStream {
     config.set(AppCUITrait::OnMouseEvent, TraitImplementation::BaseFallbackNonOverwritable);
     config.set(AppCUITrait::OnSiblingSelected, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::OnThemeChanged, TraitImplementation::Default);
+    config.set(AppCUITrait::OnMeasure, TraitImplementation::Default);
+    config.set(AppCUITrait::OnSessionState, TraitImplementation::Default);
+    config.set(AppCUITrait::OnChildEvent, TraitImplementation::Default);
 
 
     // control events
@@ -253,6 +281,8 @@ pub fn Window(args: TokenStream, input: TokenStream) -> TokenStream {
     config.set(AppCUITrait::ComboBoxEvents, TraitImplementation::Default);
     config.set(AppCUITrait::GenericDropDownListEvents, TraitImplementation::Default);
     config.set(AppCUITrait::GenericNumericSelectorEvents, TraitImplementation::Default);
+    config.set(AppCUITrait::GenericSliderEvents, TraitImplementation::Default);
+    config.set(AppCUITrait::GenericRangeSliderEvents, TraitImplementation::Default);
     config.set(AppCUITrait::DatePickerEvents, TraitImplementation::Default);
     config.set(AppCUITrait::ListBoxEvents, TraitImplementation::Default);
     config.set(AppCUITrait::GenericListViewEvents, TraitImplementation::Default);
@@ -261,6 +291,11 @@ pub fn Window(args: TokenStream, input: TokenStream) -> TokenStream {
     config.set(AppCUITrait::MarkdownEvents, TraitImplementation::Default);
     config.set(AppCUITrait::GenericBackgroundTaskEvents, TraitImplementation::Default);
     config.set(AppCUITrait::AccordionEvents, TraitImplementation::Default);
+    config.set(AppCUITrait::WizardEvents, TraitImplementation::Default);
+    config.set(AppCUITrait::TerminalEvents, TraitImplementation::Default);
+    config.set(AppCUITrait::TimePickerEvents, TraitImplementation::Default);
+    config.set(AppCUITrait::DateTimePickerEvents, TraitImplementation::Default);
+    config.set(AppCUITrait::DateRangePickerEvents, TraitImplementation::Default);
     config.set(AppCUITrait::TabEvents, TraitImplementation::Default);
     config.set(AppCUITrait::CharPickerEvents, TraitImplementation::Default);
     config.set(AppCUITrait::GenericGraphViewEvents, TraitImplementation::Default);
@@ -271,6 +306,7 @@ pub fn Window(args: TokenStream, input: TokenStream) -> TokenStream {
 
     // timer events
     config.set(AppCUITrait::TimerEvents, TraitImplementation::Default);
+    config.set(AppCUITrait::ToastEvents, TraitImplementation::Default);
 
     // desktop
     config.set(AppCUITrait::DesktopEvents, TraitImplementation::DefaultNonOverwritable);
@@ -298,6 +334,9 @@ pub fn ModalWindow(args: TokenStream, input: TokenStream) -> TokenStream {
     config.set(AppCUITrait::OnMouseEvent, TraitImplementation::BaseFallbackNonOverwritable);
     config.set(AppCUITrait::OnSiblingSelected, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::OnThemeChanged, TraitImplementation::Default);
+    config.set(AppCUITrait::OnMeasure, TraitImplementation::Default);
+    config.set(AppCUITrait::OnSessionState, TraitImplementation::Default);
+    config.set(AppCUITrait::OnChildEvent, TraitImplementation::Default);
 
     // control events
     config.set(AppCUITrait::ButtonEvents, TraitImplementation::Default);
@@ -318,6 +357,8 @@ pub fn ModalWindow(args: TokenStream, input: TokenStream) -> TokenStream {
     config.set(AppCUITrait::ComboBoxEvents, TraitImplementation::Default);
     config.set(AppCUITrait::GenericDropDownListEvents, TraitImplementation::Default);
     config.set(AppCUITrait::GenericNumericSelectorEvents, TraitImplementation::Default);
+    config.set(AppCUITrait::GenericSliderEvents, TraitImplementation::Default);
+    config.set(AppCUITrait::GenericRangeSliderEvents, TraitImplementation::Default);
     config.set(AppCUITrait::DatePickerEvents, TraitImplementation::Default);
     config.set(AppCUITrait::ListBoxEvents, TraitImplementation::Default);
     config.set(AppCUITrait::GenericListViewEvents, TraitImplementation::Default);
@@ -326,6 +367,11 @@ pub fn ModalWindow(args: TokenStream, input: TokenStream) -> TokenStream {
     config.set(AppCUITrait::MarkdownEvents, TraitImplementation::Default);
     config.set(AppCUITrait::GenericBackgroundTaskEvents, TraitImplementation::Default);
     config.set(AppCUITrait::AccordionEvents, TraitImplementation::Default);
+    config.set(AppCUITrait::WizardEvents, TraitImplementation::Default);
+    config.set(AppCUITrait::TerminalEvents, TraitImplementation::Default);
+    config.set(AppCUITrait::TimePickerEvents, TraitImplementation::Default);
+    config.set(AppCUITrait::DateTimePickerEvents, TraitImplementation::Default);
+    config.set(AppCUITrait::DateRangePickerEvents, TraitImplementation::Default);
     config.set(AppCUITrait::TabEvents, TraitImplementation::Default);
     config.set(AppCUITrait::CharPickerEvents, TraitImplementation::Default);
     config.set(AppCUITrait::GenericGraphViewEvents, TraitImplementation::Default);
@@ -333,10 +379,11 @@ pub fn ModalWindow(args: TokenStream, input: TokenStream) -> TokenStream {
 
 
     // custom events
-    config.set(AppCUITrait::CustomEvents, TraitImplementation::DefaultNonOverwritable);
+    config.set(AppCUITrait::CustomEvents, TraitImplementation::Default);
 
     // timer events
     config.set(AppCUITrait::TimerEvents, TraitImplementation::Default);
+    config.set(AppCUITrait::ToastEvents, TraitImplementation::Default);
 
     // desktop
     config.set(AppCUITrait::DesktopEvents, TraitImplementation::Default);
@@ -389,6 +436,9 @@ pub fn Desktop(args: TokenStream, input: TokenStream) -> TokenStream {
     config.set(AppCUITrait::OnMouseEvent, TraitImplementation::BaseFallbackNonOverwritable);
     config.set(AppCUITrait::OnSiblingSelected, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::OnThemeChanged, TraitImplementation::Default);
+    config.set(AppCUITrait::OnMeasure, TraitImplementation::DefaultNonOverwritable);
+    config.set(AppCUITrait::OnSessionState, TraitImplementation::Default);
+    config.set(AppCUITrait::OnChildEvent, TraitImplementation::Default);
 
     // control events
     config.set(AppCUITrait::ButtonEvents, TraitImplementation::DefaultNonOverwritable);
@@ -409,6 +459,8 @@ pub fn Desktop(args: TokenStream, input: TokenStream) -> TokenStream {
     config.set(AppCUITrait::ComboBoxEvents, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::GenericDropDownListEvents, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::GenericNumericSelectorEvents, TraitImplementation::DefaultNonOverwritable);
+    config.set(AppCUITrait::GenericSliderEvents, TraitImplementation::DefaultNonOverwritable);
+    config.set(AppCUITrait::GenericRangeSliderEvents, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::DatePickerEvents, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::ListBoxEvents, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::GenericListViewEvents, TraitImplementation::DefaultNonOverwritable);
@@ -417,6 +469,11 @@ pub fn Desktop(args: TokenStream, input: TokenStream) -> TokenStream {
     config.set(AppCUITrait::MarkdownEvents, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::GenericBackgroundTaskEvents, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::AccordionEvents, TraitImplementation::DefaultNonOverwritable);
+    config.set(AppCUITrait::WizardEvents, TraitImplementation::DefaultNonOverwritable);
+    config.set(AppCUITrait::TerminalEvents, TraitImplementation::DefaultNonOverwritable);
+    config.set(AppCUITrait::TimePickerEvents, TraitImplementation::DefaultNonOverwritable);
+    config.set(AppCUITrait::DateTimePickerEvents, TraitImplementation::DefaultNonOverwritable);
+    config.set(AppCUITrait::DateRangePickerEvents, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::TabEvents, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::CharPickerEvents, TraitImplementation::DefaultNonOverwritable);
     config.set(AppCUITrait::GenericGraphViewEvents, TraitImplementation::DefaultNonOverwritable);
@@ -427,6 +484,7 @@ pub fn Desktop(args: TokenStream, input: TokenStream) -> TokenStream {
 
     // timer events
     config.set(AppCUITrait::TimerEvents, TraitImplementation::Default);
+    config.set(AppCUITrait::ToastEvents, TraitImplementation::Default);
 
     // desktop
     config.set(AppCUITrait::DesktopEvents, TraitImplementation::Default);
@@ -1207,6 +1265,8 @@ pub fn imageviewer(input: TokenStream) -> TokenStream {
 /// * `flags` - Control flags (optional). Can be:
 ///   - **TransparentBackground** - Uses transparent background
 ///   - **TabsBar** - Shows a bar for tabs
+///   - **CloseButtons** - Adds a close button to every tab
+///   - **Reorderable** - Tabs can be reordered by dragging them with the mouse
 /// * `tabwidth` or `tab-width` or `tw` - Width of each tab (optional)
 /// * Position and size:
 ///   - `x`, `y` - Position coordinates
@@ -1349,6 +1409,7 @@ pub fn keyselector(input: TokenStream) -> TokenStream {
 ///   - **ProcessEnter** - the textfield will process the Enter key
 ///   - **ReadOnly** - the textfield is read-only
 ///   - **DisableAutoSelectOnFocus** - the text will not be selected when the textfield receives the focus
+/// * `mask` - an input mask (e.g. `mask:'(999) 000-0000'`). See `textfield::Mask` for the available characters
 /// * position attributes: `x` and  `y`,
 /// * size attributes: `width` or `w` (alias), `height` or `h` (alias),
 /// * margin attributes: `left` or `l`(alias), `right` or `r`(alias), `top` or `t`(alias), `bottom` or `b`(alias)
@@ -1862,6 +1923,157 @@ pub fn hsplitter(input: TokenStream) -> TokenStream {
     crate::controls::hsplitter::create(input)
 }
 
+/// Creates a new StackPanel control that positions its children one after another (horizontally or vertically).
+/// The format is `stackpanel!("attributes")` where the attributes are pairs of key-value, separated by comma.
+///
+/// # Parameters
+/// * `orientation` or `o` (optional, first positional parameter) - one of **Vertical** (default) or **Horizontal**
+/// * `spacing` or `sp` - Space (in characters) between two consecutive children (optional, default is 0)
+/// * `padding` - Space (in characters) between the margins of the panel and its children (optional, default is 0)
+/// * Position and size:
+///   - `x`, `y` - Position coordinates
+///   - `width`/`w`, `height`/`h` - Control dimensions
+/// * Layout:
+///   - `align`/`a` - Alignment: Left, Right, Top, Bottom, Center, etc.
+///   - `dock`/`d` - Docking: Left, Right, Top, Bottom, Center, etc.
+/// * State: `enabled`, `visible`
+///
+/// # Examples
+/// ```rust,compile_fail
+/// use appcui::prelude::*;
+///
+/// let mut form = stackpanel!("Vertical,d:f,spacing:1,padding:1");
+/// form.add(label!("'Name:',a:l,w:10,h:1"));
+/// form.add(textfield!("'',a:l,w:20,h:1"));
+/// ```
+#[proc_macro]
+pub fn stackpanel(input: TokenStream) -> TokenStream {
+    crate::controls::stackpanel::create(input)
+}
+
+/// Creates a new WrapPanel control that positions its children in lines (rows or columns), wrapping them when there is no more space.
+/// The format is `wrappanel!("attributes")` where the attributes are pairs of key-value, separated by comma.
+///
+/// # Parameters
+/// * `orientation` or `o` (optional, first positional parameter) - one of **Horizontal** (default, the children are arranged in rows) or **Vertical** (the children are arranged in columns)
+/// * `spacing` or `sp` - Space (in characters) between two consecutive children of the same line (optional, default is 0)
+/// * `line-spacing` or `ls` - Space (in characters) between two lines (optional, default is 0)
+/// * `padding` - Space (in characters) between the margins of the panel and its children (optional, default is 0)
+/// * Position and size:
+///   - `x`, `y` - Position coordinates
+///   - `width`/`w`, `height`/`h` - Control dimensions
+/// * Layout:
+///   - `align`/`a` - Alignment: Left, Right, Top, Bottom, Center, etc.
+///   - `dock`/`d` - Docking: Left, Right, Top, Bottom, Center, etc.
+/// * State: `enabled`, `visible`
+///
+/// # Examples
+/// ```rust,compile_fail
+/// use appcui::prelude::*;
+///
+/// let mut tags = wrappanel!("d:f,spacing:1,ls:1");
+/// tags.add(button!("Red,a:c,w:10,type:Flat"));
+/// tags.add(button!("Green,a:c,w:10,type:Flat"));
+/// ```
+#[proc_macro]
+pub fn wrappanel(input: TokenStream) -> TokenStream {
+    crate::controls::wrappanel::create(input)
+}
+
+/// Creates a new Grid control that arranges its children in rows and columns.
+/// The format is `grid!("attributes")` where the attributes are pairs of key-value, separated by comma.
+///
+/// # Parameters
+/// * `columns` or `cols` - A list with the size of every column (optional, by default the grid has only one column)
+/// * `rows` - A list with the size of every row (optional, by default the grid has only one row)
+/// * `column-spacing` or `csp` - Space (in characters) between two consecutive columns (optional, default is 0)
+/// * `row-spacing` or `rsp` - Space (in characters) between two consecutive rows (optional, default is 0)
+/// * `padding` - Space (in characters) between the margins of the grid and its cells (optional, default is 0)
+/// * Position and size:
+///   - `x`, `y` - Position coordinates
+///   - `width`/`w`, `height`/`h` - Control dimensions
+/// * Layout:
+///   - `align`/`a` - Alignment: Left, Right, Top, Bottom, Center, etc.
+///   - `dock`/`d` - Docking: Left, Right, Top, Bottom, Center, etc.
+/// * State: `enabled`, `visible`
+///
+/// The size of a row or column can be:
+/// * a number (e.g. `10`) - a fixed size (in characters)
+/// * `auto` - the size of the biggest child from that row or column
+/// * `*` or a weight followed by `*` (e.g. `2*`) - a part of the remaining space, proportional with the weight
+///
+/// The children specify their cell through their layout (`row`, `column`/`col`, `row-span`/`rowspan` and `column-span`/`colspan`).
+///
+/// # Examples
+/// ```rust,compile_fail
+/// use appcui::prelude::*;
+///
+/// let mut form = grid!("d:f,columns:[10,*],rows:[auto,auto,*],rsp:1");
+/// form.add(label!("'Name:',row:0,col:0"));
+/// form.add(textfield!("'',row:0,col:1"));
+/// form.add(textarea!("'',row:2,col:0,colspan:2"));
+/// ```
+#[proc_macro]
+pub fn grid(input: TokenStream) -> TokenStream {
+    crate::controls::grid::create(input)
+}
+
+/// Creates a new ScrollViewer control that displays its children on a virtual canvas larger than itself.
+/// The format is `scrollviewer!("attributes")` where the attributes are pairs of key-value, separated by comma.
+///
+/// # Parameters
+/// * `flags` - Initialization flags (optional):
+///   - `ScrollBars` - the viewer has a horizontal and a vertical scrollbar
+/// * `canvas` or `canvas-size` - Size of the virtual canvas in the format `<width>x<height>` (optional, by default the canvas has the size of its content)
+/// * `padding` - Space (in characters) between the margins of the viewer and its visible area (optional, default is 0)
+/// * Position and size:
+///   - `x`, `y` - Position coordinates
+///   - `width`/`w`, `height`/`h` - Control dimensions
+/// * Layout:
+///   - `align`/`a` - Alignment: Left, Right, Top, Bottom, Center, etc.
+///   - `dock`/`d` - Docking: Left, Right, Top, Bottom, Center, etc.
+/// * State: `enabled`, `visible`
+///
+/// # Examples
+/// ```rust,compile_fail
+/// use appcui::prelude::*;
+///
+/// let mut sv = scrollviewer!("d:f,flags:ScrollBars,canvas:80x40");
+/// sv.add(button!("'First',x:1,y:1,w:15"));
+/// sv.add(button!("'Last',x:60,y:35,w:15"));
+/// ```
+#[proc_macro]
+pub fn scrollviewer(input: TokenStream) -> TokenStream {
+    crate::controls::scrollviewer::create(input)
+}
+
+/// Creates a new Wizard control (a sequence of steps with Back/Next/Finish/Cancel actions).
+/// The format is `wizard!("attributes")` where the attributes are pairs of key-value, separated by comma.
+///
+/// # Parameters
+/// * `steps` - List of step captions. Format: `[Step1, Step2, ...]` (optional, steps can also be added via `add_step`)
+/// * `flags` - Initialization flags (optional):
+///   - `HideStepIndicator` - the list of steps is not displayed on the top of the wizard
+/// * Position and size:
+///   - `x`, `y` - Position coordinates
+///   - `width`/`w`, `height`/`h` - Control dimensions
+/// * Layout:
+///   - `align`/`a` - Alignment: Left, Right, Top, Bottom, Center, etc.
+///   - `dock`/`d` - Docking: Left, Right, Top, Bottom, Center, etc.
+/// * State: `enabled`, `visible`
+///
+/// # Examples
+/// ```rust,compile_fail
+/// use appcui::prelude::*;
+///
+/// let mut wz = wizard!("d:f,steps:['Welcome','Options','Summary']");
+/// wz.add(0, label!("'Welcome to the setup wizard',x:1,y:1,w:30"));
+/// ```
+#[proc_macro]
+pub fn wizard(input: TokenStream) -> TokenStream {
+    crate::controls::wizard::create(input)
+}
+
 /// Creates a new DatePicker control for selecting dates.
 /// The format is `datepicker!("attributes")` where the attributes are pairs of key-value, separated by comma.
 /// 
@@ -2232,16 +2444,24 @@ pub fn textarea(input: TokenStream) -> TokenStream {
 /// | `right`   | `r`   | `.right_anchor(...)` | numerical or percentage | Right anchor (space between parent right margin and control)                            |
 /// | `top`     | `t`   | `.top_anchor(...)`   | numerical or percentage | Top anchor (space between parent top margin and control)                                |
 /// | `bottom`  | `b`   | `.bottom_anchor(...)` | numerical or percentage | Bottom anchor (space between parent bottom margin and control)                          |
-/// | `width`   | `w`   | `.width(...)`        | numerical or percentage | Width of the control                                                                     |
-/// | `height`  | `h`   | `.height(...)`       | numerical or percentage | Height of the control                                                                    |
+/// | `width`   | `w`   | `.width(...)`        | numerical, percentage or `auto` | Width of the control (`auto` uses the size of its content)                       |
+/// | `height`  | `h`   | `.height(...)`       | numerical, percentage or `auto` | Height of the control (`auto` uses the size of its content)                      |
 /// | `dock`    | `d`   | `.dock(...)`         | docking value           | How the control is docked to its parent                                                 |
 /// | `align`   | `a`   | `.alignment(...)`    | alignment value         | How the control is aligned against the margins of its parent                            |
 /// | `pivot`   | `p`   | `.pivot(...)`        | pivoting direction      | How the control is aligned against the point represented by (x,y) - the pivot          |
+/// | `row`     |       | `.row(...)`          | positive number         | Row of the cell where the control is placed within a `Grid`                             |
+/// | `column`  | `col` | `.column(...)`       | positive number         | Column of the cell where the control is placed within a `Grid`                          |
+/// | `row-span` | `rowspan` | `.row_span(...)` | positive number        | Number of rows occupied by the control within a `Grid` (default is 1)                  |
+/// | `column-span` | `colspan` | `.column_span(...)` | positive number | Number of columns occupied by the control within a `Grid` (default is 1)               |
+/// 
+/// When a control is added to a `Grid`, the rest of its layout is computed relative to its cell. A layout that only
+/// specifies the cell (e.g. `layout!("row:1,col:2")`) fills the entire cell.
 /// 
 /// # Value Types
 /// 
 /// * **Numerical values**: Integer numbers between -30000 and 30000 (e.g., `x:100`)
 /// * **Percentage values**: Floating point numbers followed by `%` between -300% and 300% (e.g., `width:50%`)
+/// * **Auto**: `width` and `height` can use the `auto` value (e.g., `a:c,w:auto,h:auto`) to fit the content of the control
 /// * All parameters are case-insensitive
 /// 
 /// # Dock Values
@@ -2307,6 +2527,9 @@ pub fn textarea(input: TokenStream) -> TokenStream {
 /// 
 /// // Fill parent completely
 /// let layout = layout!("dock:fill");
+/// 
+/// // Second row and first two columns of a Grid
+/// let layout = layout!("row:1, col:0, colspan:2");
 /// ```
 /// 
 /// The layout macro provides a more concise alternative to manually building layouts with LayoutBuilder methods.
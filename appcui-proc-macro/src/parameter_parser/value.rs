@@ -289,10 +289,13 @@ impl<'a> Value<'a> {
             }
             return Ok(());
         }
+        if self.get_string().eq_ignore_ascii_case("auto") {
+            return Ok(());
+        }
         Err(Error::new(
             param_list,
             format!(
-                "Expecting an integer value (e.g. '{display_param_name}: 10') or a percentage value (e.g. '{display_param_name}: 50%`) for parameter '{display_param_name}' but found '{}'",
+                "Expecting an integer value (e.g. '{display_param_name}: 10'), a percentage value (e.g. '{display_param_name}: 50%`) or 'auto' for parameter '{display_param_name}' but found '{}'",
                 self.raw_data
             )
             .as_str(),
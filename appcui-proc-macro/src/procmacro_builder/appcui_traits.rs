@@ -56,6 +56,20 @@ pub(crate) enum AppCUITrait {
     CharPickerEvents = 47,
     GenericGraphViewEvents = 48,
     AppBarEvents = 49,
+    // raw events (added later)
+    OnMeasure = 50,
+    OnSessionState = 51,
+    // control events (added later)
+    WizardEvents = 52,
+    TerminalEvents = 53,
+    GenericSliderEvents = 54,
+    GenericRangeSliderEvents = 55,
+    TimePickerEvents = 56,
+    DateTimePickerEvents = 57,
+    DateRangePickerEvents = 58,
+    ToastEvents = 59,
+    // raw events (added later)
+    OnChildEvent = 60,
 }
 
 #[repr(u8)]
@@ -89,6 +103,9 @@ impl AppCUITrait {
             AppCUITrait::OnExpand => "OnExpand",
             AppCUITrait::OnSiblingSelected => "OnSiblingSelected",
             AppCUITrait::OnThemeChanged => "OnThemeChanged",
+            AppCUITrait::OnMeasure => "OnMeasure",
+            AppCUITrait::OnSessionState => "OnSessionState",
+            AppCUITrait::OnChildEvent => "OnChildEvent",
             // control events
             AppCUITrait::ButtonEvents => "ButtonEvents",
             AppCUITrait::CheckBoxEvents => "CheckBoxEvents",
@@ -118,6 +135,14 @@ impl AppCUITrait {
             AppCUITrait::MarkdownEvents => "MarkdownEvents",
             AppCUITrait::GenericBackgroundTaskEvents => "BackgroundTaskEvents", // important to be without Generic
             AppCUITrait::AccordionEvents => "AccordionEvents",
+            AppCUITrait::WizardEvents => "WizardEvents",
+            AppCUITrait::TerminalEvents => "TerminalEvents",
+            AppCUITrait::GenericSliderEvents => "SliderEvents", // important to be without Generic
+            AppCUITrait::GenericRangeSliderEvents => "RangeSliderEvents", // important to be without Generic
+            AppCUITrait::TimePickerEvents => "TimePickerEvents",
+            AppCUITrait::DateTimePickerEvents => "DateTimePickerEvents",
+            AppCUITrait::DateRangePickerEvents => "DateRangePickerEvents",
+            AppCUITrait::ToastEvents => "ToastEvents",
             AppCUITrait::TabEvents => "TabEvents",
             AppCUITrait::CharPickerEvents => "CharPickerEvents",
             AppCUITrait::GenericGraphViewEvents => "GraphViewEvents", // important to be without Generic
@@ -146,6 +171,9 @@ impl AppCUITrait {
             AppCUITrait::OnExpand => TraitType::RawEvent,
             AppCUITrait::OnSiblingSelected => TraitType::RawEvent,
             AppCUITrait::OnThemeChanged => TraitType::RawEvent,
+            AppCUITrait::OnMeasure => TraitType::RawEvent,
+            AppCUITrait::OnSessionState => TraitType::RawEvent,
+            AppCUITrait::OnChildEvent => TraitType::RawEvent,
             // control events
             AppCUITrait::ButtonEvents => TraitType::ControlEvent,
             AppCUITrait::CheckBoxEvents => TraitType::ControlEvent,
@@ -175,6 +203,14 @@ impl AppCUITrait {
             AppCUITrait::MarkdownEvents => TraitType::ControlEvent,
             AppCUITrait::GenericBackgroundTaskEvents => TraitType::ControlEvent,
             AppCUITrait::AccordionEvents => TraitType::ControlEvent,
+            AppCUITrait::WizardEvents => TraitType::ControlEvent,
+            AppCUITrait::TerminalEvents => TraitType::ControlEvent,
+            AppCUITrait::GenericSliderEvents => TraitType::ControlEvent,
+            AppCUITrait::GenericRangeSliderEvents => TraitType::ControlEvent,
+            AppCUITrait::TimePickerEvents => TraitType::ControlEvent,
+            AppCUITrait::DateTimePickerEvents => TraitType::ControlEvent,
+            AppCUITrait::DateRangePickerEvents => TraitType::ControlEvent,
+            AppCUITrait::ToastEvents => TraitType::ControlEvent,
             AppCUITrait::TabEvents => TraitType::ControlEvent,
             AppCUITrait::CharPickerEvents => TraitType::ControlEvent,
             AppCUITrait::GenericGraphViewEvents => TraitType::ControlEvent,
@@ -203,6 +239,9 @@ impl AppCUITrait {
             AppCUITrait::OnExpand => templates::ON_EXPAND_TRAIT,
             AppCUITrait::OnSiblingSelected => templates::ON_SIBLING_SELECTED,
             AppCUITrait::OnThemeChanged => templates::ON_THEME_CHANGED_TRAIT,
+            AppCUITrait::OnMeasure => templates::ON_MEASURE_TRAIT,
+            AppCUITrait::OnSessionState => templates::ON_SESSION_STATE_TRAIT,
+            AppCUITrait::OnChildEvent => "",
             // control events
             AppCUITrait::ButtonEvents => "",
             AppCUITrait::CheckBoxEvents => "",
@@ -232,6 +271,14 @@ impl AppCUITrait {
             AppCUITrait::MarkdownEvents => "",
             AppCUITrait::GenericBackgroundTaskEvents => "",
             AppCUITrait::AccordionEvents => "",
+            AppCUITrait::WizardEvents => "",
+            AppCUITrait::TerminalEvents => "",
+            AppCUITrait::GenericSliderEvents => "",
+            AppCUITrait::GenericRangeSliderEvents => "",
+            AppCUITrait::TimePickerEvents => "",
+            AppCUITrait::DateTimePickerEvents => "",
+            AppCUITrait::DateRangePickerEvents => "",
+            AppCUITrait::ToastEvents => "",
             AppCUITrait::TabEvents => "",
             AppCUITrait::CharPickerEvents => "",
             AppCUITrait::GenericGraphViewEvents => "",
@@ -260,6 +307,9 @@ impl AppCUITrait {
             AppCUITrait::OnExpand => "impl$(TEMPLATE_TYPE) OnExpand for $(STRUCT_NAME)$(TEMPLATE_DEF) {}",
             AppCUITrait::OnSiblingSelected => "impl$(TEMPLATE_TYPE) OnSiblingSelected for $(STRUCT_NAME)$(TEMPLATE_DEF) {}",
             AppCUITrait::OnThemeChanged => "impl$(TEMPLATE_TYPE) OnThemeChanged for $(STRUCT_NAME)$(TEMPLATE_DEF) {}",
+            AppCUITrait::OnMeasure => "impl$(TEMPLATE_TYPE) OnMeasure for $(STRUCT_NAME)$(TEMPLATE_DEF) {}",
+            AppCUITrait::OnSessionState => "impl$(TEMPLATE_TYPE) OnSessionState for $(STRUCT_NAME)$(TEMPLATE_DEF) {}",
+            AppCUITrait::OnChildEvent => templates::ON_CHILD_EVENT_DEFAULT,
             // control events
             AppCUITrait::ButtonEvents => "impl$(TEMPLATE_TYPE) ButtonEvents for $(STRUCT_NAME)$(TEMPLATE_DEF) {}",
             AppCUITrait::CheckBoxEvents => "impl$(TEMPLATE_TYPE) CheckBoxEvents for $(STRUCT_NAME)$(TEMPLATE_DEF) {}",
@@ -289,6 +339,14 @@ impl AppCUITrait {
             AppCUITrait::MarkdownEvents => "impl$(TEMPLATE_TYPE) MarkdownEvents for $(STRUCT_NAME)$(TEMPLATE_DEF) {}",
             AppCUITrait::GenericBackgroundTaskEvents => "impl$(TEMPLATE_TYPE) GenericBackgroundTaskEvents for $(STRUCT_NAME)$(TEMPLATE_DEF) {}",
             AppCUITrait::AccordionEvents => "impl$(TEMPLATE_TYPE) AccordionEvents for $(STRUCT_NAME)$(TEMPLATE_DEF) {}",
+            AppCUITrait::WizardEvents => "impl$(TEMPLATE_TYPE) WizardEvents for $(STRUCT_NAME)$(TEMPLATE_DEF) {}",
+            AppCUITrait::TerminalEvents => "impl$(TEMPLATE_TYPE) TerminalEvents for $(STRUCT_NAME)$(TEMPLATE_DEF) {}",
+            AppCUITrait::GenericSliderEvents => "impl$(TEMPLATE_TYPE) GenericSliderEvents for $(STRUCT_NAME)$(TEMPLATE_DEF) {}",
+            AppCUITrait::GenericRangeSliderEvents => "impl$(TEMPLATE_TYPE) GenericRangeSliderEvents for $(STRUCT_NAME)$(TEMPLATE_DEF) {}",
+            AppCUITrait::TimePickerEvents => "impl$(TEMPLATE_TYPE) TimePickerEvents for $(STRUCT_NAME)$(TEMPLATE_DEF) {}",
+            AppCUITrait::DateTimePickerEvents => "impl$(TEMPLATE_TYPE) DateTimePickerEvents for $(STRUCT_NAME)$(TEMPLATE_DEF) {}",
+            AppCUITrait::DateRangePickerEvents => "impl$(TEMPLATE_TYPE) DateRangePickerEvents for $(STRUCT_NAME)$(TEMPLATE_DEF) {}",
+            AppCUITrait::ToastEvents => "impl$(TEMPLATE_TYPE) ToastEvents for $(STRUCT_NAME)$(TEMPLATE_DEF) {}",
             AppCUITrait::TabEvents => "impl$(TEMPLATE_TYPE) TabEvents for $(STRUCT_NAME)$(TEMPLATE_DEF) {}",
             AppCUITrait::CharPickerEvents => "impl$(TEMPLATE_TYPE) CharPickerEvents for $(STRUCT_NAME)$(TEMPLATE_DEF) {}",
             AppCUITrait::GenericGraphViewEvents => "impl$(TEMPLATE_TYPE) GenericGraphViewEvents for $(STRUCT_NAME)$(TEMPLATE_DEF) {}",
@@ -305,6 +363,8 @@ impl AppCUITrait {
                 | AppCUITrait::GenericTreeViewEvents
                 | AppCUITrait::GenericBackgroundTaskEvents
                 | AppCUITrait::GenericGraphViewEvents
+                | AppCUITrait::GenericSliderEvents
+                | AppCUITrait::GenericRangeSliderEvents
         )
     }
     pub(crate) fn new(name: &str) -> Option<AppCUITrait> {
@@ -319,6 +379,9 @@ impl AppCUITrait {
             "OnExpand" => Some(AppCUITrait::OnExpand),
             "OnSiblingSelected" => Some(AppCUITrait::OnSiblingSelected),
             "OnThemeChanged" => Some(AppCUITrait::OnThemeChanged),
+            "OnMeasure" => Some(AppCUITrait::OnMeasure),
+            "OnSessionState" => Some(AppCUITrait::OnSessionState),
+            "OnChildEvent" => Some(AppCUITrait::OnChildEvent),
             // control events
             "ButtonEvents" | "Button" => Some(AppCUITrait::ButtonEvents),
             "CheckBoxEvents" | "CheckBox" => Some(AppCUITrait::CheckBoxEvents),
@@ -348,6 +411,14 @@ impl AppCUITrait {
             "MarkdownEvents" | "Markdown" => Some(AppCUITrait::MarkdownEvents),
             "BackgroundTaskEvents" | "BackgroundTask" => Some(AppCUITrait::GenericBackgroundTaskEvents),
             "AccordionEvents" | "Accordion" => Some(AppCUITrait::AccordionEvents),
+            "WizardEvents" | "Wizard" => Some(AppCUITrait::WizardEvents),
+            "TerminalEvents" | "Terminal" => Some(AppCUITrait::TerminalEvents),
+            "SliderEvents" | "Slider" => Some(AppCUITrait::GenericSliderEvents),
+            "RangeSliderEvents" | "RangeSlider" => Some(AppCUITrait::GenericRangeSliderEvents),
+            "TimePickerEvents" | "TimePicker" => Some(AppCUITrait::TimePickerEvents),
+            "DateTimePickerEvents" | "DateTimePicker" => Some(AppCUITrait::DateTimePickerEvents),
+            "DateRangePickerEvents" | "DateRangePicker" => Some(AppCUITrait::DateRangePickerEvents),
+            "ToastEvents" | "Toast" => Some(AppCUITrait::ToastEvents),
             "TabEvents" | "Tab" => Some(AppCUITrait::TabEvents),
             "CharPickerEvents" | "CharPicker" => Some(AppCUITrait::CharPickerEvents),
             "GraphViewEvents" | "GraphView" => Some(AppCUITrait::GenericGraphViewEvents),
@@ -412,6 +483,17 @@ impl AppCUITrait {
             47 => Some(AppCUITrait::CharPickerEvents),
             48 => Some(AppCUITrait::GenericGraphViewEvents),
             49 => Some(AppCUITrait::AppBarEvents),
+            50 => Some(AppCUITrait::OnMeasure),
+            51 => Some(AppCUITrait::OnSessionState),
+            52 => Some(AppCUITrait::WizardEvents),
+            53 => Some(AppCUITrait::TerminalEvents),
+            54 => Some(AppCUITrait::GenericSliderEvents),
+            55 => Some(AppCUITrait::GenericRangeSliderEvents),
+            56 => Some(AppCUITrait::TimePickerEvents),
+            57 => Some(AppCUITrait::DateTimePickerEvents),
+            58 => Some(AppCUITrait::DateRangePickerEvents),
+            59 => Some(AppCUITrait::ToastEvents),
+            60 => Some(AppCUITrait::OnChildEvent),
             _ => None,
         };
         result?;
@@ -147,6 +147,26 @@ fn generate_numeric_selector_events(a: &mut Arguments) -> String {
     }
     templates::NUMERIC_SELECTOR_TRAIT_DEF.replace("$(TYPE_ID_TRANSLATION_FOR_NUMERIC_SELECTOR)", s.as_str())
 }
+fn generate_slider_events(a: &mut Arguments) -> String {
+    if !a.template_events.contains_key(&AppCUITrait::GenericSliderEvents) {
+        panic!("Missing generic type for SliderEvents event (Have you used events=SliderEvents<Type> ?)");
+    }
+    let mut s = String::new();
+    for trait_name in a.template_events[&AppCUITrait::GenericSliderEvents].iter() {
+        s.push_str(templates::SLIDER_ON_VALUE_CHANGE_DEF.replace("$(TYPE)", trait_name).as_str());
+    }
+    templates::SLIDER_TRAIT_DEF.replace("$(TYPE_ID_TRANSLATION_FOR_SLIDER)", s.as_str())
+}
+fn generate_range_slider_events(a: &mut Arguments) -> String {
+    if !a.template_events.contains_key(&AppCUITrait::GenericRangeSliderEvents) {
+        panic!("Missing generic type for RangeSliderEvents event (Have you used events=RangeSliderEvents<Type> ?)");
+    }
+    let mut s = String::new();
+    for trait_name in a.template_events[&AppCUITrait::GenericRangeSliderEvents].iter() {
+        s.push_str(templates::RANGE_SLIDER_ON_RANGE_CHANGE_DEF.replace("$(TYPE)", trait_name).as_str());
+    }
+    templates::RANGE_SLIDER_TRAIT_DEF.replace("$(TYPE_ID_TRANSLATION_FOR_RANGE_SLIDER)", s.as_str())
+}
 
 fn generate_listview_events(a: &mut Arguments) -> String {
     if !a.template_events.contains_key(&AppCUITrait::GenericListViewEvents) {
@@ -293,6 +313,8 @@ pub(crate) fn build(args: TokenStream, input: TokenStream, base_control: BaseCon
                         AppCUITrait::GenericTreeViewEvents => code.push_str(generate_treeview_events(&mut a).as_str()),
                         AppCUITrait::GenericBackgroundTaskEvents=> code.push_str(generate_backgroundtask_events(&mut a).as_str()),
                         AppCUITrait::GenericGraphViewEvents => code.push_str(generate_graphview_events(&mut a).as_str()),
+                        AppCUITrait::GenericSliderEvents => code.push_str(generate_slider_events(&mut a).as_str()),
+                        AppCUITrait::GenericRangeSliderEvents => code.push_str(generate_range_slider_events(&mut a).as_str()),
                         _ => {}
                     }
                 }
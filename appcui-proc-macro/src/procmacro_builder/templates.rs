@@ -93,6 +93,26 @@ impl$(TEMPLATE_TYPE) OnThemeChanged for $(STRUCT_NAME)$(TEMPLATE_DEF) {
 }
 ";
 
+pub(crate) static ON_MEASURE_TRAIT: &str = "
+impl$(TEMPLATE_TYPE) OnMeasure for $(STRUCT_NAME)$(TEMPLATE_DEF) {
+    fn preferred_size(&self, available: Size) -> Option<Size>  { self.base.preferred_size(available) }
+}
+";
+
+pub(crate) static ON_SESSION_STATE_TRAIT: &str = "
+impl$(TEMPLATE_TYPE) OnSessionState for $(STRUCT_NAME)$(TEMPLATE_DEF) {
+    fn save_state(&self, state: &mut SessionState)  { self.base.save_state(state); }
+    fn restore_state(&mut self, state: &SessionState)  { self.base.restore_state(state); }
+}
+";
+
+// a control that does not overwrite OnChildEvent is never notified about the events of its children
+pub(crate) static ON_CHILD_EVENT_DEFAULT: &str = "
+impl$(TEMPLATE_TYPE) OnChildEvent for $(STRUCT_NAME)$(TEMPLATE_DEF) {
+    fn notifies_child_events() -> bool { false }
+}
+";
+
 pub(crate) static ON_WINDOW_REGISTERED_TRAIT: &str = "
 impl$(TEMPLATE_TYPE) OnWindowRegistered for $(STRUCT_NAME)$(TEMPLATE_DEF) {
     fn on_registered(&mut self)  { self.base.on_registered(); }
@@ -128,12 +148,16 @@ pub(crate) static COMMANDS_TEMPLATE: &str = "
 pub(crate) static COMMANDBAR_EVENTS: &str = "
 trait CommandBarEvents {
     fn on_update_commandbar(&self, commandbar: &mut CommandBar);
+    fn on_update_command_palette(&self, _actions: &mut command_palette::Actions) {}
     fn on_event(&mut self, command_id: $(MOD_NAME)::Commands);
 }
 impl$(TEMPLATE_TYPE) GenericCommandBarEvents for $(STRUCT_NAME)$(TEMPLATE_DEF) {
     fn on_update_commandbar(&self, commandbar: &mut CommandBar) {
         CommandBarEvents::on_update_commandbar(self, commandbar);
     }
+    fn on_update_command_palette(&self, actions: &mut command_palette::Actions) {
+        CommandBarEvents::on_update_command_palette(self, actions);
+    }
     fn on_event(&mut self, command_id: u32) {
         if let Ok(command) = $(MOD_NAME)::Commands::try_from(command_id) {
             CommandBarEvents::on_event(self, command);
@@ -302,6 +326,50 @@ if std::any::TypeId::of::<$(TYPE)>() == type_id {
 }
 ";
 
+pub(crate) static SLIDER_TRAIT_DEF: &str = "
+trait SliderEvents<T: Number+'static> {
+    fn on_value_changed(&mut self, handle: Handle<Slider<T>>, value: T) -> EventProcessStatus;
+}
+impl$(TEMPLATE_TYPE) GenericSliderEvents for $(STRUCT_NAME)$(TEMPLATE_DEF) {
+    fn on_value_changed(&mut self, handle: Handle<()>, type_id: std::any::TypeId) -> EventProcessStatus {
+        $(TYPE_ID_TRANSLATION_FOR_SLIDER)
+        return EventProcessStatus::Ignored;
+    }
+}
+";
+pub(crate) static SLIDER_ON_VALUE_CHANGE_DEF: &str = "
+if std::any::TypeId::of::<$(TYPE)>() == type_id {
+    let h: Handle<Slider<$(TYPE)>> = unsafe { handle.unsafe_cast() };
+    if let Some(obj) = self.control(h) {
+        let value = obj.value();
+        return SliderEvents::<$(TYPE)>::on_value_changed(self, h, value);
+    }
+    return EventProcessStatus::Ignored;
+}
+";
+
+pub(crate) static RANGE_SLIDER_TRAIT_DEF: &str = "
+trait RangeSliderEvents<T: Number+'static> {
+    fn on_range_changed(&mut self, handle: Handle<RangeSlider<T>>, start: T, end: T) -> EventProcessStatus;
+}
+impl$(TEMPLATE_TYPE) GenericRangeSliderEvents for $(STRUCT_NAME)$(TEMPLATE_DEF) {
+    fn on_range_changed(&mut self, handle: Handle<()>, type_id: std::any::TypeId) -> EventProcessStatus {
+        $(TYPE_ID_TRANSLATION_FOR_RANGE_SLIDER)
+        return EventProcessStatus::Ignored;
+    }
+}
+";
+pub(crate) static RANGE_SLIDER_ON_RANGE_CHANGE_DEF: &str = "
+if std::any::TypeId::of::<$(TYPE)>() == type_id {
+    let h: Handle<RangeSlider<$(TYPE)>> = unsafe { handle.unsafe_cast() };
+    if let Some(obj) = self.control(h) {
+        let (start, end) = (obj.start(), obj.end());
+        return RangeSliderEvents::<$(TYPE)>::on_range_changed(self, h, start, end);
+    }
+    return EventProcessStatus::Ignored;
+}
+";
+
 
 pub(crate) static LISTVIEW_ON_CURRENT_ITEM_CHANGED_DEF: &str = "
 if std::any::TypeId::of::<$(TYPE)>() == type_id {
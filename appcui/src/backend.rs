@@ -98,6 +98,10 @@ pub(crate) trait Backend {
     }
     fn is_single_threaded(&self) -> bool;
     fn on_close(&mut self) {}
+    // restores the terminal to its original state (cooked mode, main screen) so that
+    // another process can use it. `resume` sets up the terminal again.
+    fn suspend(&mut self) {}
+    fn resume(&mut self) {}
 }
 
 #[repr(u8)]
@@ -117,6 +121,19 @@ pub enum Type {
     CrossTerm,
 }
 
+pub(crate) fn start_replay(builder: &crate::system::Builder, backend: &dyn Backend, sender: Sender<SystemEvent>) -> Result<(), Error> {
+    if let Some((script, speed)) = builder.replay.as_ref() {
+        if builder.debug_script.is_some() || backend.is_single_threaded() {
+            return Err(Error::new(
+                ErrorKind::InvalidFeature,
+                String::from("A recorded script can not be replayed using the debug backend or a single-threaded backend !"),
+            ));
+        }
+        debug::Replay::new(script, *speed)?.start(sender);
+    }
+    Ok(())
+}
+
 pub(crate) fn new(builder: &crate::system::Builder, sender: Sender<SystemEvent>) -> Result<Box<dyn Backend>, Error> {
     // check if backend size if valid (if present)
     if let Some(sz) = builder.size.as_ref() {
@@ -135,6 +152,10 @@ pub(crate) fn new(builder: &crate::system::Builder, sender: Sender<SystemEvent>)
         let term = DebugTerminal::new(builder)?;
         return Ok(Box::new(term));
     }
+    // inline mode is only supported by some backends
+    if builder.inline.is_some() {
+        return build_inline_backend(builder, sender);
+    }
     // if no backend is provided --> consider the default backend (best approach)
     // this depends on the OS
     if builder.backend.is_none() {
@@ -177,6 +198,24 @@ pub(crate) fn new(builder: &crate::system::Builder, sender: Sender<SystemEvent>)
     }
 }
 
+#[allow(unused_variables)]
+fn build_inline_backend(builder: &crate::system::Builder, sender: Sender<SystemEvent>) -> Result<Box<dyn Backend>, Error> {
+    match builder.backend {
+        #[cfg(target_family = "unix")]
+        None | Some(Type::Termios) => TermiosTerminal::new(builder, sender),
+        #[cfg(feature = "CROSSTERM")]
+        Some(Type::CrossTerm) => {
+            let term = CrossTerm::new(builder, sender)?;
+            Ok(Box::new(term))
+        }
+        #[allow(unreachable_patterns)]
+        _ => Err(Error::new(
+            ErrorKind::InvalidFeature,
+            "Inline mode is only supported by the Termios and CrossTerm backends !".to_string(),
+        )),
+    }
+}
+
 #[cfg(target_arch = "wasm32")]
 fn build_default_backend(builder: &crate::system::Builder, sender: Sender<SystemEvent>) -> Result<Box<dyn Backend>, Error> {
     let term = WebTerminal::new(builder, sender)?;
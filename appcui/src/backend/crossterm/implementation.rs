@@ -1,18 +1,22 @@
 use super::input::Input;
 use crate::{
-    backend::{Backend, SystemEventReader},
-    graphics::{CharFlags, Color, Size, Surface},
+    backend::{utils::InlineViewport, Backend, SystemEventReader},
+    graphics::{CharFlags, Color, Point, Size, Surface},
     system::{Error, SystemEvent},
 };
 use crossterm::event::{DisableMouseCapture, EnableMouseCapture};
 use crossterm::{
-    cursor::{Hide, MoveTo, Show},
+    cursor::{position, Hide, MoveTo, Show},
     execute, queue,
     style::{Color as CrosstermColor, Print, ResetColor, SetBackgroundColor, SetForegroundColor},
     terminal::{disable_raw_mode, enable_raw_mode, size, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use std::io::stdout;
-use std::sync::mpsc::Sender;
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    mpsc::Sender,
+    Arc,
+};
 
 #[cfg(target_os = "windows")]
 use crate::backend::utils::win32;
@@ -25,6 +29,8 @@ use copypasta::ClipboardProvider;
 pub(crate) struct CrossTerm {
     size: Size,
     use_color_schema: bool,
+    viewport: Option<Arc<InlineViewport>>,
+    suspended: Arc<AtomicBool>,
 }
 
 impl CrossTerm {
@@ -54,24 +60,59 @@ impl CrossTerm {
         let mut term = CrossTerm {
             size: Size::new(width as u32, height as u32),
             use_color_schema: builder.use_color_schema,
+            viewport: None,
+            suspended: Arc::new(AtomicBool::new(false)),
         };
 
-        if let Some(sz) = builder.size {
-            term.size = sz;
-        }
+        if let Some(height) = builder.inline {
+            // inline mode: reserve the lines below the cursor instead of switching to the alternate screen
+            term.viewport = Some(Arc::new(InlineViewport::new(height)));
+            term.reserve_inline_viewport(term.size).map_err(|e| {
+                Error::new(
+                    crate::system::ErrorKind::InitializationFailure,
+                    format!("Failed to initialize terminal: {}", e),
+                )
+            })?;
+        } else {
+            if let Some(sz) = builder.size {
+                term.size = sz;
+            }
 
-        execute!(stdout(), EnterAlternateScreen, Clear(ClearType::All), Hide).map_err(|e| {
-            Error::new(
-                crate::system::ErrorKind::InitializationFailure,
-                format!("Failed to initialize terminal: {}", e),
-            )
-        })?;
+            execute!(stdout(), EnterAlternateScreen, Clear(ClearType::All), Hide).map_err(|e| {
+                Error::new(
+                    crate::system::ErrorKind::InitializationFailure,
+                    format!("Failed to initialize terminal: {}", e),
+                )
+            })?;
+        }
 
-        Input::new().start(sender);
+        Input::new(term.viewport.clone(), term.suspended.clone()).start(sender);
 
         Ok(term)
     }
 
+    fn reserve_inline_viewport(&mut self, terminal_size: Size) -> std::io::Result<()> {
+        if let Some(viewport) = &self.viewport {
+            // if the cursor position can not be read, we assume that the cursor is on the last line
+            let cursor = position()
+                .map(|(x, y)| Point::new(x as i32, y as i32))
+                .unwrap_or(Point::new(0, terminal_size.height as i32 - 1));
+            let new_lines = viewport.reserve(terminal_size, cursor);
+            execute!(stdout(), Print("\r\n".repeat(new_lines as usize)), Hide)?;
+            self.size = viewport.size(terminal_size.width);
+        }
+        Ok(())
+    }
+
+    fn leave_screen(&mut self) {
+        if let Some(viewport) = &self.viewport {
+            // leave the last frame in the scrollback and move the cursor on the next line
+            let _ = execute!(stdout(), ResetColor, MoveTo(0, viewport.last_line() as u16), Print("\r\n"), Show);
+        } else {
+            let _ = execute!(stdout(), LeaveAlternateScreen, Show, ResetColor);
+        }
+    }
+
     fn convert_color(&self, color: Color) -> CrosstermColor {
         if self.use_color_schema {
             match color {
@@ -127,7 +168,8 @@ impl Backend for CrossTerm {
         use crossterm::queue;
         use std::io::Write;
 
-        queue!(stdout, MoveTo(0, 0)).unwrap();
+        let origin = self.viewport.as_ref().map(|v| v.origin() as u16).unwrap_or(0);
+        queue!(stdout, MoveTo(0, origin)).unwrap();
 
         let mut x = 0;
         let mut y = 0;
@@ -179,13 +221,13 @@ impl Backend for CrossTerm {
                 x = 0;
                 y += 1;
                 if y < surface.size.height {
-                    queue!(stdout, MoveTo(0, y as u16)).unwrap();
+                    queue!(stdout, MoveTo(0, y as u16 + origin)).unwrap();
                 }
             }
         }
 
         if surface.cursor.is_visible() {
-            queue!(stdout, Show, MoveTo(surface.cursor.x as u16, surface.cursor.y as u16)).unwrap();
+            queue!(stdout, Show, MoveTo(surface.cursor.x as u16, surface.cursor.y as u16 + origin)).unwrap();
         } else {
             queue!(stdout, Hide).unwrap();
         }
@@ -195,6 +237,10 @@ impl Backend for CrossTerm {
 
     fn on_resize(&mut self, new_size: Size) {
         self.size = new_size;
+        if let Some(viewport) = &self.viewport {
+            // the terminal might have re-flowed the lines of the viewport --> clear everything from the viewport origin down
+            let _ = execute!(stdout(), MoveTo(0, viewport.origin() as u16), Clear(ClearType::FromCursorDown));
+        }
     }
 
     fn size(&self) -> Size {
@@ -244,7 +290,34 @@ impl Backend for CrossTerm {
 
     fn on_close(&mut self) {
         let _ = execute!(stdout(), DisableMouseCapture);
-        let _ = execute!(stdout(), LeaveAlternateScreen, Show, ResetColor);
+        self.leave_screen();
         let _ = disable_raw_mode();
     }
+
+    fn suspend(&mut self) {
+        if self.suspended.swap(true, Ordering::AcqRel) {
+            return;
+        }
+        let _ = execute!(stdout(), DisableMouseCapture);
+        self.leave_screen();
+        let _ = disable_raw_mode();
+    }
+
+    fn resume(&mut self) {
+        let _ = enable_raw_mode();
+        if self.suspended.load(Ordering::Acquire) {
+            let terminal_size = size()
+                .map(|(w, h)| Size::new(w as u32, h as u32))
+                .unwrap_or(self.size);
+            if self.viewport.is_some() {
+                // the input thread is still paused at this point, so the cursor position can be read safely
+                let _ = self.reserve_inline_viewport(terminal_size);
+            } else {
+                let _ = execute!(stdout(), EnterAlternateScreen, Clear(ClearType::All));
+                self.size = terminal_size;
+            }
+        }
+        let _ = execute!(stdout(), EnableMouseCapture, Hide);
+        self.suspended.store(false, Ordering::Release);
+    }
 }
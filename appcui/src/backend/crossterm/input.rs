@@ -1,28 +1,51 @@
+use crate::backend::utils::InlineViewport;
 use crate::backend::SystemEventReader;
 use crate::input::{Key, KeyCode, KeyModifier, MouseButton, MouseWheelDirection};
 use crate::system::SystemEvent;
 use crate::system::{KeyModifierChangedEvent, KeyPressedEvent, MouseButtonDownEvent, MouseButtonUpEvent, MouseMoveEvent, MouseWheelEvent};
 use crossterm::event::KeyCode as CrosstermKeyCode;
 use crossterm::event::{self, Event, KeyEvent, KeyEventKind, MouseButton as CrosstermMouseButton, MouseEvent, MouseEventKind};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
 
 pub(crate) struct Input {
     last_modifiers: KeyModifier,
+    viewport: Option<Arc<InlineViewport>>,
+    suspended: Arc<AtomicBool>,
 }
 
 impl Input {
-    pub(super) fn new() -> Self {
+    pub(super) fn new(viewport: Option<Arc<InlineViewport>>, suspended: Arc<AtomicBool>) -> Self {
         Self {
             last_modifiers: KeyModifier::None,
+            viewport,
+            suspended,
         }
     }
 }
 
 impl SystemEventReader for Input {
     fn read(&mut self) -> Option<SystemEvent> {
+        // no input should be consumed while the application is suspended
+        if self.suspended.load(Ordering::Acquire) {
+            std::thread::sleep(Duration::from_millis(100));
+            return None;
+        }
+        if !event::poll(Duration::from_millis(100)).unwrap_or(false) || self.suspended.load(Ordering::Acquire) {
+            return None;
+        }
         match event::read() {
             Ok(Event::Key(key_event)) => self.handle_key_event(key_event),
             Ok(Event::Mouse(mouse_event)) => self.handle_mouse_event(mouse_event),
-            Ok(Event::Resize(width, height)) => Some(SystemEvent::Resize(crate::graphics::Size::new(width as u32, height as u32))),
+            Ok(Event::Resize(width, height)) => {
+                let terminal_size = crate::graphics::Size::new(width as u32, height as u32);
+                if let Some(viewport) = &self.viewport {
+                    Some(SystemEvent::Resize(viewport.on_terminal_resize(terminal_size)))
+                } else {
+                    Some(SystemEvent::Resize(terminal_size))
+                }
+            }
             Ok(Event::FocusGained) => None,
             Ok(Event::FocusLost) => None,
             Ok(Event::Paste(_)) => None,
@@ -62,7 +85,11 @@ impl Input {
 
     fn handle_mouse_event(&mut self, mouse_event: MouseEvent) -> Option<SystemEvent> {
         let x = mouse_event.column as i32;
-        let y = mouse_event.row as i32;
+        let y = if let Some(viewport) = &self.viewport {
+            viewport.translate_y(mouse_event.row as i32)
+        } else {
+            mouse_event.row as i32
+        };
 
         match mouse_event.kind {
             MouseEventKind::Down(button) => {
@@ -13,15 +13,20 @@ mod error_disable_command;
 mod check_hash_command;
 mod check_cursor_command;
 mod check_clipboardtext_command;
+mod check_snapshot_command;
+mod check_text_command;
 mod resize_command;
 mod keypress_command;
 mod keytypetext_command;
 mod keymodifier_command;
 mod clipboard_clear_command;
 mod clipboard_settext_command;
+mod wait_command;
 mod command;
+mod replay;
 
 #[cfg(test)]
 mod tests;
 
-pub (crate) use self::implementation::DebugTerminal;
\ No newline at end of file
+pub (crate) use self::implementation::DebugTerminal;
+pub (crate) use self::replay::Replay;
\ No newline at end of file
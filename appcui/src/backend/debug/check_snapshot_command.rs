@@ -0,0 +1,23 @@
+use super::command_parser::{CommandParser, ParserError};
+
+pub(super) struct CheckSnapshotCommand {
+    path: String,
+}
+
+impl CheckSnapshotCommand {
+    pub(super) fn new(parser: &CommandParser) -> Result<Self, ParserError> {
+        if parser.get_params_count() != 1 {
+            return Err(ParserError::new(
+                "CheckSnapshot command must have one parameter (the path of the snapshot file) !",
+            ));
+        }
+        let path = parser.get_string(0).unwrap();
+        if path.is_empty() {
+            return Err(ParserError::new("CheckSnapshot (empty path) --> expecting the path of a snapshot file"));
+        }
+        Ok(Self { path })
+    }
+    pub(super) fn get_path(&self) -> &str {
+        &self.path
+    }
+}
@@ -0,0 +1,39 @@
+use super::command_parser::{CommandParser, ParserError};
+use crate::graphics::Point;
+
+pub(super) struct CheckTextCommand {
+    point: Point,
+    text: String,
+}
+
+impl CheckTextCommand {
+    pub(super) fn new(parser: &CommandParser) -> Result<Self, ParserError> {
+        if parser.get_params_count() != 3 {
+            return Err(ParserError::new(
+                "CheckText command must have three parameters: CheckText(x,y,text) - to check that a text is written on the screen starting from position (x,y) !",
+            ));
+        }
+        let x = parser.get_i32(0);
+        let y = parser.get_i32(1);
+        if x.is_none() {
+            return Err(ParserError::new("Invalid numerical value for the 'x' coordonate of the text !"));
+        }
+        if y.is_none() {
+            return Err(ParserError::new("Invalid numerical value for the 'y' coordonate of the text !"));
+        }
+        let text = parser.get_string(2).unwrap();
+        if text.is_empty() {
+            return Err(ParserError::new("CheckText (empty text) --> expecting a text to look for"));
+        }
+        Ok(Self {
+            point: Point::new(x.unwrap(), y.unwrap()),
+            text,
+        })
+    }
+    pub(super) fn get_point(&self) -> Point {
+        self.point
+    }
+    pub(super) fn get_text(&self) -> &str {
+        &self.text
+    }
+}
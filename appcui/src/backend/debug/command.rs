@@ -10,6 +10,8 @@ use super::{
     clipboard_clear_command::ClipboardClearCommand, 
     clipboard_settext_command::ClipboardSetTextCommand, 
     check_clipboardtext_command::CheckClipboardTextCommand,
+    check_snapshot_command::CheckSnapshotCommand,
+    check_text_command::CheckTextCommand,
     command_parser::{CommandParser, ParserError}, 
     error_disable_command::ErrorDisableCommand, 
     keypress_command::KeyPressedCommand, 
@@ -25,6 +27,7 @@ use super::{
     paint_enable_command::PaintEnableCommand, 
     resize_command::ResizeCommand,
     keymodifier_command::KeyModifierCommand,
+    wait_command::WaitCommand,
 };
 
 pub(super) enum Command {
@@ -47,6 +50,9 @@ pub(super) enum Command {
     ClipboardSetText(ClipboardSetTextCommand),
     ClipboardClear(ClipboardClearCommand),
     CheckClipboardText(CheckClipboardTextCommand),
+    CheckSnapshot(CheckSnapshotCommand),
+    CheckText(CheckTextCommand),
+    Wait(WaitCommand),
 }
 impl Command {
     pub(super) fn new(text: &str) -> Result<Command, ParserError> {
@@ -99,7 +105,19 @@ impl Command {
             "CheckClipboardText" => {
                 let variant = CheckClipboardTextCommand::new(&cp)?;
                 Ok(Command::CheckClipboardText(variant))
-            }            
+            }
+            "CheckSnapshot" => {
+                let variant = CheckSnapshotCommand::new(&cp)?;
+                Ok(Command::CheckSnapshot(variant))
+            }
+            "CheckText" => {
+                let variant = CheckTextCommand::new(&cp)?;
+                Ok(Command::CheckText(variant))
+            }
+            "Wait" => {
+                let variant = WaitCommand::new(&cp)?;
+                Ok(Command::Wait(variant))
+            }
             "Error.Disable" => {
                 let variant = ErrorDisableCommand::new(&cp)?;
                 Ok(Command::ErrorDisable(variant))
@@ -154,6 +172,9 @@ impl Command {
             Command::CheckHash(_) => {}
             Command::CheckCursor(_) => {},
             Command::CheckClipboardText(_) => {},
+            Command::CheckSnapshot(_) => {},
+            Command::CheckText(_) => {},
+            Command::Wait(_) => {},
             Command::ClipboardSetText(_) => {},
             Command::ClipboardClear(_) => {},
             
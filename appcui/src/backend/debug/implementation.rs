@@ -24,10 +24,13 @@ pub(crate) struct DebugTerminal {
     paint_title: String,
     hash_to_test: Option<u64>,
     cursor_point_to_check: Option<Point>,
+    snapshot_to_check: Option<String>,
+    text_to_check: Option<(Point, String)>,
     mouse_pos: Point,
     keymodifier_state: KeyModifier,
     errors_disabled: bool,
     clipboard_text: String,
+    close_on_script_end: bool,
 }
 impl DebugTerminal {
     fn build_commands(script: &str) -> VecDeque<Command> {
@@ -72,9 +75,12 @@ impl DebugTerminal {
             paint_title: String::new(),
             hash_to_test: None,
             cursor_point_to_check: None,
+            snapshot_to_check: None,
+            text_to_check: None,
             mouse_pos: Point::new(0, 0),
             keymodifier_state: KeyModifier::None,
             clipboard_text: String::new(),
+            close_on_script_end: !builder.test_driver,
         })
     }
 
@@ -114,6 +120,20 @@ impl DebugTerminal {
         }
         hash
     }
+
+    // reads (at most) `count` characters from the surface starting from `point` (the text is clipped to the end of the line)
+    fn read_text(surface: &Surface, point: Point, count: usize) -> String {
+        let sz = surface.size();
+        let mut result = String::with_capacity(count);
+        if (point.x < 0) || (point.y < 0) || (point.y >= sz.height as i32) {
+            return result;
+        }
+        for x in (point.x as u32)..(sz.width.min(point.x as u32 + count as u32)) {
+            let ch = surface.chars[(point.y as u32 * sz.width + x) as usize].code;
+            result.push(if ch < ' ' { ' ' } else { ch });
+        }
+        result
+    }
 }
 impl Backend for DebugTerminal {
     fn is_single_threaded(&self) -> bool {
@@ -160,6 +180,28 @@ impl Backend for DebugTerminal {
                 }
             }
         }
+        if let Some(path) = self.snapshot_to_check.take() {
+            if let Err(message) = crate::testing::check_snapshot(&path, surface) {
+                if self.errors_disabled {
+                    println!("\x1b[91;40m[Error] {message}\x1b[0m");
+                } else {
+                    panic!("{message}");
+                }
+            }
+        }
+        if let Some((point, text)) = self.text_to_check.take() {
+            let found = DebugTerminal::read_text(surface, point, text.chars().count());
+            if found != text {
+                if self.errors_disabled {
+                    println!(
+                        "\x1b[91;40m[Error] Invalid text at ({},{}): (expecting: '{}' but found '{}')\x1b[0m",
+                        point.x, point.y, text, found
+                    );
+                } else {
+                    panic!("Invalid text at ({},{}): (expecting: '{}' but found '{}')", point.x, point.y, text, found);
+                }
+            }
+        }
 
         self.hash_to_test = None;
         self.cursor_point_to_check = None;
@@ -389,7 +431,23 @@ impl Backend for DebugTerminal {
                     RuntimeManager::get().request_repaint();
                     return None;
                 }
+                Command::CheckSnapshot(obj) => {
+                    self.paint = false;
+                    self.snapshot_to_check = Some(obj.get_path().to_string()); // next time I paint --> I will compare it with the snapshot
+                    RuntimeManager::get().request_repaint();
+                    return None;
+                }
 
+                Command::CheckText(obj) => {
+                    self.paint = false;
+                    self.text_to_check = Some((obj.get_point(), obj.get_text().to_string())); // next time I paint --> I will check it
+                    RuntimeManager::get().request_repaint();
+                    return None;
+                }
+                Command::Wait(_) => {
+                    // scripts are executed as fast as possible (timing is only used when a script is replayed)
+                    return None;
+                }
                 Command::ClipboardSetText(obj) => {
                     self.set_clipboard_text(obj.get_text());
                     return None;
@@ -420,7 +478,12 @@ impl Backend for DebugTerminal {
         }
 
         // if nothing else works, close the app (script has finished)
-        Some(SystemEvent::AppClose)
+        // when driven by a TestDriver, the input comes from the driver (via the system event channel)
+        if self.close_on_script_end {
+            Some(SystemEvent::AppClose)
+        } else {
+            None
+        }
     }
 
     fn clipboard_text(&self) -> Option<String> {
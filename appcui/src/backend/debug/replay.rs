@@ -0,0 +1,92 @@
+use std::collections::VecDeque;
+use std::sync::mpsc::Sender;
+use std::thread;
+use std::time::Duration;
+
+use super::command::Command;
+use crate::graphics::Point;
+use crate::input::KeyModifier;
+use crate::system::{Error, ErrorKind, SystemEvent};
+
+/// A recorded script (see the EVENT_RECORDER feature) that is played back against a real backend.
+/// Every input event is sent (through the system event channel) after the delay specified by the
+/// previous `Wait` commands (scaled by the replay speed). Validation and paint commands are ignored.
+pub(crate) struct Replay {
+    events: Vec<(Duration, SystemEvent)>,
+}
+
+impl Replay {
+    pub(crate) fn new(script: &str, speed: f32) -> Result<Self, Error> {
+        if !speed.is_finite() || speed <= 0.0 {
+            return Err(Error::new(
+                ErrorKind::InvalidParameter,
+                format!("Invalid replay speed ({speed}). The speed must be a positive value (1.0 means the original speed) !"),
+            ));
+        }
+        let mut events = Vec::with_capacity(64);
+        let mut sys_events: VecDeque<SystemEvent> = VecDeque::with_capacity(8);
+        let mut mouse_pos = Point::new(0, 0);
+        let mut keymodifier_state = KeyModifier::None;
+        let mut delay = Duration::ZERO;
+        for (index, line) in script.lines().enumerate() {
+            let trim_line = line.trim();
+            if (trim_line.is_empty()) || (trim_line.starts_with(';')) || (trim_line.starts_with('#')) || (trim_line.starts_with("//")) {
+                continue;
+            }
+            let cmd = match Command::new(trim_line) {
+                Ok(cmd) => cmd,
+                Err(err) => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidParameter,
+                        format!("Invalid replay script (line {}) -> {}", index + 1, err.as_string()),
+                    ));
+                }
+            };
+            match cmd {
+                Command::Wait(obj) => {
+                    delay += Duration::from_secs_f64(obj.get_milliseconds() as f64 / (1000.0 * speed as f64));
+                }
+                // the size of a real terminal can not be changed by the replay
+                Command::Resize(_) => {}
+                _ => {
+                    cmd.generate_event(mouse_pos, keymodifier_state, &mut sys_events);
+                    while let Some(event) = sys_events.pop_front() {
+                        match event {
+                            SystemEvent::MouseButtonDown(evnt) => mouse_pos = Point::new(evnt.x, evnt.y),
+                            SystemEvent::MouseButtonUp(evnt) => mouse_pos = Point::new(evnt.x, evnt.y),
+                            SystemEvent::MouseDoubleClick(evnt) => mouse_pos = Point::new(evnt.x, evnt.y),
+                            SystemEvent::MouseMove(evnt) => mouse_pos = Point::new(evnt.x, evnt.y),
+                            SystemEvent::MouseWheel(evnt) => mouse_pos = Point::new(evnt.x, evnt.y),
+                            SystemEvent::KeyModifierChanged(evnt) => keymodifier_state = evnt.new_state,
+                            _ => {}
+                        }
+                        events.push((delay, event));
+                        delay = Duration::ZERO;
+                    }
+                }
+            }
+        }
+        Ok(Self { events })
+    }
+
+    #[cfg(test)]
+    pub(crate) fn events(&self) -> &[(Duration, SystemEvent)] {
+        &self.events
+    }
+
+    /// Starts a thread that sends the recorded events to the runtime manager.
+    /// The application keeps running (and accepts user input) after the replay ends.
+    pub(crate) fn start(self, sender: Sender<SystemEvent>) {
+        thread::spawn(move || {
+            for (delay, event) in self.events {
+                if !delay.is_zero() {
+                    thread::sleep(delay);
+                }
+                if sender.send(event).is_err() {
+                    // the application was closed
+                    break;
+                }
+            }
+        });
+    }
+}
@@ -1,6 +1,8 @@
 use crate::backend::debug::check_clipboardtext_command::CheckClipboardTextCommand;
 use crate::backend::debug::check_cursor_command::CheckCursorCommand;
 use crate::backend::debug::check_hash_command::CheckHashCommand;
+use crate::backend::debug::check_snapshot_command::CheckSnapshotCommand;
+use crate::backend::debug::check_text_command::CheckTextCommand;
 use crate::backend::debug::clipboard_clear_command::ClipboardClearCommand;
 use crate::backend::debug::clipboard_settext_command::ClipboardSetTextCommand;
 use crate::backend::debug::error_disable_command::ErrorDisableCommand;
@@ -15,6 +17,11 @@ use crate::backend::debug::mouse_move_command::MouseMoveCommand;
 use crate::backend::debug::mouse_release_command::MouseReleaseCommand;
 use crate::backend::debug::mouse_wheel_command::MouseWheelCommand;
 use crate::backend::debug::paint_enable_command::PaintEnableCommand;
+use crate::backend::debug::replay::Replay;
+use crate::backend::debug::wait_command::WaitCommand;
+use crate::prelude::*;
+use crate::system::SystemEvent;
+use std::time::Duration;
 
 use super::command_parser::CommandParser;
 use super::resize_command::ResizeCommand;
@@ -373,6 +380,18 @@ fn check_checkclipboardtext_errors() {
     assert!(CheckClipboardTextCommand::new(&CommandParser::new("CheckClipboatfText()").unwrap()).is_err());
 }
 
+#[test]
+fn check_checksnapshot_errors() {
+    // invalid number of parameters
+    assert!(CheckSnapshotCommand::new(&CommandParser::new("CheckSnapshot(a,b)").unwrap()).is_err());
+    assert!(CheckSnapshotCommand::new(&CommandParser::new("CheckSnapshot()").unwrap()).is_err());
+    // empty path
+    assert!(CheckSnapshotCommand::new(&CommandParser::new("CheckSnapshot('')").unwrap()).is_err());
+    // valid path
+    let cmd = CheckSnapshotCommand::new(&CommandParser::new("CheckSnapshot('snapshots/test.ansi')").unwrap()).unwrap();
+    assert_eq!(cmd.get_path(), "snapshots/test.ansi");
+}
+
 #[test]
 fn check_checkhash_errors() {
     // invalid number of parameters
@@ -395,4 +414,104 @@ fn check_checkcursor_errors() {
     // invalid position values (integers)
     assert!(CheckCursorCommand::new(&CommandParser::new("CheckCursor(x,0)").unwrap()).is_err());
     assert!(CheckCursorCommand::new(&CommandParser::new("CheckCursor(0,y)").unwrap()).is_err());
-}
\ No newline at end of file
+}
+#[test]
+fn check_wait_errors() {
+    // invalid number of parameters
+    assert!(WaitCommand::new(&CommandParser::new("Wait(1,2)").unwrap()).is_err());
+    assert!(WaitCommand::new(&CommandParser::new("Wait()").unwrap()).is_err());
+    // invalid values
+    assert!(WaitCommand::new(&CommandParser::new("Wait(abc)").unwrap()).is_err());
+    assert!(WaitCommand::new(&CommandParser::new("Wait(-10)").unwrap()).is_err());
+    // valid value
+    let cmd = WaitCommand::new(&CommandParser::new("Wait(250)").unwrap()).unwrap();
+    assert_eq!(cmd.get_milliseconds(), 250);
+}
+
+#[test]
+fn check_checktext_errors() {
+    // invalid number of parameters
+    assert!(CheckTextCommand::new(&CommandParser::new("CheckText(1,2)").unwrap()).is_err());
+    assert!(CheckTextCommand::new(&CommandParser::new("CheckText(1,2,'a',3)").unwrap()).is_err());
+    // invalid position values (integers)
+    assert!(CheckTextCommand::new(&CommandParser::new("CheckText(x,2,'a')").unwrap()).is_err());
+    assert!(CheckTextCommand::new(&CommandParser::new("CheckText(1,y,'a')").unwrap()).is_err());
+    // empty text
+    assert!(CheckTextCommand::new(&CommandParser::new("CheckText(1,2,'')").unwrap()).is_err());
+    // valid command
+    let cmd = CheckTextCommand::new(&CommandParser::new("CheckText(1,2,'Some \"text\"')").unwrap()).unwrap();
+    assert_eq!(cmd.get_point(), Point::new(1, 2));
+    assert_eq!(cmd.get_text(), "Some \"text\"");
+}
+
+#[test]
+fn check_checktext_and_wait_in_script() {
+    let script = "
+        Paint.Enable(false)
+        Wait(5000)
+        CheckText(5,2,'╔═')
+        CheckText(7,3,'Hello')
+        CheckText(6,3,' Hello World ')
+        Wait(100)
+        CheckText(34,7,'╝')
+    ";
+    let mut a = App::debug(40, 10, script).build().unwrap();
+    let mut w = window!("Test,a:c,w:30,h:6");
+    w.add(label!("'Hello World',x:1,y:0,w:20"));
+    a.add_window(w);
+    a.run();
+}
+
+#[test]
+#[should_panic(expected = "Invalid text at (7,3): (expecting: 'Hallo' but found 'Hello')")]
+fn check_checktext_failure() {
+    let script = "
+        Paint.Enable(false)
+        CheckText(7,3,'Hallo')
+    ";
+    let mut a = App::debug(40, 10, script).build().unwrap();
+    let mut w = window!("Test,a:c,w:30,h:6");
+    w.add(label!("'Hello World',x:1,y:0,w:20"));
+    a.add_window(w);
+    a.run();
+}
+
+#[test]
+fn check_replay_events() {
+    let script = "
+        # a recorded script
+        Paint.Enable(false)
+        Wait(200)
+        Key.Pressed(Tab)
+        Resize(100,50)
+        CheckHash(0x1234)
+        Wait(400)
+        Mouse.Click(10,5,left)
+        Wait(100)
+    ";
+    let replay = Replay::new(script, 1.0).unwrap();
+    let events = replay.events();
+    // Key.Pressed, then Mouse.Click (move + button down + button up)
+    assert_eq!(events.len(), 4);
+    assert_eq!(events[0].0, Duration::from_millis(200));
+    assert!(matches!(events[0].1, SystemEvent::KeyPressed(_)));
+    assert_eq!(events[1].0, Duration::from_millis(400));
+    assert!(matches!(events[1].1, SystemEvent::MouseMove(_)));
+    assert_eq!(events[2].0, Duration::ZERO);
+    assert!(matches!(events[2].1, SystemEvent::MouseButtonDown(_)));
+    assert!(matches!(events[3].1, SystemEvent::MouseButtonUp(_)));
+
+    // accelerated speed
+    let replay = Replay::new(script, 4.0).unwrap();
+    assert_eq!(replay.events()[0].0, Duration::from_millis(50));
+    assert_eq!(replay.events()[1].0, Duration::from_millis(100));
+}
+
+#[test]
+fn check_replay_errors() {
+    assert!(Replay::new("Key.Pressed(Tab)", 0.0).is_err());
+    assert!(Replay::new("Key.Pressed(Tab)", -1.0).is_err());
+    assert!(Replay::new("Key.Pressed(Tab)", f32::NAN).is_err());
+    assert!(Replay::new("Key.Pressed(Tab)\nUnknownCommand(1)", 1.0).is_err());
+    assert!(App::debug(40, 10, "").replay("Key.Pressed(Tab)", 1.0).build().is_err());
+}
@@ -0,0 +1,29 @@
+use super::command_parser::{CommandParser, ParserError};
+
+pub(super) struct WaitCommand {
+    milliseconds: u32,
+}
+
+impl WaitCommand {
+    pub(super) fn new(parser: &CommandParser) -> Result<Self, ParserError> {
+        if parser.get_params_count() != 1 {
+            return Err(ParserError::new(
+                "Wait command must have one parameter (the number of milliseconds to wait) !",
+            ));
+        }
+        if let Some(value) = parser.get_i32(0) {
+            if value < 0 {
+                return Err(ParserError::new(
+                    "The number of milliseconds for the Wait command must be a positive value !",
+                ));
+            }
+            return Ok(Self { milliseconds: value as u32 });
+        }
+        Err(ParserError::new(
+            "Invalid numerical value for the number of milliseconds of the Wait command !",
+        ))
+    }
+    pub(super) fn get_milliseconds(&self) -> u32 {
+        self.milliseconds
+    }
+}
@@ -3,11 +3,14 @@ use crate::backend::Backend;
 use super::ncursesapi::lib::ncurses_wcwidth;
 use super::ncursesapi::externs::*;
 use crate::backend::ncurses::ncursesapi::input::Input;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc::Sender;
+use std::sync::Arc;
 
 use crate::graphics::*;
 use crate::system::Error;
 use crate::backend::SystemEventReader;
+use crate::backend::utils::job_control::listen_for_job_control;
 
 use super::ncursesapi;
 use copypasta::ClipboardContext;
@@ -20,6 +23,7 @@ use ncursesapi::constants::mmask_t;
 pub struct NcursesTerminal {
     size: Size,
     win: WINDOW,
+    suspended: Arc<AtomicBool>,
 }
 
 #[cfg(target_family = "unix")]
@@ -65,10 +69,19 @@ impl NcursesTerminal {
         let term = NcursesTerminal {
             size: Size::new(x as u32, y as u32),
             win,
+            suspended: Arc::new(AtomicBool::new(false)),
         };
 
+        // ncurses installs its own SIGTSTP handler (that does not know anything about the input thread)
+        if let Err(err) = listen_for_job_control(sender.clone()) {
+            return Err(Error::new(
+                crate::system::ErrorKind::InitializationFailure,
+                format!("Failed to setup SIGTSTP/SIGCONT handlers: {:?}", err),
+            ));
+        }
+
         // Start the event thread
-        Input::new().start(sender);
+        Input::new(term.suspended.clone()).start(sender);
 
         Ok(term)
     }
@@ -195,4 +208,23 @@ impl Backend for NcursesTerminal {
     fn on_close(&mut self) {
         println!("\x1b[?1000l\x1b[?1002l\x1b[?1003l\x1b[?1006l")
     }
+
+    fn suspend(&mut self) {
+        if self.suspended.swap(true, Ordering::AcqRel) {
+            return;
+        }
+        println!("\x1b[?1000l\x1b[?1002l\x1b[?1003l\x1b[?1006l");
+        ncursesapi::lib::ncurses_endwin();
+    }
+
+    fn resume(&mut self) {
+        // refresh restores the program mode (saved by endwin) and repaints the entire screen
+        ncursesapi::lib::ncurses_refresh();
+        println!("\x1b[?1000h\x1b[?1002h\x1b[?1003h\x1b[?1006h");
+        let mut x: i32 = 0;
+        let mut y: i32 = 0;
+        ncursesapi::lib::ncurses_getmaxyx(self.win, &mut y, &mut x);
+        self.size = Size::new(x as u32, y as u32);
+        self.suspended.store(false, Ordering::Release);
+    }
 }
@@ -15,6 +15,9 @@ use super::constants::*;
 use crate::graphics::*;
 use super::lib::*;
 use super::structs::*;  
+use crate::backend::utils::job_control::{is_suspend_key, wait_for_stdin};
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
 use std::time::Instant;
 
     
@@ -64,14 +67,16 @@ pub(crate) struct Input {
     last_event: Option<SystemEvent>,
     next_event: Option<SystemEvent>,
     diff: Instant,
+    suspended: Arc<AtomicBool>,
 }
 
 impl Input {
-    pub(crate) fn new() -> Self {
+    pub(crate) fn new(suspended: Arc<AtomicBool>) -> Self {
         Self {
             last_event: None,
             next_event: None,
             diff: Instant::now(),
+            suspended,
         }
     }
 
@@ -239,6 +244,9 @@ impl Input {
                 }
 
                 let mut key = get_key_struct(ch);
+                if is_suspend_key(key.key) {
+                    return Some(SystemEvent::Suspend);
+                }
                 if key.key.code == KeyCode::Backspace {
                     key.character = 8 as char;
                 }
@@ -258,6 +266,10 @@ impl SystemEventReader for Input {
         if let Some(e) = self.next_event.take() {
             return Some(e);
         }
+        // reading a character would force ncurses to leave the suspended state
+        if !wait_for_stdin(&self.suspended, 100) {
+            return None;
+        }
        if let Some(result) = self.read_event() {
             return Some(result);
        }
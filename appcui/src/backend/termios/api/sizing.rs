@@ -1,10 +1,11 @@
 // reference: https://man7.org/linux/man-pages/man2/TIOCGWINSZ.2const.html
 
+use std::io::Write;
 use std::sync::{Arc, Condvar, Mutex, OnceLock};
 
-use libc::{ioctl, sighandler_t, signal, SIGWINCH, SIG_ERR, STDOUT_FILENO, TIOCGWINSZ, TIOCSWINSZ};
+use libc::{ioctl, poll, pollfd, read, sighandler_t, signal, POLLIN, SIGWINCH, SIG_ERR, STDIN_FILENO, STDOUT_FILENO, TIOCGWINSZ, TIOCSWINSZ};
 
-use crate::prelude::Size;
+use crate::prelude::{Point, Size};
 
 pub struct ResizeNotification {
     pub mutex: Mutex<Size>,
@@ -78,3 +79,36 @@ pub(crate) fn set_terminal_size(size: &Size) -> Result<(), std::io::Error> {
 
     Ok(())
 }
+
+// Queries the cursor position using the `Device Status Report` sequence (ESC [ 6 n).
+// The terminal responds with ESC [ <row> ; <col> R (1-based values). This function must be
+// called while the terminal is in raw mode and before the input thread starts reading from stdin.
+pub(crate) fn get_cursor_position() -> Result<Point, std::io::Error> {
+    let mut stdout = std::io::stdout();
+    stdout.write_all(b"\x1b[6n")?;
+    stdout.flush()?;
+
+    let mut response = Vec::with_capacity(16);
+    let mut fds = pollfd { fd: STDIN_FILENO, events: POLLIN, revents: 0 };
+    while response.len() < 32 {
+        // wait at most 500ms for the terminal to respond
+        if unsafe { poll(&mut fds, 1, 500) } <= 0 {
+            return Err(std::io::Error::new(std::io::ErrorKind::TimedOut, "terminal did not report the cursor position"));
+        }
+        let mut byte = 0u8;
+        if unsafe { read(STDIN_FILENO, &mut byte as *mut u8 as *mut libc::c_void, 1) } != 1 {
+            return Err(std::io::Error::last_os_error());
+        }
+        if byte == b'R' {
+            break;
+        }
+        response.push(byte);
+    }
+    let text = String::from_utf8_lossy(&response);
+    let values = text.trim_start_matches('\x1b').trim_start_matches('[');
+    let mut parts = values.split(';').map(|v| v.parse::<i32>());
+    match (parts.next(), parts.next()) {
+        (Some(Ok(row)), Some(Ok(col))) => Ok(Point::new(col - 1, row - 1)),
+        _ => Err(std::io::Error::new(std::io::ErrorKind::InvalidData, "invalid cursor position report")),
+    }
+}
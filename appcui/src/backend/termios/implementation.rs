@@ -3,15 +3,23 @@
 
 use copypasta::ClipboardContext;
 use copypasta::ClipboardProvider;
-use std::{io::Write, sync::mpsc::Sender};
+use std::{
+    io::Write,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc::Sender,
+        Arc,
+    },
+};
 
 use super::{
     super::SystemEvent,
-    api::sizing::{get_resize_notification, get_terminal_size, set_terminal_size},
+    api::sizing::{get_cursor_position, get_resize_notification, get_terminal_size, set_terminal_size},
     input::Input,
     size_reader::SizeReader,
 };
-use crate::backend::utils::{AnsiFlags, AnsiFormatter};
+use crate::backend::utils::job_control::listen_for_job_control;
+use crate::backend::utils::{AnsiFlags, AnsiFormatter, InlineViewport};
 use crate::{
     backend::{termios::api::sizing::listen_for_resizes, Backend, SystemEventReader},
     graphics::*,
@@ -28,6 +36,8 @@ pub struct TermiosTerminal {
     size: Size,
     orig_termios: Termios,
     ansi_buffer: AnsiFormatter,
+    viewport: Option<Arc<InlineViewport>>,
+    suspended: Arc<AtomicBool>,
 }
 
 impl TermiosTerminal {
@@ -50,6 +60,8 @@ impl TermiosTerminal {
                     AnsiFlags::None
                 },
             ),
+            viewport: None,
+            suspended: Arc::new(AtomicBool::new(false)),
         };
 
         if let Err(err) = listen_for_resizes() {
@@ -59,6 +71,13 @@ impl TermiosTerminal {
             ));
         }
 
+        if let Err(err) = listen_for_job_control(sender.clone()) {
+            return Err(Error::new(
+                crate::system::ErrorKind::InitializationFailure,
+                format!("Failed to setup SIGTSTP/SIGCONT handlers: {:?}", err),
+            ));
+        }
+
         if let Some(sz) = builder.size {
             t.size = sz;
         }
@@ -78,26 +97,60 @@ impl TermiosTerminal {
         }
 
         t.ansi_buffer.clear();
+        if let Some(height) = builder.inline {
+            t.viewport = Some(Arc::new(InlineViewport::new(height)));
+            t.reserve_inline_viewport(t.size);
+        }
         t.ansi_buffer.enable_mouse_events();
         let _ = std::io::stdout().write_all(t.ansi_buffer.text().as_bytes());
         let _ = std::io::stdout().flush();
 
-        Input::new().start(sender.clone());
-        SizeReader::new(get_resize_notification().clone()).start(sender);
+        Input::new(t.viewport.clone(), t.suspended.clone()).start(sender.clone());
+        SizeReader::new(get_resize_notification().clone(), t.viewport.clone()).start(sender);
         Ok(Box::new(t))
     }
+
+    // writes (in the ansi buffer) the new lines needed to reserve the inline viewport below the cursor
+    fn reserve_inline_viewport(&mut self, terminal_size: Size) {
+        if let Some(viewport) = &self.viewport {
+            // if the terminal does not report the cursor position, we assume that the cursor is on the last line
+            let cursor = get_cursor_position().unwrap_or(Point::new(0, terminal_size.height as i32 - 1));
+            for _ in 0..viewport.reserve(terminal_size, cursor) {
+                self.ansi_buffer.write_string("\r\n");
+            }
+            self.size = viewport.size(terminal_size.width);
+        }
+    }
+
+    // moves the cursor on the line right after the inline viewport (the last frame remains in the scrollback)
+    fn leave_inline_viewport(&mut self) {
+        if let Some(viewport) = &self.viewport {
+            self.ansi_buffer.reset_color();
+            self.ansi_buffer.set_cursor_position(0, viewport.last_line() as i32);
+            self.ansi_buffer.write_string("\r\n");
+        }
+    }
 }
 
 impl Backend for TermiosTerminal {
     fn update_screen(&mut self, surface: &Surface) {
         //self.clear();
-        self.ansi_buffer.render(surface, Point::new(0, 0));
+        let origin = self.viewport.as_ref().map(|v| v.origin() as i32).unwrap_or(0);
+        self.ansi_buffer.render(surface, Point::new(0, origin));
         let _ = std::io::stdout().write_all(self.ansi_buffer.text().as_bytes());
         let _ = std::io::stdout().flush();
     }
 
     fn on_resize(&mut self, new_size: Size) {
         self.size = new_size;
+        if let Some(viewport) = &self.viewport {
+            // the terminal might have re-flowed the lines of the viewport --> clear everything from the viewport origin down
+            self.ansi_buffer.clear();
+            self.ansi_buffer.set_cursor_position(0, viewport.origin() as i32);
+            self.ansi_buffer.write_string("\x1b[J");
+            let _ = std::io::stdout().write_all(self.ansi_buffer.text().as_bytes());
+            let _ = std::io::stdout().flush();
+        }
     }
 
     fn size(&self) -> Size {
@@ -129,9 +182,50 @@ impl Backend for TermiosTerminal {
 
     fn on_close(&mut self) {
         self.ansi_buffer.clear();
+        if self.viewport.is_some() {
+            self.leave_inline_viewport();
+            self.ansi_buffer.show_cursor();
+        }
         self.ansi_buffer.disable_mouse_events();
         let _ = std::io::stdout().write_all(self.ansi_buffer.text().as_bytes());
         let _ = std::io::stdout().flush();
         self.orig_termios.restore();
     }
-}
\ No newline at end of file
+
+    fn suspend(&mut self) {
+        if self.suspended.swap(true, Ordering::AcqRel) {
+            return;
+        }
+        self.ansi_buffer.clear();
+        if self.viewport.is_some() {
+            self.leave_inline_viewport();
+        } else {
+            self.ansi_buffer.reset_color();
+            self.ansi_buffer.write_string("\x1b[2J\x1b[H");
+        }
+        self.ansi_buffer.show_cursor();
+        self.ansi_buffer.disable_mouse_events();
+        let _ = std::io::stdout().write_all(self.ansi_buffer.text().as_bytes());
+        let _ = std::io::stdout().flush();
+        self.orig_termios.restore();
+    }
+
+    fn resume(&mut self) {
+        // the original state of the terminal is already stored in `orig_termios`
+        let _ = Termios::enable_raw_mode();
+        let terminal_size = get_terminal_size().unwrap_or(self.size);
+        self.ansi_buffer.clear();
+        if self.suspended.load(Ordering::Acquire) {
+            // the input thread is still paused at this point, so the cursor position can be read safely
+            if self.viewport.is_some() {
+                self.reserve_inline_viewport(terminal_size);
+            } else {
+                self.size = terminal_size;
+            }
+        }
+        self.ansi_buffer.enable_mouse_events();
+        let _ = std::io::stdout().write_all(self.ansi_buffer.text().as_bytes());
+        let _ = std::io::stdout().flush();
+        self.suspended.store(false, Ordering::Release);
+    }
+}
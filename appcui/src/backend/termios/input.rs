@@ -6,21 +6,41 @@ use crate::system::MouseButtonDownEvent;
 use crate::system::MouseButtonUpEvent;
 use crate::system::MouseMoveEvent;
 use crate::system::SystemEvent;
+use crate::backend::utils::InlineViewport;
 use crate::backend::SystemEventReader;
+use crate::backend::utils::job_control::{is_suspend_key, wait_for_stdin};
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
 
 use super::api::io::AnsiKeyCode;
 use super::api::io::TermiosReader;
 
-pub(super) struct Input {}
+pub(super) struct Input {
+    viewport: Option<Arc<InlineViewport>>,
+    suspended: Arc<AtomicBool>,
+}
 
 impl Input {
-    pub(super) fn new() -> Self {
-        Self {}
+    pub(super) fn new(viewport: Option<Arc<InlineViewport>>, suspended: Arc<AtomicBool>) -> Self {
+        Self { viewport, suspended }
+    }
+    #[inline(always)]
+    fn translate_y(&self, y: i32) -> i32 {
+        if let Some(viewport) = &self.viewport {
+            viewport.translate_y(y)
+        } else {
+            y
+        }
     }
 }
 
 impl SystemEventReader for Input {
     fn read(&mut self) -> Option<crate::system::SystemEvent> {
+        // stdin must not be read while the application is suspended
+        #[cfg(target_family = "unix")]
+        if !wait_for_stdin(&self.suspended, 100) {
+            return None;
+        }
         #[cfg(target_family = "unix")]
         match TermiosReader::read_key() {
             Ok(ansi_key) => {
@@ -29,7 +49,7 @@ impl SystemEventReader for Input {
                         MouseButton::None => {
                             return Some(SystemEvent::MouseButtonUp(MouseButtonUpEvent {
                                 x: ev.x.into(),
-                                y: ev.y.into(),
+                                y: self.translate_y(ev.y.into()),
                                 button: MouseButton::None,
                             }))
                         }
@@ -37,7 +57,7 @@ impl SystemEventReader for Input {
                             return Some(SystemEvent::MouseButtonDown(MouseButtonDownEvent {
                                 button: other,
                                 x: ev.x.into(),
-                                y: ev.y.into(),
+                                y: self.translate_y(ev.y.into()),
                             }))
                         }
                     }
@@ -45,7 +65,7 @@ impl SystemEventReader for Input {
                 if let AnsiKeyCode::MouseMove(ev) = ansi_key.code() {
                     return Some(SystemEvent::MouseMove(MouseMoveEvent {
                         x: ev.x.into(),
-                        y: ev.y.into(),
+                        y: self.translate_y(ev.y.into()),
                         button: ev.button,
                     }));
                 }
@@ -58,6 +78,9 @@ impl SystemEventReader for Input {
 
                 // We convert our ANSI key to the system's `Key` known key type
                 let key: Key = ansi_key.into();
+                if is_suspend_key(key) {
+                    return Some(SystemEvent::Suspend);
+                }
 
                 match key.code {
                     KeyCode::F1
@@ -1,12 +1,19 @@
 use std::sync::Arc;
 
-use crate::{backend::SystemEventReader, prelude::Size, system::SystemEvent};
+use crate::{
+    backend::{utils::InlineViewport, SystemEventReader},
+    prelude::Size,
+    system::SystemEvent,
+};
 
 use super::api::sizing::ResizeNotification;
 
 macro_rules! check_guard {
-    ($guard: ident) => {
+    ($self: ident, $guard: ident) => {
         if $guard.width > 0 {
+            if let Some(viewport) = &$self.viewport {
+                return Some(SystemEvent::Resize(viewport.on_terminal_resize(*$guard)));
+            }
             return Some(SystemEvent::Resize(*$guard));
         }
         *$guard = Size::default();
@@ -15,11 +22,12 @@ macro_rules! check_guard {
 
 pub(super) struct SizeReader {
     a: Arc<ResizeNotification>,
+    viewport: Option<Arc<InlineViewport>>,
 }
 
 impl SizeReader {
-    pub(super) fn new(a: Arc<ResizeNotification>) -> Self {
-        Self { a }
+    pub(super) fn new(a: Arc<ResizeNotification>, viewport: Option<Arc<InlineViewport>>) -> Self {
+        Self { a, viewport }
     }
 }
 
@@ -27,11 +35,11 @@ impl SystemEventReader for SizeReader {
     fn read(&mut self) -> Option<crate::system::SystemEvent> {
         let mut guard = self.a.mutex.lock().unwrap();
 
-        check_guard!(guard);
+        check_guard!(self, guard);
 
         guard = self.a.cond_var.wait(guard).unwrap();
 
-        check_guard!(guard);
+        check_guard!(self, guard);
 
         None
     }
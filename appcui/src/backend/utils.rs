@@ -1,4 +1,7 @@
 mod ansi_formatter;
+mod inline_viewport;
+#[cfg(target_family = "unix")]
+pub(crate) mod job_control;
 #[cfg(target_os = "windows")]
 pub(crate) mod win32;
 #[cfg(test)]
@@ -6,3 +9,4 @@ mod tests;
 
 pub(crate) use ansi_formatter::AnsiFormatter;
 pub(crate) use ansi_formatter::AnsiFlags;
+pub(crate) use inline_viewport::InlineViewport;
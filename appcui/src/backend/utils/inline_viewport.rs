@@ -0,0 +1,80 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use crate::graphics::{Point, Size};
+use crate::system::InlineHeight;
+
+/// Keeps track of the terminal lines used by an application that runs in inline mode.
+/// The object is shared (via an `Arc`) between the backend and its input threads, as mouse
+/// coordinates and resize events have to be translated into viewport coordinates.
+pub(crate) struct InlineViewport {
+    height: InlineHeight,
+    origin: AtomicU32,
+    rows: AtomicU32,
+}
+
+impl InlineViewport {
+    pub(crate) fn new(height: InlineHeight) -> Self {
+        Self {
+            height,
+            origin: AtomicU32::new(0),
+            rows: AtomicU32::new(1),
+        }
+    }
+    /// Computes the viewport position based on the current terminal size and cursor position and
+    /// returns the number of new lines that need to be written (starting from the cursor position) to
+    /// reserve the viewport. The viewport always starts at the beginning of a line (if the cursor is not
+    /// on the first column, the viewport will start on the next line).
+    pub(crate) fn reserve(&self, terminal_size: Size, cursor: Point) -> u32 {
+        let h = terminal_size.height.max(1);
+        let cursor_y = (cursor.y.max(0) as u32).min(h - 1);
+        let start = if cursor.x > 0 { cursor_y + 1 } else { cursor_y };
+        let rows = match self.height {
+            InlineHeight::Fixed(value) => value.clamp(1, h),
+            InlineHeight::Auto => h.saturating_sub(start).max(h / 2).clamp(1, h),
+        };
+        // the lines are reserved by writing new lines (the terminal will scroll if the
+        // viewport does not fit below the cursor)
+        let new_lines = if cursor.x > 0 { rows } else { rows - 1 };
+        let last_line = (cursor_y + new_lines).min(h - 1);
+        self.origin.store(last_line + 1 - rows, Ordering::Relaxed);
+        self.rows.store(rows, Ordering::Relaxed);
+        new_lines
+    }
+    /// The first terminal line of the viewport
+    #[inline(always)]
+    pub(crate) fn origin(&self) -> u32 {
+        self.origin.load(Ordering::Relaxed)
+    }
+    /// The last terminal line of the viewport
+    #[inline(always)]
+    pub(crate) fn last_line(&self) -> u32 {
+        self.origin() + self.rows() - 1
+    }
+    #[inline(always)]
+    pub(crate) fn rows(&self) -> u32 {
+        self.rows.load(Ordering::Relaxed)
+    }
+    #[inline(always)]
+    pub(crate) fn size(&self, terminal_width: u32) -> Size {
+        Size::new(terminal_width, self.rows())
+    }
+    /// Converts a terminal line into a viewport line
+    #[inline(always)]
+    pub(crate) fn translate_y(&self, y: i32) -> i32 {
+        y - self.origin() as i32
+    }
+    /// Updates the viewport after the terminal has been resized and returns the new size of the viewport.
+    /// The viewport keeps its height (if it still fits the terminal) and is moved up if it exceeds the
+    /// bottom of the terminal.
+    pub(crate) fn on_terminal_resize(&self, terminal_size: Size) -> Size {
+        let h = terminal_size.height.max(1);
+        let rows = match self.height {
+            InlineHeight::Fixed(value) => value.clamp(1, h),
+            InlineHeight::Auto => self.rows().clamp(1, h),
+        };
+        let origin = self.origin().min(h - rows);
+        self.rows.store(rows, Ordering::Relaxed);
+        self.origin.store(origin, Ordering::Relaxed);
+        Size::new(terminal_size.width, rows)
+    }
+}
@@ -0,0 +1,121 @@
+//! Job control support (SIGTSTP / SIGCONT) for unix based backends.
+//!
+//! When the application receives a SIGTSTP signal (e.g. `kill -TSTP <pid>`) or the user presses `Ctrl+Z`
+//! (the terminal is in raw mode, so the input readers report it as a suspend request) the terminal is restored
+//! to its original state and the process is stopped. Once the process is continued (SIGCONT) the
+//! terminal is set back into raw mode and the entire screen is repainted.
+
+use std::sync::atomic::{AtomicBool, AtomicI32, Ordering};
+use std::sync::OnceLock;
+
+use libc::{c_void, poll, pollfd, raise, sighandler_t, signal, POLLIN, SIGCONT, SIGSTOP, SIGTSTP, SIG_ERR, STDIN_FILENO};
+
+use crate::backend::SystemEventReader;
+use crate::input::{Key, KeyCode, KeyModifier};
+use crate::system::SystemEvent;
+
+const SUSPEND: u8 = b'S';
+const RESUME: u8 = b'R';
+
+// The signal handlers only write one byte into this pipe (`write` is async-signal-safe) and the
+// job control thread converts that byte into a system event.
+static PIPE: OnceLock<Result<[libc::c_int; 2], i32>> = OnceLock::new();
+static PIPE_WRITE_FD: AtomicI32 = AtomicI32::new(-1);
+
+fn notify(code: u8) {
+    let fd = PIPE_WRITE_FD.load(Ordering::Relaxed);
+    if fd >= 0 {
+        unsafe {
+            libc::write(fd, &code as *const u8 as *const c_void, 1);
+        }
+    }
+}
+
+extern "C" fn handle_sigtstp(_: libc::c_int) {
+    notify(SUSPEND);
+}
+
+extern "C" fn handle_sigcont(_: libc::c_int) {
+    notify(RESUME);
+}
+
+/// Installs the SIGTSTP and SIGCONT handlers and starts a thread that converts these
+/// signals into `SystemEvent::Suspend` and `SystemEvent::Resume` events.
+pub(crate) fn listen_for_job_control(sender: std::sync::mpsc::Sender<SystemEvent>) -> Result<(), std::io::Error> {
+    let fds = *PIPE.get_or_init(|| {
+        let mut fds: [libc::c_int; 2] = [-1; 2];
+        if unsafe { libc::pipe(fds.as_mut_ptr()) } == 0 {
+            Ok(fds)
+        } else {
+            Err(std::io::Error::last_os_error().raw_os_error().unwrap_or(0))
+        }
+    });
+    let fds = fds.map_err(std::io::Error::from_raw_os_error)?;
+    PIPE_WRITE_FD.store(fds[1], Ordering::Relaxed);
+    unsafe {
+        if SIG_ERR == signal(SIGTSTP, handle_sigtstp as *const () as sighandler_t) {
+            return Err(std::io::Error::last_os_error());
+        }
+        if SIG_ERR == signal(SIGCONT, handle_sigcont as *const () as sighandler_t) {
+            return Err(std::io::Error::last_os_error());
+        }
+    }
+    JobControlReader { fd: fds[0] }.start(sender);
+    Ok(())
+}
+
+/// Stops the current process (the same way the default SIGTSTP handler does). The function returns
+/// once the process has been continued.
+pub(crate) fn stop_process() {
+    unsafe {
+        raise(SIGSTOP);
+    }
+}
+
+/// Returns `true` if the key is the one used to suspend the application (`Ctrl+Z`). As the terminal
+/// is in raw mode, `Ctrl+Z` does not raise SIGTSTP and the input readers must translate it into a
+/// `SystemEvent::Suspend` event themselves.
+pub(crate) fn is_suspend_key(key: Key) -> bool {
+    key == Key::new(KeyCode::Z, KeyModifier::Ctrl)
+}
+
+/// Waits (at most `timeout_ms` milliseconds) for data to be available on the standard input.
+/// Input threads use this method so that they do not consume any input while the application
+/// is suspended (the `suspended` flag is set).
+pub(crate) fn wait_for_stdin(suspended: &AtomicBool, timeout_ms: i32) -> bool {
+    if suspended.load(Ordering::Acquire) {
+        std::thread::sleep(std::time::Duration::from_millis(timeout_ms as u64));
+        return false;
+    }
+    let mut fds = pollfd {
+        fd: STDIN_FILENO,
+        events: POLLIN,
+        revents: 0,
+    };
+    let has_data = unsafe { poll(&mut fds, 1, timeout_ms) } > 0;
+    // the application might have been suspended while waiting
+    has_data && !suspended.load(Ordering::Acquire)
+}
+
+struct JobControlReader {
+    fd: libc::c_int,
+}
+
+impl SystemEventReader for JobControlReader {
+    fn read(&mut self) -> Option<SystemEvent> {
+        let mut code = 0u8;
+        let result = unsafe { libc::read(self.fd, &mut code as *mut u8 as *mut c_void, 1) };
+        if result != 1 {
+            // interrupted by a signal (EINTR) - the byte written by the handler will be read next time
+            if std::io::Error::last_os_error().kind() != std::io::ErrorKind::Interrupted {
+                std::thread::sleep(std::time::Duration::from_millis(100));
+            }
+            return None;
+        }
+        match code {
+            SUSPEND => Some(SystemEvent::Suspend),
+            RESUME => Some(SystemEvent::Resume),
+            _ => None,
+        }
+    }
+}
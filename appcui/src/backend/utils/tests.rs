@@ -1,4 +1,4 @@
-use crate::backend::utils::{AnsiFlags, AnsiFormatter};
+use crate::backend::utils::{AnsiFlags, AnsiFormatter, InlineViewport};
 use crate::graphics::Color;
 use crate::prelude::{CharFlags, Point, Surface};
 use crate::prelude::*;
@@ -190,4 +190,56 @@ fn check_ansi_renderer_with_cursor() {
     s.set_cursor(2, 0);
     a.render(&s, Point::ORIGIN);
     assert_eq!(a.text(),"\u{1b}[0m\u{1b}[?25l\u{1b}[1;1H\u{1b}[38;2;255;0;0m\u{1b}[48;2;0;0;255mHe\u{1b}[38;2;255;0;255m\u{1b}[48;2;0;128;0m\u{1b}[1mll\u{1b}[38;2;255;0;0m\u{1b}[48;2;0;0;255m\u{1b}[22mo\u{1b}[1;3H\u{1b}[?25h");
-}
\ No newline at end of file
+}
+#[test]
+fn check_inline_viewport_fixed() {
+    // enough space below the cursor
+    let v = InlineViewport::new(InlineHeight::Fixed(5));
+    assert_eq!(v.reserve(Size::new(80, 30), Point::new(0, 10)), 4);
+    assert_eq!(v.origin(), 10);
+    assert_eq!(v.rows(), 5);
+    assert_eq!(v.last_line(), 14);
+    assert_eq!(v.size(80), Size::new(80, 5));
+    assert_eq!(v.translate_y(12), 2);
+    // cursor on the last line --> terminal needs to scroll
+    assert_eq!(v.reserve(Size::new(80, 30), Point::new(0, 29)), 4);
+    assert_eq!(v.origin(), 25);
+    assert_eq!(v.last_line(), 29);
+    // cursor not on the first column --> viewport starts on the next line
+    assert_eq!(v.reserve(Size::new(80, 30), Point::new(7, 10)), 5);
+    assert_eq!(v.origin(), 11);
+    // height bigger than the terminal
+    let v = InlineViewport::new(InlineHeight::Fixed(100));
+    v.reserve(Size::new(80, 30), Point::new(0, 10));
+    assert_eq!(v.origin(), 0);
+    assert_eq!(v.rows(), 30);
+}
+
+#[test]
+fn check_inline_viewport_auto() {
+    let v = InlineViewport::new(InlineHeight::Auto);
+    v.reserve(Size::new(80, 30), Point::new(0, 5));
+    assert_eq!(v.origin(), 5);
+    assert_eq!(v.rows(), 25);
+    // less than half of the terminal is available
+    v.reserve(Size::new(80, 30), Point::new(0, 29));
+    assert_eq!(v.origin(), 15);
+    assert_eq!(v.rows(), 15);
+    assert_eq!(v.last_line(), 29);
+}
+
+#[test]
+fn check_inline_viewport_resize() {
+    let v = InlineViewport::new(InlineHeight::Fixed(10));
+    v.reserve(Size::new(80, 30), Point::new(0, 15));
+    assert_eq!(v.origin(), 15);
+    // terminal becomes smaller --> viewport moves up
+    assert_eq!(v.on_terminal_resize(Size::new(60, 20)), Size::new(60, 10));
+    assert_eq!(v.origin(), 10);
+    // terminal smaller than the viewport
+    assert_eq!(v.on_terminal_resize(Size::new(60, 6)), Size::new(60, 6));
+    assert_eq!(v.origin(), 0);
+    // back to a larger terminal --> the fixed height is restored
+    assert_eq!(v.on_terminal_resize(Size::new(80, 30)), Size::new(80, 10));
+    assert_eq!(v.origin(), 0);
+}
@@ -20,10 +20,10 @@ pub enum MouseButton {
 impl MouseButton {
     pub(crate) fn name(&self)->&'static str {
         match self {
-            MouseButton::None => "None",
-            MouseButton::Left => "Left",
-            MouseButton::Right => "Right",
-            MouseButton::Center => "Center",
+            MouseButton::None => "none",
+            MouseButton::Left => "left",
+            MouseButton::Right => "right",
+            MouseButton::Center => "center",
         }
     }
 }
\ No newline at end of file
@@ -14,4 +14,15 @@ pub enum MouseWheelDirection {
     Right,
     Up,
     Down
-}
\ No newline at end of file
+}
+#[cfg(feature = "EVENT_RECORDER")]
+impl MouseWheelDirection {
+    pub(crate) fn name(&self) -> &'static str {
+        match self {
+            MouseWheelDirection::Left => "left",
+            MouseWheelDirection::Right => "right",
+            MouseWheelDirection::Up => "up",
+            MouseWheelDirection::Down => "down",
+        }
+    }
+}
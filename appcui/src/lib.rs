@@ -7,22 +7,36 @@
 //! * CheckBox
 //! * ComboBox
 //! * Canvas
+//! * Chart
 //! * DatePicker
+//! * DateRangePicker
+//! * DateTimePicker
 //! * DropDownList
+//! * Grid
+//! * HexView
 //! * HLine
 //! * HSplitter
 //! * ImageViewer
 //! * Label
 //! * ListBox
+//! * LogView
 //! * Menu
 //! * Markdown
+//! * MasterDetail
 //! * Panel
 //! * ProgressBar
 //! * RadioButton
+//! * ScrollViewer
+//! * Slider
+//! * StackPanel
 //! * Tab
+//! * Terminal
 //! * TextBox
+//! * TimePicker
 //! * VLine
 //! * Window
+//! * Wizard
+//! * WrapPanel
 //!   and many more.
 
 pub mod dialogs;
@@ -30,6 +44,7 @@ pub mod graphics;
 pub mod input;
 pub mod prelude;
 pub mod system;
+pub mod testing;
 pub mod backend;
 pub mod ui;
 mod utils;
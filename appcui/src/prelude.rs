@@ -18,6 +18,7 @@ pub use super::ui::accordion::events::AccordionEvents;
 pub use super::ui::button;
 pub use super::ui::button::events::ButtonEvents;
 pub use super::ui::canvas;
+pub use super::ui::chart;
 pub use super::ui::checkbox;
 pub use super::ui::checkbox::events::CheckBoxEvents;
 pub use super::ui::colorpicker;
@@ -27,16 +28,22 @@ pub use super::ui::charpicker::events::CharPickerEvents;
 pub use super::ui::combobox;
 pub use super::ui::combobox::events::ComboBoxEvents;
 pub use super::ui::command_bar::events::GenericCommandBarEvents;
+pub use super::ui::command_palette;
 pub use super::ui::common::traits::*;
 pub use super::ui::components::*;
 pub use super::ui::datepicker;
 pub use super::ui::datepicker::events::DatePickerEvents;
+pub use super::ui::datepicker::events::DateRangePickerEvents;
+pub use super::ui::datepicker::events::DateTimePickerEvents;
+pub use super::ui::datepicker::events::TimePickerEvents;
 pub use super::ui::desktop::events::DesktopEvents;
 pub use super::ui::dropdownlist;
 pub use super::ui::dropdownlist::events::GenericDropDownListEvents;
 pub use super::ui::dropdownlist::DropDownListType;
 pub use super::ui::graphview;
 pub use super::ui::graphview::events::GenericGraphViewEvents;
+pub use super::ui::grid;
+pub use super::ui::hexview;
 pub use super::ui::hsplitter;
 pub use super::ui::imageviewer;
 pub use super::ui::keyselector;
@@ -46,8 +53,10 @@ pub use super::ui::listbox::events::ListBoxEvents;
 pub use super::ui::listview;
 pub use super::ui::listview::events::GenericListViewEvents;
 pub use super::ui::listview::ListItem;
+pub use super::ui::logview;
 pub use super::ui::markdown;
 pub use super::ui::markdown::events::MarkdownEvents;
+pub use super::ui::masterdetail;
 pub use super::ui::menu::events::GenericMenuEvents;
 pub use super::ui::menu::Menu;
 pub use super::ui::appbar;
@@ -63,24 +72,36 @@ pub use super::ui::pathfinder::events::PathFinderEvents;
 pub use super::ui::progressbar;
 pub use super::ui::radiobox;
 pub use super::ui::radiobox::events::RadioBoxEvents;
+pub use super::ui::scrollviewer;
 pub use super::ui::selector;
 pub use super::ui::selector::events::GenericSelectorEvents;
 pub use super::ui::selector::EnumSelector;
+pub use super::ui::slider;
+pub use super::ui::slider::events::GenericRangeSliderEvents;
+pub use super::ui::slider::events::GenericSliderEvents;
+pub use super::ui::stackpanel;
 pub use super::ui::tab;
 pub use super::ui::tab::events::TabEvents;
+pub use super::ui::terminal;
+pub use super::ui::terminal::events::TerminalEvents;
 pub use super::ui::textfield;
 pub use super::ui::textfield::events::TextFieldEvents;
 pub use super::ui::threestatebox;
 pub use super::ui::threestatebox::events::ThreeStateBoxEvents;
+pub use super::ui::toast;
+pub use super::ui::toast::events::ToastEvents;
 pub use super::ui::togglebutton;
 pub use super::ui::togglebutton::events::ToggleButtonEvents;
 pub use super::ui::treeview;
 pub use super::ui::treeview::events::GenericTreeViewEvents;
 pub use super::ui::vsplitter;
+pub use super::ui::wrappanel;
 pub use super::ui::window::events::ModalWindowMethods;
 pub use super::ui::window::events::ToolBarEvents;
 pub use super::ui::window::events::WindowEvents;
 pub use super::ui::window::toolbar;
+pub use super::ui::wizard;
+pub use super::ui::wizard::events::WizardEvents;
 pub use super::ui::*;
 pub use appcui_proc_macro::*;
 
@@ -76,9 +76,11 @@ mod error;
 #[cfg(feature = "EVENT_RECORDER")]
 mod event_recorder;
 mod handle;
+mod inline_height;
 mod menu_handle_manager;
 mod runtime_manager;
 mod runtime_manager_traits;
+mod session_state;
 #[cfg(test)]
 mod tests;
 mod theme;
@@ -102,10 +104,13 @@ pub use self::builder::Builder;
 pub use self::clipboard::Clipboard;
 pub use self::error::Error;
 pub use self::error::ErrorKind;
+pub use self::inline_height::InlineHeight;
 pub(crate) use self::runtime_manager::RuntimeManager;
 pub(crate) use self::runtime_manager_traits::LayoutMethods;
 pub(crate) use self::runtime_manager_traits::PaintMethods;
 pub(crate) use self::runtime_manager_traits::TimerMethods;
+pub use self::session_state::SessionState;
+pub(crate) use self::session_state::Session;
 pub use self::timer::Timer;
 pub(crate) use self::tooltip::ToolTip;
 
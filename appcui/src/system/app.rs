@@ -5,6 +5,7 @@ use super::Error;
 use super::ErrorKind;
 use super::Handle;
 use super::RuntimeManager;
+use super::Session;
 use super::Theme;
 use super::ThemeMethods;
 use crate::graphics::Size;
@@ -83,9 +84,12 @@ impl App {
     ///
     /// **System events**
     /// * `Resize(width,height)` simulates a resize of the virtual terminal to the size represented by `width` and `height` parameters
+    /// * `Wait(ms)` a pause between two events (recorded by the **EVENT_RECORDER** feature). It is ignored by the debug backend.
     ///
     /// **Validation commands**
     /// * `CheckHash(hash)` checks if the hash computer over the current virtual screen is as expected. If not it will panic. This is useful for unit testing.
+    /// * `CheckText(x,y,text)` checks if a text is written on the virtual screen starting from position (x,y). If not it will panic.
+    /// * `CheckSnapshot(path)` compares the current virtual screen with a golden file (see the [`testing`](crate::testing) module). If they differ it will panic with a cell-level diff. If the `APPCUI_UPDATE_SNAPSHOTS` environment variable is set, the golden file is (re)created instead.
     pub fn debug(width: u16, height: u16, script: &str) -> crate::system::Builder {
         let mut builder = crate::system::Builder::new();
         builder.size = Some(Size::new(width as u32, height as u32));
@@ -103,6 +107,8 @@ impl App {
         }
         // must pe self so that after a run a second call will not be possible
         RuntimeManager::get().run();
+        // persist the session state (if a session file was provided)
+        RuntimeManager::get().save_session_file();
         // close the backend
         RuntimeManager::get().backend_mut().on_close();
         // clear the mutex from open_save_dialog to clear the last path
@@ -136,6 +142,51 @@ impl App {
         RuntimeManager::get().set_theme(theme);
     }
 
+    /// Saves the session state of the application in a file. The session state contains the position and size of every
+    /// window, the dock arrangement, the focused control and the key/value pairs added by the desktop, the windows and
+    /// the controls that implement the `OnSessionState` trait. The file uses a simple text format (similar to an INI file).
+    ///
+    /// Returns an error (`ErrorKind::InvalidParameter`) if the file can not be written.
+    pub fn save_session(path: &str) -> Result<(), Error> {
+        if !App::is_created() {
+            panic!("App::save_session can only be called after the App has been created !");
+        }
+        Session::save(path)
+    }
+
+    /// Loads the session state of the application from a file (created via `App::save_session`) and applies it to the
+    /// desktop and to the existing windows (windows are identified by their tag or, if they don't have one, by their
+    /// title and the controls of a window are identified by their position in the window).
+    ///
+    /// Returns an error (`ErrorKind::InvalidParameter`) if the file can not be read or its content is not valid.
+    pub fn load_session(path: &str) -> Result<(), Error> {
+        if !App::is_created() {
+            panic!("App::load_session can only be called after the App has been created !");
+        }
+        Session::load(path)
+    }
+
+    /// Temporarily gives the terminal back to the user (or to another process) and executes the provided closure.
+    /// Before the closure is executed, the terminal is restored to its original state (cooked mode, main screen).
+    /// Once the closure ends, the terminal is set back into raw mode and the entire application is repainted.
+    ///
+    /// This method is usually used to run an external program (such as an editor or a shell) from an event handler:
+    /// ```rust,no_run
+    /// use appcui::prelude::*;
+    ///
+    /// App::suspend(|| {
+    ///     let _ = std::process::Command::new("vim").status();
+    /// });
+    /// ```
+    ///
+    /// For backends that do not support this feature (e.g. the debug backend) the closure is executed directly.
+    pub fn suspend<F: FnOnce()>(f: F) {
+        if !App::is_created() {
+            panic!("App::suspend can only be called after the App has been created !");
+        }
+        RuntimeManager::get().suspend(f);
+    }
+
     pub(crate) fn drop_app() {
         if APP_CREATED_MUTEX.is_poisoned() {
             APP_CREATED_MUTEX.clear_poison();
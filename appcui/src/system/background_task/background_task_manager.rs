@@ -54,6 +54,9 @@ impl BackgroundTaskManager {
         }
         self.tasks[index].as_ref().map(|interface| interface.receiver_control_handle())
     }
+    pub(crate) fn running_tasks(&self) -> usize {
+        self.tasks.iter().filter(|t| t.is_some()).count()
+    }
     pub(crate) fn remove_task(&mut self, handle: Handle<()>) {
         let index = handle.index();
         if index < self.tasks.len() {
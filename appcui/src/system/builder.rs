@@ -12,12 +12,17 @@ pub struct Builder {
     pub(crate) desktop_manager: Option<ControlManager>,
     pub(crate) has_app_bar: bool,
     pub(crate) has_command_bar: bool,
+    pub(crate) has_command_palette: bool,
     pub(crate) single_window: bool,
     pub(crate) theme: Theme,
     pub(crate) max_timer_count: u8,
     pub(crate) log_file: Option<String>,
     pub(crate) log_append: bool,
     pub(crate) use_color_schema: bool,
+    pub(crate) inline: Option<InlineHeight>,
+    pub(crate) test_driver: bool,
+    pub(crate) replay: Option<(String, f32)>,
+    pub(crate) session_file: Option<String>,
 }
 impl Builder {
     pub(crate) fn new() -> Self {
@@ -29,12 +34,17 @@ impl Builder {
             desktop_manager: None,
             has_app_bar: false,
             has_command_bar: false,
+            has_command_palette: false,
             single_window: false,
             max_timer_count: 4,
             theme: Theme::new(Themes::Default),
             log_file: None,
             log_append: false,
             use_color_schema: true,
+            inline: None,
+            test_driver: false,
+            replay: None,
+            session_file: None,
         }
     }
     /// Builds the application using the current settings.
@@ -66,6 +76,12 @@ impl Builder {
         self.has_command_bar = true;
         self
     }
+    /// Enables the command palette (a searchable list with the commands of the focused control, opened with `Ctrl+Shift+P`).
+    #[inline(always)]
+    pub fn command_palette(mut self) -> Self {
+        self.has_command_palette = true;
+        self
+    }
     /// Enables the single window mode.
     #[inline(always)]
     pub fn single_window(mut self) -> Self {
@@ -106,4 +122,30 @@ impl Builder {
         self.use_color_schema = enabled;
         self
     }
+    /// Runs the application inline (below the current cursor position) instead of using the entire terminal.
+    /// The last painted frame remains in the terminal scrollback once the application ends.
+    /// This option is only supported by the Termios and CrossTerm backends.
+    #[inline(always)]
+    pub fn inline(mut self, height: InlineHeight) -> Self {
+        self.inline = Some(height);
+        self
+    }
+    /// Replays a script recorded with the **EVENT_RECORDER** feature (or any debug script) against the real terminal.
+    /// The `speed` parameter scales the recorded timing (`1.0` is the original speed, `2.0` replays twice as fast).
+    /// Validation commands (`CheckHash`, `CheckText`, ...) are ignored and the application keeps running once the replay ends.
+    /// This option can not be used for the debug backend or for single-threaded backends.
+    #[inline(always)]
+    pub fn replay(mut self, script: &str, speed: f32) -> Self {
+        self.replay = Some((String::from(script), speed));
+        self
+    }
+    /// Sets a file used to persist the session state of the application (the position and size of the windows, the
+    /// dock arrangement, the focused control and the state of the controls that implement the `OnSessionState` trait).
+    /// The state is restored when the application starts (after the `on_start` event of the desktop) and it is saved
+    /// when the application ends. A missing or invalid file is ignored.
+    #[inline(always)]
+    pub fn session_file(mut self, path: &str) -> Self {
+        self.session_file = Some(String::from(path));
+        self
+    }
 }
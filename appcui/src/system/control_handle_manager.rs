@@ -1,5 +1,7 @@
 use crate::{
+    graphics::Size,
     ui::common::ControlManager,
+    ui::layout::ControlLayout,
     utils::HandleManager,
 };
 
@@ -38,6 +40,49 @@ impl ControlHandleManager {
             }
         }
     }
+    // the size a control needs to display its content:
+    // - the preferred size reported by the control (if any)
+    // - otherwise, the space required by its visible children (plus its margins)
+    pub(crate) fn measure(&self, handle: Handle<()>, available: Size) -> Option<Size> {
+        let control = self.get(handle)?;
+        if let Some(sz) = control.control().preferred_size(available) {
+            return Some(sz);
+        }
+        let base = control.base();
+        let horizontal_margins = base.margins.left as u32 + base.margins.right as u32;
+        let vertical_margins = base.margins.top as u32 + base.margins.bottom as u32;
+        let client = Size::new(
+            available.width.saturating_sub(horizontal_margins),
+            available.height.saturating_sub(vertical_margins),
+        );
+        let mut result: Option<Size> = None;
+        for child in base.children.iter() {
+            if let Some(c) = self.get(*child) {
+                if !c.base().is_visible() {
+                    continue;
+                }
+                let extent = self.measured_extent(*child, &c.base().layout, client);
+                let r = result.get_or_insert(Size::default());
+                r.width = r.width.max(extent.width);
+                r.height = r.height.max(extent.height);
+            }
+        }
+        result.map(|sz| Size::new(sz.width + horizontal_margins, sz.height + vertical_margins))
+    }
+    // the layout of a child of a container: the layout stored by the container (the layout of the child when it was
+    // added), or the alternative layout selected by a breakpoint (if any)
+    pub(crate) fn effective_layout(&self, handle: Handle<()>, stored: &ControlLayout) -> ControlLayout {
+        let mut layout = *stored;
+        if let Some(responsive) = self.get(handle).and_then(|c| c.base().responsive_layout()) {
+            layout.set_rules_from(responsive);
+        }
+        layout
+    }
+    // the space (position + size) a control with the provided layout needs inside its parent
+    #[inline(always)]
+    pub(crate) fn measured_extent(&self, handle: Handle<()>, layout: &ControlLayout, available: Size) -> Size {
+        layout.measured_extent(self.measure(handle, available))
+    }
     #[inline(always)]
     pub(crate) fn add(&mut self, manager: ControlManager) -> Handle<()> {
         self.manager.add(manager).cast()
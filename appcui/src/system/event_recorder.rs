@@ -2,6 +2,7 @@ use std::fmt::Display;
 use std::fmt::Formatter;
 use std::fmt::Result;
 use std::fs;
+use std::time::Instant;
 
 use crate::backend::Backend;
 use crate::graphics::*;
@@ -15,6 +16,9 @@ use appcui_proc_macro::*;
 
 use super::RuntimeManager;
 
+// pauses shorter than this value are not recorded (so that repeated keys or wheel events can still be merged)
+const MIN_WAIT_MS: u128 = 50;
+
 enum MouseUpPossibleCombineAction {
     Up,
     Click,
@@ -39,6 +43,11 @@ struct MouseClick {
     y: i32,
     button: MouseButton,
 }
+struct TextCheck {
+    x: i32,
+    y: i32,
+    text: String,
+}
 struct MouseDrag {
     x1: i32,
     y1: i32,
@@ -56,6 +65,8 @@ enum Command {
     MouseDrag(MouseDrag),
     Paint(PaintCommand),
     CheckHash(u64),
+    CheckText(TextCheck),
+    Wait(u128),
 }
 impl Display for Command {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
@@ -83,6 +94,14 @@ impl Display for Command {
             }
             Command::Paint(cmd) => write!(f, "Paint('{}')\n", cmd.state_name),
             Command::CheckHash(hash) => write!(f, "CheckHash(0x{:x})\n", hash),
+            Command::CheckText(cmd) => {
+                if cmd.text.contains('"') {
+                    write!(f, "CheckText({},{},'{}')\n", cmd.x, cmd.y, cmd.text)
+                } else {
+                    write!(f, "CheckText({},{},\"{}\")\n", cmd.x, cmd.y, cmd.text)
+                }
+            }
+            Command::Wait(ms) => write!(f, "Wait({})\n", ms),
         };
         var_name
     }
@@ -92,6 +111,9 @@ pub(super) struct EventRecorder {
     state_id: u32,
     auto_mode: bool,
     last_hash: u64,
+    last_event: Instant,
+    mouse_pos: Point,
+    size: Size,
 }
 impl EventRecorder {
     pub(super) fn new() -> Self {
@@ -100,41 +122,58 @@ impl EventRecorder {
             state_id: 1,
             auto_mode: false,
             last_hash: 0,
+            last_event: Instant::now(),
+            mouse_pos: Point::new(0, 0),
+            size: Size::new(0, 0),
         }
     }
     pub(super) fn save(&self) {
-        let mut content = String::with_capacity(self.commands.len() * 32);
-        let mut step = String::with_capacity(256);
+        let mut content = String::with_capacity(self.commands.len() * 32 + 512);
+        content.push_str("# AppCUI event recording\n");
+        content.push_str(&format!("# Terminal size: {}x{}\n", self.size.width, self.size.height));
+        content.push_str("# One debug command per line (see App::debug). Wait(ms) keeps the original timing: it is ignored\n");
+        content.push_str("# when the script is used as a unit test and respected when it is replayed via Builder::replay(...).\n");
         content.push_str("Paint.Enable(false)\n");
         for cmd in &self.commands {
-            step += cmd.to_string().as_str();
-            match cmd {
-                Command::CheckHash(_) => {
-                    // we need at least one check hash
-                    content += step.as_str();
-                    step.clear();
-                }
-                _ => {}
-            }
+            content += cmd.to_string().as_str();
         }
         let _ = fs::write("events.txt", content);
     }
     pub(super) fn add(&mut self, sys_event: &SystemEvent, backend: &mut Box<dyn Backend>, surface: &Surface) {
+        self.size = surface.size();
+        match sys_event {
+            SystemEvent::KeyPressed(_) | SystemEvent::Resize(_) | SystemEvent::MouseButtonDown(_) | SystemEvent::MouseWheel(_) => self.add_wait(),
+            _ => {}
+        }
         match sys_event {
             SystemEvent::AppClose => {}
             SystemEvent::KeyPressed(event) => {
                 if self.add_keypressed(event.key) {
                     self.save_state(backend, surface);
+                    // the time spent in the dialog must not be part of the next wait command
+                    self.last_event = Instant::now();
                     RuntimeManager::get().request_update();
                 }
             }
             SystemEvent::KeyModifierChanged(_) => {}
             SystemEvent::Resize(new_size) => self.add_resize(*new_size),
-            SystemEvent::MouseButtonDown(evnt) => self.add_mouse_button_down(evnt),
-            SystemEvent::MouseButtonUp(evnt) => self.add_mouse_button_up(evnt),
+            SystemEvent::MouseButtonDown(evnt) => {
+                self.mouse_pos = Point::new(evnt.x, evnt.y);
+                self.add_mouse_button_down(evnt);
+            }
+            SystemEvent::MouseButtonUp(evnt) => {
+                self.mouse_pos = Point::new(evnt.x, evnt.y);
+                self.add_mouse_button_up(evnt);
+            }
             SystemEvent::MouseDoubleClick(_) => {}
-            SystemEvent::MouseMove(evnt) => self.add_mouse_move(evnt),
-            SystemEvent::MouseWheel(evnt) => self.add_mouse_wheel(evnt),
+            SystemEvent::MouseMove(evnt) => {
+                self.mouse_pos = Point::new(evnt.x, evnt.y);
+                self.add_mouse_move(evnt);
+            }
+            SystemEvent::MouseWheel(evnt) => {
+                self.mouse_pos = Point::new(evnt.x, evnt.y);
+                self.add_mouse_wheel(evnt);
+            }
             _ => {}
         }
     }
@@ -189,6 +228,34 @@ impl EventRecorder {
         }
         return hash;
     }
+    fn add_wait(&mut self) {
+        let now = Instant::now();
+        let ms = now.duration_since(self.last_event).as_millis();
+        self.last_event = now;
+        if ms >= MIN_WAIT_MS {
+            self.commands.push(Command::Wait(ms));
+        }
+    }
+    fn text_check(surface: &Surface, y: i32) -> Option<TextCheck> {
+        // the text from the line under the mouse cursor (without the leading and trailing spaces)
+        let sz = surface.size();
+        if (y < 0) || (y >= sz.height as i32) {
+            return None;
+        }
+        let start = (y as usize) * (sz.width as usize);
+        let line: Vec<char> = surface.chars[start..start + sz.width as usize]
+            .iter()
+            .map(|ch| if ch.code < ' ' { ' ' } else { ch.code })
+            .collect();
+        let first = line.iter().position(|ch| *ch != ' ')?;
+        let last = line.iter().rposition(|ch| *ch != ' ')?;
+        let text: String = line[first..=last].iter().collect();
+        if text.contains('"') && text.contains('\'') {
+            // can not be represented as a string parameter
+            return None;
+        }
+        Some(TextCheck { x: first as i32, y, text })
+    }
     fn add_keypressed(&mut self, key: Key) -> bool {
         if key.value() == key!("Ctrl+Alt+Space") {
             // save state
@@ -358,6 +425,7 @@ impl EventRecorder {
             EventRecorder::print_hot_key("Enter", "Add", 13, &mut screen);
             EventRecorder::print_hot_key("F8", "Clear All", 25, &mut screen);
             EventRecorder::print_hot_key("F9", &auto, 40, &mut screen);
+            EventRecorder::print_hot_key("F2", "Check text", 54, &mut screen);
 
             backend.update_screen(&screen);
             // get the events
@@ -373,6 +441,13 @@ impl EventRecorder {
                             self.commands.push(Command::CheckHash(EventRecorder::compute_surface_hash(surface)));
                             return;
                         }
+                        key!("F2") => {
+                            // adds a check for the text from the line under the mouse cursor
+                            if let Some(check) = EventRecorder::text_check(surface, self.mouse_pos.y) {
+                                self.commands.push(Command::CheckText(check));
+                            }
+                            return;
+                        }
                         key!("F8") => {
                             self.commands.clear();
                             comands = format!("Commands: {}", self.commands.len());
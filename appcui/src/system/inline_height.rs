@@ -0,0 +1,16 @@
+/// Describes the height of the viewport used when an application runs in inline mode
+/// (see [`Builder::inline`](crate::system::Builder::inline)).
+///
+/// In inline mode the application does not take over the entire terminal. Instead, it reserves
+/// a number of lines right below the current cursor position (scrolling the terminal if needed)
+/// and draws only within them. When the application ends, the last painted frame is left in
+/// the terminal scrollback and the cursor is moved on the line right after it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum InlineHeight {
+    /// The viewport has a fixed number of lines (limited to the height of the terminal)
+    Fixed(u32),
+    /// The viewport uses all the lines from the cursor position to the bottom of the terminal.
+    /// If less than half of the terminal is available, the terminal is scrolled so that the
+    /// viewport covers half of it.
+    Auto,
+}
@@ -7,7 +7,7 @@ use self::menu::events::MousePressedMenuResult;
 use super::background_task::BackgroundTaskManager;
 use super::runtime_manager_traits::*;
 use super::timer::TimerManager;
-use super::{ControlHandleManager, Handle, MenuHandleManager, Theme, ToolTip};
+use super::{ControlHandleManager, Handle, MenuHandleManager, Session, Theme, ToolTip};
 use crate::backend::{self, Backend};
 use crate::graphics::{Point, Rect, Size, Surface};
 use crate::input::{Key, KeyModifier, MouseButton, MouseEvent, MouseEventData};
@@ -15,11 +15,13 @@ use crate::prelude::*;
 use crate::ui::appbar::events::{AppBarEvent, AppBarEvents};
 use crate::ui::command_bar::events::GenericCommandBarEvents;
 use crate::ui::command_bar::{events::CommandBarEvent, CommandBar};
+use crate::ui::command_palette::{self, RecentCommands};
 use crate::ui::common::control_manager::ParentLayout;
 use crate::ui::common::ControlEvent;
 use crate::ui::common::ControlManager;
-use crate::ui::desktop::EmptyDesktop;
+use crate::ui::desktop::{DockManager, EmptyDesktop};
 use crate::ui::menu::events::{GenericMenuEvents, MenuEvent};
+use crate::ui::toast::{self, Toast, ToastManager};
 use crate::ui::window::events::WindowEvents;
 use crate::ui::{AppBar, Menu};
 use crate::utils::VectorIndex;
@@ -65,9 +67,13 @@ pub(crate) struct RuntimeManager {
     task_manager: BackgroundTaskManager,
     desktop_handle: Handle<()>,
     tooltip: ToolTip,
+    toasts: ToastManager,
+    dock: DockManager,
     commandbar: Option<CommandBar>,
     appbar: Option<AppBar>,
     recompute_layout: bool,
+    // the terminal size for which the breakpoints of the controls were last evaluated
+    breakpoints_size: Size,
     repaint: bool,
     mouse_pos: Point,
     key_modifier: KeyModifier,
@@ -87,12 +93,18 @@ pub(crate) struct RuntimeManager {
     commandbar_event: Option<CommandBarEvent>,
     menu_event: Option<MenuEvent>,
     appbar_event: Option<AppBarEvent>,
+    has_command_palette: bool,
+    request_command_palette: bool,
+    command_palette_opened: bool,
+    recent_commands: RecentCommands,
     mouse_locked_object: MouseLockedObject,
     opened_menu_handle: Handle<Menu>,
     modal_windows: Vec<Handle<()>>,
     to_remove_list: Vec<Handle<()>>,
     event_receiver: Receiver<SystemEvent>,
     event_sender: Sender<SystemEvent>,
+    test_driver: bool,
+    session_file: Option<String>,
     #[cfg(feature = "EVENT_RECORDER")]
     event_recorder: super::event_recorder::EventRecorder,
 }
@@ -114,6 +126,7 @@ impl RuntimeManager {
 
         let (sender, receiver) = std::sync::mpsc::channel::<SystemEvent>();
         let backend_term = backend::new(&builder, sender.clone())?;
+        backend::start_replay(&builder, backend_term.as_ref(), sender.clone())?;
         let term_sz = backend_term.size();
         let surface = Surface::new(term_sz.width, term_sz.height);
         let mut manager = RuntimeManager {
@@ -121,10 +134,15 @@ impl RuntimeManager {
             backend: backend_term,
             event_receiver: receiver,
             event_sender: sender,
+            test_driver: builder.test_driver,
+            session_file: builder.session_file.take(),
             surface,
             desktop_handle: Handle::new(0),
             tooltip: ToolTip::new(),
+            toasts: ToastManager::new(),
+            dock: DockManager::new(),
             recompute_layout: true,
+            breakpoints_size: Size::default(),
             repaint: true,
             desktop_os_start_called: false,
             update_command_and_app_bars: true,
@@ -146,6 +164,10 @@ impl RuntimeManager {
             commandbar_event: None,
             menu_event: None,
             appbar_event: None,
+            has_command_palette: builder.has_command_palette,
+            request_command_palette: false,
+            command_palette_opened: false,
+            recent_commands: RecentCommands::new(),
             controls: Box::into_raw(Box::new(ControlHandleManager::new())),
             timers_manager: TimerManager::new(builder.max_timer_count),
             task_manager: BackgroundTaskManager::new(),
@@ -237,6 +259,19 @@ impl RuntimeManager {
         self.backend.as_mut()
     }
 
+    pub(crate) fn suspend<F: FnOnce()>(&mut self, f: F) {
+        self.backend.suspend();
+        f();
+        self.resume();
+    }
+
+    fn resume(&mut self) {
+        self.backend.resume();
+        // the terminal might have been resized in the meantime and its content was lost --> everything must be painted again
+        self.process_terminal_resize_event(self.backend.size());
+        self.repaint = true;
+    }
+
     pub(crate) fn exit_execution_loop(&mut self) {
         self.loop_status = LoopStatus::ExitCurrentLoop;
     }
@@ -246,9 +281,131 @@ impl RuntimeManager {
     pub(crate) fn show_tooltip(&mut self, txt: &str, rect: &Rect) {
         self.tooltip.show(txt, rect, self.backend.size(), &self.theme);
     }
+    #[inline(always)]
+    pub(crate) fn get_dock_manager_mut(&mut self) -> &mut DockManager {
+        &mut self.dock
+    }
+    #[inline(always)]
+    pub(crate) fn get_dock_manager(&self) -> &DockManager {
+        &self.dock
+    }
     pub(crate) fn hide_tooltip(&mut self) {
         self.tooltip.hide();
     }
+    #[inline(always)]
+    pub(crate) fn get_toast_manager(&self) -> &ToastManager {
+        &self.toasts
+    }
+    #[inline(always)]
+    pub(crate) fn get_toast_manager_mut(&mut self) -> &mut ToastManager {
+        &mut self.toasts
+    }
+    #[inline(always)]
+    pub(crate) fn get_recent_commands(&self) -> &RecentCommands {
+        &self.recent_commands
+    }
+    #[inline(always)]
+    pub(crate) fn get_recent_commands_mut(&mut self) -> &mut RecentCommands {
+        &mut self.recent_commands
+    }
+    #[inline(always)]
+    pub(crate) fn is_command_palette_opened(&self) -> bool {
+        self.command_palette_opened
+    }
+    #[inline(always)]
+    pub(crate) fn set_command_palette_opened(&mut self, value: bool) {
+        self.command_palette_opened = value;
+    }
+    // collects the entries of the command palette for the focused control: the app bar items, the command bar
+    // items and the custom actions added by the focused control and its parents
+    pub(crate) fn command_palette_entries(&mut self) -> Vec<command_palette::Entry> {
+        if self.update_command_and_app_bars {
+            self.update_command_and_app_bars();
+        }
+        let mut entries = Vec::new();
+        if let Some(appbar) = self.appbar.as_ref() {
+            appbar.add_command_palette_entries(&mut entries);
+        }
+        if let Some(cmdbar) = self.commandbar.as_ref() {
+            cmdbar.add_command_palette_entries(&mut entries);
+        }
+        let controls = unsafe { &mut *self.controls };
+        let mut actions = command_palette::Actions::new();
+        let mut h = self.get_focused_control();
+        while let Some(control) = controls.get(h) {
+            actions.set_receiver_control_handle(h);
+            GenericCommandBarEvents::on_update_command_palette(control.control(), &mut actions);
+            h = control.base().parent;
+            if h.is_none() {
+                break;
+            }
+        }
+        entries.extend(actions.into_entries());
+        entries
+    }
+    // fires the event of an entry selected from the command palette
+    pub(crate) fn execute_command_palette_trigger(&mut self, trigger: command_palette::Trigger) {
+        match trigger {
+            command_palette::Trigger::MenuItem { menu, index, receiver } => {
+                if let Some(menu) = self.get_menu(menu) {
+                    menu.execute_item(index, receiver);
+                }
+            }
+            command_palette::Trigger::AppBarItem(handle) => {
+                if let Some(appbar) = self.appbar.as_mut() {
+                    appbar.execute_item(handle);
+                }
+            }
+            command_palette::Trigger::Command { command, receiver } => {
+                self.commandbar_event = Some(CommandBarEvent {
+                    command_id: command,
+                    control_receiver_handle: receiver,
+                });
+            }
+        }
+        self.repaint = true;
+    }
+    pub(crate) fn show_toast(&mut self, toast: Toast, receiver: Handle<()>) -> u32 {
+        let id = self.toasts.show(toast, receiver);
+        self.update_toasts();
+        id
+    }
+    pub(crate) fn dismiss_toast(&mut self, id: u32) -> bool {
+        let result = self.toasts.dismiss(id);
+        if result {
+            self.update_toasts();
+        }
+        result
+    }
+    // starts (or pauses) the timer that dismisses the toasts and updates the counter of the notification buttons
+    pub(crate) fn update_toasts(&mut self) {
+        let needs_timer = self.toasts.needs_timer();
+        if needs_timer != self.toasts.is_ticking() {
+            if self.toasts.timer().is_none() {
+                let timer = self.timers_manager.allocate_for(self.desktop_handle);
+                self.toasts.set_timer(timer);
+            }
+            if let Some(timer) = self.timers_manager.get_mut(self.toasts.timer()) {
+                if needs_timer {
+                    timer.start(ToastManager::TICK);
+                } else {
+                    timer.pause();
+                }
+                self.toasts.set_ticking(needs_timer);
+            }
+        }
+        if let Some(appbar) = self.appbar.as_mut() {
+            if appbar.set_unread_notifications(self.toasts.unread_count()) {
+                self.update_command_and_app_bars = true;
+            }
+        }
+        self.repaint = true;
+    }
+    #[inline(always)]
+    fn is_toast_timer(&self, id: u8) -> bool {
+        let timer = self.toasts.timer();
+        !timer.is_none() && timer.index() == id as usize
+    }
     pub(crate) fn close_opened_menu(&mut self) {
         if !self.opened_menu_handle.is_none() {
             self.opened_menu_handle = Handle::None;
@@ -317,6 +474,13 @@ impl RuntimeManager {
     pub(crate) fn request_focus_for_control(&mut self, handle: Handle<()>) {
         self.request_focus = Some(handle);
     }
+    // moves the focus right away (some controls, such as Tab, change their state via a focus request)
+    pub(crate) fn apply_focus_request(&mut self) {
+        if let Some(handle) = self.request_focus {
+            self.update_focus(handle);
+            self.repaint = true;
+        }
+    }
     pub(crate) fn request_default_action_for_control(&mut self, handle: Handle<()>) {
         self.request_default_action = Some(handle);
     }
@@ -336,6 +500,12 @@ impl RuntimeManager {
         self.repaint = true;
         self.recompute_layout = true;
     }
+    // a control that was added to a parent that is already part of a window
+    pub(crate) fn register_child(&mut self, handle: Handle<()>, event_processor: Handle<()>) {
+        self.set_event_processors(handle, event_processor);
+        self.recompute_parent_indexes = true;
+        self.recompute_layout = true;
+    }
     pub(crate) fn request_remove(&mut self, handle: Handle<()>) {
         if !handle.is_none() {
             self.to_remove_list.push(handle);
@@ -510,6 +680,19 @@ impl RuntimeManager {
             DesktopEvents::on_start(desktop.control_mut());
         }
     }
+    fn load_session_file(&mut self) {
+        if let Some(path) = self.session_file.as_ref() {
+            if std::path::Path::new(path).exists() {
+                // an invalid session file is ignored (the application starts with its default state)
+                let _ = Session::load(path);
+            }
+        }
+    }
+    pub(crate) fn save_session_file(&mut self) {
+        if let Some(path) = self.session_file.as_ref() {
+            let _ = Session::save(path);
+        }
+    }
     pub(crate) fn update_desktop_window_count(&mut self) {
         let controls = unsafe { &mut *self.controls };
         if let Some(desktop) = controls.get_mut(self.desktop_handle.cast()) {
@@ -519,6 +702,48 @@ impl RuntimeManager {
     }
 
     pub(crate) fn tick(&mut self, single_threaded: bool) {
+        self.process_pending_requests();
+
+        // auto save changes
+        #[cfg(feature = "EVENT_RECORDER")]
+        self.event_recorder.auto_update(&self.surface);
+
+        if single_threaded {
+            if let Some(sys_event) = self.backend.query_system_event() {
+                self.process_system_event(sys_event);
+            } else if self.test_driver && self.loop_status == LoopStatus::Normal {
+                // a modal loop was started from a test driver action --> its input must already be queued
+                self.process_queued_test_driver_event();
+            }
+        } else {
+            let event = if cfg!(target_arch = "wasm32") {
+                self.event_receiver.try_recv().ok()
+            } else {
+                self.event_receiver.recv().ok()
+            };
+
+            if let Some(sys_event) = event {
+                self.process_system_event(sys_event);
+                #[cfg(feature = "EVENT_RECORDER")]
+                self.event_recorder.add(&sys_event, &mut self.backend, &self.surface);
+            }
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        {
+            if self.loop_status == LoopStatus::ExitCurrentLoop {
+                self.exit_loop();
+            }
+        }
+    }
+
+    pub(crate) fn process_pending_requests(&mut self) {
+        // 0. Open the command palette (the selected entry sets up a command bar, menu or appbar event)
+        if self.request_command_palette {
+            self.request_command_palette = false;
+            command_palette::show();
+        }
+
         // 1. Process events from command bar
         if let Some(event) = self.commandbar_event {
             self.process_commandbar_event(event);
@@ -578,36 +803,49 @@ impl RuntimeManager {
             self.timers_manager.update_threads();
             self.request_update_timer_threads = false;
         }
-
-        // auto save changes
-        #[cfg(feature = "EVENT_RECORDER")]
-        self.event_recorder.auto_update(&self.surface);
-
-        if single_threaded {
-            if let Some(sys_event) = self.backend.query_system_event() {
-                self.process_system_event(sys_event);
-            }
+    }
+    #[inline(always)]
+    pub(crate) fn has_pending_requests(&self) -> bool {
+        !self.events.is_empty()
+            || !self.to_remove_list.is_empty()
+            || self.commandbar_event.is_some()
+            || self.menu_event.is_some()
+            || self.appbar_event.is_some()
+            || self.request_command_palette
+            || self.request_focus.is_some()
+    }
+    /// Returns the next event sent by another thread (timers, background tasks, test driver) without
+    /// querying the backend. If `timeout` is `None` the method does not wait.
+    pub(crate) fn receive_system_event(&self, timeout: Option<std::time::Duration>) -> Option<SystemEvent> {
+        match timeout {
+            Some(duration) => self.event_receiver.recv_timeout(duration).ok(),
+            None => self.event_receiver.try_recv().ok(),
+        }
+    }
+    fn process_queued_test_driver_event(&mut self) {
+        const MODAL_INPUT_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+        if let Some(sys_event) = self.receive_system_event(Some(MODAL_INPUT_TIMEOUT)) {
+            self.process_system_event(sys_event);
         } else {
-            let event = if cfg!(target_arch = "wasm32") {
-                self.event_receiver.try_recv().ok()
-            } else {
-                self.event_receiver.recv().ok()
-            };
-
-            if let Some(sys_event) = event {
-                self.process_system_event(sys_event);
-                #[cfg(feature = "EVENT_RECORDER")]
-                self.event_recorder.add(&sys_event, &mut self.backend, &self.surface);
-            }
-        }
-
-        #[cfg(target_arch = "wasm32")]
-        {
-            if self.loop_status == LoopStatus::ExitCurrentLoop {
-                self.exit_loop();
-            }
+            panic!("A modal window is waiting for input, but no event was received in the last {} seconds. Use TestDriver::queue_key(...) or TestDriver::queue_text(...) before the action that opens the modal window !", MODAL_INPUT_TIMEOUT.as_secs());
         }
     }
+    #[inline(always)]
+    pub(crate) fn is_running(&self) -> bool {
+        self.loop_status == LoopStatus::Normal
+    }
+    #[inline(always)]
+    pub(crate) fn surface(&self) -> &Surface {
+        &self.surface
+    }
+    #[inline(always)]
+    pub(crate) fn modal_windows(&self) -> &[Handle<()>] {
+        &self.modal_windows
+    }
+    #[inline(always)]
+    pub(crate) fn desktop_handle(&self) -> Handle<()> {
+        self.desktop_handle
+    }
 
     fn exit_loop(&mut self) {
         // if we are in a modal loop --> we just need to change loop_statup
@@ -625,21 +863,26 @@ impl RuntimeManager {
         self.request_update();
     }
 
-    pub(crate) fn run(&mut self) {
+    pub(crate) fn prepare_execution_loop(&mut self) {
         self.recompute_layout = true;
         self.repaint = true;
         self.recompute_parent_indexes = true;
         self.commandbar_event = None;
         self.menu_event = None;
-        let single_threaded = self.backend.is_single_threaded();
         // if first time an execution start
         if !self.desktop_os_start_called {
             self.process_terminal_resize_event(self.backend.size());
             self.process_desktop_on_start();
+            self.load_session_file();
             if self.single_window && self.get_controls_mut().desktop_mut().base().children.len() != 1 {
                 panic!("You can not run a single window app and not add a window to the app. Have you forget to add an '.add_window(...)' call before the .run() call ?")
             }
         }
+    }
+
+    pub(crate) fn run(&mut self) {
+        self.prepare_execution_loop();
+        let single_threaded = self.backend.is_single_threaded();
 
         #[cfg(not(target_arch = "wasm32"))]
         {
@@ -691,7 +934,7 @@ impl RuntimeManager {
         }
     }
     #[inline(always)]
-    fn process_system_event(&mut self, sys_event: SystemEvent) {
+    pub(crate) fn process_system_event(&mut self, sys_event: SystemEvent) {
         match sys_event {
             SystemEvent::AppClose => self.loop_status = LoopStatus::StopApp,
             SystemEvent::KeyPressed(event) => self.process_keypressed_event(event),
@@ -712,6 +955,19 @@ impl RuntimeManager {
             SystemEvent::BackgroundTaskEnd(h) => BackgroundTaskMethods::on_finish(self, h),
             SystemEvent::BackgroundTaskNotify(h) => BackgroundTaskMethods::on_notify(self, h),
             SystemEvent::BackgroundTaskQuery(h) => BackgroundTaskMethods::on_query(self, h),
+            SystemEvent::Suspend => {
+                // the terminal is set up again when the process is continued (the SIGCONT signal is
+                // received as a SystemEvent::Resume event)
+                #[cfg(target_family = "unix")]
+                {
+                    self.backend.suspend();
+                    crate::backend::utils::job_control::stop_process();
+                }
+            }
+            SystemEvent::Resume => {
+                // the process was continued (the terminal might have been modified in the meantime)
+                self.resume();
+            }
         }
     }
     fn remove_control(&mut self, handle: Handle<()>, unlink_from_parent: bool) -> (Handle<()>, bool) {
@@ -787,6 +1043,9 @@ impl RuntimeManager {
             // if focused_parent != Handle::None, we are in this scenario
         }
         if window_removed {
+            if !self.dock.is_empty() {
+                self.recompute_layout = true;
+            }
             self.update_desktop_window_count();
         }
     }
@@ -868,6 +1127,14 @@ impl RuntimeManager {
     fn process_events_queue(&mut self) {
         let controls = unsafe { &mut *self.controls };
         while let Some(evnt) = self.events.pop() {
+            // the parents of the emitter are notified first (so that a container can react to the changes of its children)
+            let mut h = controls.get(evnt.emitter).map(|c| c.base().parent).unwrap_or(Handle::None);
+            while let Some(control) = controls.get_mut(h) {
+                h = control.base().parent;
+                if control.notifies_child_events() {
+                    control.control_mut().on_child_event(evnt.emitter);
+                }
+            }
             if let Some(receiver) = controls.get_mut(evnt.receiver) {
                 let result = evnt.invoke(receiver.control_mut());
                 self.repaint |= result == EventProcessStatus::Processed;
@@ -927,6 +1194,11 @@ impl RuntimeManager {
                     self.repaint = true;
                 }
             }
+            AppBarEvent::ShowNotificationCenter => {
+                // the notification center is a modal window (it has its own loop) - the event must be consumed first
+                self.appbar_event = None;
+                toast::show_notification_center();
+            }
         }
         self.appbar_event = None;
     }
@@ -954,6 +1226,7 @@ impl RuntimeManager {
         }
         // process appbar
         if let Some(appbar) = self.appbar.as_mut() {
+            appbar.set_unread_notifications(self.toasts.unread_count());
             appbar.clear();
             // start from the focused control and call on_update_menubar for each control
             let mut h = focused_handle;
@@ -1103,6 +1376,12 @@ impl RuntimeManager {
             }
             parent_handle = Some(handle);
         }
+        // 4. notify the parents (from the closest one to the window/desktop) that a descendant has the focus
+        let mut h = controls.get(handle).map(|c| c.base().parent).unwrap_or(Handle::None);
+        while let Some(control) = controls.get_mut(h) {
+            h = control.base().parent;
+            control.control_mut().on_child_focus(handle);
+        }
         self.current_focus = Some(handle);
         self.request_focus = None;
         // check default actio
@@ -1203,6 +1482,19 @@ impl RuntimeManager {
 
 impl LayoutMethods for RuntimeManager {
     fn recompute_layouts(&mut self) {
+        let terminal = self.terminal_size();
+        if terminal != self.breakpoints_size {
+            self.breakpoints_size = terminal;
+            self.update_breakpoints(self.desktop_handle, terminal);
+            let count = self.modal_windows.len();
+            for index in 0..count {
+                let handle = self.modal_windows[index];
+                self.update_breakpoints(handle, terminal);
+            }
+        }
+        if !self.dock.is_empty() {
+            self.dock.relayout();
+        }
         let term_layout = ParentLayout::from(&self.backend);
         self.update_control_layout(self.desktop_handle, &term_layout);
         let count = self.modal_windows.len();
@@ -1211,14 +1503,41 @@ impl LayoutMethods for RuntimeManager {
             self.update_control_layout(handle, &term_layout);
         }
     }
+    // selects the alternative layouts (and the collapsed state) of a control and of all of its descendants
+    // returns `true` if the layout of the control was changed
+    fn update_breakpoints(&mut self, handle: Handle<()>, terminal: Size) -> bool {
+        let controls = unsafe { &mut *self.controls };
+        if let Some(control) = controls.get_mut(handle) {
+            let changed = control.base_mut().update_breakpoints(terminal);
+            let mut children_changed = false;
+            for child_handle in &control.base().children {
+                children_changed |= self.update_breakpoints(*child_handle, terminal);
+            }
+            if children_changed {
+                control.control_mut().on_children_layout_changed();
+            }
+            changed
+        } else {
+            false
+        }
+    }
     fn update_control_layout(&mut self, handle: Handle<()>, parent_layout: &ParentLayout) {
         let controls = unsafe { &mut *self.controls };
+        // controls with an `auto` width or height are measured before their layout is computed
+        let content = match controls.get(handle) {
+            Some(control) if control.base().layout.has_auto_size() => controls.measure(handle, parent_layout.client_size()),
+            _ => None,
+        };
         if let Some(control) = controls.get_mut(handle) {
             let base = control.base_mut();
+            if let Some(size) = content {
+                base.layout.set_content_size(size);
+            }
             let window_control = base.is_window_control();
             let old_size = base.size();
             let old_pos = base.position();
             let expanded = base.is_expanded();
+            let old_expanded_size = base.expanded_size();
             let mut expand_status = ExpandStatus::None;
             base.update_control_layout_and_screen_origin(parent_layout);
             if expanded {
@@ -1226,6 +1545,24 @@ impl LayoutMethods for RuntimeManager {
                     // need to pack myself as there is another expamded control
                     base.set_expand_flag(false);
                     expand_status = ExpandStatus::Pack;
+                } else {
+                    // still expanded --> recompute the expanded area (the control might have requested a new size)
+                    let termsize = self.terminal_size();
+                    match base.update_expanded_layout(self.expanded_control.prefered_size, self.expanded_control.min_size, termsize) {
+                        Some(dir) => {
+                            if base.expanded_size() != old_expanded_size {
+                                expand_status = match dir {
+                                    ExpandedDirection::OnTop => ExpandStatus::ExpandOnTop,
+                                    ExpandedDirection::OnBottom => ExpandStatus::ExpandOnBottom,
+                                };
+                            }
+                        }
+                        None => {
+                            base.set_expand_flag(false);
+                            self.expanded_control.handle = Handle::None;
+                            expand_status = ExpandStatus::Pack;
+                        }
+                    }
                 }
             } else if handle == self.expanded_control.handle {
                 // need to compute my expended size
@@ -1284,6 +1621,8 @@ impl PaintMethods for RuntimeManager {
         self.surface.hide_cursor();
         self.surface.reset();
         self.paint_control(self.desktop_handle);
+        self.surface.reset();
+        self.dock.paint_drop_preview(&mut self.surface, &self.theme);
         if !self.modal_windows.is_empty() {
             let count = self.modal_windows.len();
             for index in 0..count {
@@ -1304,6 +1643,10 @@ impl PaintMethods for RuntimeManager {
         if self.appbar.is_some() {
             self.appbar.as_ref().unwrap().paint(&mut self.surface, &self.theme);
         }
+        if !self.toasts.is_empty() {
+            self.toasts.arrange(self.get_desktop_rect());
+            self.toasts.paint(&mut self.surface, &self.theme);
+        }
         if self.tooltip.is_visible() {
             self.tooltip.paint(&mut self.surface, &self.theme);
         }
@@ -1320,6 +1663,10 @@ impl PaintMethods for RuntimeManager {
             if base.prepare_paint(&mut self.surface) {
                 // paint is possible
                 element.control().on_paint(&mut self.surface, &self.theme);
+                if handle == self.desktop_handle {
+                    // the tab strips of the docked windows are drawn over the desktop (bellow the windows)
+                    self.dock.paint(&mut self.surface, &self.theme);
+                }
                 let children_count = base.children.len();
                 if base.focused_child_index.in_range(children_count) {
                     // draw from the next visible element until
@@ -1375,12 +1722,18 @@ impl KeyboardMethods for RuntimeManager {
                 }
             }
         }
-        // 2. check controls
+        // 2. check the command palette shortcut
+        if self.has_command_palette && !self.command_palette_opened && event.key.value() == key!("Ctrl+Shift+P") {
+            self.close_opened_menu();
+            self.request_command_palette = true;
+            return;
+        }
+        // 3. check controls
         if self.process_control_keypressed_event(self.get_root_control_handle(), event.key, event.character) == EventProcessStatus::Processed {
             self.repaint = true;
             return;
         };
-        // 3. check cmdbar
+        // 4. check cmdbar
         if let Some(cmdbar) = self.commandbar.as_mut() {
             self.commandbar_event = cmdbar.get_event(event.key);
             if self.commandbar_event.is_some() {
@@ -1388,7 +1741,7 @@ impl KeyboardMethods for RuntimeManager {
                 return;
             }
         }
-        // 4. check the appbar
+        // 5. check the appbar
         if let Some(appbar) = self.appbar.as_mut() {
             if appbar.on_key_event(event.key, false) == EventProcessStatus::Processed {
                 self.repaint = true;
@@ -1624,8 +1977,17 @@ impl MouseMethods for RuntimeManager {
         let handle = self.coordinates_to_control(event.x, event.y, false);
         if !handle.is_none() {
             let controls = unsafe { &mut *self.controls };
-            if let Some(control) = controls.get_mut(handle) {
-                self.repaint |= control.control_mut().on_mouse_event(&MouseEvent::Wheel(event.direction)) == EventProcessStatus::Processed;
+            // if the control does not process the wheel event, the event is sent to its parents (e.g. a scrollable container)
+            let mut h = handle;
+            while let Some(control) = controls.get_mut(h) {
+                if control.control_mut().on_mouse_event(&MouseEvent::Wheel(event.direction)) == EventProcessStatus::Processed {
+                    self.repaint = true;
+                    break;
+                }
+                if control.base().is_window_control() {
+                    break;
+                }
+                h = control.base().parent;
             }
         }
     }
@@ -1651,6 +2013,13 @@ impl MouseMethods for RuntimeManager {
         if self.process_menu_and_cmdbar_mousemove(event.x, event.y) {
             return;
         }
+        if !self.toasts.is_empty() {
+            // the hovered toast is highlighted (and its countdown is paused)
+            self.repaint = true;
+            if self.toasts.on_mouse_move(event.x, event.y) {
+                return;
+            }
+        }
         let controls = unsafe { &mut *self.controls };
         let handle = self.coordinates_to_control(event.x, event.y, false);
         if handle != self.mouse_over_control {
@@ -1711,6 +2080,12 @@ impl MouseMethods for RuntimeManager {
         if !self.opened_menu_handle.is_none() && self.process_menu_mouse_click(self.opened_menu_handle, event.x, event.y) {
             return;
         }
+        // check the toasts (they are painted on top of the desktop and of the windows)
+        if self.toasts.on_mouse_pressed(event.x, event.y, &mut self.events) {
+            self.update_toasts();
+            self.mouse_locked_object = MouseLockedObject::None;
+            return;
+        }
         // check appbar (only if y==0 - the event is on the appbar)
         if event.y == 0 {
             if let Some(appbar) = self.appbar.as_mut() {
@@ -1733,6 +2108,16 @@ impl MouseMethods for RuntimeManager {
         }
         // check for a control
         let handle = self.coordinates_to_control(event.x, event.y, false);
+        if (handle == self.desktop_handle) && self.modal_windows.is_empty() {
+            // check the tab strips of the docked windows
+            if let Some(window) = self.dock.tab_at(event.x, event.y) {
+                self.dock.activate(window);
+                self.request_focus_for_control(window);
+                self.mouse_locked_object = MouseLockedObject::None;
+                self.repaint = true;
+                return;
+            }
+        }
         if !handle.is_none() {
             let controls = unsafe { &mut *self.controls };
             if let Some(control) = controls.get_mut(handle) {
@@ -1891,6 +2276,12 @@ impl TimerMethods for RuntimeManager {
     }
 
     fn process_timer_tick_update_event(&mut self, id: u8, tick: u64) {
+        if self.is_toast_timer(id) {
+            if self.toasts.on_tick(ToastManager::TICK) {
+                self.update_toasts();
+            }
+            return;
+        }
         if let Some(cm) = self.timer_id_to_control(id) {
             if TimerEvents::on_update(cm.control_mut(), tick) == EventProcessStatus::Processed {
                 self.repaint = true;
@@ -1908,6 +2299,9 @@ impl TimerMethods for RuntimeManager {
             // if timer is invalid -> ignore the event
             return;
         };
+        if self.is_toast_timer(id) {
+            return;
+        }
         if let Some(cm) = self.timer_id_to_control(id) {
             if TimerEvents::on_pause(cm.control_mut(), tick) == EventProcessStatus::Processed {
                 self.repaint = true;
@@ -1925,6 +2319,9 @@ impl TimerMethods for RuntimeManager {
             // if timer is invalid -> ignore the event
             return;
         };
+        if self.is_toast_timer(id) {
+            return;
+        }
         if let Some(cm) = self.timer_id_to_control(id) {
             let result = if tick == 0 {
                 TimerEvents::on_start(cm.control_mut())
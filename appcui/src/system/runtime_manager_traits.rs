@@ -3,6 +3,7 @@ use crate::ui::common::traits::*;
 use crate::ui::common::control_manager::ParentLayout;
 use crate::ui::menu::Menu;
 use crate::input::*;
+use crate::graphics::Size;
 use super::timer::TimerManager;
 use super::Handle;
 use super::Theme;
@@ -14,6 +15,7 @@ use super::MouseDoubleClickEvent;
 use super::MouseButtonUpEvent;
 
 pub(crate) trait LayoutMethods {
+    fn update_breakpoints(&mut self, handle: Handle<()>, terminal: Size) -> bool;
     fn update_control_layout(&mut self, handle: Handle<()>, parent_layout: &ParentLayout);
     fn recompute_layouts(&mut self);
     fn request_recompute_layout(&mut self);
@@ -0,0 +1,394 @@
+use std::str::FromStr;
+
+use super::{Error, ErrorKind, Handle, LayoutMethods, RuntimeManager};
+use crate::graphics::Rect;
+use crate::ui::desktop::DockManager;
+
+/// A list of key/value pairs that describe the state of a control (or of a window, or of the desktop) that
+/// should be persisted between two runs of an application (for example the position of a splitter or the
+/// selected page of a tab).
+///
+/// Keys can not be empty and can not contain the `=` character or new lines (these characters are replaced
+/// with `_`). Values can contain any character.
+///
+/// # Example
+/// ```rust
+/// use appcui::prelude::*;
+///
+/// let mut state = SessionState::new();
+/// state.set("zoom", 150);
+/// state.set("path", "/home/user");
+/// assert_eq!(state.get_as::<u32>("zoom"), Some(150));
+/// assert_eq!(state.get("path"), Some("/home/user"));
+/// assert_eq!(state.get("missing"), None);
+/// ```
+#[derive(Clone, Default, Debug, PartialEq, Eq)]
+pub struct SessionState {
+    entries: Vec<(String, String)>,
+}
+
+impl SessionState {
+    /// Creates a new (empty) state.
+    pub fn new() -> Self {
+        Self { entries: Vec::new() }
+    }
+
+    /// Sets the value associated with a key (if the key already exists, its value is replaced).
+    pub fn set<T: ToString>(&mut self, key: &str, value: T) {
+        let key = Self::sanitize_key(key);
+        if key.is_empty() {
+            return;
+        }
+        let value = value.to_string();
+        if let Some(entry) = self.entries.iter_mut().find(|(k, _)| *k == key) {
+            entry.1 = value;
+        } else {
+            self.entries.push((key, value));
+        }
+    }
+
+    /// Returns the value associated with a key (or `None` if the key does not exist).
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.entries.iter().find(|(k, _)| k == key).map(|(_, v)| v.as_str())
+    }
+
+    /// Returns the value associated with a key converted to a specific type (or `None` if the key does not
+    /// exist or if its value can not be converted).
+    pub fn get_as<T: FromStr>(&self, key: &str) -> Option<T> {
+        self.get(key)?.parse::<T>().ok()
+    }
+
+    /// Removes a key (and its value) from the state.
+    pub fn remove(&mut self, key: &str) {
+        self.entries.retain(|(k, _)| k != key);
+    }
+
+    /// Returns `true` if the state does not contain any key.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the number of keys from the state.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns an iterator over the key/value pairs of the state (in the order they were added).
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.entries.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+
+    fn sanitize_key(key: &str) -> String {
+        key.trim()
+            .chars()
+            .map(|c| if c == '=' || c == '\n' || c == '\r' { '_' } else { c })
+            .collect()
+    }
+}
+
+// the state of an entire application: a list of named sections (one for the desktop, one for every window
+// and one for every control that has something to save)
+#[derive(Default)]
+pub(crate) struct Session {
+    sections: Vec<(String, SessionState)>,
+}
+
+impl Session {
+    pub(crate) fn add(&mut self, name: String, state: SessionState) {
+        if !state.is_empty() {
+            self.sections.push((name, state));
+        }
+    }
+
+    pub(crate) fn get(&self, name: &str) -> Option<&SessionState> {
+        self.sections.iter().find(|(n, _)| n == name).map(|(_, s)| s)
+    }
+
+    // the text format is similar to an INI file:
+    //      [section name]
+    //      key = value
+    // values are escaped (\\, \n and \r) so that each one fits on a single line
+    pub(crate) fn to_text(&self) -> String {
+        let mut result = String::new();
+        for (name, state) in self.sections.iter() {
+            if !result.is_empty() {
+                result.push('\n');
+            }
+            result.push('[');
+            result.push_str(name);
+            result.push_str("]\n");
+            for (key, value) in state.iter() {
+                result.push_str(key);
+                result.push_str(" = ");
+                for ch in value.chars() {
+                    match ch {
+                        '\\' => result.push_str("\\\\"),
+                        '\n' => result.push_str("\\n"),
+                        '\r' => result.push_str("\\r"),
+                        _ => result.push(ch),
+                    }
+                }
+                result.push('\n');
+            }
+        }
+        result
+    }
+
+    pub(crate) fn from_text(text: &str) -> Result<Session, Error> {
+        let mut session = Session::default();
+        let mut current: Option<(String, SessionState)> = None;
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim_start();
+            if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+                continue;
+            }
+            if line.starts_with('[') {
+                let line = line.trim_end();
+                if !line.ends_with(']') || line.len() < 3 {
+                    return Err(Self::error(index, "invalid section name"));
+                }
+                if let Some((name, state)) = current.take() {
+                    session.add(name, state);
+                }
+                current = Some((line[1..line.len() - 1].to_string(), SessionState::new()));
+                continue;
+            }
+            let Some((key, value)) = line.split_once('=') else {
+                return Err(Self::error(index, "expecting a 'key = value' pair"));
+            };
+            let Some((_, state)) = current.as_mut() else {
+                return Err(Self::error(index, "a 'key = value' pair must be part of a section"));
+            };
+            let value = value.strip_prefix(' ').unwrap_or(value);
+            let mut unescaped = String::with_capacity(value.len());
+            let mut chars = value.chars();
+            while let Some(ch) = chars.next() {
+                if ch != '\\' {
+                    unescaped.push(ch);
+                    continue;
+                }
+                match chars.next() {
+                    Some('n') => unescaped.push('\n'),
+                    Some('r') => unescaped.push('\r'),
+                    Some(other) => unescaped.push(other),
+                    None => unescaped.push('\\'),
+                }
+            }
+            state.set(key, unescaped);
+        }
+        if let Some((name, state)) = current.take() {
+            session.add(name, state);
+        }
+        Ok(session)
+    }
+
+    fn error(line: usize, message: &str) -> Error {
+        Error::new(
+            ErrorKind::InvalidParameter,
+            format!("Invalid session state (line {}): {}", line + 1, message),
+        )
+    }
+
+    // collects the state of the desktop, of all windows (except for modal windows) and of their controls
+    pub(crate) fn capture() -> Session {
+        let rm = RuntimeManager::get();
+        let controls = rm.get_controls();
+        let dock = rm.get_dock_manager();
+        let mut session = Session::default();
+        let Some(desktop) = controls.get(rm.desktop_handle()) else {
+            return session;
+        };
+        let windows = Self::windows();
+        let mut state = SessionState::new();
+        desktop.control().save_state(&mut state);
+        let arrangement = dock.arrangement();
+        if !arrangement.is_empty() {
+            state.set("dock", arrangement);
+        }
+        if let Some((window, path)) = Self::focus_path(&windows) {
+            // the name of the window and the indexes are stored separately (a window title may contain '/')
+            state.set("focus", window);
+            if !path.is_empty() {
+                state.set("focus_path", path);
+            }
+        }
+        session.add("desktop".to_string(), state);
+        for (name, handle) in windows.iter() {
+            if let Some(window) = controls.get(*handle) {
+                let base = window.base();
+                let r = dock
+                    .floating_rect(*handle)
+                    .unwrap_or_else(|| Rect::with_point_and_size(base.position(), base.size()));
+                let mut state = SessionState::new();
+                state.set("x", r.left());
+                state.set("y", r.top());
+                state.set("width", r.width());
+                state.set("height", r.height());
+                window.control().save_state(&mut state);
+                session.add(name.clone(), state);
+                Self::capture_children(*handle, name, &mut session);
+            }
+        }
+        session
+    }
+
+    fn capture_children(handle: Handle<()>, path: &str, session: &mut Session) {
+        let controls = RuntimeManager::get().get_controls();
+        if let Some(control) = controls.get(handle) {
+            for (index, child) in control.base().children.iter().enumerate() {
+                if let Some(child_control) = controls.get(*child) {
+                    let child_path = format!("{}/{}", path, index);
+                    let mut state = SessionState::new();
+                    child_control.control().save_state(&mut state);
+                    session.add(child_path.clone(), state);
+                    Self::capture_children(*child, &child_path, session);
+                }
+            }
+        }
+    }
+
+    // applies the state to the desktop, to the existing windows and to their controls
+    pub(crate) fn apply(&self) {
+        let rm = RuntimeManager::get();
+        // the desktop is restored first (so that it can create the windows it had in the previous session)
+        if let Some(state) = self.get("desktop") {
+            let handle = rm.desktop_handle();
+            if let Some(desktop) = rm.get_controls_mut().get_mut(handle) {
+                desktop.control_mut().restore_state(state);
+            }
+        }
+        // the layout of the windows must be computed before restoring the state of their controls
+        rm.recompute_layouts();
+        let windows = Self::windows();
+        for (name, handle) in windows.iter() {
+            let Some(state) = self.get(name) else {
+                continue;
+            };
+            if let Some(window) = rm.get_controls_mut().get_mut(*handle) {
+                let base = window.base_mut();
+                if let (Some(x), Some(y)) = (state.get_as::<i32>("x"), state.get_as::<i32>("y")) {
+                    base.set_position(x, y);
+                }
+                if let (Some(w), Some(h)) = (state.get_as::<u16>("width"), state.get_as::<u16>("height")) {
+                    base.set_size(w, h);
+                }
+            }
+        }
+        rm.recompute_layouts();
+        for (name, handle) in windows.iter() {
+            if let Some(state) = self.get(name) {
+                if let Some(window) = rm.get_controls_mut().get_mut(*handle) {
+                    window.control_mut().restore_state(state);
+                }
+                rm.apply_focus_request();
+            }
+            self.apply_children(*handle, name);
+        }
+        if let Some(state) = self.get("desktop") {
+            let handles: Vec<Handle<()>> = windows.iter().map(|(_, h)| *h).collect();
+            // an arrangement that is no longer valid is ignored
+            let _ = rm
+                .get_dock_manager_mut()
+                .restore_arrangement(state.get("dock").unwrap_or_default(), &handles);
+            let focus = state
+                .get("focus")
+                .and_then(|window| Self::resolve(&windows, window, state.get("focus_path").unwrap_or_default()));
+            if let Some(focus) = focus {
+                rm.get_dock_manager_mut().activate(focus);
+                rm.request_focus_for_control(focus);
+            }
+        }
+        rm.request_recompute_layout();
+    }
+
+    fn apply_children(&self, handle: Handle<()>, path: &str) {
+        let controls = RuntimeManager::get().get_controls_mut();
+        let children = match controls.get(handle) {
+            Some(control) => control.base().children.clone(),
+            None => return,
+        };
+        for (index, child) in children.iter().enumerate() {
+            let child_path = format!("{}/{}", path, index);
+            if let Some(state) = self.get(&child_path) {
+                if let Some(child_control) = controls.get_mut(*child) {
+                    child_control.control_mut().restore_state(state);
+                }
+                RuntimeManager::get().apply_focus_request();
+            }
+            self.apply_children(*child, &child_path);
+        }
+    }
+
+    // the windows of the desktop and the names of their sections ("window:<tag or title>"). If several windows
+    // have the same name, a suffix (#2, #3, ...) is added in the order the windows were added to the desktop.
+    fn windows() -> Vec<(String, Handle<()>)> {
+        let rm = RuntimeManager::get();
+        let mut result: Vec<(String, Handle<()>)> = Vec::new();
+        let Some(desktop) = rm.get_controls().get(rm.desktop_handle()) else {
+            return result;
+        };
+        for handle in desktop.base().children.iter() {
+            let name = format!("window:{}", DockManager::window_name(*handle));
+            let count = result.iter().filter(|(n, _)| *n == name || n.starts_with(&format!("{}#", name))).count();
+            if count == 0 {
+                result.push((name, *handle));
+            } else {
+                result.push((format!("{}#{}", name, count + 1), *handle));
+            }
+        }
+        result
+    }
+
+    // the focused control: the name of the window and the indexes of the focused children (separated by '/')
+    fn focus_path(windows: &[(String, Handle<()>)]) -> Option<(String, String)> {
+        let rm = RuntimeManager::get();
+        let controls = rm.get_controls();
+        let desktop = controls.get(rm.desktop_handle())?.base();
+        let window = *desktop.children.get(desktop.focused_child_index.index())?;
+        let name = windows.iter().find(|(_, h)| *h == window)?.0.clone();
+        let mut path = String::new();
+        let mut base = controls.get(window)?.base();
+        while let Some(child) = base.children.get(base.focused_child_index.index()) {
+            if !path.is_empty() {
+                path.push('/');
+            }
+            path.push_str(&base.focused_child_index.index().to_string());
+            base = controls.get(*child)?.base();
+        }
+        Some((name, path))
+    }
+
+    fn resolve(windows: &[(String, Handle<()>)], name: &str, path: &str) -> Option<Handle<()>> {
+        let controls = RuntimeManager::get().get_controls();
+        let mut indexes = path;
+        let mut handle = windows.iter().find(|(n, _)| n == name)?.1;
+        while !indexes.is_empty() {
+            let (index, rest) = indexes.split_once('/').unwrap_or((indexes, ""));
+            handle = *controls.get(handle)?.base().children.get(index.parse::<usize>().ok()?)?;
+            indexes = rest;
+        }
+        Some(handle)
+    }
+
+    pub(crate) fn save(path: &str) -> Result<(), Error> {
+        std::fs::write(path, Session::capture().to_text()).map_err(|err| {
+            Error::new(
+                ErrorKind::InvalidParameter,
+                format!("Fail to save the session state to '{}' ({})", path, err),
+            )
+        })
+    }
+
+    pub(crate) fn load(path: &str) -> Result<(), Error> {
+        let text = std::fs::read_to_string(path).map_err(|err| {
+            Error::new(
+                ErrorKind::InvalidParameter,
+                format!("Fail to load the session state from '{}' ({})", path, err),
+            )
+        })?;
+        Session::from_text(&text)?.apply();
+        Ok(())
+    }
+}
@@ -103,6 +103,8 @@ pub(crate) enum SystemEvent {
     BackgroundTaskEnd(Handle<()>),
     BackgroundTaskNotify(Handle<()>),
     BackgroundTaskQuery(Handle<()>),
+    Suspend,
+    Resume,
 }
 
 impl SystemEvent {
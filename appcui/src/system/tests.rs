@@ -357,3 +357,233 @@ fn check_create_app_with_windows_terminal() {
     let _ = App::with_backend(crate::backend::Type::WindowsConsole).build();
 }
 
+
+#[cfg(target_family = "unix")]
+#[test]
+fn check_app_create_inline_with_invalid_backend() {
+    let a = App::with_backend(crate::backend::Type::NcursesTerminal).inline(crate::system::InlineHeight::Fixed(10)).build();
+    assert!(a.is_err());
+    let err: crate::system::Error = a.err().unwrap();
+    assert_eq!(err.kind, crate::system::ErrorKind::InvalidFeature);
+}
+
+#[test]
+fn check_app_suspend() {
+    let script = "
+        Paint.Enable(false)
+        CheckHash(0x734FECAF52FDE955)
+    ";
+    let a = App::debug(60, 10, script).build().unwrap();
+    let mut called = false;
+    App::suspend(|| called = true);
+    assert!(called);
+    a.run();
+}
+
+#[test]
+fn check_session_state() {
+    use crate::system::{Session, SessionState};
+
+    let mut state = SessionState::new();
+    state.set("x", 10);
+    state.set(" name ", "a=b");
+    state.set("bad=key\n", true);
+    state.set("", 1);
+    state.set("x", -5);
+    assert_eq!(state.len(), 3);
+    assert_eq!(state.get_as::<i32>("x"), Some(-5));
+    assert_eq!(state.get("name"), Some("a=b"));
+    assert_eq!(state.get_as::<bool>("bad_key"), Some(true));
+    assert_eq!(state.get_as::<u32>("x"), None);
+    state.remove("x");
+    assert_eq!(state.get("x"), None);
+
+    // text format (values are escaped so that they fit on a single line)
+    let mut session = Session::default();
+    let mut s1 = SessionState::new();
+    s1.set("text", "line 1\nline 2 \\ end");
+    s1.set("empty", "");
+    session.add("window:Editor".to_string(), s1.clone());
+    session.add("window:Empty".to_string(), SessionState::new());
+    let text = session.to_text();
+    assert_eq!(text, "[window:Editor]\ntext = line 1\\nline 2 \\\\ end\nempty = \n");
+    let loaded = Session::from_text(&format!("# comment\n\n{}", text)).unwrap();
+    assert_eq!(loaded.get("window:Editor"), Some(&s1));
+    assert!(loaded.get("window:Empty").is_none());
+
+    // invalid content
+    for text in ["key = value", "[section]\nno value", "[section\nx = 1"] {
+        let err = Session::from_text(text).err().unwrap();
+        assert_eq!(err.kind, crate::system::ErrorKind::InvalidParameter);
+    }
+}
+
+#[test]
+fn check_save_and_load_session() {
+    use crate::prelude::*;
+    use crate::testing::TestDriver;
+
+    let path = std::env::temp_dir().join(format!("appcui_session_{}.txt", std::process::id()));
+    let path = path.to_str().unwrap();
+    let mut driver = TestDriver::new(App::debug(80, 25, "")).unwrap();
+    let mut w = Window::new("Editor", layout!("x:2,y:2,w:40,h:15"), window::Flags::Sizeable);
+    let mut vs = VSplitter::new(0.5, layout!("x:0,y:0,w:100%,h:6"), vsplitter::ResizeBehavior::PreserveAspectRatio);
+    let b1 = vs.add(vsplitter::Panel::Left, Button::new("First", layout!("x:0,y:0,w:10"), button::Type::Normal));
+    let b2 = vs.add(vsplitter::Panel::Right, Button::new("Second", layout!("x:0,y:0,w:10"), button::Type::Normal));
+    let vs = w.add(vs);
+    let mut tab = Tab::new(layout!("x:0,y:6,w:100%,h:7"), tab::Flags::None);
+    tab.add_tab("One");
+    tab.add_tab("Two");
+    tab.add_tab("Three");
+    let tb = tab.add(1, Button::new("Inside", layout!("x:0,y:0,w:10"), button::Type::Normal));
+    let tab = w.add(tab);
+    let w = driver.add_window(w);
+    let mut w2 = Window::new("Editor", layout!("x:50,y:5,w:20,h:8"), window::Flags::None);
+    w2.set_tag("notes");
+    let w2 = driver.add_window(w2);
+    driver.settle();
+
+    // change the state of the application and save it
+    driver.get_mut(w).unwrap().set_position(5, 3);
+    driver.get_mut(vs).unwrap().set_position(10);
+    driver.get_mut(tab).unwrap().set_current_tab(1);
+    driver.settle();
+    driver.focus(b2);
+    assert!(driver.has_focus(b2));
+    App::save_session(path).unwrap();
+    let text = std::fs::read_to_string(path).unwrap();
+    assert!(text.contains("[window:Editor]\nx = 5\ny = 3\nwidth = 40\nheight = 15\n"));
+    assert!(text.contains("[window:Editor/0]\nposition = 10\n"));
+    assert!(text.contains("[window:Editor/1]\ntab = 1\n"));
+    assert!(text.contains("[window:notes]\n"));
+    assert!(text.contains("focus = window:Editor\nfocus_path = 0/1/0\n"));
+
+    // change everything and restore the saved state
+    driver.get_mut(w).unwrap().set_position(0, 0);
+    driver.get_mut(w2).unwrap().set_position(1, 1);
+    driver.get_mut(vs).unwrap().set_position(20);
+    driver.get_mut(tab).unwrap().set_current_tab(2);
+    driver.settle();
+    driver.focus(b1);
+    App::load_session(path).unwrap();
+    driver.settle();
+    assert_eq!(driver.bounds(w), Some(Rect::new(5, 3, 44, 17)));
+    assert_eq!(driver.bounds(w2), Some(Rect::new(50, 5, 69, 12)));
+    assert_eq!(driver.get(vs).unwrap().position(), 10);
+    assert_eq!(driver.get(tab).unwrap().current_tab(), Some(1));
+    assert!(driver.bounds(tb).is_some());
+    assert!(driver.has_focus(b2));
+
+    // invalid session files
+    std::fs::write(path, "x = 1").unwrap();
+    assert_eq!(App::load_session(path).err().unwrap().kind, ErrorKind::InvalidParameter);
+    std::fs::remove_file(path).unwrap();
+    assert_eq!(App::load_session(path).err().unwrap().kind, ErrorKind::InvalidParameter);
+}
+
+#[test]
+fn check_session_percentage_splitters() {
+    use crate::prelude::*;
+    use crate::testing::TestDriver;
+
+    let path = std::env::temp_dir().join(format!("appcui_session_pct_{}.txt", std::process::id()));
+    let path = path.to_str().unwrap();
+    let mut driver = TestDriver::new(App::debug(80, 25, "")).unwrap();
+    let mut w = Window::new("Split", layout!("x:0,y:0,w:42,h:20"), window::Flags::Sizeable);
+    let vs = w.add(VSplitter::new(0.5, layout!("x:0,y:0,w:100%,h:10"), vsplitter::ResizeBehavior::PreserveAspectRatio));
+    let hs = w.add(HSplitter::new(0.25, layout!("l:0,t:10,r:0,b:0"), hsplitter::ResizeBehavior::PreserveAspectRatio));
+    let w = driver.add_window(w);
+    driver.settle();
+    App::save_session(path).unwrap();
+    let text = std::fs::read_to_string(path).unwrap();
+    assert!(text.contains("[window:Split/0]\npercentage = 0.5\n"));
+    assert!(text.contains("[window:Split/1]\npercentage = 0.25\n"));
+
+    // an absolute position is replaced by the saved percentage
+    driver.get_mut(vs).unwrap().set_position(5);
+    driver.get_mut(hs).unwrap().set_position(1);
+    driver.settle();
+    App::load_session(path).unwrap();
+    driver.settle();
+    assert_eq!(driver.get(vs).unwrap().position(), 19);
+    assert_eq!(driver.get(hs).unwrap().position(), 1);
+
+    // the restored positions scale with the size of the splitters
+    driver.get_mut(w).unwrap().set_size(62, 24);
+    driver.settle();
+    let vs = driver.get(vs).unwrap();
+    assert!(vs.size().width > 40);
+    assert_eq!(vs.position(), ((vs.size().width - 1) as f32 * 0.5) as i32);
+    let hs = driver.get(hs).unwrap();
+    assert!(hs.size().height > 9);
+    assert_eq!(hs.position(), ((hs.size().height - 1) as f32 * 0.25) as i32);
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn check_session_focus_with_slash_in_title() {
+    use crate::prelude::*;
+    use crate::testing::TestDriver;
+
+    let path = std::env::temp_dir().join(format!("appcui_session_focus_{}.txt", std::process::id()));
+    let path = path.to_str().unwrap();
+    let mut driver = TestDriver::new(App::debug(80, 25, "")).unwrap();
+    let mut w = Window::new("/home/user/notes.txt", layout!("x:0,y:0,w:40,h:10"), window::Flags::None);
+    let b1 = w.add(Button::new("First", layout!("x:1,y:1,w:10"), button::Type::Normal));
+    let b2 = w.add(Button::new("Second", layout!("x:1,y:3,w:10"), button::Type::Normal));
+    driver.add_window(w);
+    driver.settle();
+    driver.focus(b2);
+    App::save_session(path).unwrap();
+    let text = std::fs::read_to_string(path).unwrap();
+    assert!(text.contains("focus = window:/home/user/notes.txt\nfocus_path = 1\n"));
+
+    driver.focus(b1);
+    App::load_session(path).unwrap();
+    driver.settle();
+    assert!(driver.has_focus(b2));
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn check_child_events() {
+    use crate::prelude::*;
+    use crate::testing::TestDriver;
+
+    #[CustomContainer(overwrite = OnChildEvent, internal = true)]
+    struct Counter {
+        events: Vec<Handle<()>>,
+    }
+    impl Counter {
+        fn new(layout: Layout) -> Self {
+            Self {
+                base: ContainerBase::new(layout, true),
+                events: Vec::new(),
+            }
+        }
+    }
+    impl OnChildEvent for Counter {
+        fn on_child_event(&mut self, handle: Handle<()>) {
+            self.events.push(handle);
+        }
+    }
+
+    let mut driver = TestDriver::new(App::debug(60, 12, "")).unwrap();
+    let mut w = Window::new("Test", layout!("x:0,y:0,w:40,h:10"), window::Flags::None);
+    let mut counter = Counter::new(layout!("x:0,y:0,w:30,h:6"));
+    let direct = counter.add(CheckBox::new("Direct", layout!("x:0,y:0,w:20"), false));
+    // a panel does not overwrite OnChildEvent (the event reaches the counter through it)
+    let mut panel = Panel::new("", layout!("x:0,y:1,w:28,h:4"), panel::Type::Border);
+    let nested = panel.add(CheckBox::new("Nested", layout!("x:0,y:0,w:20"), false));
+    counter.add(panel);
+    let counter = w.add(counter);
+    let outside = w.add(CheckBox::new("Outside", layout!("x:0,y:7,w:20"), false));
+    driver.add_window(w);
+    driver.settle();
+
+    driver.press_on(direct, key!("Space"));
+    driver.press_on(nested, key!("Space"));
+    driver.press_on(outside, key!("Space"));
+    assert!(driver.get(outside).unwrap().is_checked());
+    assert_eq!(driver.get(counter).unwrap().events, vec![direct.cast::<()>(), nested.cast::<()>()]);
+}
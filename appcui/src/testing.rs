@@ -0,0 +1,30 @@
+//! # Testing Module
+//!
+//! The testing module provides helpers for testing AppCUI based applications (from regular `#[test]` functions):
+//! - **Snapshot testing** - the content of a [`Surface`](crate::graphics::Surface) is compared against a golden file
+//!   stored on disk (either as plain text or as text with ANSI escape sequences for colors and attributes).
+//! - **Test driver** - a [`TestDriver`] runs an application step by step, finds controls by type, caption, window title
+//!   or tag, reads their state and sends keys or clicks to them (without using screen coordinates).
+//!
+//! If a snapshot does not match, the error message contains a readable cell-level diff (characters, colors and
+//! character flags). Golden files are created or updated by running the tests with the `APPCUI_UPDATE_SNAPSHOTS`
+//! environment variable set:
+//! ```bash
+//! APPCUI_UPDATE_SNAPSHOTS=1 cargo test
+//! ```
+//!
+//! Snapshots can also be checked from a debug script (see `App::debug`) via the `CheckSnapshot("<path>")` command.
+
+mod driver;
+mod snapshot;
+#[cfg(test)]
+mod tests;
+
+pub use self::driver::Query;
+pub use self::driver::TestDriver;
+pub use self::snapshot::assert_snapshot;
+pub use self::snapshot::check_snapshot;
+pub use self::snapshot::update_snapshots_enabled;
+pub use self::snapshot::Snapshot;
+pub use self::snapshot::SnapshotFormat;
+pub use self::snapshot::UPDATE_SNAPSHOTS_ENV;
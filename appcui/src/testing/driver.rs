@@ -0,0 +1,573 @@
+use std::any::TypeId;
+use std::marker::PhantomData;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use super::{Snapshot, SnapshotFormat};
+use crate::graphics::{Point, Rect, Size, Surface};
+use crate::input::{Key, KeyCode, KeyModifier, MouseButton};
+use crate::system::{
+    App, Builder, Error, ErrorKind, Handle, KeyPressedEvent, MouseButtonDownEvent, MouseButtonUpEvent, MouseDoubleClickEvent, MouseMoveEvent,
+    RuntimeManager, SystemEvent,
+};
+use crate::ui::common::traits::{Control, NotModalWindow, WindowControl};
+use crate::ui::common::ControlManager;
+use crate::ui::{Button, CheckBox, Desktop, Label, Panel, RadioBox, ThreeStateBox, ToggleButton, Window};
+
+/// Drives an AppCUI application from a regular `#[test]` function.
+///
+/// Unlike a debug script (see `App::debug`) that uses screen coordinates, a test driver finds controls
+/// by their type, caption, the title or the tag of the window they belong to, reads their state and sends
+/// keys or mouse clicks to them. Every action is processed synchronously (including the events fired by the
+/// controls as a result of that action) so that the state of the application can be checked right after.
+///
+/// Timers and background tasks run on their own threads. Their events are processed when the driver
+/// settles (after every action) or when one of the `wait...` methods is called.
+///
+/// # Example
+/// ```rust
+/// use appcui::prelude::*;
+/// use appcui::testing::TestDriver;
+///
+/// let mut driver = TestDriver::new(App::debug(60, 10, "")).unwrap();
+/// let mut w = window!("'Login',a:c,w:30,h:7");
+/// w.add(checkbox!("'Remember me',x:1,y:1,w:20"));
+/// driver.add_window(w);
+///
+/// let h = driver.find::<CheckBox>().caption("Remember me").first().unwrap();
+/// driver.click(h);
+/// assert!(driver.get(h).unwrap().is_checked());
+/// ```
+///
+/// # Modal windows
+/// A modal window (see `ModalWindow::show`) runs its own execution loop and only returns once it is closed.
+/// As such, the input for a modal window must be queued (via [`TestDriver::queue_key`] or [`TestDriver::queue_text`])
+/// **before** the action that opens it.
+pub struct TestDriver {
+    app: App,
+    queued: Vec<SystemEvent>,
+}
+
+impl TestDriver {
+    /// Creates a new test driver from an application builder. The builder must either be created with
+    /// `App::debug(width, height, "")` (an empty script) or with `App::new()` (in which case the size of the
+    /// virtual terminal can be set via `.size(...)`). The application always uses the debug backend.
+    pub fn new(builder: Builder) -> Result<Self, Error> {
+        let mut builder = builder;
+        if builder.debug_script.as_ref().map(|s| !s.trim().is_empty()).unwrap_or(false) {
+            return Err(Error::new(
+                ErrorKind::InvalidParameter,
+                "A test driver can not be used together with a debug script (use `App::debug(width, height, \"\")` instead) !".to_string(),
+            ));
+        }
+        builder.debug_script = Some(String::new());
+        builder.test_driver = true;
+        Ok(Self {
+            app: builder.build()?,
+            queued: Vec::new(),
+        })
+    }
+
+    /// Adds a new window to the application and returns a handle towards it.
+    pub fn add_window<T>(&mut self, window: T) -> Handle<T>
+    where
+        T: Control + WindowControl + NotModalWindow + 'static,
+    {
+        let h = self.app.add_window(window);
+        self.settle();
+        h
+    }
+
+    /// Creates a query that searches for controls of type `T`. Use `Window` as a type to search for windows
+    /// (including the ones created with the `#[Window]` macro).
+    pub fn find<T: Control + 'static>(&self) -> Query<'_, T> {
+        Query::new()
+    }
+
+    /// Creates a query that searches for controls of any type.
+    pub fn find_any(&self) -> Query<'_, ()> {
+        Query::new()
+    }
+
+    /// Returns a reference to a control (or `None` if the handle is no longer valid).
+    pub fn get<T: Control + 'static>(&self, handle: Handle<T>) -> Option<&T> {
+        Self::manager(handle.cast())?;
+        RuntimeManager::get().get_control(handle)
+    }
+
+    /// Returns a mutable reference to a control (or `None` if the handle is no longer valid).
+    /// Changes made through this reference are applied (layout, repaint) the next time the driver settles.
+    pub fn get_mut<T: Control + 'static>(&mut self, handle: Handle<T>) -> Option<&mut T> {
+        Self::manager(handle.cast())?;
+        RuntimeManager::get().request_update();
+        RuntimeManager::get().get_control_mut(handle)
+    }
+
+    /// Returns the screen area occupied by the visible part of a control (or `None` if the handle is not
+    /// valid or the control is not visible).
+    pub fn bounds<T>(&self, handle: Handle<T>) -> Option<Rect> {
+        let m = Self::manager(handle.cast())?;
+        let clip = &m.base().screen_clip;
+        if !clip.is_visible() {
+            return None;
+        }
+        // a control is visible only if all of its parents are visible
+        let mut base = m.base();
+        loop {
+            if !base.is_visible() {
+                return None;
+            }
+            match Self::manager(base.parent) {
+                Some(parent) => base = parent.base(),
+                None => break,
+            }
+        }
+        Some(Rect::new(clip.left, clip.top, clip.right, clip.bottom))
+    }
+
+    /// Returns the caption of a control (for buttons, checkboxes, labels, etc.) or the title of a window or panel.
+    pub fn caption<T>(&self, handle: Handle<T>) -> Option<String> {
+        caption_of(Self::manager(handle.cast())?).map(|s| s.to_string())
+    }
+
+    /// Returns `true` if the control has the focus.
+    pub fn has_focus<T>(&self, handle: Handle<T>) -> bool {
+        Self::manager(handle.cast()).map(|m| m.base().has_focus()).unwrap_or(false)
+    }
+
+    /// Moves the focus to a specific control.
+    #[track_caller]
+    pub fn focus<T>(&mut self, handle: Handle<T>) {
+        self.check_running();
+        if Self::manager(handle.cast()).is_none() {
+            panic!("TestDriver::focus - invalid control handle ({handle:?}) !");
+        }
+        RuntimeManager::get().request_focus_for_control(handle.cast());
+        self.settle();
+    }
+
+    /// Simulates a key press (a key code, a character, or a key combination).
+    pub fn press<K: Into<Key>>(&mut self, key: K) {
+        let key = key.into();
+        let character = Self::key_to_char(key);
+        self.send(SystemEvent::KeyPressed(KeyPressedEvent { key, character }));
+        self.settle();
+    }
+
+    /// Moves the focus to a control and simulates a key press.
+    #[track_caller]
+    pub fn press_on<T, K: Into<Key>>(&mut self, handle: Handle<T>, key: K) {
+        self.focus(handle);
+        self.press(key);
+    }
+
+    /// Simulates typing a text (one key press for every character).
+    pub fn type_text(&mut self, text: &str) {
+        for ch in text.chars() {
+            self.send(SystemEvent::KeyPressed(KeyPressedEvent {
+                key: Key::from(ch),
+                character: ch,
+            }));
+        }
+        self.settle();
+    }
+
+    /// Queues a key press without processing it. Queued keys are sent after the events of the next action
+    /// (or when the driver settles) so that they can be received by a modal window opened by that action.
+    pub fn queue_key<K: Into<Key>>(&mut self, key: K) {
+        let key = key.into();
+        let character = Self::key_to_char(key);
+        self.queued.push(SystemEvent::KeyPressed(KeyPressedEvent { key, character }));
+    }
+
+    /// Queues a text (one key press for every character) without processing it (see [`TestDriver::queue_key`]).
+    pub fn queue_text(&mut self, text: &str) {
+        for ch in text.chars() {
+            self.queued.push(SystemEvent::KeyPressed(KeyPressedEvent {
+                key: Key::from(ch),
+                character: ch,
+            }));
+        }
+    }
+
+    /// Simulates a resize of the terminal (all layouts, including the ones selected by breakpoints, are re-computed).
+    pub fn resize(&mut self, width: u32, height: u32) {
+        self.send(SystemEvent::Resize(Size::new(width, height)));
+        self.settle();
+    }
+
+    /// Simulates a left mouse click in the middle of the visible area of a control.
+    /// Panics if the control is not visible.
+    #[track_caller]
+    pub fn click<T>(&mut self, handle: Handle<T>) {
+        let p = self.center_of(handle.cast());
+        self.click_at(p.x, p.y);
+    }
+
+    /// Simulates a double click in the middle of the visible area of a control.
+    /// Panics if the control is not visible.
+    #[track_caller]
+    pub fn double_click<T>(&mut self, handle: Handle<T>) {
+        let p = self.center_of(handle.cast());
+        self.move_mouse(p.x, p.y);
+        self.send(SystemEvent::MouseDoubleClick(MouseDoubleClickEvent {
+            x: p.x,
+            y: p.y,
+            button: MouseButton::Left,
+        }));
+        self.settle();
+    }
+
+    /// Simulates a left mouse click at a specific screen position.
+    pub fn click_at(&mut self, x: i32, y: i32) {
+        self.move_mouse(x, y);
+        self.send(SystemEvent::MouseButtonDown(MouseButtonDownEvent {
+            x,
+            y,
+            button: MouseButton::Left,
+        }));
+        self.send(SystemEvent::MouseButtonUp(MouseButtonUpEvent {
+            x,
+            y,
+            button: MouseButton::None,
+        }));
+        self.settle();
+    }
+
+    /// Simulates dragging the mouse (with the left button pressed) from one screen position to another. The mouse
+    /// goes through every intermediate position on the line between the two points.
+    pub fn drag(&mut self, from_x: i32, from_y: i32, to_x: i32, to_y: i32) {
+        self.move_mouse(from_x, from_y);
+        self.send(SystemEvent::MouseButtonDown(MouseButtonDownEvent {
+            x: from_x,
+            y: from_y,
+            button: MouseButton::Left,
+        }));
+        let steps = (to_x - from_x).abs().max((to_y - from_y).abs());
+        for step in 1..=steps {
+            let x = from_x + (to_x - from_x) * step / steps;
+            let y = from_y + (to_y - from_y) * step / steps;
+            self.send(SystemEvent::MouseMove(MouseMoveEvent {
+                x,
+                y,
+                button: MouseButton::Left,
+            }));
+        }
+        self.send(SystemEvent::MouseButtonUp(MouseButtonUpEvent {
+            x: to_x,
+            y: to_y,
+            button: MouseButton::None,
+        }));
+        self.settle();
+    }
+
+    /// Simulates moving the mouse (without any button pressed) to a specific screen position.
+    pub fn hover_at(&mut self, x: i32, y: i32) {
+        self.move_mouse(x, y);
+        self.settle();
+    }
+
+    /// Returns a handle to the desktop of the application.
+    pub fn desktop(&self) -> Handle<Desktop> {
+        RuntimeManager::get().desktop_handle().cast()
+    }
+
+    /// Processes all pending requests (events fired by controls, focus changes, layout updates and repaints) and
+    /// all the events that are already available from timers and background tasks.
+    pub fn settle(&mut self) {
+        let rm = RuntimeManager::get();
+        if rm.is_running() {
+            for event in self.queued.drain(..) {
+                let _ = rm.get_system_event_sender().send(event);
+            }
+        }
+        Self::process_available_events();
+    }
+
+    /// Processes all events received in the next `duration` (e.g. timer ticks).
+    pub fn wait(&mut self, duration: Duration) {
+        let deadline = Instant::now() + duration;
+        self.wait_until_deadline(deadline, |_| false);
+    }
+
+    /// Processes events until all background tasks have finished or the timeout expires.
+    /// Returns `true` if all background tasks have finished.
+    pub fn wait_for_background_tasks(&mut self, timeout: Duration) -> bool {
+        let deadline = Instant::now() + timeout;
+        self.wait_until_deadline(deadline, |_| RuntimeManager::get().get_background_task_manager().running_tasks() == 0)
+    }
+
+    /// Processes events until a condition becomes true or the timeout expires.
+    /// Returns `true` if the condition was met.
+    pub fn wait_until<F: Fn(&TestDriver) -> bool>(&mut self, timeout: Duration, condition: F) -> bool {
+        let deadline = Instant::now() + timeout;
+        self.wait_until_deadline(deadline, condition)
+    }
+
+    /// Returns `false` if the application was closed (e.g. via a `Ctrl+Q` shortcut or by closing the window of a
+    /// single window application).
+    pub fn is_running(&self) -> bool {
+        RuntimeManager::get().is_running()
+    }
+
+    /// The current content of the virtual screen.
+    pub fn screen(&self) -> &Surface {
+        RuntimeManager::get().surface()
+    }
+
+    /// The current content of the virtual screen as text (one line for every row).
+    pub fn screen_text(&self) -> String {
+        Snapshot::new(self.screen(), SnapshotFormat::Text).text()
+    }
+
+    /// Compares the virtual screen with a golden file (see [`assert_snapshot`](super::assert_snapshot)).
+    #[track_caller]
+    pub fn assert_snapshot<P: AsRef<Path>>(&self, path: P) {
+        super::assert_snapshot(path, self.screen());
+    }
+
+    fn wait_until_deadline<F: Fn(&TestDriver) -> bool>(&mut self, deadline: Instant, condition: F) -> bool {
+        loop {
+            self.settle();
+            if condition(self) {
+                return true;
+            }
+            let now = Instant::now();
+            if now >= deadline || !self.is_running() {
+                return false;
+            }
+            let rm = RuntimeManager::get();
+            if let Some(event) = rm.receive_system_event(Some(deadline - now)) {
+                rm.process_system_event(event);
+            }
+        }
+    }
+
+    fn process_available_events() {
+        let rm = RuntimeManager::get();
+        loop {
+            rm.process_pending_requests();
+            while rm.is_running() && rm.has_pending_requests() {
+                rm.process_pending_requests();
+            }
+            if !rm.is_running() {
+                break;
+            }
+            if let Some(event) = rm.receive_system_event(None) {
+                rm.process_system_event(event);
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn move_mouse(&mut self, x: i32, y: i32) {
+        self.send(SystemEvent::MouseMove(MouseMoveEvent {
+            x,
+            y,
+            button: MouseButton::None,
+        }));
+    }
+
+    #[track_caller]
+    fn send(&mut self, event: SystemEvent) {
+        self.check_running();
+        let _ = RuntimeManager::get().get_system_event_sender().send(event);
+    }
+
+    #[track_caller]
+    fn check_running(&self) {
+        if !self.is_running() {
+            panic!("TestDriver: the application has been closed !");
+        }
+    }
+
+    #[track_caller]
+    fn center_of(&mut self, handle: Handle<()>) -> Point {
+        // make sure that the layout is up to date (queued events are kept for the next action)
+        Self::process_available_events();
+        if let Some(r) = self.bounds(handle) {
+            r.center()
+        } else {
+            panic!("TestDriver: the control ({handle:?}) is not visible (or the handle is invalid) and can not be clicked !");
+        }
+    }
+
+    fn key_to_char(key: Key) -> char {
+        if key.modifier != KeyModifier::None && key.modifier != KeyModifier::Shift {
+            return '\0';
+        }
+        let shift = key.modifier == KeyModifier::Shift;
+        let code = key.code as u8;
+        match key.code {
+            _ if (KeyCode::A as u8..=KeyCode::Z as u8).contains(&code) => {
+                let ch = (b'a' + code - KeyCode::A as u8) as char;
+                if shift {
+                    ch.to_ascii_uppercase()
+                } else {
+                    ch
+                }
+            }
+            _ if (KeyCode::N0 as u8..=KeyCode::N9 as u8).contains(&code) && !shift => (b'0' + code - KeyCode::N0 as u8) as char,
+            KeyCode::Space => ' ',
+            _ => '\0',
+        }
+    }
+
+    fn manager(handle: Handle<()>) -> Option<&'static ControlManager> {
+        RuntimeManager::get().get_controls().get(handle)
+    }
+}
+
+impl Drop for TestDriver {
+    fn drop(&mut self) {
+        RuntimeManager::get().backend_mut().on_close();
+        crate::dialogs::clear_last_path();
+    }
+}
+
+/// A query used by a [`TestDriver`] to find controls. All conditions have to be met for a control to be selected.
+/// Controls are visited in depth-first order, starting with the windows from the desktop (in the order they
+/// were added) followed by the modal windows.
+pub struct Query<'a, T> {
+    caption: Option<String>,
+    window_title: Option<String>,
+    window_tag: Option<String>,
+    only_visible: bool,
+    _phantom: PhantomData<&'a T>,
+}
+
+impl<T: 'static> Query<'_, T> {
+    fn new() -> Self {
+        Self {
+            caption: None,
+            window_title: None,
+            window_tag: None,
+            only_visible: false,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Only selects controls with a specific caption (for buttons, checkboxes, labels, etc.) or title (for windows and panels).
+    pub fn caption(mut self, caption: &str) -> Self {
+        self.caption = Some(caption.to_string());
+        self
+    }
+
+    /// Only selects controls from a window with a specific title (a window is considered to be part of itself).
+    pub fn in_window(mut self, title: &str) -> Self {
+        self.window_title = Some(title.to_string());
+        self
+    }
+
+    /// Only selects controls from a window with a specific tag (see `Window::set_tag`).
+    pub fn with_tag(mut self, tag: &str) -> Self {
+        self.window_tag = Some(tag.to_string());
+        self
+    }
+
+    /// Only selects visible controls.
+    pub fn visible(mut self) -> Self {
+        self.only_visible = true;
+        self
+    }
+
+    /// Returns the first control that matches the query.
+    pub fn first(&self) -> Option<Handle<T>> {
+        self.all().into_iter().next()
+    }
+
+    /// Returns the only control that matches the query. Panics if no control or more than one control match the query.
+    #[track_caller]
+    pub fn single(&self) -> Handle<T> {
+        let list = self.all();
+        if list.len() != 1 {
+            panic!("TestDriver: expecting exactly one control to match the query, but found {} !", list.len());
+        }
+        list[0]
+    }
+
+    /// Returns all the controls that match the query.
+    pub fn all(&self) -> Vec<Handle<T>> {
+        let rm = RuntimeManager::get();
+        let mut result = Vec::new();
+        if let Some(desktop) = rm.get_controls().get(rm.desktop_handle()) {
+            for child in desktop.base().children.iter() {
+                self.visit(*child, None, &mut result);
+            }
+        }
+        for modal in rm.modal_windows() {
+            self.visit(*modal, None, &mut result);
+        }
+        result
+    }
+
+    /// Returns the number of controls that match the query.
+    pub fn count(&self) -> usize {
+        self.all().len()
+    }
+
+    fn visit(&self, handle: Handle<()>, window: Option<&Window>, result: &mut Vec<Handle<T>>) {
+        let Some(m) = RuntimeManager::get().get_controls().get(handle) else {
+            return;
+        };
+        if self.only_visible && !m.base().is_visible() {
+            // the children of a hidden control are hidden as well
+            return;
+        }
+        let window = if m.base().is_window_control() { Some(m.get::<Window>()) } else { window };
+        if self.matches(m, window) {
+            result.push(handle.cast());
+        }
+        for child in m.base().children.iter() {
+            self.visit(*child, window, result);
+        }
+    }
+
+    fn matches(&self, m: &ControlManager, window: Option<&Window>) -> bool {
+        let type_id = TypeId::of::<T>();
+        if type_id == TypeId::of::<Window>() {
+            if !m.base().is_window_control() {
+                return false;
+            }
+        } else if type_id != TypeId::of::<()>() && m.type_id() != type_id {
+            return false;
+        }
+        if self.only_visible && !m.base().is_visible() {
+            return false;
+        }
+        if let Some(caption) = &self.caption {
+            if caption_of(m) != Some(caption.as_str()) {
+                return false;
+            }
+        }
+        if let Some(title) = &self.window_title {
+            if window.map(|w| w.title()) != Some(title.as_str()) {
+                return false;
+            }
+        }
+        if let Some(tag) = &self.window_tag {
+            if window.and_then(|w| w.tag()) != Some(tag.as_str()) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+fn caption_of(m: &ControlManager) -> Option<&str> {
+    if m.base().is_window_control() {
+        return Some(m.get::<Window>().title());
+    }
+    let t = m.type_id();
+    match () {
+        _ if t == TypeId::of::<Button>() => Some(m.get::<Button>().caption()),
+        _ if t == TypeId::of::<CheckBox>() => Some(m.get::<CheckBox>().caption()),
+        _ if t == TypeId::of::<RadioBox>() => Some(m.get::<RadioBox>().caption()),
+        _ if t == TypeId::of::<ThreeStateBox>() => Some(m.get::<ThreeStateBox>().caption()),
+        _ if t == TypeId::of::<ToggleButton>() => Some(m.get::<ToggleButton>().caption()),
+        _ if t == TypeId::of::<Label>() => Some(m.get::<Label>().caption()),
+        _ if t == TypeId::of::<Panel>() => Some(m.get::<Panel>().title()),
+        _ => None,
+    }
+}
@@ -0,0 +1,498 @@
+use std::fmt::Write as FmtWrite;
+use std::path::Path;
+
+use crate::graphics::{CharFlags, Character, Color, Size, Surface};
+
+/// The environment variable that enables the **update snapshots** mode. If it is set (to any value except `0`
+/// or `false`), the golden files are (re)written with the current content of the surface instead of being compared.
+pub const UPDATE_SNAPSHOTS_ENV: &str = "APPCUI_UPDATE_SNAPSHOTS";
+
+// maximum number of cell differences that are listed in a diff
+const MAX_REPORTED_CELLS: usize = 32;
+
+/// The format of a golden (snapshot) file.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SnapshotFormat {
+    /// Plain text (one line for every row of the surface). Only the characters are compared.
+    Text,
+    /// Text with ANSI (SGR) escape sequences for colors and character flags. Characters, colors and
+    /// attributes are compared. The file can be viewed with `cat` in any terminal that supports ANSI codes.
+    Ansi,
+}
+
+impl SnapshotFormat {
+    /// Returns the format associated with a path: `Ansi` for files with the `.ansi` extension and `Text` otherwise.
+    pub fn from_path(path: &Path) -> SnapshotFormat {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("ansi") => SnapshotFormat::Ansi,
+            _ => SnapshotFormat::Text,
+        }
+    }
+}
+
+/// A textual representation of the content of a [`Surface`] (characters, and for the `Ansi` format, colors and flags)
+/// that can be saved into a golden file and compared with the content of another surface.
+///
+/// # Example
+/// ```rust
+/// use appcui::prelude::*;
+/// use appcui::testing::{Snapshot, SnapshotFormat};
+///
+/// let s = Surface::from_string("Hello", Size::new(5, 1));
+/// let snapshot = Snapshot::new(&s, SnapshotFormat::Text);
+/// assert_eq!(snapshot.text(), "Hello\n");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Snapshot {
+    size: Size,
+    cells: Vec<Character>,
+    format: SnapshotFormat,
+}
+
+impl Snapshot {
+    /// Creates a snapshot from the content of a surface.
+    pub fn new(surface: &Surface, format: SnapshotFormat) -> Self {
+        Self {
+            size: surface.size,
+            cells: surface.chars.clone(),
+            format,
+        }
+    }
+
+    /// Parses the content of a golden file.
+    pub fn parse(text: &str, format: SnapshotFormat) -> Result<Self, String> {
+        let mut rows: Vec<Vec<Character>> = Vec::new();
+        for (index, line) in text.lines().enumerate() {
+            let row = match format {
+                SnapshotFormat::Text => line
+                    .chars()
+                    .map(|c| Character::new(c, Color::White, Color::Black, CharFlags::None))
+                    .collect(),
+                SnapshotFormat::Ansi => Self::parse_ansi_line(line).map_err(|e| format!("Invalid ANSI snapshot (line {}): {}", index + 1, e))?,
+            };
+            rows.push(row);
+        }
+        let width = rows.first().map(|r| r.len()).unwrap_or(0);
+        if let Some(index) = rows.iter().position(|r| r.len() != width) {
+            return Err(format!(
+                "Invalid snapshot: line {} has {} characters (expecting {} characters as the first line)",
+                index + 1,
+                rows[index].len(),
+                width
+            ));
+        }
+        Ok(Self {
+            size: Size::new(width as u32, rows.len() as u32),
+            cells: rows.into_iter().flatten().collect(),
+            format,
+        })
+    }
+
+    /// Returns the size (width and height) of the snapshot.
+    #[inline(always)]
+    pub fn size(&self) -> Size {
+        self.size
+    }
+
+    /// Returns the format of the snapshot.
+    #[inline(always)]
+    pub fn format(&self) -> SnapshotFormat {
+        self.format
+    }
+
+    /// Returns the character from a specific position (or `None` if the position is outside the snapshot).
+    pub fn char(&self, x: u32, y: u32) -> Option<&Character> {
+        if x >= self.size.width || y >= self.size.height {
+            return None;
+        }
+        self.cells.get((y * self.size.width + x) as usize)
+    }
+
+    /// Returns the content of the snapshot, as it is saved in a golden file.
+    pub fn text(&self) -> String {
+        let mut output = String::with_capacity(self.cells.len() * 2);
+        for row in self.rows() {
+            match self.format {
+                SnapshotFormat::Text => {
+                    for ch in row {
+                        output.push(Self::printable(ch.code));
+                    }
+                }
+                SnapshotFormat::Ansi => {
+                    let mut last: Option<(Color, Color, CharFlags)> = None;
+                    for ch in row {
+                        let attr = (ch.foreground, ch.background, ch.flags);
+                        if last != Some(attr) {
+                            Self::write_sgr(&mut output, ch);
+                            last = Some(attr);
+                        }
+                        output.push(Self::printable(ch.code));
+                    }
+                    output.push_str("\x1b[0m");
+                }
+            }
+            output.push('\n');
+        }
+        output
+    }
+
+    /// Compares two snapshots and returns a readable description of the differences (or `None` if they are identical).
+    /// Colors and character flags are only compared if both snapshots use the `Ansi` format.
+    pub fn diff(&self, actual: &Snapshot) -> Option<String> {
+        if self.size != actual.size {
+            return Some(format!(
+                "Snapshot size is different: expecting {}x{} but found {}x{}\n\nExpected:\n{}\nFound:\n{}",
+                self.size.width,
+                self.size.height,
+                actual.size.width,
+                actual.size.height,
+                self.plain_text(),
+                actual.plain_text()
+            ));
+        }
+        let compare_attributes = self.format == SnapshotFormat::Ansi && actual.format == SnapshotFormat::Ansi;
+        let mut cells = String::new();
+        let mut rows = String::new();
+        let mut count = 0usize;
+        for y in 0..self.size.height {
+            let mut markers = String::new();
+            let mut row_is_different = false;
+            for x in 0..self.size.width {
+                let e = self.char(x, y).unwrap();
+                let a = actual.char(x, y).unwrap();
+                let mut changes: Vec<String> = Vec::new();
+                if Self::printable(e.code) != Self::printable(a.code) {
+                    changes.push(format!("char {:?} -> {:?}", e.code, a.code));
+                }
+                if compare_attributes {
+                    if e.foreground != a.foreground {
+                        changes.push(format!(
+                            "foreground {} -> {}",
+                            Self::color_name(e.foreground),
+                            Self::color_name(a.foreground)
+                        ));
+                    }
+                    if e.background != a.background {
+                        changes.push(format!(
+                            "background {} -> {}",
+                            Self::color_name(e.background),
+                            Self::color_name(a.background)
+                        ));
+                    }
+                    if e.flags != a.flags {
+                        changes.push(format!("flags {} -> {}", Self::flags_name(e.flags), Self::flags_name(a.flags)));
+                    }
+                }
+                if changes.is_empty() {
+                    markers.push(' ');
+                    continue;
+                }
+                row_is_different = true;
+                markers.push('^');
+                if count < MAX_REPORTED_CELLS {
+                    let _ = writeln!(cells, "  ({x},{y}): {}", changes.join(", "));
+                }
+                count += 1;
+            }
+            if row_is_different {
+                let _ = writeln!(rows, "  row {y:>3} expected: |{}|", self.plain_row(y));
+                let _ = writeln!(rows, "          found   : |{}|", actual.plain_row(y));
+                let _ = writeln!(rows, "                     {}", markers.trim_end());
+            }
+        }
+        if count == 0 {
+            return None;
+        }
+        if count > MAX_REPORTED_CELLS {
+            let _ = writeln!(cells, "  ... and {} more", count - MAX_REPORTED_CELLS);
+        }
+        Some(format!(
+            "{count} cell(s) are different:\n{rows}\nCell differences (expected -> found):\n{cells}"
+        ))
+    }
+
+    fn rows(&self) -> impl Iterator<Item = &[Character]> {
+        self.cells.chunks(self.size.width.max(1) as usize)
+    }
+
+    fn plain_row(&self, y: u32) -> String {
+        let start = (y * self.size.width) as usize;
+        let end = start + self.size.width as usize;
+        self.cells[start..end].iter().map(|c| Self::printable(c.code)).collect()
+    }
+
+    fn plain_text(&self) -> String {
+        (0..self.size.height).map(|y| format!("|{}|\n", self.plain_row(y))).collect()
+    }
+
+    #[inline(always)]
+    fn printable(code: char) -> char {
+        if code < ' ' || code == '\x7f' {
+            ' '
+        } else {
+            code
+        }
+    }
+
+    fn color_name(color: Color) -> String {
+        if let Some((r, g, b)) = color.rgb() {
+            format!("RGB({r},{g},{b})")
+        } else {
+            color.name().to_string()
+        }
+    }
+
+    fn flags_name(flags: CharFlags) -> String {
+        const NAMES: [(CharFlags, &str); 7] = [
+            (CharFlags::Bold, "Bold"),
+            (CharFlags::Italic, "Italic"),
+            (CharFlags::Underline, "Underline"),
+            (CharFlags::DoubleUnderline, "DoubleUnderline"),
+            (CharFlags::CurlyUnderline, "CurlyUnderline"),
+            (CharFlags::DottedUnderline, "DottedUnderline"),
+            (CharFlags::StrikeThrough, "StrikeThrough"),
+        ];
+        let list: Vec<&str> = NAMES.iter().filter(|(f, _)| flags.contains(*f)).map(|(_, n)| *n).collect();
+        if list.is_empty() {
+            "None".to_string()
+        } else {
+            list.join("|")
+        }
+    }
+
+    fn write_sgr(output: &mut String, ch: &Character) {
+        output.push_str("\x1b[0");
+        Self::write_color(output, ch.foreground, 30, 90);
+        Self::write_color(output, ch.background, 40, 100);
+        if ch.flags.contains(CharFlags::Bold) {
+            output.push_str(";1");
+        }
+        if ch.flags.contains(CharFlags::Italic) {
+            output.push_str(";3");
+        }
+        if ch.flags.contains(CharFlags::Underline) {
+            output.push_str(";4");
+        }
+        if ch.flags.contains(CharFlags::DoubleUnderline) {
+            output.push_str(";21");
+        }
+        if ch.flags.contains(CharFlags::CurlyUnderline) {
+            output.push_str(";4:3");
+        }
+        if ch.flags.contains(CharFlags::DottedUnderline) {
+            output.push_str(";4:4");
+        }
+        if ch.flags.contains(CharFlags::StrikeThrough) {
+            output.push_str(";9");
+        }
+        output.push('m');
+    }
+
+    fn write_color(output: &mut String, color: Color, dark_base: u8, light_base: u8) {
+        // ANSI order: black, red, green, yellow, blue, magenta, cyan, white
+        let code = match color {
+            Color::Black => dark_base,
+            Color::DarkRed => dark_base + 1,
+            Color::DarkGreen => dark_base + 2,
+            Color::Olive => dark_base + 3,
+            Color::DarkBlue => dark_base + 4,
+            Color::Magenta => dark_base + 5,
+            Color::Teal => dark_base + 6,
+            Color::Silver => dark_base + 7,
+            Color::Gray => light_base,
+            Color::Red => light_base + 1,
+            Color::Green => light_base + 2,
+            Color::Yellow => light_base + 3,
+            Color::Blue => light_base + 4,
+            Color::Pink => light_base + 5,
+            Color::Aqua => light_base + 6,
+            Color::White => light_base + 7,
+            Color::Transparent => dark_base + 9,
+            #[cfg(feature = "TRUE_COLORS")]
+            Color::RGB(r, g, b) => {
+                let _ = write!(output, ";{};2;{};{};{}", dark_base + 8, r, g, b);
+                return;
+            }
+        };
+        let _ = write!(output, ";{code}");
+    }
+
+    fn parse_color(code: u32, dark_base: u32, light_base: u32) -> Option<Color> {
+        const ANSI_ORDER: [Color; 8] = [
+            Color::Black,
+            Color::DarkRed,
+            Color::DarkGreen,
+            Color::Olive,
+            Color::DarkBlue,
+            Color::Magenta,
+            Color::Teal,
+            Color::Silver,
+        ];
+        const ANSI_LIGHT_ORDER: [Color; 8] = [
+            Color::Gray,
+            Color::Red,
+            Color::Green,
+            Color::Yellow,
+            Color::Blue,
+            Color::Pink,
+            Color::Aqua,
+            Color::White,
+        ];
+        match code {
+            _ if (dark_base..dark_base + 8).contains(&code) => Some(ANSI_ORDER[(code - dark_base) as usize]),
+            _ if (light_base..light_base + 8).contains(&code) => Some(ANSI_LIGHT_ORDER[(code - light_base) as usize]),
+            _ if code == dark_base + 9 => Some(Color::Transparent),
+            _ => None,
+        }
+    }
+
+    fn apply_sgr(params: &str, fore: &mut Color, back: &mut Color, flags: &mut CharFlags) -> Result<(), String> {
+        let values: Vec<&str> = params.split(';').collect();
+        let mut index = 0;
+        while index < values.len() {
+            let value = values[index];
+            index += 1;
+            match value {
+                "" | "0" => {
+                    *fore = Color::White;
+                    *back = Color::Black;
+                    *flags = CharFlags::None;
+                }
+                "1" => *flags |= CharFlags::Bold,
+                "3" => *flags |= CharFlags::Italic,
+                "4" => *flags |= CharFlags::Underline,
+                "21" => *flags |= CharFlags::DoubleUnderline,
+                "4:3" => *flags |= CharFlags::CurlyUnderline,
+                "4:4" => *flags |= CharFlags::DottedUnderline,
+                "9" => *flags |= CharFlags::StrikeThrough,
+                "38" | "48" => {
+                    if index + 4 > values.len() || values[index] != "2" {
+                        return Err(format!("invalid RGB color sequence '{params}'"));
+                    }
+                    let mut rgb = [0u8; 3];
+                    for (i, c) in rgb.iter_mut().enumerate() {
+                        *c = values[index + 1 + i]
+                            .parse::<u8>()
+                            .map_err(|_| format!("invalid RGB color sequence '{params}'"))?;
+                    }
+                    index += 4;
+                    let color = Color::from_rgb(rgb[0], rgb[1], rgb[2]);
+                    if value == "38" {
+                        *fore = color;
+                    } else {
+                        *back = color;
+                    }
+                }
+                _ => {
+                    let code = value.parse::<u32>().map_err(|_| format!("unknown SGR parameter '{value}'"))?;
+                    if let Some(color) = Self::parse_color(code, 30, 90) {
+                        *fore = color;
+                    } else if let Some(color) = Self::parse_color(code, 40, 100) {
+                        *back = color;
+                    } else {
+                        return Err(format!("unknown SGR parameter '{value}'"));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn parse_ansi_line(line: &str) -> Result<Vec<Character>, String> {
+        let mut row = Vec::new();
+        let mut fore = Color::White;
+        let mut back = Color::Black;
+        let mut flags = CharFlags::None;
+        let mut chars = line.chars();
+        while let Some(c) = chars.next() {
+            if c != '\x1b' {
+                row.push(Character::new(c, fore, back, flags));
+                continue;
+            }
+            if chars.next() != Some('[') {
+                return Err("expecting '[' after the escape character".to_string());
+            }
+            let mut params = String::new();
+            loop {
+                match chars.next() {
+                    Some('m') => break,
+                    Some(ch) if ch.is_ascii_digit() || ch == ';' || ch == ':' => params.push(ch),
+                    _ => return Err("only SGR sequences (ESC [ ... m) are supported".to_string()),
+                }
+            }
+            Self::apply_sgr(&params, &mut fore, &mut back, &mut flags)?;
+        }
+        Ok(row)
+    }
+}
+
+/// Returns `true` if the **update snapshots** mode is enabled (via the `APPCUI_UPDATE_SNAPSHOTS` environment variable).
+pub fn update_snapshots_enabled() -> bool {
+    match std::env::var(UPDATE_SNAPSHOTS_ENV) {
+        Ok(value) => !(value.is_empty() || value == "0" || value.eq_ignore_ascii_case("false")),
+        Err(_) => false,
+    }
+}
+
+/// Compares the content of a surface with a golden file and returns a readable description of the differences
+/// in case of a mismatch. The format of the golden file is deduced from its extension (`.ansi` files store colors
+/// and attributes, any other extension is considered plain text).
+///
+/// If the **update snapshots** mode is enabled (the `APPCUI_UPDATE_SNAPSHOTS` environment variable is set), the golden
+/// file is (re)written with the content of the surface and no comparison is performed.
+pub fn check_snapshot<P: AsRef<Path>>(path: P, surface: &Surface) -> Result<(), String> {
+    check_or_update_snapshot(path.as_ref(), surface, update_snapshots_enabled())
+}
+
+pub(super) fn check_or_update_snapshot(path: &Path, surface: &Surface, update: bool) -> Result<(), String> {
+    let format = SnapshotFormat::from_path(path);
+    let actual = Snapshot::new(surface, format);
+    if update {
+        if let Some(parent) = path.parent() {
+            if !parent.as_os_str().is_empty() {
+                std::fs::create_dir_all(parent).map_err(|e| format!("Fail to create folder '{}': {}", parent.display(), e))?;
+            }
+        }
+        return std::fs::write(path, actual.text()).map_err(|e| format!("Fail to write snapshot '{}': {}", path.display(), e));
+    }
+    let content = match std::fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) => {
+            return Err(format!(
+                "Fail to read snapshot '{}': {}\nRun the tests with {}=1 to create it.",
+                path.display(),
+                e,
+                UPDATE_SNAPSHOTS_ENV
+            ))
+        }
+    };
+    let expected = Snapshot::parse(&content, format)?;
+    if let Some(diff) = expected.diff(&actual) {
+        return Err(format!(
+            "Snapshot '{}' does not match !\n{}\nRun the tests with {}=1 to update the snapshot.",
+            path.display(),
+            diff,
+            UPDATE_SNAPSHOTS_ENV
+        ));
+    }
+    Ok(())
+}
+
+/// Checks that the content of a surface matches a golden file and panics (with a cell-level diff) if it does not.
+/// This function is designed to be used in `#[test]` functions.
+///
+/// # Example
+/// ```rust,no_run
+/// use appcui::prelude::*;
+/// use appcui::testing;
+///
+/// let mut s = Surface::new(20, 3);
+/// s.write_string(1, 1, "Hello", CharAttribute::with_color(Color::Yellow, Color::Blue), false);
+/// testing::assert_snapshot("tests/snapshots/hello.ansi", &s);
+/// ```
+#[track_caller]
+pub fn assert_snapshot<P: AsRef<Path>>(path: P, surface: &Surface) {
+    if let Err(message) = check_snapshot(path, surface) {
+        panic!("{message}");
+    }
+}
@@ -0,0 +1,293 @@
+use std::path::PathBuf;
+
+use std::time::Duration;
+
+use super::snapshot::check_or_update_snapshot;
+use super::{Snapshot, SnapshotFormat, TestDriver};
+use crate::graphics::{CharAttribute, CharFlags, Character, Color, Size, Surface};
+use crate::prelude::*;
+
+fn temp_snapshot_path(name: &str) -> PathBuf {
+    let mut path = std::env::temp_dir();
+    path.push(format!("appcui_snapshots_{}", std::process::id()));
+    path.push(name);
+    path
+}
+
+fn create_surface() -> Surface {
+    let mut s = Surface::new(12, 3);
+    s.clear(Character::new(' ', Color::White, Color::DarkBlue, CharFlags::None));
+    s.write_string(1, 0, "Hello", CharAttribute::with_color(Color::Yellow, Color::DarkBlue), false);
+    s.write_string(1, 1, "World", CharAttribute::new(Color::Red, Color::Black, CharFlags::Bold | CharFlags::Underline), false);
+    s.write_char(0, 2, Character::new('\t', Color::Transparent, Color::Gray, CharFlags::StrikeThrough));
+    s
+}
+
+#[test]
+fn check_snapshot_text_format() {
+    let s = Surface::from_string("abc\nxyz", Size::new(4, 2));
+    let snapshot = Snapshot::new(&s, SnapshotFormat::Text);
+    assert_eq!(snapshot.text(), "abc \nxyz \n");
+    let parsed = Snapshot::parse(&snapshot.text(), SnapshotFormat::Text).unwrap();
+    assert_eq!(parsed.size(), Size::new(4, 2));
+    assert_eq!(parsed.char(1, 1).unwrap().code, 'y');
+    assert!(parsed.char(4, 0).is_none());
+    assert!(parsed.diff(&snapshot).is_none());
+}
+
+#[test]
+fn check_snapshot_ansi_roundtrip() {
+    let s = create_surface();
+    let snapshot = Snapshot::new(&s, SnapshotFormat::Ansi);
+    let text = snapshot.text();
+    assert!(text.starts_with("\x1b[0;97;44m \x1b[0;93;44mHello"));
+    assert!(text.contains("\x1b[0;91;40;1;4mWorld"));
+    // a transparent foreground keeps the previous color of the cell
+    assert!(text.contains("\x1b[0;97;100;9m "));
+    let parsed = Snapshot::parse(&text, SnapshotFormat::Ansi).unwrap();
+    assert_eq!(parsed.size(), Size::new(12, 3));
+    let ch = parsed.char(1, 1).unwrap();
+    assert_eq!(ch.code, 'W');
+    assert_eq!(ch.foreground, Color::Red);
+    assert_eq!(ch.background, Color::Black);
+    assert_eq!(ch.flags, CharFlags::Bold | CharFlags::Underline);
+    // control characters are saved as spaces
+    assert!(parsed.diff(&snapshot).is_none());
+}
+
+#[test]
+fn check_snapshot_parse_errors() {
+    assert!(Snapshot::parse("abc\nab\n", SnapshotFormat::Text).is_err());
+    assert!(Snapshot::parse("\x1b[0;200mabc\n", SnapshotFormat::Ansi).is_err());
+    assert!(Snapshot::parse("\x1b]0;titleabc\n", SnapshotFormat::Ansi).is_err());
+    assert!(Snapshot::parse("\x1b[2Jabc\n", SnapshotFormat::Ansi).is_err());
+    assert!(Snapshot::parse("\x1b[0;38;5;12mabc\n", SnapshotFormat::Ansi).is_err());
+}
+
+#[test]
+fn check_snapshot_parse_ansi_colors() {
+    let parsed = Snapshot::parse("\x1b[0;39;49;21;4:3;4:4;3mA\x1b[0mB\n", SnapshotFormat::Ansi).unwrap();
+    let ch = parsed.char(0, 0).unwrap();
+    assert_eq!(ch.foreground, Color::Transparent);
+    assert_eq!(ch.background, Color::Transparent);
+    assert_eq!(
+        ch.flags,
+        CharFlags::DoubleUnderline | CharFlags::CurlyUnderline | CharFlags::DottedUnderline | CharFlags::Italic
+    );
+    let ch = parsed.char(1, 0).unwrap();
+    assert_eq!(ch.foreground, Color::White);
+    assert_eq!(ch.background, Color::Black);
+    assert_eq!(ch.flags, CharFlags::None);
+}
+
+#[test]
+fn check_snapshot_diff() {
+    let expected = Snapshot::new(&create_surface(), SnapshotFormat::Ansi);
+    let mut s = create_surface();
+    s.write_string(2, 0, "a", CharAttribute::with_color(Color::Yellow, Color::DarkBlue), false);
+    s.write_char(3, 1, Character::new('r', Color::Green, Color::Black, CharFlags::Bold));
+    let diff = expected.diff(&Snapshot::new(&s, SnapshotFormat::Ansi)).unwrap();
+    assert!(diff.starts_with("2 cell(s) are different"));
+    assert!(diff.contains("row   0 expected: | Hello      |"));
+    assert!(diff.contains("        found   : | Hallo      |"));
+    assert!(diff.contains("(2,0): char 'e' -> 'a'"));
+    assert!(diff.contains("(3,1): foreground Red -> Green, flags Bold|Underline -> Bold"));
+
+    // in text mode only the characters are compared
+    let expected = Snapshot::new(&create_surface(), SnapshotFormat::Text);
+    let diff = expected.diff(&Snapshot::new(&s, SnapshotFormat::Text)).unwrap();
+    assert!(diff.starts_with("1 cell(s) are different"));
+
+    let diff = expected.diff(&Snapshot::new(&Surface::new(5, 5), SnapshotFormat::Text)).unwrap();
+    assert!(diff.starts_with("Snapshot size is different: expecting 12x3 but found 5x5"));
+}
+
+#[test]
+fn check_snapshot_file() {
+    let path = temp_snapshot_path("subfolder/surface.ansi");
+    let _ = std::fs::remove_file(&path);
+    let s = create_surface();
+    // missing snapshot
+    let err = check_or_update_snapshot(&path, &s, false).unwrap_err();
+    assert!(err.contains("APPCUI_UPDATE_SNAPSHOTS=1"));
+    // create it
+    assert!(check_or_update_snapshot(&path, &s, true).is_ok());
+    assert!(path.exists());
+    assert!(check_or_update_snapshot(&path, &s, false).is_ok());
+    // change the surface
+    let mut s2 = create_surface();
+    s2.write_char(0, 0, Character::new('X', Color::White, Color::DarkBlue, CharFlags::None));
+    let err = check_or_update_snapshot(&path, &s2, false).unwrap_err();
+    assert!(err.contains("does not match"));
+    assert!(err.contains("(0,0): char ' ' -> 'X'"));
+    // update it
+    assert!(check_or_update_snapshot(&path, &s2, true).is_ok());
+    assert!(check_or_update_snapshot(&path, &s2, false).is_ok());
+    let _ = std::fs::remove_dir_all(path.parent().unwrap().parent().unwrap());
+}
+
+#[test]
+fn check_snapshot_format_from_path() {
+    assert_eq!(SnapshotFormat::from_path(std::path::Path::new("a/b.ansi")), SnapshotFormat::Ansi);
+    assert_eq!(SnapshotFormat::from_path(std::path::Path::new("a/b.ANSI")), SnapshotFormat::Ansi);
+    assert_eq!(SnapshotFormat::from_path(std::path::Path::new("a/b.txt")), SnapshotFormat::Text);
+    assert_eq!(SnapshotFormat::from_path(std::path::Path::new("a/b")), SnapshotFormat::Text);
+}
+
+#[Window(events = ButtonEvents + BackgroundTaskEvents<u32, bool>, internal = true)]
+struct DriverWin {
+    info: Handle<Label>,
+    start: Handle<Button>,
+    about: Handle<Button>,
+}
+impl DriverWin {
+    fn new(title: &str) -> Self {
+        let mut w = Self {
+            base: Window::new(title, layout!("a:c,w:40,h:10"), window::Flags::None),
+            info: Handle::None,
+            start: Handle::None,
+            about: Handle::None,
+        };
+        w.info = w.add(Label::new("<none>", layout!("x:1,y:0,w:30")));
+        w.add(CheckBox::new("Remember me", layout!("x:1,y:1,w:20"), false));
+        w.add(TextField::new("", layout!("x:1,y:2,w:20"), textfield::Flags::None));
+        w.start = w.add(Button::new("&Start", layout!("x:1,y:5,w:12"), button::Type::Normal));
+        w.about = w.add(Button::new("&About", layout!("x:14,y:5,w:12"), button::Type::Normal));
+        w
+    }
+    fn set_info(&mut self, txt: &str) {
+        let h = self.info;
+        if let Some(label) = self.control_mut(h) {
+            label.set_caption(txt);
+        }
+    }
+}
+impl ButtonEvents for DriverWin {
+    fn on_pressed(&mut self, handle: Handle<Button>) -> EventProcessStatus {
+        if handle == self.start {
+            self.set_info("Running");
+            BackgroundTask::<u32, bool>::run(
+                |conector| {
+                    std::thread::sleep(Duration::from_millis(50));
+                    conector.notify(42);
+                },
+                self.handle(),
+            );
+            return EventProcessStatus::Processed;
+        }
+        if handle == self.about {
+            dialogs::message("About", "Test driver");
+            self.set_info("About closed");
+            return EventProcessStatus::Processed;
+        }
+        EventProcessStatus::Ignored
+    }
+}
+impl BackgroundTaskEvents<u32, bool> for DriverWin {
+    fn on_update(&mut self, value: u32, _: &BackgroundTask<u32, bool>) -> EventProcessStatus {
+        self.set_info(&format!("Value: {value}"));
+        EventProcessStatus::Processed
+    }
+    fn on_finish(&mut self, _: &BackgroundTask<u32, bool>) -> EventProcessStatus {
+        self.set_info("Finished");
+        EventProcessStatus::Processed
+    }
+    fn on_query(&mut self, _: u32, _: &BackgroundTask<u32, bool>) -> bool {
+        false
+    }
+}
+
+fn label_text(driver: &TestDriver, title: &str) -> String {
+    let h = driver.find::<Label>().in_window(title).first().unwrap();
+    driver.get(h).unwrap().caption().to_string()
+}
+
+#[test]
+fn check_test_driver_find_controls() {
+    let mut driver = TestDriver::new(App::debug(80, 25, "")).unwrap();
+    let mut w = DriverWin::new("First");
+    w.set_tag("Tagged");
+    driver.add_window(w);
+    driver.add_window(DriverWin::new("Second"));
+
+    assert_eq!(driver.find::<Window>().count(), 2);
+    assert_eq!(driver.find::<Button>().count(), 4);
+    assert_eq!(driver.find::<Button>().in_window("Second").count(), 2);
+    assert_eq!(driver.find::<Button>().with_tag("Tagged").count(), 2);
+    assert_eq!(driver.find::<Button>().caption("Start").count(), 2);
+    assert_eq!(driver.find::<Label>().caption("<none>").in_window("First").count(), 1);
+    assert_eq!(driver.find::<Button>().caption("Stop").count(), 0);
+    // windows are part of themselves, labels and checkboxes have captions as well
+    let w = driver.find::<Window>().in_window("Second").single();
+    assert_eq!(driver.caption(w).as_deref(), Some("Second"));
+    assert_eq!(driver.find_any().in_window("First").count(), 6);
+    assert_eq!(driver.find_any().caption("Remember me").count(), 2);
+    // controls are listed in the order they were added
+    let all = driver.find::<Button>().in_window("First").all();
+    assert_eq!(driver.caption(all[0]).as_deref(), Some("Start"));
+    assert_eq!(driver.caption(all[1]).as_deref(), Some("About"));
+    // the last window added has the focus
+    assert!(driver.has_focus(w));
+    assert!(driver.bounds(all[0]).is_some());
+}
+
+#[test]
+fn check_test_driver_input() {
+    let mut driver = TestDriver::new(App::debug(60, 20, "")).unwrap();
+    driver.add_window(DriverWin::new("Input"));
+
+    let cb = driver.find::<CheckBox>().caption("Remember me").single();
+    assert!(!driver.get(cb).unwrap().is_checked());
+    driver.click(cb);
+    assert!(driver.get(cb).unwrap().is_checked());
+    assert!(driver.has_focus(cb));
+    driver.press(key!("Space"));
+    assert!(!driver.get(cb).unwrap().is_checked());
+
+    let tf = driver.find::<TextField>().single();
+    driver.focus(tf);
+    driver.type_text("Hello");
+    assert_eq!(driver.get(tf).unwrap().text(), "Hello");
+    driver.press(KeyCode::Backspace);
+    assert_eq!(driver.get(tf).unwrap().text(), "Hell");
+    assert!(driver.screen_text().contains("Hell"));
+
+    driver.get_mut(tf).unwrap().set_text("Changed");
+    driver.settle();
+    assert!(driver.screen_text().contains("Changed"));
+
+    assert!(driver.is_running());
+}
+
+#[test]
+fn check_test_driver_background_task() {
+    let mut driver = TestDriver::new(App::debug(60, 20, "")).unwrap();
+    driver.add_window(DriverWin::new("Tasks"));
+
+    let start = driver.find::<Button>().caption("Start").single();
+    driver.press_on(start, key!("Enter"));
+    assert!(driver.wait_for_background_tasks(Duration::from_secs(5)));
+    assert_eq!(label_text(&driver, "Tasks"), "Finished");
+
+    driver.click(start);
+    assert!(driver.wait_until(Duration::from_secs(5), |d| label_text(d, "Tasks") == "Finished"));
+    assert!(driver.wait_for_background_tasks(Duration::from_secs(5)));
+}
+
+#[test]
+fn check_test_driver_modal_window() {
+    let mut driver = TestDriver::new(App::debug(60, 20, "")).unwrap();
+    driver.add_window(DriverWin::new("Modal"));
+
+    let about = driver.find::<Button>().caption("About").single();
+    // the input for the modal window must be queued before it is opened
+    driver.queue_key(key!("Enter"));
+    driver.click(about);
+    assert_eq!(label_text(&driver, "Modal"), "About closed");
+    assert_eq!(driver.find::<Window>().count(), 1);
+}
+
+#[test]
+fn check_test_driver_with_script() {
+    assert!(TestDriver::new(App::debug(60, 20, "Key.Pressed(Enter)")).is_err());
+}
@@ -22,7 +22,9 @@
 //! - [`ComboBox`]: Editable dropdown list
 //! - [`DropDownList`]: Non-editable dropdown list
 //! - [`NumericSelector`]: Numeric value input and adjustment
+//! - [`Slider`]/[`RangeSlider`]: Selection of a value (or of a range of values) by dragging a thumb along a track
 //! - [`DatePicker`]: Date selection control
+//! - [`TimePicker`]/[`DateTimePicker`]/[`DateRangePicker`]: Time, date-time (with an optional time zone) and date interval selection
 //! - [`KeySelector`]: Keyboard shortcut selector
 //! - [`ColorPicker`]: Color selection control
 //!
@@ -32,6 +34,10 @@
 //! - [`ProgressBar`]: Visual representation of progress
 //! - [`ImageViewer`]: Display and manipulation of images
 //! - [`Canvas`]: Custom drawing surface
+//! - [`Chart`]: Line, bar, area and scatter plots with axes and a legend
+//! - [`HexView`]: Hexadecimal and ASCII view (and editor) of binary data
+//! - [`LogView`]: Live log viewer with level coloring, filtering, bookmarks and follow mode
+//! - [`Terminal`]: Terminal emulator that runs a child process (shell, build command, etc.)
 //! - [`HLine`]/[`VLine`]: Horizontal and vertical separators
 //!
 //! # Navigation and Organization
@@ -39,6 +45,11 @@
 //! - [`Tab`]: Tabbed interface for organizing content
 //! - [`Accordion`]: Collapsible sections of controls
 //! - [`VSplitter`]/[`HSplitter`]: Resizable split views (vertical/horizontal)
+//! - [`StackPanel`]/[`WrapPanel`]: Containers that position their children automatically (stacked or wrapped)
+//! - [`Grid`]: Container that arranges its children in rows and columns
+//! - [`ScrollViewer`]: Container that scrolls its children over a virtual canvas larger than itself
+//! - [`MasterDetail`]: A master list and the detail view of its current item (collapses on narrow terminals)
+//! - [`Wizard`]: Sequence of steps with validation, skipping and Back/Next/Finish/Cancel actions
 //! - [`TreeView`]: Hierarchical data presentation
 //! - [`ListBox`]: Simple list of selectable items
 //! - [`ListView`]: Multi-column list with headers
@@ -48,6 +59,11 @@
 //!
 //! - [`Menu`]: Application menus
 //! - [`CommandBar`]: Shortcut command interface
+//! - [`command_palette`]: Fuzzy search over the menu, command bar and app bar items (and custom actions)
+//!
+//! # Notifications
+//!
+//! - [`toast`]: Non-modal toast notifications and the notification center
 //!
 //! # Example
 //!
@@ -93,6 +109,7 @@ pub mod textfield;
 pub mod menu;
 pub mod appbar;
 pub mod command_bar;
+pub mod command_palette;
 pub mod window;
 pub mod button;
 pub mod tab;
@@ -121,6 +138,18 @@ pub mod treeview;
 pub mod progressbar;
 pub mod textarea;
 pub mod graphview;
+pub mod stackpanel;
+pub mod wrappanel;
+pub mod grid;
+pub mod scrollviewer;
+pub mod masterdetail;
+pub mod wizard;
+pub mod terminal;
+pub mod chart;
+pub mod hexview;
+pub mod logview;
+pub mod slider;
+pub mod toast;
 
 // re-export
 pub use common::ControlBase;
@@ -152,12 +181,16 @@ pub use layout::LayoutBuilder;
 pub use layout::Dock;
 pub use layout::Alignment;
 pub use layout::Pivot;
+pub use layout::Breakpoint;
 pub use numericselector::NumericSelector;
 pub use vsplitter::VSplitter;
 pub use hsplitter::HSplitter;
 pub use hline::HLine;
 pub use vline::VLine;
 pub use datepicker::DatePicker;
+pub use datepicker::DateRangePicker;
+pub use datepicker::DateTimePicker;
+pub use datepicker::TimePicker;
 pub use listbox::ListBox;
 pub use listview::ListView;
 pub use togglebutton::ToggleButton;
@@ -168,4 +201,16 @@ pub use progressbar::ProgressBar;
 pub use textarea::TextArea;
 pub use menu::Menu;
 pub use appbar::AppBar;
-pub use graphview::GraphView;
\ No newline at end of file
+pub use graphview::GraphView;
+pub use stackpanel::StackPanel;
+pub use wrappanel::WrapPanel;
+pub use grid::Grid;
+pub use scrollviewer::ScrollViewer;
+pub use masterdetail::MasterDetail;
+pub use wizard::Wizard;
+pub use terminal::Terminal;
+pub use chart::Chart;
+pub use hexview::HexView;
+pub use logview::LogView;
+pub use slider::RangeSlider;
+pub use slider::Slider;
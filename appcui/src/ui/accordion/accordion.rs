@@ -2,7 +2,7 @@ use crate::prelude::*;
 use crate::ui::accordion::events::EventData;
 use crate::ui::accordion::Flags;
 
-#[CustomControl(overwrite=OnPaint+OnMouseEvent+OnKeyPressed+OnResize, internal=true)]
+#[CustomControl(overwrite=OnPaint+OnMouseEvent+OnKeyPressed+OnResize+OnSessionState, internal=true)]
 pub struct Accordion {
     flags: Flags,
     panels: Vec<Caption>,
@@ -319,3 +319,15 @@ impl OnResize for Accordion {
         self.update_margins();
     }
 }
+impl OnSessionState for Accordion {
+    fn save_state(&self, state: &mut SessionState) {
+        if let Some(index) = self.current_panel() {
+            state.set("panel", index);
+        }
+    }
+    fn restore_state(&mut self, state: &SessionState) {
+        if let Some(index) = state.get_as::<usize>("panel") {
+            self.set_current_panel(index);
+        }
+    }
+}
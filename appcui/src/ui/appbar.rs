@@ -8,6 +8,7 @@ mod switch_button;
 mod separator;
 mod button;
 mod label;
+mod notification_button;
 mod side;
 mod item_status;
 #[cfg(test)]
@@ -23,6 +24,7 @@ pub use self::label::Label;
 pub use self::button::Button;
 pub use self::toggle_button::ToggleButton;
 pub use self::switch_button::SwitchButton;
+pub use self::notification_button::NotificationButton;
 pub use self::switch_button::SwitchButtonSymbol;
 pub use self::app_bar::AppBar;
 pub use self::side::Side;
\ No newline at end of file
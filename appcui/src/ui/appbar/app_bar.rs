@@ -4,6 +4,7 @@ use crate::input::*;
 use crate::system::{Handle, RuntimeManager, Theme};
 use crate::ui::appbar;
 use crate::ui::appbar::ItemStatus;
+use crate::ui::command_palette;
 use crate::ui::common::traits::EventProcessStatus;
 use crate::utils::HandleManager;
 
@@ -109,6 +110,7 @@ impl AppBar {
             AppBarItem::Button(obj) => const_cast!(obj, appbar::Button, T),
             AppBarItem::ToggleButton(obj) => const_cast!(obj, appbar::ToggleButton, T),
             AppBarItem::SwitchButton(obj) => const_cast!(obj, appbar::SwitchButton, T),
+            AppBarItem::NotificationButton(obj) => const_cast!(obj, appbar::NotificationButton, T),
         })
     }
     /// Get a mutable reference to an item from the app bar by its handle. The item has to be converted to an AppBarItem.
@@ -133,12 +135,59 @@ impl AppBar {
             AppBarItem::Button(obj) => mut_cast!(obj, appbar::Button, T),
             AppBarItem::ToggleButton(obj) => mut_cast!(obj, appbar::ToggleButton, T),
             AppBarItem::SwitchButton(obj) => mut_cast!(obj, appbar::SwitchButton, T),
+            AppBarItem::NotificationButton(obj) => mut_cast!(obj, appbar::NotificationButton, T),
         })
     }
     #[inline(always)]
     pub(crate) fn set_receiver_control_handle(&mut self, handle: Handle<()>) {
         self.receiver_control_handle = handle;
     }
+    // updates the counter displayed by the notification buttons (returns true if at least one button was changed)
+    pub(crate) fn set_unread_notifications(&mut self, count: usize) -> bool {
+        let mut changed = false;
+        for index in 0..self.manager.allocated_objects() {
+            if let Some(AppBarItem::NotificationButton(obj)) = self.manager.element_mut(index) {
+                changed |= obj.set_unread_count(count);
+            }
+        }
+        changed
+    }
+    // adds the visible items (and the items from the menus they open) to the entries of the command palette
+    pub(crate) fn add_command_palette_entries(&self, output: &mut Vec<command_palette::Entry>) {
+        for pos in self.shown_items.iter() {
+            let Some(item) = self.manager.element(pos.idx as usize) else {
+                continue;
+            };
+            if !item.is_enabled() || !item.base().accepts_input() {
+                continue;
+            }
+            let caption = match item {
+                AppBarItem::MenuButton(obj) => {
+                    obj.add_command_palette_entries(output);
+                    continue;
+                }
+                AppBarItem::Button(obj) => obj.caption(),
+                AppBarItem::ToggleButton(obj) => obj.caption(),
+                AppBarItem::SwitchButton(obj) => obj.current_caption(),
+                AppBarItem::NotificationButton(obj) => obj.caption(),
+                AppBarItem::Separator(_) | AppBarItem::Label(_) => continue,
+            };
+            output.push(command_palette::Entry::new(
+                caption,
+                command_palette::Source::AppBar,
+                item.hotkey(),
+                command_palette::Trigger::AppBarItem(item.base().handle().cast()),
+            ));
+        }
+    }
+    // runs the action of an item (as if it was clicked)
+    pub(crate) fn execute_item(&mut self, handle: Handle<()>) {
+        if let Some(item) = self.manager.get_mut(handle.cast()) {
+            if item.is_enabled() && item.base().accepts_input() {
+                item.execute_action();
+            }
+        }
+    }
     pub(crate) fn clear(&mut self) {
         self.shown_items.clear();
         self.receiver_control_handle = Handle::None;
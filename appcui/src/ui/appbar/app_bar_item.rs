@@ -2,6 +2,7 @@ use super::Button;
 use super::ItemBase;
 use super::Label;
 use super::MenuButton;
+use super::NotificationButton;
 use super::Separator;
 use super::ToggleButton;
 use super::SwitchButton;
@@ -20,6 +21,7 @@ pub(crate) enum AppBarItem {
     Button(Button),
     ToggleButton(ToggleButton),
     SwitchButton(SwitchButton),
+    NotificationButton(NotificationButton),
 }
 
 impl From<super::MenuButton> for AppBarItem {
@@ -52,6 +54,11 @@ impl From<super::SwitchButton> for AppBarItem {
         AppBarItem::SwitchButton(value)
     }
 }
+impl From<super::NotificationButton> for AppBarItem {
+    fn from(value: super::NotificationButton) -> Self {
+        AppBarItem::NotificationButton(value)
+    }
+}
 
 impl AppBarItem {
     pub(super) fn base(&self) -> &ItemBase {
@@ -62,6 +69,7 @@ impl AppBarItem {
             AppBarItem::Button(obj) => &obj.base,
             AppBarItem::ToggleButton(obj) => &obj.base,
             AppBarItem::SwitchButton(obj) => &obj.base,
+            AppBarItem::NotificationButton(obj) => &obj.base,
         }
     }
     pub(super) fn base_mut(&mut self) -> &mut ItemBase {
@@ -72,6 +80,7 @@ impl AppBarItem {
             AppBarItem::Button(obj) => &mut obj.base,
             AppBarItem::ToggleButton(obj) => &mut obj.base,
             AppBarItem::SwitchButton(obj) => &mut obj.base,
+            AppBarItem::NotificationButton(obj) => &mut obj.base,
         }
     }
     #[inline(always)]
@@ -87,6 +96,7 @@ impl AppBarItem {
             AppBarItem::Button(but) => but.hotkey(),
             AppBarItem::ToggleButton(but) => but.hotkey(),
             AppBarItem::SwitchButton(but) => but.hotkey(),
+            AppBarItem::NotificationButton(but) => but.hotkey(),
         }
     }
     #[inline(always)]
@@ -98,6 +108,7 @@ impl AppBarItem {
             AppBarItem::Button(obj) => obj.tooltip(),
             AppBarItem::ToggleButton(obj) => obj.tooltip(),
             AppBarItem::SwitchButton(obj) => obj.tooltip(),
+            AppBarItem::NotificationButton(obj) => obj.tooltip(),
         };
         if result.is_empty() {
             None
@@ -118,6 +129,7 @@ impl AppBarItem {
             AppBarItem::Button(_) => false,
             AppBarItem::ToggleButton(_) => false,
             AppBarItem::SwitchButton(_) => false,
+            AppBarItem::NotificationButton(_) => false,
         }
     }
     pub(super) fn activate(&mut self) {
@@ -128,6 +140,7 @@ impl AppBarItem {
             AppBarItem::Button(_) => {}
             AppBarItem::ToggleButton(_) => {}
             AppBarItem::SwitchButton(_) => {}
+            AppBarItem::NotificationButton(_) => {}
         }
     }
     pub(super) fn execute_action(&mut self) {
@@ -138,6 +151,7 @@ impl AppBarItem {
             AppBarItem::Button(obj) => obj.on_execute(),
             AppBarItem::ToggleButton(obj) => obj.on_execute(),
             AppBarItem::SwitchButton(obj) => obj.on_execute(),
+            AppBarItem::NotificationButton(obj) => obj.on_execute(),
         }
     }
     #[inline(always)]
@@ -149,6 +163,7 @@ impl AppBarItem {
             AppBarItem::Button(obj) => obj.paint(surface, theme, status),
             AppBarItem::ToggleButton(obj) => obj.paint(surface, theme, status),
             AppBarItem::SwitchButton(obj) => obj.paint(surface, theme, status),
+            AppBarItem::NotificationButton(obj) => obj.paint(surface, theme, status),
         }
     }
     pub(super) fn set_receiver_control_handle(&mut self, handle: Handle<()>) {
@@ -159,6 +174,7 @@ impl AppBarItem {
             AppBarItem::Button(obj) => obj.set_receiver_control_handle(handle),
             AppBarItem::ToggleButton(obj) => obj.set_receiver_control_handle(handle),
             AppBarItem::SwitchButton(obj) => obj.set_receiver_control_handle(handle),
+            AppBarItem::NotificationButton(_) => {}
         }
     }
 }
@@ -204,4 +204,5 @@ pub(crate) enum AppBarEvent {
     ButtonClick(ButtonClickEvent),
     ToggleButtonStatusChanged(ToggleButtonStatusChangedEvent),
     SwitchButtonStatusChanged(SwitchButtonStatusChangedEvent),
+    ShowNotificationCenter,
 }
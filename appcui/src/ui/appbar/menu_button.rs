@@ -2,6 +2,7 @@ use super::{ItemBase, ItemStatus, Side};
 use crate::graphics::*;
 use crate::input::*;
 use crate::system::{Handle, MenuHandleManager, RuntimeManager, Theme};
+use crate::ui::command_palette;
 use crate::ui::menu::Menu;
 use crate::utils::Caption;
 
@@ -136,6 +137,14 @@ impl MenuButton {
             false
         }
     }
+    pub(super) fn add_command_palette_entries(&self, output: &mut Vec<command_palette::Entry>) {
+        if self.receiver_control_handle.is_none() {
+            return;
+        }
+        if let Some(menu) = RuntimeManager::get().get_menu(self.handle) {
+            menu.add_command_palette_entries(self.caption.text(), self.receiver_control_handle, output);
+        }
+    }
     #[inline(always)]
     pub(super) fn hotkey(&self) -> Key {
         self.caption.hotkey()
@@ -0,0 +1,149 @@
+use super::{ItemBase, ItemStatus, Side};
+use crate::graphics::*;
+use crate::input::*;
+use crate::system::{RuntimeManager, Theme};
+use crate::ui::appbar::events::AppBarEvent;
+use crate::utils::Caption;
+
+/// A button in the app bar that opens the notification center (the list of the dismissed toasts - see the
+/// `toast` module). Besides its caption, the button displays the number of notifications that were not seen yet.
+///
+/// # Examples
+///
+/// ```rust, no_run
+/// use appcui::prelude::*;
+///
+/// let button = appbar::NotificationButton::new("&Notifications", 0, appbar::Side::Right);
+/// ```
+pub struct NotificationButton {
+    caption: Caption,
+    tooltip: String,
+    unread: usize,
+    pub(super) base: ItemBase,
+}
+
+impl NotificationButton {
+    /// Creates a new notification button with the specified caption, order and position.
+    ///
+    /// # Parameters
+    ///
+    /// * `caption` - The caption of the button. If the caption contains the `&` character, the next character (if it is a letter or number) will be set as a hot-key for the button. For example, `"&Notifications"` will set the hot-key to `Alt+N`.
+    /// * `order` - The order of the button (a number that determines the order of the button in the app bar - lower numbers are displayed first from either **left** or **right** depending on the **pos** parameter)
+    /// * `pos` - The position of the button (`Left` or `Right`)
+    pub fn new(caption: &str, order: u8, pos: Side) -> Self {
+        Self::with_tooltip(caption, "", order, pos)
+    }
+
+    /// Creates a new notification button with the specified caption, tooltip, order and position.
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// use appcui::prelude::*;
+    ///
+    /// let button = appbar::NotificationButton::with_tooltip("&Notifications", "Show the notification center", 0, appbar::Side::Right);
+    /// ```
+    pub fn with_tooltip(caption: &str, tooltip: &str, order: u8, pos: Side) -> Self {
+        let c = Caption::new(caption, crate::utils::ExtractHotKeyMethod::AltPlusKey);
+        let w = c.chars_count() as u8;
+        Self {
+            caption: c,
+            tooltip: tooltip.to_string(),
+            unread: 0,
+            base: ItemBase::new(w, order, pos, true),
+        }
+    }
+
+    /// Returns **true** if the button is enabled, **false** otherwise.
+    #[inline(always)]
+    pub fn is_enabled(&self) -> bool {
+        self.base.is_enabled()
+    }
+
+    /// Enables or disables the button.
+    #[inline(always)]
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.base.set_enabled(enabled);
+    }
+
+    /// Returns the caption of the button.
+    #[inline(always)]
+    pub fn caption(&self) -> &str {
+        self.caption.text()
+    }
+
+    /// Sets the caption of the button. If the caption contains the `&` character, the next character (if it is a letter or number) will be set as a hot-key for the button.
+    pub fn set_caption(&mut self, text: &str) {
+        self.caption.set_text(text, crate::utils::ExtractHotKeyMethod::AltPlusKey);
+        self.update_width();
+        self.base.refresh();
+    }
+
+    /// Returns the number of notifications that were not seen yet (the number displayed by the button).
+    #[inline(always)]
+    pub fn unread_count(&self) -> usize {
+        self.unread
+    }
+
+    /// Returns the tooltip of the button.
+    #[inline(always)]
+    pub fn tooltip(&self) -> &str {
+        &self.tooltip
+    }
+
+    /// Sets the tooltip of the button.
+    #[inline(always)]
+    pub fn set_tooltip(&mut self, text: &str) {
+        if self.tooltip != text {
+            self.tooltip.clear();
+            self.tooltip.push_str(text);
+            self.base.refresh();
+        }
+    }
+
+    pub(super) fn set_unread_count(&mut self, count: usize) -> bool {
+        if self.unread == count {
+            return false;
+        }
+        self.unread = count;
+        self.update_width();
+        true
+    }
+
+    fn counter(&self) -> String {
+        match self.unread {
+            0 => String::new(),
+            1..=99 => format!(" [{}]", self.unread),
+            _ => String::from(" [99+]"),
+        }
+    }
+
+    fn update_width(&mut self) {
+        let w = self.caption.chars_count() + self.counter().len();
+        self.base.set_width(w.min(u8::MAX as usize) as u8);
+    }
+
+    pub(super) fn paint(&self, surface: &mut Surface, theme: &Theme, status: ItemStatus) {
+        let mut format = TextFormatBuilder::new()
+            .position(self.base.x(), 0)
+            .attribute(status.text_attribute(theme))
+            .align(TextAlignment::Left)
+            .chars_count(self.caption.chars_count() as u16)
+            .build();
+        format.set_hotkey_from_caption(status.hotkey_attribute(theme), &self.caption);
+        surface.write_text(self.caption.text(), &format);
+        if self.unread > 0 {
+            let x = self.base.x() + self.caption.chars_count() as i32;
+            surface.write_string(x, 0, &self.counter(), status.hotkey_attribute(theme), false);
+        }
+    }
+
+    pub(super) fn on_execute(&self) {
+        RuntimeManager::get().set_appbar_event(AppBarEvent::ShowNotificationCenter);
+    }
+
+    #[inline(always)]
+    pub(super) fn hotkey(&self) -> Key {
+        self.caption.hotkey()
+    }
+}
@@ -205,6 +205,14 @@ impl SwitchButton {
         format.set_hotkey_from_caption(status.hotkey_attribute(theme), caption);
         surface.write_text(caption.text(), &format);
     }
+    #[inline(always)]
+    pub(super) fn current_caption(&self) -> &str {
+        if self.selected {
+            self.selected_caption.text()
+        } else {
+            self.unselected_caption.text()
+        }
+    }
     pub(super) fn on_execute(&mut self) {
         self.selected = !self.selected;
         RuntimeManager::get().set_appbar_event(AppBarEvent::SwitchButtonStatusChanged(SwitchButtonStatusChangedEvent {
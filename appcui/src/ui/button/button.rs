@@ -1,7 +1,7 @@
 use crate::prelude::*;
 use crate::ui::button::{events::EventData, Type};
 
-#[CustomControl(overwrite=OnPaint+OnDefaultAction+OnKeyPressed+OnMouseEvent, internal=true)]
+#[CustomControl(overwrite=OnPaint+OnDefaultAction+OnKeyPressed+OnMouseEvent+OnMeasure, internal=true)]
 pub struct Button {
     button_type: Type,
     caption: Caption,
@@ -115,6 +115,17 @@ impl OnPaint for Button {
         }
     }
 }
+impl OnMeasure for Button {
+    fn preferred_size(&self, _available: Size) -> Option<Size> {
+        // one space on each side of the caption (and an extra column and line for the shadow of a normal button)
+        let width = self.caption.chars_count() as u32 + 2;
+        if self.button_type == super::Type::Flat {
+            Some(Size::new(width, 1))
+        } else {
+            Some(Size::new(width + 1, 2))
+        }
+    }
+}
 impl OnMouseEvent for Button {
     fn on_mouse_event(&mut self, event: &MouseEvent) -> EventProcessStatus {
         match event {
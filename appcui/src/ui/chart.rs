@@ -0,0 +1,20 @@
+//! A chart UI control for plotting numeric data series.
+//!
+//! The Chart control draws one or more data series as lines, bars, filled areas or scattered points,
+//! with auto-scaled axes, a legend and a hover cursor that shows the values under the mouse. Points
+//! can be appended while the chart is displayed, which makes it suitable for streaming data.
+
+mod canvas;
+mod chart;
+mod initialization_flags;
+mod scale;
+mod series;
+#[cfg(test)]
+mod tests;
+
+pub use self::chart::Chart;
+pub use self::initialization_flags::AxisFormat;
+pub use self::initialization_flags::Flags;
+pub use self::initialization_flags::Resolution;
+pub use self::initialization_flags::SeriesType;
+pub use self::series::Series;
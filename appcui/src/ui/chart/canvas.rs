@@ -0,0 +1,190 @@
+use super::Resolution;
+use crate::graphics::{CharFlags, Character, Color, Surface};
+
+// braille dots for a 2 x 4 cell (indexed by [y][x])
+const BRAILLE_DOTS: [[u8; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+const UPPER_HALF: u8 = 1;
+const LOWER_HALF: u8 = 2;
+
+#[derive(Copy, Clone)]
+struct Cell {
+    bits: u8,
+    // the color of the pixels (for half blocks the color of the upper half)
+    color: Color,
+    // the color of the lower half (only for half blocks)
+    lower: Color,
+    ascii: char,
+}
+
+/// A grid of characters where every character is split in "pixels" (depending on the resolution).
+/// Series are drawn in pixels and the canvas is later painted on the surface of the chart.
+pub(super) struct Canvas {
+    resolution: Resolution,
+    width: i32,
+    height: i32,
+    cells: Vec<Cell>,
+}
+
+impl Canvas {
+    pub(super) fn new(resolution: Resolution, width: i32, height: i32) -> Self {
+        let (width, height) = (width.max(0), height.max(0));
+        Self {
+            resolution,
+            width,
+            height,
+            cells: vec![
+                Cell {
+                    bits: 0,
+                    color: Color::Transparent,
+                    lower: Color::Transparent,
+                    ascii: ' ',
+                };
+                (width * height) as usize
+            ],
+        }
+    }
+
+    /// Number of pixels in one character (horizontally and vertically)
+    pub(super) fn pixels_per_cell(resolution: Resolution) -> (i32, i32) {
+        match resolution {
+            Resolution::Braille => (2, 4),
+            Resolution::HalfBlock => (1, 2),
+            Resolution::Ascii => (1, 1),
+        }
+    }
+
+    /// The size of the canvas in pixels
+    pub(super) fn pixel_size(&self) -> (i32, i32) {
+        let (sx, sy) = Canvas::pixels_per_cell(self.resolution);
+        (self.width * sx, self.height * sy)
+    }
+
+    pub(super) fn set_pixel(&mut self, x: i32, y: i32, color: Color, ascii: char) {
+        let (sx, sy) = Canvas::pixels_per_cell(self.resolution);
+        let (w, h) = self.pixel_size();
+        if x < 0 || y < 0 || x >= w || y >= h {
+            return;
+        }
+        let cell = &mut self.cells[((y / sy) * self.width + (x / sx)) as usize];
+        match self.resolution {
+            Resolution::Braille => {
+                cell.bits |= BRAILLE_DOTS[(y % 4) as usize][(x % 2) as usize];
+                cell.color = color;
+            }
+            Resolution::HalfBlock => {
+                if y % 2 == 0 {
+                    cell.bits |= UPPER_HALF;
+                    cell.color = color;
+                } else {
+                    cell.bits |= LOWER_HALF;
+                    cell.lower = color;
+                }
+            }
+            Resolution::Ascii => {
+                cell.bits = 1;
+                cell.color = color;
+                cell.ascii = ascii;
+            }
+        }
+    }
+
+    /// Draws a line between two points (in pixels). The parts of the line outside the canvas are clipped.
+    pub(super) fn line(&mut self, p1: (f64, f64), p2: (f64, f64), color: Color, ascii: char) {
+        let (w, h) = self.pixel_size();
+        let Some(((x1, y1), (x2, y2))) = clip(p1, p2, (w - 1) as f64, (h - 1) as f64) else {
+            return;
+        };
+        let (mut x, mut y) = (x1.round() as i32, y1.round() as i32);
+        let (x2, y2) = (x2.round() as i32, y2.round() as i32);
+        let dx = (x2 - x).abs();
+        let dy = -(y2 - y).abs();
+        let step_x = if x < x2 { 1 } else { -1 };
+        let step_y = if y < y2 { 1 } else { -1 };
+        let mut err = dx + dy;
+        loop {
+            self.set_pixel(x, y, color, ascii);
+            if x == x2 && y == y2 {
+                break;
+            }
+            let e2 = 2 * err;
+            if e2 >= dy {
+                err += dy;
+                x += step_x;
+            }
+            if e2 <= dx {
+                err += dx;
+                y += step_y;
+            }
+        }
+    }
+
+    /// Draws a vertical segment of pixels (from `y1` to `y2`, both included) on column `x`
+    pub(super) fn column(&mut self, x: i32, y1: f64, y2: f64, color: Color, ascii: char) {
+        let (_, h) = self.pixel_size();
+        let (top, bottom) = if y1 <= y2 { (y1, y2) } else { (y2, y1) };
+        let top = top.round().max(0.0) as i32;
+        let bottom = bottom.round().min((h - 1) as f64) as i32;
+        for y in top..=bottom {
+            self.set_pixel(x, y, color, ascii);
+        }
+    }
+
+    pub(super) fn paint(&self, surface: &mut Surface, left: i32, top: i32) {
+        for y in 0..self.height {
+            for x in 0..self.width {
+                if let Some(ch) = self.character(x, y) {
+                    surface.write_char(left + x, top + y, ch);
+                }
+            }
+        }
+    }
+
+    pub(super) fn character(&self, x: i32, y: i32) -> Option<Character> {
+        if x < 0 || y < 0 || x >= self.width || y >= self.height {
+            return None;
+        }
+        let cell = &self.cells[(y * self.width + x) as usize];
+        if cell.bits == 0 {
+            return None;
+        }
+        let (code, fore, back) = match self.resolution {
+            Resolution::Braille => (char::from_u32(0x2800 + cell.bits as u32).unwrap_or(' '), cell.color, Color::Transparent),
+            Resolution::HalfBlock => match cell.bits {
+                UPPER_HALF => ('▀', cell.color, Color::Transparent),
+                LOWER_HALF => ('▄', cell.lower, Color::Transparent),
+                _ if cell.color == cell.lower => ('█', cell.color, Color::Transparent),
+                _ => ('▀', cell.color, cell.lower),
+            },
+            Resolution::Ascii => (cell.ascii, cell.color, Color::Transparent),
+        };
+        Some(Character::new(code, fore, back, CharFlags::None))
+    }
+}
+
+// clips a segment to the [0, max_x] x [0, max_y] rectangle (Liang-Barsky)
+fn clip(p1: (f64, f64), p2: (f64, f64), max_x: f64, max_y: f64) -> Option<((f64, f64), (f64, f64))> {
+    if max_x < 0.0 || max_y < 0.0 {
+        return None;
+    }
+    let (dx, dy) = (p2.0 - p1.0, p2.1 - p1.1);
+    let mut t0 = 0.0f64;
+    let mut t1 = 1.0f64;
+    for (p, q) in [(-dx, p1.0), (dx, max_x - p1.0), (-dy, p1.1), (dy, max_y - p1.1)] {
+        if p == 0.0 {
+            if q < 0.0 {
+                return None;
+            }
+        } else {
+            let r = q / p;
+            if p < 0.0 {
+                t0 = t0.max(r);
+            } else {
+                t1 = t1.min(r);
+            }
+        }
+    }
+    if t0 > t1 {
+        return None;
+    }
+    Some(((p1.0 + t0 * dx, p1.1 + t0 * dy), (p1.0 + t1 * dx, p1.1 + t1 * dy)))
+}
@@ -0,0 +1,492 @@
+use super::canvas::Canvas;
+use super::scale::Scale;
+use super::series::Series;
+use super::{AxisFormat, Flags, Resolution, SeriesType};
+use crate::prelude::*;
+
+const LEGEND_MARKER: char = '■';
+
+// the plot area (in characters, relative to the control) and the scales of the two axes
+struct Frame {
+    left: i32,
+    top: i32,
+    width: i32,
+    height: i32,
+    x: Scale,
+    y: Scale,
+}
+
+#[CustomControl(overwrite=OnPaint+OnKeyPressed+OnMouseEvent, internal=true)]
+pub struct Chart {
+    flags: Flags,
+    series: Vec<Series>,
+    resolution: Resolution,
+    x_format: AxisFormat,
+    y_format: AxisFormat,
+    x_range: Option<(f64, f64)>,
+    y_range: Option<(f64, f64)>,
+    max_points: usize,
+    // the column (relative to the plot area) where the hover cursor is displayed
+    cursor: Option<i32>,
+}
+impl Chart {
+    /// Creates a new (empty) chart with the specified layout and flags. The flags can be a combination of:
+    /// * `chart::Flags::HideLegend` - the legend (the names of the series) is not displayed
+    /// * `chart::Flags::HideAxes` - the axes and their labels are not displayed (the plot uses the entire control)
+    /// * `chart::Flags::Grid` - a grid is drawn for every tick of the axes
+    ///
+    /// By default the chart uses the `Braille` resolution and both axes are formatted as numbers.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use appcui::prelude::*;
+    ///
+    /// let mut c = Chart::new(layout!("d:f"), chart::Flags::Grid);
+    /// let cpu = c.add_series("CPU", chart::SeriesType::Line, Color::Green);
+    /// c.extend(cpu, &[(0.0, 12.5), (1.0, 40.0), (2.0, 33.0)]);
+    /// ```
+    pub fn new(layout: Layout, flags: Flags) -> Self {
+        Self {
+            base: ControlBase::with_status_flags(layout, StatusFlags::Visible | StatusFlags::Enabled | StatusFlags::AcceptInput),
+            flags,
+            series: Vec::new(),
+            resolution: Resolution::Braille,
+            x_format: AxisFormat::Number,
+            y_format: AxisFormat::Number,
+            x_range: None,
+            y_range: None,
+            max_points: 0,
+            cursor: None,
+        }
+    }
+
+    /// Adds a new (empty) series to the chart and returns its index. The index is used to add points to the series.
+    pub fn add_series(&mut self, name: &str, kind: SeriesType, color: Color) -> usize {
+        self.series.push(Series::new(name, kind, color));
+        self.series.len() - 1
+    }
+
+    /// Returns the series with the specified index (or `None` if the index is invalid)
+    pub fn series(&self, index: usize) -> Option<&Series> {
+        self.series.get(index)
+    }
+
+    /// Returns the number of series in the chart
+    #[inline(always)]
+    pub fn series_count(&self) -> usize {
+        self.series.len()
+    }
+
+    /// Appends a point to a series. If the series already has the maximum number of points (see `set_max_points`)
+    /// the oldest point is removed. Points with values that are not finite (NaN or infinite) are ignored.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use appcui::prelude::*;
+    ///
+    /// let mut c = Chart::new(layout!("d:f"), chart::Flags::None);
+    /// let s = c.add_series("Requests", chart::SeriesType::Bar, Color::Aqua);
+    /// c.set_max_points(60);
+    /// c.append(s, 1.0, 250.0);
+    /// ```
+    pub fn append(&mut self, series: usize, x: f64, y: f64) {
+        if let Some(s) = self.series.get_mut(series) {
+            s.push(x, y, self.max_points);
+        }
+    }
+
+    /// Appends a value to a series. The x coordinate of the new point is the x coordinate of the last point
+    /// of the series plus one (or `0` if the series is empty).
+    pub fn append_value(&mut self, series: usize, y: f64) {
+        if let Some(s) = self.series.get_mut(series) {
+            let x = s.last().map(|p| p.0 + 1.0).unwrap_or(0.0);
+            s.push(x, y, self.max_points);
+        }
+    }
+
+    /// Appends multiple points (in order) to a series
+    pub fn extend(&mut self, series: usize, points: &[(f64, f64)]) {
+        if let Some(s) = self.series.get_mut(series) {
+            for (x, y) in points {
+                s.push(*x, *y, self.max_points);
+            }
+        }
+    }
+
+    /// Removes all points from a series
+    pub fn clear_series(&mut self, series: usize) {
+        if let Some(s) = self.series.get_mut(series) {
+            s.clear();
+        }
+    }
+
+    /// Removes all points from all series (the series are kept)
+    pub fn clear(&mut self) {
+        for s in self.series.iter_mut() {
+            s.clear();
+        }
+    }
+
+    /// Sets the maximum number of points kept for every series (`0` means no limit). When a new point is appended
+    /// to a full series, the oldest point is removed - this way a chart that receives streaming data always shows
+    /// the last `max_points` values.
+    pub fn set_max_points(&mut self, max_points: usize) {
+        self.max_points = max_points;
+        for s in self.series.iter_mut() {
+            s.truncate(max_points);
+        }
+    }
+
+    /// Returns the maximum number of points kept for every series (`0` means no limit)
+    #[inline(always)]
+    pub fn max_points(&self) -> usize {
+        self.max_points
+    }
+
+    /// Sets the resolution used to draw the series (`Braille`, `HalfBlock` or `Ascii`)
+    pub fn set_resolution(&mut self, resolution: Resolution) {
+        self.resolution = resolution;
+    }
+
+    /// Returns the resolution used to draw the series
+    #[inline(always)]
+    pub fn resolution(&self) -> Resolution {
+        self.resolution
+    }
+
+    /// Sets how the values of the horizontal axis are formatted. For `Time`, `Date` and `DateTime` the x coordinates
+    /// of the points are considered to be seconds since UNIX epoch (UTC).
+    pub fn set_x_axis_format(&mut self, format: AxisFormat) {
+        self.x_format = format;
+    }
+
+    /// Sets how the values of the vertical axis are formatted
+    pub fn set_y_axis_format(&mut self, format: AxisFormat) {
+        self.y_format = format;
+    }
+
+    /// Sets a fixed interval `(min, max)` for the horizontal axis. If `None` is provided the interval is computed
+    /// from the points of all series.
+    pub fn set_x_range(&mut self, range: Option<(f64, f64)>) {
+        self.x_range = range;
+    }
+
+    /// Sets a fixed interval `(min, max)` for the vertical axis. If `None` is provided the interval is computed from
+    /// the points of all series (and extended to the closest ticks).
+    pub fn set_y_range(&mut self, range: Option<(f64, f64)>) {
+        self.y_range = range;
+    }
+
+    // limits of the data (x_min, x_max, y_min, y_max)
+    fn data_bounds(&self) -> (f64, f64, f64, f64) {
+        let mut bounds: Option<(f64, f64, f64, f64)> = None;
+        let mut has_base_line = false;
+        for s in self.series.iter() {
+            has_base_line |= matches!(s.kind(), SeriesType::Bar | SeriesType::Area);
+            for (x, y) in s.points() {
+                bounds = Some(match bounds {
+                    None => (x, x, y, y),
+                    Some((x1, x2, y1, y2)) => (x1.min(x), x2.max(x), y1.min(y), y2.max(y)),
+                });
+            }
+        }
+        let (x1, x2, y1, y2) = bounds.unwrap_or((0.0, 1.0, 0.0, 1.0));
+        if has_base_line {
+            (x1, x2, y1.min(0.0), y2.max(0.0))
+        } else {
+            (x1, x2, y1, y2)
+        }
+    }
+
+    fn frame(&self) -> Option<Frame> {
+        let size = self.size();
+        let (w, h) = (size.width as i32, size.height as i32);
+        let axes = !self.flags.contains(Flags::HideAxes);
+        let top = if self.flags.contains(Flags::HideLegend) || self.series.is_empty() {
+            0
+        } else {
+            1
+        };
+        let height = h - top - if axes { 2 } else { 0 };
+        if height < 1 || w < 1 {
+            return None;
+        }
+        let (x_min, x_max, y_min, y_max) = self.data_bounds();
+        let (y_min, y_max) = self.y_range.unwrap_or((y_min, y_max));
+        let y = Scale::new(y_min, y_max, (height as usize / 3 + 1).max(2), self.y_format, self.y_range.is_none());
+        let left = if axes { Chart::labels_width(&y) + 1 } else { 0 };
+        let width = w - left;
+        if width < 1 {
+            return None;
+        }
+        let (x_min, x_max) = self.x_range.unwrap_or((x_min, x_max));
+        // first estimation of the number of ticks, then adjust it to the width of the labels
+        let x = Scale::new(x_min, x_max, (width as usize / 10).max(1), self.x_format, false);
+        let label_width = Chart::labels_width(&x) as usize + 2;
+        let x = Scale::new(x_min, x_max, (width as usize / label_width).max(1), self.x_format, false);
+        Some(Frame {
+            left,
+            top,
+            width,
+            height,
+            x,
+            y,
+        })
+    }
+
+    fn labels_width(scale: &Scale) -> i32 {
+        let mut label = String::new();
+        let mut width = 0;
+        for v in scale.ticks() {
+            scale.label(v, &mut label);
+            width = width.max(label.chars().count() as i32);
+        }
+        width
+    }
+
+    fn tick_row(f: &Frame, value: f64) -> i32 {
+        f.top + f.height - 1 - f.y.position(value, f.height).round() as i32
+    }
+
+    fn tick_column(f: &Frame, value: f64) -> i32 {
+        f.left + f.x.position(value, f.width).round() as i32
+    }
+
+    fn render(&self, f: &Frame) -> Canvas {
+        let mut canvas = Canvas::new(self.resolution, f.width, f.height);
+        let (pw, ph) = canvas.pixel_size();
+        let (sx, _) = Canvas::pixels_per_cell(self.resolution);
+        let to_pixel = |p: (f64, f64)| (f.x.position(p.0, pw), (ph - 1) as f64 - f.y.position(p.1, ph));
+        let base = (ph - 1) as f64 - f.y.position(0.0f64.clamp(f.y.min, f.y.max), ph);
+        for s in self.series.iter() {
+            let color = s.color();
+            match s.kind() {
+                SeriesType::Scatter => {
+                    for p in s.points() {
+                        let (x, y) = to_pixel(p);
+                        canvas.set_pixel(x.round() as i32, y.round() as i32, color, 'o');
+                    }
+                }
+                SeriesType::Bar => {
+                    for p in s.points() {
+                        let (x, y) = to_pixel(p);
+                        let x = x.round() as i32;
+                        if x < 0 || x >= pw {
+                            continue;
+                        }
+                        // a bar fills an entire character
+                        let start = x - x % sx;
+                        for bx in start..start + sx {
+                            canvas.column(bx, y, base, color, '#');
+                        }
+                    }
+                }
+                SeriesType::Area => {
+                    let mut previous: Option<(f64, f64)> = None;
+                    for p in s.points() {
+                        let current = to_pixel(p);
+                        let from = previous.unwrap_or(current);
+                        let first = from.0.ceil().max(0.0) as i32;
+                        let last = current.0.floor().min((pw - 1) as f64) as i32;
+                        for x in first..=last {
+                            let y = if current.0 > from.0 {
+                                from.1 + (current.1 - from.1) * (x as f64 - from.0) / (current.0 - from.0)
+                            } else {
+                                current.1
+                            };
+                            canvas.column(x, y, base, color, ':');
+                        }
+                        previous = Some(current);
+                    }
+                    Chart::render_line(&mut canvas, s, &to_pixel);
+                }
+                SeriesType::Line => Chart::render_line(&mut canvas, s, &to_pixel),
+            }
+        }
+        canvas
+    }
+
+    fn render_line(canvas: &mut Canvas, series: &Series, to_pixel: &dyn Fn((f64, f64)) -> (f64, f64)) {
+        let mut previous: Option<(f64, f64)> = None;
+        for p in series.points() {
+            let current = to_pixel(p);
+            canvas.line(previous.unwrap_or(current), current, series.color(), '*');
+            previous = Some(current);
+        }
+    }
+
+    fn paint_legend(&self, surface: &mut Surface, attr: CharAttribute, left: i32) {
+        let mut x = left;
+        for s in self.series.iter() {
+            surface.write_char(x, 0, Character::new(LEGEND_MARKER, s.color(), Color::Transparent, CharFlags::None));
+            surface.write_string(x + 2, 0, s.name(), attr, false);
+            x += s.name().chars().count() as i32 + 4;
+        }
+    }
+
+    fn paint_axes(&self, surface: &mut Surface, f: &Frame, text_attr: CharAttribute, line_attr: CharAttribute) {
+        let ax = f.left - 1;
+        let ay = f.top + f.height;
+        surface.draw_vertical_line(ax, f.top, ay - 1, LineType::Single, line_attr);
+        surface.draw_horizontal_line(f.left, ay, f.left + f.width - 1, LineType::Single, line_attr);
+        surface.write_char(ax, ay, Character::with_attributes('└', line_attr));
+        let mut label = String::new();
+        for v in f.y.ticks() {
+            let row = Chart::tick_row(f, v);
+            surface.write_char(ax, row, Character::with_attributes('┤', line_attr));
+            f.y.label(v, &mut label);
+            surface.write_string(ax - label.chars().count() as i32, row, &label, text_attr, false);
+        }
+        // labels that would overlap the previous one are skipped
+        let mut next_free = i32::MIN;
+        let right = f.left + f.width;
+        for v in f.x.ticks() {
+            let col = Chart::tick_column(f, v);
+            surface.write_char(col, ay, Character::with_attributes('┬', line_attr));
+            f.x.label(v, &mut label);
+            let width = label.chars().count() as i32;
+            let x = (col - width / 2).min(right - width).max(0);
+            if x >= next_free {
+                surface.write_string(x, ay + 1, &label, text_attr, false);
+                next_free = x + width + 1;
+            }
+        }
+    }
+
+    fn paint_grid(&self, surface: &mut Surface, f: &Frame, attr: CharAttribute) {
+        let ch = Character::with_attributes('·', attr);
+        for v in f.y.ticks() {
+            surface.fill_horizontal_line(f.left, Chart::tick_row(f, v), f.left + f.width - 1, ch);
+        }
+        for v in f.x.ticks() {
+            surface.fill_vertical_line(Chart::tick_column(f, v), f.top, f.top + f.height - 1, ch);
+        }
+    }
+
+    // the x value that corresponds to the center of a column of the plot area
+    fn cursor_value(&self, f: &Frame, column: i32) -> f64 {
+        let (sx, _) = Canvas::pixels_per_cell(self.resolution);
+        f.x.value((column * sx) as f64 + (sx - 1) as f64 / 2.0, f.width * sx)
+    }
+
+    fn paint_cursor_values(&self, surface: &mut Surface, theme: &Theme, f: &Frame, column: i32) {
+        let x_value = self.cursor_value(f, column);
+        let mut lines: Vec<(Option<Color>, String)> = Vec::with_capacity(self.series.len() + 1);
+        let mut label = String::new();
+        f.x.precise_label(x_value, &mut label);
+        lines.push((None, label.clone()));
+        for s in self.series.iter() {
+            if let Some((_, y)) = s.nearest(x_value) {
+                f.y.precise_label(y, &mut label);
+                lines.push((Some(s.color()), format!("{}: {}", s.name(), label)));
+            }
+        }
+        let width = lines
+            .iter()
+            .map(|(c, t)| t.chars().count() as i32 + if c.is_some() { 2 } else { 0 })
+            .max()
+            .unwrap_or(0)
+            + 2;
+        let height = lines.len() as i32;
+        let mut x = f.left + column + 2;
+        if x + width > f.left + f.width {
+            x = (f.left + column - 1 - width).max(0);
+        }
+        let attr = theme.tooltip.text;
+        surface.fill_rect(
+            Rect::with_size(x, f.top, width as u16, height as u16),
+            Character::with_attributes(' ', attr),
+        );
+        for (index, (color, text)) in lines.iter().enumerate() {
+            let y = f.top + index as i32;
+            if let Some(color) = color {
+                surface.write_char(x + 1, y, Character::new(LEGEND_MARKER, *color, Color::Transparent, CharFlags::None));
+                surface.write_string(x + 3, y, text, attr, false);
+            } else {
+                surface.write_string(x + 1, y, text, attr, false);
+            }
+        }
+    }
+
+    fn move_cursor(&mut self, f: &Frame, column: i32) -> EventProcessStatus {
+        self.cursor = Some(column.clamp(0, f.width - 1));
+        EventProcessStatus::Processed
+    }
+}
+
+impl OnPaint for Chart {
+    fn on_paint(&self, surface: &mut Surface, theme: &Theme) {
+        let Some(f) = self.frame() else {
+            return;
+        };
+        let (text_attr, line_attr) = if self.is_enabled() {
+            (theme.text.normal, theme.lines.normal)
+        } else {
+            (theme.text.inactive, theme.lines.inactive)
+        };
+        if f.top > 0 {
+            self.paint_legend(surface, text_attr, f.left);
+        }
+        if !self.flags.contains(Flags::HideAxes) {
+            self.paint_axes(surface, &f, text_attr, line_attr);
+        }
+        if self.flags.contains(Flags::Grid) {
+            self.paint_grid(surface, &f, theme.lines.inactive);
+        }
+        let cursor = self.cursor.map(|c| c.min(f.width - 1));
+        if let Some(column) = cursor {
+            surface.fill_vertical_line(
+                f.left + column,
+                f.top,
+                f.top + f.height - 1,
+                Character::with_attributes('│', theme.lines.hovered),
+            );
+        }
+        self.render(&f).paint(surface, f.left, f.top);
+        if let Some(column) = cursor {
+            self.paint_cursor_values(surface, theme, &f, column);
+        }
+    }
+}
+
+impl OnKeyPressed for Chart {
+    fn on_key_pressed(&mut self, key: Key, _character: char) -> EventProcessStatus {
+        let Some(f) = self.frame() else {
+            return EventProcessStatus::Ignored;
+        };
+        match key.value() {
+            key!("Left") => self.move_cursor(&f, self.cursor.map(|c| c - 1).unwrap_or(f.width - 1)),
+            key!("Right") => self.move_cursor(&f, self.cursor.map(|c| c + 1).unwrap_or(0)),
+            key!("Home") => self.move_cursor(&f, 0),
+            key!("End") => self.move_cursor(&f, f.width - 1),
+            key!("Escape") if self.cursor.is_some() => {
+                self.cursor = None;
+                EventProcessStatus::Processed
+            }
+            _ => EventProcessStatus::Ignored,
+        }
+    }
+}
+
+impl OnMouseEvent for Chart {
+    fn on_mouse_event(&mut self, event: &MouseEvent) -> EventProcessStatus {
+        match event {
+            MouseEvent::Over(p) => {
+                let inside = self
+                    .frame()
+                    .filter(|f| p.x >= f.left && p.x < f.left + f.width && p.y >= f.top && p.y < f.top + f.height)
+                    .map(|f| p.x - f.left);
+                if inside.is_none() && self.cursor.is_none() {
+                    return EventProcessStatus::Ignored;
+                }
+                self.cursor = inside;
+                EventProcessStatus::Processed
+            }
+            MouseEvent::Leave if self.cursor.is_some() => {
+                self.cursor = None;
+                EventProcessStatus::Processed
+            }
+            _ => EventProcessStatus::Ignored,
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use EnumBitFlags::EnumBitFlags;
+
+#[EnumBitFlags(bits: 8)]
+pub enum Flags {
+    HideLegend = 0x01,
+    HideAxes = 0x02,
+    Grid = 0x04,
+}
+
+/// The way the points of a series are drawn.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum SeriesType {
+    /// consecutive points are joined by a line
+    Line,
+    /// each point is drawn as a vertical bar that starts from the base line (`0` if visible)
+    Bar,
+    /// like `Line`, but the area between the line and the base line is filled
+    Area,
+    /// each point is drawn individually
+    Scatter,
+}
+
+/// How many "pixels" are drawn in one character of the plot area.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Resolution {
+    /// braille characters - 2 x 4 pixels per character
+    Braille,
+    /// half block characters (`▀` and `▄`) - 1 x 2 pixels per character
+    HalfBlock,
+    /// plain ASCII characters (`*`, `#`, `o`) - one pixel per character
+    Ascii,
+}
+
+/// How the values of an axis are formatted.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum AxisFormat {
+    /// numbers (with thousands separator and a number of decimals that depends on the tick step)
+    Number,
+    /// the values are seconds since UNIX epoch (UTC), displayed as `hh:mm:ss` or `hh:mm`
+    Time,
+    /// the values are seconds since UNIX epoch (UTC), displayed as `YYYY-MM-DD`
+    Date,
+    /// the values are seconds since UNIX epoch (UTC), displayed as `YYYY-MM-DD hh:mm`
+    DateTime,
+}
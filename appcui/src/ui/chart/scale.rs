@@ -0,0 +1,162 @@
+use super::AxisFormat;
+use crate::utils::{FormatDate, FormatDateTime, FormatNumber, FormatTime};
+use chrono::{DateTime, NaiveDateTime};
+
+const SECONDS_PER_DAY: f64 = 86400.0;
+// "round" steps (in seconds) used for time axes
+const TIME_STEPS: [f64; 18] = [
+    1.0,
+    2.0,
+    5.0,
+    10.0,
+    15.0,
+    30.0,
+    60.0,
+    120.0,
+    300.0,
+    600.0,
+    900.0,
+    1800.0,
+    3600.0,
+    7200.0,
+    10800.0,
+    21600.0,
+    43200.0,
+    SECONDS_PER_DAY,
+];
+
+/// Maps the values of an axis (between `min` and `max`) to positions and computes the ticks of that axis.
+pub(super) struct Scale {
+    pub(super) min: f64,
+    pub(super) max: f64,
+    pub(super) step: f64,
+    format: AxisFormat,
+    decimals: u8,
+}
+
+impl Scale {
+    /// Creates a scale with at most `max_ticks` ticks. If `extend` is `true` the limits are moved to the
+    /// closest multiple of the tick step (so that the first and last tick are at the edges of the axis).
+    pub(super) fn new(min: f64, max: f64, max_ticks: usize, format: AxisFormat, extend: bool) -> Self {
+        let (mut min, mut max) = if min <= max { (min, max) } else { (max, min) };
+        if max - min < f64::EPSILON * max.abs().max(1.0) {
+            let delta = match format {
+                AxisFormat::Number => (min.abs() * 0.1).max(1.0),
+                AxisFormat::Time | AxisFormat::DateTime => 60.0,
+                AxisFormat::Date => SECONDS_PER_DAY,
+            };
+            min -= delta;
+            max += delta;
+        }
+        let step = match format {
+            AxisFormat::Number => nice_step(max - min, max_ticks),
+            AxisFormat::Time | AxisFormat::DateTime => time_step(max - min, max_ticks),
+            AxisFormat::Date => time_step(max - min, max_ticks).max(SECONDS_PER_DAY),
+        };
+        if extend {
+            min = (min / step).floor() * step;
+            max = (max / step).ceil() * step;
+        }
+        let decimals = if format == AxisFormat::Number && step < 1.0 {
+            (-step.log10()).ceil().clamp(0.0, 8.0) as u8
+        } else {
+            0
+        };
+        Self {
+            min,
+            max,
+            step,
+            format,
+            decimals,
+        }
+    }
+
+    /// Returns the values where the ticks of the axis are placed (multiples of the step between `min` and `max`)
+    pub(super) fn ticks(&self) -> impl Iterator<Item = f64> + '_ {
+        let first = (self.min / self.step - 1e-9).ceil() as i64;
+        let last = (self.max / self.step + 1e-9).floor() as i64;
+        (first..=last).map(move |i| i as f64 * self.step)
+    }
+
+    /// Converts a value into a position between `0` and `size - 1` (where `0` corresponds to `min`).
+    /// The result can be outside this interval if the value is outside the limits of the scale.
+    pub(super) fn position(&self, value: f64, size: i32) -> f64 {
+        (value - self.min) / (self.max - self.min) * ((size - 1).max(0) as f64)
+    }
+
+    /// Converts a position between `0` and `size - 1` back into a value
+    pub(super) fn value(&self, position: f64, size: i32) -> f64 {
+        if size <= 1 {
+            return self.min;
+        }
+        self.min + position / ((size - 1) as f64) * (self.max - self.min)
+    }
+
+    /// Formats a tick value (with the precision of the tick step)
+    pub(super) fn label(&self, value: f64, output: &mut String) {
+        self.write(value, self.decimals, false, output);
+    }
+
+    /// Formats a value with a higher precision than the ticks (used by the hover cursor)
+    pub(super) fn precise_label(&self, value: f64, output: &mut String) {
+        self.write(value, (self.decimals + 2).min(8), true, output);
+    }
+
+    fn write(&self, value: f64, decimals: u8, precise: bool, output: &mut String) {
+        output.clear();
+        let mut buf = [0u8; 64];
+        let text = match self.format {
+            AxisFormat::Number => {
+                let factor = 10f64.powi(decimals as i32);
+                let value = if (value * factor).round() == 0.0 {
+                    0.0
+                } else {
+                    // `write_float` truncates the decimals, so the value is rounded first
+                    value + value.signum() * 0.5 / factor
+                };
+                FormatNumber::new(10).group(3, b',').decimals(decimals).write_float(value, &mut buf)
+            }
+            AxisFormat::Time => {
+                let dt = to_datetime(value);
+                if self.step >= 60.0 && !precise {
+                    FormatTime::short(&dt.time(), &mut buf)
+                } else {
+                    FormatTime::normal(&dt.time(), &mut buf)
+                }
+            }
+            AxisFormat::Date => FormatDate::ymd(&to_datetime(value).date(), &mut buf),
+            AxisFormat::DateTime => FormatDateTime::short(&to_datetime(value), &mut buf),
+        };
+        if let Some(text) = text {
+            output.push_str(text.trim_start());
+        }
+    }
+}
+
+fn to_datetime(value: f64) -> NaiveDateTime {
+    let seconds = value.round() as i64;
+    DateTime::from_timestamp(seconds, 0).unwrap_or_default().naive_utc()
+}
+
+// the smallest 1/2/5 x 10^n step that splits the range in at most `max_ticks` intervals
+fn nice_step(range: f64, max_ticks: usize) -> f64 {
+    let max_ticks = max_ticks.max(1) as f64;
+    let raw = range / max_ticks;
+    let magnitude = 10f64.powf(raw.log10().floor());
+    for m in [1.0, 2.0, 5.0, 10.0] {
+        if m * magnitude >= raw {
+            return m * magnitude;
+        }
+    }
+    10.0 * magnitude
+}
+
+fn time_step(range: f64, max_ticks: usize) -> f64 {
+    let raw = range / (max_ticks.max(1) as f64);
+    for step in TIME_STEPS {
+        if step >= raw {
+            return step;
+        }
+    }
+    nice_step(range / SECONDS_PER_DAY, max_ticks) * SECONDS_PER_DAY
+}
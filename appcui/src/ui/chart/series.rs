@@ -0,0 +1,99 @@
+use super::SeriesType;
+use crate::graphics::Color;
+use std::collections::VecDeque;
+
+/// A named set of `(x, y)` points drawn by a [`Chart`](super::Chart) in the same way and with the same color.
+pub struct Series {
+    name: String,
+    kind: SeriesType,
+    color: Color,
+    points: VecDeque<(f64, f64)>,
+}
+
+impl Series {
+    pub(super) fn new(name: &str, kind: SeriesType, color: Color) -> Self {
+        Self {
+            name: name.to_string(),
+            kind,
+            color,
+            points: VecDeque::new(),
+        }
+    }
+
+    /// Returns the name of the series (as it is displayed in the legend)
+    #[inline(always)]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the way the points of the series are drawn
+    #[inline(always)]
+    pub fn kind(&self) -> SeriesType {
+        self.kind
+    }
+
+    /// Returns the color of the series
+    #[inline(always)]
+    pub fn color(&self) -> Color {
+        self.color
+    }
+
+    /// Returns the number of points in the series
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Returns `true` if the series has no points
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Returns the point with the specified index (points are kept in the order they were added)
+    pub fn point(&self, index: usize) -> Option<(f64, f64)> {
+        self.points.get(index).copied()
+    }
+
+    /// Returns the last point that was added to the series
+    pub fn last(&self) -> Option<(f64, f64)> {
+        self.points.back().copied()
+    }
+
+    /// Returns an iterator over the points of the series
+    pub fn points(&self) -> impl Iterator<Item = (f64, f64)> + '_ {
+        self.points.iter().copied()
+    }
+
+    pub(super) fn push(&mut self, x: f64, y: f64, max_points: usize) {
+        if !x.is_finite() || !y.is_finite() {
+            return;
+        }
+        self.points.push_back((x, y));
+        self.truncate(max_points);
+    }
+
+    pub(super) fn truncate(&mut self, max_points: usize) {
+        if max_points > 0 {
+            while self.points.len() > max_points {
+                self.points.pop_front();
+            }
+        }
+    }
+
+    pub(super) fn clear(&mut self) {
+        self.points.clear();
+    }
+
+    // the point whose x coordinate is the closest to the specified value
+    pub(super) fn nearest(&self, x: f64) -> Option<(f64, f64)> {
+        let mut result: Option<(f64, f64)> = None;
+        for p in self.points.iter() {
+            match result {
+                Some(r) if (r.0 - x).abs() <= (p.0 - x).abs() => {}
+                _ => result = Some(*p),
+            }
+        }
+        result
+    }
+}
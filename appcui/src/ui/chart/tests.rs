@@ -0,0 +1,168 @@
+use super::canvas::Canvas;
+use super::scale::Scale;
+use super::{AxisFormat, Resolution, SeriesType};
+use crate::prelude::*;
+
+fn labels(scale: &Scale) -> Vec<String> {
+    let mut label = String::new();
+    scale
+        .ticks()
+        .map(|v| {
+            scale.label(v, &mut label);
+            label.clone()
+        })
+        .collect()
+}
+
+fn chart_window(chart: Chart) -> (crate::testing::TestDriver, Handle<Chart>) {
+    let mut driver = crate::testing::TestDriver::new(App::debug(60, 16, "")).unwrap();
+    let mut w = Window::new("Chart", layout!("x:0,y:0,w:42,h:14"), window::Flags::None);
+    let h = w.add(chart);
+    driver.add_window(w);
+    driver.settle();
+    (driver, h)
+}
+
+#[test]
+fn check_number_scale() {
+    let s = Scale::new(3.0, 97.0, 5, AxisFormat::Number, true);
+    assert_eq!((s.min, s.max, s.step), (0.0, 100.0, 20.0));
+    assert_eq!(labels(&s), ["0", "20", "40", "60", "80", "100"]);
+    let s = Scale::new(0.13, 0.42, 4, AxisFormat::Number, true);
+    assert_eq!(labels(&s), ["0.1", "0.2", "0.3", "0.4", "0.5"]);
+    let s = Scale::new(-2500.0, 12000.0, 3, AxisFormat::Number, false);
+    assert_eq!(labels(&s), ["0", "5,000", "10,000"]);
+    // an empty interval is extended
+    let s = Scale::new(5.0, 5.0, 2, AxisFormat::Number, true);
+    assert!(s.min < 5.0 && s.max > 5.0);
+    let mut label = String::new();
+    s.precise_label(-0.004, &mut label);
+    assert_eq!(label, "0.00");
+    assert_eq!(s.position(s.max, 11), 10.0);
+    assert_eq!(s.value(0.0, 11), s.min);
+}
+
+#[test]
+fn check_time_scale() {
+    // 2024-01-01 10:00:00 UTC -> 10:03:20
+    let start = 1704103200.0;
+    let s = Scale::new(start, start + 200.0, 4, AxisFormat::Time, false);
+    assert_eq!(s.step, 60.0);
+    assert_eq!(labels(&s), ["10:00", "10:01", "10:02", "10:03"]);
+    let mut label = String::new();
+    s.precise_label(start + 75.0, &mut label);
+    assert_eq!(label, "10:01:15");
+    let s = Scale::new(start, start + 20.0, 4, AxisFormat::Time, false);
+    assert_eq!(labels(&s)[1], "10:00:05");
+    let s = Scale::new(start, start + 3.0 * 86400.0, 3, AxisFormat::Date, false);
+    assert_eq!(labels(&s), ["2024-01-02", "2024-01-03", "2024-01-04"]);
+    let s = Scale::new(start, start + 7200.0, 2, AxisFormat::DateTime, false);
+    assert_eq!(labels(&s), ["2024-01-01 10:00", "2024-01-01 11:00", "2024-01-01 12:00"]);
+}
+
+#[test]
+fn check_canvas_resolutions() {
+    let mut c = Canvas::new(Resolution::Braille, 2, 1);
+    assert_eq!(c.pixel_size(), (4, 4));
+    c.line((0.0, 0.0), (3.0, 3.0), Color::Red, '*');
+    assert_eq!(c.character(0, 0).unwrap().code, '\u{2811}');
+    assert_eq!(c.character(1, 0).unwrap().code, '\u{2884}');
+
+    let mut c = Canvas::new(Resolution::HalfBlock, 1, 1);
+    c.set_pixel(0, 0, Color::Red, '*');
+    assert_eq!(c.character(0, 0).unwrap().code, '▀');
+    c.set_pixel(0, 1, Color::Green, '*');
+    let ch = c.character(0, 0).unwrap();
+    assert_eq!((ch.code, ch.foreground, ch.background), ('▀', Color::Red, Color::Green));
+
+    let mut c = Canvas::new(Resolution::Ascii, 5, 3);
+    // the segment is clipped to the canvas
+    c.line((-10.0, 1.0), (10.0, 1.0), Color::Red, '*');
+    c.column(2, 0.0, 2.0, Color::Blue, '#');
+    let row = |y: i32| (0..5).map(|x| c.character(x, y).map(|ch| ch.code).unwrap_or(' ')).collect::<String>();
+    assert_eq!([row(0), row(1), row(2)], ["  #  ", "**#**", "  #  "]);
+}
+
+#[test]
+fn check_series_and_streaming() {
+    let mut c = Chart::new(layout!("d:f"), chart::Flags::None);
+    let s = c.add_series("cpu", SeriesType::Line, Color::Green);
+    c.append_value(s, 10.0);
+    c.append_value(s, 20.0);
+    c.append(s, 5.0, f64::NAN);
+    assert_eq!(c.series(s).unwrap().len(), 2);
+    assert_eq!(c.series(s).unwrap().last(), Some((1.0, 20.0)));
+    c.set_max_points(3);
+    c.extend(s, &[(2.0, 1.0), (3.0, 2.0), (4.0, 3.0)]);
+    let points: Vec<_> = c.series(s).unwrap().points().collect();
+    assert_eq!(points, [(2.0, 1.0), (3.0, 2.0), (4.0, 3.0)]);
+    c.set_max_points(1);
+    assert_eq!(c.series(s).unwrap().point(0), Some((4.0, 3.0)));
+    // invalid series are ignored
+    c.append(10, 1.0, 1.0);
+    c.clear();
+    assert!(c.series(s).unwrap().is_empty());
+    assert_eq!(c.series_count(), 1);
+}
+
+#[test]
+fn check_axes_and_legend() {
+    let mut c = Chart::new(layout!("d:f"), chart::Flags::None);
+    c.set_resolution(Resolution::Ascii);
+    let s = c.add_series("load", SeriesType::Line, Color::Green);
+    c.extend(s, &[(0.0, 0.0), (5.0, 50.0), (10.0, 100.0)]);
+    let b = c.add_series("disk", SeriesType::Bar, Color::Red);
+    c.extend(b, &[(2.0, 20.0), (8.0, 40.0)]);
+    let (driver, _) = chart_window(c);
+    let text = driver.screen_text();
+    assert!(text.contains("■ load  ■ disk"));
+    assert!(text.contains("100┤"));
+    assert!(text.contains("  0┤"));
+    assert!(text.contains(" 50┤"));
+    assert!(text.contains("└┬──────┬──────┬"));
+    assert!(text.contains("0      2      4      6      8     10"));
+    assert!(text.contains("  0┤***    #"));
+}
+
+#[test]
+fn check_hidden_axes_and_legend() {
+    let mut c = Chart::new(layout!("d:f"), chart::Flags::HideAxes | chart::Flags::HideLegend);
+    c.set_resolution(Resolution::HalfBlock);
+    let s = c.add_series("area", SeriesType::Area, Color::Aqua);
+    c.extend(s, &[(0.0, 1.0), (1.0, 3.0), (2.0, 2.0)]);
+    let (driver, _) = chart_window(c);
+    let text = driver.screen_text();
+    assert!(!text.contains("area"));
+    assert!(!text.contains('┤'));
+    assert!(text.contains('█'));
+}
+
+#[test]
+fn check_hover_cursor() {
+    let mut c = Chart::new(layout!("d:f"), chart::Flags::HideLegend);
+    let s = c.add_series("cpu", SeriesType::Line, Color::Green);
+    for i in 0..=40 {
+        c.append(s, i as f64, (i * 2) as f64);
+    }
+    let (mut driver, h) = chart_window(c);
+    assert!(!driver.screen_text().contains("cpu:"));
+
+    // the plot area starts after the labels of the vertical axis ("80" + the axis)
+    let b = driver.bounds(h).unwrap();
+    driver.hover_at(b.left() + 3, b.top() + 2);
+    let text = driver.screen_text();
+    assert!(text.contains("cpu: 0.00"));
+    driver.hover_at(b.right(), b.top() + 2);
+    assert!(driver.screen_text().contains("cpu: 80.00"));
+    driver.hover_at(b.left() + 1, b.bottom());
+    assert!(!driver.screen_text().contains("cpu:"));
+
+    // the cursor can also be moved with the keyboard
+    driver.focus(h);
+    driver.press(key!("Home"));
+    assert!(driver.screen_text().contains("cpu: 0.00"));
+    driver.press(key!("Escape"));
+    assert!(!driver.screen_text().contains("cpu:"));
+    driver.press(key!("Left"));
+    assert!(driver.screen_text().contains("cpu: 80.00"));
+}
@@ -2,6 +2,7 @@ use crate::{
     graphics::{Character, Size, Surface},
     input::{Key, KeyCode, KeyModifier},
     system::{Handle, Theme, MouseButtonDownEvent, MouseMoveEvent},
+    ui::command_palette,
     ui::common::traits::CommandID,
 };
 
@@ -161,6 +162,24 @@ impl CommandBar {
             control_receiver_handle: item.receiver_control,
         })
     }
+    // adds the current items to the entries of the command palette
+    pub(crate) fn add_command_palette_entries(&self, output: &mut Vec<command_palette::Entry>) {
+        for (idx, item) in self.items.iter().enumerate() {
+            if item.version != self.version || item.receiver_control.is_none() {
+                continue;
+            }
+            let key = Key::new(KeyCode::from((idx % MAX_KEYS) as u8), KeyModifier::from((idx / MAX_KEYS) as u8));
+            output.push(command_palette::Entry::new(
+                item.text.trim_end(),
+                command_palette::Source::CommandBar,
+                key,
+                command_palette::Trigger::Command {
+                    command: item.command,
+                    receiver: item.receiver_control,
+                },
+            ));
+        }
+    }
     pub(crate) fn update_positions(&mut self) {
         // recompute all positions regardless of the shift state
         for shift_state in 0..MAX_SHIFT_STATES {
@@ -1,4 +1,5 @@
 use crate::system::Handle;
+use crate::ui::command_palette;
 use super::CommandBar;
 
 #[derive(Copy,Clone)]
@@ -8,5 +9,6 @@ pub(crate) struct CommandBarEvent {
 }
 pub trait GenericCommandBarEvents {
     fn on_update_commandbar(&self, _commandbar: &mut CommandBar) {}
+    fn on_update_command_palette(&self, _actions: &mut command_palette::Actions) {}
     fn on_event(&mut self, _command_id: u32) {}
 }
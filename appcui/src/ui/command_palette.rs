@@ -0,0 +1,73 @@
+//! A searchable list with all the commands that are available for the focused control.
+//!
+//! The command palette is a modal window that indexes (every time it is opened) the following items:
+//! * the commands, check boxes and single choice items from the menus displayed in the application bar
+//!   (including the ones from sub-menus)
+//! * the buttons, toggle buttons and switch buttons displayed in the application bar
+//! * the items from the command bar
+//! * the custom actions added via the `on_update_command_palette` method from the `CommandBarEvents` trait
+//!
+//! Every item is displayed with its shortcut (if any). While typing, the list is filtered using fuzzy matching
+//! (the characters must appear in the same order, but not necessarily one after another) and the matched
+//! characters are highlighted. The recently selected items are displayed first. Selecting an item fires the
+//! same event as the original item (e.g. `MenuEvents::on_command` for a menu command).
+//!
+//! The command palette can be opened via the [`show`] function or, if enabled when the application was created
+//! (via `.command_palette()`), by pressing `Ctrl+Shift+P`.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use appcui::prelude::*;
+//!
+//! fn main() -> Result<(), appcui::system::Error> {
+//!     let mut a = App::new().app_bar().command_bar().command_palette().build()?;
+//!     // add windows with menus and command bar items
+//!     a.run();
+//!     Ok(())
+//! }
+//! ```
+
+mod actions;
+mod entry;
+mod palette;
+mod palette_view;
+mod recent;
+#[cfg(test)]
+mod tests;
+
+pub use self::actions::Actions;
+pub(crate) use self::entry::{Entry, Source, Trigger};
+pub(crate) use self::recent::RecentCommands;
+
+use self::palette::CommandPalette;
+use crate::system::RuntimeManager;
+use crate::ui::window::events::ModalWindowMethods;
+
+/// Opens the command palette for the focused control. If an item is selected, the event associated with
+/// that item is fired.
+pub fn show() {
+    let rm = RuntimeManager::get();
+    if rm.is_command_palette_opened() {
+        return;
+    }
+    let entries = rm.command_palette_entries();
+    if entries.is_empty() {
+        return;
+    }
+    let palette = CommandPalette::new(entries.clone(), rm.get_recent_commands());
+    rm.set_command_palette_opened(true);
+    let result = palette.show();
+    let rm = RuntimeManager::get();
+    rm.set_command_palette_opened(false);
+    if let Some(index) = result {
+        let entry = &entries[index];
+        rm.get_recent_commands_mut().add(entry.id());
+        rm.execute_command_palette_trigger(entry.trigger);
+    }
+}
+
+/// Clears the list of the recently selected items (the items that are displayed first in the command palette).
+pub fn clear_recent() {
+    RuntimeManager::get().get_recent_commands_mut().clear();
+}
@@ -0,0 +1,90 @@
+use super::entry::{Entry, Source, Trigger};
+use crate::input::Key;
+use crate::system::Handle;
+use crate::ui::common::traits::CommandID;
+
+/// The list of custom actions displayed by the command palette (besides the menu, command bar and app bar items).
+/// The list is filled every time the command palette is opened, by calling the `on_update_command_palette` method
+/// from the `CommandBarEvents` trait for the focused control and all of its parents. When a custom action is
+/// selected, the `on_event` method from the `CommandBarEvents` trait of the control that added it is called
+/// (just like for a command bar item).
+///
+/// # Example
+///
+/// ```rust, no_run
+/// use appcui::prelude::*;
+///
+/// #[Window(events = CommandBarEvents, commands = Reload+ClearCache)]
+/// struct MyWin {}
+///
+/// impl CommandBarEvents for MyWin {
+///     fn on_update_commandbar(&self, commandbar: &mut CommandBar) {
+///         commandbar.set(key!("F5"), "Reload", mywin::Commands::Reload);
+///     }
+///     fn on_update_command_palette(&self, actions: &mut command_palette::Actions) {
+///         actions.add("Clear the cache", mywin::Commands::ClearCache);
+///     }
+///     fn on_event(&mut self, command_id: mywin::Commands) {
+///         match command_id {
+///             mywin::Commands::Reload => { /* reload */ }
+///             mywin::Commands::ClearCache => { /* clear the cache */ }
+///         }
+///     }
+/// }
+/// ```
+pub struct Actions {
+    entries: Vec<Entry>,
+    receiver_control_handle: Handle<()>,
+}
+
+impl Actions {
+    pub(crate) fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            receiver_control_handle: Handle::None,
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn set_receiver_control_handle(&mut self, handle: Handle<()>) {
+        self.receiver_control_handle = handle;
+    }
+
+    #[inline(always)]
+    pub(crate) fn into_entries(self) -> Vec<Entry> {
+        self.entries
+    }
+
+    /// Adds a custom action with the specified caption. When the action is selected from the command palette,
+    /// the `on_event` method from the `CommandBarEvents` trait will be called with the provided command.
+    pub fn add<U>(&mut self, caption: &str, command: U)
+    where
+        u32: From<U>,
+        U: CommandID + Copy,
+    {
+        if self.receiver_control_handle.is_none() || caption.is_empty() {
+            return;
+        }
+        self.entries.push(Entry::new(
+            caption,
+            Source::Action,
+            Key::None,
+            Trigger::Command {
+                command: u32::from(command),
+                receiver: self.receiver_control_handle,
+            },
+        ));
+    }
+
+    /// Returns the number of actions that were added.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if no action was added.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
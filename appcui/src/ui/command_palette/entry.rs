@@ -0,0 +1,61 @@
+use crate::input::Key;
+use crate::system::Handle;
+use crate::ui::menu::Menu;
+
+/// Where an entry from the command palette was collected from.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub(crate) enum Source {
+    Menu,
+    CommandBar,
+    AppBar,
+    Action,
+}
+
+impl Source {
+    pub(super) fn name(&self) -> &'static str {
+        match self {
+            Source::Menu => "Menu",
+            Source::CommandBar => "Command",
+            Source::AppBar => "AppBar",
+            Source::Action => "Action",
+        }
+    }
+}
+
+/// What happens when an entry is selected (the same event as the one fired by the original item).
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub(crate) enum Trigger {
+    MenuItem {
+        menu: Handle<Menu>,
+        index: usize,
+        receiver: Handle<()>,
+    },
+    AppBarItem(Handle<()>),
+    Command {
+        command: u32,
+        receiver: Handle<()>,
+    },
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct Entry {
+    pub(crate) caption: String,
+    pub(crate) source: Source,
+    pub(crate) shortcut: Key,
+    pub(crate) trigger: Trigger,
+}
+
+impl Entry {
+    pub(crate) fn new(caption: &str, source: Source, shortcut: Key, trigger: Trigger) -> Self {
+        Self {
+            caption: caption.to_string(),
+            source,
+            shortcut,
+            trigger,
+        }
+    }
+    // identifies an entry between two different openings of the command palette (used for the recent list)
+    pub(super) fn id(&self) -> String {
+        format!("{}:{}", self.source.name(), self.caption)
+    }
+}
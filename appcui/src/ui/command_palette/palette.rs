@@ -0,0 +1,39 @@
+use super::entry::Entry;
+use super::palette_view::{paletteview, PaletteView};
+use super::recent::RecentCommands;
+use crate::prelude::*;
+
+#[ModalWindow(events: WindowEvents, custom_events: PaletteViewEvents, response: usize, internal: true)]
+pub(super) struct CommandPalette {
+    view: Handle<PaletteView>,
+}
+
+impl CommandPalette {
+    pub(super) fn new(entries: Vec<Entry>, recent: &RecentCommands) -> Self {
+        let mut w = Self {
+            base: ModalWindow::new("Command Palette", layout!("a:t,w:64,h:18"), window::Flags::None),
+            view: Handle::None,
+        };
+        w.view = w.add(PaletteView::new(entries, recent));
+        w
+    }
+    fn accept(&mut self) {
+        let h = self.view;
+        if let Some(index) = self.control(h).and_then(|v| v.selected()) {
+            self.exit_with(index);
+        }
+    }
+}
+
+impl WindowEvents for CommandPalette {
+    fn on_accept(&mut self) {
+        self.accept();
+    }
+}
+
+impl PaletteViewEvents for CommandPalette {
+    fn on_event(&mut self, _: Handle<PaletteView>, _: paletteview::Events) -> EventProcessStatus {
+        self.accept();
+        EventProcessStatus::Processed
+    }
+}
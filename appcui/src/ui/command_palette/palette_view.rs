@@ -0,0 +1,258 @@
+use super::entry::Entry;
+use super::recent::RecentCommands;
+use crate::prelude::*;
+use crate::utils::string_comparison::fuzzy_match;
+
+// the first line contains the search text and the second one a separator
+const LIST_TOP: i32 = 2;
+
+struct Match {
+    index: usize,
+    score: i32,
+    positions: Vec<usize>,
+}
+
+#[CustomControl(overwrite=OnPaint+OnKeyPressed+OnMouseEvent, emit=EntrySelected, internal=true)]
+pub(super) struct PaletteView {
+    entries: Vec<Entry>,
+    bonus: Vec<i32>,
+    matches: Vec<Match>,
+    text: String,
+    current: usize,
+    top: usize,
+    hovered: Option<usize>,
+}
+
+impl PaletteView {
+    pub(super) fn new(entries: Vec<Entry>, recent: &RecentCommands) -> Self {
+        let bonus = entries.iter().map(|e| recent.bonus(&e.id())).collect();
+        let mut v = Self {
+            base: ControlBase::with_status_flags(
+                layout!("l:0,t:0,r:0,b:0"),
+                StatusFlags::Visible | StatusFlags::Enabled | StatusFlags::AcceptInput,
+            ),
+            entries,
+            bonus,
+            matches: Vec::new(),
+            text: String::new(),
+            current: 0,
+            top: 0,
+            hovered: None,
+        };
+        v.refilter();
+        v
+    }
+
+    /// The index (in the list of entries) of the selected entry
+    pub(super) fn selected(&self) -> Option<usize> {
+        self.matches.get(self.current).map(|m| m.index)
+    }
+
+    fn refilter(&mut self) {
+        self.matches.clear();
+        let mut positions = Vec::new();
+        for (index, entry) in self.entries.iter().enumerate() {
+            if let Some(score) = fuzzy_match(&self.text, &entry.caption, &mut positions) {
+                self.matches.push(Match {
+                    index,
+                    score: score + self.bonus[index],
+                    positions: positions.clone(),
+                });
+            }
+        }
+        // stable sort - entries with the same score keep the order in which they were collected
+        self.matches.sort_by_key(|m| std::cmp::Reverse(m.score));
+        self.current = 0;
+        self.top = 0;
+        self.hovered = None;
+    }
+
+    fn visible_rows(&self) -> usize {
+        (self.size().height as i32 - LIST_TOP).max(1) as usize
+    }
+
+    fn move_to(&mut self, index: usize) {
+        if self.matches.is_empty() {
+            return;
+        }
+        self.current = index.min(self.matches.len() - 1);
+        let rows = self.visible_rows();
+        if self.current < self.top {
+            self.top = self.current;
+        } else if self.current >= self.top + rows {
+            self.top = self.current + 1 - rows;
+        }
+    }
+
+    fn row_to_index(&self, x: i32, y: i32) -> Option<usize> {
+        if x < 0 || x >= self.size().width as i32 || y < LIST_TOP {
+            return None;
+        }
+        let index = self.top + (y - LIST_TOP) as usize;
+        if index < self.matches.len() {
+            Some(index)
+        } else {
+            None
+        }
+    }
+
+    fn paint_entry(&self, surface: &mut Surface, theme: &Theme, m: &Match, y: i32, current: bool, hovered: bool) {
+        let w = self.size().width as i32;
+        let entry = &self.entries[m.index];
+        let attr = match () {
+            _ if current => theme.list_current_item.focus,
+            _ if hovered => theme.text.hovered,
+            _ => theme.text.normal,
+        };
+        if current {
+            surface.fill_horizontal_line_with_size(0, y, w as u32, Character::with_attributes(' ', attr));
+        }
+        let match_attr = CharAttribute::with_color(theme.text.hot_key.foreground, attr.background);
+        let info_attr = CharAttribute::with_color(theme.text.inactive.foreground, attr.background);
+
+        // the shortcut and the source of the entry are right aligned
+        let mut right = w - 1;
+        if entry.shortcut.code != KeyCode::None {
+            let shortcut = entry.shortcut.to_string();
+            right -= shortcut.chars().count() as i32;
+            surface.write_string(right, y, &shortcut, match_attr, false);
+            right -= 1;
+        }
+        let source = entry.source.name();
+        right -= source.len() as i32;
+        surface.write_string(right, y, source, info_attr, false);
+
+        // the caption (with the matched characters highlighted)
+        let max_x = right - 2;
+        let mut next = 0;
+        for (index, ch) in entry.caption.chars().enumerate() {
+            let x = 1 + index as i32;
+            if x > max_x {
+                break;
+            }
+            let highlighted = m.positions.get(next) == Some(&index);
+            if highlighted {
+                next += 1;
+            }
+            surface.write_char(x, y, Character::with_attributes(ch, if highlighted { match_attr } else { attr }));
+        }
+    }
+}
+
+impl OnPaint for PaletteView {
+    fn on_paint(&self, surface: &mut Surface, theme: &Theme) {
+        let w = self.size().width as i32;
+        // search text
+        let attr = theme.editor.focused;
+        surface.fill_horizontal_line_with_size(0, 0, w as u32, Character::with_attributes(' ', attr));
+        surface.write_char(1, 0, Character::with_attributes('>', theme.text.hot_key));
+        let count = self.text.chars().count() as i32;
+        let available = (w - 4).max(1);
+        let skip = (count - available + 1).max(0) as usize;
+        let visible: String = self.text.chars().skip(skip).collect();
+        surface.write_string(3, 0, &visible, attr, false);
+        surface.set_cursor(3 + count - skip as i32, 0);
+        surface.draw_horizontal_line_with_size(0, 1, w as u32, LineType::Single, theme.lines.normal);
+
+        // entries
+        if self.matches.is_empty() {
+            surface.write_string(1, LIST_TOP, "No matching commands", theme.text.inactive, false);
+            return;
+        }
+        let rows = self.visible_rows();
+        for (row, m) in self.matches.iter().enumerate().skip(self.top).take(rows) {
+            let y = LIST_TOP + (row - self.top) as i32;
+            self.paint_entry(surface, theme, m, y, row == self.current, Some(row) == self.hovered);
+        }
+    }
+}
+
+impl OnKeyPressed for PaletteView {
+    fn on_key_pressed(&mut self, key: Key, character: char) -> EventProcessStatus {
+        let rows = self.visible_rows();
+        match key.value() {
+            key!("Up") => {
+                self.move_to(self.current.saturating_sub(1));
+                EventProcessStatus::Processed
+            }
+            key!("Down") => {
+                self.move_to(self.current + 1);
+                EventProcessStatus::Processed
+            }
+            key!("PageUp") => {
+                self.move_to(self.current.saturating_sub(rows));
+                EventProcessStatus::Processed
+            }
+            key!("PageDown") => {
+                self.move_to(self.current + rows);
+                EventProcessStatus::Processed
+            }
+            key!("Ctrl+Home") => {
+                self.move_to(0);
+                EventProcessStatus::Processed
+            }
+            key!("Ctrl+End") => {
+                self.move_to(usize::MAX);
+                EventProcessStatus::Processed
+            }
+            key!("Back") => {
+                if self.text.pop().is_some() {
+                    self.refilter();
+                }
+                EventProcessStatus::Processed
+            }
+            key!("Ctrl+Back") => {
+                if !self.text.is_empty() {
+                    self.text.clear();
+                    self.refilter();
+                }
+                EventProcessStatus::Processed
+            }
+            _ => {
+                if (character as u32) > 0 && !character.is_control() {
+                    self.text.push(character);
+                    self.refilter();
+                    EventProcessStatus::Processed
+                } else {
+                    EventProcessStatus::Ignored
+                }
+            }
+        }
+    }
+}
+
+impl OnMouseEvent for PaletteView {
+    fn on_mouse_event(&mut self, event: &MouseEvent) -> EventProcessStatus {
+        match event {
+            MouseEvent::Leave => {
+                self.hovered = None;
+                EventProcessStatus::Processed
+            }
+            MouseEvent::Over(p) => {
+                let hovered = self.row_to_index(p.x, p.y);
+                if hovered != self.hovered {
+                    self.hovered = hovered;
+                    EventProcessStatus::Processed
+                } else {
+                    EventProcessStatus::Ignored
+                }
+            }
+            MouseEvent::Pressed(data) => {
+                if let Some(index) = self.row_to_index(data.x, data.y) {
+                    self.current = index;
+                    self.raise_event(paletteview::Events::EntrySelected);
+                }
+                EventProcessStatus::Processed
+            }
+            MouseEvent::Wheel(direction) => {
+                match direction {
+                    MouseWheelDirection::Up => self.move_to(self.current.saturating_sub(1)),
+                    MouseWheelDirection::Down => self.move_to(self.current + 1),
+                    _ => return EventProcessStatus::Ignored,
+                }
+                EventProcessStatus::Processed
+            }
+            _ => EventProcessStatus::Ignored,
+        }
+    }
+}
@@ -0,0 +1,31 @@
+const MAX_RECENT: usize = 16;
+
+/// The list of the entries that were recently selected from the command palette (the most recent one first).
+pub(crate) struct RecentCommands {
+    list: Vec<String>,
+}
+
+impl RecentCommands {
+    pub(crate) fn new() -> Self {
+        Self { list: Vec::new() }
+    }
+    pub(crate) fn add(&mut self, id: String) {
+        if let Some(index) = self.list.iter().position(|s| *s == id) {
+            self.list.remove(index);
+        }
+        self.list.insert(0, id);
+        self.list.truncate(MAX_RECENT);
+    }
+    #[inline(always)]
+    pub(crate) fn clear(&mut self) {
+        self.list.clear();
+    }
+    // a bonus added to the score of an entry (the most recent entry gets the highest bonus)
+    pub(super) fn bonus(&self, id: &str) -> i32 {
+        self.list
+            .iter()
+            .position(|s| s == id)
+            .map(|index| (MAX_RECENT - index) as i32)
+            .unwrap_or(0)
+    }
+}
@@ -0,0 +1,210 @@
+use super::palette_view::PaletteView;
+use super::{Entry, RecentCommands, Source, Trigger};
+use crate::prelude::*;
+use crate::testing::TestDriver;
+
+#[Window(events: MenuEvents+AppBarEvents+CommandBarEvents, commands: New+Save+Bold+Reload+ClearCache, internal: true)]
+struct PaletteWin {
+    log: Vec<&'static str>,
+    h_menu: Handle<appbar::MenuButton>,
+    h_run: Handle<appbar::Button>,
+}
+impl PaletteWin {
+    fn new() -> Self {
+        let mut w = Self {
+            base: window!("Test,x:0,y:0,w:40,h:8"),
+            log: Vec::new(),
+            h_menu: Handle::None,
+            h_run: Handle::None,
+        };
+        let m = menu!(
+            "class:PaletteWin,items=[
+                {&New,key:Ctrl+N,cmd:New},
+                {&Save,key:Ctrl+S,cmd:Save},
+                {Format,items=[
+                    {&Bold,cmd:Bold}
+                ]}
+            ]"
+        );
+        w.h_menu = w.appbar().add(appbar::MenuButton::new("&File", m, 0, appbar::Side::Left));
+        w.h_run = w.appbar().add(appbar::Button::new("Run", 1, appbar::Side::Left));
+        w
+    }
+}
+impl MenuEvents for PaletteWin {
+    fn on_command(&mut self, _menu: Handle<Menu>, _item: Handle<menu::Command>, command: palettewin::Commands) {
+        self.log.push(match command {
+            palettewin::Commands::New => "menu:New",
+            palettewin::Commands::Save => "menu:Save",
+            palettewin::Commands::Bold => "menu:Bold",
+            _ => "menu:?",
+        });
+    }
+}
+impl AppBarEvents for PaletteWin {
+    fn on_update(&self, appbar: &mut AppBar) {
+        appbar.show(self.h_menu);
+        appbar.show(self.h_run);
+    }
+    fn on_button_click(&mut self, _button: Handle<appbar::Button>) {
+        self.log.push("appbar:Run");
+    }
+}
+impl CommandBarEvents for PaletteWin {
+    fn on_update_commandbar(&self, commandbar: &mut CommandBar) {
+        commandbar.set(key!("F5"), "Reload", palettewin::Commands::Reload);
+    }
+    fn on_update_command_palette(&self, actions: &mut command_palette::Actions) {
+        actions.add("Clear the cache", palettewin::Commands::ClearCache);
+    }
+    fn on_event(&mut self, command_id: palettewin::Commands) {
+        self.log.push(match command_id {
+            palettewin::Commands::Reload => "command:Reload",
+            palettewin::Commands::ClearCache => "action:ClearCache",
+            _ => "command:?",
+        });
+    }
+}
+
+fn setup() -> (TestDriver, Handle<PaletteWin>) {
+    let mut driver = TestDriver::new(App::debug(80, 20, "").app_bar().command_bar().command_palette()).unwrap();
+    let h = driver.add_window(PaletteWin::new());
+    driver.settle();
+    (driver, h)
+}
+
+// opens the command palette, types a text and selects the first entry
+fn run(driver: &mut TestDriver, text: &str) {
+    driver.queue_text(text);
+    driver.queue_key(key!("Enter"));
+    driver.press(key!("Ctrl+Shift+P"));
+}
+
+fn log(driver: &TestDriver, h: Handle<PaletteWin>) -> Vec<&'static str> {
+    driver.get(h).unwrap().log.clone()
+}
+
+#[test]
+fn check_menu_items() {
+    let (mut driver, h) = setup();
+    run(&mut driver, "new");
+    assert_eq!(log(&driver, h), vec!["menu:New"]);
+    // items from a sub-menu
+    run(&mut driver, "bold");
+    assert_eq!(log(&driver, h), vec!["menu:New", "menu:Bold"]);
+}
+
+#[test]
+fn check_appbar_command_bar_and_actions() {
+    let (mut driver, h) = setup();
+    run(&mut driver, "run");
+    run(&mut driver, "reload");
+    run(&mut driver, "cache");
+    assert_eq!(log(&driver, h), vec!["appbar:Run", "command:Reload", "action:ClearCache"]);
+}
+
+#[test]
+fn check_cancel() {
+    let (mut driver, h) = setup();
+    driver.queue_text("save");
+    driver.queue_key(key!("Escape"));
+    driver.press(key!("Ctrl+Shift+P"));
+    // nothing matches - Enter does not close the palette
+    driver.queue_text("xyz");
+    driver.queue_key(key!("Enter"));
+    driver.queue_key(key!("Escape"));
+    driver.press(key!("Ctrl+Shift+P"));
+    assert!(log(&driver, h).is_empty());
+}
+
+#[test]
+fn check_recent_first() {
+    let (mut driver, h) = setup();
+    // by default, the entries are displayed in the order they were collected (the menu items first)
+    run(&mut driver, "");
+    run(&mut driver, "cache");
+    run(&mut driver, "save");
+    // the most recent entry is displayed first, followed by the other recent entries
+    run(&mut driver, "");
+    driver.queue_key(key!("Down"));
+    driver.queue_key(key!("Enter"));
+    driver.press(key!("Ctrl+Shift+P"));
+    assert_eq!(
+        log(&driver, h),
+        vec!["menu:New", "action:ClearCache", "menu:Save", "menu:Save", "action:ClearCache"]
+    );
+    command_palette::clear_recent();
+    run(&mut driver, "");
+    assert_eq!(log(&driver, h).last(), Some(&"menu:New"));
+}
+
+#[test]
+fn check_disabled_palette() {
+    let mut driver = TestDriver::new(App::debug(80, 20, "").app_bar().command_bar()).unwrap();
+    let h = driver.add_window(PaletteWin::new());
+    driver.settle();
+    // the shortcut is ignored (no modal window is opened)
+    driver.press(key!("Ctrl+Shift+P"));
+    assert!(log(&driver, h).is_empty());
+    assert!(!driver.screen_text().contains("Command Palette"));
+}
+
+#[Window(internal: true)]
+struct ViewWin {}
+
+fn view_driver() -> TestDriver {
+    let entries = vec![
+        Entry::new(
+            "File > Paste Special",
+            Source::Menu,
+            key!("Ctrl+Shift+V").into(),
+            Trigger::AppBarItem(Handle::None),
+        ),
+        Entry::new("File > Print", Source::Menu, key!("Ctrl+P").into(), Trigger::AppBarItem(Handle::None)),
+        Entry::new("Open project", Source::Action, Key::None, Trigger::AppBarItem(Handle::None)),
+    ];
+    let mut driver = TestDriver::new(App::debug(60, 10, "")).unwrap();
+    let mut w = ViewWin {
+        base: window!("Test,x:0,y:0,w:60,h:10"),
+    };
+    w.add(PaletteView::new(entries, &RecentCommands::new()));
+    driver.add_window(w);
+    driver.settle();
+    driver
+}
+
+fn line(driver: &TestDriver, y: usize) -> String {
+    driver.screen_text().lines().nth(y).unwrap_or_default().to_string()
+}
+
+#[test]
+fn check_view_filter_and_highlight() {
+    let mut driver = view_driver();
+    // the search line, a separator and the entries (with the source and shortcut right aligned)
+    assert!(line(&driver, 1).contains(">"));
+    assert!(line(&driver, 3).contains("File > Paste Special"));
+    assert!(line(&driver, 3).contains("Menu Ctrl+Shift+V"));
+    assert!(line(&driver, 4).contains("File > Print"));
+    assert!(line(&driver, 5).contains("Open project"));
+    assert!(line(&driver, 5).contains("Action"));
+
+    // "pr" matches the start of the word "Print" better than "project" ("Paste Special" does not match)
+    driver.type_text("pr");
+    assert!(line(&driver, 1).contains("> pr"));
+    assert!(line(&driver, 3).contains("File > Print"));
+    assert!(line(&driver, 4).contains("Open project"));
+    assert!(!driver.screen_text().contains("Paste Special"));
+    // the matched characters ('P' and 'r' from "Print") are highlighted
+    let screen = driver.screen();
+    let normal = screen.char(3, 4).unwrap().foreground;
+    assert_ne!(screen.char(9, 3).unwrap().foreground, screen.char(11, 3).unwrap().foreground);
+    assert_eq!(screen.char(9, 3).unwrap().foreground, screen.char(10, 3).unwrap().foreground);
+    assert_ne!(screen.char(9, 3).unwrap().foreground, normal);
+
+    driver.type_text("x");
+    assert!(line(&driver, 3).contains("No matching commands"));
+    driver.press(key!("Back"));
+    assert!(line(&driver, 4).contains("Open project"));
+    driver.press(key!("Ctrl+Back"));
+    assert!(line(&driver, 3).contains("File > Paste Special"));
+}
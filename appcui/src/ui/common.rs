@@ -9,6 +9,7 @@ pub(crate) mod control_char_attributes_state;
 pub(crate) mod control_base;
 pub(crate) mod container_base;
 pub(crate) mod control_manager;
+pub(crate) mod responsive;
 
 pub(crate) use control_char_attributes_state::ControlCharAttributesState;
 pub(crate) use control_event_wrapper::ControlEvent;
@@ -1,4 +1,5 @@
 use self::control_event_wrapper::CustomEventData;
+use super::responsive::Responsive;
 use super::control_manager::ParentLayout;
 use crate::graphics::*;
 use crate::input::*;
@@ -33,6 +34,7 @@ pub enum StatusFlags {
     IncreaseRightMarginOnFocus = 0x0800,
     IncreaseBottomMarginOnFocus = 0x1000,
     SingleWindow = 0x2000,
+    Collapsed = 0x4000,
 }
 #[derive(Copy, Clone, Default)]
 pub(crate) struct Margins {
@@ -60,6 +62,7 @@ pub struct ControlBase {
     pub(crate) hotkey: Key,
     pub(crate) left_components_margin: u8,
     pub(crate) top_components_margin: u8,
+    responsive: Option<Box<Responsive>>,
 }
 
 impl ControlBase {
@@ -109,6 +112,7 @@ impl ControlBase {
             hotkey: Key::default(),
             left_components_margin: 0,
             top_components_margin: 0,
+            responsive: None,
         }
     }
 
@@ -281,8 +285,9 @@ impl ControlBase {
             self.status_flags.remove(StatusFlags::Expanded)
         }
     }
+    // an expanded control can call this method again to change its expanded size
     pub(crate) fn expand(&self, min_size: Size, prefered_size: Size) {
-        if self.has_focus() && self.children.is_empty() && (!self.is_expanded()) {
+        if self.has_focus() && self.children.is_empty() {
             RuntimeManager::get().request_expand_for_control(self.handle, min_size, prefered_size);
         }
     }
@@ -338,6 +343,10 @@ impl ControlBase {
         let rm = RuntimeManager::get();
         let handle = rm.get_controls_mut().add(c);
         self.children.push(handle);
+        if !self.event_processor.is_none() {
+            // the parent is already part of a window (the control is added at runtime)
+            rm.register_child(handle, self.event_processor);
+        }
         if focusable {
             rm.request_focus_for_control(handle);
             let children_count = self.children.len();
@@ -369,7 +378,7 @@ impl ControlBase {
     /// Returns `true` if the current control is visible or `false` otherwise
     #[inline(always)]
     pub fn is_visible(&self) -> bool {
-        self.status_flags.contains(StatusFlags::Visible)
+        self.status_flags.contains(StatusFlags::Visible) && !self.status_flags.contains_one(StatusFlags::Collapsed)
     }
 
     /// Returns `true` if the current control is enabled or `false` otherwise
@@ -381,7 +390,7 @@ impl ControlBase {
     /// Returns `true` if the current control is active (enabled and visible at the same time) or `false` otherwise
     #[inline(always)]
     pub fn is_active(&self) -> bool {
-        self.status_flags.contains(StatusFlags::Enabled | StatusFlags::Visible)
+        self.status_flags.contains(StatusFlags::Enabled | StatusFlags::Visible) && !self.status_flags.contains_one(StatusFlags::Collapsed)
     }
 
     /// Returns `true` if the current control can receive focus or `false` otherwise. If the control is not visible or it is disable this function will return `false`.
@@ -390,6 +399,7 @@ impl ControlBase {
         // all 3 flags must be present for an object to be able to receive input (key or mouse)
         self.status_flags
             .contains(StatusFlags::Enabled | StatusFlags::Visible | StatusFlags::AcceptInput)
+            && !self.status_flags.contains_one(StatusFlags::Collapsed)
     }
 
     /// Returns `true` if the current control has the focus or `false` otherwise
@@ -576,6 +586,14 @@ impl ControlBase {
             RuntimeManager::get().request_update();
         }
     }
+
+    /// Shows a non-modal toast notification in a corner of the desktop and returns its id. If the toast has an
+    /// action button, the `ToastEvents::on_toast_action` method of the current control will be called when
+    /// the button is pressed. The toast can be closed from code via `toast::dismiss(id)`.
+    pub fn show_toast(&self, toast: crate::ui::toast::Toast) -> u32 {
+        RuntimeManager::get().show_toast(toast, self.handle)
+    }
+
     pub(crate) fn show_tooltip_on_point(&self, txt: &str, x: i32, y: i32) {
         if self.is_visible() && self.screen_clip.is_visible() {
             let r = Rect::with_size(self.screen_clip.left + x, self.screen_clip.top + y, 1, 1);
@@ -667,6 +685,105 @@ impl ControlBase {
         self.layout = ControlLayout::from(layout);
         RuntimeManager::get().request_update();
     }
+
+    /// Adds an alternative layout for the current control. The layout is used when the terminal is at least as large as
+    /// the breakpoint (if more breakpoints match, the one with the biggest minimum width and then the biggest minimum height
+    /// is used). When no breakpoint matches, the control uses the layout it was created with. Adding a layout for a
+    /// breakpoint that already has one replaces it.
+    ///
+    /// The layout is selected again every time the terminal is resized.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use appcui::prelude::*;
+    ///
+    /// // a panel docked on the top side of a narrow terminal and on the left side of a wide one
+    /// let mut p = Panel::new("Options", layout!("d:t,h:6"), panel::Type::Border);
+    /// p.add_responsive_layout(Breakpoint::with_width(120), layout!("d:l,w:30"));
+    /// ```
+    pub fn add_responsive_layout(&mut self, breakpoint: Breakpoint, layout: Layout) {
+        let current = self.layout;
+        self.responsive
+            .get_or_insert_with(|| Box::new(Responsive::new(current)))
+            .add(breakpoint, ControlLayout::from(layout));
+        self.update_breakpoints(RuntimeManager::get().terminal_size());
+        RuntimeManager::get().request_recompute_layout();
+    }
+
+    /// Marks the current control as optional: the control is collapsed (hidden and excluded from the layout of containers
+    /// such as `StackPanel` or `Grid`) while the terminal is smaller than the breakpoint, and shown again when the terminal
+    /// becomes large enough.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use appcui::prelude::*;
+    ///
+    /// // the help label is shown only on terminals with at least 100 columns and 30 rows
+    /// let mut help = label!("'Press F1 for help',x:1,y:1,w:20");
+    /// help.set_collapse_below(Breakpoint::new(100, 30));
+    /// ```
+    pub fn set_collapse_below(&mut self, breakpoint: Breakpoint) {
+        let current = self.layout;
+        self.responsive
+            .get_or_insert_with(|| Box::new(Responsive::new(current)))
+            .set_collapse_below(Some(breakpoint));
+        self.update_breakpoints(RuntimeManager::get().terminal_size());
+        RuntimeManager::get().request_recompute_layout();
+    }
+
+    /// Removes all alternative layouts and the collapse breakpoint of the current control. The control goes back to the
+    /// layout it was created with and it is no longer collapsed.
+    pub fn clear_responsive_layouts(&mut self) {
+        if let Some(r) = self.responsive.take() {
+            self.layout.set_rules_from(r.default_layout());
+            self.status_flags.remove(StatusFlags::Collapsed);
+            RuntimeManager::get().request_recompute_layout();
+        }
+    }
+
+    /// Returns `true` if the current control is collapsed (the terminal is smaller than the breakpoint set via `set_collapse_below`)
+    #[inline(always)]
+    pub fn is_collapsed(&self) -> bool {
+        self.status_flags.contains_one(StatusFlags::Collapsed)
+    }
+
+    // selects the layout and the collapsed state for a terminal size
+    // returns `true` if the layout rules or the collapsed state were changed
+    pub(crate) fn update_breakpoints(&mut self, terminal: Size) -> bool {
+        if let Some(r) = self.responsive.as_mut() {
+            let (layout, collapse_changed) = r.update(terminal);
+            if collapse_changed {
+                if r.is_collapsed() {
+                    self.status_flags.set(StatusFlags::Collapsed);
+                } else {
+                    self.status_flags.remove(StatusFlags::Collapsed);
+                }
+            }
+            if let Some(layout) = &layout {
+                self.layout.set_rules_from(layout);
+            }
+            layout.is_some() || collapse_changed
+        } else {
+            false
+        }
+    }
+
+    // the layout the control was created with (before any alternative layout was applied)
+    pub(crate) fn declared_layout(&self) -> ControlLayout {
+        match &self.responsive {
+            Some(r) => {
+                let mut layout = self.layout;
+                layout.set_rules_from(r.default_layout());
+                layout
+            }
+            None => self.layout,
+        }
+    }
+
+    // the alternative layout selected for the current size of the terminal (if any)
+    pub(crate) fn responsive_layout(&self) -> Option<&ControlLayout> {
+        self.responsive.as_ref().and_then(|r| r.active_layout())
+    }
 }
 // default implementations
 impl OnPaint for ControlBase {}
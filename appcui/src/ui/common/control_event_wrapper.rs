@@ -1,6 +1,6 @@
 use textfield::TextField;
 
-use super::traits::{Control, CustomEvents, EventProcessStatus};
+use super::traits::{ActionRequest, Control, CustomEvents, EventProcessStatus};
 use crate::prelude::colorpicker::events::ColorPickerEvents;
 use crate::prelude::keyselector::events::KeySelectorEvents;
 use crate::prelude::{
@@ -11,11 +11,14 @@ use crate::system::Handle;
 
 use crate::ui::{
     accordion, accordion::events::AccordionEvents, button, button::events::ButtonEvents, charpicker, charpicker::events::CharPickerEvents, checkbox,
-    checkbox::events::CheckBoxEvents, combobox::events::ComboBoxEvents, datepicker::events::DatePickerEvents,
+    checkbox::events::CheckBoxEvents, combobox::events::ComboBoxEvents,
+    datepicker::events::{DatePickerEvents, DateRangePickerEvents, DateTimePickerEvents, TimePickerEvents},
     dropdownlist::events::GenericDropDownListEvents, graphview, graphview::events::GenericGraphViewEvents, listbox::events::ListBoxEvents,
     listview::events::GenericListViewEvents, markdown, markdown::events::MarkdownEvents, numericselector::events::GenericNumericSelectorEvents,
-    password, password::events::PasswordEvents, radiobox, radiobox::events::RadioBoxEvents, tab, tab::events::TabEvents,
-    textfield::events::TextFieldEvents, treeview::events::GenericTreeViewEvents,
+    password, password::events::PasswordEvents, radiobox, radiobox::events::RadioBoxEvents, slider,
+    slider::events::{GenericRangeSliderEvents, GenericSliderEvents}, tab, tab::events::TabEvents, terminal,
+    terminal::events::TerminalEvents, toast, toast::events::ToastEvents,
+    textfield::events::TextFieldEvents, treeview::events::GenericTreeViewEvents, wizard, wizard::Wizard,
 };
 use crate::ui::{pathfinder, treeview};
 
@@ -42,6 +45,9 @@ pub(crate) enum ControlEventData {
     DropDownList(dropdownlist::events::EventData),
     NumericSelector(numericselector::events::EventData),
     DatePicker(datepicker::events::EventData),
+    TimePicker(datepicker::events::TimeEventData),
+    DateTimePicker(datepicker::events::DateTimeEventData),
+    DateRangePicker(datepicker::events::RangeEventData),
     ListBox(listbox::events::EventData),
     ListView(listview::events::EventData),
     PathFinder(pathfinder::events::EventData),
@@ -50,6 +56,11 @@ pub(crate) enum ControlEventData {
     Accordion(accordion::events::EventData),
     Tab(tab::events::EventData),
     GraphView(graphview::events::EventData),
+    Wizard(wizard::events::EventData),
+    Terminal(terminal::events::EventData),
+    Slider(slider::events::EventData),
+    RangeSlider(slider::events::EventData),
+    Toast(toast::events::EventData),
 }
 
 pub(crate) struct ControlEvent {
@@ -80,14 +91,21 @@ impl ControlEvent {
             ControlEventData::TextField(data) => {
                 let h: Handle<TextField> = self.emitter.cast();
                 match data.evtype {
-                    textfield::events::TextFieldEventsType::OnValidate => {
+                    textfield::events::TextFieldEventsType::Validate => {
                         if let Some(tf) = RuntimeManager::get().get_control(h) {
                             TextFieldEvents::on_validate(receiver, self.emitter.cast(), tf.text())
                         } else {
                             EventProcessStatus::Ignored
                         }
                     }
-                    textfield::events::TextFieldEventsType::OnTextChanged => TextFieldEvents::on_text_changed(receiver, self.emitter.cast()),
+                    textfield::events::TextFieldEventsType::TextChanged => TextFieldEvents::on_text_changed(receiver, self.emitter.cast()),
+                    textfield::events::TextFieldEventsType::SuggestionsRequested => {
+                        if let Some(tf) = RuntimeManager::get().get_control(h) {
+                            TextFieldEvents::on_suggestions_requested(receiver, self.emitter.cast(), tf.text())
+                        } else {
+                            EventProcessStatus::Ignored
+                        }
+                    }
                 }
             }
             ControlEventData::Custom(data) => CustomEvents::on_event(receiver, self.emitter.cast(), data.class_hash, data.event_id),
@@ -96,6 +114,9 @@ impl ControlEvent {
             ControlEventData::DropDownList(data) => GenericDropDownListEvents::on_selection_changed(receiver, self.emitter.cast(), data.type_id),
             ControlEventData::NumericSelector(data) => GenericNumericSelectorEvents::on_value_changed(receiver, self.emitter.cast(), data.type_id),
             ControlEventData::DatePicker(data) => DatePickerEvents::on_date_change(receiver, self.emitter.cast(), data.date),
+            ControlEventData::TimePicker(data) => TimePickerEvents::on_time_changed(receiver, self.emitter.cast(), data.time),
+            ControlEventData::DateTimePicker(data) => DateTimePickerEvents::on_date_time_changed(receiver, self.emitter.cast(), data.date_time),
+            ControlEventData::DateRangePicker(data) => DateRangePickerEvents::on_range_changed(receiver, self.emitter.cast(), data.start, data.end),
             ControlEventData::ListBox(data) => match data.event_type {
                 listbox::events::ListBoxEventTypes::CurrentItemChanged => {
                     ListBoxEvents::on_current_item_changed(receiver, self.emitter.cast(), data.index)
@@ -147,7 +168,22 @@ impl ControlEvent {
             ControlEventData::Accordion(data) => {
                 AccordionEvents::on_panel_changed(receiver, self.emitter.cast(), data.new_panel_index, data.old_panel_index)
             }
-            ControlEventData::Tab(data) => TabEvents::on_tab_changed(receiver, self.emitter.cast(), data.new_tab_index, data.old_tab_index),
+            ControlEventData::Tab(data) => match *data {
+                tab::events::EventData::Changed { new_tab_index, old_tab_index } => {
+                    TabEvents::on_tab_changed(receiver, self.emitter.cast(), new_tab_index, old_tab_index)
+                }
+                tab::events::EventData::Closing { index } => {
+                    if TabEvents::on_tab_closing(receiver, self.emitter.cast(), index) == ActionRequest::Allow {
+                        if let Some(t) = RuntimeManager::get().get_control_mut(self.emitter.cast::<tab::Tab>()) {
+                            t.remove_tab(index as usize);
+                        }
+                    }
+                    EventProcessStatus::Processed
+                }
+                tab::events::EventData::Moved { old_index, new_index } => {
+                    TabEvents::on_tab_moved(receiver, self.emitter.cast(), old_index, new_index)
+                }
+            },
             ControlEventData::CharPicker(data) => {
                 CharPickerEvents::on_char_changed(receiver, self.emitter.cast(), if data.code as u32 > 0 { Some(data.code) } else { None })
             }
@@ -159,6 +195,18 @@ impl ControlEvent {
                     GenericGraphViewEvents::on_node_action(receiver, self.emitter.cast(), data.type_id, index)
                 }
             },
+            ControlEventData::Wizard(data) => Wizard::process_event(receiver, self.emitter.cast(), *data),
+            ControlEventData::Terminal(data) => match *data {
+                terminal::events::EventData::ChildExited { exit_code } => TerminalEvents::on_child_exited(receiver, self.emitter.cast(), exit_code),
+                terminal::events::EventData::TitleChanged => {
+                    let handle = self.emitter.cast::<terminal::Terminal>();
+                    let title = RuntimeManager::get().get_control(handle).map(|t| t.title().to_string()).unwrap_or_default();
+                    TerminalEvents::on_title_changed(receiver, handle, &title)
+                }
+            },
+            ControlEventData::Slider(data) => GenericSliderEvents::on_value_changed(receiver, self.emitter.cast(), data.type_id),
+            ControlEventData::RangeSlider(data) => GenericRangeSliderEvents::on_range_changed(receiver, self.emitter.cast(), data.type_id),
+            ControlEventData::Toast(data) => ToastEvents::on_toast_action(receiver, data.toast, data.action),
         }
     }
 }
@@ -1,11 +1,12 @@
 use super::ControlBase;
 use crate::{
-    graphics::{ClipArea, Point},
+    graphics::{ClipArea, Point, Size},
     prelude::RuntimeManager,
     system::{Handle, HandleSupport},
     backend::Backend,
     ui::common::traits::Control,
 };
+use std::any::TypeId;
 use std::ptr::NonNull;
 
 pub(crate) struct ParentLayout {
@@ -14,6 +15,12 @@ pub(crate) struct ParentLayout {
     pub(super) client_width: u16,
     pub(super) client_height: u16,
 }
+impl ParentLayout {
+    #[inline(always)]
+    pub(crate) fn client_size(&self) -> Size {
+        Size::new(self.client_width as u32, self.client_height as u32)
+    }
+}
 impl From<&ControlBase> for ParentLayout {
     fn from(base: &ControlBase) -> Self {
         let client_sz = base.client_size();
@@ -43,6 +50,8 @@ impl From<&Box<dyn Backend>> for ParentLayout {
 pub(crate) struct ControlManager {
     interface: NonNull<dyn Control>,
     base: *mut ControlBase,
+    type_id: TypeId,
+    child_events: bool,
 }
 impl ControlManager {
     #[inline]
@@ -61,6 +70,16 @@ impl ControlManager {
     pub(crate) fn base_mut(&mut self) -> &mut ControlBase {
         unsafe { &mut *self.base }
     }
+    /// `true` if the control overwrites the `OnChildEvent` trait (and should be notified when its children raise events)
+    #[inline(always)]
+    pub(crate) fn notifies_child_events(&self) -> bool {
+        self.child_events
+    }
+    /// The type of the control that is being managed
+    #[inline(always)]
+    pub(crate) fn type_id(&self) -> TypeId {
+        self.type_id
+    }
     pub(crate) fn new<T>(obj: T) -> ControlManager
     where
         T: Control + 'static,
@@ -70,6 +89,8 @@ impl ControlManager {
         ControlManager {
             interface: ctrl,
             base: ptr as *mut ControlBase,
+            type_id: TypeId::of::<T>(),
+            child_events: T::notifies_child_events(),
         }
     }
     pub(crate) fn get_mut<T>(&mut self) -> &mut T
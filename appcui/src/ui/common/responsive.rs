@@ -0,0 +1,78 @@
+use crate::graphics::Size;
+use crate::ui::layout::{Breakpoint, ControlLayout};
+
+// the alternative layouts of a control (selected based on the size of the terminal)
+// and the breakpoint under which the control is collapsed
+pub(crate) struct Responsive {
+    // the layout of the control when no breakpoint matches
+    default: ControlLayout,
+    layouts: Vec<(Breakpoint, ControlLayout)>,
+    collapse_below: Option<Breakpoint>,
+    active: Option<usize>,
+    collapsed: bool,
+    // the layouts were changed since the last update
+    dirty: bool,
+}
+
+impl Responsive {
+    pub(crate) fn new(default: ControlLayout) -> Self {
+        Self {
+            default,
+            layouts: Vec::new(),
+            collapse_below: None,
+            active: None,
+            collapsed: false,
+            dirty: false,
+        }
+    }
+    pub(crate) fn add(&mut self, breakpoint: Breakpoint, layout: ControlLayout) {
+        if let Some(entry) = self.layouts.iter_mut().find(|(b, _)| *b == breakpoint) {
+            entry.1 = layout;
+        } else {
+            self.layouts.push((breakpoint, layout));
+        }
+        self.dirty = true;
+    }
+    pub(crate) fn set_collapse_below(&mut self, breakpoint: Option<Breakpoint>) {
+        self.collapse_below = breakpoint;
+    }
+    #[inline(always)]
+    pub(crate) fn is_collapsed(&self) -> bool {
+        self.collapsed
+    }
+    #[inline(always)]
+    pub(crate) fn default_layout(&self) -> &ControlLayout {
+        &self.default
+    }
+    // the layout selected on the last update (if any)
+    pub(crate) fn active_layout(&self) -> Option<&ControlLayout> {
+        self.active.map(|idx| &self.layouts[idx].1)
+    }
+    // the matching breakpoint with the biggest minimum width (and then the biggest minimum height)
+    fn select(&self, terminal: Size) -> Option<usize> {
+        self.layouts
+            .iter()
+            .enumerate()
+            .filter(|(_, (b, _))| b.matches(terminal))
+            .max_by_key(|(_, (b, _))| (b.min_width(), b.min_height()))
+            .map(|(idx, _)| idx)
+    }
+    // selects the layout and the collapsed state for a terminal size
+    // returns the new layout rules (if the selected layout was changed) and `true` if the collapsed state was changed
+    pub(crate) fn update(&mut self, terminal: Size) -> (Option<ControlLayout>, bool) {
+        let collapsed = self.collapse_below.map(|b| !b.matches(terminal)).unwrap_or(false);
+        let collapse_changed = collapsed != self.collapsed;
+        self.collapsed = collapsed;
+        let active = self.select(terminal);
+        if (active == self.active) && !self.dirty {
+            return (None, collapse_changed);
+        }
+        self.active = active;
+        self.dirty = false;
+        let layout = match active {
+            Some(idx) => self.layouts[idx].1,
+            None => self.default,
+        };
+        (Some(layout), collapse_changed)
+    }
+}
@@ -1,7 +1,7 @@
 use crate::{
     graphics::{Size, Surface},
     input::{Key, MouseEvent},
-    system::{Handle, Theme},
+    system::{Handle, SessionState, Theme},
     ui::{
         appbar::events::AppBarEvents,
         accordion::events::AccordionEvents,
@@ -11,7 +11,7 @@ use crate::{
         charpicker::events::CharPickerEvents,
         combobox::events::ComboBoxEvents,
         command_bar::events::GenericCommandBarEvents,
-        datepicker::events::DatePickerEvents,
+        datepicker::events::{DatePickerEvents, DateRangePickerEvents, DateTimePickerEvents, TimePickerEvents},
         desktop::events::DesktopEvents,
         dropdownlist::events::GenericDropDownListEvents,
         graphview::events::GenericGraphViewEvents,
@@ -25,12 +25,16 @@ use crate::{
         pathfinder::events::PathFinderEvents,
         radiobox::events::RadioBoxEvents,
         selector::events::GenericSelectorEvents,
+        slider::events::{GenericRangeSliderEvents, GenericSliderEvents},
         textfield::events::TextFieldEvents,
         threestatebox::events::ThreeStateBoxEvents,
         togglebutton::events::ToggleButtonEvents,
         treeview::events::GenericTreeViewEvents,
         window::events::{ToolBarEvents, WindowEvents},
         tab::events::TabEvents,
+        terminal::events::TerminalEvents,
+        toast::events::ToastEvents,
+        wizard::events::WizardEvents,
     },
 };
 
@@ -72,11 +76,33 @@ pub trait OnDefaultAction {
 
 pub trait OnResize {
     fn on_resize(&mut self, _old_size: Size, _new_size: Size) {}
+    /// Called after the terminal was resized if the layout (or the collapsed state) of at least one of the children
+    /// of the control was changed by a breakpoint.
+    fn on_children_layout_changed(&mut self) {}
+}
+
+pub trait OnChildEvent {
+    /// Called (for every parent of the control, starting with its direct parent) when a child or a descendant
+    /// of the current control raises an event (before the event is sent to the window that processes it).
+    /// Only the controls that overwrite this trait are notified.
+    #[allow(private_interfaces)]
+    fn on_child_event(&mut self, _handle: Handle<()>) {}
+    #[doc(hidden)]
+    fn notifies_child_events() -> bool
+    where
+        Self: Sized,
+    {
+        true
+    }
 }
 
 pub trait OnFocus {
     fn on_focus(&mut self) {}
     fn on_lose_focus(&mut self) {}
+    /// Called (for every parent of the control, starting with its direct parent) after a child or a descendant
+    /// of the current control received the focus.
+    #[allow(private_interfaces)]
+    fn on_child_focus(&mut self, _handle: Handle<()>) {}
 }
 
 pub trait OnExpand {
@@ -88,6 +114,23 @@ pub trait OnThemeChanged {
     fn on_theme_changed(&mut self, _theme: &Theme) {}
 }
 
+pub trait OnMeasure {
+    /// Returns the size the control needs to display its content (or `None` if the control does not have a preferred size).
+    /// The `available` parameter is the size of the client area of the parent of the control.
+    fn preferred_size(&self, _available: Size) -> Option<Size> {
+        None
+    }
+}
+
+pub trait OnSessionState {
+    /// Called when the state of the application is saved (see `App::save_session`). A control adds in `state` the
+    /// key/value pairs it needs to be restored in the same way in a future session.
+    fn save_state(&self, _state: &mut SessionState) {}
+    /// Called when the state of the application is restored (see `App::load_session`) with the key/value pairs
+    /// the control added in `save_state` during a previous session.
+    fn restore_state(&mut self, _state: &SessionState) {}
+}
+
 pub trait OnWindowRegistered {
     fn on_registered(&mut self) {}
 }
@@ -143,6 +186,9 @@ pub trait Control:
     + OnWindowRegistered
     + OnSiblingSelected
     + OnThemeChanged
+    + OnMeasure
+    + OnSessionState
+    + OnChildEvent
     /* events from each control */
     + ButtonEvents
     + CheckBoxEvents
@@ -176,6 +222,14 @@ pub trait Control:
     + CharPickerEvents
     + GenericGraphViewEvents
     + AppBarEvents
+    + WizardEvents
+    + TerminalEvents
+    + GenericSliderEvents
+    + GenericRangeSliderEvents
+    + TimePickerEvents
+    + DateTimePickerEvents
+    + DateRangePickerEvents
+    + ToastEvents
 {
 }
 
@@ -3,11 +3,19 @@
 //! The DatePicker control provides an intuitive way to input and select dates.
 //! It displays a calendar view that allows navigation between months and years.
 
+mod calendar;
 mod datepicker;
+mod daterangepicker;
+mod datetimepicker;
 mod initialization_flags;
+mod segments;
+mod timepicker;
 #[cfg(test)]
 mod tests;
 pub mod events;
 
-// pub use self::initialization_flags::Flags;
-pub use self::datepicker::DatePicker;
\ No newline at end of file
+pub use self::initialization_flags::Flags;
+pub use self::datepicker::DatePicker;
+pub use self::daterangepicker::DateRangePicker;
+pub use self::datetimepicker::DateTimePicker;
+pub use self::timepicker::TimePicker;
@@ -0,0 +1,241 @@
+use crate::prelude::*;
+use chrono::{Datelike, Days, Months, NaiveDate};
+use std::ops::RangeInclusive;
+
+pub(super) const CALENDAR_WIDTH: u32 = 30;
+pub(super) const CALENDAR_HEIGHT: u32 = 12;
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub(super) enum HoveredDate {
+    DoubleLeftArrow,
+    LeftArrowYear,
+    RightArrowYear,
+    DoubleRightArrow,
+    LeftArrowMonth,
+    RightArrowMonth,
+    Day(u32),
+    None,
+}
+enum CharOrSpecialChar {
+    Regular(char),
+    Special(SpecialChar),
+}
+
+/// The calendar popup shared by the date pickers. It keeps the month that is displayed (through the
+/// `virtual_date` - the date that has the keyboard cursor) and the interval of the dates that can be selected.
+pub(super) struct Calendar {
+    pub(super) panel_y: i32,
+    pub(super) virtual_date: NaiveDate,
+    pub(super) hover: HoveredDate,
+    pub(super) min: NaiveDate,
+    pub(super) max: NaiveDate,
+}
+
+/// Returns the number of days of a month (`month` is between 1 and 12)
+pub(super) fn days_in_month(year: i32, month: u32) -> u32 {
+    let next_month = if month == 12 { 1 } else { month + 1 };
+    let next_month_year = if month == 12 { year + 1 } else { year };
+
+    let first_of_next_month = NaiveDate::from_ymd_opt(next_month_year, next_month, 1).unwrap();
+    let first_of_current_month = NaiveDate::from_ymd_opt(year, month, 1).unwrap();
+
+    first_of_next_month.signed_duration_since(first_of_current_month).num_days() as u32
+}
+
+impl Calendar {
+    const DAYS: [&'static str; 7] = ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"];
+    pub(super) const MONTHS: [&'static str; 12] = ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"];
+
+    pub(super) fn new(date: NaiveDate) -> Self {
+        Self {
+            panel_y: 1,
+            virtual_date: date,
+            hover: HoveredDate::None,
+            min: NaiveDate::MIN,
+            max: NaiveDate::MAX,
+        }
+    }
+
+    /// Returns `true` if the date can be selected (it is within the bounds of the calendar)
+    #[inline(always)]
+    pub(super) fn contains(&self, date: NaiveDate) -> bool {
+        date >= self.min && date <= self.max
+    }
+
+    pub(super) fn hit_test(&mut self, x: i32, y: i32) -> HoveredDate {
+        if y == 1 + self.panel_y {
+            if x == 5 {
+                return HoveredDate::LeftArrowYear;
+            }
+            if x == 12 {
+                return HoveredDate::RightArrowYear;
+            }
+            if x == 2 || x == 3 {
+                return HoveredDate::DoubleLeftArrow;
+            }
+            if x == 14 || x == 15 {
+                return HoveredDate::DoubleRightArrow;
+            }
+
+            if x == 20 {
+                return HoveredDate::LeftArrowMonth;
+            }
+            if x == 26 {
+                return HoveredDate::RightArrowMonth;
+            }
+        }
+        let mut col = self.first_day_index() * 4 + 3;
+        let mut row = 4 + self.panel_y;
+        let last_day = self.days_in_month() as i32;
+
+        for i in 0..last_day {
+            let day = i + 1;
+
+            if (y == row) && (x == col || x == (col - 1)) {
+                self.virtual_date = self.virtual_date.with_day(day as u32).unwrap();
+                return HoveredDate::Day(day as u32);
+            }
+
+            col += 4;
+            if col >= 30 {
+                col = 3;
+                row += 1;
+            }
+        }
+        HoveredDate::None
+    }
+
+    /// Processes a click on the calendar. The arrows change the displayed month (or year) and a click on a day
+    /// returns the selected date (if it is within the bounds of the calendar).
+    pub(super) fn on_pressed(&mut self, hovered: HoveredDate) -> Option<NaiveDate> {
+        match hovered {
+            HoveredDate::DoubleLeftArrow => self.virtual_date = self.virtual_date - Months::new(120),
+            HoveredDate::LeftArrowYear => self.virtual_date = self.virtual_date - Months::new(12),
+            HoveredDate::RightArrowYear => self.virtual_date = self.virtual_date + Months::new(12),
+            HoveredDate::DoubleRightArrow => self.virtual_date = self.virtual_date + Months::new(120),
+            HoveredDate::LeftArrowMonth => self.virtual_date = self.virtual_date - Months::new(1),
+            HoveredDate::RightArrowMonth => self.virtual_date = self.virtual_date + Months::new(1),
+            HoveredDate::Day(day) => {
+                let date = self.virtual_date.with_day(day).unwrap();
+                return if self.contains(date) { Some(date) } else { None };
+            }
+            HoveredDate::None => {}
+        }
+        None
+    }
+
+    /// Moves the keyboard cursor (the `virtual_date`). Returns `false` if the key is not a navigation key.
+    pub(super) fn on_key_pressed(&mut self, key: Key) -> bool {
+        self.virtual_date = match key.value() {
+            key!("Up") => self.virtual_date - Days::new(7),
+            key!("Down") => self.virtual_date + Days::new(7),
+            key!("Left") => self.virtual_date - Days::new(1),
+            key!("Right") => self.virtual_date + Days::new(1),
+            key!("Shift+Left") => self.virtual_date - Months::new(1),
+            key!("Shift+Right") => self.virtual_date + Months::new(1),
+            key!("Ctrl+Left") => self.virtual_date - Months::new(12),
+            key!("Ctrl+Right") => self.virtual_date + Months::new(12),
+            key!("Ctrl+Shift+Left") => self.virtual_date - Months::new(120),
+            key!("Ctrl+Shift+Right") => self.virtual_date + Months::new(120),
+            _ => return false,
+        };
+        true
+    }
+
+    fn days_in_month(&self) -> u32 {
+        days_in_month(self.virtual_date.year(), self.virtual_date.month())
+    }
+
+    fn first_day_index(&self) -> i32 {
+        let first_day = self.virtual_date.with_day(1).unwrap().format("%a").to_string();
+        for i in 0..Self::DAYS.len() {
+            if first_day.starts_with(Self::DAYS[i]) {
+                return i as i32;
+            }
+        }
+        0
+    }
+
+    /// Paints the calendar (the days from `selection` are highlighted)
+    pub(super) fn paint(&self, surface: &mut Surface, theme: &Theme, size: Size, selection: RangeInclusive<NaiveDate>) {
+        let col = theme.menu.text.normal;
+        let space_char = Character::with_attributes(' ', col);
+        surface.fill_rect(Rect::with_size(0, self.panel_y, size.width as u16, (size.height - 1) as u16), space_char);
+        surface.draw_rect(
+            Rect::with_size(0, self.panel_y, size.width as u16, (size.height - 1) as u16),
+            LineType::Single,
+            col,
+        );
+        surface.draw_horizontal_line(1, 2 + self.panel_y, (size.width - 1) as i32, LineType::SingleRound, col);
+        surface.write_char(0, 2 + self.panel_y, Character::with_attributes(SpecialChar::BoxMidleLeft, col));
+        surface.write_char(
+            (size.width - 1) as i32,
+            2 + self.panel_y,
+            Character::with_attributes(SpecialChar::BoxMidleRight, col),
+        );
+
+        let year = self.virtual_date.year();
+        let p_y = 1 + self.panel_y;
+        surface.write_char(7, p_y, Character::with_attributes(((year / 1000) + 48) as u8, col));
+        surface.write_char(8, p_y, Character::with_attributes(((year / 100) % 10 + 48) as u8, col));
+        surface.write_char(9, p_y, Character::with_attributes(((year / 10) % 10 + 48) as u8, col));
+        surface.write_char(10, p_y, Character::with_attributes((year % 10 + 48) as u8, col));
+
+        fn set_char(surface: &mut Surface, x: i32, y: i32, char_or_special: CharOrSpecialChar, condition: bool, theme: &Theme) {
+            let attr = if condition { theme.menu.text.hovered } else { theme.menu.text.normal };
+            let character = match char_or_special {
+                CharOrSpecialChar::Regular(c) => Character::with_attributes(c, attr),
+                CharOrSpecialChar::Special(sc) => Character::with_attributes(sc, attr),
+            };
+            surface.write_char(x, y, character);
+        }
+        let y_pos = 1 + self.panel_y;
+        let arrows = [
+            (5, CharOrSpecialChar::Special(SpecialChar::TriangleLeft), HoveredDate::LeftArrowYear),
+            (12, CharOrSpecialChar::Special(SpecialChar::TriangleRight), HoveredDate::RightArrowYear),
+            (2, CharOrSpecialChar::Regular('<'), HoveredDate::DoubleLeftArrow),
+            (3, CharOrSpecialChar::Regular('<'), HoveredDate::DoubleLeftArrow),
+            (14, CharOrSpecialChar::Regular('>'), HoveredDate::DoubleRightArrow),
+            (15, CharOrSpecialChar::Regular('>'), HoveredDate::DoubleRightArrow),
+            (20, CharOrSpecialChar::Special(SpecialChar::TriangleLeft), HoveredDate::LeftArrowMonth),
+            (26, CharOrSpecialChar::Special(SpecialChar::TriangleRight), HoveredDate::RightArrowMonth),
+        ];
+        for (x, ch, hovered) in arrows {
+            set_char(surface, x, y_pos, ch, self.hover == hovered, theme);
+        }
+
+        let month = Self::MONTHS[self.virtual_date.month0() as usize];
+        surface.write_ascii(22, 1 + self.panel_y, month.as_bytes(), col, false);
+
+        let mut x = 2;
+        for day_name in Self::DAYS {
+            surface.write_ascii(x, 3 + self.panel_y, day_name.as_bytes(), theme.menu.text.inactive, false);
+            x += 4;
+        }
+
+        let mut day_row = 4 + self.panel_y;
+        let mut day_col = self.first_day_index() * 4 + 3;
+
+        let last_day = self.days_in_month();
+
+        for i in 0..last_day {
+            let day = i + 1;
+            let date = self.virtual_date.with_day(day).unwrap();
+            let day_attr = if self.contains(date) { col } else { theme.menu.text.inactive };
+            surface.write_char(day_col, day_row, Character::with_attributes((day % 10 + 48) as u8 as char, day_attr));
+            if day > 9 {
+                surface.write_char(day_col - 1, day_row, Character::with_attributes((day / 10 + 48) as u8 as char, day_attr));
+            }
+            if selection.contains(&date) {
+                surface.fill_horizontal_line_with_size(day_col - 2, day_row, 4, Character::with_attributes(0, theme.menu.text.pressed_or_selectd));
+            } else if self.virtual_date.day() == day {
+                surface.fill_horizontal_line_with_size(day_col - 2, day_row, 4, Character::with_attributes(0, theme.menu.text.hovered));
+            }
+            day_col += 4;
+            if day_col >= 30 {
+                day_col = 3;
+                day_row += 1;
+            }
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use super::calendar::{Calendar, HoveredDate, CALENDAR_HEIGHT, CALENDAR_WIDTH};
 use chrono::{Datelike, Days, Months, NaiveDate};
 use datepicker::events::EventData;
 use appcui_proc_macro::CustomControl;
@@ -7,43 +8,21 @@ const MINSPACE_FOR_LONG_DATE: u32 = 18;
 const MINSPACE_FOR_DROPBUTTON_DRAWING: u32 = 3;
 const MINSPACE_FOR_DATE_DRAWING: u32 = 5;
 const MIN_WIDTH_FOR_DATE_NAME: u32 = 6;
-const CALENDAR_WIDTH: u32 = 30;
-const CALENDAR_HEIGHT: u32 = 12;
 enum DateSize {
     Large,
     Small,
     VerySmall,
 }
-#[derive(PartialEq, Eq)]
-enum HoveredDate {
-    DoubleLeftArrow,
-    LeftArrowYear,
-    RightArrowYear,
-    DoubleRightArrow,
-    LeftArrowMonth,
-    RightArrowMonth,
-    Day(u32),
-    None,
-}
-enum CharOrSpecialChar {
-    Regular(char),
-    Special(SpecialChar),
-}
 
 #[CustomControl(overwrite=OnPaint+OnDefaultAction+OnExpand+OnMouseEvent+OnKeyPressed, internal=true)]
 pub struct DatePicker {
     header_y_ofs: i32,
-    expanded_panel_y: i32,
     selected_date: NaiveDate,
     date_string: String,
-    hover_date: HoveredDate,
-    virtual_date: NaiveDate,
+    calendar: Calendar,
 }
 
 impl DatePicker {
-    const DAYS: [&'static str; 7] = ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"];
-    const MONTHS: [&'static str; 12] = ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"];
-
     /// Creates a new date picker with a NaiveDate and a layout.
     ///
     /// # Example
@@ -57,11 +36,9 @@ impl DatePicker {
         let mut dp = DatePicker {
             base: ControlBase::with_status_flags(layout, StatusFlags::Enabled | StatusFlags::Visible | StatusFlags::AcceptInput),
             header_y_ofs: 0,
-            expanded_panel_y: 1,
             selected_date: date,
             date_string: Self::format_long_date(date),
-            hover_date: HoveredDate::None,
-            virtual_date: date,
+            calendar: Calendar::new(date),
         };
         dp.set_size_bounds(6, 1, u16::MAX, 1);
         let date_len = dp.get_date_size();
@@ -143,46 +120,7 @@ impl DatePicker {
         if !self.is_expanded() {
             return HoveredDate::None;
         }
-        if y == 1 + self.expanded_panel_y {
-            if x == 5 {
-                return HoveredDate::LeftArrowYear;
-            }
-            if x == 12 {
-                return HoveredDate::RightArrowYear;
-            }
-            if x == 2 || x == 3 {
-                return HoveredDate::DoubleLeftArrow;
-            }
-            if x == 14 || x == 15 {
-                return HoveredDate::DoubleRightArrow;
-            }
-
-            if x == 20 {
-                return HoveredDate::LeftArrowMonth;
-            }
-            if x == 26 {
-                return HoveredDate::RightArrowMonth;
-            }
-        }
-        let mut col = self.get_first_day_index() * 4 + 3;
-        let mut row = 4 + self.expanded_panel_y;
-        let last_day = self.days_in_month() as i32;
-
-        for i in 0..last_day {
-            let day = i + 1;
-
-            if (y == row) && (x == col || x == (col - 1)) {
-                self.virtual_date = self.virtual_date.with_day(day as u32).unwrap();
-                return HoveredDate::Day(day as u32);
-            }
-
-            col += 4;
-            if col >= 30 {
-                col = 3;
-                row += 1;
-            }
-        }
-        HoveredDate::None
+        self.calendar.hit_test(x, y)
     }
 
     fn format_very_short_date(selected_date: NaiveDate) -> String {
@@ -215,27 +153,6 @@ impl DatePicker {
         }
     }
 
-    fn days_in_month(&self) -> u32 {
-        let month = self.virtual_date.month();
-        let year = self.virtual_date.year();
-        let next_month = if month == 12 { 1 } else { month + 1 };
-        let next_month_year = if month == 12 { year + 1 } else { year };
-
-        let first_of_next_month = NaiveDate::from_ymd_opt(next_month_year, next_month, 1).unwrap();
-        let first_of_current_month = NaiveDate::from_ymd_opt(year, month, 1).unwrap();
-
-        first_of_next_month.signed_duration_since(first_of_current_month).num_days() as u32
-    }
-
-    fn get_first_day_index(&self) -> i32 {
-        let first_day = self.virtual_date.with_day(1).unwrap().format("%a").to_string();
-        for i in 0..DatePicker::DAYS.len() {
-            if first_day.starts_with(DatePicker::DAYS[i]) {
-                return i as i32;
-            }
-        }
-        0
-    }
 }
 
 impl OnPaint for DatePicker {
@@ -289,153 +206,7 @@ impl OnPaint for DatePicker {
 
         // expanded calendar
         if self.is_expanded() {
-            let size = self.expanded_size();
-            let col = theme.menu.text.normal;
-            let space_char = Character::with_attributes(' ', col);
-            surface.fill_rect(
-                Rect::with_size(0, self.expanded_panel_y, size.width as u16, (size.height - 1) as u16),
-                space_char,
-            );
-            surface.draw_rect(
-                Rect::with_size(0, self.expanded_panel_y, size.width as u16, (size.height - 1) as u16),
-                LineType::Single,
-                col,
-            );
-            surface.draw_horizontal_line(1, 2 + self.expanded_panel_y, (CALENDAR_WIDTH - 1) as i32, LineType::SingleRound, col);
-            surface.write_char(0, 2 + self.expanded_panel_y, Character::with_attributes(SpecialChar::BoxMidleLeft, col));
-            surface.write_char(
-                (CALENDAR_WIDTH - 1) as i32,
-                2 + self.expanded_panel_y,
-                Character::with_attributes(SpecialChar::BoxMidleRight, col),
-            );
-
-            let year = self.virtual_date.year();
-            let p_y = 1 + self.expanded_panel_y;
-            surface.write_char(7, p_y, Character::with_attributes(((year / 1000) + 48) as u8, col));
-            surface.write_char(8, p_y, Character::with_attributes(((year / 100) % 10 + 48) as u8, col));
-            surface.write_char(9, p_y, Character::with_attributes(((year / 10) % 10 + 48) as u8, col));
-            surface.write_char(10, p_y, Character::with_attributes((year % 10 + 48) as u8, col));
-
-            fn set_char(surface: &mut Surface, x: i32, y: i32, char_or_special: CharOrSpecialChar, condition: bool, theme: &Theme) {
-                let attr = if condition { theme.menu.text.hovered } else { theme.menu.text.normal };
-                let character = match char_or_special {
-                    CharOrSpecialChar::Regular(c) => Character::with_attributes(c, attr),
-                    CharOrSpecialChar::Special(sc) => Character::with_attributes(sc, attr),
-                };
-                surface.write_char(x, y, character);
-            }
-            let y_pos = 1 + self.expanded_panel_y;
-
-            set_char(
-                surface,
-                5,
-                y_pos,
-                CharOrSpecialChar::Special(SpecialChar::TriangleLeft),
-                self.hover_date == HoveredDate::LeftArrowYear,
-                theme,
-            );
-            set_char(
-                surface,
-                12,
-                y_pos,
-                CharOrSpecialChar::Special(SpecialChar::TriangleRight),
-                self.hover_date == HoveredDate::RightArrowYear,
-                theme,
-            );
-            set_char(
-                surface,
-                2,
-                y_pos,
-                CharOrSpecialChar::Regular('<'),
-                self.hover_date == HoveredDate::DoubleLeftArrow,
-                theme,
-            );
-            set_char(
-                surface,
-                3,
-                y_pos,
-                CharOrSpecialChar::Regular('<'),
-                self.hover_date == HoveredDate::DoubleLeftArrow,
-                theme,
-            );
-            set_char(
-                surface,
-                14,
-                y_pos,
-                CharOrSpecialChar::Regular('>'),
-                self.hover_date == HoveredDate::DoubleRightArrow,
-                theme,
-            );
-            set_char(
-                surface,
-                15,
-                y_pos,
-                CharOrSpecialChar::Regular('>'),
-                self.hover_date == HoveredDate::DoubleRightArrow,
-                theme,
-            );
-            set_char(
-                surface,
-                20,
-                y_pos,
-                CharOrSpecialChar::Special(SpecialChar::TriangleLeft),
-                self.hover_date == HoveredDate::LeftArrowMonth,
-                theme,
-            );
-            set_char(
-                surface,
-                26,
-                y_pos,
-                CharOrSpecialChar::Special(SpecialChar::TriangleRight),
-                self.hover_date == HoveredDate::RightArrowMonth,
-                theme,
-            );
-
-            let month = Self::MONTHS[self.virtual_date.month0() as usize];
-            surface.write_ascii(22, 1 + self.expanded_panel_y, month.as_bytes(), col, false);
-
-            let mut x = 2;
-            for i in 0..7 {
-                surface.write_ascii(
-                    x,
-                    3 + self.expanded_panel_y,
-                    DatePicker::DAYS[i].as_bytes(),
-                    theme.menu.text.inactive,
-                    false,
-                );
-                x += 4;
-            }
-
-            let mut day_row = 4 + self.expanded_panel_y;
-            let mut day_col = self.get_first_day_index() * 4 + 3;
-
-            let last_day = self.days_in_month();
-
-            for i in 0..last_day {
-                let day = i + 1;
-                surface.write_char(day_col, day_row, Character::with_attributes((day % 10 + 48) as u8 as char, col));
-                if day > 9 {
-                    surface.write_char(day_col - 1, day_row, Character::with_attributes((day / 10 + 48) as u8 as char, col));
-                }
-                if day == self.selected_date.day()
-                    && self.selected_date.month() == self.virtual_date.month()
-                    && self.selected_date.year() == self.virtual_date.year()
-                {
-                    surface.fill_horizontal_line_with_size(
-                        day_col - 2,
-                        day_row,
-                        4,
-                        Character::with_attributes(0, theme.menu.text.pressed_or_selectd),
-                    );
-                } else if self.virtual_date.day() == day {
-                    surface.fill_horizontal_line_with_size(day_col - 2, day_row, 4, Character::with_attributes(0, theme.menu.text.hovered));
-                }
-                day_col += 4;
-                if day_col >= 30 {
-                    day_col = 3;
-                    day_row += 1;
-                }
-            }
+            self.calendar.paint(surface, theme, self.expanded_size(), self.selected_date..=self.selected_date);
         }
     }
 }
@@ -451,21 +222,21 @@ impl OnDefaultAction for DatePicker {
 }
 impl OnExpand for DatePicker {
     fn on_expand(&mut self, direction: ExpandedDirection) {
-        self.virtual_date = self.selected_date;
+        self.calendar.virtual_date = self.selected_date;
         match direction {
             ExpandedDirection::OnTop => {
-                self.expanded_panel_y = 0;
+                self.calendar.panel_y = 0;
                 self.header_y_ofs = (self.expanded_size().height as i32) - 1;
             }
             ExpandedDirection::OnBottom => {
-                self.expanded_panel_y = 1;
+                self.calendar.panel_y = 1;
                 self.header_y_ofs = 0;
             }
         }
-        self.hover_date = HoveredDate::None;
+        self.calendar.hover = HoveredDate::None;
     }
     fn on_pack(&mut self) {
-        self.expanded_panel_y = 1;
+        self.calendar.panel_y = 1;
         self.header_y_ofs = 0;
     }
 }
@@ -486,41 +257,18 @@ impl OnMouseEvent for DatePicker {
             }
             MouseEvent::Over(p) => {
                 let hd = self.mouse_over_calendar(p.x, p.y);
-                if hd != self.hover_date {
-                    self.hover_date = hd;
+                if hd != self.calendar.hover {
+                    self.calendar.hover = hd;
                 }
                 EventProcessStatus::Processed
             }
             MouseEvent::Pressed(data) => {
                 let hd = self.mouse_over_calendar(data.x, data.y);
                 if hd != HoveredDate::None {
-                    match hd {
-                        HoveredDate::DoubleLeftArrow => {
-                            self.virtual_date = self.virtual_date - Months::new(120);
-                        }
-                        HoveredDate::LeftArrowYear => {
-                            self.virtual_date = self.virtual_date - Months::new(12);
-                        }
-                        HoveredDate::RightArrowYear => {
-                            self.virtual_date = self.virtual_date + Months::new(12);
-                        }
-                        HoveredDate::DoubleRightArrow => {
-                            self.virtual_date = self.virtual_date + Months::new(120);
-                        }
-                        HoveredDate::LeftArrowMonth => {
-                            self.virtual_date = self.virtual_date - Months::new(1);
-                        }
-                        HoveredDate::RightArrowMonth => {
-                            self.virtual_date = self.virtual_date + Months::new(1);
-                        }
-                        HoveredDate::Day(day) => {
-                            self.update_date(self.virtual_date.with_day(day).unwrap());
-                            self.on_default_action();
-                        }
-
-                        _ => {}
+                    if let Some(date) = self.calendar.on_pressed(hd) {
+                        self.update_date(date);
+                        self.on_default_action();
                     }
-
                     return EventProcessStatus::Processed;
                 }
                 self.on_default_action();
@@ -546,7 +294,7 @@ impl OnKeyPressed for DatePicker {
                     _ => unreachable!(),
                 };
                 if expanded {
-                    self.virtual_date = Self::jump_to_month(self.virtual_date, month);
+                    self.calendar.virtual_date = Self::jump_to_month(self.calendar.virtual_date, month);
                 } else {
                     self.update_date(Self::jump_to_month(self.selected_date, month));
                 }
@@ -573,7 +321,7 @@ impl OnKeyPressed for DatePicker {
                     }
                     _ => unreachable!(),
                 };
-                let target_month: &mut NaiveDate = if expanded { &mut self.virtual_date } else { &mut self.selected_date };
+                let target_month: &mut NaiveDate = if expanded { &mut self.calendar.virtual_date } else { &mut self.selected_date };
 
                 let month = {
                     let mut current_month = target_month.month() as i32 + val;
@@ -583,8 +331,8 @@ impl OnKeyPressed for DatePicker {
                     if current_month < 1 {
                         current_month = 12;
                     }
-                    for _ in 0..Self::MONTHS.len() {
-                        if Self::MONTHS[(current_month - 1) as usize].starts_with(month_char) {
+                    for _ in 0..Calendar::MONTHS.len() {
+                        if Calendar::MONTHS[(current_month - 1) as usize].starts_with(month_char) {
                             break;
                         } else {
                             current_month += val;
@@ -599,7 +347,7 @@ impl OnKeyPressed for DatePicker {
                     current_month
                 };
                 if expanded {
-                    self.virtual_date = Self::jump_to_month(self.virtual_date, month as u32);
+                    self.calendar.virtual_date = Self::jump_to_month(self.calendar.virtual_date, month as u32);
                 } else {
                     self.update_date(Self::jump_to_month(self.selected_date, month as u32));
                 }
@@ -667,65 +415,16 @@ impl OnKeyPressed for DatePicker {
                     self.pack();
                     return EventProcessStatus::Processed;
                 }
-
-                key!("Up") => {
-                    self.virtual_date = self.virtual_date - Days::new(7);
-                    return EventProcessStatus::Processed;
-                }
-
-                key!("Down") => {
-                    self.virtual_date = self.virtual_date + Days::new(7);
-                    return EventProcessStatus::Processed;
-                }
-
-                key!("Left") => {
-                    self.virtual_date = self.virtual_date - Days::new(1);
-                    return EventProcessStatus::Processed;
-                }
-
-                key!("Right") => {
-                    self.virtual_date = self.virtual_date + Days::new(1);
-                    return EventProcessStatus::Processed;
-                }
-
-                key!("Shift+Left") => {
-                    self.virtual_date = self.virtual_date - Months::new(1);
-                    return EventProcessStatus::Processed;
-                }
-
-                key!("Shift+Right") => {
-                    self.virtual_date = self.virtual_date + Months::new(1);
-                    return EventProcessStatus::Processed;
-                }
-
-                key!("Ctrl+Left") => {
-                    self.virtual_date = self.virtual_date - Months::new(12);
-                    return EventProcessStatus::Processed;
-                }
-
-                key!("Ctrl+Right") => {
-                    self.virtual_date = self.virtual_date + Months::new(12);
-                    return EventProcessStatus::Processed;
-                }
-
-                key!("Ctrl+Shift+Left") => {
-                    self.virtual_date = self.virtual_date - Months::new(120);
-                    return EventProcessStatus::Processed;
-                }
-
-                key!("Ctrl+Shift+Right") => {
-                    self.virtual_date = self.virtual_date + Months::new(120);
-                    return EventProcessStatus::Processed;
-                }
-
                 key!("Enter") => {
-                    self.update_date(self.virtual_date);
+                    self.update_date(self.calendar.virtual_date);
                     self.on_default_action();
                     return EventProcessStatus::Processed;
                 }
                 _ => {}
             }
-
+            if self.calendar.on_key_pressed(key) {
+                return EventProcessStatus::Processed;
+            }
             EventProcessStatus::Ignored
         }
     }
@@ -0,0 +1,256 @@
+use super::calendar::{Calendar, HoveredDate, CALENDAR_HEIGHT, CALENDAR_WIDTH};
+use super::events::RangeEventData;
+use crate::prelude::*;
+use chrono::NaiveDate;
+
+const DROPBUTTON_WIDTH: u32 = 3;
+// "YYYY-MM-DD - YYYY-MM-DD"
+const RANGE_WIDTH: u32 = 23;
+
+#[CustomControl(overwrite=OnPaint+OnDefaultAction+OnExpand+OnMouseEvent+OnKeyPressed, internal=true)]
+pub struct DateRangePicker {
+    header_y_ofs: i32,
+    start: NaiveDate,
+    end: NaiveDate,
+    pending_start: Option<NaiveDate>,
+    range_string: String,
+    calendar: Calendar,
+}
+
+impl DateRangePicker {
+    /// Creates a new date-range picker with two date strings (the start and the end of the interval) and a layout.
+    /// The date strings must be in the format "YYYY-MM-DD".
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use appcui::prelude::*;
+    ///
+    /// let drp = DateRangePicker::new("2024-06-01", "2024-06-30", layout!("x:1,y:1,w:28"));
+    /// ```
+    pub fn new(start_str: &str, end_str: &str, layout: Layout) -> Self {
+        let start = start_str.parse::<NaiveDate>().unwrap();
+        let end = end_str.parse::<NaiveDate>().unwrap();
+        Self::with_range(start, end, layout)
+    }
+
+    /// Creates a new date-range picker with the start and the end of the interval and a layout.
+    /// If `start` is after `end`, the two dates are swapped.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use appcui::prelude::*;
+    /// use chrono::NaiveDate;
+    ///
+    /// let drp = DateRangePicker::with_range(NaiveDate::from_ymd_opt(2024, 6, 1).unwrap(),
+    ///                                       NaiveDate::from_ymd_opt(2024, 6, 30).unwrap(),
+    ///                                       layout!("x:1,y:1,w:28"));
+    /// ```
+    pub fn with_range(start: NaiveDate, end: NaiveDate, layout: Layout) -> Self {
+        let mut drp = DateRangePicker {
+            base: ControlBase::with_status_flags(layout, StatusFlags::Enabled | StatusFlags::Visible | StatusFlags::AcceptInput),
+            header_y_ofs: 0,
+            start,
+            end,
+            pending_start: None,
+            range_string: String::new(),
+            calendar: Calendar::new(start),
+        };
+        drp.set_size_bounds((RANGE_WIDTH + 2 + DROPBUTTON_WIDTH) as u16, 1, u16::MAX, 1);
+        drp.set_range(start, end);
+        drp
+    }
+
+    /// Returns the start of the selected interval
+    #[inline(always)]
+    pub fn start(&self) -> NaiveDate {
+        self.start
+    }
+
+    /// Returns the end of the selected interval
+    #[inline(always)]
+    pub fn end(&self) -> NaiveDate {
+        self.end
+    }
+
+    /// Returns the selected interval as a `(start, end)` tuple
+    #[inline(always)]
+    pub fn range(&self) -> (NaiveDate, NaiveDate) {
+        (self.start, self.end)
+    }
+
+    /// Sets the selected interval. The dates are swapped if `start` is after `end` and clamped to the
+    /// interval [min, max].
+    pub fn set_range(&mut self, start: NaiveDate, end: NaiveDate) {
+        let (start, end) = if start <= end { (start, end) } else { (end, start) };
+        self.start = start.clamp(self.calendar.min, self.calendar.max);
+        self.end = end.clamp(self.calendar.min, self.calendar.max);
+        self.range_string = format!("{} - {}", self.start.format("%Y-%m-%d"), self.end.format("%Y-%m-%d"));
+    }
+
+    /// Sets the interval of the dates that can be selected (the limits are swapped if `min` is after `max`).
+    /// The selected interval is clamped to the new limits.
+    pub fn set_bounds(&mut self, min: NaiveDate, max: NaiveDate) {
+        (self.calendar.min, self.calendar.max) = if min <= max { (min, max) } else { (max, min) };
+        self.set_range(self.start, self.end);
+    }
+
+    /// Returns the first date that can be selected
+    #[inline(always)]
+    pub fn min(&self) -> NaiveDate {
+        self.calendar.min
+    }
+
+    /// Returns the last date that can be selected
+    #[inline(always)]
+    pub fn max(&self) -> NaiveDate {
+        self.calendar.max
+    }
+
+    // the first selected day is the start of the interval, the second one is the end of the interval
+    fn select_date(&mut self, date: NaiveDate) {
+        if let Some(start) = self.pending_start.take() {
+            self.update_range(start, date);
+            self.pack();
+        } else {
+            self.pending_start = Some(date);
+        }
+    }
+
+    fn update_range(&mut self, start: NaiveDate, end: NaiveDate) {
+        let old = (self.start, self.end);
+        self.set_range(start, end);
+        if old != (self.start, self.end) {
+            self.raise_event(ControlEvent {
+                emitter: self.handle,
+                receiver: self.event_processor,
+                data: ControlEventData::DateRangePicker(RangeEventData {
+                    start: self.start,
+                    end: self.end,
+                }),
+            });
+        }
+    }
+
+    fn mouse_over_calendar(&mut self, x: i32, y: i32) -> HoveredDate {
+        if !self.is_expanded() {
+            return HoveredDate::None;
+        }
+        self.calendar.hit_test(x, y)
+    }
+}
+
+impl OnPaint for DateRangePicker {
+    fn on_paint(&self, surface: &mut Surface, theme: &Theme) {
+        let size = self.size();
+        let col_text = match () {
+            _ if !self.is_enabled() => theme.button.text.inactive,
+            _ if self.has_focus() => theme.button.text.focused,
+            _ if self.is_mouse_over() => theme.button.text.hovered,
+            _ => theme.button.text.normal,
+        };
+        surface.fill_horizontal_line_with_size(0, self.header_y_ofs, size.width, Character::with_attributes(' ', col_text));
+        surface.write_string(1, self.header_y_ofs, &self.range_string, col_text, false);
+        let px = (size.width.saturating_sub(DROPBUTTON_WIDTH)) as i32;
+        surface.write_char(px + 1, self.header_y_ofs, Character::with_attributes(SpecialChar::TriangleDown, col_text));
+
+        if self.is_expanded() {
+            // while the end of the interval is chosen, the interval between the start and the cursor is highlighted
+            let selection = match self.pending_start {
+                Some(start) if start <= self.calendar.virtual_date => start..=self.calendar.virtual_date,
+                Some(start) => self.calendar.virtual_date..=start,
+                None => self.start..=self.end,
+            };
+            self.calendar.paint(surface, theme, self.expanded_size(), selection);
+        }
+    }
+}
+
+impl OnDefaultAction for DateRangePicker {
+    fn on_default_action(&mut self) {
+        if self.is_expanded() {
+            self.pack();
+        } else {
+            let size = Size::new(CALENDAR_WIDTH.max(self.size().width), CALENDAR_HEIGHT);
+            self.expand(size, size);
+        }
+    }
+}
+
+impl OnExpand for DateRangePicker {
+    fn on_expand(&mut self, direction: ExpandedDirection) {
+        self.calendar.virtual_date = self.start;
+        self.pending_start = None;
+        match direction {
+            ExpandedDirection::OnTop => {
+                self.calendar.panel_y = 0;
+                self.header_y_ofs = (self.expanded_size().height as i32) - 1;
+            }
+            ExpandedDirection::OnBottom => {
+                self.calendar.panel_y = 1;
+                self.header_y_ofs = 0;
+            }
+        }
+        self.calendar.hover = HoveredDate::None;
+    }
+    fn on_pack(&mut self) {
+        self.calendar.panel_y = 1;
+        self.header_y_ofs = 0;
+        self.pending_start = None;
+    }
+}
+
+impl OnMouseEvent for DateRangePicker {
+    fn on_mouse_event(&mut self, event: &MouseEvent) -> EventProcessStatus {
+        match event {
+            MouseEvent::Enter | MouseEvent::Leave => EventProcessStatus::Processed,
+            MouseEvent::Over(p) => {
+                self.calendar.hover = self.mouse_over_calendar(p.x, p.y);
+                EventProcessStatus::Processed
+            }
+            MouseEvent::Pressed(data) => {
+                let hd = self.mouse_over_calendar(data.x, data.y);
+                if hd != HoveredDate::None {
+                    if let Some(date) = self.calendar.on_pressed(hd) {
+                        self.select_date(date);
+                    }
+                    return EventProcessStatus::Processed;
+                }
+                self.on_default_action();
+                EventProcessStatus::Processed
+            }
+            _ => EventProcessStatus::Ignored,
+        }
+    }
+}
+
+impl OnKeyPressed for DateRangePicker {
+    fn on_key_pressed(&mut self, key: Key, _character: char) -> EventProcessStatus {
+        if !self.is_expanded() {
+            if let key!("Enter") | key!("Space") = key.value() {
+                self.on_default_action();
+                return EventProcessStatus::Processed;
+            }
+            return EventProcessStatus::Ignored;
+        }
+        match key.value() {
+            key!("Escape") => {
+                // the first escape cancels the selection of the interval (if any), the second one closes the calendar
+                if self.pending_start.take().is_none() {
+                    self.pack();
+                }
+                return EventProcessStatus::Processed;
+            }
+            key!("Enter") | key!("Space") => {
+                if self.calendar.contains(self.calendar.virtual_date) {
+                    self.select_date(self.calendar.virtual_date);
+                }
+                return EventProcessStatus::Processed;
+            }
+            _ => {}
+        }
+        if self.calendar.on_key_pressed(key) {
+            return EventProcessStatus::Processed;
+        }
+        EventProcessStatus::Ignored
+    }
+}
@@ -0,0 +1,340 @@
+use super::calendar::{Calendar, HoveredDate, CALENDAR_HEIGHT, CALENDAR_WIDTH};
+use super::events::DateTimeEventData;
+use super::segments::Segments;
+use super::Flags;
+use crate::prelude::*;
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime};
+
+const DROPBUTTON_WIDTH: u32 = 3;
+
+#[CustomControl(overwrite=OnPaint+OnDefaultAction+OnExpand+OnMouseEvent+OnKeyPressed, internal=true)]
+pub struct DateTimePicker {
+    header_y_ofs: i32,
+    date_time: NaiveDateTime,
+    time_zone: Option<FixedOffset>,
+    min: NaiveDateTime,
+    max: NaiveDateTime,
+    flags: Flags,
+    segments: Segments,
+    calendar: Calendar,
+}
+
+impl DateTimePicker {
+    /// Creates a new date-time picker with a date-time string, a layout and flags.
+    /// The date-time string must be in the format "YYYY-MM-DD HH:MM:SS".
+    /// The flags can be a combination of the following values:
+    /// * `Flags::Hour12` - the time is displayed in a 12 hour format (with an AM/PM indicator)
+    /// * `Flags::HideSeconds` - the seconds are not displayed (and can not be changed)
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use appcui::prelude::*;
+    ///
+    /// let dtp = DateTimePicker::new("2024-06-13 14:30:00", layout!("x:1,y:1,w:24"), datepicker::Flags::None);
+    /// ```
+    pub fn new(date_time_str: &str, layout: Layout, flags: Flags) -> Self {
+        let date_time = NaiveDateTime::parse_from_str(date_time_str, "%Y-%m-%d %H:%M:%S").unwrap();
+        Self::with_date_time(date_time, layout, flags)
+    }
+
+    /// Creates a new date-time picker with a NaiveDateTime, a layout and flags.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use appcui::prelude::*;
+    /// use chrono::NaiveDate;
+    ///
+    /// let date_time = NaiveDate::from_ymd_opt(2024, 6, 13).unwrap().and_hms_opt(9, 0, 0).unwrap();
+    /// let dtp = DateTimePicker::with_date_time(date_time, layout!("x:1,y:1,w:24"), datepicker::Flags::HideSeconds);
+    /// ```
+    pub fn with_date_time(date_time: NaiveDateTime, layout: Layout, flags: Flags) -> Self {
+        let mut dtp = DateTimePicker {
+            base: ControlBase::with_status_flags(layout, StatusFlags::Enabled | StatusFlags::Visible | StatusFlags::AcceptInput),
+            header_y_ofs: 0,
+            date_time,
+            time_zone: None,
+            min: NaiveDate::from_ymd_opt(1, 1, 1).unwrap().and_time(NaiveTime::MIN),
+            max: NaiveDate::from_ymd_opt(9999, 12, 31).unwrap().and_hms_opt(23, 59, 59).unwrap(),
+            flags,
+            segments: Segments::new(true, false, flags),
+            calendar: Calendar::new(date_time.date()),
+        };
+        dtp.update_segments();
+        dtp.set_date_time(date_time);
+        dtp
+    }
+
+    /// Returns the date and time of the picker
+    #[inline(always)]
+    pub fn date_time(&self) -> NaiveDateTime {
+        self.date_time
+    }
+
+    /// Returns the date part of the picker
+    #[inline(always)]
+    pub fn date(&self) -> NaiveDate {
+        self.date_time.date()
+    }
+
+    /// Returns the time part of the picker
+    #[inline(always)]
+    pub fn time(&self) -> NaiveTime {
+        self.date_time.time()
+    }
+
+    /// Sets the date and time of the picker. If the value is outside the interval [min, max], it will be set to the closest limit.
+    pub fn set_date_time(&mut self, date_time: NaiveDateTime) {
+        self.date_time = date_time.clamp(self.min, self.max);
+        self.segments.update_text(self.date_time, self.time_zone);
+    }
+
+    /// Sets the interval of the values that can be selected (the limits are swapped if `min` is bigger than `max`).
+    /// The current value is clamped to the new interval and the days outside the interval can not be selected from the calendar.
+    pub fn set_bounds(&mut self, min: NaiveDateTime, max: NaiveDateTime) {
+        (self.min, self.max) = if min <= max { (min, max) } else { (max, min) };
+        self.calendar.min = self.min.date();
+        self.calendar.max = self.max.date();
+        self.set_date_time(self.date_time);
+    }
+
+    /// Returns the minimum value that can be selected
+    #[inline(always)]
+    pub fn min(&self) -> NaiveDateTime {
+        self.min
+    }
+
+    /// Returns the maximum value that can be selected
+    #[inline(always)]
+    pub fn max(&self) -> NaiveDateTime {
+        self.max
+    }
+
+    /// Sets the time zone of the picker. If a time zone is provided, its offset is displayed after the time and
+    /// can be changed by the user (in steps of 15 minutes, between -12:00 and +14:00).
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use appcui::prelude::*;
+    /// use chrono::FixedOffset;
+    ///
+    /// let mut dtp = DateTimePicker::new("2024-06-13 14:30:00", layout!("x:1,y:1,w:32"), datepicker::Flags::None);
+    /// dtp.set_time_zone(FixedOffset::east_opt(2 * 3600));
+    /// ```
+    pub fn set_time_zone(&mut self, time_zone: Option<FixedOffset>) {
+        self.time_zone = time_zone;
+        self.update_segments();
+    }
+
+    /// Returns the time zone of the picker (if any)
+    #[inline(always)]
+    pub fn time_zone(&self) -> Option<FixedOffset> {
+        self.time_zone
+    }
+
+    /// Returns the date and time of the picker in the selected time zone (or `None` if no time zone was set)
+    pub fn date_time_with_time_zone(&self) -> Option<DateTime<FixedOffset>> {
+        self.time_zone.and_then(|tz| self.date_time.and_local_timezone(tz).single())
+    }
+
+    fn update_segments(&mut self) {
+        self.segments = Segments::new(true, self.time_zone.is_some(), self.flags);
+        self.segments.update_text(self.date_time, self.time_zone);
+        let min_width = (self.segments.width() + 2 + DROPBUTTON_WIDTH) as u16;
+        self.set_size_bounds(min_width, 1, u16::MAX, 1);
+    }
+
+    fn update_date_time(&mut self, date_time: NaiveDateTime, time_zone: Option<FixedOffset>) {
+        let date_time = date_time.clamp(self.min, self.max);
+        if date_time != self.date_time || time_zone != self.time_zone {
+            self.date_time = date_time;
+            self.time_zone = time_zone;
+            self.segments.update_text(self.date_time, self.time_zone);
+            self.raise_event(ControlEvent {
+                emitter: self.handle,
+                receiver: self.event_processor,
+                data: ControlEventData::DateTimePicker(DateTimeEventData { date_time: self.date_time }),
+            });
+        }
+    }
+
+    fn step(&mut self, delta: i32) {
+        let mut time_zone = self.time_zone;
+        let value = self.segments.step(self.date_time, &mut time_zone, delta);
+        self.update_date_time(value, time_zone);
+    }
+
+    fn mouse_over_calendar(&mut self, x: i32, y: i32) -> HoveredDate {
+        if !self.is_expanded() {
+            return HoveredDate::None;
+        }
+        self.calendar.hit_test(x, y)
+    }
+
+    fn select_date(&mut self, date: NaiveDate) {
+        self.update_date_time(date.and_time(self.date_time.time()), self.time_zone);
+        self.pack();
+    }
+}
+
+impl OnPaint for DateTimePicker {
+    fn on_paint(&self, surface: &mut Surface, theme: &Theme) {
+        let size = self.size();
+        let col_text = match () {
+            _ if !self.is_enabled() => theme.button.text.inactive,
+            _ if self.has_focus() => theme.button.text.focused,
+            _ if self.is_mouse_over() => theme.button.text.hovered,
+            _ => theme.button.text.normal,
+        };
+        surface.fill_horizontal_line_with_size(0, self.header_y_ofs, size.width, Character::with_attributes(' ', col_text));
+        let selected = if self.has_focus() && !self.is_expanded() {
+            Some(theme.button.text.pressed_or_selectd)
+        } else {
+            None
+        };
+        self.segments.paint(surface, 1, self.header_y_ofs, col_text, selected);
+        let px = (size.width.saturating_sub(DROPBUTTON_WIDTH)) as i32;
+        surface.write_char(px + 1, self.header_y_ofs, Character::with_attributes(SpecialChar::TriangleDown, col_text));
+
+        if self.is_expanded() {
+            let selected_date = self.date_time.date();
+            self.calendar.paint(surface, theme, self.expanded_size(), selected_date..=selected_date);
+        }
+    }
+}
+
+impl OnDefaultAction for DateTimePicker {
+    fn on_default_action(&mut self) {
+        if self.is_expanded() {
+            self.pack();
+        } else {
+            let size = Size::new(CALENDAR_WIDTH.max(self.size().width), CALENDAR_HEIGHT);
+            self.expand(size, size);
+        }
+    }
+}
+
+impl OnExpand for DateTimePicker {
+    fn on_expand(&mut self, direction: ExpandedDirection) {
+        self.calendar.virtual_date = self.date_time.date();
+        match direction {
+            ExpandedDirection::OnTop => {
+                self.calendar.panel_y = 0;
+                self.header_y_ofs = (self.expanded_size().height as i32) - 1;
+            }
+            ExpandedDirection::OnBottom => {
+                self.calendar.panel_y = 1;
+                self.header_y_ofs = 0;
+            }
+        }
+        self.calendar.hover = HoveredDate::None;
+    }
+    fn on_pack(&mut self) {
+        self.calendar.panel_y = 1;
+        self.header_y_ofs = 0;
+    }
+}
+
+impl OnMouseEvent for DateTimePicker {
+    fn on_mouse_event(&mut self, event: &MouseEvent) -> EventProcessStatus {
+        match event {
+            MouseEvent::Enter | MouseEvent::Leave => EventProcessStatus::Processed,
+            MouseEvent::Over(p) => {
+                self.calendar.hover = self.mouse_over_calendar(p.x, p.y);
+                EventProcessStatus::Processed
+            }
+            MouseEvent::Pressed(data) => {
+                let hd = self.mouse_over_calendar(data.x, data.y);
+                if hd != HoveredDate::None {
+                    if let Some(date) = self.calendar.on_pressed(hd) {
+                        self.select_date(date);
+                    }
+                    return EventProcessStatus::Processed;
+                }
+                let button = (self.size().width.saturating_sub(DROPBUTTON_WIDTH)) as i32;
+                if !self.is_expanded() && data.y == self.header_y_ofs && data.x < button {
+                    self.segments.select_at(data.x - 1);
+                } else {
+                    self.on_default_action();
+                }
+                EventProcessStatus::Processed
+            }
+            MouseEvent::Wheel(direction) if !self.is_expanded() => {
+                match direction {
+                    MouseWheelDirection::Up => self.step(1),
+                    MouseWheelDirection::Down => self.step(-1),
+                    _ => return EventProcessStatus::Ignored,
+                }
+                EventProcessStatus::Processed
+            }
+            _ => EventProcessStatus::Ignored,
+        }
+    }
+}
+
+impl OnKeyPressed for DateTimePicker {
+    fn on_key_pressed(&mut self, key: Key, character: char) -> EventProcessStatus {
+        if self.is_expanded() {
+            match key.value() {
+                key!("Escape") => {
+                    self.pack();
+                    return EventProcessStatus::Processed;
+                }
+                key!("Enter") => {
+                    if self.calendar.contains(self.calendar.virtual_date) {
+                        self.select_date(self.calendar.virtual_date);
+                    }
+                    return EventProcessStatus::Processed;
+                }
+                _ => {}
+            }
+            if self.calendar.on_key_pressed(key) {
+                return EventProcessStatus::Processed;
+            }
+            return EventProcessStatus::Ignored;
+        }
+        match key.value() {
+            key!("Enter") | key!("Space") => {
+                self.on_default_action();
+                EventProcessStatus::Processed
+            }
+            key!("Left") => {
+                self.segments.select_previous();
+                EventProcessStatus::Processed
+            }
+            key!("Right") => {
+                self.segments.select_next();
+                EventProcessStatus::Processed
+            }
+            key!("Up") => {
+                self.step(1);
+                EventProcessStatus::Processed
+            }
+            key!("Down") => {
+                self.step(-1);
+                EventProcessStatus::Processed
+            }
+            key!("Home") => {
+                self.update_date_time(self.min, self.time_zone);
+                EventProcessStatus::Processed
+            }
+            key!("End") => {
+                self.update_date_time(self.max, self.time_zone);
+                EventProcessStatus::Processed
+            }
+            key!("A") | key!("P") => {
+                let value = self.segments.set_pm(self.date_time, key.value() == key!("P"));
+                self.update_date_time(value, self.time_zone);
+                EventProcessStatus::Processed
+            }
+            _ => {
+                if let Some(digit) = character.to_digit(10) {
+                    if let Some(value) = self.segments.type_digit(self.date_time, digit) {
+                        self.update_date_time(value, self.time_zone);
+                    }
+                    return EventProcessStatus::Processed;
+                }
+                EventProcessStatus::Ignored
+            }
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{system::Handle, ui::common::traits::EventProcessStatus};
-use super::DatePicker;
-use chrono::NaiveDate;
+use super::{DatePicker, DateRangePicker, DateTimePicker, TimePicker};
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
 
 pub trait DatePickerEvents {
     fn on_date_change(&mut self, _handle: Handle<DatePicker>, _date: NaiveDate) -> EventProcessStatus {
@@ -10,4 +10,35 @@ pub trait DatePickerEvents {
 #[derive(Copy, Clone)]
 pub(crate) struct EventData{
     pub date: NaiveDate,
-}
\ No newline at end of file
+}
+
+pub trait TimePickerEvents {
+    fn on_time_changed(&mut self, _handle: Handle<TimePicker>, _time: NaiveTime) -> EventProcessStatus {
+        EventProcessStatus::Ignored
+    }
+}
+#[derive(Copy, Clone)]
+pub(crate) struct TimeEventData {
+    pub time: NaiveTime,
+}
+
+pub trait DateTimePickerEvents {
+    fn on_date_time_changed(&mut self, _handle: Handle<DateTimePicker>, _date_time: NaiveDateTime) -> EventProcessStatus {
+        EventProcessStatus::Ignored
+    }
+}
+#[derive(Copy, Clone)]
+pub(crate) struct DateTimeEventData {
+    pub date_time: NaiveDateTime,
+}
+
+pub trait DateRangePickerEvents {
+    fn on_range_changed(&mut self, _handle: Handle<DateRangePicker>, _start: NaiveDate, _end: NaiveDate) -> EventProcessStatus {
+        EventProcessStatus::Ignored
+    }
+}
+#[derive(Copy, Clone)]
+pub(crate) struct RangeEventData {
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+}
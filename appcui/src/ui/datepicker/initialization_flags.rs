@@ -0,0 +1,7 @@
+use EnumBitFlags::EnumBitFlags;
+
+#[EnumBitFlags(bits = 8)]
+pub enum Flags {
+    Hour12 = 0x0001,
+    HideSeconds = 0x0002,
+}
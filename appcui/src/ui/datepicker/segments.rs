@@ -0,0 +1,253 @@
+use super::calendar::days_in_month;
+use super::Flags;
+use crate::prelude::*;
+use chrono::{Datelike, FixedOffset, Months, NaiveDate, NaiveDateTime, Timelike};
+use std::fmt::Write;
+
+const MIN_OFFSET: i32 = -12 * 3600;
+const MAX_OFFSET: i32 = 14 * 3600;
+const OFFSET_STEP: i32 = 15 * 60;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub(super) enum Field {
+    Year,
+    Month,
+    Day,
+    Hour,
+    Minute,
+    Second,
+    AmPm,
+    Offset,
+}
+
+impl Field {
+    fn width(&self) -> u32 {
+        match self {
+            Field::Year => 4,
+            Field::Offset => 6,
+            _ => 2,
+        }
+    }
+}
+
+/// The editable representation (`YYYY-MM-DD HH:MM:SS AM +HH:MM`) of a date and/or a time used by the
+/// time pickers. Every part (segment) of the representation can be selected and changed individually
+/// (its value wraps around within the limits of the segment).
+pub(super) struct Segments {
+    items: Vec<(Field, i32)>,
+    current: usize,
+    typed: u32,
+    typed_digits: u32,
+    hour12: bool,
+    width: u32,
+    txt: String,
+}
+
+impl Segments {
+    pub(super) fn new(has_date: bool, has_offset: bool, flags: Flags) -> Self {
+        let mut items = Vec::with_capacity(8);
+        let mut x = 0;
+        if has_date {
+            items.push((Field::Year, 0));
+            items.push((Field::Month, 5));
+            items.push((Field::Day, 8));
+            x = 11;
+        }
+        items.push((Field::Hour, x));
+        items.push((Field::Minute, x + 3));
+        x += 5;
+        if !flags.contains(Flags::HideSeconds) {
+            items.push((Field::Second, x + 1));
+            x += 3;
+        }
+        if flags.contains(Flags::Hour12) {
+            items.push((Field::AmPm, x + 1));
+            x += 3;
+        }
+        if has_offset {
+            items.push((Field::Offset, x + 1));
+            x += 7;
+        }
+        // the hour is selected by default
+        let current = if has_date { 3 } else { 0 };
+        Self {
+            items,
+            current,
+            typed: 0,
+            typed_digits: 0,
+            hour12: flags.contains(Flags::Hour12),
+            width: x as u32,
+            txt: String::new(),
+        }
+    }
+
+    /// Returns the number of characters of the representation
+    #[inline(always)]
+    pub(super) fn width(&self) -> u32 {
+        self.width
+    }
+
+    #[inline(always)]
+    pub(super) fn current(&self) -> Field {
+        self.items[self.current].0
+    }
+
+    pub(super) fn update_text(&mut self, value: NaiveDateTime, offset: Option<FixedOffset>) {
+        self.txt.clear();
+        if self.items[0].0 == Field::Year {
+            let _ = write!(self.txt, "{:04}-{:02}-{:02} ", value.year(), value.month(), value.day());
+        }
+        let hour = if self.hour12 { value.hour12().1 } else { value.hour() };
+        let _ = write!(self.txt, "{:02}:{:02}", hour, value.minute());
+        if self.items.iter().any(|i| i.0 == Field::Second) {
+            let _ = write!(self.txt, ":{:02}", value.second());
+        }
+        if self.hour12 {
+            self.txt.push_str(if value.hour() >= 12 { " PM" } else { " AM" });
+        }
+        if let Some(offset) = offset {
+            let seconds = offset.local_minus_utc();
+            let sign = if seconds < 0 { '-' } else { '+' };
+            let seconds = seconds.abs();
+            let _ = write!(self.txt, " {}{:02}:{:02}", sign, seconds / 3600, (seconds % 3600) / 60);
+        }
+    }
+
+    #[inline(always)]
+    pub(super) fn reset_typing(&mut self) {
+        self.typed = 0;
+        self.typed_digits = 0;
+    }
+
+    pub(super) fn select_next(&mut self) -> bool {
+        self.reset_typing();
+        if self.current + 1 < self.items.len() {
+            self.current += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    pub(super) fn select_previous(&mut self) -> bool {
+        self.reset_typing();
+        if self.current > 0 {
+            self.current -= 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Selects the segment found at the `x` position (relative to the beginning of the representation)
+    pub(super) fn select_at(&mut self, x: i32) -> bool {
+        if let Some(idx) = self.items.iter().position(|(f, pos)| x >= *pos && x < *pos + f.width() as i32) {
+            self.reset_typing();
+            self.current = idx;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Adds `delta` units to the selected segment (the other segments are not modified)
+    pub(super) fn step(&mut self, value: NaiveDateTime, offset: &mut Option<FixedOffset>, delta: i32) -> NaiveDateTime {
+        self.reset_typing();
+        let date = value.date();
+        let time = value.time();
+        match self.current() {
+            Field::Year => {
+                let months = Months::new(12 * delta.unsigned_abs());
+                let result = if delta < 0 {
+                    value.checked_sub_months(months)
+                } else {
+                    value.checked_add_months(months)
+                };
+                result.unwrap_or(value)
+            }
+            Field::Month => {
+                let month = (date.month0() as i32 + delta).rem_euclid(12) as u32 + 1;
+                let day = date.day().min(days_in_month(date.year(), month));
+                NaiveDate::from_ymd_opt(date.year(), month, day)
+                    .map(|d| d.and_time(time))
+                    .unwrap_or(value)
+            }
+            Field::Day => {
+                let days = days_in_month(date.year(), date.month()) as i32;
+                let day = (date.day0() as i32 + delta).rem_euclid(days) as u32 + 1;
+                value.with_day(day).unwrap_or(value)
+            }
+            Field::Hour => value.with_hour((time.hour() as i32 + delta).rem_euclid(24) as u32).unwrap_or(value),
+            Field::Minute => value.with_minute((time.minute() as i32 + delta).rem_euclid(60) as u32).unwrap_or(value),
+            Field::Second => value.with_second((time.second() as i32 + delta).rem_euclid(60) as u32).unwrap_or(value),
+            Field::AmPm => value.with_hour((time.hour() + 12) % 24).unwrap_or(value),
+            Field::Offset => {
+                if let Some(tz) = offset {
+                    let count = (MAX_OFFSET - MIN_OFFSET) / OFFSET_STEP + 1;
+                    let idx = ((tz.local_minus_utc() - MIN_OFFSET) / OFFSET_STEP + delta).rem_euclid(count);
+                    *offset = FixedOffset::east_opt(MIN_OFFSET + idx * OFFSET_STEP);
+                }
+                value
+            }
+        }
+    }
+
+    /// Sets the AM/PM part of the time (if the representation uses a 12 hour format)
+    pub(super) fn set_pm(&mut self, value: NaiveDateTime, pm: bool) -> NaiveDateTime {
+        self.reset_typing();
+        if !self.hour12 {
+            return value;
+        }
+        let hour = value.hour() % 12 + if pm { 12 } else { 0 };
+        value.with_hour(hour).unwrap_or(value)
+    }
+
+    /// Adds a digit to the selected segment. The new value is returned if it is valid (the year is applied only
+    /// after all four digits were typed). Once the segment is complete, the next segment is selected.
+    pub(super) fn type_digit(&mut self, value: NaiveDateTime, digit: u32) -> Option<NaiveDateTime> {
+        let field = self.current();
+        let (min, max) = match field {
+            Field::Year => (1, 9999),
+            Field::Month => (1, 12),
+            Field::Day => (1, days_in_month(value.year(), value.month())),
+            Field::Hour if self.hour12 => (1, 12),
+            Field::Hour => (0, 23),
+            Field::Minute | Field::Second => (0, 59),
+            Field::AmPm | Field::Offset => return None,
+        };
+        self.typed = self.typed * 10 + digit;
+        self.typed_digits += 1;
+        let typed = self.typed;
+        let complete = (self.typed_digits >= field.width()) || (typed * 10 > max);
+        if complete {
+            self.select_next();
+        }
+        if typed < min || typed > max || (field == Field::Year && !complete) {
+            return None;
+        }
+        match field {
+            Field::Year => value
+                .with_year(typed as i32)
+                .or_else(|| value.with_day(28).and_then(|v| v.with_year(typed as i32))),
+            Field::Month => {
+                let day = value.day().min(days_in_month(value.year(), typed));
+                NaiveDate::from_ymd_opt(value.year(), typed, day).map(|d| d.and_time(value.time()))
+            }
+            Field::Day => value.with_day(typed),
+            Field::Hour if self.hour12 => value.with_hour(typed % 12 + if value.hour() >= 12 { 12 } else { 0 }),
+            Field::Hour => value.with_hour(typed),
+            Field::Minute => value.with_minute(typed),
+            Field::Second => value.with_second(typed),
+            Field::AmPm | Field::Offset => None,
+        }
+    }
+
+    /// Paints the representation. If `selected` is provided, the selected segment is highlighted with it.
+    pub(super) fn paint(&self, surface: &mut Surface, x: i32, y: i32, attr: CharAttribute, selected: Option<CharAttribute>) {
+        surface.write_string(x, y, &self.txt, attr, false);
+        if let Some(sel) = selected {
+            let (field, pos) = self.items[self.current];
+            surface.fill_horizontal_line_with_size(x + pos, y, field.width(), Character::with_attributes(0, sel));
+        }
+    }
+}
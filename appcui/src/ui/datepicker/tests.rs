@@ -522,4 +522,255 @@ fn check_expanded_keys(){
     w.add(DatePicker::new("2024-06-13", layout!("a:c,w:19,h:100%")));
     a.add_window(w);
     a.run();
-}
\ No newline at end of file
+}
+// the pickers are created after the test driver (the size bounds of a control require the runtime manager)
+fn picker_window<T, F>(create: F, width: u16) -> (crate::testing::TestDriver, Handle<T>)
+where
+    T: Control + NotWindow + NotDesktop + 'static,
+    F: FnOnce() -> T,
+{
+    let mut driver = crate::testing::TestDriver::new(App::debug(width + 4, 20, "")).unwrap();
+    let control = create();
+    let mut w = Window::new(
+        "Pickers",
+        LayoutBuilder::new().x(0).y(0).width(width + 2).height(5).build(),
+        window::Flags::None,
+    );
+    let handle = w.add(control);
+    driver.add_window(w);
+    driver.focus(handle);
+    driver.settle();
+    (driver, handle)
+}
+
+fn row(driver: &crate::testing::TestDriver, y: i32, from: i32, to: i32) -> String {
+    (from..to).filter_map(|x| driver.screen().char(x, y).map(|c| c.code)).collect()
+}
+
+fn time(h: u32, m: u32, s: u32) -> chrono::NaiveTime {
+    chrono::NaiveTime::from_hms_opt(h, m, s).unwrap()
+}
+
+fn date(y: i32, m: u32, d: u32) -> chrono::NaiveDate {
+    chrono::NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+#[test]
+fn check_time_picker_keys() {
+    let (mut driver, h) = picker_window(|| TimePicker::new("14:30:05", layout!("x:0,y:0,w:12"), datepicker::Flags::None), 12);
+    assert_eq!(row(&driver, 1, 1, 13), " 14:30:05   ");
+    // the hour is selected by default and every segment wraps around
+    driver.press(key!("Up"));
+    assert_eq!(driver.get(h).unwrap().time(), time(15, 30, 5));
+    driver.press(key!("Right"));
+    driver.press(key!("Down"));
+    assert_eq!(driver.get(h).unwrap().time(), time(15, 29, 5));
+    // once the minutes are typed, the seconds are selected
+    driver.type_text("45");
+    assert_eq!(driver.get(h).unwrap().time(), time(15, 45, 5));
+    driver.press(key!("Up"));
+    assert_eq!(driver.get(h).unwrap().time(), time(15, 45, 6));
+    driver.get_mut(h).unwrap().set_time(time(23, 59, 59));
+    driver.press(key!("Up"));
+    assert_eq!(driver.get(h).unwrap().time(), time(23, 59, 0));
+    driver.press(key!("Home"));
+    assert_eq!(driver.get(h).unwrap().time(), time(0, 0, 0));
+    assert_eq!(row(&driver, 1, 1, 13), " 00:00:00   ");
+}
+
+#[test]
+fn check_time_picker_hour12_and_bounds() {
+    let (mut driver, h) = picker_window(
+        || TimePicker::new("13:05", layout!("x:0,y:0,w:12"), datepicker::Flags::Hour12 | datepicker::Flags::HideSeconds),
+        12,
+    );
+    assert_eq!(row(&driver, 1, 1, 13), " 01:05 PM   ");
+    driver.press(key!("A"));
+    assert_eq!(driver.get(h).unwrap().time(), time(1, 5, 0));
+    // a 12 hour value keeps the AM/PM part
+    driver.type_text("11");
+    assert_eq!(driver.get(h).unwrap().time(), time(11, 5, 0));
+    driver.get_mut(h).unwrap().set_bounds(time(18, 0, 0), time(8, 0, 0));
+    assert_eq!(driver.get(h).unwrap().min(), time(8, 0, 0));
+    assert_eq!(driver.get(h).unwrap().time(), time(11, 5, 0));
+    driver.press(key!("P"));
+    assert_eq!(driver.get(h).unwrap().time(), time(18, 0, 0));
+    driver.press(key!("End"));
+    assert_eq!(row(&driver, 1, 1, 13), " 06:00 PM   ");
+    driver.get_mut(h).unwrap().set_time(time(7, 0, 0));
+    assert_eq!(driver.get(h).unwrap().time(), time(8, 0, 0));
+}
+
+#[test]
+fn check_time_picker_mouse() {
+    let (mut driver, h) = picker_window(|| TimePicker::new("10:20:30", layout!("x:0,y:0,w:12"), datepicker::Flags::None), 12);
+    // click on the seconds
+    driver.click_at(9, 1);
+    driver.press(key!("Up"));
+    assert_eq!(driver.get(h).unwrap().time(), time(10, 20, 31));
+    // click on the minutes
+    driver.click_at(5, 1);
+    driver.press(key!("Down"));
+    assert_eq!(driver.get(h).unwrap().time(), time(10, 19, 31));
+}
+
+#[test]
+fn check_date_time_picker() {
+    let (mut driver, h) = picker_window(
+        || DateTimePicker::new("2024-01-31 23:59:00", layout!("x:0,y:0,w:26"), datepicker::Flags::None),
+        26,
+    );
+    assert_eq!(row(&driver, 1, 1, 22), " 2024-01-31 23:59:00 ");
+    assert_eq!(driver.screen().char(25, 1).unwrap().code, '▼');
+    // the day is clamped to the length of the month
+    driver.press(key!("Left"));
+    driver.press(key!("Left"));
+    driver.press(key!("Up"));
+    assert_eq!(driver.get(h).unwrap().date(), date(2024, 2, 29));
+    driver.press(key!("Left"));
+    driver.press(key!("Up"));
+    assert_eq!(driver.get(h).unwrap().date(), date(2025, 2, 28));
+    driver.type_text("2030");
+    assert_eq!(driver.get(h).unwrap().date(), date(2030, 2, 28));
+    // the calendar changes only the date
+    driver.press(key!("Enter"));
+    assert!(driver.get(h).unwrap().is_expanded());
+    driver.press(key!("Right"));
+    driver.press(key!("Enter"));
+    assert!(!driver.get(h).unwrap().is_expanded());
+    assert_eq!(driver.get(h).unwrap().date_time(), date(2030, 3, 1).and_hms_opt(23, 59, 0).unwrap());
+}
+
+#[test]
+fn check_date_time_picker_time_zone_and_bounds() {
+    let (mut driver, h) = picker_window(
+        || DateTimePicker::new("2024-06-13 14:30:00", layout!("x:0,y:0,w:32"), datepicker::Flags::HideSeconds),
+        32,
+    );
+    assert!(driver.get(h).unwrap().date_time_with_time_zone().is_none());
+    driver.get_mut(h).unwrap().set_time_zone(chrono::FixedOffset::east_opt(2 * 3600));
+    driver.settle();
+    assert_eq!(row(&driver, 1, 1, 25), " 2024-06-13 14:30 +02:00");
+    driver.press(key!("Right"));
+    driver.press(key!("Right"));
+    driver.press(key!("Down"));
+    let value = driver.get(h).unwrap().date_time_with_time_zone().unwrap();
+    assert_eq!(value.offset().local_minus_utc(), 105 * 60);
+    assert_eq!(value.naive_local(), date(2024, 6, 13).and_hms_opt(14, 30, 0).unwrap());
+    assert_eq!(row(&driver, 1, 1, 25), " 2024-06-13 14:30 +01:45");
+
+    let min = date(2024, 6, 10).and_hms_opt(8, 0, 0).unwrap();
+    let max = date(2024, 6, 12).and_hms_opt(17, 0, 0).unwrap();
+    driver.get_mut(h).unwrap().set_bounds(min, max);
+    assert_eq!(driver.get(h).unwrap().date_time(), max);
+    driver.press(key!("Home"));
+    assert_eq!(driver.get(h).unwrap().date_time(), min);
+    // the days outside the bounds can not be selected from the calendar
+    driver.press(key!("Enter"));
+    driver.press(key!("Left"));
+    driver.press(key!("Enter"));
+    assert!(driver.get(h).unwrap().is_expanded());
+    assert_eq!(driver.get(h).unwrap().date_time(), min);
+    driver.press(key!("Escape"));
+    assert!(!driver.get(h).unwrap().is_expanded());
+}
+
+#[test]
+fn check_date_range_picker() {
+    let (mut driver, h) = picker_window(|| DateRangePicker::new("2024-06-30", "2024-06-01", layout!("x:0,y:0,w:28")), 28);
+    assert_eq!(driver.get(h).unwrap().range(), (date(2024, 6, 1), date(2024, 6, 30)));
+    assert_eq!(row(&driver, 1, 1, 25), " 2024-06-01 - 2024-06-30");
+    // the first day is the start of the interval, the second one is its end
+    driver.press(key!("Enter"));
+    driver.press(key!("Enter"));
+    for _ in 0..3 {
+        driver.press(key!("Right"));
+    }
+    driver.press(key!("Enter"));
+    assert!(!driver.get(h).unwrap().is_expanded());
+    assert_eq!(driver.get(h).unwrap().range(), (date(2024, 6, 1), date(2024, 6, 4)));
+    // the days are swapped if the second one is before the first one
+    driver.press(key!("Enter"));
+    driver.press(key!("Enter"));
+    driver.press(key!("Left"));
+    driver.press(key!("Left"));
+    driver.press(key!("Enter"));
+    assert_eq!(driver.get(h).unwrap().range(), (date(2024, 5, 30), date(2024, 6, 1)));
+    // the first escape cancels the selection, the second one closes the calendar
+    driver.press(key!("Enter"));
+    driver.press(key!("Enter"));
+    driver.press(key!("Escape"));
+    assert!(driver.get(h).unwrap().is_expanded());
+    driver.press(key!("Escape"));
+    assert!(!driver.get(h).unwrap().is_expanded());
+    driver.get_mut(h).unwrap().set_bounds(date(2024, 6, 1), date(2024, 6, 30));
+    assert_eq!(driver.get(h).unwrap().range(), (date(2024, 6, 1), date(2024, 6, 1)));
+}
+
+#[test]
+fn check_date_range_picker_mouse() {
+    let (mut driver, h) = picker_window(|| DateRangePicker::new("2024-06-01", "2024-06-02", layout!("x:0,y:0,w:28")), 28);
+    driver.click_at(27, 1);
+    assert!(driver.get(h).unwrap().is_expanded());
+    // June 2024 starts on Saturday => the 3rd is the first day of the second row of days
+    driver.click_at(3, 7);
+    driver.click_at(11, 7);
+    assert!(!driver.get(h).unwrap().is_expanded());
+    assert_eq!(driver.get(h).unwrap().range(), (date(2024, 6, 3), date(2024, 6, 5)));
+}
+
+#[test]
+fn check_picker_events() {
+    #[Window(events: TimePickerEvents+DateTimePickerEvents+DateRangePickerEvents, internal: true)]
+    struct MyWin {
+        log: Vec<String>,
+    }
+    impl MyWin {
+        fn new() -> Self {
+            let mut w = Self {
+                base: window!("Events,x:0,y:0,w:34,h:7"),
+                log: Vec::new(),
+            };
+            w.add(TimePicker::new("10:00:00", layout!("x:0,y:0,w:12"), datepicker::Flags::None));
+            w.add(DateTimePicker::new("2024-06-13 10:00:00", layout!("x:0,y:1,w:26"), datepicker::Flags::None));
+            w.add(DateRangePicker::new("2024-06-01", "2024-06-30", layout!("x:0,y:2,w:28")));
+            w
+        }
+    }
+    impl TimePickerEvents for MyWin {
+        fn on_time_changed(&mut self, _handle: Handle<TimePicker>, time: chrono::NaiveTime) -> EventProcessStatus {
+            self.log.push(format!("time:{time}"));
+            EventProcessStatus::Processed
+        }
+    }
+    impl DateTimePickerEvents for MyWin {
+        fn on_date_time_changed(&mut self, _handle: Handle<DateTimePicker>, date_time: chrono::NaiveDateTime) -> EventProcessStatus {
+            self.log.push(format!("date_time:{date_time}"));
+            EventProcessStatus::Processed
+        }
+    }
+    impl DateRangePickerEvents for MyWin {
+        fn on_range_changed(&mut self, _handle: Handle<DateRangePicker>, start: chrono::NaiveDate, end: chrono::NaiveDate) -> EventProcessStatus {
+            self.log.push(format!("range:{start}/{end}"));
+            EventProcessStatus::Processed
+        }
+    }
+    let mut driver = crate::testing::TestDriver::new(App::debug(40, 20, "")).unwrap();
+    let w = driver.add_window(MyWin::new());
+    let tp = driver.find::<TimePicker>().single();
+    let dtp = driver.find::<DateTimePicker>().single();
+    let drp = driver.find::<DateRangePicker>().single();
+    driver.press_on(tp, key!("Up"));
+    // no event if the value does not change
+    driver.press_on(tp, key!("A"));
+    driver.press_on(dtp, key!("Down"));
+    driver.focus(drp);
+    driver.press(key!("Enter"));
+    driver.press(key!("Enter"));
+    driver.press(key!("Right"));
+    driver.press(key!("Enter"));
+    assert_eq!(
+        driver.get(w).unwrap().log,
+        vec!["time:11:00:00", "date_time:2024-06-13 09:00:00", "range:2024-06-01/2024-06-02"]
+    );
+}
@@ -0,0 +1,190 @@
+use super::events::TimeEventData;
+use super::segments::Segments;
+use super::Flags;
+use crate::prelude::*;
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+
+#[CustomControl(overwrite=OnPaint+OnKeyPressed+OnMouseEvent, internal=true)]
+pub struct TimePicker {
+    time: NaiveTime,
+    min: NaiveTime,
+    max: NaiveTime,
+    segments: Segments,
+}
+
+impl TimePicker {
+    /// Creates a new time picker with a time string, a layout and flags.
+    /// The time string must be in the format "HH:MM:SS" or "HH:MM" (24 hour format).
+    /// The flags can be a combination of the following values:
+    /// * `Flags::Hour12` - the time is displayed in a 12 hour format (with an AM/PM indicator)
+    /// * `Flags::HideSeconds` - the seconds are not displayed (and can not be changed)
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use appcui::prelude::*;
+    ///
+    /// let timepicker = TimePicker::new("14:30:00", layout!("x:1,y:1,w:12"), datepicker::Flags::None);
+    /// ```
+    pub fn new(time_str: &str, layout: Layout, flags: Flags) -> Self {
+        let time = time_str.parse::<NaiveTime>().unwrap();
+        Self::with_time(time, layout, flags)
+    }
+
+    /// Creates a new time picker with a NaiveTime, a layout and flags.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use appcui::prelude::*;
+    /// use chrono::NaiveTime;
+    ///
+    /// let timepicker = TimePicker::with_time(NaiveTime::from_hms_opt(9, 15, 0).unwrap(),
+    ///                                        layout!("x:1,y:1,w:12"),
+    ///                                        datepicker::Flags::Hour12 | datepicker::Flags::HideSeconds);
+    /// ```
+    pub fn with_time(time: NaiveTime, layout: Layout, flags: Flags) -> Self {
+        let mut tp = TimePicker {
+            base: ControlBase::with_status_flags(layout, StatusFlags::Enabled | StatusFlags::Visible | StatusFlags::AcceptInput),
+            time,
+            min: NaiveTime::MIN,
+            max: NaiveTime::from_hms_opt(23, 59, 59).unwrap(),
+            segments: Segments::new(false, false, flags),
+        };
+        let min_width = (tp.segments.width() + 2) as u16;
+        tp.set_size_bounds(min_width, 1, u16::MAX, 1);
+        tp.set_time(time);
+        tp
+    }
+
+    /// Returns the time of the time picker
+    #[inline(always)]
+    pub fn time(&self) -> NaiveTime {
+        self.time
+    }
+
+    /// Sets the time of the time picker. If the time is outside the interval [min, max], it will be set to the closest limit.
+    pub fn set_time(&mut self, time: NaiveTime) {
+        self.time = time.clamp(self.min, self.max);
+        self.segments.update_text(Self::as_date_time(self.time), None);
+    }
+
+    /// Sets the interval of the times that can be selected (the limits are swapped if `min` is bigger than `max`).
+    /// The current time is clamped to the new interval.
+    pub fn set_bounds(&mut self, min: NaiveTime, max: NaiveTime) {
+        (self.min, self.max) = if min <= max { (min, max) } else { (max, min) };
+        self.set_time(self.time);
+    }
+
+    /// Returns the minimum time that can be selected
+    #[inline(always)]
+    pub fn min(&self) -> NaiveTime {
+        self.min
+    }
+
+    /// Returns the maximum time that can be selected
+    #[inline(always)]
+    pub fn max(&self) -> NaiveTime {
+        self.max
+    }
+
+    #[inline(always)]
+    fn as_date_time(time: NaiveTime) -> NaiveDateTime {
+        NaiveDate::MIN.and_time(time)
+    }
+
+    fn update_time(&mut self, time: NaiveTime) {
+        let time = time.clamp(self.min, self.max);
+        if time != self.time {
+            self.set_time(time);
+            self.raise_event(ControlEvent {
+                emitter: self.handle,
+                receiver: self.event_processor,
+                data: ControlEventData::TimePicker(TimeEventData { time: self.time }),
+            });
+        }
+    }
+
+    fn step(&mut self, delta: i32) {
+        let value = self.segments.step(Self::as_date_time(self.time), &mut None, delta);
+        self.update_time(value.time());
+    }
+}
+
+impl OnPaint for TimePicker {
+    fn on_paint(&self, surface: &mut Surface, theme: &Theme) {
+        let attr = match () {
+            _ if !self.is_enabled() => theme.editor.inactive,
+            _ if self.has_focus() => theme.editor.focused,
+            _ if self.is_mouse_over() => theme.editor.hovered,
+            _ => theme.editor.normal,
+        };
+        surface.clear(Character::with_attributes(' ', attr));
+        let selected = if self.has_focus() { Some(theme.editor.pressed_or_selectd) } else { None };
+        self.segments.paint(surface, 1, 0, attr, selected);
+    }
+}
+
+impl OnKeyPressed for TimePicker {
+    fn on_key_pressed(&mut self, key: Key, character: char) -> EventProcessStatus {
+        match key.value() {
+            key!("Left") => {
+                self.segments.select_previous();
+                EventProcessStatus::Processed
+            }
+            key!("Right") => {
+                self.segments.select_next();
+                EventProcessStatus::Processed
+            }
+            key!("Up") => {
+                self.step(1);
+                EventProcessStatus::Processed
+            }
+            key!("Down") => {
+                self.step(-1);
+                EventProcessStatus::Processed
+            }
+            key!("Home") => {
+                self.update_time(self.min);
+                EventProcessStatus::Processed
+            }
+            key!("End") => {
+                self.update_time(self.max);
+                EventProcessStatus::Processed
+            }
+            key!("A") | key!("P") => {
+                let value = self.segments.set_pm(Self::as_date_time(self.time), key.value() == key!("P"));
+                self.update_time(value.time());
+                EventProcessStatus::Processed
+            }
+            _ => {
+                if let Some(digit) = character.to_digit(10) {
+                    if let Some(value) = self.segments.type_digit(Self::as_date_time(self.time), digit) {
+                        self.update_time(value.time());
+                    }
+                    return EventProcessStatus::Processed;
+                }
+                EventProcessStatus::Ignored
+            }
+        }
+    }
+}
+
+impl OnMouseEvent for TimePicker {
+    fn on_mouse_event(&mut self, event: &MouseEvent) -> EventProcessStatus {
+        match event {
+            MouseEvent::Enter | MouseEvent::Leave => EventProcessStatus::Processed,
+            MouseEvent::Pressed(data) => {
+                self.segments.select_at(data.x - 1);
+                EventProcessStatus::Processed
+            }
+            MouseEvent::Wheel(direction) => {
+                match direction {
+                    MouseWheelDirection::Up => self.step(1),
+                    MouseWheelDirection::Down => self.step(-1),
+                    _ => return EventProcessStatus::Ignored,
+                }
+                EventProcessStatus::Processed
+            }
+            _ => EventProcessStatus::Ignored,
+        }
+    }
+}
@@ -4,6 +4,9 @@
 //! It manages windows, dialogs, and global key bindings for the entire application.
 
 mod desktop;
+mod dock_manager;
+mod dock_node;
+mod dock_position;
 mod empty_desktop;
 pub mod events;
 #[cfg(test)]
@@ -11,4 +14,6 @@ mod tests;
 
 pub use self::desktop::Desktop;
 pub(crate) use self::empty_desktop::EmptyDesktop;
-pub use self::desktop::ArrangeWindowsMethod;
\ No newline at end of file
+pub use self::desktop::ArrangeWindowsMethod;
+pub(crate) use self::dock_manager::DockManager;
+pub use self::dock_position::DockPosition;
\ No newline at end of file
@@ -1,3 +1,4 @@
+use super::DockPosition;
 use crate::prelude::*;
 
 #[derive(Copy, Clone, Eq, PartialEq)]
@@ -85,6 +86,8 @@ impl Desktop {
         if (idx.in_range(len)) && (idx.index() != self.base.focused_child_index.index()) {
             let handle = self.base.children[idx.index()];
             if !handle.is_none() {
+                // a docked window that is not the visible tab of its pane is activated first
+                RuntimeManager::get().get_dock_manager_mut().activate(handle);
                 RuntimeManager::get().request_focus_for_control(handle);
             }
         }
@@ -202,6 +205,92 @@ impl Desktop {
     {
         RuntimeManager::get().add_window(window)
     }
+
+    /// Docks a window on one side of the desktop (or, if `position` is `DockPosition::Center`, as a new tab in the
+    /// first pane of the dock arrangement). A window docked on a side of the desktop uses 30% of its width (or height)
+    /// and the rest of the docked windows are resized to fill the remaining space.
+    ///
+    /// Returns `false` if the handle is not a window that was added to the desktop.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use appcui::prelude::*;
+    ///
+    /// #[Desktop(events = DesktopEvents)]
+    /// struct MyDesktop {}
+    /// impl DesktopEvents for MyDesktop {
+    ///     fn on_start(&mut self) {
+    ///         let explorer = self.add_window(window!("Explorer,a:c,w:30,h:10,flags:Dockable"));
+    ///         let editor = self.add_window(window!("Editor,a:c,w:30,h:10,flags:Dockable"));
+    ///         self.dock_window(explorer, desktop::DockPosition::Left);
+    ///         self.dock_window_to(editor, explorer, desktop::DockPosition::Center);
+    ///     }
+    /// }
+    /// ```
+    pub fn dock_window<T>(&mut self, window: Handle<T>, position: DockPosition) -> bool
+    where
+        T: Control + WindowControl + 'static,
+    {
+        RuntimeManager::get().get_dock_manager_mut().dock(window.cast(), Handle::None, position)
+    }
+
+    /// Docks a window relative to another docked window (`target`). If `position` is `DockPosition::Center` the window
+    /// is added as a new tab in the pane of the target window, otherwise the pane of the target window is split in two
+    /// and the window is placed on the specified side.
+    ///
+    /// Returns `false` if any of the handles is not a window that was added to the desktop or if `target` is not docked.
+    pub fn dock_window_to<T, U>(&mut self, window: Handle<T>, target: Handle<U>, position: DockPosition) -> bool
+    where
+        T: Control + WindowControl + 'static,
+        U: Control + WindowControl + 'static,
+    {
+        RuntimeManager::get()
+            .get_dock_manager_mut()
+            .dock(window.cast(), target.cast(), position)
+    }
+
+    /// Removes a window from the dock arrangement (the window floats again and gets back the position and the size it had
+    /// before it was docked). Returns `false` if the window is not docked.
+    ///
+    /// A docked window can also be made to float by dragging it with the mouse.
+    pub fn float_window<T>(&mut self, window: Handle<T>) -> bool
+    where
+        T: Control + WindowControl + 'static,
+    {
+        RuntimeManager::get().get_dock_manager_mut().float(window.cast()).is_some()
+    }
+
+    /// Returns `true` if the window is docked (it is part of a split pane or a tab group).
+    pub fn is_docked<T>(&self, window: Handle<T>) -> bool
+    where
+        T: Control + WindowControl + 'static,
+    {
+        RuntimeManager::get().get_dock_manager().is_docked(window.cast())
+    }
+
+    /// Returns the current dock arrangement as a string that can be stored and later restored via
+    /// `restore_dock_layout` (for example, when the application starts). Windows are identified by their tag or,
+    /// if they don't have one, by their title.
+    ///
+    /// The format of the string is a tree of nodes:
+    /// * `v(<percentage>%,<left>,<right>)` - a pane split in a left and a right part
+    /// * `h(<percentage>%,<top>,<bottom>)` - a pane split in a top and a bottom part
+    /// * `tabs(<active>,"<window>",...)` - a group of windows displayed as tabs
+    ///
+    /// For example: `v(30%,tabs(0,"Explorer"),h(70%,tabs(1,"Editor","Notes"),tabs(0,"Output")))`
+    pub fn dock_layout(&self) -> String {
+        RuntimeManager::get().get_dock_manager().arrangement()
+    }
+
+    /// Restores a dock arrangement (created via `dock_layout`). Windows from the arrangement that can not be found
+    /// are skipped and the windows that are currently docked but are not part of the arrangement will float again.
+    /// An empty string makes all docked windows float.
+    ///
+    /// Returns an error (`ErrorKind::InvalidParameter`) if the arrangement is not valid.
+    pub fn restore_dock_layout(&mut self, layout: &str) -> Result<(), Error> {
+        let windows = self.base.children.clone();
+        RuntimeManager::get().get_dock_manager_mut().restore_arrangement(layout, &windows)
+    }
 }
 impl OnPaint for Desktop {
     fn on_paint(&self, surface: &mut Surface, theme: &Theme) {
@@ -0,0 +1,337 @@
+use super::dock_node::{DockLayoutParser, DockNode, DockPane};
+use super::DockPosition;
+use crate::prelude::*;
+
+// the percentage of the desktop used by a window docked on one of the sides of the desktop
+const DESKTOP_PANE_PERCENTAGE: u8 = 30;
+
+#[derive(Copy, Clone)]
+struct DropTarget {
+    // the window relative to which the dragged window is docked (Handle::None for the desktop)
+    window: Handle<()>,
+    position: DockPosition,
+    preview: Rect,
+}
+
+// keeps the arrangement of the windows docked on the desktop (a tree of split panes and tab groups)
+pub(crate) struct DockManager {
+    root: Option<DockNode>,
+    panes: Vec<DockPane>,
+    // the position and size a window had before it was docked (used when the window floats again)
+    floating: Vec<(Handle<()>, Rect)>,
+    drop_target: Option<DropTarget>,
+}
+
+impl DockManager {
+    pub(crate) fn new() -> Self {
+        Self {
+            root: None,
+            panes: Vec::new(),
+            floating: Vec::new(),
+            drop_target: None,
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn is_empty(&self) -> bool {
+        self.root.is_none() && self.panes.is_empty()
+    }
+
+    pub(crate) fn is_docked(&self, window: Handle<()>) -> bool {
+        self.root.as_ref().map(|r| r.contains(window)).unwrap_or(false)
+    }
+
+    // docks a window relative to another docked window or (if `target` is Handle::None) relative to the desktop
+    pub(crate) fn dock(&mut self, window: Handle<()>, target: Handle<()>, position: DockPosition) -> bool {
+        if window == target || !Self::is_desktop_window(window) {
+            return false;
+        }
+        if !target.is_none() && (!self.is_docked(target) || !Self::is_desktop_window(target)) {
+            return false;
+        }
+        if self.is_docked(window) {
+            self.root = self.root.take().and_then(|r| r.retain(&|w| w != window));
+        } else if let Some(control) = RuntimeManager::get().get_controls().get(window) {
+            let base = control.base();
+            let rect = Rect::with_point_and_size(base.position(), base.size());
+            self.floating.retain(|(h, _)| *h != window);
+            self.floating.push((window, rect));
+        }
+        self.root = Some(match self.root.take() {
+            None => DockNode::with_window(window),
+            Some(root) if target.is_none() => root.wrap(window, position, DESKTOP_PANE_PERCENTAGE),
+            Some(root) => root.dock_at(target, window, position),
+        });
+        self.relayout();
+        RuntimeManager::get().request_focus_for_control(window);
+        true
+    }
+
+    // removes a window from the dock arrangement and restores its previous position and size
+    pub(crate) fn float(&mut self, window: Handle<()>) -> Option<Rect> {
+        if !self.is_docked(window) {
+            return None;
+        }
+        self.root = self.root.take().and_then(|r| r.retain(&|w| w != window));
+        let rect = self.restore(window);
+        self.relayout();
+        rect
+    }
+
+    // makes a docked window the visible tab of its pane
+    pub(crate) fn activate(&mut self, window: Handle<()>) -> bool {
+        let activated = self.root.as_mut().map(|r| r.activate(window)).unwrap_or(false);
+        if activated {
+            self.relayout();
+        }
+        activated
+    }
+
+    // the window whose tab (from a tab strip) is at the specified position
+    pub(crate) fn tab_at(&self, x: i32, y: i32) -> Option<Handle<()>> {
+        for pane in self.panes.iter().filter(|p| p.has_tab_strip() && p.rect.top() == y) {
+            let mut left = pane.rect.left();
+            for window in pane.windows.iter() {
+                let width = Self::tab_width(*window);
+                if (x >= left) && (x < left + width) && (x <= pane.rect.right()) {
+                    return Some(*window);
+                }
+                left += width;
+            }
+        }
+        None
+    }
+
+    // the position and size of a docked window before it was docked
+    pub(crate) fn floating_rect(&self, window: Handle<()>) -> Option<Rect> {
+        if !self.is_docked(window) {
+            return None;
+        }
+        self.floating.iter().find(|(h, _)| *h == window).map(|(_, r)| *r)
+    }
+
+    pub(crate) fn arrangement(&self) -> String {
+        let mut result = String::new();
+        if let Some(root) = self.root.as_ref() {
+            root.write(&mut result, &Self::window_name);
+        }
+        result
+    }
+
+    pub(crate) fn restore_arrangement(&mut self, text: &str, windows: &[Handle<()>]) -> Result<(), Error> {
+        let mut used: Vec<Handle<()>> = Vec::new();
+        let root = DockLayoutParser::new(text).parse(&mut |name| {
+            let handle = windows
+                .iter()
+                .copied()
+                .find(|w| !used.contains(w) && Self::is_desktop_window(*w) && Self::window_name(*w) == name)?;
+            used.push(handle);
+            Some(handle)
+        })?;
+        // windows that are no longer docked will float again
+        let mut docked = Vec::new();
+        if let Some(old) = self.root.as_ref() {
+            old.windows(&mut docked);
+        }
+        for window in docked.iter().filter(|w| !used.contains(w)) {
+            self.restore(*window);
+        }
+        // windows that were floating remember their current position and size
+        let controls = RuntimeManager::get().get_controls();
+        for window in used.iter().filter(|w| !docked.contains(w)) {
+            if let Some(control) = controls.get(*window) {
+                let base = control.base();
+                self.floating.retain(|(h, _)| h != window);
+                self.floating.push((*window, Rect::with_point_and_size(base.position(), base.size())));
+            }
+        }
+        self.root = root;
+        self.relayout();
+        Ok(())
+    }
+
+    // updates the position, size and visibility of all docked windows
+    pub(crate) fn relayout(&mut self) {
+        let controls = RuntimeManager::get().get_controls_mut();
+        // windows that were closed are removed from the arrangement
+        self.root = self.root.take().and_then(|r| r.retain(&|w| controls.get(w).is_some()));
+        self.floating.retain(|(h, _)| controls.get(*h).is_some());
+        self.panes.clear();
+        if let Some(root) = self.root.as_ref() {
+            root.layout(RuntimeManager::get().get_desktop_rect(), &mut self.panes);
+        }
+        for pane in self.panes.iter() {
+            let r = pane.client_rect();
+            for (index, window) in pane.windows.iter().enumerate() {
+                if let Some(control) = controls.get_mut(*window) {
+                    let base = control.base_mut();
+                    base.set_visible(index == pane.active);
+                    if index == pane.active {
+                        base.set_position(r.left(), r.top());
+                        base.set_size(r.width() as u16, r.height() as u16);
+                    }
+                }
+            }
+        }
+    }
+
+    // computes the zone where a window that is dragged to the point (x,y) (screen coordinates) will be docked
+    pub(crate) fn update_drop_target(&mut self, window: Handle<()>, point: Point) {
+        self.drop_target = self.compute_drop_target(window, point);
+    }
+
+    // docks a dragged window in the last computed zone (if any)
+    pub(crate) fn drop_window(&mut self, window: Handle<()>) -> bool {
+        if let Some(target) = self.drop_target.take() {
+            self.dock(window, target.window, target.position)
+        } else {
+            false
+        }
+    }
+
+    fn compute_drop_target(&self, window: Handle<()>, point: Point) -> Option<DropTarget> {
+        let desktop = RuntimeManager::get().get_desktop_rect();
+        if !desktop.contains(point) {
+            return None;
+        }
+        // the margins of the desktop
+        let position = match () {
+            _ if point.x <= desktop.left() + 1 => Some(DockPosition::Left),
+            _ if point.x >= desktop.right() - 1 => Some(DockPosition::Right),
+            _ if point.y <= desktop.top() => Some(DockPosition::Top),
+            _ if point.y >= desktop.bottom() => Some(DockPosition::Bottom),
+            _ => None,
+        };
+        if let Some(position) = position {
+            let preview = if self.root.is_none() {
+                desktop
+            } else {
+                Self::preview_rect(desktop, position, DESKTOP_PANE_PERCENTAGE as i32)
+            };
+            return Some(DropTarget {
+                window: Handle::None,
+                position,
+                preview,
+            });
+        }
+        // the panes (except for the one that only contains the dragged window)
+        let pane = self
+            .panes
+            .iter()
+            .find(|p| p.rect.contains(point) && !(p.windows.len() == 1 && p.windows[0] == window))?;
+        // the edges of a pane split it, its center adds a new tab (anywhere else the window floats)
+        let r = pane.rect;
+        let position = match () {
+            _ if point.x <= r.left() + 1 => DockPosition::Left,
+            _ if point.x >= r.right() - 1 => DockPosition::Right,
+            _ if point.y == r.top() => DockPosition::Top,
+            _ if point.y == r.bottom() => DockPosition::Bottom,
+            _ if (point.x - r.center_x()).abs() <= 3 && (point.y - r.center_y()).abs() <= 1 => DockPosition::Center,
+            _ => return None,
+        };
+        let target = pane.windows.iter().copied().find(|w| *w != window)?;
+        Some(DropTarget {
+            window: target,
+            position,
+            preview: Self::preview_rect(r, position, 50),
+        })
+    }
+
+    fn preview_rect(r: Rect, position: DockPosition, percentage: i32) -> Rect {
+        let w = ((r.width() as i32) * percentage / 100).max(1);
+        let h = ((r.height() as i32) * percentage / 100).max(1);
+        match position {
+            DockPosition::Left => Rect::new(r.left(), r.top(), r.left() + w - 1, r.bottom()),
+            DockPosition::Right => Rect::new(r.right() + 1 - w, r.top(), r.right(), r.bottom()),
+            DockPosition::Top => Rect::new(r.left(), r.top(), r.right(), r.top() + h - 1),
+            DockPosition::Bottom => Rect::new(r.left(), r.bottom() + 1 - h, r.right(), r.bottom()),
+            DockPosition::Center => r,
+        }
+    }
+
+    // paints the tab strips of the panes that contain more than one window
+    pub(crate) fn paint(&self, surface: &mut Surface, theme: &Theme) {
+        for pane in self.panes.iter().filter(|p| p.has_tab_strip()) {
+            let y = pane.rect.top();
+            surface.fill_horizontal_line(
+                pane.rect.left(),
+                y,
+                pane.rect.right(),
+                Character::with_attributes(' ', theme.tab.text.normal),
+            );
+            let mut x = pane.rect.left();
+            for (index, window) in pane.windows.iter().enumerate() {
+                let attr = if index == pane.active {
+                    theme.tab.text.pressed_or_selectd
+                } else {
+                    theme.tab.text.normal
+                };
+                let width = Self::tab_width(*window);
+                let right = (x + width - 1).min(pane.rect.right());
+                if right < x {
+                    break;
+                }
+                surface.fill_horizontal_line(x, y, right, Character::with_attributes(' ', attr));
+                let text: String = Self::window_title(*window).chars().take((right - x) as usize).collect();
+                surface.write_string(x + 1, y, &text, attr, false);
+                x += width;
+            }
+        }
+    }
+
+    // paints the zone where a window that is being dragged will be docked
+    pub(crate) fn paint_drop_preview(&self, surface: &mut Surface, theme: &Theme) {
+        if let Some(target) = self.drop_target.as_ref() {
+            surface.draw_rect(target.preview, LineType::Double, theme.border.pressed_or_selectd);
+        }
+    }
+
+    // restores the position and size a window had before it was docked
+    fn restore(&mut self, window: Handle<()>) -> Option<Rect> {
+        let index = self.floating.iter().position(|(h, _)| *h == window);
+        let rect = index.map(|i| self.floating.remove(i).1);
+        if let Some(control) = RuntimeManager::get().get_controls_mut().get_mut(window) {
+            let base = control.base_mut();
+            base.set_visible(true);
+            if let Some(r) = rect {
+                base.set_position(r.left(), r.top());
+                base.set_size(r.width() as u16, r.height() as u16);
+            }
+        }
+        rect
+    }
+
+    fn is_desktop_window(window: Handle<()>) -> bool {
+        let rm = RuntimeManager::get();
+        let desktop = rm.desktop_handle();
+        rm.get_controls()
+            .get(window)
+            .map(|c| c.base().is_window_control() && c.base().parent == desktop)
+            .unwrap_or(false)
+    }
+
+    fn window_title(window: Handle<()>) -> String {
+        RuntimeManager::get()
+            .get_controls()
+            .get(window)
+            .map(|c| c.get::<Window>().title().to_string())
+            .unwrap_or_default()
+    }
+
+    // the name used to identify a window in a serialized arrangement (its tag or its title)
+    pub(crate) fn window_name(window: Handle<()>) -> String {
+        if let Some(control) = RuntimeManager::get().get_controls().get(window) {
+            let w = control.get::<Window>();
+            match w.tag() {
+                Some(tag) if !tag.is_empty() => tag.to_string(),
+                _ => w.title().to_string(),
+            }
+        } else {
+            String::new()
+        }
+    }
+
+    fn tab_width(window: Handle<()>) -> i32 {
+        Self::window_title(window).chars().count() as i32 + 2
+    }
+}
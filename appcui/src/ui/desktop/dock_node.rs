@@ -0,0 +1,410 @@
+use super::DockPosition;
+use crate::prelude::*;
+
+// a pane of the dock arrangement (a group of tabs) and the area it occupies on the desktop
+pub(crate) struct DockPane {
+    pub(crate) rect: Rect,
+    pub(crate) windows: Vec<Handle<()>>,
+    pub(crate) active: usize,
+}
+
+impl DockPane {
+    // the area where the active window is displayed (a pane with more than one window
+    // reserves its first line for the tab strip)
+    pub(crate) fn client_rect(&self) -> Rect {
+        if self.has_tab_strip() && self.rect.height() > 1 {
+            Rect::new(self.rect.left(), self.rect.top() + 1, self.rect.right(), self.rect.bottom())
+        } else {
+            self.rect
+        }
+    }
+    #[inline(always)]
+    pub(crate) fn has_tab_strip(&self) -> bool {
+        self.windows.len() > 1
+    }
+}
+
+pub(crate) enum DockNode {
+    Tabs {
+        windows: Vec<Handle<()>>,
+        active: usize,
+    },
+    Split {
+        // vertical splits have their panes on the left and right side (similar to a VSplitter)
+        vertical: bool,
+        // the percentage of the space occupied by the first pane
+        percentage: u8,
+        first: Box<DockNode>,
+        second: Box<DockNode>,
+    },
+}
+
+impl DockNode {
+    pub(crate) fn with_window(window: Handle<()>) -> Self {
+        DockNode::Tabs {
+            windows: vec![window],
+            active: 0,
+        }
+    }
+
+    pub(crate) fn contains(&self, window: Handle<()>) -> bool {
+        match self {
+            DockNode::Tabs { windows, .. } => windows.contains(&window),
+            DockNode::Split { first, second, .. } => first.contains(window) || second.contains(window),
+        }
+    }
+
+    pub(crate) fn windows(&self, output: &mut Vec<Handle<()>>) {
+        match self {
+            DockNode::Tabs { windows, .. } => output.extend_from_slice(windows),
+            DockNode::Split { first, second, .. } => {
+                first.windows(output);
+                second.windows(output);
+            }
+        }
+    }
+
+    // keeps only the windows for which `keep` returns true (empty panes are removed and
+    // a split with only one pane left is replaced by that pane)
+    pub(crate) fn retain(self, keep: &dyn Fn(Handle<()>) -> bool) -> Option<DockNode> {
+        match self {
+            DockNode::Tabs { mut windows, active } => {
+                let active_window = windows.get(active).copied();
+                windows.retain(|w| keep(*w));
+                if windows.is_empty() {
+                    return None;
+                }
+                let active = match active_window.and_then(|w| windows.iter().position(|x| *x == w)) {
+                    Some(index) => index,
+                    None => active.min(windows.len() - 1),
+                };
+                Some(DockNode::Tabs { windows, active })
+            }
+            DockNode::Split {
+                vertical,
+                percentage,
+                first,
+                second,
+            } => match (first.retain(keep), second.retain(keep)) {
+                (Some(first), Some(second)) => Some(DockNode::Split {
+                    vertical,
+                    percentage,
+                    first: Box::new(first),
+                    second: Box::new(second),
+                }),
+                (Some(node), None) | (None, Some(node)) => Some(node),
+                (None, None) => None,
+            },
+        }
+    }
+
+    // places `window` next to the current node (or adds it as a new tab if the position is `Center`)
+    // `percentage` is the percentage of the space used by the new pane
+    pub(crate) fn wrap(self, window: Handle<()>, position: DockPosition, percentage: u8) -> DockNode {
+        let pane = Box::new(DockNode::with_window(window));
+        let me = Box::new(self);
+        match position {
+            DockPosition::Left => DockNode::Split {
+                vertical: true,
+                percentage,
+                first: pane,
+                second: me,
+            },
+            DockPosition::Right => DockNode::Split {
+                vertical: true,
+                percentage: 100 - percentage,
+                first: me,
+                second: pane,
+            },
+            DockPosition::Top => DockNode::Split {
+                vertical: false,
+                percentage,
+                first: pane,
+                second: me,
+            },
+            DockPosition::Bottom => DockNode::Split {
+                vertical: false,
+                percentage: 100 - percentage,
+                first: me,
+                second: pane,
+            },
+            DockPosition::Center => {
+                let mut me = *me;
+                me.add_tab(window);
+                me
+            }
+        }
+    }
+
+    // docks `window` relative to the pane that contains `target`
+    pub(crate) fn dock_at(self, target: Handle<()>, window: Handle<()>, position: DockPosition) -> DockNode {
+        match self {
+            DockNode::Tabs { ref windows, .. } if windows.contains(&target) => self.wrap(window, position, 50),
+            DockNode::Split {
+                vertical,
+                percentage,
+                first,
+                second,
+            } => DockNode::Split {
+                vertical,
+                percentage,
+                first: Box::new(first.dock_at(target, window, position)),
+                second: Box::new(second.dock_at(target, window, position)),
+            },
+            _ => self,
+        }
+    }
+
+    // adds a new tab to the first pane (the top-left one) of the node
+    fn add_tab(&mut self, window: Handle<()>) {
+        match self {
+            DockNode::Tabs { windows, active } => {
+                windows.push(window);
+                *active = windows.len() - 1;
+            }
+            DockNode::Split { first, .. } => first.add_tab(window),
+        }
+    }
+
+    // makes `window` the visible tab of its pane
+    pub(crate) fn activate(&mut self, window: Handle<()>) -> bool {
+        match self {
+            DockNode::Tabs { windows, active } => {
+                if let Some(index) = windows.iter().position(|w| *w == window) {
+                    *active = index;
+                    true
+                } else {
+                    false
+                }
+            }
+            DockNode::Split { first, second, .. } => first.activate(window) || second.activate(window),
+        }
+    }
+
+    pub(crate) fn layout(&self, rect: Rect, panes: &mut Vec<DockPane>) {
+        match self {
+            DockNode::Tabs { windows, active } => panes.push(DockPane {
+                rect,
+                windows: windows.clone(),
+                active: *active,
+            }),
+            DockNode::Split {
+                vertical,
+                percentage,
+                first,
+                second,
+            } => {
+                let total = if *vertical { rect.width() } else { rect.height() } as i32;
+                let size = if total > 1 {
+                    (total * (*percentage as i32) / 100).clamp(1, total - 1)
+                } else {
+                    total
+                };
+                if *vertical {
+                    first.layout(Rect::new(rect.left(), rect.top(), rect.left() + size - 1, rect.bottom()), panes);
+                    second.layout(
+                        Rect::new((rect.left() + size).min(rect.right()), rect.top(), rect.right(), rect.bottom()),
+                        panes,
+                    );
+                } else {
+                    first.layout(Rect::new(rect.left(), rect.top(), rect.right(), rect.top() + size - 1), panes);
+                    second.layout(
+                        Rect::new(rect.left(), (rect.top() + size).min(rect.bottom()), rect.right(), rect.bottom()),
+                        panes,
+                    );
+                }
+            }
+        }
+    }
+
+    // serializes the node using the following format:
+    //      v(<percentage>%,<first>,<second>)   - panes on the left and right side
+    //      h(<percentage>%,<first>,<second>)   - panes on the top and bottom side
+    //      tabs(<active>,"<name>",...)         - a group of tabs
+    pub(crate) fn write(&self, output: &mut String, name: &dyn Fn(Handle<()>) -> String) {
+        match self {
+            DockNode::Tabs { windows, active } => {
+                output.push_str("tabs(");
+                output.push_str(&active.to_string());
+                for window in windows {
+                    output.push_str(",\"");
+                    for ch in name(*window).chars() {
+                        if ch == '"' || ch == '\\' {
+                            output.push('\\');
+                        }
+                        output.push(ch);
+                    }
+                    output.push('"');
+                }
+                output.push(')');
+            }
+            DockNode::Split {
+                vertical,
+                percentage,
+                first,
+                second,
+            } => {
+                output.push_str(if *vertical { "v(" } else { "h(" });
+                output.push_str(&percentage.to_string());
+                output.push_str("%,");
+                first.write(output, name);
+                output.push(',');
+                second.write(output, name);
+                output.push(')');
+            }
+        }
+    }
+}
+
+// parses a dock arrangement (created via `DockNode::write`). The names of the windows are converted
+// into handles via `resolve` (names that can not be resolved are skipped).
+pub(crate) struct DockLayoutParser<'a> {
+    text: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> DockLayoutParser<'a> {
+    pub(crate) fn new(text: &'a str) -> Self {
+        Self {
+            text: text.as_bytes(),
+            pos: 0,
+        }
+    }
+
+    pub(crate) fn parse(&mut self, resolve: &mut dyn FnMut(&str) -> Option<Handle<()>>) -> Result<Option<DockNode>, Error> {
+        self.skip_spaces();
+        if self.pos >= self.text.len() {
+            return Ok(None);
+        }
+        let node = self.parse_node(resolve)?;
+        self.skip_spaces();
+        if self.pos < self.text.len() {
+            return Err(self.error("unexpected characters after the end of the arrangement"));
+        }
+        Ok(node)
+    }
+
+    fn parse_node(&mut self, resolve: &mut dyn FnMut(&str) -> Option<Handle<()>>) -> Result<Option<DockNode>, Error> {
+        self.skip_spaces();
+        let start = self.pos;
+        while self.pos < self.text.len() && self.text[self.pos].is_ascii_alphabetic() {
+            self.pos += 1;
+        }
+        let keyword = &self.text[start..self.pos];
+        self.expect(b'(')?;
+        let node = match keyword {
+            b"v" | b"h" => {
+                let percentage = self.parse_number()?;
+                self.expect(b'%')?;
+                if !(1..=99).contains(&percentage) {
+                    return Err(self.error("the percentage of a split must be between 1% and 99%"));
+                }
+                self.expect(b',')?;
+                let first = self.parse_node(resolve)?;
+                self.expect(b',')?;
+                let second = self.parse_node(resolve)?;
+                match (first, second) {
+                    (Some(first), Some(second)) => Some(DockNode::Split {
+                        vertical: keyword == b"v",
+                        percentage: percentage as u8,
+                        first: Box::new(first),
+                        second: Box::new(second),
+                    }),
+                    (Some(node), None) | (None, Some(node)) => Some(node),
+                    (None, None) => None,
+                }
+            }
+            b"tabs" => {
+                let active = self.parse_number()?;
+                let mut windows = Vec::new();
+                let mut active_window = None;
+                let mut index = 0;
+                loop {
+                    self.skip_spaces();
+                    if self.pos < self.text.len() && self.text[self.pos] == b')' {
+                        break;
+                    }
+                    self.expect(b',')?;
+                    let name = self.parse_string()?;
+                    if let Some(handle) = resolve(&name) {
+                        if index == active {
+                            active_window = Some(windows.len());
+                        }
+                        windows.push(handle);
+                    }
+                    index += 1;
+                }
+                if windows.is_empty() {
+                    None
+                } else {
+                    Some(DockNode::Tabs {
+                        active: active_window.unwrap_or(0),
+                        windows,
+                    })
+                }
+            }
+            _ => return Err(self.error("expecting 'v', 'h' or 'tabs'")),
+        };
+        self.expect(b')')?;
+        Ok(node)
+    }
+
+    fn parse_number(&mut self) -> Result<usize, Error> {
+        self.skip_spaces();
+        let start = self.pos;
+        while self.pos < self.text.len() && self.text[self.pos].is_ascii_digit() {
+            self.pos += 1;
+        }
+        if start == self.pos {
+            return Err(self.error("expecting a number"));
+        }
+        // the slice contains only ascii digits
+        let value = std::str::from_utf8(&self.text[start..self.pos]).unwrap_or_default();
+        value.parse::<usize>().map_err(|_| self.error("invalid number"))
+    }
+
+    fn parse_string(&mut self) -> Result<String, Error> {
+        self.expect(b'"')?;
+        let mut result = Vec::new();
+        while self.pos < self.text.len() {
+            match self.text[self.pos] {
+                b'"' => {
+                    self.pos += 1;
+                    return Ok(String::from_utf8_lossy(&result).to_string());
+                }
+                b'\\' if self.pos + 1 < self.text.len() => {
+                    result.push(self.text[self.pos + 1]);
+                    self.pos += 2;
+                }
+                ch => {
+                    result.push(ch);
+                    self.pos += 1;
+                }
+            }
+        }
+        Err(self.error("unterminated window name"))
+    }
+
+    fn expect(&mut self, ch: u8) -> Result<(), Error> {
+        self.skip_spaces();
+        if self.pos < self.text.len() && self.text[self.pos] == ch {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.error(&format!("expecting '{}'", ch as char)))
+        }
+    }
+
+    fn skip_spaces(&mut self) {
+        while self.pos < self.text.len() && self.text[self.pos].is_ascii_whitespace() {
+            self.pos += 1;
+        }
+    }
+
+    fn error(&self, message: &str) -> Error {
+        Error::new(
+            ErrorKind::InvalidParameter,
+            format!("Invalid dock arrangement: {} (at position {})", message, self.pos),
+        )
+    }
+}
@@ -0,0 +1,14 @@
+/// The zone (relative to the desktop or to a docked window) where a window is docked.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum DockPosition {
+    /// The window is docked in a new pane on the left side of the target
+    Left,
+    /// The window is docked in a new pane on the right side of the target
+    Right,
+    /// The window is docked in a new pane above the target
+    Top,
+    /// The window is docked in a new pane below the target
+    Bottom,
+    /// The window is added as a new tab in the same pane as the target
+    Center,
+}
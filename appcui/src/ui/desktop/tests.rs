@@ -1,6 +1,6 @@
-use crate::{prelude::*, ui};
+use crate::{prelude::*, testing::TestDriver, ui};
 
-use super::EmptyDesktop;
+use super::{DockPosition, EmptyDesktop};
 
 #[test]
 fn check_custom_paint_for_desktop() {
@@ -736,3 +736,131 @@ fn check_commands_ignored() {
     let a = App::debug(40, 6, script).desktop(MyDesktop::new()).build().unwrap();
     a.run();
 }
+
+#[test]
+fn check_dock_windows() {
+    let mut driver = TestDriver::new(App::debug(60, 20, "")).unwrap();
+    let a = driver.add_window(Window::new("Alpha", layout!("x:2,y:2,w:20,h:8"), window::Flags::None));
+    let b = driver.add_window(Window::new("Beta", layout!("x:10,y:4,w:20,h:8"), window::Flags::None));
+    let c = driver.add_window(Window::new("Gamma", layout!("x:20,y:6,w:20,h:8"), window::Flags::None));
+    let d = driver.desktop();
+    driver.settle();
+
+    // the first docked window fills the desktop
+    assert!(driver.get_mut(d).unwrap().dock_window(a, DockPosition::Left));
+    driver.settle();
+    assert_eq!(driver.bounds(a), Some(Rect::new(0, 0, 59, 19)));
+
+    // a window docked on the left side of the desktop uses 30% of its width
+    assert!(driver.get_mut(d).unwrap().dock_window(b, DockPosition::Left));
+    driver.settle();
+    assert_eq!(driver.bounds(b), Some(Rect::new(0, 0, 17, 19)));
+    assert_eq!(driver.bounds(a), Some(Rect::new(18, 0, 59, 19)));
+
+    // a tab group (the first line is used by the tab strip)
+    assert!(driver.get_mut(d).unwrap().dock_window_to(c, a, DockPosition::Center));
+    driver.settle();
+    assert_eq!(driver.bounds(c), Some(Rect::new(18, 1, 59, 19)));
+    assert_eq!(driver.bounds(a), None);
+    assert!(driver.screen_text().lines().next().unwrap().contains(" Alpha  Gamma "));
+
+    // clicking on a tab activates its window
+    driver.click_at(20, 0);
+    assert_eq!(driver.bounds(a), Some(Rect::new(18, 1, 59, 19)));
+    assert_eq!(driver.bounds(c), None);
+    assert!(driver.has_focus(a));
+
+    let arrangement = driver.get(d).unwrap().dock_layout();
+    assert_eq!(arrangement, "v(30%,tabs(0,\"Beta\"),tabs(0,\"Alpha\",\"Gamma\"))");
+
+    // a floating window gets back its position and size
+    assert!(driver.get_mut(d).unwrap().float_window(b));
+    driver.settle();
+    assert!(!driver.get(d).unwrap().is_docked(b));
+    assert_eq!(driver.bounds(b), Some(Rect::new(10, 4, 29, 11)));
+    assert_eq!(driver.bounds(a), Some(Rect::new(0, 1, 59, 19)));
+    assert!(!driver.get_mut(d).unwrap().float_window(b));
+
+    // restore the saved arrangement
+    driver.get_mut(d).unwrap().restore_dock_layout(&arrangement).unwrap();
+    driver.settle();
+    assert!(driver.get(d).unwrap().is_docked(b));
+    assert_eq!(driver.bounds(b), Some(Rect::new(0, 0, 17, 19)));
+    assert_eq!(driver.bounds(a), Some(Rect::new(18, 1, 59, 19)));
+
+    // an empty arrangement makes all windows float
+    driver.get_mut(d).unwrap().restore_dock_layout("").unwrap();
+    driver.settle();
+    assert_eq!(driver.bounds(a), Some(Rect::new(2, 2, 21, 9)));
+    assert_eq!(driver.bounds(c), Some(Rect::new(20, 6, 39, 13)));
+}
+
+#[test]
+fn check_restore_dock_layout() {
+    let mut driver = TestDriver::new(App::debug(60, 20, "")).unwrap();
+    let a = driver.add_window(Window::new("Alpha", layout!("x:2,y:2,w:20,h:8"), window::Flags::None));
+    let mut w = Window::new("Beta", layout!("x:10,y:4,w:20,h:8"), window::Flags::None);
+    w.set_tag("out \"1\"");
+    let b = driver.add_window(w);
+    let d = driver.desktop();
+    driver.settle();
+
+    // unknown windows are skipped (and so are the nodes that become empty)
+    let layout = "h( 60% , tabs(0,\"Alpha\",\"Missing\"), v(50%, tabs(0,\"Unknown\"), tabs(0,\"out \\\"1\\\"\")))";
+    driver.get_mut(d).unwrap().restore_dock_layout(layout).unwrap();
+    driver.settle();
+    assert_eq!(driver.bounds(a), Some(Rect::new(0, 0, 59, 11)));
+    assert_eq!(driver.bounds(b), Some(Rect::new(0, 12, 59, 19)));
+    assert_eq!(
+        driver.get(d).unwrap().dock_layout(),
+        "h(60%,tabs(0,\"Alpha\"),tabs(0,\"out \\\"1\\\"\"))"
+    );
+
+    // invalid arrangements
+    for text in ["x(50%,tabs(0,\"Alpha\"))", "v(50%,tabs(0,\"Alpha\")", "v(0%,tabs(0,\"Alpha\"),tabs(0,\"Beta\"))", "tabs(0,\"Alpha)", "tabs(0) abc"] {
+        let err = driver.get_mut(d).unwrap().restore_dock_layout(text).unwrap_err();
+        assert_eq!(err.kind, ErrorKind::InvalidParameter);
+    }
+    // the arrangement is not changed by an invalid string
+    assert!(driver.get(d).unwrap().is_docked(a));
+
+    // a closed window is removed from the arrangement
+    driver.get_mut(a).unwrap().close();
+    driver.settle();
+    assert_eq!(driver.bounds(b), Some(Rect::new(0, 0, 59, 19)));
+    assert_eq!(driver.get(d).unwrap().dock_layout(), "tabs(0,\"out \\\"1\\\"\")");
+}
+
+#[test]
+fn check_dock_windows_with_mouse() {
+    let mut driver = TestDriver::new(App::debug(60, 20, "")).unwrap();
+    let a = driver.add_window(Window::new("Alpha", layout!("x:20,y:5,w:20,h:8"), window::Flags::Dockable));
+    let b = driver.add_window(Window::new("Beta", layout!("x:5,y:2,w:16,h:6"), window::Flags::Dockable));
+    let c = driver.add_window(Window::new("Gamma", layout!("x:40,y:12,w:16,h:6"), window::Flags::None));
+    let d = driver.desktop();
+    driver.settle();
+
+    // dragging a window to the left margin of the desktop docks it
+    driver.drag(25, 5, 0, 10);
+    assert!(driver.get(d).unwrap().is_docked(a));
+    assert_eq!(driver.bounds(a), Some(Rect::new(0, 0, 59, 19)));
+
+    // dragging a window in the center of a pane adds a new tab
+    driver.focus(b);
+    driver.drag(10, 2, 29, 9);
+    assert!(driver.get(d).unwrap().is_docked(b));
+    assert_eq!(driver.bounds(b), Some(Rect::new(0, 1, 59, 19)));
+    assert_eq!(driver.bounds(a), None);
+
+    // windows without the Dockable flag are not docked
+    driver.focus(c);
+    driver.drag(45, 12, 5, 10);
+    assert!(!driver.get(d).unwrap().is_docked(c));
+    assert_eq!(driver.bounds(c), Some(Rect::new(0, 10, 15, 15)));
+
+    // dragging a docked window (anywhere else) makes it float again
+    driver.drag(10, 1, 30, 15);
+    assert!(!driver.get(d).unwrap().is_docked(b));
+    assert_eq!(driver.bounds(b), Some(Rect::new(20, 15, 35, 19)));
+    assert_eq!(driver.bounds(a), Some(Rect::new(0, 0, 59, 19)));
+}
@@ -0,0 +1,14 @@
+//! A container that arranges its children in a table of rows and columns.
+//!
+//! The Grid control splits its client area in rows and columns (each of them with a fixed, automatic or
+//! proportional size) and places every child in a cell, optionally spanning multiple rows or columns.
+//! The cells are re-computed every time the grid is resized.
+
+mod grid;
+mod grid_item;
+mod length;
+#[cfg(test)]
+mod tests;
+
+pub use self::grid::Grid;
+pub use self::length::Length;
@@ -0,0 +1,243 @@
+use super::grid_item::GridItem;
+use super::length::{compute_tracks, Length};
+use crate::prelude::*;
+
+#[CustomControl(overwrite=OnResize+OnMeasure, internal = true)]
+pub struct Grid {
+    items: Vec<GridItem>,
+    columns: Vec<Length>,
+    rows: Vec<Length>,
+    column_spacing: u16,
+    row_spacing: u16,
+}
+impl Grid {
+    /// Creates a new Grid control with the specified layout, columns and rows.
+    /// Every column (or row) can have a fixed size (`Length::Fixed`), the size of its content (`Length::Auto`) or
+    /// a part of the remaining space (`Length::Star`). If no columns (or rows) are provided, the grid has one column
+    /// (or row) that occupies the entire client area.
+    ///
+    /// The cell of every child is taken from its layout (`row`, `column`, `row-span` and `column-span` parameters)
+    /// and the rest of the layout is computed relative to that cell.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use appcui::prelude::*;
+    ///
+    /// let mut g = Grid::new(
+    ///     layout!("d:f"),
+    ///     &[grid::Length::Fixed(10), grid::Length::Star(1)],
+    ///     &[grid::Length::Auto, grid::Length::Auto, grid::Length::Star(1)],
+    /// );
+    /// g.add(label!("'Name:',row:0,col:0"));
+    /// g.add(textfield!("'',row:0,col:1"));
+    /// g.add(label!("'Notes:',row:1,col:0,colspan:2,a:l,h:1,w:10"));
+    /// g.add(textarea!("'',row:2,col:0,colspan:2"));
+    /// ```
+    pub fn new(layout: Layout, columns: &[Length], rows: &[Length]) -> Self {
+        Self {
+            base: ControlBase::with_status_flags(layout, StatusFlags::Visible | StatusFlags::Enabled),
+            items: Vec::with_capacity(8),
+            columns: columns.to_vec(),
+            rows: rows.to_vec(),
+            column_spacing: 0,
+            row_spacing: 0,
+        }
+    }
+
+    /// Adds a new control to the grid and returns a handle towards it. The cell of the control is taken from its layout
+    /// (if the layout does not specify a cell, the control is placed in the first cell of the grid).
+    /// A row or a column that is outside the grid is clamped to the last row or column.
+    pub fn add<T>(&mut self, control: T) -> Handle<T>
+    where
+        T: Control + NotWindow + NotDesktop + 'static,
+    {
+        let h = self.add_child(control);
+        if !h.is_none() {
+            self.items.push(GridItem::new(h));
+            self.reflow();
+        }
+        h
+    }
+
+    /// Moves a child of the grid to a different cell (the spans of the child remain the same).
+    pub fn set_cell<T>(&mut self, handle: Handle<T>, column: u16, row: u16) {
+        let h: Handle<()> = handle.cast();
+        if let Some(item) = self.items.iter_mut().find(|i| i.handle() == h) {
+            item.cell.column = column;
+            item.cell.row = row;
+            self.reflow();
+        }
+    }
+
+    /// Returns the cell (column, row) of a child or `None` if the handle is not a child of the grid.
+    pub fn cell<T>(&self, handle: Handle<T>) -> Option<(u16, u16)> {
+        let h: Handle<()> = handle.cast();
+        self.items.iter().find(|i| i.handle() == h).map(|i| (i.cell.column, i.cell.row))
+    }
+
+    /// Sets the number of columns and rows occupied by a child of the grid (a value of 0 is treated as 1).
+    pub fn set_span<T>(&mut self, handle: Handle<T>, column_span: u16, row_span: u16) {
+        let h: Handle<()> = handle.cast();
+        if let Some(item) = self.items.iter_mut().find(|i| i.handle() == h) {
+            item.cell.column_span = column_span.max(1);
+            item.cell.row_span = row_span.max(1);
+            self.reflow();
+        }
+    }
+
+    /// Returns the number of columns and rows occupied by a child or `None` if the handle is not a child of the grid.
+    pub fn span<T>(&self, handle: Handle<T>) -> Option<(u16, u16)> {
+        let h: Handle<()> = handle.cast();
+        self.items.iter().find(|i| i.handle() == h).map(|i| (i.cell.column_span, i.cell.row_span))
+    }
+
+    /// Sets the columns of the grid.
+    pub fn set_columns(&mut self, columns: &[Length]) {
+        self.columns = columns.to_vec();
+        self.reflow();
+    }
+
+    /// Returns the columns of the grid.
+    #[inline(always)]
+    pub fn columns(&self) -> &[Length] {
+        &self.columns
+    }
+
+    /// Sets the rows of the grid.
+    pub fn set_rows(&mut self, rows: &[Length]) {
+        self.rows = rows.to_vec();
+        self.reflow();
+    }
+
+    /// Returns the rows of the grid.
+    #[inline(always)]
+    pub fn rows(&self) -> &[Length] {
+        &self.rows
+    }
+
+    /// Sets the space (in characters) between two consecutive columns and between two consecutive rows.
+    pub fn set_spacing(&mut self, column_spacing: u16, row_spacing: u16) {
+        self.column_spacing = column_spacing;
+        self.row_spacing = row_spacing;
+        self.reflow();
+    }
+
+    /// Returns the space (in characters) between two consecutive columns.
+    #[inline(always)]
+    pub fn column_spacing(&self) -> u16 {
+        self.column_spacing
+    }
+
+    /// Returns the space (in characters) between two consecutive rows.
+    #[inline(always)]
+    pub fn row_spacing(&self) -> u16 {
+        self.row_spacing
+    }
+
+    /// Sets the padding (the empty space between the margins of the grid and its cells).
+    pub fn set_padding(&mut self, left: u8, top: u8, right: u8, bottom: u8) {
+        self.base.set_margins(left, top, right, bottom);
+        self.reflow();
+    }
+
+    /// Re-computes the size of the rows and columns and the position and size of all children. This is done automatically
+    /// when the grid is resized or when its properties are changed, but it needs to be called if the visibility of a child
+    /// was changed (hidden children are not considered when computing the size of `Auto` rows and columns).
+    pub fn reflow(&mut self) {
+        let client = self.client_size();
+        if (client.width == 0) || (client.height == 0) {
+            return;
+        }
+        let columns = Self::definitions(&self.columns);
+        let rows = Self::definitions(&self.rows);
+        let visible: Vec<&GridItem> = self.items.iter().filter(|i| i.is_visible()).collect();
+        let (auto_widths, auto_heights) = Self::content_tracks(&visible, columns.len(), rows.len(), client);
+        let column_spacing = self.column_spacing as u32;
+        let row_spacing = self.row_spacing as u32;
+        let widths = compute_tracks(&columns, client.width, column_spacing, &auto_widths);
+        let heights = compute_tracks(&rows, client.height, row_spacing, &auto_heights);
+        let xs = Self::offsets(&widths, column_spacing);
+        let ys = Self::offsets(&heights, row_spacing);
+        for item in visible.iter() {
+            let (column, column_span) = item.columns(columns.len());
+            let (row, row_span) = item.rows(rows.len());
+            let width = widths[column..column + column_span].iter().sum::<u32>() + column_spacing * (column_span as u32 - 1);
+            let height = heights[row..row + row_span].iter().sum::<u32>() + row_spacing * (row_span as u32 - 1);
+            item.place(xs[column], ys[row], width, height);
+        }
+    }
+
+    // the size of the content of every column and row (only children that occupy one column or one row are considered)
+    fn content_tracks(visible: &[&GridItem], columns: usize, rows: usize, available: Size) -> (Vec<u32>, Vec<u32>) {
+        let mut widths = vec![0u32; columns];
+        let mut heights = vec![0u32; rows];
+        for item in visible.iter() {
+            let (column, column_span) = item.columns(columns);
+            let (row, row_span) = item.rows(rows);
+            let size = item.content_size(available);
+            if column_span == 1 {
+                widths[column] = widths[column].max(size.width);
+            }
+            if row_span == 1 {
+                heights[row] = heights[row].max(size.height);
+            }
+        }
+        (widths, heights)
+    }
+
+    // the size of a list of tracks when every Auto or Star track has the size of its content
+    fn content_length(definitions: &[Length], content: &[u32], spacing: u32) -> u32 {
+        let sum: u32 = definitions
+            .iter()
+            .zip(content.iter())
+            .map(|(d, c)| match d {
+                Length::Fixed(v) => *v as u32,
+                Length::Auto | Length::Star(_) => *c,
+            })
+            .sum();
+        sum + spacing * (definitions.len() as u32 - 1)
+    }
+
+    fn definitions(list: &[Length]) -> Vec<Length> {
+        if list.is_empty() {
+            vec![Length::Star(1)]
+        } else {
+            list.to_vec()
+        }
+    }
+
+    fn offsets(sizes: &[u32], spacing: u32) -> Vec<i32> {
+        let mut pos = 0i32;
+        sizes
+            .iter()
+            .map(|size| {
+                let current = pos;
+                pos += (*size + spacing) as i32;
+                current
+            })
+            .collect()
+    }
+}
+
+impl OnResize for Grid {
+    fn on_resize(&mut self, _old_size: Size, _new_size: Size) {
+        self.reflow();
+    }
+    fn on_children_layout_changed(&mut self) {
+        self.reflow();
+    }
+}
+
+impl OnMeasure for Grid {
+    fn preferred_size(&self, available: Size) -> Option<Size> {
+        let columns = Self::definitions(&self.columns);
+        let rows = Self::definitions(&self.rows);
+        let visible: Vec<&GridItem> = self.items.iter().filter(|i| i.is_visible()).collect();
+        let (widths, heights) = Self::content_tracks(&visible, columns.len(), rows.len(), available);
+        let m = &self.base.margins;
+        Some(Size::new(
+            Self::content_length(&columns, &widths, self.column_spacing as u32) + m.left as u32 + m.right as u32,
+            Self::content_length(&rows, &heights, self.row_spacing as u32) + m.top as u32 + m.bottom as u32,
+        ))
+    }
+}
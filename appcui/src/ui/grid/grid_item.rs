@@ -0,0 +1,85 @@
+use crate::prelude::*;
+use crate::ui::layout::{ControlLayout, GridCell};
+
+// a child of a Grid container
+// the original layout of the child is kept as it is evaluated relative to the cell on every re-flow
+pub(super) struct GridItem {
+    handle: Handle<()>,
+    layout: ControlLayout,
+    pub(super) cell: GridCell,
+}
+
+impl GridItem {
+    pub(super) fn new<T>(handle: Handle<T>) -> Self {
+        let handle = handle.cast();
+        let layout = match RuntimeManager::get().get_controls_mut().get(handle) {
+            Some(control) => control.base().declared_layout(),
+            None => ControlLayout::default(),
+        };
+        Self {
+            handle,
+            layout,
+            cell: layout.cell().unwrap_or_default(),
+        }
+    }
+    #[inline(always)]
+    pub(super) fn handle(&self) -> Handle<()> {
+        self.handle
+    }
+    // the stored layout or the alternative layout selected by a breakpoint
+    fn layout(&self) -> ControlLayout {
+        RuntimeManager::get().get_controls().effective_layout(self.handle, &self.layout)
+    }
+    pub(super) fn is_visible(&self) -> bool {
+        RuntimeManager::get()
+            .get_controls_mut()
+            .get(self.handle)
+            .map(|c| c.base().is_visible())
+            .unwrap_or(false)
+    }
+    // the size of the child when it is not constrained by a cell
+    pub(super) fn content_size(&self, available: Size) -> Size {
+        self.layout().measured_size(RuntimeManager::get().get_controls().measure(self.handle, available))
+    }
+    // the cell of the child (an alternative layout selected by a breakpoint can move the child to a different cell)
+    fn current_cell(&self) -> GridCell {
+        RuntimeManager::get()
+            .get_controls()
+            .get(self.handle)
+            .and_then(|c| c.base().responsive_layout())
+            .and_then(|l| l.cell())
+            .unwrap_or(self.cell)
+    }
+    // (first, count) for a grid with `columns` columns
+    pub(super) fn columns(&self, columns: usize) -> (usize, usize) {
+        let cell = self.current_cell();
+        Self::clamp(cell.column, cell.column_span, columns)
+    }
+    // (first, count) for a grid with `rows` rows
+    pub(super) fn rows(&self, rows: usize) -> (usize, usize) {
+        let cell = self.current_cell();
+        Self::clamp(cell.row, cell.row_span, rows)
+    }
+    fn clamp(index: u16, span: u16, count: usize) -> (usize, usize) {
+        let first = (index as usize).min(count - 1);
+        (first, (span.max(1) as usize).min(count - first))
+    }
+    // places the child within the cell (the layout of the child is evaluated relative to the cell)
+    pub(super) fn place(&self, x: i32, y: i32, width: u32, height: u32) {
+        let width = width.min(u16::MAX as u32) as u16;
+        let height = height.min(u16::MAX as u32) as u16;
+        let mut layout = self.layout();
+        if layout.has_auto_size() {
+            let available = Size::new(width as u32, height as u32);
+            if let Some(sz) = RuntimeManager::get().get_controls().measure(self.handle, available) {
+                layout.set_content_size(sz);
+            }
+        }
+        let (pos, size) = layout.preferred_bounds(width, height);
+        if let Some(control) = RuntimeManager::get().get_controls_mut().get_mut(self.handle) {
+            let base = control.base_mut();
+            base.set_position(x + pos.x, y + pos.y);
+            base.set_size(size.width.min(width as u32) as u16, size.height.min(height as u32) as u16);
+        }
+    }
+}
@@ -0,0 +1,38 @@
+/// The size of a row or a column of a [`Grid`](crate::ui::Grid).
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Length {
+    /// A fixed size (in characters).
+    Fixed(u16),
+    /// The size of the biggest child from that row or column (children that span multiple rows or columns are not considered).
+    Auto,
+    /// A part of the space that remains after the fixed and automatic rows or columns are sized, proportional with the provided weight.
+    Star(u16),
+}
+
+// computes the size of every track (row or column) for an available space of `available` characters
+pub(super) fn compute_tracks(definitions: &[Length], available: u32, spacing: u32, auto_sizes: &[u32]) -> Vec<u32> {
+    let mut sizes: Vec<u32> = definitions
+        .iter()
+        .zip(auto_sizes.iter())
+        .map(|(d, auto)| match d {
+            Length::Fixed(value) => *value as u32,
+            Length::Auto => *auto,
+            Length::Star(_) => 0,
+        })
+        .collect();
+    let used = sizes.iter().sum::<u32>() + spacing * (definitions.len().max(1) as u32 - 1);
+    let weights: Vec<u16> = definitions
+        .iter()
+        .map(|d| match d {
+            Length::Star(weight) => *weight,
+            _ => 0,
+        })
+        .collect();
+    let shares = crate::ui::stackpanel::distribute(available.saturating_sub(used), &weights);
+    for (index, d) in definitions.iter().enumerate() {
+        if matches!(d, Length::Star(_)) {
+            sizes[index] = shares[index];
+        }
+    }
+    sizes
+}
@@ -0,0 +1,93 @@
+use crate::prelude::*;
+use crate::testing::TestDriver;
+
+fn cell(column: u16, row: u16) -> LayoutBuilder {
+    LayoutBuilder::new().column(column).row(row)
+}
+
+#[test]
+fn check_fixed_auto_and_star_tracks() {
+    let mut driver = TestDriver::new(App::debug(60, 20, "")).unwrap();
+    // window client area: (11,5) - 38x10
+    let mut w = Window::new("Test", layout!("a:c,w:40,h:12"), window::Flags::None);
+    let mut g = Grid::new(
+        layout!("d:f"),
+        &[grid::Length::Fixed(10), grid::Length::Auto, grid::Length::Star(1), grid::Length::Star(2)],
+        &[grid::Length::Auto, grid::Length::Star(1), grid::Length::Fixed(2)],
+    );
+    g.set_spacing(1, 0);
+    let a = g.add(Label::new("a", cell(0, 0).build()));
+    let b = g.add(Label::new(
+        "b",
+        cell(1, 0).alignment(layout::Alignment::TopLeft).width(6).height(2).build(),
+    ));
+    let c = g.add(Label::new("c", cell(2, 1).column_span(2).build()));
+    let d = g.add(Label::new("d", cell(0, 2).column_span(4).build()));
+    w.add(g);
+    driver.add_window(w);
+    driver.settle();
+    // columns: 10, 6 (auto), 6 and 13 (the remaining 19 characters split in 1:2)
+    // rows: 2 (auto), 6 and 2
+    assert_eq!(driver.bounds(a), Some(Rect::new(11, 5, 20, 6)));
+    assert_eq!(driver.bounds(b), Some(Rect::new(22, 5, 27, 6)));
+    assert_eq!(driver.bounds(c), Some(Rect::new(29, 7, 48, 12)));
+    assert_eq!(driver.bounds(d), Some(Rect::new(11, 13, 48, 14)));
+}
+
+#[test]
+fn check_recompute_on_resize_and_cell_changes() {
+    let mut driver = TestDriver::new(App::debug(60, 20, "")).unwrap();
+    let mut w = Window::new("Test", layout!("x:0,y:0,w:40,h:12"), window::Flags::Sizeable);
+    let g = w.add(Grid::new(
+        layout!("d:f"),
+        &[grid::Length::Star(1), grid::Length::Star(1)],
+        &[grid::Length::Star(1), grid::Length::Star(1)],
+    ));
+    let w = driver.add_window(w);
+    let (e, f) = {
+        let g = driver.get_mut(g).unwrap();
+        let e = g.add(Label::new(
+            "e",
+            cell(0, 0).alignment(layout::Alignment::Center).width(4).height(1).build(),
+        ));
+        let f = g.add(Label::new("f", cell(1, 1).build()));
+        (e, f)
+    };
+    driver.settle();
+    // cells of 19x5 characters, the first label is centered in its cell
+    assert_eq!(driver.bounds(e), Some(Rect::new(8, 3, 11, 3)));
+    assert_eq!(driver.bounds(f), Some(Rect::new(20, 6, 38, 10)));
+
+    // the window becomes smaller --> cells of 14x3 characters
+    driver.get_mut(w).unwrap().set_size(30, 8);
+    driver.settle();
+    assert_eq!(driver.bounds(e), Some(Rect::new(6, 2, 9, 2)));
+    assert_eq!(driver.bounds(f), Some(Rect::new(15, 4, 28, 6)));
+
+    // move and span the children (cells outside of the grid are clamped to the last row/column)
+    let gr = driver.get_mut(g).unwrap();
+    gr.set_cell(f, 0, 0);
+    gr.set_span(f, 2, 0);
+    gr.set_cell(e, 5, 5);
+    assert_eq!(gr.cell(f), Some((0, 0)));
+    assert_eq!(gr.span(f), Some((2, 1)));
+    assert_eq!(gr.cell(e), Some((5, 5)));
+    driver.settle();
+    assert_eq!(driver.bounds(f), Some(Rect::new(1, 1, 28, 3)));
+    assert_eq!(driver.bounds(e), Some(Rect::new(20, 5, 23, 5)));
+
+    // a grid without rows and columns has only one cell
+    let gr = driver.get_mut(g).unwrap();
+    gr.set_columns(&[]);
+    gr.set_rows(&[]);
+    assert!(gr.columns().is_empty());
+    driver.settle();
+    assert_eq!(driver.bounds(f), Some(Rect::new(1, 1, 28, 6)));
+}
+
+#[test]
+fn check_cell_only_layout() {
+    assert!(LayoutBuilder::new().row(1).column(2).try_build().is_ok());
+    assert!(LayoutBuilder::new().row_span(2).try_build().is_ok());
+    assert!(LayoutBuilder::new().try_build().is_err());
+}
@@ -0,0 +1,16 @@
+//! A hex view UI control for inspecting and editing binary data.
+//!
+//! The HexView control displays bytes as an offset gutter, a hexadecimal pane and an ASCII pane. The
+//! bytes are read lazily (only the visible ones) from a file or any `Read + Seek` source, so very large
+//! files can be opened instantly. It supports synchronized selection, overwrite editing, go-to-offset
+//! and searching for byte patterns or strings.
+
+mod hexview;
+mod initialization_flags;
+mod source;
+#[cfg(test)]
+mod tests;
+
+pub use self::hexview::HexView;
+pub use self::initialization_flags::Endianness;
+pub use self::initialization_flags::Flags;
@@ -0,0 +1,677 @@
+use super::source::{ReadSeek, Source};
+use super::{Endianness, Flags};
+use crate::prelude::*;
+use std::io::{Read, Seek, Write};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+const WHEEL_ROWS: u64 = 3;
+const MAX_PROMPT_LEN: usize = 64;
+const HEX_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Pane {
+    Hex,
+    Ascii,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum PromptKind {
+    GoTo,
+    FindHex,
+    FindText,
+}
+impl PromptKind {
+    fn label(&self) -> &'static str {
+        match self {
+            PromptKind::GoTo => "Go to offset: ",
+            PromptKind::FindHex => "Find bytes: ",
+            PromptKind::FindText => "Find text: ",
+        }
+    }
+}
+
+// positions (in characters) of the parts of a row
+struct Geometry {
+    offset_digits: usize,
+    hex_x: i32,
+    ascii_x: i32,
+    bytes_per_row: u64,
+    rows: u64,
+}
+
+#[CustomControl(overwrite=OnPaint+OnKeyPressed+OnMouseEvent+OnResize, internal=true)]
+pub struct HexView {
+    source: Source,
+    path: Option<PathBuf>,
+    flags: Flags,
+    endianness: Endianness,
+    group_size: u8,
+    bytes_per_row: u8,
+    // first visible row
+    top: u64,
+    cursor: u64,
+    high_nibble: bool,
+    pane: Pane,
+    anchor: Option<u64>,
+    // the visible bytes (and which of them were changed)
+    view: Vec<u8>,
+    view_changed: Vec<bool>,
+    view_offset: u64,
+    last_search: Vec<u8>,
+    prompt: Option<(PromptKind, String)>,
+    message: Option<&'static str>,
+    drag_start: Option<u64>,
+}
+impl HexView {
+    /// Creates a new (empty) hex view with the specified layout and flags. The flags can be a combination of:
+    /// * `hexview::Flags::ReadOnly` - the bytes can not be edited
+    /// * `hexview::Flags::HideAscii` - the ASCII pane is not displayed
+    ///
+    /// The content is set via the `open`, `set_reader` or `set_data` methods.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use appcui::prelude::*;
+    ///
+    /// let mut h = HexView::new(layout!("d:f"), hexview::Flags::ReadOnly);
+    /// h.set_data(b"Hello world".to_vec());
+    /// ```
+    pub fn new(layout: Layout, flags: Flags) -> Self {
+        Self {
+            base: ControlBase::with_status_flags(layout, StatusFlags::Visible | StatusFlags::Enabled | StatusFlags::AcceptInput),
+            source: Source::empty(),
+            path: None,
+            flags,
+            endianness: Endianness::Big,
+            group_size: 1,
+            bytes_per_row: 0,
+            top: 0,
+            cursor: 0,
+            high_nibble: true,
+            pane: Pane::Hex,
+            anchor: None,
+            view: Vec::new(),
+            view_changed: Vec::new(),
+            view_offset: 0,
+            last_search: Vec::new(),
+            prompt: None,
+            message: None,
+            drag_start: None,
+        }
+    }
+
+    /// Opens a file. The file is not loaded in memory - only the bytes that are displayed are read from it.
+    /// The changes can be later written back in the file via the `save` method.
+    pub fn open(&mut self, path: &Path) -> Result<(), Error> {
+        let file =
+            std::fs::File::open(path).map_err(|e| Error::new(ErrorKind::InvalidParameter, format!("Fail to open '{}': {e}", path.display())))?;
+        self.set_reader(file)?;
+        self.path = Some(path.to_path_buf());
+        Ok(())
+    }
+
+    /// Sets the source of the bytes (any object that implements `Read + Seek`). The bytes are read only when
+    /// they are displayed or searched.
+    pub fn set_reader<R: Read + Seek + 'static>(&mut self, reader: R) -> Result<(), Error> {
+        let reader: Box<dyn ReadSeek> = Box::new(reader);
+        let source = Source::new(reader).map_err(|e| Error::new(ErrorKind::InvalidParameter, format!("Fail to read the data: {e}")))?;
+        self.set_source(source);
+        Ok(())
+    }
+
+    /// Displays a buffer of bytes
+    pub fn set_data(&mut self, data: Vec<u8>) {
+        if let Ok(source) = Source::new(Box::new(std::io::Cursor::new(data))) {
+            self.set_source(source);
+        }
+    }
+
+    fn set_source(&mut self, source: Source) {
+        self.source = source;
+        self.path = None;
+        self.top = 0;
+        self.cursor = 0;
+        self.high_nibble = true;
+        self.anchor = None;
+        self.update();
+    }
+
+    /// Returns the number of bytes displayed
+    #[inline(always)]
+    pub fn len(&self) -> u64 {
+        self.source.len()
+    }
+
+    /// Returns `true` if there are no bytes to display
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.source.len() == 0
+    }
+
+    /// Reads the bytes (including the changes) starting from `offset`. Returns the number of bytes read.
+    pub fn read(&mut self, offset: u64, buf: &mut [u8]) -> usize {
+        self.source.read(offset, buf)
+    }
+
+    /// Overwrites the byte from `offset` (the change is highlighted until it is saved or discarded)
+    pub fn set_byte(&mut self, offset: u64, value: u8) {
+        self.source.set(offset, value);
+        self.update();
+    }
+
+    /// Returns `true` if any byte was changed
+    #[inline(always)]
+    pub fn is_modified(&self) -> bool {
+        self.source.changes_count() > 0
+    }
+
+    /// Removes all the changes (the original bytes are displayed again)
+    pub fn discard_changes(&mut self) {
+        self.source.discard_changes();
+        self.update();
+    }
+
+    /// Writes the changed bytes (and only them - at their offsets) in `writer`
+    pub fn write_changes<W: Write + Seek>(&self, writer: &mut W) -> Result<(), Error> {
+        self.source
+            .write_changes(writer)
+            .map_err(|e| Error::new(ErrorKind::InvalidParameter, format!("Fail to write the changes: {e}")))
+    }
+
+    /// Writes the changes in the file opened via the `open` method. Returns an error if the content was not
+    /// loaded from a file or if the file can not be written.
+    pub fn save(&mut self) -> Result<(), Error> {
+        let Some(path) = self.path.as_ref() else {
+            return Err(Error::new(
+                ErrorKind::InvalidParameter,
+                "The content was not loaded from a file".to_string(),
+            ));
+        };
+        let mut file = std::fs::OpenOptions::new()
+            .write(true)
+            .open(path)
+            .map_err(|e| Error::new(ErrorKind::InvalidParameter, format!("Fail to open '{}' for writing: {e}", path.display())))?;
+        self.write_changes(&mut file)?;
+        self.source.changes_saved();
+        self.update();
+        Ok(())
+    }
+
+    /// Returns the offset of the byte under the cursor
+    #[inline(always)]
+    pub fn cursor(&self) -> u64 {
+        self.cursor
+    }
+
+    /// Moves the cursor to the specified offset (and makes it visible). The selection is removed.
+    pub fn go_to(&mut self, offset: u64) {
+        self.move_cursor(offset, false);
+    }
+
+    /// Returns the selected bytes (or `None` if there is no selection)
+    pub fn selection(&self) -> Option<Range<u64>> {
+        self.anchor.map(|a| a.min(self.cursor)..a.max(self.cursor) + 1)
+    }
+
+    /// Selects a range of bytes (the cursor is moved at the end of the range)
+    pub fn select(&mut self, range: Range<u64>) {
+        if range.is_empty() || range.start >= self.len() {
+            return;
+        }
+        self.anchor = Some(range.start);
+        self.cursor = range.end.min(self.len()) - 1;
+        self.update();
+    }
+
+    /// Sets the number of bytes displayed on a row. If `0` is provided (the default), the number of bytes is
+    /// computed from the width of the control.
+    pub fn set_bytes_per_row(&mut self, count: u8) {
+        self.bytes_per_row = count;
+        self.update();
+    }
+
+    /// Sets the number of bytes of a group (1, 2, 4 or 8). The groups are separated by a space in the
+    /// hexadecimal pane.
+    pub fn set_group_size(&mut self, size: u8) {
+        self.group_size = match size {
+            0..=1 => 1,
+            2..=3 => 2,
+            4..=7 => 4,
+            _ => 8,
+        };
+        self.update();
+    }
+
+    /// Sets the order in which the bytes of a group are displayed in the hexadecimal pane
+    pub fn set_endianness(&mut self, endianness: Endianness) {
+        self.endianness = endianness;
+    }
+
+    /// Searches for a sequence of bytes (starting after the cursor and wrapping around at the end). If found, the
+    /// bytes are selected and `true` is returned.
+    pub fn find(&mut self, pattern: &[u8]) -> bool {
+        self.last_search = pattern.to_vec();
+        self.find_next()
+    }
+
+    /// Searches for a text (its UTF-8 bytes). If found, the bytes are selected and `true` is returned.
+    pub fn find_text(&mut self, text: &str) -> bool {
+        self.find(text.as_bytes())
+    }
+
+    /// Searches for the next occurrence of the last pattern
+    pub fn find_next(&mut self) -> bool {
+        if self.last_search.is_empty() {
+            return false;
+        }
+        let start = if self.anchor.is_some() { self.cursor + 1 } else { self.cursor };
+        let found = match self.source.find(&self.last_search, start) {
+            Some(offset) => Some(offset),
+            None => self.source.find(&self.last_search, 0).filter(|o| *o < start),
+        };
+        match found {
+            Some(offset) => {
+                self.select(offset..offset + self.last_search.len() as u64);
+                true
+            }
+            None => {
+                self.message = Some("Pattern not found");
+                false
+            }
+        }
+    }
+
+    fn geometry(&self) -> Geometry {
+        let size = self.size();
+        let offset_digits = HexView::offset_digits(self.len());
+        let group = self.group_size as i32;
+        let hex_x = offset_digits as i32 + 2;
+        let bytes_per_row = if self.bytes_per_row > 0 {
+            self.bytes_per_row as i32
+        } else {
+            // a group needs 2 characters for every byte, a space and (if visible) one character in the ASCII pane
+            let ascii = if self.flags.contains(Flags::HideAscii) { 0 } else { 2 };
+            let per_group = group * 2 + 1 + if ascii > 0 { group } else { 0 };
+            let available = size.width as i32 - hex_x - ascii + 1;
+            let mut count = (available / per_group).max(1) * group;
+            if count >= 8 {
+                count -= count % 8;
+            }
+            count
+        };
+        let groups = (bytes_per_row + group - 1) / group;
+        let hex_width = bytes_per_row * 2 + groups - 1;
+        let reserved = if self.prompt.is_some() || self.message.is_some() { 1 } else { 0 };
+        Geometry {
+            offset_digits,
+            hex_x,
+            ascii_x: hex_x + hex_width + 2,
+            bytes_per_row: bytes_per_row as u64,
+            rows: (size.height as i32 - reserved).max(1) as u64,
+        }
+    }
+
+    fn offset_digits(len: u64) -> usize {
+        let mut digits = 1;
+        let mut value = len.saturating_sub(1) >> 4;
+        while value > 0 {
+            digits += 1;
+            value >>= 4;
+        }
+        digits.max(8)
+    }
+
+    // the column of the first hex digit of the i-th byte of a row
+    fn hex_column(&self, g: &Geometry, index: u64) -> i32 {
+        let group = self.group_size as u64;
+        let in_group = index % group;
+        // the last group of a row can be shorter (if the number of bytes per row is not a multiple of the group size)
+        let group_len = group.min(g.bytes_per_row - (index - in_group));
+        let position = match self.endianness {
+            Endianness::Big => in_group,
+            Endianness::Little => group_len - 1 - in_group,
+        };
+        g.hex_x + ((index / group) * (group * 2 + 1) + position * 2) as i32
+    }
+
+    // makes sure the cursor is valid and visible and reads the visible bytes
+    fn update(&mut self) {
+        let len = self.len();
+        self.cursor = self.cursor.min(len.saturating_sub(1));
+        if let Some(anchor) = self.anchor {
+            self.anchor = Some(anchor.min(len.saturating_sub(1)));
+        }
+        let g = self.geometry();
+        let row = self.cursor / g.bytes_per_row;
+        if row < self.top {
+            self.top = row;
+        } else if row >= self.top + g.rows {
+            self.top = row + 1 - g.rows;
+        }
+        self.load_view(&g);
+    }
+
+    fn load_view(&mut self, g: &Geometry) {
+        let last_row = self.len().div_ceil(g.bytes_per_row);
+        self.top = self.top.min(last_row.saturating_sub(g.rows));
+        self.view_offset = self.top * g.bytes_per_row;
+        self.view.resize((g.rows * g.bytes_per_row) as usize, 0);
+        let count = self.source.read(self.view_offset, &mut self.view);
+        self.view.truncate(count);
+        self.view_changed.clear();
+        for index in 0..count as u64 {
+            self.view_changed.push(self.source.is_changed(self.view_offset + index));
+        }
+    }
+
+    fn move_cursor(&mut self, offset: u64, select: bool) {
+        if select {
+            if self.anchor.is_none() {
+                self.anchor = Some(self.cursor);
+            }
+        } else {
+            self.anchor = None;
+        }
+        self.cursor = offset;
+        self.high_nibble = true;
+        self.update();
+    }
+
+    fn edit(&mut self, character: char) -> bool {
+        if self.flags.contains(Flags::ReadOnly) || self.is_empty() {
+            return false;
+        }
+        let Some(current) = self.source.byte(self.cursor) else {
+            return false;
+        };
+        match self.pane {
+            Pane::Hex => {
+                let Some(digit) = character.to_digit(16) else {
+                    return false;
+                };
+                let digit = digit as u8;
+                if self.high_nibble {
+                    self.source.set(self.cursor, (digit << 4) | (current & 0x0F));
+                    self.high_nibble = false;
+                } else {
+                    self.source.set(self.cursor, (current & 0xF0) | digit);
+                    self.cursor = (self.cursor + 1).min(self.len() - 1);
+                    self.high_nibble = true;
+                }
+            }
+            Pane::Ascii => {
+                if !(' '..='~').contains(&character) {
+                    return false;
+                }
+                self.source.set(self.cursor, character as u8);
+                self.cursor = (self.cursor + 1).min(self.len() - 1);
+            }
+        }
+        self.anchor = None;
+        self.update();
+        true
+    }
+
+    fn execute_prompt(&mut self, kind: PromptKind, text: &str) {
+        match kind {
+            PromptKind::GoTo => {
+                let digits = text.trim().trim_start_matches("0x").trim_start_matches("0X").replace('_', "");
+                match u64::from_str_radix(&digits, 16) {
+                    Ok(offset) if offset < self.len() => self.go_to(offset),
+                    _ => self.message = Some("Invalid offset"),
+                }
+            }
+            PromptKind::FindHex => {
+                let digits: Vec<u32> = text
+                    .chars()
+                    .filter(|c| !c.is_whitespace())
+                    .map(|c| c.to_digit(16).unwrap_or(16))
+                    .collect();
+                if digits.is_empty() || !digits.len().is_multiple_of(2) || digits.contains(&16) {
+                    self.message = Some("Invalid byte pattern");
+                } else {
+                    let pattern: Vec<u8> = digits.chunks(2).map(|c| (c[0] * 16 + c[1]) as u8).collect();
+                    self.find(&pattern);
+                }
+            }
+            PromptKind::FindText => {
+                if !text.is_empty() {
+                    self.find_text(text);
+                }
+            }
+        }
+    }
+
+    fn on_prompt_key(&mut self, key: Key, character: char) -> EventProcessStatus {
+        let Some((kind, text)) = self.prompt.as_mut() else {
+            return EventProcessStatus::Ignored;
+        };
+        match key.value() {
+            key!("Escape") => self.prompt = None,
+            key!("Enter") => {
+                let (kind, text) = (*kind, std::mem::take(text));
+                self.prompt = None;
+                self.execute_prompt(kind, &text);
+            }
+            key!("Backspace") => {
+                text.pop();
+            }
+            _ if character >= ' ' && text.chars().count() < MAX_PROMPT_LEN => text.push(character),
+            _ => return EventProcessStatus::Ignored,
+        }
+        self.update();
+        EventProcessStatus::Processed
+    }
+
+    fn show_prompt(&mut self, kind: PromptKind) -> EventProcessStatus {
+        self.prompt = Some((kind, String::new()));
+        self.message = None;
+        self.update();
+        EventProcessStatus::Processed
+    }
+
+    // the byte (and pane) at a position inside the control
+    fn hit_test(&self, x: i32, y: i32) -> Option<(u64, Pane, bool)> {
+        let g = self.geometry();
+        if y < 0 || y as u64 >= g.rows {
+            return None;
+        }
+        let row_offset = (self.top + y as u64) * g.bytes_per_row;
+        for index in 0..g.bytes_per_row {
+            let offset = row_offset + index;
+            if offset >= self.len() {
+                break;
+            }
+            let col = self.hex_column(&g, index);
+            if x == col || x == col + 1 {
+                return Some((offset, Pane::Hex, x == col));
+            }
+            if !self.flags.contains(Flags::HideAscii) && x == g.ascii_x + index as i32 {
+                return Some((offset, Pane::Ascii, true));
+            }
+        }
+        None
+    }
+
+    fn paint_prompt(&self, surface: &mut Surface, y: i32, attr: CharAttribute, error_attr: CharAttribute) {
+        if let Some((kind, text)) = self.prompt.as_ref() {
+            let label = kind.label();
+            surface.write_string(0, y, label, attr, false);
+            surface.write_string(label.len() as i32, y, text, attr, false);
+            if self.has_focus() {
+                surface.set_cursor(label.len() as i32 + text.chars().count() as i32, y);
+            }
+        } else if let Some(message) = self.message {
+            surface.write_string(0, y, message, error_attr, false);
+        }
+    }
+}
+
+impl OnPaint for HexView {
+    fn on_paint(&self, surface: &mut Surface, theme: &Theme) {
+        let attr = match () {
+            _ if !self.is_enabled() => theme.editor.inactive,
+            _ if self.has_focus() => theme.editor.focused,
+            _ if self.is_mouse_over() => theme.editor.hovered,
+            _ => theme.editor.normal,
+        };
+        surface.clear(Character::with_attributes(' ', attr));
+        let g = self.geometry();
+        let gutter_attr = theme.editor.inactive;
+        let selected_attr = theme.editor.pressed_or_selectd;
+        let changed_attr = CharAttribute::with_color(theme.text.error.foreground, attr.background);
+        let selection = self.selection();
+        let show_ascii = !self.flags.contains(Flags::HideAscii);
+        let mut cursor_pos = None;
+        for row in 0..g.rows {
+            let row_offset = (self.top + row) * g.bytes_per_row;
+            if row_offset >= self.len() && row_offset > 0 {
+                break;
+            }
+            let y = row as i32;
+            let text = format!("{:0width$X}", row_offset, width = g.offset_digits);
+            surface.write_string(0, y, &text, gutter_attr, false);
+            for index in 0..g.bytes_per_row {
+                let offset = row_offset + index;
+                let view_index = (offset - self.view_offset) as usize;
+                if offset >= self.len() || view_index >= self.view.len() {
+                    break;
+                }
+                let value = self.view[view_index];
+                let mut a = match () {
+                    _ if selection.as_ref().is_some_and(|s| s.contains(&offset)) => selected_attr,
+                    _ if self.view_changed[view_index] => changed_attr,
+                    _ => attr,
+                };
+                let col = self.hex_column(&g, index);
+                let ascii_col = g.ascii_x + index as i32;
+                if offset == self.cursor {
+                    cursor_pos = Some(match self.pane {
+                        Pane::Hex => (col + if self.high_nibble { 0 } else { 1 }, y),
+                        Pane::Ascii => (ascii_col, y),
+                    });
+                    // the byte under the cursor is underlined in the other pane
+                    a.flags |= CharFlags::Underline;
+                }
+                surface.write_char(col, y, Character::with_attributes(HEX_DIGITS[(value >> 4) as usize] as char, a));
+                surface.write_char(col + 1, y, Character::with_attributes(HEX_DIGITS[(value & 0x0F) as usize] as char, a));
+                if show_ascii {
+                    let ch = if (0x20..0x7F).contains(&value) { value as char } else { '.' };
+                    surface.write_char(ascii_col, y, Character::with_attributes(ch, a));
+                }
+            }
+        }
+        let error_attr = CharAttribute::with_color(theme.text.error.foreground, attr.background);
+        self.paint_prompt(surface, g.rows as i32, attr, error_attr);
+        if self.has_focus() && self.prompt.is_none() {
+            if let Some((x, y)) = cursor_pos {
+                surface.set_cursor(x, y);
+            }
+        }
+    }
+}
+
+impl OnKeyPressed for HexView {
+    fn on_key_pressed(&mut self, key: Key, character: char) -> EventProcessStatus {
+        if self.prompt.is_some() {
+            return self.on_prompt_key(key, character);
+        }
+        if self.message.take().is_some() {
+            self.update();
+        }
+        let g = self.geometry();
+        let bpr = g.bytes_per_row;
+        let page = g.rows * bpr;
+        let c = self.cursor;
+        let last = self.len().saturating_sub(1);
+        let row_start = c - c % bpr;
+        match key.value() {
+            key!("Left") | key!("Shift+Left") => self.move_cursor(c.saturating_sub(1), key.modifier.contains(KeyModifier::Shift)),
+            key!("Right") | key!("Shift+Right") => self.move_cursor((c + 1).min(last), key.modifier.contains(KeyModifier::Shift)),
+            key!("Up") | key!("Shift+Up") => self.move_cursor(if c >= bpr { c - bpr } else { c }, key.modifier.contains(KeyModifier::Shift)),
+            key!("Down") | key!("Shift+Down") => {
+                self.move_cursor(if c + bpr <= last { c + bpr } else { c }, key.modifier.contains(KeyModifier::Shift))
+            }
+            key!("PageUp") | key!("Shift+PageUp") => self.move_cursor(c.saturating_sub(page), key.modifier.contains(KeyModifier::Shift)),
+            key!("PageDown") | key!("Shift+PageDown") => self.move_cursor((c + page).min(last), key.modifier.contains(KeyModifier::Shift)),
+            key!("Home") | key!("Shift+Home") => self.move_cursor(row_start, key.modifier.contains(KeyModifier::Shift)),
+            key!("End") | key!("Shift+End") => self.move_cursor((row_start + bpr - 1).min(last), key.modifier.contains(KeyModifier::Shift)),
+            key!("Ctrl+Home") | key!("Ctrl+Shift+Home") => self.move_cursor(0, key.modifier.contains(KeyModifier::Shift)),
+            key!("Ctrl+End") | key!("Ctrl+Shift+End") => self.move_cursor(last, key.modifier.contains(KeyModifier::Shift)),
+            key!("Ctrl+A") => self.select(0..self.len()),
+            key!("Tab") if !self.flags.contains(Flags::HideAscii) => {
+                self.pane = if self.pane == Pane::Hex { Pane::Ascii } else { Pane::Hex };
+                self.high_nibble = true;
+            }
+            key!("Ctrl+G") => return self.show_prompt(PromptKind::GoTo),
+            key!("Ctrl+F") => {
+                return self.show_prompt(if self.pane == Pane::Hex {
+                    PromptKind::FindHex
+                } else {
+                    PromptKind::FindText
+                });
+            }
+            key!("F3") => {
+                self.find_next();
+                self.update();
+            }
+            key!("Escape") if self.anchor.is_some() => {
+                self.anchor = None;
+            }
+            _ => {
+                if key.modifier.contains_one(KeyModifier::Ctrl | KeyModifier::Alt) || !self.edit(character) {
+                    return EventProcessStatus::Ignored;
+                }
+            }
+        }
+        EventProcessStatus::Processed
+    }
+}
+
+impl OnMouseEvent for HexView {
+    fn on_mouse_event(&mut self, event: &MouseEvent) -> EventProcessStatus {
+        match event {
+            MouseEvent::Enter | MouseEvent::Leave => EventProcessStatus::Processed,
+            MouseEvent::Pressed(data) => {
+                if let Some((offset, pane, high_nibble)) = self.hit_test(data.x, data.y) {
+                    self.pane = pane;
+                    self.move_cursor(offset, false);
+                    self.high_nibble = high_nibble;
+                    self.drag_start = Some(offset);
+                }
+                EventProcessStatus::Processed
+            }
+            MouseEvent::Drag(data) => {
+                if let (Some(start), Some((offset, _, _))) = (self.drag_start, self.hit_test(data.x, data.y)) {
+                    self.anchor = Some(start);
+                    self.cursor = offset;
+                    self.high_nibble = true;
+                    self.update();
+                }
+                EventProcessStatus::Processed
+            }
+            MouseEvent::Released(_) => {
+                self.drag_start = None;
+                EventProcessStatus::Processed
+            }
+            MouseEvent::Wheel(direction) => {
+                match direction {
+                    MouseWheelDirection::Up => self.top = self.top.saturating_sub(WHEEL_ROWS),
+                    MouseWheelDirection::Down => self.top += WHEEL_ROWS,
+                    _ => return EventProcessStatus::Ignored,
+                }
+                let g = self.geometry();
+                self.load_view(&g);
+                EventProcessStatus::Processed
+            }
+            _ => EventProcessStatus::Ignored,
+        }
+    }
+}
+
+impl OnResize for HexView {
+    fn on_resize(&mut self, _old_size: Size, _new_size: Size) {
+        self.update();
+    }
+}
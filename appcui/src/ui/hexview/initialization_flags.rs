@@ -0,0 +1,16 @@
+use EnumBitFlags::EnumBitFlags;
+
+#[EnumBitFlags(bits: 8)]
+pub enum Flags {
+    ReadOnly = 0x01,
+    HideAscii = 0x02,
+}
+
+/// The order in which the bytes of a group are displayed in the hexadecimal pane.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Endianness {
+    /// the bytes of a group are displayed in the order they are stored (the group reads as a big endian value)
+    Big,
+    /// the bytes of a group are displayed in reverse order (the group reads as a little endian value)
+    Little,
+}
@@ -0,0 +1,198 @@
+use std::collections::BTreeMap;
+use std::io::{Read, Seek, SeekFrom, Write};
+
+const PAGE_SIZE: u64 = 4096;
+// maximum number of pages kept in memory (the least recently used page is dropped first)
+const MAX_PAGES: usize = 64;
+const SEARCH_CHUNK: usize = 0x10000;
+
+pub(super) trait ReadSeek: Read + Seek {}
+impl<T: Read + Seek> ReadSeek for T {}
+
+struct Page {
+    index: u64,
+    data: Vec<u8>,
+    last_used: u64,
+}
+
+/// The bytes displayed by a hex view. The bytes are read (in pages) only when they are needed, and
+/// the edited bytes are kept separately (on top of the original content) until they are saved.
+pub(super) struct Source {
+    reader: Option<Box<dyn ReadSeek>>,
+    len: u64,
+    pages: Vec<Page>,
+    tick: u64,
+    changes: BTreeMap<u64, u8>,
+}
+
+impl Source {
+    pub(super) fn empty() -> Self {
+        Self {
+            reader: None,
+            len: 0,
+            pages: Vec::new(),
+            tick: 0,
+            changes: BTreeMap::new(),
+        }
+    }
+
+    pub(super) fn new(mut reader: Box<dyn ReadSeek>) -> std::io::Result<Self> {
+        let len = reader.seek(SeekFrom::End(0))?;
+        Ok(Self {
+            reader: Some(reader),
+            len,
+            pages: Vec::new(),
+            tick: 0,
+            changes: BTreeMap::new(),
+        })
+    }
+
+    #[inline(always)]
+    pub(super) fn len(&self) -> u64 {
+        self.len
+    }
+
+    fn page(&mut self, index: u64) -> Option<&Page> {
+        self.tick += 1;
+        if let Some(pos) = self.pages.iter().position(|p| p.index == index) {
+            self.pages[pos].last_used = self.tick;
+            return Some(&self.pages[pos]);
+        }
+        let reader = self.reader.as_mut()?;
+        let start = index * PAGE_SIZE;
+        let size = PAGE_SIZE.min(self.len.saturating_sub(start)) as usize;
+        let mut data = vec![0u8; size];
+        let mut read = 0;
+        if reader.seek(SeekFrom::Start(start)).is_ok() {
+            while read < size {
+                match reader.read(&mut data[read..]) {
+                    Ok(0) | Err(_) => break,
+                    Ok(n) => read += n,
+                }
+            }
+        }
+        data.truncate(read);
+        if self.pages.len() >= MAX_PAGES {
+            if let Some(pos) = self.pages.iter().enumerate().min_by_key(|(_, p)| p.last_used).map(|(pos, _)| pos) {
+                self.pages.swap_remove(pos);
+            }
+        }
+        self.pages.push(Page {
+            index,
+            data,
+            last_used: self.tick,
+        });
+        self.pages.last()
+    }
+
+    /// Reads the original bytes (without the changes) starting from `offset`. Returns the number of bytes read.
+    fn read_original(&mut self, offset: u64, buf: &mut [u8]) -> usize {
+        let mut count = 0;
+        while count < buf.len() {
+            let pos = offset + count as u64;
+            if pos >= self.len {
+                break;
+            }
+            let Some(page) = self.page(pos / PAGE_SIZE) else {
+                break;
+            };
+            let start = (pos % PAGE_SIZE) as usize;
+            if start >= page.data.len() {
+                break;
+            }
+            let n = (page.data.len() - start).min(buf.len() - count);
+            buf[count..count + n].copy_from_slice(&page.data[start..start + n]);
+            count += n;
+        }
+        count
+    }
+
+    /// Reads the bytes (including the changes) starting from `offset`. Returns the number of bytes read.
+    pub(super) fn read(&mut self, offset: u64, buf: &mut [u8]) -> usize {
+        let count = self.read_original(offset, buf);
+        for (pos, value) in self.changes.range(offset..offset + count as u64) {
+            buf[(pos - offset) as usize] = *value;
+        }
+        count
+    }
+
+    pub(super) fn byte(&mut self, offset: u64) -> Option<u8> {
+        let mut buf = [0u8; 1];
+        (self.read(offset, &mut buf) == 1).then_some(buf[0])
+    }
+
+    /// Changes a byte. If the new value is the same as the original one, the change is removed.
+    pub(super) fn set(&mut self, offset: u64, value: u8) {
+        let mut buf = [0u8; 1];
+        if self.read_original(offset, &mut buf) != 1 {
+            return;
+        }
+        if buf[0] == value {
+            self.changes.remove(&offset);
+        } else {
+            self.changes.insert(offset, value);
+        }
+    }
+
+    #[inline(always)]
+    pub(super) fn is_changed(&self, offset: u64) -> bool {
+        self.changes.contains_key(&offset)
+    }
+
+    #[inline(always)]
+    pub(super) fn changes_count(&self) -> usize {
+        self.changes.len()
+    }
+
+    pub(super) fn discard_changes(&mut self) {
+        self.changes.clear();
+    }
+
+    /// Writes the changed bytes (and only them) in `writer`
+    pub(super) fn write_changes(&self, writer: &mut dyn WriteSeek) -> std::io::Result<()> {
+        let mut iter = self.changes.iter().peekable();
+        while let Some((start, value)) = iter.next() {
+            // consecutive changes are written at once
+            let mut run = vec![*value];
+            while let Some((pos, value)) = iter.peek() {
+                if **pos != start + run.len() as u64 {
+                    break;
+                }
+                run.push(**value);
+                iter.next();
+            }
+            writer.seek(SeekFrom::Start(*start))?;
+            writer.write_all(&run)?;
+        }
+        writer.flush()
+    }
+
+    /// Marks the changes as saved (the cached pages are dropped so that the bytes are read again)
+    pub(super) fn changes_saved(&mut self) {
+        self.changes.clear();
+        self.pages.clear();
+    }
+
+    /// Searches for `pattern` starting from `from` (forward). Returns the offset of the first match.
+    pub(super) fn find(&mut self, pattern: &[u8], from: u64) -> Option<u64> {
+        if pattern.is_empty() {
+            return None;
+        }
+        let mut buf = vec![0u8; SEARCH_CHUNK + pattern.len() - 1];
+        let mut pos = from;
+        while pos < self.len {
+            let n = self.read(pos, &mut buf);
+            if n < pattern.len() {
+                return None;
+            }
+            if let Some(index) = buf[..n].windows(pattern.len()).position(|w| w == pattern) {
+                return Some(pos + index as u64);
+            }
+            pos += SEARCH_CHUNK as u64;
+        }
+        None
+    }
+}
+
+pub(super) trait WriteSeek: Write + Seek {}
+impl<T: Write + Seek> WriteSeek for T {}
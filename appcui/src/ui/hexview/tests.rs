@@ -0,0 +1,246 @@
+use super::source::Source;
+use super::Endianness;
+use crate::prelude::*;
+use std::cell::Cell;
+use std::io::{Cursor, Read, Seek, SeekFrom};
+use std::rc::Rc;
+
+fn hex_window(h: HexView, width: u16, height: u16) -> (crate::testing::TestDriver, Handle<HexView>) {
+    let mut driver = crate::testing::TestDriver::new(App::debug(width + 4, height + 4, "")).unwrap();
+    let mut w = Window::new(
+        "Hex",
+        LayoutBuilder::new().x(0).y(0).width(width + 2).height(height + 2).build(),
+        window::Flags::None,
+    );
+    let handle = w.add(h);
+    driver.add_window(w);
+    driver.focus(handle);
+    driver.settle();
+    (driver, handle)
+}
+
+fn data(len: usize) -> Vec<u8> {
+    (0..len).map(|i| i as u8).collect()
+}
+
+// a reader that counts how many bytes were read from it
+struct CountingReader {
+    inner: Cursor<Vec<u8>>,
+    read: Rc<Cell<usize>>,
+}
+impl Read for CountingReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.read.set(self.read.get() + n);
+        Ok(n)
+    }
+}
+impl Seek for CountingReader {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        self.inner.seek(pos)
+    }
+}
+
+#[test]
+fn check_source_changes_and_search() {
+    let mut s = Source::new(Box::new(Cursor::new(b"Hello world, hello!".to_vec()))).unwrap();
+    assert_eq!(s.len(), 19);
+    assert_eq!(s.find(b"hello", 0), Some(13));
+    assert_eq!(s.find(b"o", 5), Some(7));
+    assert_eq!(s.find(b"xyz", 0), None);
+    s.set(0, b'J');
+    s.set(1, b'e');
+    assert_eq!(s.changes_count(), 1);
+    assert!(s.is_changed(0) && !s.is_changed(1));
+    let mut buf = [0u8; 5];
+    assert_eq!(s.read(0, &mut buf), 5);
+    assert_eq!(&buf, b"Jello");
+    // the search sees the changes
+    assert_eq!(s.find(b"Jello", 0), Some(0));
+
+    let mut output = Cursor::new(b"................".to_vec());
+    s.set(3, b'p');
+    s.write_changes(&mut output).unwrap();
+    assert_eq!(&output.get_ref()[..5], b"J..p.");
+    s.discard_changes();
+    assert_eq!(s.byte(0), Some(b'H'));
+}
+
+#[test]
+fn check_search_across_chunks() {
+    let mut bytes = vec![0u8; 0x10000 + 10];
+    bytes[0xFFFE..0x10002].copy_from_slice(b"MARK");
+    let mut s = Source::new(Box::new(Cursor::new(bytes))).unwrap();
+    assert_eq!(s.find(b"MARK", 0), Some(0xFFFE));
+    assert_eq!(s.find(b"MARK", 0xFFFF), None);
+}
+
+#[test]
+fn check_lazy_reading() {
+    let read = Rc::new(Cell::new(0));
+    let reader = CountingReader {
+        inner: Cursor::new(vec![0x55u8; 8 * 1024 * 1024]),
+        read: read.clone(),
+    };
+    let mut h = HexView::new(layout!("d:f"), hexview::Flags::None);
+    h.set_reader(reader).unwrap();
+    let (mut driver, handle) = hex_window(h, 78, 10);
+    assert_eq!(driver.get(handle).unwrap().len(), 8 * 1024 * 1024);
+    // only the visible page was read
+    assert!(read.get() <= 4096);
+    driver.press(key!("Ctrl+End"));
+    assert_eq!(driver.get(handle).unwrap().cursor(), 8 * 1024 * 1024 - 1);
+    assert!(read.get() <= 3 * 4096);
+    assert!(driver.screen_text().contains("007FFFF0  55 55"));
+}
+
+#[test]
+fn check_layout() {
+    let mut h = HexView::new(layout!("d:f"), hexview::Flags::None);
+    h.set_data(b"ABCDEFGHIJKLMNOPQRSTUVWXYZ\x00\x01".to_vec());
+    let (mut driver, handle) = hex_window(h, 78, 6);
+    let text = driver.screen_text();
+    assert!(text.contains("00000000  41 42 43 44 45 46 47 48 49 4A 4B 4C 4D 4E 4F 50  ABCDEFGHIJKLMNOP"));
+    assert!(text.contains("00000010  51 52 53 54 55 56 57 58 59 5A 00 01"));
+    assert!(text.contains("QRSTUVWXYZ.."));
+
+    // groups of 4 bytes, displayed as little endian values
+    driver.get_mut(handle).unwrap().set_group_size(4);
+    driver.get_mut(handle).unwrap().set_endianness(Endianness::Little);
+    driver.get_mut(handle).unwrap().set_bytes_per_row(8);
+    driver.settle();
+    let text = driver.screen_text();
+    assert!(text.contains("00000000  44434241 48474645  ABCDEFGH"));
+    assert!(text.contains("00000018  01005A59           YZ.."));
+}
+
+#[test]
+fn check_overwrite_editing() {
+    let mut h = HexView::new(layout!("d:f"), hexview::Flags::None);
+    h.set_data(data(32));
+    let (mut driver, handle) = hex_window(h, 78, 6);
+    driver.type_text("ab1");
+    let mut buf = [0u8; 2];
+    driver.get_mut(handle).unwrap().read(0, &mut buf);
+    assert_eq!(buf, [0xAB, 0x11]);
+    assert!(driver.get(handle).unwrap().is_modified());
+    assert_eq!(driver.get(handle).unwrap().cursor(), 1);
+
+    // the ASCII pane
+    driver.press(key!("Tab"));
+    driver.press(key!("Right"));
+    driver.type_text("Hi");
+    assert!(driver.screen_text().contains("00000000  AB 11 48 69 04"));
+    assert_eq!(driver.get(handle).unwrap().cursor(), 4);
+
+    // writing the original value removes the change
+    driver.get_mut(handle).unwrap().set_byte(0, 0);
+    driver.get_mut(handle).unwrap().discard_changes();
+    driver.settle();
+    assert!(!driver.get(handle).unwrap().is_modified());
+    assert!(driver.screen_text().contains("00000000  00 01 02 03"));
+}
+
+#[test]
+fn check_read_only() {
+    let mut h = HexView::new(layout!("d:f"), hexview::Flags::ReadOnly);
+    h.set_data(data(16));
+    let (mut driver, handle) = hex_window(h, 78, 6);
+    driver.type_text("ff");
+    assert!(!driver.get(handle).unwrap().is_modified());
+}
+
+#[test]
+fn check_selection_and_navigation() {
+    let mut h = HexView::new(layout!("d:f"), hexview::Flags::None);
+    h.set_data(data(256));
+    let (mut driver, handle) = hex_window(h, 78, 6);
+    driver.press(key!("Shift+Right"));
+    driver.press(key!("Shift+Right"));
+    driver.press(key!("Shift+Down"));
+    assert_eq!(driver.get(handle).unwrap().selection(), Some(0..19));
+    driver.press(key!("Escape"));
+    assert_eq!(driver.get(handle).unwrap().selection(), None);
+    driver.press(key!("End"));
+    assert_eq!(driver.get(handle).unwrap().cursor(), 31);
+    driver.press(key!("PageDown"));
+    assert_eq!(driver.get(handle).unwrap().cursor(), 31 + 6 * 16);
+    let text = driver.screen_text();
+    assert!(!text.contains("00000000  00"));
+    assert!(text.contains("00000070  70 71"));
+    driver.press(key!("Ctrl+Home"));
+    assert!(driver.screen_text().contains("00000000  00"));
+    driver.press(key!("Ctrl+A"));
+    assert_eq!(driver.get(handle).unwrap().selection(), Some(0..256));
+}
+
+#[test]
+fn check_go_to_and_find() {
+    let mut bytes = data(200);
+    bytes[150..155].copy_from_slice(b"hello");
+    bytes[170..175].copy_from_slice(b"hello");
+    let mut h = HexView::new(layout!("d:f"), hexview::Flags::None);
+    h.set_data(bytes);
+    let (mut driver, handle) = hex_window(h, 78, 6);
+
+    driver.press(key!("Ctrl+G"));
+    driver.type_text("0x7B");
+    assert!(driver.screen_text().contains("Go to offset: 0x7B"));
+    driver.press(key!("Enter"));
+    assert_eq!(driver.get(handle).unwrap().cursor(), 0x7B);
+    driver.press(key!("Ctrl+G"));
+    driver.type_text("FFFF");
+    driver.press(key!("Enter"));
+    assert!(driver.screen_text().contains("Invalid offset"));
+    assert_eq!(driver.get(handle).unwrap().cursor(), 0x7B);
+
+    // in the hex pane the pattern is a sequence of bytes
+    driver.press(key!("Ctrl+F"));
+    driver.type_text("68 65 6C");
+    driver.press(key!("Enter"));
+    assert_eq!(driver.get(handle).unwrap().selection(), Some(150..153));
+    driver.press(key!("F3"));
+    assert_eq!(driver.get(handle).unwrap().selection(), Some(170..173));
+    // the search wraps around
+    driver.press(key!("F3"));
+    assert_eq!(driver.get(handle).unwrap().selection(), Some(150..153));
+
+    // in the ASCII pane the pattern is a text
+    driver.press(key!("Tab"));
+    driver.press(key!("Ctrl+F"));
+    driver.type_text("llo");
+    driver.press(key!("Enter"));
+    assert_eq!(driver.get(handle).unwrap().selection(), Some(172..175));
+    assert!(!driver.get_mut(handle).unwrap().find_text("missing"));
+}
+
+#[test]
+fn check_mouse() {
+    let mut h = HexView::new(layout!("d:f"), hexview::Flags::None);
+    h.set_data(data(64));
+    let (mut driver, handle) = hex_window(h, 78, 6);
+    let b = driver.bounds(handle).unwrap();
+    // the 4th byte of the second row (hex pane starts at column 10)
+    driver.click_at(b.left() + 10 + 3 * 3, b.top() + 1);
+    assert_eq!(driver.get(handle).unwrap().cursor(), 19);
+    // drag in the ASCII pane (starts at column 59)
+    driver.drag(b.left() + 59, b.top(), b.left() + 62, b.top() + 2);
+    assert_eq!(driver.get(handle).unwrap().selection(), Some(0..36));
+}
+
+#[test]
+fn check_save() {
+    let path = std::env::temp_dir().join(format!("appcui_hexview_{}.bin", std::process::id()));
+    std::fs::write(&path, b"0123456789").unwrap();
+    let mut h = HexView::new(layout!("d:f"), hexview::Flags::None);
+    h.open(&path).unwrap();
+    let (mut driver, handle) = hex_window(h, 78, 6);
+    driver.press(key!("Tab"));
+    driver.press(key!("Right"));
+    driver.type_text("AB");
+    driver.get_mut(handle).unwrap().save().unwrap();
+    assert!(!driver.get(handle).unwrap().is_modified());
+    assert_eq!(std::fs::read(&path).unwrap(), b"0AB3456789");
+    let _ = std::fs::remove_file(&path);
+    assert!(HexView::new(layout!("d:f"), hexview::Flags::None).open(&path).is_err());
+}
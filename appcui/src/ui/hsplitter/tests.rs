@@ -232,4 +232,18 @@ fn check_resize_preserve_top_size() {
     w.add(hsplitter!("25%,d:f,rb:PreserveTopPanelSize"));
     a.add_window(w);
     a.run();
-}
\ No newline at end of file
+}
+#[test]
+fn check_auto_min_height() {
+    let mut driver = crate::testing::TestDriver::new(App::debug(60, 20, "")).unwrap();
+    let mut w = window!("Test,a:c,w:50,h:20");
+    let mut hs = HSplitter::new(8, layout!("d:f"), hsplitter::ResizeBehavior::PreserveAspectRatio);
+    hs.set_min_height(hsplitter::Panel::Top, 5);
+    hs.set_min_height(hsplitter::Panel::Top, layout::Dimension::Auto);
+    let hs = w.add(hs);
+    driver.add_window(w);
+    driver.settle();
+    // Dimension::Auto means no minimum height
+    driver.press_on(hs, key!("Ctrl+Alt+Shift+Up"));
+    assert_eq!(driver.get(hs).unwrap().position(), 0);
+}
@@ -1,6 +1,6 @@
 use crate::prelude::*;
 
-#[CustomControl(overwrite=OnPaint, internal=true)]
+#[CustomControl(overwrite=OnPaint+OnMeasure, internal=true)]
 pub struct Label {
     caption: Caption,
 }
@@ -53,3 +53,8 @@ impl OnPaint for Label {
         surface.write_text(self.caption.text(), &format);
     }
 }
+impl OnMeasure for Label {
+    fn preferred_size(&self, _available: Size) -> Option<Size> {
+        Some(Size::new(self.caption.chars_count() as u32, 1))
+    }
+}
@@ -26,6 +26,8 @@ mod left_bottom_right_anchors_layout;
 mod top_left_bottom_anchors_layout;
 mod top_right_bottom_anchors_layout;
 mod all_anchors_layout;
+mod grid_cell;
+mod breakpoint;
 #[cfg(test)]
 mod tests;
 
@@ -49,8 +51,10 @@ pub use dimension::Dimension;
 pub use dock::Dock;
 pub use pivot::Pivot;
 pub use alignment::Alignment;
+pub use breakpoint::Breakpoint;
 pub (in crate) use dimension16::Dimension16;
 pub (in crate) use coordinate16::Coordinate16;
 pub (in crate) use control_layout::ControlLayout;
+pub (in crate) use grid_cell::GridCell;
 
 
@@ -0,0 +1,56 @@
+use crate::graphics::Size;
+
+/// A condition on the size of the terminal, used to select an alternative layout for a control
+/// (see `ControlBase::add_responsive_layout`) or to collapse an optional control (see `ControlBase::set_collapse_below`).
+///
+/// A breakpoint matches a terminal that is at least `min_width` characters wide and at least `min_height`
+/// characters high (a value of 0 means that the corresponding dimension is not checked).
+///
+/// # Example
+/// ```rust
+/// use appcui::prelude::*;
+///
+/// let wide = Breakpoint::with_width(120);
+/// assert!(wide.matches(Size::new(200, 50)));
+/// assert!(!wide.matches(Size::new(80, 24)));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub struct Breakpoint {
+    min_width: u16,
+    min_height: u16,
+}
+
+impl Breakpoint {
+    /// Creates a breakpoint that matches a terminal of at least `min_width` x `min_height` characters.
+    pub const fn new(min_width: u16, min_height: u16) -> Self {
+        Self { min_width, min_height }
+    }
+
+    /// Creates a breakpoint that matches a terminal that is at least `min_width` characters wide (regardless of its height).
+    pub const fn with_width(min_width: u16) -> Self {
+        Self { min_width, min_height: 0 }
+    }
+
+    /// Creates a breakpoint that matches a terminal that is at least `min_height` characters high (regardless of its width).
+    pub const fn with_height(min_height: u16) -> Self {
+        Self { min_width: 0, min_height }
+    }
+
+    /// Returns the minimum width of the terminal (0 if the width is not checked).
+    #[inline(always)]
+    pub fn min_width(&self) -> u16 {
+        self.min_width
+    }
+
+    /// Returns the minimum height of the terminal (0 if the height is not checked).
+    #[inline(always)]
+    pub fn min_height(&self) -> u16 {
+        self.min_height
+    }
+
+    /// Returns `true` if a terminal of the given size matches the breakpoint.
+    #[inline(always)]
+    pub fn matches(&self, size: Size) -> bool {
+        (size.width >= self.min_width as u32) && (size.height >= self.min_height as u32)
+    }
+}
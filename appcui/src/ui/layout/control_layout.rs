@@ -1,7 +1,7 @@
-use super::{AbsoluteLayout, LayoutMode, Layout};
-use crate::graphics::Size;
+use super::{AbsoluteLayout, GridCell, LayoutMode, Layout};
+use crate::graphics::{Point, Size};
 
-#[derive(Default)]
+#[derive(Default, Copy, Clone)]
 pub(crate) struct ControlLayout {
     mode: LayoutMode,
     x: i32,
@@ -12,6 +12,9 @@ pub(crate) struct ControlLayout {
     max_width: u16,
     min_height: u16,
     max_height: u16,
+    cell: Option<GridCell>,
+    content_width: u16,
+    content_height: u16,
 }
 
 impl ControlLayout {
@@ -94,6 +97,69 @@ impl ControlLayout {
             }
         }
     }
+    // the size the control would have (based on its layout rules) inside a parent of the given size
+    pub(crate) fn preferred_size(&self, parent_width: u16, parent_height: u16) -> Size {
+        let mut layout = *self;
+        layout.update(parent_width, parent_height);
+        layout.size()
+    }
+    // the position and size the control would have (based on its layout rules) inside a parent of the given size
+    pub(crate) fn preferred_bounds(&self, parent_width: u16, parent_height: u16) -> (Point, Size) {
+        let mut layout = *self;
+        layout.update(parent_width, parent_height);
+        (Point::new(layout.x, layout.y), layout.size())
+    }
+    // the position and size of the control inside a parent that has no size
+    // (dimensions that depend on the parent are replaced with the size of the content, if known)
+    fn measure(&self, content: Option<Size>) -> (Point, Size) {
+        let mut layout = *self;
+        if let Some(sz) = content {
+            layout.set_content_size(sz);
+        }
+        layout.update(0, 0);
+        let (fixed_width, fixed_height) = self.mode.fixed_size();
+        let content = content.unwrap_or_default();
+        let mut width = layout.width as u32;
+        let mut height = layout.height as u32;
+        if !fixed_width {
+            width = width.max(content.width).clamp(self.min_width as u32, self.max_width as u32);
+        }
+        if !fixed_height {
+            height = height.max(content.height).clamp(self.min_height as u32, self.max_height as u32);
+        }
+        (Point::new(layout.x, layout.y), Size::new(width, height))
+    }
+    // the size of the control when its parent does not constrain it
+    #[inline(always)]
+    pub(crate) fn measured_size(&self, content: Option<Size>) -> Size {
+        self.measure(content).1
+    }
+    // the space (position + size) required by the control inside its parent
+    pub(crate) fn measured_extent(&self, content: Option<Size>) -> Size {
+        let (pos, size) = self.measure(content);
+        Size::new(pos.x.max(0) as u32 + size.width, pos.y.max(0) as u32 + size.height)
+    }
+    #[inline(always)]
+    pub(crate) fn has_auto_size(&self) -> bool {
+        self.mode.has_auto_size()
+    }
+    #[inline(always)]
+    pub(crate) fn set_content_size(&mut self, size: Size) {
+        self.content_width = size.width.min(u16::MAX as u32) as u16;
+        self.content_height = size.height.min(u16::MAX as u32) as u16;
+    }
+    #[inline(always)]
+    pub(super) fn content_width(&self) -> u16 {
+        self.content_width
+    }
+    #[inline(always)]
+    pub(super) fn content_height(&self) -> u16 {
+        self.content_height
+    }
+    #[inline(always)]
+    pub(crate) fn cell(&self) -> Option<GridCell> {
+        self.cell
+    }
     pub(crate) fn layout_resize(&mut self, width: u16, height: u16) {
         match &mut self.mode {
             LayoutMode::Absolute(layout) => {
@@ -105,6 +171,11 @@ impl ControlLayout {
             }
         }
     }
+    // uses the layout rules (mode and cell) of another layout, but keeps the current size bounds
+    pub(crate) fn set_rules_from(&mut self, other: &ControlLayout) {
+        self.mode = other.mode;
+        self.cell = other.cell;
+    }
     pub(crate) fn layout_set_position(&mut self, x: i32, y: i32) {
         match &mut self.mode {
             LayoutMode::Absolute(layout) => {
@@ -120,6 +191,7 @@ impl ControlLayout {
 
 impl From<Layout> for ControlLayout {
     fn from(value: Layout) -> Self {
+        let cell = value.cell;
         Self {
             mode: match LayoutMode::new(value) {
                 Ok(mode) => mode,
@@ -133,6 +205,9 @@ impl From<Layout> for ControlLayout {
             min_height: 1,
             max_width: u16::MAX,
             max_height: u16::MAX,
+            cell,
+            content_width: 0,
+            content_height: 0,
         }
     }
 }   
\ No newline at end of file
@@ -1,19 +1,28 @@
+/// The width or the height of a control: an absolute value, a percentage of the size of the parent or
+/// `Auto` (the size is computed from the content of the control).
+///
+/// New kinds of dimensions may be added in the future, so a `match` over a `Dimension` (outside of
+/// AppCUI) must include a wildcard arm.
 #[derive(Copy, Clone, PartialEq, Debug)]
+#[non_exhaustive]
 pub enum Dimension {
     Absolute(u32),
     Percentage(f32),
+    Auto,
 }
 impl Dimension {
     pub fn is_absolute(&self) -> bool {
         match self {
             Dimension::Absolute(_) => true,
             Dimension::Percentage(_) => false,
+            Dimension::Auto => false,
         }
     }
     pub fn absolute(&self, parent_size: u16) -> u16 {
         match self {
             Dimension::Absolute(v) => (*v) as u16,
             Dimension::Percentage(v) =>((parent_size as f32) * v) as u16,
+            Dimension::Auto => 0,
         }
     }
 }
@@ -1,3 +1,5 @@
+use super::Dimension;
+
 
 const MAX_DIMENSION: i32 = 30000;
 const MIN_DIMENSION: i32 = 0;
@@ -6,18 +8,42 @@ const MIN_DIMENSION: i32 = 0;
 pub enum Dimension16 {
     Absolute(u16),
     Percentage(u16),
+    Auto,
 }
 impl Dimension16 {
     pub fn is_absolute(&self) -> bool {
         match self {
             Dimension16::Absolute(_) => true,
             Dimension16::Percentage(_) => false,
+            Dimension16::Auto => false,
         }
     }
+    #[inline(always)]
+    pub fn is_auto(&self) -> bool {
+        matches!(self, Dimension16::Auto)
+    }
     pub fn absolute(&self, parent_size: u16) -> u16 {
         match self {
             Dimension16::Absolute(v) => *v,
             Dimension16::Percentage(v) => (((*v) as u32) * (parent_size as u32) / 10000u32).clamp(0, 0xFFFF) as u16,
+            Dimension16::Auto => 0,
+        }
+    }
+    // the size of a control: an `Auto` dimension uses the size of the content of the control
+    #[inline(always)]
+    pub fn size(&self, parent_size: u16, content_size: u16) -> u16 {
+        match self {
+            Dimension16::Auto => content_size,
+            _ => self.absolute(parent_size),
+        }
+    }
+}
+impl From<Dimension> for Dimension16 {
+    fn from(value: Dimension) -> Self {
+        match value {
+            Dimension::Absolute(v) => Dimension16::from(v),
+            Dimension::Percentage(v) => Dimension16::from(v),
+            Dimension::Auto => Dimension16::Auto,
         }
     }
 }
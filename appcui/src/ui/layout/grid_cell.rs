@@ -0,0 +1,19 @@
+// the cell (and span) of a control that is placed inside a Grid container
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub(crate) struct GridCell {
+    pub(crate) column: u16,
+    pub(crate) row: u16,
+    pub(crate) column_span: u16,
+    pub(crate) row_span: u16,
+}
+
+impl Default for GridCell {
+    fn default() -> Self {
+        Self {
+            column: 0,
+            row: 0,
+            column_span: 1,
+            row_span: 1,
+        }
+    }
+}
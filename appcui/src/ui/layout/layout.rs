@@ -5,6 +5,7 @@ use super::Alignment;
 use super::Coordinate16;
 use super::Dimension16;
 use super::Dock;
+use super::GridCell;
 use super::Pivot;
 
 /// Represents a new layout instance with the specified format string.
@@ -38,6 +39,7 @@ pub struct Layout {
     pub(super) align: Option<Alignment>,
     pub(super) pivot: Option<Pivot>,
     pub(super) dock: Option<Dock>,
+    pub(super) cell: Option<GridCell>,
 }
 
 impl Layout {
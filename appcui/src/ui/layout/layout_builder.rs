@@ -5,6 +5,7 @@ use super::Error;
 use super::Layout;
 use super::LayoutMode;
 use super::Pivot;
+use super::GridCell;
 use crate::ui::Dock;
 
 pub struct LayoutBuilder {
@@ -28,6 +29,7 @@ impl LayoutBuilder {
                 align: None,
                 pivot: None,
                 dock: None,
+                cell: None,
             },
         }
     }
@@ -173,8 +175,8 @@ impl LayoutBuilder {
     /// * `width` - The control's width (absolute or relative).
     ///
     /// # Type Constraints
-    /// * `T` must implement `Into<Dimension16>`, allowing unsigned integers for absolute sizes
-    ///   or `f32` values between `0.0` and `1.0` for percentages.
+    /// * `T` must implement `Into<Dimension16>`, allowing unsigned integers for absolute sizes,
+    ///   `f32` values between `0.0` and `1.0` for percentages or `Dimension::Auto` for the size of the content of the control.
     ///
     /// # Examples
     /// ```rust
@@ -195,6 +197,13 @@ impl LayoutBuilder {
     ///     .width(0.5)   // 50% width
     ///     .height(10)
     ///     .build();
+    ///
+    /// // Width computed from the content of the control (e.g. the text of a label)
+    /// let layout_auto = LayoutBuilder::new()
+    ///     .alignment(Alignment::Center)
+    ///     .width(layout::Dimension::Auto)
+    ///     .height(1)
+    ///     .build();
     /// ```
     pub fn width<T>(mut self, width: T) -> Self
     where
@@ -227,8 +236,8 @@ impl LayoutBuilder {
     /// * `height` - The control's height (absolute or relative).
     ///
     /// # Type Constraints
-    /// * `T` must implement `Into<Dimension16>`, allowing unsigned integers for absolute sizes
-    ///   or `f32` values between `0.0` and `1.0` for percentages.
+    /// * `T` must implement `Into<Dimension16>`, allowing unsigned integers for absolute sizes,
+    ///   `f32` values between `0.0` and `1.0` for percentages or `Dimension::Auto` for the size of the content of the control.
     ///
     /// # Examples
     /// ```rust
@@ -666,6 +675,77 @@ impl LayoutBuilder {
         self
     }
 
+    /// Sets the row (0-based index) of the cell where the control will be placed when it is added to a [`Grid`](crate::ui::Grid).
+    ///
+    /// The rest of the layout (if any) is computed relative to the cell, meaning that a control
+    /// that only specifies its cell (`row`, `column` and optionally the spans) fills the entire cell.
+    /// Outside of a grid these values are ignored and such a control fills its parent.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use appcui::prelude::*;
+    ///
+    /// // fills the cell from the second row and third column
+    /// let layout = LayoutBuilder::new().row(1).column(2).build();
+    ///
+    /// // centered within the first cell of the grid (10 characters wide)
+    /// let layout = LayoutBuilder::new()
+    ///     .row(0)
+    ///     .column(0)
+    ///     .alignment(Alignment::Center)
+    ///     .width(10)
+    ///     .height(1)
+    ///     .build();
+    /// ```
+    pub fn row(mut self, row: u16) -> Self {
+        self.inner_layout.cell.get_or_insert_with(GridCell::default).row = row;
+        self
+    }
+
+    /// Sets the column (0-based index) of the cell where the control will be placed when it is added to a [`Grid`](crate::ui::Grid).
+    /// See [`LayoutBuilder::row`] for more details.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use appcui::prelude::*;
+    ///
+    /// let layout = LayoutBuilder::new().row(0).column(3).build();
+    /// ```
+    pub fn column(mut self, column: u16) -> Self {
+        self.inner_layout.cell.get_or_insert_with(GridCell::default).column = column;
+        self
+    }
+
+    /// Sets the number of rows occupied by the control when it is added to a [`Grid`](crate::ui::Grid) (default is 1).
+    /// A value of 0 is treated as 1.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use appcui::prelude::*;
+    ///
+    /// // occupies the first column from the first three rows
+    /// let layout = LayoutBuilder::new().row(0).column(0).row_span(3).build();
+    /// ```
+    pub fn row_span(mut self, span: u16) -> Self {
+        self.inner_layout.cell.get_or_insert_with(GridCell::default).row_span = span.max(1);
+        self
+    }
+
+    /// Sets the number of columns occupied by the control when it is added to a [`Grid`](crate::ui::Grid) (default is 1).
+    /// A value of 0 is treated as 1.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use appcui::prelude::*;
+    ///
+    /// // occupies the entire second row of a grid with 4 columns
+    /// let layout = LayoutBuilder::new().row(1).column(0).column_span(4).build();
+    /// ```
+    pub fn column_span(mut self, span: u16) -> Self {
+        self.inner_layout.cell.get_or_insert_with(GridCell::default).column_span = span.max(1);
+        self
+    }
+
     /// Finalizes the layout configuration and returns a [`Layout`] instance.
     ///
     /// This method collects all the parameters set using the builder methods (such as
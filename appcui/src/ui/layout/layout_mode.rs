@@ -2,6 +2,7 @@ use super::AbsoluteLayout;
 use super::Alignment;
 use super::AllAnchorsLayout;
 use super::Anchors;
+use super::Dimension16;
 use super::Error;
 use super::Layout;
 use super::LeftBottomRightAnchorsLayout;
@@ -40,6 +41,10 @@ impl LayoutMode {
             && layout.dock.is_none()
             && anchors == Anchors::None
         {
+            // a control that only specifies its grid cell fills that cell
+            if layout.cell.is_some() {
+                return Ok(LayoutMode::PointAndSize(PointAndSizeLayout::new_fill()));
+            }
             return Err(Error::NoParameters);
         }
         // Step 1 ==> if dock option is present
@@ -114,6 +119,30 @@ impl LayoutMode {
         }
         Err(Error::InvalidLayoutRule)
     }
+    // (width, height) dimensions of the layout - `None` if the dimension is computed from anchors
+    fn dimensions(&self) -> (Option<Dimension16>, Option<Dimension16>) {
+        match self {
+            LayoutMode::Absolute(l) => (Some(Dimension16::Absolute(l.width)), Some(Dimension16::Absolute(l.height))),
+            LayoutMode::PointAndSize(l) => (Some(l.width), Some(l.height)),
+            LayoutMode::LeftRightAnchors(l) => (None, Some(l.height)),
+            LayoutMode::LeftTopRightAnchors(l) => (None, Some(l.height)),
+            LayoutMode::LeftBottomRightAnchors(l) => (None, Some(l.height)),
+            LayoutMode::TopBottomAnchors(l) => (Some(l.width), None),
+            LayoutMode::TopLeftBottomAnchors(l) => (Some(l.width), None),
+            LayoutMode::TopRightBottomAnchors(l) => (Some(l.width), None),
+            LayoutMode::AllAnchors(_) => (None, None),
+        }
+    }
+    // true if the width or the height of the control is computed from its content
+    pub(super) fn has_auto_size(&self) -> bool {
+        let (w, h) = self.dimensions();
+        w.is_some_and(|d| d.is_auto()) || h.is_some_and(|d| d.is_auto())
+    }
+    // (width, height) flags - true if that dimension does not depend on the parent or on the content of the control
+    pub(super) fn fixed_size(&self) -> (bool, bool) {
+        let (w, h) = self.dimensions();
+        (w.is_some_and(|d| d.is_absolute()), h.is_some_and(|d| d.is_absolute()))
+    }
 }
 
 impl Default for LayoutMode {
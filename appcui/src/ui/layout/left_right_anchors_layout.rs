@@ -37,7 +37,7 @@ impl LeftRightAnchorsLayout {
         let y = self.y.absolute(parent_height);
         control_layout.resize(
             ((parent_width as i32) - (left + right)).clamp(1, 0xFFFF) as u16,
-            self.height.absolute(parent_height),
+            self.height.size(parent_height, control_layout.content_height()),
         );
         let new_h = control_layout.height() as i32;
         let new_w = control_layout.width() as i32;
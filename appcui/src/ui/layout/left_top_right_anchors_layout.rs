@@ -34,7 +34,7 @@ impl LeftTopRightAnchorsLayout {
         let top = self.top.absolute(parent_height);
         control_layout.resize(
             ((parent_width as i32) - (left + right)).clamp(1, 0xFFFF) as u16,
-            self.height.absolute(parent_height),
+            self.height.size(parent_height, control_layout.content_height()),
         );
         control_layout.set_position(left, top);
     }
@@ -77,18 +77,23 @@ impl PointAndSizeLayout {
             Dock::Fill => {
                 should_not_use!(params.width, Error::WidthOrHeightParameterUsedWithDockFill);
                 should_not_use!(params.height, Error::WidthOrHeightParameterUsedWithDockFill);
-                Ok(PointAndSizeLayout {
-                    x: Coordinate16::Absolute(0),
-                    y: Coordinate16::Absolute(0),
-                    width: Dimension16::Percentage(10000),
-                    height: Dimension16::Percentage(10000),
-                    align: Alignment::TopLeft,
-                    anchor: Alignment::TopLeft,
-                })
+                Ok(PointAndSizeLayout::new_fill())
             }
         }
     }
 
+    #[inline]
+    pub(super) fn new_fill() -> Self {
+        PointAndSizeLayout {
+            x: Coordinate16::Absolute(0),
+            y: Coordinate16::Absolute(0),
+            width: Dimension16::Percentage(10000),
+            height: Dimension16::Percentage(10000),
+            align: Alignment::TopLeft,
+            anchor: Alignment::TopLeft,
+        }
+    }
+
     pub(super) fn new_aligned(params: &Layout) -> Result<Self, Error> {
         should_not_use!(params.x, Error::XYParameterUsedWithAlign);
         should_not_use!(params.y, Error::XYParameterUsedWithAlign);
@@ -164,7 +169,10 @@ impl PointAndSizeLayout {
 
     #[inline]
     pub(super) fn update_control_layout(&self, control_layout: &mut ControlLayout, parent_width: u16, parent_height: u16) {
-        control_layout.resize(self.width.absolute(parent_width), self.height.absolute(parent_height));
+        control_layout.resize(
+            self.width.size(parent_width, control_layout.content_width()),
+            self.height.size(parent_height, control_layout.content_height()),
+        );
         let mut x = self.x.absolute(parent_width);
         let mut y = self.y.absolute(parent_height);
 
@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use crate::testing::TestDriver;
 use crate::ui::layout::absolute_layout::AbsoluteLayout;
 
 use super::Alignment;
@@ -1803,4 +1804,122 @@ fn check_layout_builder_errors_2() {
     // pivot without x and y
     assert_eq!(LayoutBuilder::new().pivot(Pivot::Center).try_build().err().unwrap(),Error::PivotWithoutXorY);
 
-}
\ No newline at end of file
+}
+#[test]
+fn check_auto_dimension() {
+    let l = LayoutBuilder::new().alignment(Alignment::Center).width(Dimension::Auto).height(1).build();
+    assert!(matches!(LayoutMode::new(l.clone()).unwrap(), LayoutMode::PointAndSize(p) if p.width == Dimension16::Auto));
+    let mut cl = ControlLayout::from(l);
+    assert!(cl.has_auto_size());
+    // without a content size, the control has the minimum width
+    cl.update(40, 10);
+    assert_eq!((cl.x(), cl.y(), cl.width(), cl.height()), (20, 5, 1, 1));
+    cl.set_content_size(Size::new(12, 3));
+    cl.update(40, 10);
+    assert_eq!((cl.x(), cl.y(), cl.width(), cl.height()), (14, 5, 12, 1));
+    // the extent of a control is computed from its content (only for the dimensions that are not absolute)
+    assert_eq!(cl.measured_size(Some(Size::new(7, 4))), Size::new(7, 1));
+    let cl = ControlLayout::from(LayoutBuilder::new().x(2).y(1).width(Dimension::Auto).height(Dimension::Auto).build());
+    assert_eq!(cl.measured_extent(Some(Size::new(5, 3))), Size::new(7, 4));
+    // anchors with an auto size
+    let mut cl = ControlLayout::from(LayoutBuilder::new().left_anchor(1).right_anchor(1).y(0).pivot(Pivot::TopLeft).height(Dimension::Auto).build());
+    cl.set_content_size(Size::new(3, 4));
+    cl.update(20, 10);
+    assert_eq!((cl.x(), cl.y(), cl.width(), cl.height()), (1, 0, 18, 4));
+}
+
+#[test]
+fn check_window_fit_to_content() {
+    let mut driver = TestDriver::new(App::debug(60, 20, "")).unwrap();
+    let mut w = Window::new(
+        "Test",
+        LayoutBuilder::new().alignment(Alignment::Center).width(Dimension::Auto).height(Dimension::Auto).build(),
+        window::Flags::None,
+    );
+    let l = w.add(Label::new("Hello world", LayoutBuilder::new().x(1).y(1).width(Dimension::Auto).height(1).build()));
+    let b = w.add(Button::new("OK", LayoutBuilder::new().x(1).y(3).width(Dimension::Auto).build(), button::Type::Normal));
+    let w = driver.add_window(w);
+    driver.settle();
+    // the window (14x7) fits the label (11x1 at 1,1) and the button (5x2 at 1,3) plus its borders
+    assert_eq!(driver.bounds(w), Some(Rect::new(23, 7, 36, 13)));
+    assert_eq!(driver.bounds(l), Some(Rect::new(25, 9, 35, 9)));
+    assert_eq!(driver.bounds(b), Some(Rect::new(25, 11, 29, 12)));
+}
+
+#[test]
+fn check_breakpoint_matches() {
+    let b = Breakpoint::new(80, 25);
+    assert!(b.matches(Size::new(80, 25)));
+    assert!(b.matches(Size::new(200, 60)));
+    assert!(!b.matches(Size::new(79, 60)));
+    assert!(!b.matches(Size::new(200, 24)));
+    assert!(Breakpoint::with_width(100).matches(Size::new(100, 1)));
+    assert!(Breakpoint::with_height(30).matches(Size::new(1, 30)));
+    assert!(Breakpoint::default().matches(Size::new(0, 0)));
+}
+
+#[test]
+fn check_responsive_layout() {
+    let mut driver = TestDriver::new(App::debug(60, 20, "")).unwrap();
+    let mut w = Window::new("Test", layout!("x:0,y:0,w:40,h:12"), window::Flags::None);
+    w.add_responsive_layout(Breakpoint::with_width(80), layout!("x:0,y:0,w:70,h:12"));
+    w.add_responsive_layout(Breakpoint::new(120, 40), layout!("x:0,y:0,w:100,h:30"));
+    let l = w.add(Label::new("optional", layout!("x:1,y:1,w:10,h:1")));
+    let w = driver.add_window(w);
+    driver.get_mut(l).unwrap().set_collapse_below(Breakpoint::with_width(80));
+    driver.settle();
+    assert_eq!(driver.bounds(w), Some(Rect::new(0, 0, 39, 11)));
+    assert!(driver.get(l).unwrap().is_collapsed());
+    assert_eq!(driver.bounds(l), None);
+
+    // a wide terminal --> the first alternative layout is used and the label is shown
+    driver.resize(100, 30);
+    assert_eq!(driver.bounds(w), Some(Rect::new(0, 0, 69, 11)));
+    assert!(!driver.get(l).unwrap().is_collapsed());
+    assert_eq!(driver.bounds(l), Some(Rect::new(2, 2, 11, 2)));
+
+    // both breakpoints match --> the one with the biggest width is used
+    driver.resize(150, 50);
+    assert_eq!(driver.bounds(w), Some(Rect::new(0, 0, 99, 29)));
+
+    // back to the original layout
+    driver.resize(60, 20);
+    assert_eq!(driver.bounds(w), Some(Rect::new(0, 0, 39, 11)));
+    assert_eq!(driver.bounds(l), None);
+
+    // without responsive layouts the control keeps its layout and it is no longer collapsed
+    driver.get_mut(l).unwrap().clear_responsive_layouts();
+    driver.settle();
+    assert_eq!(driver.bounds(l), Some(Rect::new(2, 2, 11, 2)));
+}
+
+#[test]
+fn check_responsive_layout_in_containers() {
+    let mut driver = TestDriver::new(App::debug(60, 20, "")).unwrap();
+    // window client area: (1,1) - 38x10
+    let mut w = Window::new("Test", layout!("x:0,y:0,w:40,h:12"), window::Flags::None);
+    let mut sp = StackPanel::new(layout!("d:f"), stackpanel::Orientation::Vertical);
+    let a = sp.add(Label::new("a", layout!("a:tl,w:10,h:1")));
+    let mut optional = Label::new("b", layout!("a:tl,w:10,h:1"));
+    optional.set_collapse_below(Breakpoint::with_width(80));
+    let b = sp.add(optional);
+    let mut resizable = Label::new("c", layout!("a:tl,w:10,h:1"));
+    resizable.add_responsive_layout(Breakpoint::with_width(80), layout!("a:tl,w:10,h:3"));
+    let c = sp.add(resizable);
+    w.add(sp);
+    driver.add_window(w);
+    driver.settle();
+    // the second label is collapsed --> the third one takes its place
+    assert_eq!(driver.bounds(a), Some(Rect::new(1, 1, 38, 1)));
+    assert_eq!(driver.bounds(b), None);
+    assert_eq!(driver.bounds(c), Some(Rect::new(1, 2, 38, 2)));
+
+    driver.resize(100, 30);
+    assert_eq!(driver.bounds(a), Some(Rect::new(1, 1, 38, 1)));
+    assert_eq!(driver.bounds(b), Some(Rect::new(1, 2, 38, 2)));
+    assert_eq!(driver.bounds(c), Some(Rect::new(1, 3, 38, 5)));
+
+    driver.resize(60, 20);
+    assert_eq!(driver.bounds(b), None);
+    assert_eq!(driver.bounds(c), Some(Rect::new(1, 2, 38, 2)));
+}
@@ -36,7 +36,7 @@ impl TopBottomAnchorsLayout {
         let bottom = self.bottom.absolute(parent_height);
         let x = self.x.absolute(parent_width);
         control_layout.resize(
-            self.width.absolute(parent_width),
+            self.width.size(parent_width, control_layout.content_width()),
             ((parent_height as i32) - (top + bottom)).clamp(1, 0xFFFF) as u16,
         );
         let new_h = control_layout.height() as i32;
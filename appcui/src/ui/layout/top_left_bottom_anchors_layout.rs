@@ -33,7 +33,7 @@ impl TopLeftBottomAnchorsLayout {
         let left = self.left.absolute(parent_width);
         let bottom = self.bottom.absolute(parent_height);
         control_layout.resize(
-            self.width.absolute(parent_width),
+            self.width.size(parent_width, control_layout.content_width()),
             ((parent_height as i32) - (top + bottom)).clamp(1, 0xFFFF) as u16,
         );
         control_layout.set_position(left, top);
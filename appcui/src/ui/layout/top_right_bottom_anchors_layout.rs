@@ -33,7 +33,7 @@ impl TopRightBottomAnchorsLayout {
         let right = self.right.absolute(parent_width);
         let bottom = self.bottom.absolute(parent_height);
         control_layout.resize(
-            self.width.absolute(parent_width),
+            self.width.size(parent_width, control_layout.content_width()),
             ((parent_height as i32) - (top + bottom)).clamp(1, 0xFFFF) as u16,
         );
         control_layout.set_position((parent_width as i32) - (right + (control_layout.width() as i32)), top);
@@ -5,7 +5,7 @@ use crate::ui::components::ListScrollBars;
 use listbox::events::ListBoxEventTypes;
 use appcui_proc_macro::*;
 
-#[CustomControl(overwrite = OnPaint+OnKeyPressed+OnMouseEvent+OnResize, internal = true)]
+#[CustomControl(overwrite = OnPaint+OnKeyPressed+OnMouseEvent+OnResize+OnMeasure, internal = true)]
 pub struct ListBox {
     items: Vec<Item>,
     flags: Flags,
@@ -417,6 +417,28 @@ impl OnPaint for ListBox {
     }
 }
 
+impl OnMeasure for ListBox {
+    fn preferred_size(&self, _available: Size) -> Option<Size> {
+        // the longest item (or the empty message) and one line for every item
+        let mut width = if self.items.is_empty() {
+            self.empty_message.chars().count() as u32
+        } else {
+            self.max_chars
+        };
+        let mut height = (self.items.len() as u32).max(1);
+        if self.flags.contains(Flags::CheckBoxes) {
+            width += 2;
+        }
+        // the scrollbars (and the search bar) are drawn over the last column and line
+        if self.flags.contains(Flags::ScrollBars) {
+            width += 1;
+        }
+        if self.flags.contains_one(Flags::ScrollBars | Flags::SearchBar) {
+            height += 1;
+        }
+        Some(Size::new(width.max(1), height))
+    }
+}
 impl OnKeyPressed for ListBox {
     fn on_key_pressed(&mut self, key: Key, character: char) -> EventProcessStatus {
         if self.comp.process_key_pressed(key, character) {
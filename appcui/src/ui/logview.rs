@@ -0,0 +1,17 @@
+//! A log viewer UI control that displays (and follows) a stream of log lines.
+//!
+//! The LogView control receives lines from a channel, from a file that is followed like `tail -f` does,
+//! or directly from code. It detects the timestamp and the level of each line and colors them, keeps the
+//! last line visible (follow mode) until the user scrolls up, filters the lines by a substring, a regular
+//! expression or a minimum level and supports bookmarks. The lines are kept in a bounded ring buffer.
+
+mod entry;
+mod filter;
+mod initialization_flags;
+mod logview;
+#[cfg(test)]
+mod tests;
+
+pub use self::initialization_flags::Flags;
+pub use self::initialization_flags::Level;
+pub use self::logview::LogView;
@@ -0,0 +1,105 @@
+use super::Level;
+use std::ops::Range;
+
+// the level is searched only at the beginning of a line (after the timestamp)
+const LEVEL_SEARCH_LIMIT: usize = 96;
+
+/// A line from the log view together with the fields that were detected in it.
+pub(super) struct Entry {
+    pub(super) text: String,
+    pub(super) level: Option<Level>,
+    // position (in bytes) of the level field and the length of the timestamp prefix (0 if there is none)
+    level_start: u16,
+    level_end: u16,
+    timestamp_end: u16,
+}
+
+impl Entry {
+    /// Parses a line. A line without a level (and without a timestamp) is considered a continuation of the
+    /// previous one (e.g. a stack trace) and inherits the `previous` level.
+    pub(super) fn new(line: &str, previous: Option<Level>) -> Self {
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        let text = if line.contains('\t') {
+            line.replace('\t', "    ")
+        } else {
+            line.to_string()
+        };
+        let timestamp_end = timestamp_len(&text);
+        let (level, level_start, level_end) = match find_level(&text, timestamp_end) {
+            Some((level, range)) => (Some(level), range.start, range.end),
+            None if timestamp_end == 0 => (previous, 0, 0),
+            None => (None, 0, 0),
+        };
+        Self {
+            text,
+            level,
+            level_start: level_start as u16,
+            level_end: level_end as u16,
+            timestamp_end: timestamp_end as u16,
+        }
+    }
+
+    /// The range (in bytes) of the timestamp prefix
+    #[inline(always)]
+    pub(super) fn timestamp(&self) -> Range<usize> {
+        0..self.timestamp_end as usize
+    }
+
+    /// The range (in bytes) of the level field (empty if the line does not contain one)
+    #[inline(always)]
+    pub(super) fn level_field(&self) -> Range<usize> {
+        self.level_start as usize..self.level_end as usize
+    }
+}
+
+// the length (in bytes) of a timestamp at the beginning of a line, such as "2024-05-01 10:20:30.123",
+// "[2024-05-01T10:20:30Z]" or "10:20:30,123" (0 if the line does not start with a timestamp)
+fn timestamp_len(text: &str) -> usize {
+    let bytes = text.as_bytes();
+    let start = if bytes.first() == Some(&b'[') { 1 } else { 0 };
+    let mut end = start;
+    while end < bytes.len() && end < 64 && b"0123456789-:./TZ+, ".contains(&bytes[end]) {
+        end += 1;
+    }
+    // a timestamp ends with a digit (or with the 'Z' time zone)
+    while end > start && !(bytes[end - 1].is_ascii_digit() || bytes[end - 1] == b'Z') {
+        end -= 1;
+    }
+    let field = &bytes[start..end];
+    if field.iter().filter(|b| b.is_ascii_digit()).count() < 6 || !field.contains(&b':') || !field[0].is_ascii_digit() {
+        return 0;
+    }
+    if start == 1 && bytes.get(end) == Some(&b']') {
+        end + 1
+    } else {
+        end
+    }
+}
+
+// the first word (after the timestamp) that names a level. Lower case words (e.g. "error") are accepted only
+// if they look like a field (`[error]`, `level=error`, `<error>`), upper case words are accepted anywhere.
+fn find_level(text: &str, from: usize) -> Option<(Level, Range<usize>)> {
+    let bytes = text.as_bytes();
+    let limit = bytes.len().min(from + LEVEL_SEARCH_LIMIT);
+    let mut pos = from;
+    while pos < limit {
+        if !bytes[pos].is_ascii_alphabetic() {
+            pos += 1;
+            continue;
+        }
+        let start = pos;
+        while pos < bytes.len() && bytes[pos].is_ascii_alphanumeric() {
+            pos += 1;
+        }
+        let word = &text[start..pos];
+        if let Some(level) = Level::from_word(word) {
+            let upper = word.bytes().all(|b| b.is_ascii_uppercase());
+            let before = if start > 0 { bytes[start - 1] } else { b' ' };
+            let after = bytes.get(pos).copied().unwrap_or(b' ');
+            if upper || b"[=<|(".contains(&before) || after == b']' {
+                return Some((level, start..pos));
+            }
+        }
+    }
+    None
+}
@@ -0,0 +1,119 @@
+use super::entry::Entry;
+use crate::utils::regex::Regex;
+use super::Level;
+use std::ops::Range;
+
+#[derive(Clone)]
+enum Pattern {
+    None,
+    // case insensitive (for ASCII characters) substring
+    Text(String),
+    Regex(Regex),
+}
+
+/// The lines that are displayed by a log view: the lines that contain a pattern (a substring or a regular
+/// expression) and have at least a minimum level.
+#[derive(Clone)]
+pub(super) struct Filter {
+    pattern: Pattern,
+    pub(super) text: String,
+    pub(super) min_level: Option<Level>,
+}
+
+impl Filter {
+    pub(super) fn new() -> Self {
+        Self {
+            pattern: Pattern::None,
+            text: String::new(),
+            min_level: None,
+        }
+    }
+
+    #[inline(always)]
+    pub(super) fn is_active(&self) -> bool {
+        self.min_level.is_some() || !matches!(self.pattern, Pattern::None)
+    }
+
+    #[inline(always)]
+    pub(super) fn is_regex(&self) -> bool {
+        matches!(self.pattern, Pattern::Regex(_))
+    }
+
+    pub(super) fn set_text(&mut self, text: &str) {
+        self.pattern = if text.is_empty() {
+            Pattern::None
+        } else {
+            Pattern::Text(text.to_string())
+        };
+        self.text = text.to_string();
+    }
+
+    pub(super) fn set_regex(&mut self, pattern: &str) -> Result<(), String> {
+        self.pattern = if pattern.is_empty() {
+            Pattern::None
+        } else {
+            Pattern::Regex(Regex::new(pattern)?)
+        };
+        self.text = pattern.to_string();
+        Ok(())
+    }
+
+    pub(super) fn clear_pattern(&mut self) {
+        self.pattern = Pattern::None;
+        self.text.clear();
+    }
+
+    /// Returns `true` if the entry should be displayed. `chars` is a buffer used for regular expressions.
+    pub(super) fn matches(&self, entry: &Entry, chars: &mut Vec<char>) -> bool {
+        if let Some(min_level) = self.min_level {
+            if entry.level.is_none_or(|level| level < min_level) {
+                return false;
+            }
+        }
+        match &self.pattern {
+            Pattern::None => true,
+            Pattern::Text(text) => find_ignore_case(entry.text.as_bytes(), text.as_bytes(), 0).is_some(),
+            Pattern::Regex(regex) => {
+                chars.clear();
+                chars.extend(entry.text.chars());
+                regex.is_match(chars)
+            }
+        }
+    }
+
+    /// Adds the ranges (in characters) of all the matches from `text` in `output`
+    pub(super) fn highlights(&self, text: &str, output: &mut Vec<Range<usize>>) {
+        output.clear();
+        match &self.pattern {
+            Pattern::None => {}
+            Pattern::Text(pattern) => {
+                let mut from = 0;
+                while let Some(start) = find_ignore_case(text.as_bytes(), pattern.as_bytes(), from) {
+                    let first = text[..start].chars().count();
+                    output.push(first..first + pattern.chars().count());
+                    from = start + pattern.len();
+                }
+            }
+            Pattern::Regex(regex) => {
+                let chars: Vec<char> = text.chars().collect();
+                let mut from = 0;
+                while from <= chars.len() {
+                    let Some(range) = regex.find(&chars, from) else {
+                        break;
+                    };
+                    from = range.end.max(range.start + 1);
+                    if !range.is_empty() {
+                        output.push(range);
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn find_ignore_case(text: &[u8], pattern: &[u8], from: usize) -> Option<usize> {
+    if pattern.is_empty() || text.len() < pattern.len() {
+        return None;
+    }
+    (from..=text.len() - pattern.len()).find(|pos| text[*pos..*pos + pattern.len()].eq_ignore_ascii_case(pattern))
+}
@@ -0,0 +1,51 @@
+use EnumBitFlags::EnumBitFlags;
+
+#[EnumBitFlags(bits: 8)]
+pub enum Flags {
+    ShowLineNumbers = 0x01,
+    HideTimestamps = 0x02,
+}
+
+/// The severity of a log line. The levels are ordered (`Trace` is the lowest and `Fatal` the highest one).
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub enum Level {
+    Trace,
+    Debug,
+    Info,
+    Warning,
+    Error,
+    Fatal,
+}
+
+impl Level {
+    /// Returns the name of the level (e.g. `"Warning"`)
+    pub fn name(&self) -> &'static str {
+        match self {
+            Level::Trace => "Trace",
+            Level::Debug => "Debug",
+            Level::Info => "Info",
+            Level::Warning => "Warning",
+            Level::Error => "Error",
+            Level::Fatal => "Fatal",
+        }
+    }
+
+    pub(super) fn from_word(word: &str) -> Option<Level> {
+        let mut buf = [0u8; 8];
+        if word.len() > buf.len() {
+            return None;
+        }
+        let upper = &mut buf[..word.len()];
+        upper.copy_from_slice(word.as_bytes());
+        upper.make_ascii_uppercase();
+        match &*upper {
+            b"TRACE" | b"TRC" => Some(Level::Trace),
+            b"DEBUG" | b"DBG" => Some(Level::Debug),
+            b"INFO" | b"INF" | b"NOTICE" => Some(Level::Info),
+            b"WARN" | b"WARNING" | b"WRN" => Some(Level::Warning),
+            b"ERROR" | b"ERR" => Some(Level::Error),
+            b"FATAL" | b"CRITICAL" | b"CRIT" | b"PANIC" => Some(Level::Fatal),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,852 @@
+use super::entry::Entry;
+use super::filter::Filter;
+use super::{Flags, Level};
+use crate::prelude::*;
+use std::collections::{BTreeSet, VecDeque};
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::ops::Range;
+use std::path::Path;
+use std::sync::mpsc::{Receiver, TryRecvError};
+use std::time::Duration;
+
+// how often the sources (channel / file) are checked for new lines
+const REFRESH_INTERVAL: Duration = Duration::from_millis(100);
+const DEFAULT_CAPACITY: usize = 1_000_000;
+// maximum number of messages read from a channel at one refresh (so that a fast producer does not block the UI)
+const MAX_MESSAGES_PER_REFRESH: usize = 100_000;
+const MAX_FILE_BYTES_PER_REFRESH: u64 = 4 * 1024 * 1024;
+const WHEEL_LINES: usize = 3;
+const MAX_PROMPT_LEN: usize = 128;
+const BOOKMARK_CHAR: char = '●';
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum PromptKind {
+    Text,
+    Regex,
+}
+impl PromptKind {
+    fn label(&self) -> &'static str {
+        match self {
+            PromptKind::Text => "Filter: ",
+            PromptKind::Regex => "Regex: ",
+        }
+    }
+}
+
+struct Prompt {
+    kind: PromptKind,
+    text: String,
+    // the filter that was active when the prompt was opened (restored if the prompt is canceled)
+    previous: Filter,
+    error: Option<String>,
+}
+
+// a file that is read from `position` every time it grows
+struct FileSource {
+    file: File,
+    position: u64,
+    // the last (incomplete) line
+    pending: Vec<u8>,
+}
+
+#[CustomControl(overwrite=OnPaint+OnKeyPressed+OnMouseEvent+OnResize, events=TimerEvents, internal=true)]
+pub struct LogView {
+    flags: Flags,
+    entries: VecDeque<Entry>,
+    capacity: usize,
+    // the id of a line is the number of lines that were added before it (ids do not change when the first
+    // lines are dropped from the buffer)
+    first_id: u64,
+    filter: Filter,
+    // the ids of the lines that match the filter (only used if the filter is active)
+    visible: VecDeque<u64>,
+    bookmarks: BTreeSet<u64>,
+    // first visible row, the current row and the first visible column (indexes in the list of visible lines)
+    top: usize,
+    cursor: usize,
+    column: usize,
+    follow: bool,
+    receiver: Option<Receiver<String>>,
+    file: Option<FileSource>,
+    prompt: Option<Prompt>,
+    chars: Vec<char>,
+}
+impl LogView {
+    /// Creates a new (empty) log view with the specified layout and flags. The flags can be a combination of:
+    /// * `logview::Flags::ShowLineNumbers` - the number of each line is displayed on the left side
+    /// * `logview::Flags::HideTimestamps` - the timestamps from the beginning of the lines are not displayed
+    ///
+    /// Lines can be added via the `add_line` method or they can be read from a channel (`attach_receiver`) or
+    /// from a file (`follow_file`). The log view starts in follow mode (the last line is always visible).
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use appcui::prelude::*;
+    ///
+    /// let mut log = LogView::new(layout!("d:f"), logview::Flags::ShowLineNumbers);
+    /// log.add_line("2024-05-01 10:20:30 INFO server started");
+    /// log.add_line("2024-05-01 10:20:31 ERROR connection refused");
+    /// ```
+    pub fn new(layout: Layout, flags: Flags) -> Self {
+        Self {
+            base: ControlBase::with_status_flags(layout, StatusFlags::Visible | StatusFlags::Enabled | StatusFlags::AcceptInput),
+            flags,
+            entries: VecDeque::new(),
+            capacity: DEFAULT_CAPACITY,
+            first_id: 0,
+            filter: Filter::new(),
+            visible: VecDeque::new(),
+            bookmarks: BTreeSet::new(),
+            top: 0,
+            cursor: 0,
+            column: 0,
+            follow: true,
+            receiver: None,
+            file: None,
+            prompt: None,
+            chars: Vec::new(),
+        }
+    }
+
+    /// Adds a line at the end of the log. If the text contains new line characters, it is split in several lines.
+    /// If the buffer is full (see `set_capacity`), the oldest lines are dropped.
+    pub fn add_line(&mut self, text: &str) {
+        self.push_text(text);
+        self.lines_added();
+    }
+
+    fn push_text(&mut self, text: &str) {
+        if text.is_empty() {
+            self.push_line("");
+        } else {
+            for line in text.lines() {
+                self.push_line(line);
+            }
+        }
+    }
+
+    fn push_line(&mut self, line: &str) {
+        let entry = Entry::new(line, self.entries.back().and_then(|e| e.level));
+        while self.entries.len() >= self.capacity {
+            self.drop_first();
+        }
+        let id = self.first_id + self.entries.len() as u64;
+        if self.filter.is_active() && self.filter.matches(&entry, &mut self.chars) {
+            self.visible.push_back(id);
+        }
+        self.entries.push_back(entry);
+    }
+
+    fn drop_first(&mut self) {
+        if self.entries.pop_front().is_none() {
+            return;
+        }
+        let id = self.first_id;
+        self.first_id += 1;
+        self.bookmarks.remove(&id);
+        let was_visible = if self.filter.is_active() {
+            self.visible.front() == Some(&id) && self.visible.pop_front().is_some()
+        } else {
+            true
+        };
+        if was_visible {
+            self.top = self.top.saturating_sub(1);
+            self.cursor = self.cursor.saturating_sub(1);
+        }
+    }
+
+    fn lines_added(&mut self) {
+        if self.follow {
+            self.cursor = self.visible_count().saturating_sub(1);
+        }
+        self.update_view();
+    }
+
+    /// Removes all lines (and bookmarks) from the log
+    pub fn clear(&mut self) {
+        self.first_id += self.entries.len() as u64;
+        self.entries.clear();
+        self.visible.clear();
+        self.bookmarks.clear();
+        self.top = 0;
+        self.cursor = 0;
+        self.column = 0;
+        self.follow = true;
+    }
+
+    /// Returns the number of lines from the buffer (including the ones that do not match the filter)
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the log does not contain any line
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the total number of lines that were added to the log (including the ones that were dropped
+    /// because the buffer was full)
+    #[inline(always)]
+    pub fn total_lines(&self) -> u64 {
+        self.first_id + self.entries.len() as u64
+    }
+
+    /// Returns the text of a line (`index` is the position of the line in the buffer)
+    pub fn line(&self, index: usize) -> Option<&str> {
+        self.entries.get(index).map(|e| e.text.as_str())
+    }
+
+    /// Returns the level of a line. Lines without a level (e.g. the lines of a stack trace) inherit the level
+    /// of the previous line. `None` is returned if the line has no level or if the index is invalid.
+    pub fn level(&self, index: usize) -> Option<Level> {
+        self.entries.get(index).and_then(|e| e.level)
+    }
+
+    /// Sets the maximum number of lines kept in memory (by default 1,000,000). When the buffer is full, the
+    /// oldest lines are dropped to make room for the new ones.
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity.max(1);
+        while self.entries.len() > self.capacity {
+            self.drop_first();
+        }
+        self.update_view();
+    }
+
+    /// Returns the maximum number of lines kept in memory
+    #[inline(always)]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Reads lines from a channel. Every message is added to the log (a message can contain several lines). The
+    /// channel is checked periodically; it is detached when all its senders are dropped.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use appcui::prelude::*;
+    ///
+    /// let (sender, receiver) = std::sync::mpsc::channel::<String>();
+    /// let mut log = LogView::new(layout!("d:f"), logview::Flags::None);
+    /// log.attach_receiver(receiver);
+    /// std::thread::spawn(move || {
+    ///     for i in 0..100 {
+    ///         let _ = sender.send(format!("INFO step {i}"));
+    ///     }
+    /// });
+    /// ```
+    pub fn attach_receiver(&mut self, receiver: Receiver<String>) {
+        self.receiver = Some(receiver);
+        self.start_refresh();
+    }
+
+    /// Reads the content of a file and then follows it (the lines that are appended to the file are added to the
+    /// log, like `tail -f` does). If the file is truncated, it is read again from the beginning.
+    /// A large file is read in chunks (a few megabytes at every refresh), so the memory used does not depend on
+    /// the size of the file.
+    pub fn follow_file(&mut self, path: &Path) -> Result<(), Error> {
+        let file = File::open(path).map_err(|e| Error::new(ErrorKind::InvalidParameter, format!("Fail to open '{}': {e}", path.display())))?;
+        self.file = Some(FileSource {
+            file,
+            position: 0,
+            pending: Vec::new(),
+        });
+        self.read_file();
+        self.lines_added();
+        self.start_refresh();
+        Ok(())
+    }
+
+    /// Stops reading lines from the attached channel and file (if any)
+    pub fn detach_sources(&mut self) {
+        self.receiver = None;
+        self.file = None;
+        if let Some(timer) = self.timer() {
+            timer.stop();
+        }
+    }
+
+    fn start_refresh(&mut self) {
+        if let Some(timer) = self.timer() {
+            timer.start(REFRESH_INTERVAL);
+        }
+    }
+
+    // reads the new lines from the channel and from the file; returns `true` if any line was added
+    fn refresh(&mut self) -> bool {
+        let mut added = false;
+        if let Some(receiver) = self.receiver.take() {
+            let mut connected = true;
+            for _ in 0..MAX_MESSAGES_PER_REFRESH {
+                match receiver.try_recv() {
+                    Ok(text) => {
+                        self.push_text(&text);
+                        added = true;
+                    }
+                    Err(TryRecvError::Empty) => break,
+                    Err(TryRecvError::Disconnected) => {
+                        connected = false;
+                        break;
+                    }
+                }
+            }
+            if connected {
+                self.receiver = Some(receiver);
+            }
+        }
+        added |= self.read_file();
+        if self.receiver.is_none() && self.file.is_none() {
+            if let Some(timer) = self.timer() {
+                timer.stop();
+            }
+        }
+        if added {
+            self.lines_added();
+        }
+        added
+    }
+
+    // reads (at most MAX_FILE_BYTES_PER_REFRESH bytes) from the file; returns `true` if any line was added
+    fn read_file(&mut self) -> bool {
+        let Some(source) = self.file.as_mut() else {
+            return false;
+        };
+        let len = source.file.metadata().map(|m| m.len()).unwrap_or(source.position);
+        if len < source.position {
+            // the file was truncated (e.g. by a log rotation)
+            source.position = 0;
+            source.pending.clear();
+        }
+        if len == source.position || source.file.seek(SeekFrom::Start(source.position)).is_err() {
+            return false;
+        }
+        let mut data = std::mem::take(&mut source.pending);
+        let start = data.len();
+        let size = (len - source.position).min(MAX_FILE_BYTES_PER_REFRESH);
+        let read = (&mut source.file).take(size).read_to_end(&mut data).unwrap_or(0);
+        source.position += read as u64;
+        let end = match data[start..].iter().rposition(|b| *b == b'\n') {
+            Some(last) => start + last,
+            // a line that is longer than a chunk is split
+            None if data.len() as u64 >= MAX_FILE_BYTES_PER_REFRESH => data.len(),
+            None => {
+                source.pending = data;
+                return false;
+            }
+        };
+        source.pending = data.get(end + 1..).map(|rest| rest.to_vec()).unwrap_or_default();
+        let text = String::from_utf8_lossy(&data[..end]).into_owned();
+        self.push_text(&text);
+        true
+    }
+
+    /// Enables or disables the follow mode. In follow mode the view scrolls automatically so that the last line
+    /// is always visible. The follow mode is disabled when the user scrolls up and enabled again when the last
+    /// line is reached.
+    pub fn set_follow(&mut self, follow: bool) {
+        self.follow = follow;
+        self.lines_added();
+    }
+
+    /// Returns `true` if the view follows (displays) the last line
+    #[inline(always)]
+    pub fn is_following(&self) -> bool {
+        self.follow
+    }
+
+    /// Displays only the lines that contain `text` (the comparison is case insensitive). An empty text removes
+    /// the pattern from the filter (the minimum level is kept).
+    pub fn set_filter(&mut self, text: &str) {
+        self.filter.set_text(text);
+        self.rebuild();
+    }
+
+    /// Displays only the lines that match a regular expression. The supported syntax includes character
+    /// classes (`[a-z]`, `\d`, `\w`, `\s`), anchors (`^`, `$`, `\b`), groups, alternations (`a|b`), repetitions
+    /// (`*`, `+`, `?`, `{n,m}`) and the `(?i)` prefix for case insensitive matching.
+    /// An error is returned (and the filter is not changed) if the regular expression is invalid.
+    pub fn set_regex_filter(&mut self, pattern: &str) -> Result<(), Error> {
+        self.filter
+            .set_regex(pattern)
+            .map_err(|e| Error::new(ErrorKind::InvalidParameter, format!("Invalid regular expression '{pattern}': {e}")))?;
+        self.rebuild();
+        Ok(())
+    }
+
+    /// Displays only the lines that have at least the specified level (`None` displays all lines)
+    pub fn set_min_level(&mut self, level: Option<Level>) {
+        self.filter.min_level = level;
+        self.rebuild();
+    }
+
+    /// Returns the minimum level of the displayed lines
+    #[inline(always)]
+    pub fn min_level(&self) -> Option<Level> {
+        self.filter.min_level
+    }
+
+    /// Returns the pattern (text or regular expression) of the filter
+    #[inline(always)]
+    pub fn filter(&self) -> &str {
+        &self.filter.text
+    }
+
+    /// Removes the pattern and the minimum level from the filter (all lines are displayed)
+    pub fn clear_filter(&mut self) {
+        self.filter.clear_pattern();
+        self.filter.min_level = None;
+        self.rebuild();
+    }
+
+    /// Returns the number of lines that match the filter
+    pub fn visible_count(&self) -> usize {
+        if self.filter.is_active() {
+            self.visible.len()
+        } else {
+            self.entries.len()
+        }
+    }
+
+    /// Returns the index (in the buffer) of the current line or `None` if no line is displayed
+    pub fn current_line(&self) -> Option<usize> {
+        self.current_id().map(|id| (id - self.first_id) as usize)
+    }
+
+    /// Moves the current line to the line with the specified index (in the buffer). If the line does not match
+    /// the filter, the next line that matches it is selected. The follow mode is disabled unless the last line
+    /// is selected.
+    pub fn go_to_line(&mut self, index: usize) {
+        let index = self.visible_position(self.first_id + index as u64);
+        self.move_cursor(index);
+    }
+
+    /// Adds a bookmark on a line (`index` is the position of the line in the buffer) or removes it if the line
+    /// is already bookmarked. Bookmarks are removed together with their lines when the buffer is full.
+    pub fn toggle_bookmark(&mut self, index: usize) {
+        if index >= self.entries.len() {
+            return;
+        }
+        let id = self.first_id + index as u64;
+        if !self.bookmarks.remove(&id) {
+            self.bookmarks.insert(id);
+        }
+    }
+
+    /// Returns `true` if the line with the specified index is bookmarked
+    pub fn is_bookmarked(&self, index: usize) -> bool {
+        self.bookmarks.contains(&(self.first_id + index as u64))
+    }
+
+    /// Returns the indexes (in the buffer) of the bookmarked lines
+    pub fn bookmarks(&self) -> Vec<usize> {
+        self.bookmarks.iter().map(|id| (id - self.first_id) as usize).collect()
+    }
+
+    /// Moves the current line to the next (or previous) bookmarked line that matches the filter. Returns
+    /// `false` if there is no such bookmark.
+    pub fn go_to_bookmark(&mut self, forward: bool) -> bool {
+        let Some(current) = self.current_id() else {
+            return false;
+        };
+        let found = if forward {
+            self.bookmarks.range(current + 1..).find(|id| self.is_visible(**id)).copied()
+        } else {
+            self.bookmarks.range(..current).rev().find(|id| self.is_visible(**id)).copied()
+        };
+        match found {
+            Some(id) => {
+                let index = self.visible_position(id);
+                self.move_cursor(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn visible_id(&self, index: usize) -> u64 {
+        if self.filter.is_active() {
+            self.visible[index]
+        } else {
+            self.first_id + index as u64
+        }
+    }
+
+    // the position (in the list of visible lines) of the first visible line with an id greater or equal to `id`
+    fn visible_position(&self, id: u64) -> usize {
+        if self.filter.is_active() {
+            self.visible.partition_point(|v| *v < id)
+        } else {
+            (id.saturating_sub(self.first_id) as usize).min(self.entries.len())
+        }
+    }
+
+    fn is_visible(&self, id: u64) -> bool {
+        if self.filter.is_active() {
+            self.visible.binary_search(&id).is_ok()
+        } else {
+            id >= self.first_id
+        }
+    }
+
+    fn current_id(&self) -> Option<u64> {
+        (self.cursor < self.visible_count()).then(|| self.visible_id(self.cursor))
+    }
+
+    fn entry(&self, id: u64) -> &Entry {
+        &self.entries[(id - self.first_id) as usize]
+    }
+
+    // recomputes the visible lines after the filter was changed (the current line is kept, if possible)
+    fn rebuild(&mut self) {
+        let current = self.current_id();
+        self.visible.clear();
+        if self.filter.is_active() {
+            for (index, entry) in self.entries.iter().enumerate() {
+                if self.filter.matches(entry, &mut self.chars) {
+                    self.visible.push_back(self.first_id + index as u64);
+                }
+            }
+        }
+        if let Some(id) = current {
+            self.cursor = self.visible_position(id);
+        }
+        self.lines_added();
+    }
+
+    fn has_status_line(&self) -> bool {
+        self.prompt.is_some() || self.filter.is_active()
+    }
+
+    fn rows(&self) -> usize {
+        let height = self.size().height as usize;
+        height.saturating_sub(if self.has_status_line() { 1 } else { 0 })
+    }
+
+    fn max_top(&self) -> usize {
+        self.visible_count().saturating_sub(self.rows())
+    }
+
+    // keeps the cursor inside the list of visible lines and on the screen
+    fn update_view(&mut self) {
+        let count = self.visible_count();
+        let rows = self.rows().max(1);
+        self.cursor = self.cursor.min(count.saturating_sub(1));
+        if self.follow {
+            self.top = self.max_top();
+        } else if self.cursor < self.top {
+            self.top = self.cursor;
+        } else if self.cursor >= self.top + rows {
+            self.top = self.cursor + 1 - rows;
+        }
+        self.top = self.top.min(self.max_top());
+    }
+
+    fn move_cursor(&mut self, index: usize) {
+        let count = self.visible_count();
+        self.cursor = index.min(count.saturating_sub(1));
+        self.follow = self.cursor + 1 >= count;
+        self.update_view();
+    }
+
+    fn gutter_width(&self) -> i32 {
+        let mut width = 1;
+        if self.flags.contains(Flags::ShowLineNumbers) {
+            let mut digits = 1;
+            let mut total = self.total_lines();
+            while total >= 10 {
+                total /= 10;
+                digits += 1;
+            }
+            width += digits.max(4) + 1;
+        }
+        width
+    }
+
+    fn show_prompt(&mut self, kind: PromptKind) -> EventProcessStatus {
+        let text = if self.filter.is_regex() == (kind == PromptKind::Regex) {
+            self.filter.text.clone()
+        } else {
+            String::new()
+        };
+        self.prompt = Some(Prompt {
+            kind,
+            text,
+            previous: self.filter.clone(),
+            error: None,
+        });
+        self.update_view();
+        EventProcessStatus::Processed
+    }
+
+    // the filter is updated while the pattern is typed
+    fn apply_prompt(&mut self) {
+        let Some(prompt) = self.prompt.as_mut() else {
+            return;
+        };
+        prompt.error = None;
+        match prompt.kind {
+            PromptKind::Text => self.filter.set_text(&prompt.text),
+            PromptKind::Regex => {
+                if let Err(e) = self.filter.set_regex(&prompt.text) {
+                    prompt.error = Some(e);
+                    return;
+                }
+            }
+        }
+        self.rebuild();
+    }
+
+    fn on_prompt_key(&mut self, key: Key, character: char) -> EventProcessStatus {
+        let Some(prompt) = self.prompt.as_mut() else {
+            return EventProcessStatus::Ignored;
+        };
+        match key.value() {
+            key!("Escape") => {
+                let previous = prompt.previous.clone();
+                self.prompt = None;
+                self.filter = previous;
+                self.rebuild();
+            }
+            key!("Enter") => {
+                self.prompt = None;
+                self.update_view();
+            }
+            key!("Backspace") => {
+                prompt.text.pop();
+                self.apply_prompt();
+            }
+            _ if character >= ' ' && prompt.text.chars().count() < MAX_PROMPT_LEN => {
+                prompt.text.push(character);
+                self.apply_prompt();
+            }
+            _ => return EventProcessStatus::Ignored,
+        }
+        EventProcessStatus::Processed
+    }
+
+    fn next_level(&mut self) {
+        let level = match self.filter.min_level {
+            None => Some(Level::Debug),
+            Some(Level::Trace) => Some(Level::Debug),
+            Some(Level::Debug) => Some(Level::Info),
+            Some(Level::Info) => Some(Level::Warning),
+            Some(Level::Warning) => Some(Level::Error),
+            Some(Level::Error) | Some(Level::Fatal) => None,
+        };
+        self.set_min_level(level);
+    }
+
+    fn paint_status_line(&self, surface: &mut Surface, theme: &Theme, y: i32, attr: CharAttribute) {
+        let error_attr = CharAttribute::with_color(theme.text.error.foreground, attr.background);
+        if let Some(prompt) = self.prompt.as_ref() {
+            let label = prompt.kind.label();
+            surface.write_string(0, y, label, attr, false);
+            surface.write_string(label.len() as i32, y, &prompt.text, attr, false);
+            let x = label.len() as i32 + prompt.text.chars().count() as i32;
+            if let Some(error) = prompt.error.as_ref() {
+                surface.write_string(x + 2, y, error, error_attr, false);
+            }
+            if self.has_focus() {
+                surface.set_cursor(x, y);
+            }
+            return;
+        }
+        let mut status = String::new();
+        if !self.filter.text.is_empty() {
+            status.push_str(if self.filter.is_regex() { "Regex: " } else { "Filter: " });
+            status.push_str(&self.filter.text);
+            status.push_str("  ");
+        }
+        if let Some(level) = self.filter.min_level {
+            status.push_str("Level: ");
+            status.push_str(level.name());
+            status.push_str("+  ");
+        }
+        status.push_str(&format!("({} of {} lines)", self.visible.len(), self.entries.len()));
+        surface.write_string(0, y, &status, theme.editor.inactive, false);
+    }
+
+    fn level_attr(theme: &Theme, level: Option<Level>) -> Option<CharAttribute> {
+        Some(match level? {
+            Level::Trace | Level::Debug => theme.text.inactive,
+            Level::Info => theme.text.enphasized_2,
+            Level::Warning => theme.text.warning,
+            Level::Error => theme.text.error,
+            Level::Fatal => {
+                let mut attr = theme.text.error;
+                attr.flags |= CharFlags::Bold;
+                attr
+            }
+        })
+    }
+}
+
+impl OnPaint for LogView {
+    fn on_paint(&self, surface: &mut Surface, theme: &Theme) {
+        let attr = match () {
+            _ if !self.is_enabled() => theme.editor.inactive,
+            _ if self.has_focus() => theme.editor.focused,
+            _ if self.is_mouse_over() => theme.editor.hovered,
+            _ => theme.editor.normal,
+        };
+        surface.clear(Character::with_attributes(' ', attr));
+        let width = self.size().width as i32;
+        let rows = self.rows();
+        let gutter = self.gutter_width();
+        let count = self.visible_count();
+        let numbers_width = (gutter - 2).max(0) as usize;
+        let timestamp_attr = CharAttribute::with_color(theme.text.enphasized_1.foreground, attr.background);
+        let match_attr = CharAttribute::with_color(attr.background, theme.text.highlighted.foreground);
+        let mut highlights: Vec<Range<usize>> = Vec::new();
+        for row in 0..rows {
+            let index = self.top + row;
+            if index >= count {
+                break;
+            }
+            let y = row as i32;
+            let id = self.visible_id(index);
+            let entry = self.entry(id);
+            let is_current = index == self.cursor && self.has_focus();
+            let line_attr = if is_current { theme.editor.pressed_or_selectd } else { attr };
+            if is_current {
+                surface.fill_horizontal_line(0, y, width - 1, Character::with_attributes(' ', line_attr));
+            }
+            if self.bookmarks.contains(&id) {
+                let a = if is_current {
+                    line_attr
+                } else {
+                    CharAttribute::with_color(theme.text.warning.foreground, attr.background)
+                };
+                surface.write_char(0, y, Character::with_attributes(BOOKMARK_CHAR, a));
+            }
+            if numbers_width > 0 {
+                let number = format!("{:>numbers_width$}", id + 1);
+                let a = if is_current { line_attr } else { theme.editor.inactive };
+                surface.write_string(1, y, &number, a, false);
+            }
+            let level_attr = Self::level_attr(theme, entry.level).map(|a| CharAttribute::with_color(a.foreground, attr.background));
+            let (timestamp, level_field) = (entry.timestamp(), entry.level_field());
+            // the hidden timestamp (and the space after it) are skipped
+            let skip = if self.flags.contains(Flags::HideTimestamps) && timestamp.end > 0 {
+                timestamp.end + entry.text[timestamp.end..].bytes().take_while(|b| *b == b' ').count()
+            } else {
+                0
+            };
+            self.filter.highlights(&entry.text, &mut highlights);
+            let mut x = gutter - self.column as i32;
+            for (char_index, (byte_index, ch)) in entry.text.char_indices().enumerate() {
+                if byte_index < skip {
+                    continue;
+                }
+                if x >= width {
+                    break;
+                }
+                if x >= gutter {
+                    let a = match () {
+                        _ if highlights.iter().any(|r| r.contains(&char_index)) => match_attr,
+                        _ if is_current => line_attr,
+                        _ if timestamp.contains(&byte_index) => timestamp_attr,
+                        _ if level_field.contains(&byte_index) => level_attr.unwrap_or(attr),
+                        _ => attr,
+                    };
+                    surface.write_char(x, y, Character::with_attributes(ch, a));
+                }
+                x += 1;
+            }
+        }
+        if self.has_status_line() {
+            self.paint_status_line(surface, theme, rows as i32, attr);
+        }
+    }
+}
+
+impl OnKeyPressed for LogView {
+    fn on_key_pressed(&mut self, key: Key, character: char) -> EventProcessStatus {
+        if self.prompt.is_some() {
+            return self.on_prompt_key(key, character);
+        }
+        let rows = self.rows().max(1);
+        let c = self.cursor;
+        match key.value() {
+            key!("Up") => self.move_cursor(c.saturating_sub(1)),
+            key!("Down") => self.move_cursor(c + 1),
+            key!("PageUp") => self.move_cursor(c.saturating_sub(rows)),
+            key!("PageDown") => self.move_cursor(c + rows),
+            key!("Home") | key!("Ctrl+Home") => self.move_cursor(0),
+            key!("End") | key!("Ctrl+End") => self.move_cursor(usize::MAX),
+            key!("Left") => self.column = self.column.saturating_sub(1),
+            key!("Right") => self.column += 1,
+            key!("Ctrl+Left") => self.column = 0,
+            key!("Ctrl+F") => return self.show_prompt(PromptKind::Text),
+            key!("Ctrl+R") => return self.show_prompt(PromptKind::Regex),
+            key!("Ctrl+L") => self.next_level(),
+            key!("Ctrl+B") => {
+                if let Some(index) = self.current_line() {
+                    self.toggle_bookmark(index);
+                }
+            }
+            key!("F2") => {
+                self.go_to_bookmark(true);
+            }
+            key!("Shift+F2") => {
+                self.go_to_bookmark(false);
+            }
+            key!("Escape") if self.filter.is_active() => self.clear_filter(),
+            _ => return EventProcessStatus::Ignored,
+        }
+        EventProcessStatus::Processed
+    }
+}
+
+impl OnMouseEvent for LogView {
+    fn on_mouse_event(&mut self, event: &MouseEvent) -> EventProcessStatus {
+        match event {
+            MouseEvent::Enter | MouseEvent::Leave => EventProcessStatus::Processed,
+            MouseEvent::Pressed(data) => {
+                if data.y >= 0 && (data.y as usize) < self.rows() && self.top + (data.y as usize) < self.visible_count() {
+                    self.move_cursor(self.top + data.y as usize);
+                }
+                EventProcessStatus::Processed
+            }
+            MouseEvent::Wheel(direction) => {
+                match direction {
+                    MouseWheelDirection::Up => {
+                        self.top = self.top.saturating_sub(WHEEL_LINES);
+                        self.cursor = self.cursor.min(self.top + self.rows().max(1) - 1);
+                        self.follow = false;
+                    }
+                    MouseWheelDirection::Down => {
+                        self.top = (self.top + WHEEL_LINES).min(self.max_top());
+                        // scrolling to the end of the log enables the follow mode
+                        if self.top == self.max_top() {
+                            self.follow = true;
+                            self.cursor = self.visible_count().saturating_sub(1);
+                        }
+                    }
+                    MouseWheelDirection::Left => self.column = self.column.saturating_sub(WHEEL_LINES),
+                    MouseWheelDirection::Right => self.column += WHEEL_LINES,
+                }
+                EventProcessStatus::Processed
+            }
+            _ => EventProcessStatus::Ignored,
+        }
+    }
+}
+
+impl OnResize for LogView {
+    fn on_resize(&mut self, _old_size: Size, _new_size: Size) {
+        self.update_view();
+    }
+}
+
+impl TimerEvents for LogView {
+    fn on_update(&mut self, _ticks: u64) -> EventProcessStatus {
+        if self.refresh() {
+            EventProcessStatus::Processed
+        } else {
+            EventProcessStatus::Ignored
+        }
+    }
+}
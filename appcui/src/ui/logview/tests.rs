@@ -0,0 +1,351 @@
+use super::entry::Entry;
+use super::Level;
+use crate::prelude::*;
+use crate::utils::regex::Regex;
+use std::time::Duration;
+
+fn log_window(log: LogView, width: u16, height: u16) -> (crate::testing::TestDriver, Handle<LogView>) {
+    let mut driver = crate::testing::TestDriver::new(App::debug(width + 4, height + 4, "")).unwrap();
+    let mut w = Window::new(
+        "Log",
+        LayoutBuilder::new().x(0).y(0).width(width + 2).height(height + 2).build(),
+        window::Flags::None,
+    );
+    let handle = w.add(log);
+    driver.add_window(w);
+    driver.focus(handle);
+    driver.settle();
+    (driver, handle)
+}
+
+fn find(pattern: &str, text: &str) -> Option<(usize, usize)> {
+    let chars: Vec<char> = text.chars().collect();
+    Regex::new(pattern).unwrap().find(&chars, 0).map(|r| (r.start, r.end))
+}
+
+#[test]
+fn check_regex() {
+    assert_eq!(find("abc", "xxabcxx"), Some((2, 5)));
+    assert_eq!(find("a.c", "abc"), Some((0, 3)));
+    assert_eq!(find("^abc", "xabc"), None);
+    assert_eq!(find("abc$", "abcabc"), Some((3, 6)));
+    assert_eq!(find("\\d+", "port 8080 open"), Some((5, 9)));
+    assert_eq!(find("[a-c]+", "xxbcaz"), Some((2, 5)));
+    assert_eq!(find("[^0-9 ]+", "12 ab3"), Some((3, 5)));
+    assert_eq!(find("(ERROR|WARN)ING?", "a WARNING"), Some((2, 9)));
+    assert_eq!(find("(?:ab)+", "xababab"), Some((1, 7)));
+    assert_eq!(find("a{2,3}", "caaaa"), Some((1, 4)));
+    assert_eq!(find("a{2}", "ca"), None);
+    assert_eq!(find("<.+>", "<a><b>"), Some((0, 6)));
+    assert_eq!(find("<.+?>", "<a><b>"), Some((0, 3)));
+    assert_eq!(find("\\bcat\\b", "concat cat"), Some((7, 10)));
+    assert_eq!(find("x*", "abc"), Some((0, 0)));
+    assert_eq!(find("(a*)*b", "aaab"), Some((0, 4)));
+    assert_eq!(find("(?i)error", "An ERROR"), Some((3, 8)));
+    assert_eq!(find("error", "An ERROR"), None);
+    assert_eq!(find("\\.\\*", "a.*b"), Some((1, 3)));
+    assert_eq!(find("{abc}", "x{abc}"), Some((1, 6)));
+    assert!(Regex::new("(abc").is_err());
+    assert!(Regex::new("abc)").is_err());
+    assert!(Regex::new("[abc").is_err());
+    assert!(Regex::new("*a").is_err());
+    assert!(Regex::new("a{3,1}").is_err());
+    assert!(Regex::new("[z-a]").is_err());
+}
+
+#[test]
+fn check_regex_long_lines() {
+    // a long line must not overflow the stack
+    let mut line = "x".repeat(100_000);
+    line.push_str(" error: connection timeout");
+    let chars: Vec<char> = line.chars().collect();
+    assert_eq!(Regex::new("error.*timeout").unwrap().find(&chars, 0), Some(100_001..100_026));
+    assert_eq!(Regex::new("x+ e").unwrap().find(&chars, 0), Some(0..100_002));
+    assert!(!Regex::new("error.*refused").unwrap().is_match(&chars));
+    assert!(Regex::new("x*.*").unwrap().is_full_match(&chars));
+    assert!(Regex::new("(?:x|y)*error").unwrap().is_match(&chars));
+}
+
+#[test]
+fn check_regex_nested_quantifiers() {
+    // patterns that take exponential time for a backtracking engine
+    let start = std::time::Instant::now();
+    let text: Vec<char> = "a".repeat(30_000).chars().collect();
+    assert!(!Regex::new("(a+)+b").unwrap().is_match(&text));
+    assert!(!Regex::new("(a|aa)*c").unwrap().is_match(&text));
+    assert!(!Regex::new("(a*)*$x").unwrap().is_match(&text));
+    assert!(Regex::new("(a*)*").unwrap().is_full_match(&text));
+    assert!(start.elapsed() < Duration::from_secs(5));
+    assert_eq!(find("(a+)+b", "aaaab"), Some((0, 5)));
+    assert!(Regex::new(&"(".repeat(100)).is_err());
+    assert!(Regex::new("(a{1000}){1000}").is_err());
+}
+
+#[test]
+fn check_parsing() {
+    let e = Entry::new("2024-05-01 10:20:30.123 ERROR connection refused", None);
+    assert_eq!(e.level, Some(Level::Error));
+    assert_eq!(e.timestamp(), 0..23);
+    assert_eq!(&e.text[e.level_field()], "ERROR");
+
+    let e = Entry::new("[2024-05-01T10:20:30Z] [info] started", None);
+    assert_eq!(e.level, Some(Level::Info));
+    assert_eq!(e.timestamp(), 0..22);
+
+    let e = Entry::new("10:20:30,500 level=warn disk almost full", None);
+    assert_eq!(e.level, Some(Level::Warning));
+    assert_eq!(e.timestamp(), 0..12);
+
+    // lower case words are not levels unless they look like a field
+    let e = Entry::new("2024-05-01 10:20:30 no error here", None);
+    assert_eq!(e.level, None);
+    // a line without a timestamp and a level continues the previous one
+    let e = Entry::new("\tat Main.run(Main.java:10)", Some(Level::Fatal));
+    assert_eq!(e.level, Some(Level::Fatal));
+    assert_eq!(e.text, "    at Main.run(Main.java:10)");
+    assert_eq!(e.timestamp(), 0..0);
+    // version numbers are not timestamps
+    assert_eq!(Entry::new("1.2.3 DEBUG x", None).timestamp(), 0..0);
+}
+
+#[test]
+fn check_ring_buffer_and_bookmarks() {
+    let mut log = LogView::new(layout!("d:f"), logview::Flags::None);
+    log.set_capacity(5);
+    for i in 0..4 {
+        log.add_line(&format!("line {i}"));
+    }
+    log.toggle_bookmark(0);
+    log.toggle_bookmark(2);
+    log.add_line("line 4\nline 5");
+    assert_eq!(log.len(), 5);
+    assert_eq!(log.total_lines(), 6);
+    assert_eq!(log.line(0), Some("line 1"));
+    // the bookmark of the dropped line was removed, the other one moved with its line
+    assert_eq!(log.bookmarks(), vec![1]);
+    assert!(log.is_bookmarked(1));
+    log.set_capacity(2);
+    assert_eq!(log.line(0), Some("line 4"));
+    assert!(log.bookmarks().is_empty());
+    log.clear();
+    assert!(log.is_empty());
+    assert_eq!(log.total_lines(), 6);
+}
+
+#[test]
+fn check_paint_and_colors() {
+    let mut log = LogView::new(layout!("d:f"), logview::Flags::ShowLineNumbers);
+    log.add_line("2024-05-01 10:20:30 INFO server started");
+    log.add_line("2024-05-01 10:20:31 ERROR connection refused");
+    let (mut driver, _) = log_window(log, 60, 5);
+    let text = driver.screen_text();
+    assert!(text.contains("    1 2024-05-01 10:20:30 INFO server started"));
+    assert!(text.contains("    2 2024-05-01 10:20:31 ERROR connection refused"));
+
+    let theme = Theme::new(Themes::Default);
+    let screen = driver.screen();
+    // the first line is displayed on the first row of the window (the text starts after the line numbers)
+    let row = 1;
+    assert_eq!(screen.char(5, row).unwrap().code, '1');
+    // the timestamp of the first line
+    assert_eq!(screen.char(7, row).unwrap().foreground, theme.text.enphasized_1.foreground);
+    // the level of the first line
+    assert_eq!(screen.char(27, row).unwrap().code, 'I');
+    assert_eq!(screen.char(27, row).unwrap().foreground, theme.text.enphasized_2.foreground);
+    // the level of the second (current) line is not colored
+    assert_eq!(screen.char(27, row + 1).unwrap().code, 'E');
+    assert_eq!(screen.char(27, row + 1).unwrap().foreground, theme.editor.pressed_or_selectd.foreground);
+    driver.press(key!("Up"));
+    assert_eq!(driver.screen().char(27, row + 1).unwrap().foreground, theme.text.error.foreground);
+}
+
+#[test]
+fn check_hidden_timestamps() {
+    let mut log = LogView::new(layout!("d:f"), logview::Flags::HideTimestamps);
+    log.add_line("[2024-05-01 10:20:30]   WARN low memory");
+    log.add_line("no timestamp");
+    let (driver, _) = log_window(log, 40, 4);
+    let text = driver.screen_text();
+    assert!(text.contains("║ WARN low memory"));
+    assert!(text.contains("║ no timestamp"));
+    assert!(!text.contains("2024"));
+}
+
+#[test]
+fn check_follow_mode() {
+    let mut log = LogView::new(layout!("d:f"), logview::Flags::None);
+    for i in 0..20 {
+        log.add_line(&format!("line {i}"));
+    }
+    let (mut driver, handle) = log_window(log, 30, 5);
+    assert!(driver.screen_text().contains("line 19"));
+    assert!(driver.get(handle).unwrap().is_following());
+    // scrolling up stops the follow mode
+    driver.press(key!("PageUp"));
+    assert!(!driver.get(handle).unwrap().is_following());
+    driver.get_mut(handle).unwrap().add_line("line 20");
+    driver.settle();
+    let text = driver.screen_text();
+    assert!(!text.contains("line 20"));
+    assert!(text.contains("line 14"));
+    // reaching the last line enables it again
+    driver.press(key!("End"));
+    assert!(driver.get(handle).unwrap().is_following());
+    driver.get_mut(handle).unwrap().add_line("line 21");
+    driver.settle();
+    assert!(driver.screen_text().contains("line 21"));
+    assert_eq!(driver.get(handle).unwrap().current_line(), Some(21));
+}
+
+#[test]
+fn check_filters() {
+    let mut log = LogView::new(layout!("d:f"), logview::Flags::None);
+    log.add_line("10:00:01 INFO user alice logged in");
+    log.add_line("10:00:02 DEBUG cache miss");
+    log.add_line("10:00:03 WARN user bob: wrong password");
+    log.add_line("10:00:04 ERROR db timeout after 300ms");
+    log.add_line("    at db.query");
+    log.set_filter("USER");
+    assert_eq!(log.visible_count(), 2);
+    log.set_regex_filter("\\d+ms").unwrap();
+    assert_eq!(log.visible_count(), 1);
+    assert!(log.set_regex_filter("(").is_err());
+    assert_eq!(log.filter(), "\\d+ms");
+    log.set_filter("");
+    log.set_min_level(Some(Level::Warning));
+    // the stack trace line inherits the error level
+    assert_eq!(log.visible_count(), 3);
+    // new lines are filtered as they arrive
+    log.add_line("10:00:05 INFO done");
+    log.add_line("10:00:06 FATAL crash");
+    assert_eq!(log.visible_count(), 4);
+    log.clear_filter();
+    assert_eq!(log.visible_count(), 7);
+}
+
+#[test]
+fn check_live_filter_prompt() {
+    let mut log = LogView::new(layout!("d:f"), logview::Flags::None);
+    for i in 0..10 {
+        log.add_line(&format!("INFO item {i}"));
+    }
+    let (mut driver, handle) = log_window(log, 40, 6);
+    driver.press(key!("Ctrl+F"));
+    driver.type_text("item 3");
+    let text = driver.screen_text();
+    assert!(text.contains("Filter: item 3"));
+    assert!(!text.contains("item 4"));
+    assert_eq!(driver.get(handle).unwrap().visible_count(), 1);
+    // escape restores the previous filter
+    driver.press(key!("Escape"));
+    assert_eq!(driver.get(handle).unwrap().visible_count(), 10);
+
+    driver.press(key!("Ctrl+R"));
+    driver.type_text("item [2-4]");
+    assert_eq!(driver.get(handle).unwrap().visible_count(), 3);
+    driver.type_text("(");
+    assert!(driver.screen_text().contains("missing ')'"));
+    // the last valid regular expression is still used
+    assert_eq!(driver.get(handle).unwrap().visible_count(), 3);
+    driver.press(key!("Backspace"));
+    driver.press(key!("Enter"));
+    assert!(driver.screen_text().contains("Regex: item [2-4]  (3 of 10 lines)"));
+    driver.press(key!("Ctrl+L"));
+    assert_eq!(driver.get(handle).unwrap().min_level(), Some(Level::Debug));
+    driver.press(key!("Escape"));
+    assert_eq!(driver.get(handle).unwrap().visible_count(), 10);
+    assert_eq!(driver.get(handle).unwrap().min_level(), None);
+}
+
+#[test]
+fn check_bookmark_navigation() {
+    let mut log = LogView::new(layout!("d:f"), logview::Flags::None);
+    for i in 0..30 {
+        log.add_line(&format!("line {i}"));
+    }
+    log.toggle_bookmark(5);
+    log.toggle_bookmark(20);
+    let (mut driver, handle) = log_window(log, 30, 5);
+    driver.press(key!("Ctrl+B"));
+    assert!(driver.get(handle).unwrap().is_bookmarked(29));
+    driver.press(key!("Shift+F2"));
+    assert_eq!(driver.get(handle).unwrap().current_line(), Some(20));
+    driver.press(key!("Shift+F2"));
+    assert_eq!(driver.get(handle).unwrap().current_line(), Some(5));
+    assert!(!driver.get(handle).unwrap().is_following());
+    assert!(driver.screen_text().contains("●line 5"));
+    driver.press(key!("F2"));
+    assert_eq!(driver.get(handle).unwrap().current_line(), Some(20));
+    driver.press(key!("Ctrl+B"));
+    assert_eq!(driver.get(handle).unwrap().bookmarks(), vec![5, 29]);
+}
+
+#[test]
+fn check_channel_and_file_sources() {
+    let (sender, receiver) = std::sync::mpsc::channel::<String>();
+    let (mut driver, handle) = log_window(LogView::new(layout!("d:f"), logview::Flags::None), 40, 6);
+    driver.get_mut(handle).unwrap().attach_receiver(receiver);
+    sender.send("INFO from channel".to_string()).unwrap();
+    sender.send("two\nlines".to_string()).unwrap();
+    assert!(driver.wait_until(Duration::from_secs(5), |d| d.get(handle).unwrap().len() == 3));
+    assert!(driver.screen_text().contains("INFO from channel"));
+    drop(sender);
+
+    let path = std::env::temp_dir().join(format!("appcui_logview_{}.log", std::process::id()));
+    std::fs::write(&path, "first\nsecond\npart").unwrap();
+    driver.get_mut(handle).unwrap().clear();
+    driver.get_mut(handle).unwrap().follow_file(&path).unwrap();
+    // the incomplete last line is not added
+    assert_eq!(driver.get(handle).unwrap().len(), 2);
+    {
+        use std::io::Write;
+        let mut file = std::fs::OpenOptions::new().append(true).open(&path).unwrap();
+        file.write_all(b"ial\nthird\n").unwrap();
+    }
+    assert!(driver.wait_until(Duration::from_secs(5), |d| d.get(handle).unwrap().len() == 4));
+    assert_eq!(driver.get(handle).unwrap().line(2), Some("partial"));
+    // the file is truncated (rotated)
+    std::fs::write(&path, "new\n").unwrap();
+    assert!(driver.wait_until(Duration::from_secs(5), |d| d.get(handle).unwrap().len() == 5));
+    assert_eq!(driver.get(handle).unwrap().line(4), Some("new"));
+    driver.get_mut(handle).unwrap().detach_sources();
+    let _ = std::fs::remove_file(&path);
+    assert!(LogView::new(layout!("d:f"), logview::Flags::None).follow_file(&path).is_err());
+}
+
+#[test]
+fn check_follow_large_file() {
+    let (mut driver, handle) = log_window(LogView::new(layout!("d:f"), logview::Flags::None), 40, 10);
+    let path = std::env::temp_dir().join(format!("appcui_logview_large_{}.log", std::process::id()));
+    let mut content = String::new();
+    for i in 0..800_000 {
+        content.push_str(&format!("line {i:07}\n"));
+    }
+    std::fs::write(&path, content).unwrap();
+    driver.get_mut(handle).unwrap().clear();
+    driver.get_mut(handle).unwrap().follow_file(&path).unwrap();
+    // only the first chunk is read when the file is attached, the rest is read at every refresh
+    let len = driver.get(handle).unwrap().len();
+    assert!(len > 0 && len < 800_000);
+    assert!(driver.wait_until(Duration::from_secs(10), |d| d.get(handle).unwrap().len() == 800_000));
+    assert_eq!(driver.get(handle).unwrap().line(799_999), Some("line 0799999"));
+    driver.get_mut(handle).unwrap().detach_sources();
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn check_large_buffer() {
+    let mut log = LogView::new(layout!("d:f"), logview::Flags::None);
+    log.set_capacity(100_000);
+    for i in 0..250_000 {
+        log.add_line(if i % 10 == 0 { "ERROR failed" } else { "INFO ok" });
+    }
+    assert_eq!(log.len(), 100_000);
+    assert_eq!(log.total_lines(), 250_000);
+    log.set_min_level(Some(Level::Error));
+    assert_eq!(log.visible_count(), 10_000);
+    for _ in 0..10 {
+        log.add_line("ERROR again");
+    }
+    assert_eq!(log.visible_count(), 10_009);
+}
@@ -0,0 +1,16 @@
+//! A container that binds a master list to a detail view.
+//!
+//! The MasterDetail control shows a master control (a list) on the left side and the details of the current
+//! item of the list on the right side. The detail view is created by a factory function every time the current
+//! item of the master list changes. On narrow terminals the control collapses to a single pane (the master
+//! list or the detail view) and the detail view has a back action.
+
+mod master_list;
+mod masterdetail;
+mod pane;
+#[cfg(test)]
+mod tests;
+
+pub use self::master_list::MasterList;
+pub use self::masterdetail::MasterDetail;
+pub use self::pane::Pane;
@@ -0,0 +1,26 @@
+use crate::prelude::*;
+
+/// A control that can be used as the master list of a [`MasterDetail`](super::MasterDetail) control.
+pub trait MasterList: Control + NotWindow + NotDesktop {
+    /// Returns the index of the current item (or `None` if the list is empty).
+    fn current_index(&self) -> Option<usize>;
+}
+
+impl MasterList for ListBox {
+    fn current_index(&self) -> Option<usize> {
+        if self.count() > 0 {
+            Some(self.index())
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> MasterList for ListView<T>
+where
+    T: ListItem + 'static,
+{
+    fn current_index(&self) -> Option<usize> {
+        self.current_item_index()
+    }
+}
@@ -0,0 +1,395 @@
+use super::{MasterList, Pane};
+use crate::prelude::*;
+use crate::ui::layout::Dimension;
+
+// the width of the back action (" ◄ Back ") displayed when the control is collapsed
+const BACK_ACTION_WIDTH: i32 = 8;
+const DEFAULT_MASTER_WIDTH: Dimension = Dimension::Percentage(0.3);
+
+#[CustomControl(overwrite=OnPaint+OnResize+OnKeyPressed+OnMouseEvent+OnSessionState+OnChildEvent, internal = true)]
+pub struct MasterDetail<M>
+where
+    M: MasterList + 'static,
+{
+    master: Handle<M>,
+    master_pane: Handle<Pane>,
+    detail_pane: Handle<Pane>,
+    factory: fn(master: &M, index: usize, detail: &mut Pane),
+    current: Option<usize>,
+    master_width: Dimension,
+    collapse_width: u16,
+    toggle_key: Key,
+    // the master list is visible (when the control is not collapsed)
+    sidebar: bool,
+    collapsed: bool,
+    // the detail view is the visible pane (when the control is collapsed)
+    detail_mode: bool,
+    back_hovered: bool,
+}
+impl<M> MasterDetail<M>
+where
+    M: MasterList + 'static,
+{
+    /// Creates a new MasterDetail control with the specified master list, detail factory and layout.
+    /// The `factory` function is called every time the current item of the master list changes. It receives
+    /// the master list, the index of its current item and the (empty) pane where the controls of the detail
+    /// view have to be added. If the master list is empty, the detail view remains empty.
+    ///
+    /// By default the master list uses 30% of the width of the control, the control collapses to a single
+    /// pane if it is narrower than 60 characters and `Ctrl+B` shows or hides the master list.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use appcui::prelude::*;
+    ///
+    /// fn create_detail(list: &ListBox, index: usize, detail: &mut masterdetail::Pane) {
+    ///     let text = list.item(index).map(|i| i.text().to_string()).unwrap_or_default();
+    ///     detail.add(Label::new(&text, layout!("x:1,y:1,w:30")));
+    /// }
+    ///
+    /// let mut list = ListBox::new(layout!("d:f"), listbox::Flags::None);
+    /// list.add("First");
+    /// list.add("Second");
+    /// let md = MasterDetail::new(list, create_detail, layout!("d:f"));
+    /// ```
+    pub fn new(master: M, factory: fn(master: &M, index: usize, detail: &mut Pane), layout: Layout) -> Self {
+        let mut md = Self {
+            base: ControlBase::with_status_flags(layout, StatusFlags::Visible | StatusFlags::Enabled | StatusFlags::AcceptInput),
+            master: Handle::None,
+            master_pane: Handle::None,
+            detail_pane: Handle::None,
+            factory,
+            current: None,
+            master_width: DEFAULT_MASTER_WIDTH,
+            collapse_width: 60,
+            toggle_key: Key::from(key!("Ctrl+B")),
+            sidebar: true,
+            collapsed: false,
+            detail_mode: false,
+            back_hovered: false,
+        };
+        md.master_pane = md.add_child(Pane::new());
+        md.detail_pane = md.add_child(Pane::new());
+        if let Some(pane) = RuntimeManager::get().get_control_mut(md.master_pane) {
+            md.master = pane.add(master);
+        }
+        md.update_detail(true);
+        md
+    }
+
+    /// Returns the handle of the master list.
+    #[inline(always)]
+    pub fn master(&self) -> Handle<M> {
+        self.master
+    }
+
+    /// Returns the handle of the pane that contains the controls of the detail view.
+    #[inline(always)]
+    pub fn detail(&self) -> Handle<Pane> {
+        self.detail_pane
+    }
+
+    /// Returns the index of the item (from the master list) the detail view was created for.
+    #[inline(always)]
+    pub fn current_index(&self) -> Option<usize> {
+        self.current
+    }
+
+    /// Re-creates the detail view. This method should be used if the master list was modified from the code
+    /// (for example if its items were changed or if its current item was changed programmatically).
+    pub fn refresh(&mut self) {
+        self.update_detail(true);
+    }
+
+    /// Sets the width of the master list. The value can be a percentage (e.g. a float value) or an absolute
+    /// value (e.g. an unsigned value). The width of the master list can not be computed from its content, so
+    /// `Dimension::Auto` sets the default width (30% of the width of the control).
+    pub fn set_master_width<T>(&mut self, width: T)
+    where
+        Dimension: From<T>,
+    {
+        self.master_width = match width.into() {
+            Dimension::Auto => DEFAULT_MASTER_WIDTH,
+            value => value,
+        };
+        self.update_panes();
+    }
+
+    /// Sets the width (in characters) below which the control collapses to a single pane (the master list or
+    /// the detail view). A value of 0 means that the control never collapses.
+    pub fn set_collapse_width(&mut self, width: u16) {
+        self.collapse_width = width;
+        self.update_panes();
+    }
+
+    /// Sets the key that shows or hides the master list (when the control is collapsed, the key switches
+    /// between the master list and the detail view).
+    #[inline(always)]
+    pub fn set_toggle_key(&mut self, key: Key) {
+        self.toggle_key = key;
+    }
+
+    /// Returns `true` if the control is collapsed to a single pane (its width is smaller than the collapse width).
+    #[inline(always)]
+    pub fn is_collapsed(&self) -> bool {
+        self.collapsed
+    }
+
+    /// Returns `true` if the master list is visible.
+    #[inline(always)]
+    pub fn is_master_visible(&self) -> bool {
+        if self.collapsed {
+            !self.detail_mode
+        } else {
+            self.sidebar
+        }
+    }
+
+    /// Shows the master list (when the control is collapsed, the master list replaces the detail view) and
+    /// moves the focus to it.
+    pub fn show_master(&mut self) {
+        self.detail_mode = false;
+        self.sidebar = true;
+        self.update_panes();
+        RuntimeManager::get().request_focus_for_control(self.master.cast());
+    }
+
+    /// Shows the detail view (when the control is collapsed, the detail view replaces the master list) and
+    /// moves the focus to it.
+    pub fn show_detail(&mut self) {
+        self.detail_mode = true;
+        self.update_panes();
+        RuntimeManager::get().request_focus_for_control(self.detail_pane.cast());
+    }
+
+    /// Shows or hides the master list (the same action as the toggle key).
+    pub fn toggle_master(&mut self) {
+        if self.collapsed {
+            if self.detail_mode {
+                self.show_master();
+            } else {
+                self.show_detail();
+            }
+        } else if self.sidebar {
+            self.sidebar = false;
+            self.update_panes();
+            RuntimeManager::get().request_focus_for_control(self.detail_pane.cast());
+        } else {
+            self.show_master();
+        }
+    }
+
+    fn update_detail(&mut self, force: bool) {
+        let rm = RuntimeManager::get();
+        let index = rm.get_control(self.master).and_then(|m| m.current_index());
+        if (index == self.current) && !force {
+            return;
+        }
+        self.current = index;
+        let master_has_focus = rm.get_controls().get(self.master.cast()).map(|c| c.base().has_focus()).unwrap_or(false);
+        if let Some(pane) = rm.get_control_mut(self.detail_pane) {
+            pane.clear();
+        }
+        if let Some(index) = index {
+            let master = RuntimeManager::get().get_control(self.master);
+            let pane = RuntimeManager::get().get_control_mut(self.detail_pane);
+            if let (Some(master), Some(pane)) = (master, pane) {
+                (self.factory)(master, index, pane);
+            }
+        }
+        // the controls added to the detail view must not move the focus away from the master list
+        if master_has_focus {
+            rm.request_focus_for_control(self.master.cast());
+        }
+    }
+
+    // the column of the line that separates the master list from the detail view
+    fn separator(&self) -> Option<i32> {
+        if self.collapsed || !self.sidebar {
+            return None;
+        }
+        let w = self.size().width as u16;
+        if w < 3 {
+            return None;
+        }
+        Some(self.master_width.absolute(w).clamp(1, w - 2) as i32)
+    }
+
+    fn update_panes(&mut self) {
+        let sz = self.size();
+        let (w, h) = (sz.width as u16, sz.height as u16);
+        self.collapsed = (w as u32) < (self.collapse_width as u32);
+        let (master, detail) = if self.collapsed {
+            if self.detail_mode {
+                (
+                    None,
+                    Some(Rect::with_point_and_size(
+                        Point::new(0, 1),
+                        Size::new(w as u32, h.saturating_sub(1) as u32),
+                    )),
+                )
+            } else {
+                (Some(Rect::with_point_and_size(Point::ORIGIN, sz)), None)
+            }
+        } else if let Some(x) = self.separator() {
+            (
+                Some(Rect::with_point_and_size(Point::ORIGIN, Size::new(x as u32, h as u32))),
+                Some(Rect::with_point_and_size(
+                    Point::new(x + 1, 0),
+                    Size::new((w as i32 - x - 1) as u32, h as u32),
+                )),
+            )
+        } else {
+            (None, Some(Rect::with_point_and_size(Point::ORIGIN, sz)))
+        };
+        Self::place(self.master_pane, master);
+        Self::place(self.detail_pane, detail);
+    }
+
+    fn place(pane: Handle<Pane>, rect: Option<Rect>) {
+        if let Some(p) = RuntimeManager::get().get_control_mut(pane) {
+            if let Some(r) = rect {
+                p.set_position(r.left(), r.top());
+                p.set_size(r.width() as u16, r.height() as u16);
+                p.set_visible(true);
+            } else {
+                p.set_visible(false);
+            }
+        }
+    }
+
+    fn pane_has_focus(pane: Handle<Pane>) -> bool {
+        RuntimeManager::get().get_control(pane).map(|p| p.has_focus()).unwrap_or(false)
+    }
+
+    fn is_over_back_action(&self, x: i32, y: i32) -> bool {
+        self.collapsed && self.detail_mode && (y == 0) && (0..BACK_ACTION_WIDTH).contains(&x)
+    }
+}
+impl<M> OnPaint for MasterDetail<M>
+where
+    M: MasterList + 'static,
+{
+    fn on_paint(&self, surface: &mut Surface, theme: &Theme) {
+        if let Some(x) = self.separator() {
+            let attr = if !self.is_enabled() {
+                theme.lines.inactive
+            } else if self.has_focus() {
+                theme.lines.focused
+            } else {
+                theme.lines.normal
+            };
+            surface.draw_vertical_line_with_size(x, 0, self.size().height, LineType::Single, attr);
+        }
+        if self.collapsed && self.detail_mode {
+            let (attr, sym) = match () {
+                _ if !self.is_enabled() => (theme.header.text.inactive, theme.header.symbol.inactive),
+                _ if self.back_hovered => (theme.header.text.hovered, theme.header.symbol.hovered),
+                _ if self.has_focus() => (theme.header.text.focused, theme.header.symbol.focused),
+                _ => (theme.header.text.normal, theme.header.symbol.normal),
+            };
+            surface.fill_horizontal_line_with_size(0, 0, self.size().width, Character::with_attributes(' ', attr));
+            surface.write_char(1, 0, Character::with_attributes(SpecialChar::TriangleLeft, sym));
+            surface.write_string(3, 0, "Back", attr, false);
+        }
+    }
+}
+impl<M> OnResize for MasterDetail<M>
+where
+    M: MasterList + 'static,
+{
+    fn on_resize(&mut self, _old_size: Size, _new_size: Size) {
+        let collapsed = self.collapsed;
+        self.update_panes();
+        if self.collapsed && !collapsed {
+            // when the control collapses, the pane that has the focus remains visible
+            self.detail_mode = Self::pane_has_focus(self.detail_pane);
+            self.update_panes();
+        }
+    }
+}
+impl<M> OnKeyPressed for MasterDetail<M>
+where
+    M: MasterList + 'static,
+{
+    fn on_key_pressed(&mut self, key: Key, _character: char) -> EventProcessStatus {
+        if key.value() == self.toggle_key.value() {
+            self.toggle_master();
+            return EventProcessStatus::Processed;
+        }
+        if !self.collapsed {
+            return EventProcessStatus::Ignored;
+        }
+        match key.value() {
+            key!("Enter") if !self.detail_mode && self.current.is_some() => {
+                self.show_detail();
+                EventProcessStatus::Processed
+            }
+            key!("Escape") if self.detail_mode => {
+                self.show_master();
+                EventProcessStatus::Processed
+            }
+            _ => EventProcessStatus::Ignored,
+        }
+    }
+}
+impl<M> OnMouseEvent for MasterDetail<M>
+where
+    M: MasterList + 'static,
+{
+    fn on_mouse_event(&mut self, event: &MouseEvent) -> EventProcessStatus {
+        match event {
+            MouseEvent::Enter | MouseEvent::Leave => {
+                if self.back_hovered {
+                    self.back_hovered = false;
+                    EventProcessStatus::Processed
+                } else {
+                    EventProcessStatus::Ignored
+                }
+            }
+            MouseEvent::Over(point) => {
+                let hovered = self.is_over_back_action(point.x, point.y);
+                if hovered != self.back_hovered {
+                    self.back_hovered = hovered;
+                    EventProcessStatus::Processed
+                } else {
+                    EventProcessStatus::Ignored
+                }
+            }
+            MouseEvent::Pressed(evn) => {
+                if self.is_over_back_action(evn.x, evn.y) {
+                    self.back_hovered = false;
+                    self.show_master();
+                    EventProcessStatus::Processed
+                } else {
+                    EventProcessStatus::Ignored
+                }
+            }
+            _ => EventProcessStatus::Ignored,
+        }
+    }
+}
+impl<M> OnChildEvent for MasterDetail<M>
+where
+    M: MasterList + 'static,
+{
+    fn on_child_event(&mut self, handle: Handle<()>) {
+        if handle == self.master.cast::<()>() {
+            self.update_detail(false);
+        }
+    }
+}
+impl<M> OnSessionState for MasterDetail<M>
+where
+    M: MasterList + 'static,
+{
+    fn save_state(&self, state: &mut SessionState) {
+        state.set("sidebar", self.sidebar);
+    }
+    fn restore_state(&mut self, state: &SessionState) {
+        if let Some(sidebar) = state.get_as::<bool>("sidebar") {
+            self.sidebar = sidebar;
+            self.update_panes();
+        }
+    }
+}
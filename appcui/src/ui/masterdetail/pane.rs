@@ -0,0 +1,35 @@
+use crate::prelude::*;
+use crate::utils::VectorIndex;
+
+/// One of the two panes of a [`MasterDetail`](super::MasterDetail) control. The controls of the detail view are
+/// added to a pane (via the `add` method) by the factory function of the MasterDetail control.
+#[CustomControl(internal = true)]
+pub struct Pane {}
+impl Pane {
+    pub(super) fn new() -> Self {
+        Self {
+            base: ControlBase::with_status_flags(
+                LayoutBuilder::new().x(0).y(0).width(1.0f32).height(1.0f32).build(),
+                StatusFlags::Visible | StatusFlags::Enabled | StatusFlags::AcceptInput,
+            ),
+        }
+    }
+
+    /// Adds a new control to the pane and returns a handle towards it.
+    #[inline(always)]
+    pub fn add<T>(&mut self, control: T) -> Handle<T>
+    where
+        T: Control + NotWindow + NotDesktop + 'static,
+    {
+        self.add_child(control)
+    }
+
+    // removes all the controls from the pane
+    pub(super) fn clear(&mut self) {
+        let rm = RuntimeManager::get();
+        for child in std::mem::take(&mut self.base.children) {
+            rm.request_remove(child);
+        }
+        self.base.focused_child_index = VectorIndex::Invalid;
+    }
+}
@@ -0,0 +1,121 @@
+use crate::prelude::*;
+use crate::testing::TestDriver;
+
+fn create_detail(list: &ListBox, index: usize, detail: &mut masterdetail::Pane) {
+    let text = list.item(index).map(|i| i.text().to_string()).unwrap_or_default();
+    detail.add(Button::new(&format!("Open {}", text), layout!("x:1,y:1,w:16"), button::Type::Normal));
+}
+
+fn create_master_detail(driver: &mut TestDriver) -> Handle<MasterDetail<ListBox>> {
+    let mut w = Window::new("Test", layout!("x:0,y:0,w:100%,h:100%"), window::Flags::None);
+    let mut list = ListBox::new(layout!("d:f"), listbox::Flags::None);
+    list.add("Red");
+    list.add("Green");
+    list.add("Blue");
+    let md = w.add(MasterDetail::new(list, create_detail, layout!("d:f")));
+    driver.add_window(w);
+    driver.settle();
+    md
+}
+
+#[test]
+fn check_detail_follows_selection() {
+    let mut driver = TestDriver::new(App::debug(82, 20, "")).unwrap();
+    let md = create_master_detail(&mut driver);
+    let master = driver.get(md).unwrap().master();
+    assert!(!driver.get(md).unwrap().is_collapsed());
+    assert_eq!(driver.get(md).unwrap().current_index(), Some(0));
+    // the master list uses 30% of the client area (80 characters), the separator and then the detail view
+    assert_eq!(driver.bounds(master), Some(Rect::new(1, 1, 24, 18)));
+    let open = driver.find::<Button>().caption("Open Red").single();
+    assert_eq!(driver.bounds(open), Some(Rect::new(27, 2, 42, 3)));
+
+    // changing the current item of the list re-creates the detail view (and the list keeps the focus)
+    driver.focus(master);
+    driver.press(key!("Down"));
+    assert_eq!(driver.get(md).unwrap().current_index(), Some(1));
+    assert_eq!(driver.find::<Button>().caption("Open Red").count(), 0);
+    let open = driver.find::<Button>().caption("Open Green").single();
+    assert!(driver.has_focus(master));
+    assert!(driver.bounds(open).is_some());
+
+    // the controls of the detail view are part of the window (they can receive the focus)
+    driver.press(key!("Tab"));
+    assert!(driver.has_focus(open));
+}
+
+#[test]
+fn check_toggle_sidebar() {
+    let mut driver = TestDriver::new(App::debug(82, 20, "")).unwrap();
+    let md = create_master_detail(&mut driver);
+    let master = driver.get(md).unwrap().master();
+    driver.focus(master);
+    driver.press(key!("Ctrl+B"));
+    assert!(!driver.get(md).unwrap().is_master_visible());
+    assert_eq!(driver.bounds(master), None);
+    // the detail view uses the entire control
+    let open = driver.find::<Button>().caption("Open Red").single();
+    assert_eq!(driver.bounds(open), Some(Rect::new(2, 2, 17, 3)));
+    assert!(driver.has_focus(open));
+
+    driver.press(key!("Ctrl+B"));
+    assert!(driver.get(md).unwrap().is_master_visible());
+    assert!(driver.has_focus(master));
+    assert_eq!(driver.bounds(master), Some(Rect::new(1, 1, 24, 18)));
+}
+
+#[test]
+fn check_collapse_on_narrow_terminals() {
+    let mut driver = TestDriver::new(App::debug(82, 20, "")).unwrap();
+    let md = create_master_detail(&mut driver);
+    let master = driver.get(md).unwrap().master();
+    driver.focus(master);
+    driver.resize(42, 20);
+    driver.settle();
+    assert!(driver.get(md).unwrap().is_collapsed());
+    // only the master list is visible
+    assert_eq!(driver.bounds(master), Some(Rect::new(1, 1, 40, 18)));
+    assert_eq!(driver.find::<Button>().caption("Open Red").visible().count(), 0);
+
+    // Enter opens the detail view (with a back action on its first line)
+    driver.press(key!("Down"));
+    driver.press(key!("Enter"));
+    assert!(!driver.get(md).unwrap().is_master_visible());
+    assert_eq!(driver.bounds(master), None);
+    let open = driver.find::<Button>().caption("Open Green").single();
+    assert_eq!(driver.bounds(open), Some(Rect::new(2, 3, 17, 4)));
+    assert!(driver.has_focus(open));
+    assert!(driver.screen_text().lines().nth(1).unwrap().contains("Back"));
+
+    // Escape goes back to the master list
+    driver.press(key!("Escape"));
+    assert!(driver.get(md).unwrap().is_master_visible());
+    assert!(driver.has_focus(master));
+
+    // the back action can also be clicked
+    driver.press(key!("Enter"));
+    assert!(!driver.get(md).unwrap().is_master_visible());
+    driver.click_at(3, 1);
+    assert!(driver.get(md).unwrap().is_master_visible());
+
+    // a wide terminal shows both panes
+    driver.resize(82, 20);
+    driver.settle();
+    assert!(!driver.get(md).unwrap().is_collapsed());
+    assert!(driver.bounds(master).is_some());
+    assert!(driver.bounds(driver.find::<Button>().caption("Open Green").single()).is_some());
+}
+
+#[test]
+fn check_auto_master_width() {
+    let mut driver = TestDriver::new(App::debug(82, 20, "")).unwrap();
+    let md = create_master_detail(&mut driver);
+    let master = driver.get(md).unwrap().master();
+    driver.get_mut(md).unwrap().set_master_width(10);
+    driver.settle();
+    assert_eq!(driver.bounds(master), Some(Rect::new(1, 1, 10, 18)));
+    // the width of the master list can not be computed from its content (the default width is used)
+    driver.get_mut(md).unwrap().set_master_width(layout::Dimension::Auto);
+    driver.settle();
+    assert_eq!(driver.bounds(master), Some(Rect::new(1, 1, 24, 18)));
+}
@@ -7,6 +7,7 @@ use crate::{
     input::{Key, KeyCode, MouseWheelDirection},
     prelude::KeyModifier,
     system::{Handle, HandleSupport, RuntimeManager, Theme},
+    ui::command_palette,
     ui::common::traits::EventProcessStatus,
     utils::{Strategy, VectorIndex},
 };
@@ -256,6 +257,50 @@ impl Menu {
         self.receiver_control_handle = handle;
     }
 
+    // removes all items (used by controls that rebuild a menu every time it is opened)
+    pub(crate) fn clear(&mut self) {
+        self.items.clear();
+        self.current = VectorIndex::Invalid;
+        self.first_visible_item = 0;
+    }
+
+    // adds the enabled items of the menu (and of its sub-menus) to the entries of the command palette
+    pub(crate) fn add_command_palette_entries(&self, path: &str, receiver_control_handle: Handle<()>, output: &mut Vec<command_palette::Entry>) {
+        for (index, item) in self.items.iter().enumerate() {
+            if !item.is_enabled() {
+                continue;
+            }
+            let (caption, shortcut) = match item {
+                MenuItemWrapper::Command(obj) => (&obj.caption, obj.shortcut),
+                MenuItemWrapper::CheckBox(obj) => (&obj.caption, obj.shortcut),
+                MenuItemWrapper::SingleChoice(obj) => (&obj.caption, obj.shortcut),
+                MenuItemWrapper::Separator(_) => continue,
+                MenuItemWrapper::SubMenu(obj) => {
+                    if let Some(menu) = RuntimeManager::get().get_menu(obj.submenu_handle) {
+                        menu.add_command_palette_entries(&format!("{} > {}", path, obj.caption.text()), receiver_control_handle, output);
+                    }
+                    continue;
+                }
+            };
+            output.push(command_palette::Entry::new(
+                &format!("{} > {}", path, caption.text()),
+                command_palette::Source::Menu,
+                shortcut,
+                command_palette::Trigger::MenuItem {
+                    menu: self.handle,
+                    index,
+                    receiver: receiver_control_handle,
+                },
+            ));
+        }
+    }
+
+    // runs the action of an item (as if it was clicked)
+    #[inline(always)]
+    pub(crate) fn execute_item(&mut self, index: usize, receiver_control_handle: Handle<()>) {
+        self.run_item_action(index, receiver_control_handle);
+    }
+
     pub(crate) fn update_menuitems_menu_handle(&mut self) {
         for item in self.items.iter_mut() {
             item.update_menu_handle(self.handle);
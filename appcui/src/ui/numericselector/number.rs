@@ -6,6 +6,10 @@ use std::str::FromStr;
 
 pub trait Number: Add<Output = Self> + Sub<Output = Self> + Copy + Clone + PartialOrd + PartialEq + Display + FromStr {
     fn write_to_string(&self, writer: &mut String, format: Format);
+    /// Converts the value into a `f64` (used to map a value to a position, for example by a slider)
+    fn to_f64(&self) -> f64;
+    /// Creates a value from a `f64` (integer types round the value to the closest integer)
+    fn from_f64(value: f64) -> Self;
 }
 
 const DECIMAL_FORMAT: FormatNumber = FormatNumber::new(10);
@@ -101,69 +105,153 @@ impl Number for i8 {
     fn write_to_string(&self, writer: &mut String, format: Format) {
         format_signed_number(*self as i128, format, writer)
     }
+    fn to_f64(&self) -> f64 {
+        *self as f64
+    }
+    fn from_f64(value: f64) -> Self {
+        value.round() as i8
+    }
 }
 impl Number for i16 {
     fn write_to_string(&self, writer: &mut String, format: Format) {
         format_signed_number(*self as i128, format, writer)
     }
+    fn to_f64(&self) -> f64 {
+        *self as f64
+    }
+    fn from_f64(value: f64) -> Self {
+        value.round() as i16
+    }
 }
 impl Number for i32 {
     fn write_to_string(&self, writer: &mut String, format: Format) {
         format_signed_number(*self as i128, format, writer)
     }
+    fn to_f64(&self) -> f64 {
+        *self as f64
+    }
+    fn from_f64(value: f64) -> Self {
+        value.round() as i32
+    }
 }
 impl Number for i64 {
     fn write_to_string(&self, writer: &mut String, format: Format) {
         format_signed_number(*self as i128, format, writer)
     }
+    fn to_f64(&self) -> f64 {
+        *self as f64
+    }
+    fn from_f64(value: f64) -> Self {
+        value.round() as i64
+    }
 }
 impl Number for i128 {
     fn write_to_string(&self, writer: &mut String, format: Format) {
         format_signed_number(*self, format, writer)
     }
+    fn to_f64(&self) -> f64 {
+        *self as f64
+    }
+    fn from_f64(value: f64) -> Self {
+        value.round() as i128
+    }
 }
 impl Number for u8 {
     fn write_to_string(&self, writer: &mut String, format: Format) {
         format_unsigned_number(*self as u128, format, writer)
     }
+    fn to_f64(&self) -> f64 {
+        *self as f64
+    }
+    fn from_f64(value: f64) -> Self {
+        value.round() as u8
+    }
 }
 impl Number for u16 {
     fn write_to_string(&self, writer: &mut String, format: Format) {
         format_unsigned_number(*self as u128, format, writer)
     }
+    fn to_f64(&self) -> f64 {
+        *self as f64
+    }
+    fn from_f64(value: f64) -> Self {
+        value.round() as u16
+    }
 }
 impl Number for u32 {
     fn write_to_string(&self, writer: &mut String, format: Format) {
         format_unsigned_number(*self as u128, format, writer)
     }
+    fn to_f64(&self) -> f64 {
+        *self as f64
+    }
+    fn from_f64(value: f64) -> Self {
+        value.round() as u32
+    }
 }
 impl Number for u64 {
     fn write_to_string(&self, writer: &mut String, format: Format) {
         format_unsigned_number(*self as u128, format, writer)
     }
+    fn to_f64(&self) -> f64 {
+        *self as f64
+    }
+    fn from_f64(value: f64) -> Self {
+        value.round() as u64
+    }
 }
 impl Number for u128 {
     fn write_to_string(&self, writer: &mut String, format: Format) {
         format_unsigned_number(*self, format, writer)
     }
+    fn to_f64(&self) -> f64 {
+        *self as f64
+    }
+    fn from_f64(value: f64) -> Self {
+        value.round() as u128
+    }
 }
 impl Number for usize {
     fn write_to_string(&self, writer: &mut String, format: Format) {
         format_unsigned_number(*self as u128, format, writer)
     }
+    fn to_f64(&self) -> f64 {
+        *self as f64
+    }
+    fn from_f64(value: f64) -> Self {
+        value.round() as usize
+    }
 }
 impl Number for isize {
     fn write_to_string(&self, writer: &mut String, format: Format) {
         format_signed_number(*self as i128, format, writer)
     }
+    fn to_f64(&self) -> f64 {
+        *self as f64
+    }
+    fn from_f64(value: f64) -> Self {
+        value.round() as isize
+    }
 }
 impl Number for f32 {
     fn write_to_string(&self, writer: &mut String, format: Format) {
         format_float_number(*self as f64, format, writer)
     }
+    fn to_f64(&self) -> f64 {
+        *self as f64
+    }
+    fn from_f64(value: f64) -> Self {
+        value as f32
+    }
 }
 impl Number for f64 {
     fn write_to_string(&self, writer: &mut String, format: Format) {
         format_float_number(*self, format, writer)
     }
+    fn to_f64(&self) -> f64 {
+        *self
+    }
+    fn from_f64(value: f64) -> Self {
+        value
+    }
 }
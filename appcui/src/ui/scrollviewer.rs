@@ -0,0 +1,14 @@
+//! A container that hosts its children on a virtual canvas larger than itself.
+//!
+//! The ScrollViewer control positions its children on a canvas (of a fixed size or of the size of its content)
+//! and shows only the part of the canvas that fits in its client area. The visible part can be changed with the
+//! scrollbars, the mouse wheel or the keyboard and it follows the focused child automatically.
+
+mod initialization_flags;
+mod scroll_item;
+mod scrollviewer;
+#[cfg(test)]
+mod tests;
+
+pub use self::initialization_flags::Flags;
+pub use self::scrollviewer::ScrollViewer;
@@ -0,0 +1,6 @@
+#[repr(u8)]
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub enum Flags {
+    None,
+    ScrollBars,
+}
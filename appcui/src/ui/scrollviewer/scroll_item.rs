@@ -0,0 +1,64 @@
+use crate::prelude::*;
+use crate::ui::layout::ControlLayout;
+
+// a child of a ScrollViewer
+// the original layout of the child is kept as it is evaluated relative to the canvas on every re-flow
+pub(super) struct ScrollItem {
+    handle: Handle<()>,
+    layout: ControlLayout,
+    // position on the canvas (computed on the last re-flow)
+    pub(super) origin: Point,
+}
+
+impl ScrollItem {
+    pub(super) fn new<T>(handle: Handle<T>) -> Self {
+        let handle = handle.cast();
+        let layout = match RuntimeManager::get().get_controls_mut().get(handle) {
+            Some(control) => control.base().declared_layout(),
+            None => ControlLayout::default(),
+        };
+        Self {
+            handle,
+            layout,
+            origin: Point::default(),
+        }
+    }
+    #[inline(always)]
+    pub(super) fn handle(&self) -> Handle<()> {
+        self.handle
+    }
+    // the stored layout or the alternative layout selected by a breakpoint
+    fn layout(&self) -> ControlLayout {
+        RuntimeManager::get().get_controls().effective_layout(self.handle, &self.layout)
+    }
+    pub(super) fn is_visible(&self) -> bool {
+        RuntimeManager::get()
+            .get_controls_mut()
+            .get(self.handle)
+            .map(|c| c.base().is_visible())
+            .unwrap_or(false)
+    }
+    // the space (position + size) required by the child on the canvas
+    pub(super) fn extent(&self, available: Size) -> Size {
+        RuntimeManager::get().get_controls().measured_extent(self.handle, &self.layout(), available)
+    }
+    // computes the position and size of the child on a canvas of the given size
+    // and moves the child so that the point (offset) of the canvas is the top-left corner of the viewer
+    pub(super) fn place(&mut self, canvas: Size, offset: Point) {
+        let width = canvas.width.min(u16::MAX as u32) as u16;
+        let height = canvas.height.min(u16::MAX as u32) as u16;
+        let mut layout = self.layout();
+        if layout.has_auto_size() {
+            if let Some(sz) = RuntimeManager::get().get_controls().measure(self.handle, canvas) {
+                layout.set_content_size(sz);
+            }
+        }
+        let (pos, size) = layout.preferred_bounds(width, height);
+        self.origin = pos;
+        if let Some(control) = RuntimeManager::get().get_controls_mut().get_mut(self.handle) {
+            let base = control.base_mut();
+            base.set_position(pos.x - offset.x, pos.y - offset.y);
+            base.set_size(size.width as u16, size.height as u16);
+        }
+    }
+}
@@ -0,0 +1,284 @@
+use super::initialization_flags::Flags;
+use super::scroll_item::ScrollItem;
+use crate::prelude::*;
+
+use self::components::ScrollBars;
+
+#[CustomControl(overwrite=OnPaint+OnResize+OnKeyPressed+OnMouseEvent+OnFocus+OnMeasure, internal = true)]
+pub struct ScrollViewer {
+    items: Vec<ScrollItem>,
+    // explicit size of the canvas (a size of 0x0 means that the canvas has the size of its content)
+    canvas: Size,
+    // the size of the canvas computed on the last re-flow
+    extent: Size,
+    offset: Point,
+    flags: Flags,
+    scrollbars: ScrollBars,
+}
+impl ScrollViewer {
+    /// Creates a new ScrollViewer control with the specified layout and flags.
+    /// The flags can be one of the following values:
+    /// * `Flags::None` - the content can be scrolled only with the keyboard or the mouse wheel
+    /// * `Flags::ScrollBars` - the viewer has a horizontal and a vertical scrollbar
+    ///
+    /// By default the virtual canvas has the size of its content (the extent of all visible children). Use
+    /// `set_canvas_size` to use a fixed size instead.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use appcui::prelude::*;
+    ///
+    /// let mut sv = ScrollViewer::new(layout!("d:f"), scrollviewer::Flags::ScrollBars);
+    /// sv.add(button!("'First',x:1,y:1,w:15"));
+    /// sv.add(button!("'Last',x:1,y:40,w:15"));
+    /// ```
+    pub fn new(layout: Layout, flags: Flags) -> Self {
+        let mut sv = Self {
+            base: ControlBase::with_status_flags(layout, StatusFlags::Visible | StatusFlags::Enabled | StatusFlags::AcceptInput),
+            items: Vec::with_capacity(8),
+            canvas: Size::default(),
+            extent: Size::default(),
+            offset: Point::default(),
+            flags,
+            scrollbars: ScrollBars::new(flags == Flags::ScrollBars),
+        };
+        if flags == Flags::ScrollBars {
+            sv.base.set_margins(0, 0, 1, 1);
+        }
+        sv
+    }
+
+    /// Adds a new control to the viewer and returns a handle towards it. The layout of the control is computed
+    /// relative to the virtual canvas (and not relative to the visible area of the viewer).
+    pub fn add<T>(&mut self, control: T) -> Handle<T>
+    where
+        T: Control + NotWindow + NotDesktop + 'static,
+    {
+        let h = self.add_child(control);
+        if !h.is_none() {
+            self.items.push(ScrollItem::new(h));
+            self.reflow();
+        }
+        h
+    }
+
+    /// Sets the size of the virtual canvas. A size of 0x0 means that the canvas will have the size of its
+    /// content. The canvas is never smaller than the visible area of the viewer.
+    pub fn set_canvas_size(&mut self, size: Size) {
+        self.canvas = size;
+        self.reflow();
+    }
+
+    /// Returns the size of the virtual canvas (as computed on the last re-flow).
+    #[inline(always)]
+    pub fn canvas_size(&self) -> Size {
+        self.extent
+    }
+
+    /// Returns the point of the virtual canvas that is displayed in the top-left corner of the viewer.
+    #[inline(always)]
+    pub fn scroll_offset(&self) -> Point {
+        self.offset
+    }
+
+    /// Scrolls the content so that the point (x,y) of the virtual canvas is displayed in the top-left corner
+    /// of the viewer. The coordinates are clamped so that the viewer never scrolls past the margins of the canvas.
+    pub fn scroll_to(&mut self, x: i32, y: i32) {
+        let client = self.client_size();
+        let max_x = self.extent.width.saturating_sub(client.width) as i32;
+        let max_y = self.extent.height.saturating_sub(client.height) as i32;
+        let offset = Point::new(x.clamp(0, max_x), y.clamp(0, max_y));
+        self.scrollbars.set_indexes(offset.x as u64, offset.y as u64);
+        if offset != self.offset {
+            self.offset = offset;
+            self.update_positions();
+        }
+    }
+
+    /// Scrolls the content (if needed) so that a child of the viewer (or any control within a child of the viewer)
+    /// becomes visible. If the control is larger than the visible area, its top-left corner is made visible.
+    pub fn ensure_visible<T>(&mut self, handle: Handle<T>) {
+        if let Some(r) = self.canvas_rect(handle.cast()) {
+            let client = self.client_size();
+            let mut x = self.offset.x;
+            let mut y = self.offset.y;
+            if r.right() >= x + client.width as i32 {
+                x = r.right() + 1 - client.width as i32;
+            }
+            if r.left() < x {
+                x = r.left();
+            }
+            if r.bottom() >= y + client.height as i32 {
+                y = r.bottom() + 1 - client.height as i32;
+            }
+            if r.top() < y {
+                y = r.top();
+            }
+            self.scroll_to(x, y);
+        }
+    }
+
+    /// Sets the padding (the empty space between the margins of the viewer and its visible area).
+    /// If the viewer has scrollbars, the right and bottom padding can not be smaller than 1.
+    pub fn set_padding(&mut self, left: u8, top: u8, right: u8, bottom: u8) {
+        if self.flags == Flags::ScrollBars {
+            self.base.set_margins(left, top, right.max(1), bottom.max(1));
+        } else {
+            self.base.set_margins(left, top, right, bottom);
+        }
+        self.reflow();
+    }
+
+    /// Re-computes the size of the virtual canvas and the position and size of all children. This is done automatically
+    /// when the viewer is resized or when a child is added, but it needs to be called if the visibility or the size of
+    /// a child was changed.
+    pub fn reflow(&mut self) {
+        let client = self.client_size();
+        if (client.width == 0) || (client.height == 0) {
+            return;
+        }
+        let content = if (self.canvas.width == 0) && (self.canvas.height == 0) {
+            self.content_size(client)
+        } else {
+            self.canvas
+        };
+        self.extent = Size::new(content.width.max(client.width), content.height.max(client.height));
+        for item in self.items.iter_mut().filter(|i| i.is_visible()) {
+            item.place(self.extent, self.offset);
+        }
+        self.scrollbars.resize(self.extent.width as u64, self.extent.height as u64, &self.base);
+        self.scroll_to(self.offset.x, self.offset.y);
+    }
+
+    // the size of the content (the extent of all visible children)
+    fn content_size(&self, available: Size) -> Size {
+        let mut result = Size::default();
+        for item in self.items.iter().filter(|i| i.is_visible()) {
+            let extent = item.extent(available);
+            result.width = result.width.max(extent.width);
+            result.height = result.height.max(extent.height);
+        }
+        result
+    }
+
+    // moves the children without re-computing their layout (the scroll offset was changed)
+    fn update_positions(&mut self) {
+        let controls = RuntimeManager::get().get_controls_mut();
+        for item in self.items.iter() {
+            if let Some(control) = controls.get_mut(item.handle()) {
+                control
+                    .base_mut()
+                    .set_position(item.origin.x - self.offset.x, item.origin.y - self.offset.y);
+            }
+        }
+    }
+
+    // the rectangle (in canvas coordinates) of a control that is a child of the viewer or a descendant of a child
+    fn canvas_rect(&self, handle: Handle<()>) -> Option<Rect> {
+        let controls = RuntimeManager::get().get_controls();
+        let me = self.base.handle.cast::<()>();
+        let size = controls.get(handle)?.base().size();
+        let mut x = 0;
+        let mut y = 0;
+        let mut h = handle;
+        loop {
+            let base = controls.get(h)?.base();
+            if base.parent == me {
+                let item = self.items.iter().find(|i| i.handle() == h)?;
+                x += item.origin.x;
+                y += item.origin.y;
+                break;
+            }
+            let parent = controls.get(base.parent)?.base();
+            x += base.layout.x() + parent.margins.left as i32;
+            y += base.layout.y() + parent.margins.top as i32;
+            h = base.parent;
+        }
+        Some(Rect::with_point_and_size(
+            Point::new(x, y),
+            Size::new(size.width.max(1), size.height.max(1)),
+        ))
+    }
+}
+
+impl OnResize for ScrollViewer {
+    fn on_resize(&mut self, _old_size: Size, _new_size: Size) {
+        self.reflow();
+    }
+    fn on_children_layout_changed(&mut self) {
+        self.reflow();
+    }
+}
+
+impl OnPaint for ScrollViewer {
+    fn on_paint(&self, surface: &mut Surface, theme: &Theme) {
+        if self.flags == Flags::ScrollBars {
+            self.scrollbars.paint(surface, theme, self);
+        }
+    }
+}
+
+impl OnKeyPressed for ScrollViewer {
+    fn on_key_pressed(&mut self, key: Key, _character: char) -> EventProcessStatus {
+        let client = self.client_size();
+        let (x, y) = (self.offset.x, self.offset.y);
+        match key.value() {
+            key!("PageUp") => self.scroll_to(x, y - client.height as i32),
+            key!("PageDown") => self.scroll_to(x, y + client.height as i32),
+            key!("Shift+PageUp") => self.scroll_to(x - client.width as i32, y),
+            key!("Shift+PageDown") => self.scroll_to(x + client.width as i32, y),
+            key!("Ctrl+Home") => self.scroll_to(0, 0),
+            key!("Ctrl+End") => self.scroll_to(i32::MAX, i32::MAX),
+            _ => return EventProcessStatus::Ignored,
+        }
+        EventProcessStatus::Processed
+    }
+}
+
+impl OnMouseEvent for ScrollViewer {
+    fn on_mouse_event(&mut self, event: &MouseEvent) -> EventProcessStatus {
+        if self.scrollbars.process_mouse_event(event) {
+            self.scroll_to(self.scrollbars.horizontal_index() as i32, self.scrollbars.vertical_index() as i32);
+            return EventProcessStatus::Processed;
+        }
+        let response = match event {
+            MouseEvent::Wheel(dir) => {
+                let (x, y) = (self.offset.x, self.offset.y);
+                match dir {
+                    MouseWheelDirection::Left => self.scroll_to(x - 1, y),
+                    MouseWheelDirection::Right => self.scroll_to(x + 1, y),
+                    MouseWheelDirection::Up => self.scroll_to(x, y - 1),
+                    MouseWheelDirection::Down => self.scroll_to(x, y + 1),
+                };
+                EventProcessStatus::Processed
+            }
+            _ => EventProcessStatus::Ignored,
+        };
+        if self.scrollbars.should_repaint() {
+            EventProcessStatus::Processed
+        } else {
+            response
+        }
+    }
+}
+
+impl OnFocus for ScrollViewer {
+    fn on_child_focus(&mut self, handle: Handle<()>) {
+        self.ensure_visible(handle);
+    }
+}
+
+impl OnMeasure for ScrollViewer {
+    fn preferred_size(&self, available: Size) -> Option<Size> {
+        let content = if (self.canvas.width == 0) && (self.canvas.height == 0) {
+            self.content_size(available)
+        } else {
+            self.canvas
+        };
+        let m = &self.base.margins;
+        Some(Size::new(
+            content.width + m.left as u32 + m.right as u32,
+            content.height + m.top as u32 + m.bottom as u32,
+        ))
+    }
+}
@@ -0,0 +1,83 @@
+use crate::prelude::*;
+use crate::testing::TestDriver;
+
+#[test]
+fn check_placement_on_canvas() {
+    let mut driver = TestDriver::new(App::debug(60, 20, "")).unwrap();
+    // window client area: (1,1) - 38x10
+    let mut w = Window::new("Test", layout!("x:0,y:0,w:40,h:12"), window::Flags::None);
+    let mut sv = ScrollViewer::new(layout!("d:f"), scrollviewer::Flags::None);
+    let a = sv.add(Button::new("A", layout!("x:1,y:1,w:10"), button::Type::Normal));
+    let b = sv.add(Button::new("B", layout!("x:1,y:30,w:10"), button::Type::Normal));
+    let sv = w.add(sv);
+    driver.add_window(w);
+    driver.settle();
+    assert_eq!(driver.bounds(a), Some(Rect::new(2, 2, 11, 3)));
+    // the canvas has the size of its content (but it is never smaller than the viewer)
+    let viewer = driver.get(sv).unwrap();
+    assert_eq!(viewer.canvas_size(), Size::new(38, 32));
+    assert_eq!(viewer.scroll_offset(), Point::new(0, 0));
+    // the second button is outside the visible area
+    assert_eq!(driver.bounds(b), None);
+
+    // a fixed canvas size
+    driver.get_mut(sv).unwrap().set_canvas_size(Size::new(100, 50));
+    driver.settle();
+    assert_eq!(driver.get(sv).unwrap().canvas_size(), Size::new(100, 50));
+}
+
+#[test]
+fn check_auto_scroll_to_focused_child() {
+    let mut driver = TestDriver::new(App::debug(60, 20, "")).unwrap();
+    let mut w = Window::new("Test", layout!("x:0,y:0,w:40,h:12"), window::Flags::None);
+    let mut sv = ScrollViewer::new(layout!("d:f"), scrollviewer::Flags::None);
+    let a = sv.add(Button::new("A", layout!("x:1,y:1,w:10"), button::Type::Normal));
+    let b = sv.add(Button::new("B", layout!("x:1,y:30,w:10"), button::Type::Normal));
+    let sv = w.add(sv);
+    driver.add_window(w);
+    driver.settle();
+    driver.focus(a);
+    assert!(driver.has_focus(a));
+
+    // tab to a child that is outside the visible area --> its bottom edge becomes the last visible line
+    driver.press(key!("Tab"));
+    assert!(driver.has_focus(b));
+    assert_eq!(driver.get(sv).unwrap().scroll_offset(), Point::new(0, 22));
+    assert_eq!(driver.bounds(b), Some(Rect::new(2, 9, 11, 10)));
+
+    // back to the first child --> it becomes the first visible line
+    driver.press(key!("Tab"));
+    assert!(driver.has_focus(a));
+    assert_eq!(driver.get(sv).unwrap().scroll_offset(), Point::new(0, 1));
+    assert_eq!(driver.bounds(a), Some(Rect::new(2, 1, 11, 2)));
+}
+
+#[test]
+fn check_keyboard_paging() {
+    let mut driver = TestDriver::new(App::debug(60, 20, "")).unwrap();
+    let mut w = Window::new("Test", layout!("x:0,y:0,w:40,h:12"), window::Flags::None);
+    let mut sv = ScrollViewer::new(layout!("d:f"), scrollviewer::Flags::ScrollBars);
+    let a = sv.add(Button::new("A", layout!("x:1,y:1,w:10"), button::Type::Normal));
+    sv.set_canvas_size(Size::new(80, 40));
+    let sv = w.add(sv);
+    driver.add_window(w);
+    driver.settle();
+    driver.focus(a);
+    // with scrollbars the visible area is 37x9
+    driver.press(key!("PageDown"));
+    assert_eq!(driver.get(sv).unwrap().scroll_offset(), Point::new(0, 9));
+    driver.press(key!("Shift+PageDown"));
+    assert_eq!(driver.get(sv).unwrap().scroll_offset(), Point::new(37, 9));
+    driver.press(key!("Ctrl+End"));
+    assert_eq!(driver.get(sv).unwrap().scroll_offset(), Point::new(43, 31));
+    driver.press(key!("PageUp"));
+    assert_eq!(driver.get(sv).unwrap().scroll_offset(), Point::new(43, 22));
+    driver.press(key!("Ctrl+Home"));
+    assert_eq!(driver.get(sv).unwrap().scroll_offset(), Point::new(0, 0));
+
+    // scroll_to is clamped to the canvas
+    driver.get_mut(sv).unwrap().scroll_to(-5, 1000);
+    driver.settle();
+    assert_eq!(driver.get(sv).unwrap().scroll_offset(), Point::new(0, 31));
+    assert_eq!(driver.bounds(a), None);
+}
@@ -0,0 +1,19 @@
+//! Slider controls for selecting a value (or a range of values) from an interval.
+//!
+//! The Slider control displays a track with a thumb that can be moved with the mouse or with the keyboard.
+//! The RangeSlider control has two thumbs that delimit a range. Both controls support horizontal and vertical
+//! orientations, tick marks, labels and the same value formats as the NumericSelector control.
+
+pub mod events;
+mod initialization_flags;
+mod rangeslider;
+mod scale;
+mod slider;
+#[cfg(test)]
+mod tests;
+
+pub use self::initialization_flags::Flags;
+pub use self::rangeslider::RangeSlider;
+use self::scale::Scale;
+pub use self::slider::Slider;
+pub use crate::ui::numericselector::Format;
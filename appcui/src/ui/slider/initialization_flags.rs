@@ -0,0 +1,10 @@
+use EnumBitFlags::EnumBitFlags;
+
+#[EnumBitFlags(bits = 8)]
+pub enum Flags {
+    Vertical = 0x0001,
+    ShowTicks = 0x0002,
+    ShowLabels = 0x0004,
+    ShowValue = 0x0008,
+    ReadOnly = 0x0010,
+}
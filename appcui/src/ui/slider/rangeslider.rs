@@ -0,0 +1,300 @@
+use super::events::EventData;
+use super::Flags;
+use super::Format;
+use super::Scale;
+use crate::prelude::*;
+
+#[CustomControl(overwrite=OnPaint+OnKeyPressed+OnMouseEvent+OnResize, internal=true)]
+pub struct RangeSlider<T>
+where
+    T: Number + 'static,
+{
+    start: T,
+    end: T,
+    scale: Scale<T>,
+    txt: String,
+    end_thumb: bool,
+    dragging: bool,
+}
+impl<T> RangeSlider<T>
+where
+    T: Number + 'static,
+{
+    /// Creates a new RangeSlider control with the specified range (`start` and `end`), interval, step, layout, flags
+    /// and the default format (Decimal). The flags are the same as the ones of the Slider control:
+    /// * `Flags::Vertical` - the slider is vertical (the minimum value is at the bottom)
+    /// * `Flags::ShowTicks` - tick marks are displayed along the track
+    /// * `Flags::ShowLabels` - the values of the tick marks are displayed
+    /// * `Flags::ShowValue` - the selected range is displayed next to the track
+    /// * `Flags::ReadOnly` - the range can not be changed by the user
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use appcui::prelude::*;
+    ///
+    /// let r = RangeSlider::<i32>::new(20, 80, 0, 100, 1, layout!("x:1,y:1,w:30"), slider::Flags::ShowValue);
+    /// ```
+    pub fn new(start: T, end: T, min: T, max: T, step: T, layout: Layout, flags: Flags) -> Self {
+        Self::with_format(start, end, min, max, step, layout, flags, Format::Decimal)
+    }
+
+    /// Creates a new RangeSlider control with the specified range (`start` and `end`), interval, step, layout, flags and format.
+    /// The format (shared with the NumericSelector control) is used for the labels and for the selected range.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use appcui::prelude::*;
+    ///
+    /// let r = RangeSlider::<u64>::with_format(1024,
+    ///                                         65536,
+    ///                                         0,
+    ///                                         1048576,
+    ///                                         1024,
+    ///                                         layout!("x:1,y:1,w:40"),
+    ///                                         slider::Flags::ShowValue,
+    ///                                         slider::Format::Size);
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn with_format(start: T, end: T, min: T, max: T, step: T, layout: Layout, flags: Flags, format: Format) -> Self {
+        let scale = Scale::new(min, max, step, format, flags, 2);
+        let mut obj = Self {
+            base: ControlBase::with_status_flags(layout, StatusFlags::Visible | StatusFlags::Enabled | StatusFlags::AcceptInput),
+            start: scale.min,
+            end: scale.max,
+            scale,
+            txt: String::new(),
+            end_thumb: false,
+            dragging: false,
+        };
+        obj.update_size_bounds();
+        obj.set_range(start, end);
+        obj
+    }
+
+    /// Returns the start of the selected range
+    #[inline(always)]
+    pub fn start(&self) -> T {
+        self.start
+    }
+
+    /// Returns the end of the selected range
+    #[inline(always)]
+    pub fn end(&self) -> T {
+        self.end
+    }
+
+    /// Returns the selected range as a `(start, end)` tuple
+    #[inline(always)]
+    pub fn range(&self) -> (T, T) {
+        (self.start, self.end)
+    }
+
+    /// Sets the selected range. The values are clamped to the interval [min, max] and swapped if `start` is bigger than `end`.
+    pub fn set_range(&mut self, start: T, end: T) {
+        let (start, end) = if start <= end { (start, end) } else { (end, start) };
+        self.start = self.scale.clamp(start);
+        self.end = self.scale.clamp(end);
+        self.update_string_representation();
+    }
+
+    /// Returns the minimum value of the slider
+    #[inline(always)]
+    pub fn min(&self) -> T {
+        self.scale.min
+    }
+
+    /// Returns the maximum value of the slider
+    #[inline(always)]
+    pub fn max(&self) -> T {
+        self.scale.max
+    }
+
+    /// Returns the value that is added (or subtracted) when an arrow key is pressed
+    #[inline(always)]
+    pub fn step(&self) -> T {
+        self.scale.step
+    }
+
+    /// Returns the value that is added (or subtracted) when the `PageUp` (or `PageDown`) key is pressed
+    #[inline(always)]
+    pub fn page_step(&self) -> T {
+        self.scale.page_step
+    }
+
+    /// Sets the value that is added (or subtracted) when the `PageUp` (or `PageDown`) key is pressed.
+    /// By default it is a tenth of the interval.
+    pub fn set_page_step(&mut self, page_step: T) {
+        self.scale.page_step = page_step;
+    }
+
+    /// Sets the distance between two tick marks. By default, the ticks are evenly distributed along the track
+    /// depending on the size of the control.
+    pub fn set_tick_step(&mut self, tick_step: T) {
+        self.scale.tick_step = Some(tick_step);
+        self.scale.update(self.size());
+    }
+
+    /// Sets the format used for the labels and for the selected range
+    pub fn set_format(&mut self, format: Format) {
+        self.scale.set_format(format);
+        self.update_size_bounds();
+        self.update_string_representation();
+    }
+
+    fn update_size_bounds(&mut self) {
+        let (min_width, min_height, max_width, max_height) = self.scale.size_bounds();
+        self.set_size_bounds(min_width, min_height, max_width, max_height);
+        self.scale.update(self.size());
+    }
+
+    fn update_string_representation(&mut self) {
+        self.txt = format!("{} - {}", self.scale.label(self.start), self.scale.label(self.end));
+    }
+
+    // moves the active thumb (it can not pass the other one)
+    fn move_thumb(&mut self, value: T) {
+        let (start, end) = if self.end_thumb {
+            (self.start, if value < self.start { self.start } else { value })
+        } else {
+            (if value > self.end { self.end } else { value }, self.end)
+        };
+        self.update_range(start, end);
+    }
+
+    // moves both thumbs (the length of the range is preserved)
+    fn move_range(&mut self, forward: bool) {
+        let (start, end) = (self.start.to_f64(), self.end.to_f64());
+        let (min, max) = (self.scale.min.to_f64(), self.scale.max.to_f64());
+        let step = self.scale.step.to_f64();
+        let delta = if forward { step.min(max - end) } else { -step.min(start - min) };
+        if delta != 0.0 {
+            self.update_range(self.scale.snap(start + delta), self.scale.snap(end + delta));
+        }
+    }
+
+    fn update_range(&mut self, start: T, end: T) {
+        if self.scale.is_readonly() || (start == self.start && end == self.end) {
+            return;
+        }
+        self.set_range(start, end);
+        self.raise_event(ControlEvent {
+            emitter: self.handle,
+            receiver: self.event_processor,
+            data: ControlEventData::RangeSlider(EventData {
+                type_id: std::any::TypeId::of::<T>(),
+            }),
+        });
+    }
+}
+impl<T> OnPaint for RangeSlider<T>
+where
+    T: Number + 'static,
+{
+    fn on_paint(&self, surface: &mut Surface, theme: &Theme) {
+        let enabled = self.is_enabled();
+        let (focused, hovered) = (self.has_focus(), self.is_mouse_over());
+        let start = self.scale.position(self.start);
+        let end = self.scale.position(self.end);
+        let start_attr = Scale::<T>::thumb_attr(theme, enabled, focused, hovered, !self.end_thumb, self.dragging);
+        let end_attr = Scale::<T>::thumb_attr(theme, enabled, focused, hovered, self.end_thumb, self.dragging);
+        // the active thumb is drawn last (on top of the other one if they share the same position)
+        let thumbs = if self.end_thumb {
+            [(start, start_attr), (end, end_attr)]
+        } else {
+            [(end, end_attr), (start, start_attr)]
+        };
+        let value = if self.scale.flags.contains(Flags::ShowValue) {
+            let attr = match () {
+                _ if !enabled => theme.text.inactive,
+                _ if focused => theme.text.focused,
+                _ => theme.text.normal,
+            };
+            Some((self.txt.as_str(), attr))
+        } else {
+            None
+        };
+        self.scale.paint(surface, theme, enabled, (start, end), &thumbs, value);
+    }
+}
+impl<T> OnKeyPressed for RangeSlider<T>
+where
+    T: Number + 'static,
+{
+    fn on_key_pressed(&mut self, key: Key, _character: char) -> EventProcessStatus {
+        match key.value() {
+            key!("Space") => {
+                self.end_thumb = !self.end_thumb;
+                return EventProcessStatus::Processed;
+            }
+            key!("Ctrl+Up") | key!("Ctrl+Right") => {
+                self.move_range(true);
+                return EventProcessStatus::Processed;
+            }
+            key!("Ctrl+Down") | key!("Ctrl+Left") => {
+                self.move_range(false);
+                return EventProcessStatus::Processed;
+            }
+            _ => {}
+        }
+        let current = if self.end_thumb { self.end } else { self.start };
+        if let Some(value) = self.scale.value_after_key(current, key) {
+            self.move_thumb(value);
+            return EventProcessStatus::Processed;
+        }
+        EventProcessStatus::Ignored
+    }
+}
+impl<T> OnMouseEvent for RangeSlider<T>
+where
+    T: Number + 'static,
+{
+    fn on_mouse_event(&mut self, event: &MouseEvent) -> EventProcessStatus {
+        match event {
+            MouseEvent::Enter | MouseEvent::Leave => EventProcessStatus::Processed,
+            MouseEvent::Pressed(data) => {
+                if self.scale.is_readonly() {
+                    return EventProcessStatus::Ignored;
+                }
+                // the closest thumb is selected (if both are at the same distance, the one in the direction of the click)
+                let pos = self.scale.hit_test(data.x, data.y);
+                let start = self.scale.position(self.start);
+                let end = self.scale.position(self.end);
+                self.end_thumb = if start == end {
+                    pos > end
+                } else {
+                    (pos - start).abs() > (end - pos).abs()
+                };
+                self.dragging = true;
+                self.move_thumb(self.scale.value_at(pos));
+                EventProcessStatus::Processed
+            }
+            MouseEvent::Drag(data) => {
+                if !self.dragging {
+                    return EventProcessStatus::Ignored;
+                }
+                let value = self.scale.value_at(self.scale.hit_test(data.x, data.y));
+                self.move_thumb(value);
+                EventProcessStatus::Processed
+            }
+            MouseEvent::Released(_) => {
+                self.dragging = false;
+                EventProcessStatus::Processed
+            }
+            MouseEvent::Wheel(direction) => {
+                let current = if self.end_thumb { self.end } else { self.start };
+                let value = self.scale.value_after_wheel(current, *direction);
+                self.move_thumb(value);
+                EventProcessStatus::Processed
+            }
+            _ => EventProcessStatus::Ignored,
+        }
+    }
+}
+impl<T> OnResize for RangeSlider<T>
+where
+    T: Number + 'static,
+{
+    fn on_resize(&mut self, _old_size: Size, new_size: Size) {
+        self.scale.update(new_size);
+    }
+}
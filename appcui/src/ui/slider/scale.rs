@@ -0,0 +1,353 @@
+use super::Flags;
+use super::Format;
+use crate::prelude::*;
+
+const TICK_HORIZONTAL: char = '╵';
+const TICK_VERTICAL: char = '╴';
+const MAX_TICKS: usize = 1024;
+
+/// The interval of a slider (limits, steps and format) together with the geometry of its track.
+/// Values are mapped to positions on the track (`0` is the minimum, `track_len - 1` is the maximum).
+pub(super) struct Scale<T: Number> {
+    pub(super) min: T,
+    pub(super) max: T,
+    pub(super) step: T,
+    pub(super) page_step: T,
+    pub(super) tick_step: Option<T>,
+    pub(super) format: Format,
+    pub(super) flags: Flags,
+    // how many values are displayed when the ShowValue flag is set (1 for a slider, 2 for a range slider)
+    values: u8,
+    label_width: i32,
+    width: i32,
+    track_start: i32,
+    track_len: i32,
+    ticks: Vec<(i32, String)>,
+}
+
+impl<T: Number> Scale<T> {
+    pub(super) fn new(min: T, max: T, step: T, format: Format, flags: Flags, values: u8) -> Self {
+        let (min, max) = if min <= max { (min, max) } else { (max, min) };
+        let mut scale = Self {
+            min,
+            max,
+            step,
+            page_step: step,
+            tick_step: None,
+            format,
+            flags,
+            values,
+            label_width: 1,
+            width: 0,
+            track_start: 0,
+            track_len: 1,
+            ticks: Vec::new(),
+        };
+        scale.page_step = scale.default_page_step();
+        scale.update_label_width();
+        scale
+    }
+
+    // a tenth of the interval (rounded to a multiple of the step)
+    fn default_page_step(&self) -> T {
+        let interval = self.max.to_f64() - self.min.to_f64();
+        let step = self.step.to_f64();
+        if step > 0.0 {
+            T::from_f64((interval / 10.0 / step).round().max(1.0) * step)
+        } else {
+            T::from_f64(interval / 10.0)
+        }
+    }
+
+    #[inline(always)]
+    pub(super) fn is_vertical(&self) -> bool {
+        self.flags.contains(Flags::Vertical)
+    }
+
+    #[inline(always)]
+    pub(super) fn is_readonly(&self) -> bool {
+        self.flags.contains(Flags::ReadOnly)
+    }
+
+    pub(super) fn label(&self, value: T) -> String {
+        let mut text = String::new();
+        value.write_to_string(&mut text, self.format);
+        text
+    }
+
+    pub(super) fn set_format(&mut self, format: Format) {
+        self.format = format;
+        self.update_label_width();
+    }
+
+    fn update_label_width(&mut self) {
+        let min = self.label(self.min).chars().count();
+        let max = self.label(self.max).chars().count();
+        self.label_width = min.max(max).max(1) as i32;
+    }
+
+    // the width of the text with the current value (or values)
+    fn value_width(&self) -> i32 {
+        if !self.flags.contains(Flags::ShowValue) {
+            return 0;
+        }
+        let count = self.values as i32;
+        self.label_width * count + 3 * (count - 1)
+    }
+
+    /// Returns the size bounds of the control (minimum width, minimum height, maximum width, maximum height)
+    pub(super) fn size_bounds(&self) -> (u16, u16, u16, u16) {
+        let ticks = self.flags.contains(Flags::ShowTicks) as i32;
+        let labels = self.flags.contains(Flags::ShowLabels) as i32;
+        let value = self.flags.contains(Flags::ShowValue) as i32;
+        if self.is_vertical() {
+            let mut width = 1 + (ticks | labels) + labels * self.label_width;
+            width = width.max(self.value_width());
+            (width as u16, (2 + value) as u16, u16::MAX, u16::MAX)
+        } else {
+            let height = 1 + ticks + labels;
+            let margins = if labels == 1 { self.label_width - 1 } else { 0 };
+            let width = 2 + margins + self.value_width() + value;
+            (width as u16, height as u16, u16::MAX, height as u16)
+        }
+    }
+
+    /// Recomputes the geometry of the track and the position of the ticks for a new size of the control
+    pub(super) fn update(&mut self, size: Size) {
+        self.width = size.width as i32;
+        if self.is_vertical() {
+            let value_row = self.flags.contains(Flags::ShowValue) as i32;
+            self.track_start = 0;
+            self.track_len = (size.height as i32 - value_row).max(1);
+        } else {
+            // the labels of the minimum and maximum values are centered on the ends of the track
+            let (left, right) = if self.flags.contains(Flags::ShowLabels) {
+                ((self.label_width - 1) / 2, self.label_width / 2)
+            } else {
+                (0, 0)
+            };
+            let value_width = self.value_width();
+            let value_width = if value_width > 0 { value_width + 1 } else { 0 };
+            self.track_start = left;
+            self.track_len = (self.width - left - right - value_width).max(1);
+        }
+        self.update_ticks();
+    }
+
+    fn update_ticks(&mut self) {
+        self.ticks.clear();
+        if !self.flags.contains_one(Flags::ShowTicks | Flags::ShowLabels) {
+            return;
+        }
+        let (min, max) = (self.min.to_f64(), self.max.to_f64());
+        let mut values = Vec::new();
+        match self.tick_step.map(|t| t.to_f64()).filter(|t| *t > 0.0) {
+            Some(tick_step) => {
+                let mut value = min;
+                while value < max && values.len() < MAX_TICKS {
+                    values.push(value);
+                    value += tick_step;
+                }
+                values.push(max);
+            }
+            None => {
+                let spacing = if self.is_vertical() {
+                    2
+                } else if self.flags.contains(Flags::ShowLabels) {
+                    self.label_width + 2
+                } else {
+                    4
+                };
+                let count = ((self.track_len - 1) / spacing).max(1);
+                for index in 0..=count {
+                    values.push(min + (max - min) * index as f64 / count as f64);
+                }
+            }
+        }
+        for value in values {
+            let value = self.snap(value);
+            let pos = self.position(value);
+            let tick = (pos, self.label(value));
+            // ticks that share a position are merged (the last one wins, so that the maximum is always displayed)
+            match self.ticks.last_mut() {
+                Some(last) if last.0 == pos => *last = tick,
+                _ => self.ticks.push(tick),
+            }
+        }
+    }
+
+    /// Rounds a value to the closest multiple of the step (starting from the minimum) within the interval
+    pub(super) fn snap(&self, value: f64) -> T {
+        let (min, max) = (self.min.to_f64(), self.max.to_f64());
+        let step = self.step.to_f64();
+        let mut value = value.clamp(min, max);
+        if step > 0.0 {
+            value = (min + ((value - min) / step).round() * step).min(max);
+        }
+        self.clamp(T::from_f64(value))
+    }
+
+    pub(super) fn clamp(&self, value: T) -> T {
+        if value < self.min {
+            self.min
+        } else if value > self.max {
+            self.max
+        } else {
+            value
+        }
+    }
+
+    /// The position of a value on the track
+    pub(super) fn position(&self, value: T) -> i32 {
+        let (min, max) = (self.min.to_f64(), self.max.to_f64());
+        if max <= min || self.track_len <= 1 {
+            return 0;
+        }
+        let ratio = (value.to_f64() - min) / (max - min);
+        ((ratio * (self.track_len - 1) as f64).round() as i32).clamp(0, self.track_len - 1)
+    }
+
+    /// The value that corresponds to a position on the track
+    pub(super) fn value_at(&self, pos: i32) -> T {
+        if self.track_len <= 1 {
+            return self.min;
+        }
+        let (min, max) = (self.min.to_f64(), self.max.to_f64());
+        self.snap(min + (max - min) * pos as f64 / (self.track_len - 1) as f64)
+    }
+
+    /// The position on the track that is the closest to a point from the control
+    pub(super) fn hit_test(&self, x: i32, y: i32) -> i32 {
+        let pos = if self.is_vertical() {
+            self.track_len - 1 - y
+        } else {
+            x - self.track_start
+        };
+        pos.clamp(0, self.track_len - 1)
+    }
+
+    // the cell (within the control) of a position on the track
+    fn cell(&self, pos: i32) -> (i32, i32) {
+        if self.is_vertical() {
+            (0, self.track_len - 1 - pos)
+        } else {
+            (self.track_start + pos, 0)
+        }
+    }
+
+    /// The new value after a navigation key (arrows, page up/down, home or end) or `None` for other keys
+    pub(super) fn value_after_key(&self, value: T, key: Key) -> Option<T> {
+        let step = self.step.to_f64();
+        let page = self.page_step.to_f64();
+        match key.value() {
+            key!("Up") | key!("Right") => Some(self.snap(value.to_f64() + step)),
+            key!("Down") | key!("Left") => Some(self.snap(value.to_f64() - step)),
+            key!("PageUp") => Some(self.snap(value.to_f64() + page)),
+            key!("PageDown") => Some(self.snap(value.to_f64() - page)),
+            key!("Home") => Some(self.min),
+            key!("End") => Some(self.max),
+            _ => None,
+        }
+    }
+
+    /// The new value after a mouse wheel event (a step up or down)
+    pub(super) fn value_after_wheel(&self, value: T, direction: MouseWheelDirection) -> T {
+        match direction {
+            MouseWheelDirection::Up | MouseWheelDirection::Right => self.snap(value.to_f64() + self.step.to_f64()),
+            MouseWheelDirection::Down | MouseWheelDirection::Left => self.snap(value.to_f64() - self.step.to_f64()),
+        }
+    }
+
+    pub(super) fn thumb_attr(theme: &Theme, enabled: bool, focused: bool, hovered: bool, active: bool, dragging: bool) -> CharAttribute {
+        match () {
+            _ if !enabled => theme.text.inactive,
+            _ if active && dragging => theme.text.highlighted,
+            _ if active && focused => theme.text.focused,
+            _ if hovered => theme.text.hovered,
+            _ => theme.text.normal,
+        }
+    }
+
+    /// Paints the track (the positions between `filled.0` and `filled.1` are highlighted), the ticks,
+    /// the labels, the thumbs and the text with the current value
+    pub(super) fn paint(
+        &self,
+        surface: &mut Surface,
+        theme: &Theme,
+        enabled: bool,
+        filled: (i32, i32),
+        thumbs: &[(i32, CharAttribute)],
+        value: Option<(&str, CharAttribute)>,
+    ) {
+        let (line_attr, filled_attr, label_attr) = if enabled {
+            (theme.lines.normal, theme.text.enphasized_1, theme.text.normal)
+        } else {
+            (theme.lines.inactive, theme.text.inactive, theme.text.inactive)
+        };
+        let vertical = self.is_vertical();
+        for pos in 0..self.track_len {
+            let ch = match (vertical, pos >= filled.0 && pos <= filled.1) {
+                (false, false) => Character::with_attributes(SpecialChar::BoxHorizontalSingleLine, line_attr),
+                (false, true) => Character::with_attributes(SpecialChar::BoxHorizontalDoubleLine, filled_attr),
+                (true, false) => Character::with_attributes(SpecialChar::BoxVerticalSingleLine, line_attr),
+                (true, true) => Character::with_attributes(SpecialChar::BoxVerticalDoubleLine, filled_attr),
+            };
+            let (x, y) = self.cell(pos);
+            surface.write_char(x, y, ch);
+        }
+        if self.flags.contains(Flags::ShowTicks) {
+            for (pos, _) in &self.ticks {
+                let (x, y) = self.cell(*pos);
+                if vertical {
+                    surface.write_char(x + 1, y, Character::with_attributes(TICK_VERTICAL, line_attr));
+                } else {
+                    surface.write_char(x, y + 1, Character::with_attributes(TICK_HORIZONTAL, line_attr));
+                }
+            }
+        }
+        if self.flags.contains(Flags::ShowLabels) {
+            self.paint_labels(surface, label_attr);
+        }
+        for (pos, attr) in thumbs {
+            let (x, y) = self.cell(*pos);
+            surface.write_char(x, y, Character::with_attributes(SpecialChar::Block100, *attr));
+        }
+        if let Some((text, attr)) = value {
+            if vertical {
+                surface.write_string(0, self.track_len, text, attr, false);
+            } else {
+                surface.write_string(self.width - text.chars().count() as i32, 0, text, attr, false);
+            }
+        }
+    }
+
+    fn paint_labels(&self, surface: &mut Surface, attr: CharAttribute) {
+        let count = self.ticks.len();
+        if count == 0 {
+            return;
+        }
+        if self.is_vertical() {
+            for (pos, label) in &self.ticks {
+                surface.write_string(2, self.track_len - 1 - pos, label, attr, false);
+            }
+            return;
+        }
+        // the labels of the minimum and maximum are drawn first, the other ones only if they do not overlap
+        let row = 1 + self.flags.contains(Flags::ShowTicks) as i32;
+        let mut drawn: Vec<(i32, i32)> = Vec::with_capacity(count);
+        let order = std::iter::once(0)
+            .chain((count > 1).then_some(count - 1))
+            .chain(1..count.saturating_sub(1));
+        for index in order {
+            let (pos, label) = &self.ticks[index];
+            let width = label.chars().count() as i32;
+            let left = self.track_start + pos - (width - 1) / 2;
+            let right = left + width - 1;
+            if drawn.iter().any(|(l, r)| left <= r + 1 && right >= l - 1) {
+                continue;
+            }
+            surface.write_string(left, row, label, attr, false);
+            drawn.push((left, right));
+        }
+    }
+}
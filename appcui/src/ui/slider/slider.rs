@@ -0,0 +1,216 @@
+use super::events::EventData;
+use super::Flags;
+use super::Format;
+use super::Scale;
+use crate::prelude::*;
+
+#[CustomControl(overwrite=OnPaint+OnKeyPressed+OnMouseEvent+OnResize, internal=true)]
+pub struct Slider<T>
+where
+    T: Number + 'static,
+{
+    value: T,
+    scale: Scale<T>,
+    txt: String,
+    dragging: bool,
+}
+impl<T> Slider<T>
+where
+    T: Number + 'static,
+{
+    /// Creates a new Slider control with the specified value, interval, step, layout, flags and the default format (Decimal).
+    /// The flags can be a combination of the following values:
+    /// * `Flags::Vertical` - the slider is vertical (the minimum value is at the bottom)
+    /// * `Flags::ShowTicks` - tick marks are displayed along the track
+    /// * `Flags::ShowLabels` - the values of the tick marks are displayed
+    /// * `Flags::ShowValue` - the current value is displayed next to the track
+    /// * `Flags::ReadOnly` - the value can not be changed by the user
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use appcui::prelude::*;
+    ///
+    /// let s = Slider::<i32>::new(50, 0, 100, 1, layout!("x:1,y:1,w:30,h:3"), slider::Flags::ShowTicks | slider::Flags::ShowLabels);
+    /// ```
+    pub fn new(value: T, min: T, max: T, step: T, layout: Layout, flags: Flags) -> Self {
+        Self::with_format(value, min, max, step, layout, flags, Format::Decimal)
+    }
+
+    /// Creates a new Slider control with the specified value, interval, step, layout, flags and format.
+    /// The format (shared with the NumericSelector control) is used for the labels and for the current value:
+    /// * `Format::Decimal` - the value will be displayed as a decimal number
+    /// * `Format::Percentage` - the value will be displayed as a percentage
+    /// * `Format::Hex` - the value will be displayed as a hexadecimal number
+    /// * `Format::DigitGrouping` - the value will be displayed with digit grouping
+    /// * `Format::Size` - the value will be displayed as a size (bytes, KB, MB, GB, TB)
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use appcui::prelude::*;
+    ///
+    /// let s = Slider::<u8>::with_format(50, 0, 100, 5, layout!("x:1,y:1,w:30"), slider::Flags::ShowValue, slider::Format::Percentage);
+    /// ```
+    pub fn with_format(value: T, min: T, max: T, step: T, layout: Layout, flags: Flags, format: Format) -> Self {
+        let scale = Scale::new(min, max, step, format, flags, 1);
+        let mut obj = Self {
+            base: ControlBase::with_status_flags(layout, StatusFlags::Visible | StatusFlags::Enabled | StatusFlags::AcceptInput),
+            value: scale.clamp(value),
+            scale,
+            txt: String::new(),
+            dragging: false,
+        };
+        obj.update_size_bounds();
+        obj.update_string_representation();
+        obj
+    }
+
+    /// Returns the current value
+    #[inline(always)]
+    pub fn value(&self) -> T {
+        self.value
+    }
+
+    /// Sets the value of the slider. If the value is outside the interval [min, max], it will be set to the closest limit
+    pub fn set_value(&mut self, value: T) {
+        self.value = self.scale.clamp(value);
+        self.update_string_representation();
+    }
+
+    /// Returns the minimum value of the slider
+    #[inline(always)]
+    pub fn min(&self) -> T {
+        self.scale.min
+    }
+
+    /// Returns the maximum value of the slider
+    #[inline(always)]
+    pub fn max(&self) -> T {
+        self.scale.max
+    }
+
+    /// Returns the value that is added (or subtracted) when an arrow key is pressed
+    #[inline(always)]
+    pub fn step(&self) -> T {
+        self.scale.step
+    }
+
+    /// Returns the value that is added (or subtracted) when the `PageUp` (or `PageDown`) key is pressed
+    #[inline(always)]
+    pub fn page_step(&self) -> T {
+        self.scale.page_step
+    }
+
+    /// Sets the value that is added (or subtracted) when the `PageUp` (or `PageDown`) key is pressed.
+    /// By default it is a tenth of the interval.
+    pub fn set_page_step(&mut self, page_step: T) {
+        self.scale.page_step = page_step;
+    }
+
+    /// Sets the distance between two tick marks. By default, the ticks are evenly distributed along the track
+    /// depending on the size of the control.
+    pub fn set_tick_step(&mut self, tick_step: T) {
+        self.scale.tick_step = Some(tick_step);
+        self.scale.update(self.size());
+    }
+
+    /// Sets the format used for the labels and for the current value
+    pub fn set_format(&mut self, format: Format) {
+        self.scale.set_format(format);
+        self.update_size_bounds();
+        self.update_string_representation();
+    }
+
+    fn update_size_bounds(&mut self) {
+        let (min_width, min_height, max_width, max_height) = self.scale.size_bounds();
+        self.set_size_bounds(min_width, min_height, max_width, max_height);
+        self.scale.update(self.size());
+    }
+
+    fn update_string_representation(&mut self) {
+        self.value.write_to_string(&mut self.txt, self.scale.format);
+    }
+
+    fn update_value(&mut self, value: T) {
+        if self.scale.is_readonly() || value == self.value {
+            return;
+        }
+        self.set_value(value);
+        self.raise_event(ControlEvent {
+            emitter: self.handle,
+            receiver: self.event_processor,
+            data: ControlEventData::Slider(EventData {
+                type_id: std::any::TypeId::of::<T>(),
+            }),
+        });
+    }
+}
+impl<T> OnPaint for Slider<T>
+where
+    T: Number + 'static,
+{
+    fn on_paint(&self, surface: &mut Surface, theme: &Theme) {
+        let enabled = self.is_enabled();
+        let pos = self.scale.position(self.value);
+        let thumb = Scale::<T>::thumb_attr(theme, enabled, self.has_focus(), self.is_mouse_over(), true, self.dragging);
+        let value = if self.scale.flags.contains(Flags::ShowValue) {
+            let attr = match () {
+                _ if !enabled => theme.text.inactive,
+                _ if self.has_focus() => theme.text.focused,
+                _ => theme.text.normal,
+            };
+            Some((self.txt.as_str(), attr))
+        } else {
+            None
+        };
+        self.scale.paint(surface, theme, enabled, (0, pos), &[(pos, thumb)], value);
+    }
+}
+impl<T> OnKeyPressed for Slider<T>
+where
+    T: Number + 'static,
+{
+    fn on_key_pressed(&mut self, key: Key, _character: char) -> EventProcessStatus {
+        if let Some(value) = self.scale.value_after_key(self.value, key) {
+            self.update_value(value);
+            return EventProcessStatus::Processed;
+        }
+        EventProcessStatus::Ignored
+    }
+}
+impl<T> OnMouseEvent for Slider<T>
+where
+    T: Number + 'static,
+{
+    fn on_mouse_event(&mut self, event: &MouseEvent) -> EventProcessStatus {
+        match event {
+            MouseEvent::Enter | MouseEvent::Leave => EventProcessStatus::Processed,
+            MouseEvent::Pressed(data) | MouseEvent::Drag(data) => {
+                if self.scale.is_readonly() {
+                    return EventProcessStatus::Ignored;
+                }
+                self.dragging = true;
+                let value = self.scale.value_at(self.scale.hit_test(data.x, data.y));
+                self.update_value(value);
+                EventProcessStatus::Processed
+            }
+            MouseEvent::Released(_) => {
+                self.dragging = false;
+                EventProcessStatus::Processed
+            }
+            MouseEvent::Wheel(direction) => {
+                let value = self.scale.value_after_wheel(self.value, *direction);
+                self.update_value(value);
+                EventProcessStatus::Processed
+            }
+            _ => EventProcessStatus::Ignored,
+        }
+    }
+}
+impl<T> OnResize for Slider<T>
+where
+    T: Number + 'static,
+{
+    fn on_resize(&mut self, _old_size: Size, new_size: Size) {
+        self.scale.update(new_size);
+    }
+}
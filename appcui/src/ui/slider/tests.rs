@@ -0,0 +1,266 @@
+use crate::prelude::*;
+
+// the control is created after the test driver (the size bounds of a control require the runtime manager)
+fn slider_window<T, F>(create: F, width: u16, height: u16) -> (crate::testing::TestDriver, Handle<T>)
+where
+    T: Control + NotWindow + NotDesktop + 'static,
+    F: FnOnce() -> T,
+{
+    let mut driver = crate::testing::TestDriver::new(App::debug(width + 4, height + 4, "")).unwrap();
+    let control = create();
+    let mut w = Window::new(
+        "Slider",
+        LayoutBuilder::new().x(0).y(0).width(width + 2).height(height + 2).build(),
+        window::Flags::None,
+    );
+    let handle = w.add(control);
+    driver.add_window(w);
+    driver.focus(handle);
+    driver.settle();
+    (driver, handle)
+}
+
+fn row(driver: &crate::testing::TestDriver, y: i32, from: i32, to: i32) -> String {
+    (from..to).filter_map(|x| driver.screen().char(x, y).map(|c| c.code)).collect()
+}
+
+#[test]
+fn check_number_conversions() {
+    assert_eq!(7u8.to_f64(), 7.0);
+    assert_eq!(u8::from_f64(2.6), 3);
+    assert_eq!(i32::from_f64(-2.5), -3);
+    assert_eq!(f32::from_f64(0.5), 0.5);
+    assert_eq!(u64::from_f64(1e6), 1_000_000);
+}
+
+#[test]
+fn check_keyboard() {
+    let (mut driver, h) = slider_window(
+        || Slider::<i32>::new(50, 0, 100, 5, layout!("x:0,y:0,w:21,h:1"), slider::Flags::None),
+        21,
+        1,
+    );
+    assert_eq!(driver.get(h).unwrap().page_step(), 10);
+    // 21 positions => a position for every 5 units
+    assert_eq!(driver.screen().char(11, 1).unwrap().code, '█');
+    driver.press(key!("Right"));
+    assert_eq!(driver.get(h).unwrap().value(), 55);
+    driver.press(key!("PageUp"));
+    assert_eq!(driver.get(h).unwrap().value(), 65);
+    assert_eq!(driver.screen().char(14, 1).unwrap().code, '█');
+    driver.press(key!("Home"));
+    assert_eq!(driver.get(h).unwrap().value(), 0);
+    driver.press(key!("Left"));
+    assert_eq!(driver.get(h).unwrap().value(), 0);
+    driver.press(key!("End"));
+    assert_eq!(driver.get(h).unwrap().value(), 100);
+    driver.press(key!("Down"));
+    assert_eq!(driver.get(h).unwrap().value(), 95);
+    driver.get_mut(h).unwrap().set_page_step(50);
+    driver.press(key!("PageDown"));
+    assert_eq!(driver.get(h).unwrap().value(), 45);
+    driver.get_mut(h).unwrap().set_value(1000);
+    assert_eq!(driver.get(h).unwrap().value(), 100);
+}
+
+#[test]
+fn check_readonly() {
+    let (mut driver, h) = slider_window(
+        || Slider::<i32>::new(50, 0, 100, 5, layout!("x:0,y:0,w:21,h:1"), slider::Flags::ReadOnly),
+        21,
+        1,
+    );
+    driver.press(key!("Right"));
+    driver.click_at(1, 1);
+    assert_eq!(driver.get(h).unwrap().value(), 50);
+}
+
+#[test]
+fn check_mouse() {
+    let (mut driver, h) = slider_window(
+        || Slider::<i32>::new(50, 0, 100, 5, layout!("x:0,y:0,w:21,h:1"), slider::Flags::None),
+        21,
+        1,
+    );
+    driver.click_at(21, 1);
+    assert_eq!(driver.get(h).unwrap().value(), 100);
+    driver.drag(21, 1, 6, 1);
+    assert_eq!(driver.get(h).unwrap().value(), 25);
+    // dragging outside the control moves the thumb to the closest end
+    driver.drag(6, 1, 0, 1);
+    assert_eq!(driver.get(h).unwrap().value(), 0);
+}
+
+#[test]
+fn check_ticks_labels_and_value() {
+    let flags = slider::Flags::ShowTicks | slider::Flags::ShowLabels | slider::Flags::ShowValue;
+    let (driver, _) = slider_window(|| Slider::<i32>::new(50, 0, 100, 1, layout!("x:0,y:0,w:30,h:3"), flags), 30, 3);
+    // the track starts after the half of the widest label and ends before the value
+    assert_eq!(driver.screen().char(1, 1).unwrap().code, ' ');
+    assert_eq!(driver.screen().char(2, 1).unwrap().code, '═');
+    assert_eq!(driver.screen().char(14, 1).unwrap().code, '█');
+    assert_eq!(driver.screen().char(15, 1).unwrap().code, '─');
+    assert_eq!(row(&driver, 1, 29, 31), "50");
+    assert_eq!(driver.screen().char(2, 2).unwrap().code, '╵');
+    assert_eq!(driver.screen().char(25, 2).unwrap().code, '╵');
+    let labels = row(&driver, 3, 1, 31);
+    assert!(labels.starts_with(" 0 "));
+    assert!(labels.trim_end().ends_with("100"));
+    assert_eq!(&labels[23..26], "100");
+}
+
+#[test]
+fn check_format() {
+    let (mut driver, h) = slider_window(
+        || {
+            Slider::<u8>::with_format(
+                50,
+                0,
+                100,
+                10,
+                layout!("x:0,y:0,w:20,h:1"),
+                slider::Flags::ShowValue,
+                slider::Format::Percentage,
+            )
+        },
+        20,
+        1,
+    );
+    assert_eq!(row(&driver, 1, 16, 21), "  50%");
+    driver.press(key!("Right"));
+    assert_eq!(row(&driver, 1, 16, 21), "  60%");
+    driver.get_mut(h).unwrap().set_format(slider::Format::Hex);
+    driver.settle();
+    assert_eq!(row(&driver, 1, 16, 21), " 0x3C");
+}
+
+#[test]
+fn check_vertical() {
+    let flags = slider::Flags::Vertical | slider::Flags::ShowTicks | slider::Flags::ShowLabels;
+    let (mut driver, h) = slider_window(|| Slider::<u8>::new(3, 0, 10, 1, layout!("x:0,y:0,w:6,h:11"), flags), 6, 11);
+    // the minimum is at the bottom
+    assert_eq!(driver.screen().char(1, 8).unwrap().code, '█');
+    assert_eq!(driver.screen().char(1, 9).unwrap().code, '║');
+    assert_eq!(driver.screen().char(1, 7).unwrap().code, '│');
+    assert_eq!(row(&driver, 1, 1, 5), "│╴10");
+    assert_eq!(row(&driver, 11, 1, 4), "║╴0");
+    assert_eq!(row(&driver, 7, 1, 4), "│╴4");
+    driver.press(key!("Up"));
+    assert_eq!(driver.get(h).unwrap().value(), 4);
+    driver.click_at(1, 1);
+    assert_eq!(driver.get(h).unwrap().value(), 10);
+}
+
+#[test]
+fn check_tick_step() {
+    let (mut driver, h) = slider_window(
+        || Slider::<i32>::new(0, 0, 100, 1, layout!("x:0,y:0,w:21,h:2"), slider::Flags::ShowTicks),
+        21,
+        2,
+    );
+    driver.get_mut(h).unwrap().set_tick_step(25);
+    driver.settle();
+    assert_eq!(row(&driver, 2, 1, 22), "╵    ╵    ╵    ╵    ╵");
+}
+
+#[test]
+fn check_range_slider() {
+    let (mut driver, h) = slider_window(
+        || RangeSlider::<i32>::new(80, 20, 0, 100, 5, layout!("x:0,y:0,w:21,h:1"), slider::Flags::None),
+        21,
+        1,
+    );
+    assert_eq!(driver.get(h).unwrap().range(), (20, 80));
+    assert_eq!(row(&driver, 1, 1, 22), "────█═══════════█────");
+    driver.press(key!("Right"));
+    assert_eq!(driver.get(h).unwrap().range(), (25, 80));
+    driver.press(key!("Space"));
+    driver.press(key!("Left"));
+    assert_eq!(driver.get(h).unwrap().range(), (25, 75));
+    // a thumb can not pass the other one
+    driver.press(key!("Home"));
+    assert_eq!(driver.get(h).unwrap().range(), (25, 25));
+    driver.press(key!("End"));
+    assert_eq!(driver.get(h).unwrap().range(), (25, 100));
+    // the whole range is moved (and it stops at the limits)
+    driver.press(key!("Ctrl+Right"));
+    assert_eq!(driver.get(h).unwrap().range(), (25, 100));
+    driver.press(key!("Ctrl+Left"));
+    assert_eq!(driver.get(h).unwrap().range(), (20, 95));
+    // the closest thumb is moved by the mouse
+    driver.click_at(2, 1);
+    assert_eq!(driver.get(h).unwrap().range(), (5, 95));
+    driver.drag(20, 1, 13, 1);
+    assert_eq!(driver.get(h).unwrap().range(), (5, 60));
+    driver.drag(13, 1, 2, 1);
+    assert_eq!(driver.get(h).unwrap().range(), (5, 5));
+}
+
+#[test]
+fn check_range_slider_value() {
+    let (driver, _) = slider_window(
+        || {
+            RangeSlider::<u32>::with_format(
+                1024,
+                4096,
+                0,
+                8192,
+                1024,
+                layout!("x:0,y:0,w:30,h:1"),
+                slider::Flags::ShowValue,
+                slider::Format::Size,
+            )
+        },
+        30,
+        1,
+    );
+    assert!(row(&driver, 1, 1, 31).ends_with(" 1 KB - 4 KB"));
+}
+
+#[test]
+fn check_events() {
+    #[Window(events: SliderEvents<i32>+RangeSliderEvents<i32>, internal: true)]
+    struct MyWin {
+        values: Vec<i32>,
+        ranges: Vec<(i32, i32)>,
+    }
+    impl MyWin {
+        fn new() -> Self {
+            let mut w = Self {
+                base: window!("Events,x:0,y:0,w:25,h:5"),
+                values: Vec::new(),
+                ranges: Vec::new(),
+            };
+            w.add(Slider::<i32>::new(0, 0, 10, 1, layout!("x:0,y:0,w:11,h:1"), slider::Flags::None));
+            w.add(RangeSlider::<i32>::new(2, 8, 0, 10, 1, layout!("x:0,y:1,w:11,h:1"), slider::Flags::None));
+            w
+        }
+    }
+    impl SliderEvents<i32> for MyWin {
+        fn on_value_changed(&mut self, _handle: Handle<Slider<i32>>, value: i32) -> EventProcessStatus {
+            self.values.push(value);
+            EventProcessStatus::Processed
+        }
+    }
+    impl RangeSliderEvents<i32> for MyWin {
+        fn on_range_changed(&mut self, _handle: Handle<RangeSlider<i32>>, start: i32, end: i32) -> EventProcessStatus {
+            self.ranges.push((start, end));
+            EventProcessStatus::Processed
+        }
+    }
+    let mut driver = crate::testing::TestDriver::new(App::debug(30, 8, "")).unwrap();
+    let w = driver.add_window(MyWin::new());
+    let slider = driver.find::<Slider<i32>>().single();
+    let range = driver.find::<RangeSlider<i32>>().single();
+    driver.focus(slider);
+    driver.press(key!("Right"));
+    driver.press(key!("Left"));
+    // no event if the value does not change
+    driver.press(key!("Left"));
+    driver.get_mut(slider).unwrap().set_value(5);
+    driver.settle();
+    driver.click_at(4, 1);
+    driver.press_on(range, key!("Right"));
+    assert_eq!(driver.get(w).unwrap().values, vec![1, 0, 3]);
+    assert_eq!(driver.get(w).unwrap().ranges, vec![(3, 8)]);
+}
@@ -0,0 +1,15 @@
+//! A container that stacks its children horizontally or vertically.
+//!
+//! The StackPanel control positions its children one after another, based on their preferred size.
+//! It supports spacing, padding, per-child weights and alignment, and re-flows its children when it is resized.
+
+mod flow_item;
+mod initialization_flags;
+mod stackpanel;
+#[cfg(test)]
+mod tests;
+
+pub(crate) use self::flow_item::{distribute, FlowItem};
+pub use self::initialization_flags::Alignment;
+pub use self::initialization_flags::Orientation;
+pub use self::stackpanel::StackPanel;
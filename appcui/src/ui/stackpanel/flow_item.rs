@@ -0,0 +1,94 @@
+use super::Alignment;
+use crate::prelude::*;
+use crate::ui::layout::ControlLayout;
+
+// a child of a flow container (StackPanel or WrapPanel)
+// the original layout of the child is kept so that its preferred size can be computed on every re-flow
+pub(crate) struct FlowItem {
+    handle: Handle<()>,
+    layout: ControlLayout,
+    pub(crate) weight: u16,
+    pub(crate) alignment: Alignment,
+}
+
+impl FlowItem {
+    pub(crate) fn new<T>(handle: Handle<T>, weight: u16, alignment: Alignment) -> Self {
+        let handle = handle.cast();
+        let layout = match RuntimeManager::get().get_controls_mut().get(handle) {
+            Some(control) => control.base().declared_layout(),
+            None => ControlLayout::default(),
+        };
+        Self {
+            handle,
+            layout,
+            weight,
+            alignment,
+        }
+    }
+    #[inline(always)]
+    pub(crate) fn handle(&self) -> Handle<()> {
+        self.handle
+    }
+    // the stored layout or the alternative layout selected by a breakpoint
+    fn layout(&self) -> ControlLayout {
+        RuntimeManager::get().get_controls().effective_layout(self.handle, &self.layout)
+    }
+    pub(crate) fn is_visible(&self) -> bool {
+        RuntimeManager::get()
+            .get_controls_mut()
+            .get(self.handle)
+            .map(|c| c.base().is_visible())
+            .unwrap_or(false)
+    }
+    pub(crate) fn preferred_size(&self, client: Size) -> Size {
+        let mut layout = self.layout();
+        if layout.has_auto_size() {
+            if let Some(sz) = RuntimeManager::get().get_controls().measure(self.handle, client) {
+                layout.set_content_size(sz);
+            }
+        }
+        layout.preferred_size(client.width as u16, client.height as u16)
+    }
+    // the size of the child when the container does not constrain it
+    pub(crate) fn measured_size(&self, available: Size) -> Size {
+        self.layout().measured_size(RuntimeManager::get().get_controls().measure(self.handle, available))
+    }
+    // (position, size) on the cross axis for an available space of `available` characters
+    pub(crate) fn cross_axis(&self, preferred: u32, available: u32) -> (i32, u32) {
+        let size = preferred.min(available);
+        match self.alignment {
+            Alignment::Start => (0, size),
+            Alignment::Center => (((available - size) / 2) as i32, size),
+            Alignment::End => ((available - size) as i32, size),
+            Alignment::Stretch => (0, available),
+        }
+    }
+    pub(crate) fn place(&self, x: i32, y: i32, width: u32, height: u32) {
+        if let Some(control) = RuntimeManager::get().get_controls_mut().get_mut(self.handle) {
+            let base = control.base_mut();
+            base.set_position(x, y);
+            base.set_size(width.min(u16::MAX as u32) as u16, height.min(u16::MAX as u32) as u16);
+        }
+    }
+}
+
+// splits `free` characters between the items proportionally with their weights
+// (the last weighted item receives the remainder)
+pub(crate) fn distribute(free: u32, weights: &[u16]) -> Vec<u32> {
+    let total: u32 = weights.iter().map(|w| *w as u32).sum();
+    let mut result = vec![0u32; weights.len()];
+    if total == 0 {
+        return result;
+    }
+    let last = weights.iter().rposition(|w| *w > 0).unwrap_or(0);
+    let mut remaining = free;
+    for (index, w) in weights.iter().enumerate() {
+        if *w == 0 {
+            continue;
+        }
+        let value = if index == last { remaining } else { free * (*w as u32) / total };
+        result[index] = value;
+        remaining -= value;
+    }
+    result
+}
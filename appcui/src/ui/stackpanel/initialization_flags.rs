@@ -0,0 +1,14 @@
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Orientation {
+    Horizontal,
+    Vertical,
+}
+
+/// The alignment of a child on the cross axis of a flow container (horizontally for a vertical stack and vertically for a horizontal one)
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Alignment {
+    Start,
+    Center,
+    End,
+    Stretch,
+}
@@ -0,0 +1,217 @@
+use super::flow_item::{distribute, FlowItem};
+use super::{Alignment, Orientation};
+use crate::prelude::*;
+
+#[CustomControl(overwrite=OnResize+OnMeasure, internal = true)]
+pub struct StackPanel {
+    items: Vec<FlowItem>,
+    orientation: Orientation,
+    spacing: u16,
+}
+impl StackPanel {
+    /// Creates a new StackPanel control with the specified layout and orientation.
+    /// A StackPanel positions its children one after another (from top to bottom for `Orientation::Vertical` or
+    /// from left to right for `Orientation::Horizontal`). The size of every child is computed from its own layout
+    /// (e.g. `w:20,h:1` or `a:c,w:50%,h:3`), while its position is controlled by the panel.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use appcui::prelude::*;
+    ///
+    /// let mut sp = StackPanel::new(layout!("d:f"), stackpanel::Orientation::Vertical);
+    /// sp.set_spacing(1);
+    /// sp.add(label!("'Name:',a:l,w:10,h:1"));
+    /// sp.add(textfield!("'',a:l,w:20,h:1"));
+    /// ```
+    pub fn new(layout: Layout, orientation: Orientation) -> Self {
+        Self {
+            base: ControlBase::with_status_flags(layout, StatusFlags::Visible | StatusFlags::Enabled),
+            items: Vec::with_capacity(8),
+            orientation,
+            spacing: 0,
+        }
+    }
+
+    /// Adds a new control to the panel (with no weight and a stretched alignment) and returns a handle towards it.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use appcui::prelude::*;
+    ///
+    /// let mut sp = StackPanel::new(layout!("d:f"), stackpanel::Orientation::Vertical);
+    /// let h = sp.add(button!("Ok,a:c,w:10"));
+    /// ```
+    #[inline(always)]
+    pub fn add<T>(&mut self, control: T) -> Handle<T>
+    where
+        T: Control + NotWindow + NotDesktop + 'static,
+    {
+        self.add_with_options(control, 0, Alignment::Stretch)
+    }
+
+    /// Adds a new control to the panel with a specific weight and alignment.
+    /// * `weight` - if bigger than 0, the child ignores its preferred size on the main axis and receives a part of the
+    ///   remaining space (proportional with its weight). A weight of 0 means that the preferred size is used.
+    /// * `alignment` - the alignment of the child on the cross axis (one of `Start`, `Center`, `End` or `Stretch`)
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use appcui::prelude::*;
+    ///
+    /// let mut sp = StackPanel::new(layout!("d:f"), stackpanel::Orientation::Horizontal);
+    /// sp.add(button!("Left,a:c,w:10"));
+    /// // the list fills all of the remaining space
+    /// sp.add_with_options(listbox!("a:c,w:10,h:5"), 1, stackpanel::Alignment::Stretch);
+    /// sp.add_with_options(button!("Right,a:c,w:10"), 0, stackpanel::Alignment::End);
+    /// ```
+    pub fn add_with_options<T>(&mut self, control: T, weight: u16, alignment: Alignment) -> Handle<T>
+    where
+        T: Control + NotWindow + NotDesktop + 'static,
+    {
+        let h = self.add_child(control);
+        if !h.is_none() {
+            self.items.push(FlowItem::new(h, weight, alignment));
+            self.reflow();
+        }
+        h
+    }
+
+    /// Sets the weight of a child (see `add_with_options` for more details).
+    pub fn set_weight<T>(&mut self, handle: Handle<T>, weight: u16) {
+        let h: Handle<()> = handle.cast();
+        if let Some(item) = self.items.iter_mut().find(|i| i.handle() == h) {
+            item.weight = weight;
+            self.reflow();
+        }
+    }
+
+    /// Returns the weight of a child or `None` if the handle is not a child of the panel.
+    pub fn weight<T>(&self, handle: Handle<T>) -> Option<u16> {
+        let h: Handle<()> = handle.cast();
+        self.items.iter().find(|i| i.handle() == h).map(|i| i.weight)
+    }
+
+    /// Sets the alignment of a child on the cross axis.
+    pub fn set_alignment<T>(&mut self, handle: Handle<T>, alignment: Alignment) {
+        let h: Handle<()> = handle.cast();
+        if let Some(item) = self.items.iter_mut().find(|i| i.handle() == h) {
+            item.alignment = alignment;
+            self.reflow();
+        }
+    }
+
+    /// Returns the alignment of a child or `None` if the handle is not a child of the panel.
+    pub fn alignment<T>(&self, handle: Handle<T>) -> Option<Alignment> {
+        let h: Handle<()> = handle.cast();
+        self.items.iter().find(|i| i.handle() == h).map(|i| i.alignment)
+    }
+
+    /// Sets the space (in characters) between two consecutive children.
+    pub fn set_spacing(&mut self, spacing: u16) {
+        self.spacing = spacing;
+        self.reflow();
+    }
+
+    /// Returns the space (in characters) between two consecutive children.
+    #[inline(always)]
+    pub fn spacing(&self) -> u16 {
+        self.spacing
+    }
+
+    /// Sets the padding (the empty space between the margins of the panel and its children).
+    pub fn set_padding(&mut self, left: u8, top: u8, right: u8, bottom: u8) {
+        self.base.set_margins(left, top, right, bottom);
+        self.reflow();
+    }
+
+    /// Sets the orientation of the panel.
+    pub fn set_orientation(&mut self, orientation: Orientation) {
+        self.orientation = orientation;
+        self.reflow();
+    }
+
+    /// Returns the orientation of the panel.
+    #[inline(always)]
+    pub fn orientation(&self) -> Orientation {
+        self.orientation
+    }
+
+    /// Re-computes the position and size of all children. This is done automatically when the panel is resized or
+    /// when its properties are changed, but it needs to be called if the visibility of a child was changed
+    /// (hidden children do not occupy any space).
+    pub fn reflow(&mut self) {
+        let client = self.client_size();
+        if (client.width == 0) || (client.height == 0) {
+            return;
+        }
+        let vertical = self.orientation == Orientation::Vertical;
+        let (main, cross) = if vertical {
+            (client.height, client.width)
+        } else {
+            (client.width, client.height)
+        };
+        let visible: Vec<&FlowItem> = self.items.iter().filter(|i| i.is_visible()).collect();
+        if visible.is_empty() {
+            return;
+        }
+        let preferred: Vec<(u32, u32)> = visible
+            .iter()
+            .map(|i| {
+                let sz = i.preferred_size(client);
+                if vertical {
+                    (sz.height, sz.width)
+                } else {
+                    (sz.width, sz.height)
+                }
+            })
+            .collect();
+        let weights: Vec<u16> = visible.iter().map(|i| i.weight).collect();
+        let fixed: u32 = preferred.iter().zip(weights.iter()).filter(|(_, w)| **w == 0).map(|(p, _)| p.0).sum();
+        let spacing = self.spacing as u32 * (visible.len() as u32 - 1);
+        let free = main.saturating_sub(fixed + spacing);
+        let shares = distribute(free, &weights);
+        let mut pos = 0i32;
+        for (index, item) in visible.iter().enumerate() {
+            let main_size = if weights[index] > 0 { shares[index] } else { preferred[index].0 };
+            let (cross_pos, cross_size) = item.cross_axis(preferred[index].1, cross);
+            if vertical {
+                item.place(cross_pos, pos, cross_size, main_size);
+            } else {
+                item.place(pos, cross_pos, main_size, cross_size);
+            }
+            pos += main_size as i32 + self.spacing as i32;
+        }
+    }
+}
+
+impl OnResize for StackPanel {
+    fn on_resize(&mut self, _old_size: Size, _new_size: Size) {
+        self.reflow();
+    }
+    fn on_children_layout_changed(&mut self) {
+        self.reflow();
+    }
+}
+
+impl OnMeasure for StackPanel {
+    fn preferred_size(&self, available: Size) -> Option<Size> {
+        let vertical = self.orientation == Orientation::Vertical;
+        let mut main = 0u32;
+        let mut cross = 0u32;
+        let mut count = 0u32;
+        for item in self.items.iter().filter(|i| i.is_visible()) {
+            let sz = item.measured_size(available);
+            let (m, c) = if vertical { (sz.height, sz.width) } else { (sz.width, sz.height) };
+            main += m;
+            cross = cross.max(c);
+            count += 1;
+        }
+        main += (self.spacing as u32) * count.saturating_sub(1);
+        let m = &self.base.margins;
+        let (width, height) = if vertical { (cross, main) } else { (main, cross) };
+        Some(Size::new(
+            width + m.left as u32 + m.right as u32,
+            height + m.top as u32 + m.bottom as u32,
+        ))
+    }
+}
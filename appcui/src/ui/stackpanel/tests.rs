@@ -0,0 +1,124 @@
+use crate::prelude::*;
+use crate::testing::TestDriver;
+
+fn label(text: &str, width: u16, height: u16) -> Label {
+    Label::new(
+        text,
+        LayoutBuilder::new()
+            .alignment(layout::Alignment::TopLeft)
+            .width(width)
+            .height(height)
+            .build(),
+    )
+}
+
+#[test]
+fn check_vertical_stack() {
+    let mut driver = TestDriver::new(App::debug(60, 20, "")).unwrap();
+    // window client area: (11,5) - 38x10
+    let mut w = Window::new("Test", layout!("a:c,w:40,h:12"), window::Flags::None);
+    let mut sp = StackPanel::new(layout!("d:f"), stackpanel::Orientation::Vertical);
+    sp.set_spacing(1);
+    sp.set_padding(1, 1, 1, 1);
+    let l1 = sp.add(label("first", 10, 1));
+    let l2 = sp.add_with_options(label("second", 10, 2), 0, stackpanel::Alignment::Center);
+    let l3 = sp.add_with_options(label("third", 10, 1), 1, stackpanel::Alignment::End);
+    w.add(sp);
+    driver.add_window(w);
+    driver.settle();
+    // stretched to the entire width (36 characters after padding)
+    assert_eq!(driver.bounds(l1), Some(Rect::new(12, 6, 47, 6)));
+    // centered
+    assert_eq!(driver.bounds(l2), Some(Rect::new(25, 8, 34, 9)));
+    // the remaining space (8 - 1 - 2 - 2 spacing = 3 lines), aligned to the right
+    assert_eq!(driver.bounds(l3), Some(Rect::new(38, 11, 47, 13)));
+}
+
+#[test]
+fn check_horizontal_stack_with_weights() {
+    let mut driver = TestDriver::new(App::debug(60, 20, "")).unwrap();
+    let mut w = Window::new("Test", layout!("a:c,w:40,h:12"), window::Flags::None);
+    let mut sp = StackPanel::new(layout!("d:f"), stackpanel::Orientation::Horizontal);
+    let l1 = sp.add_with_options(label("first", 8, 1), 0, stackpanel::Alignment::Start);
+    let l2 = sp.add_with_options(label("second", 1, 1), 1, stackpanel::Alignment::Stretch);
+    let l3 = sp.add_with_options(label("third", 1, 1), 2, stackpanel::Alignment::End);
+    w.add(sp);
+    driver.add_window(w);
+    driver.settle();
+    // 38 - 8 = 30 characters split in 1:2 => 10 and 20
+    assert_eq!(driver.bounds(l1), Some(Rect::new(11, 5, 18, 5)));
+    assert_eq!(driver.bounds(l2), Some(Rect::new(19, 5, 28, 14)));
+    assert_eq!(driver.bounds(l3), Some(Rect::new(29, 14, 48, 14)));
+}
+
+#[test]
+fn check_reflow_on_resize_and_visibility() {
+    let mut driver = TestDriver::new(App::debug(60, 20, "")).unwrap();
+    let mut w = Window::new("Test", layout!("x:0,y:0,w:40,h:12"), window::Flags::Sizeable);
+    let sp = w.add(StackPanel::new(layout!("d:f"), stackpanel::Orientation::Horizontal));
+    let w = driver.add_window(w);
+    let (l1, l2, l3) = {
+        let p = driver.get_mut(sp).unwrap();
+        let l1 = p.add(label("a", 5, 1));
+        let l2 = p.add_with_options(label("b", 5, 1), 1, stackpanel::Alignment::Start);
+        let l3 = p.add(label("c", 5, 1));
+        (l1, l2, l3)
+    };
+    driver.settle();
+    assert_eq!(driver.bounds(l1), Some(Rect::new(1, 1, 5, 10)));
+    assert_eq!(driver.bounds(l2), Some(Rect::new(6, 1, 33, 1)));
+    assert_eq!(driver.bounds(l3), Some(Rect::new(34, 1, 38, 10)));
+
+    // the window becomes smaller --> the weighted child shrinks
+    driver.get_mut(w).unwrap().set_size(30, 8);
+    driver.settle();
+    assert_eq!(driver.bounds(l2), Some(Rect::new(6, 1, 23, 1)));
+    assert_eq!(driver.bounds(l3), Some(Rect::new(24, 1, 28, 6)));
+
+    // hidden children do not occupy any space
+    driver.get_mut(l1).unwrap().set_visible(false);
+    driver.get_mut(sp).unwrap().reflow();
+    driver.settle();
+    assert_eq!(driver.bounds(l2), Some(Rect::new(1, 1, 23, 1)));
+
+    // properties
+    let p = driver.get_mut(sp).unwrap();
+    assert_eq!(p.weight(l2), Some(1));
+    assert_eq!(p.alignment(l3), Some(stackpanel::Alignment::Stretch));
+    p.set_orientation(stackpanel::Orientation::Vertical);
+    assert_eq!(p.orientation(), stackpanel::Orientation::Vertical);
+    p.set_weight(l2, 0);
+    p.set_alignment(l3, stackpanel::Alignment::Center);
+    driver.settle();
+    assert_eq!(driver.bounds(l2), Some(Rect::new(1, 1, 5, 1)));
+    assert_eq!(driver.bounds(l3), Some(Rect::new(12, 2, 16, 2)));
+}
+
+#[test]
+fn check_stack_fit_to_content() {
+    let mut driver = TestDriver::new(App::debug(60, 20, "")).unwrap();
+    let mut w = Window::new("Test", layout!("a:c,w:40,h:12"), window::Flags::None);
+    let auto = || {
+        LayoutBuilder::new()
+            .alignment(layout::Alignment::TopLeft)
+            .width(layout::Dimension::Auto)
+            .height(layout::Dimension::Auto)
+    };
+    let mut sp = StackPanel::new(auto().build(), stackpanel::Orientation::Vertical);
+    sp.set_spacing(1);
+    sp.set_padding(1, 1, 1, 1);
+    let l = sp.add(Label::new("first", auto().height(1).build()));
+    let mut lb = ListBox::new(auto().build(), listbox::Flags::None);
+    lb.add("alpha");
+    lb.add("a longer item");
+    lb.add("beta");
+    let lb = sp.add(lb);
+    let sp = w.add(sp);
+    driver.add_window(w);
+    driver.settle();
+    // the list (13x3) and the label (5x1) plus spacing and padding
+    assert_eq!(driver.bounds(sp), Some(Rect::new(11, 5, 25, 11)));
+    // children are stretched to the width of the panel
+    assert_eq!(driver.bounds(l), Some(Rect::new(12, 6, 24, 6)));
+    assert_eq!(driver.bounds(lb), Some(Rect::new(12, 8, 24, 10)));
+}
@@ -1,13 +1,23 @@
-use crate::{system::Handle, ui::common::traits::EventProcessStatus};
+use crate::{system::Handle, ui::common::traits::ActionRequest, ui::common::traits::EventProcessStatus};
 use super::Tab;
 
 pub trait TabEvents {
     fn on_tab_changed(&mut self, _handle: Handle<Tab>, _new_tab_index: u32, _old_tabl_index: u32) -> EventProcessStatus {
         EventProcessStatus::Ignored
     }
+    /// Called when the close button of a tab is pressed. If `ActionRequest::Allow` is returned the tab (and its content) is removed,
+    /// otherwise (`ActionRequest::Deny`) the tab remains open.
+    fn on_tab_closing(&mut self, _handle: Handle<Tab>, _index: u32) -> ActionRequest {
+        ActionRequest::Allow
+    }
+    /// Called after a tab was dragged to a new position (via the mouse).
+    fn on_tab_moved(&mut self, _handle: Handle<Tab>, _old_index: u32, _new_index: u32) -> EventProcessStatus {
+        EventProcessStatus::Ignored
+    }
 }
 #[derive(Copy, Clone)]
-pub(crate) struct EventData {
-    pub(crate) new_tab_index: u32,
-    pub(crate) old_tab_index: u32,
+pub(crate) enum EventData {
+    Changed { new_tab_index: u32, old_tab_index: u32 },
+    Closing { index: u32 },
+    Moved { old_index: u32, new_index: u32 },
 }
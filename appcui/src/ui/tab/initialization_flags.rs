@@ -4,6 +4,8 @@ use EnumBitFlags::EnumBitFlags;
 pub enum Flags {
     TransparentBackground = 0x01,
     TabsBar = 0x02,
+    CloseButtons = 0x04,
+    Reorderable = 0x08,
 }
 
 #[repr(u8)]
@@ -13,4 +15,4 @@ pub enum Type {
     OnTop,
     OnBottom,
     OnLeft,
-}
\ No newline at end of file
+}
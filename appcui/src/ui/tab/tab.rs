@@ -2,13 +2,27 @@ use crate::prelude::*;
 use crate::ui::tab::{Flags, Type};
 use super::events::EventData;
 
-#[CustomControl(overwrite=OnPaint+OnMouseEvent+OnKeyPressed, internal=true)]
+struct Page {
+    caption: Caption,
+    dirty: bool,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum TabItem {
+    Tab(usize),
+    CloseButton(usize),
+    OverflowButton,
+}
+
+#[CustomControl(overwrite=OnPaint+OnMouseEvent+OnKeyPressed+OnSessionState, events=MenuEvents, commands=[SelectTab], internal=true)]
 pub struct Tab {
     tab_type: Type,
     flags: Flags,
     tab_width: u8,
-    pages: Vec<Caption>,
+    pages: Vec<Page>,
     hovered_page_idx: Option<usize>,
+    dragged_page_idx: Option<usize>,
+    overflow_menu: Handle<Menu>,
 }
 
 impl Tab {
@@ -16,6 +30,8 @@ impl Tab {
     /// The flags can be a combination of the following values:
     /// * `tab::Flags::TabsBar` - if set, the tabs will be displayed in a bar
     /// * `tab::Flags::TransparentBackground` - if set, the background will be transparent
+    /// * `tab::Flags::CloseButtons` - if set, every tab has a close button
+    /// * `tab::Flags::Reorderable` - if set, the tabs can be reordered by dragging them with the mouse
     /// 
     /// The type of the tab is `Type::OnTop` by default, which means that the tabs will be displayed on top of the control.
     /// 
@@ -36,6 +52,8 @@ impl Tab {
             flags,
             tab_width: 12,
             hovered_page_idx: None,
+            dragged_page_idx: None,
+            overflow_menu: Handle::None,
             pages: Vec::with_capacity(4),
         };
         t.update_margins();
@@ -46,7 +64,10 @@ impl Tab {
     /// The flags can be a combination of the following values:
     /// * `tab::Flags::TabsBar` - if set, the tabs will be displayed in a bar
     /// * `tab::Flags::TransparentBackground` - if set, the background will be transparent
-    ///   and the tab_type will be one of the following values:
+    /// * `tab::Flags::CloseButtons` - if set, every tab has a close button
+    /// * `tab::Flags::Reorderable` - if set, the tabs can be reordered by dragging them with the mouse
+    ///
+    /// and the tab_type will be one of the following values:
     /// * `tab::Type::OnTop` - the tabs will be displayed on top of the control
     /// * `tab::Type::OnBottom` - the tabs will be displayed on the bottom of the control
     /// * `tab::Type::OnLeft` - the tabs will be displayed on the left side of the control
@@ -70,6 +91,8 @@ impl Tab {
             flags,
             tab_width: 12,
             hovered_page_idx: None,
+            dragged_page_idx: None,
+            overflow_menu: Handle::None,
             pages: Vec::with_capacity(4),
         };
         t.update_margins();
@@ -79,10 +102,110 @@ impl Tab {
     /// Adds a new tab page with the specified caption. The caption can contain a hotkey, which is indicated by an ampersand (&) before the character.
     /// The function returns the index of the newly created tab page.
     pub fn add_tab(&mut self, caption: &str) -> u32 {
-        let idx = self.base.children.len() as u32;
-        self.base.add_child(super::TabPage::new(idx == 0));
-        self.pages.push(Caption::new(caption, ExtractHotKeyMethod::AltPlusKey));
-        idx
+        self.insert_tab(self.base.children.len(), caption)
+    }
+
+    /// Inserts a new tab page with the specified caption at the specified index (if the index is bigger than the
+    /// number of tabs, the page is added at the end). The caption can contain a hotkey, which is indicated by an ampersand (&) before the character.
+    /// The function returns the index of the newly created tab page.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use appcui::prelude::*;
+    ///
+    /// let mut tab = Tab::new(layout!("x:1,y:1,w:40,h:10"), tab::Flags::TabsBar);
+    /// tab.add_tab("Second");
+    /// let idx = tab.insert_tab(0, "First");
+    /// assert_eq!(idx, 0);
+    /// ```
+    pub fn insert_tab(&mut self, index: usize, caption: &str) -> u32 {
+        let count = self.base.children.len();
+        let index = index.min(count);
+        let h = self.base.add_child(super::TabPage::new(count == 0));
+        if index < count {
+            self.base.children.pop();
+            self.base.children.insert(index, h.cast());
+            if self.base.focused_child_index.is_valid() && self.base.focused_child_index.index() >= index {
+                self.base.focused_child_index = VectorIndex::with_value(self.base.focused_child_index.index() + 1);
+            }
+            self.update_page_indexes();
+        }
+        self.pages.insert(
+            index,
+            Page {
+                caption: Caption::new(caption, ExtractHotKeyMethod::AltPlusKey),
+                dirty: false,
+            },
+        );
+        // the first page added to a tab that is already part of a window becomes the current one
+        if (count == 0) && (!self.handle.is_none()) {
+            self.show_page(0);
+        }
+        self.request_update();
+        index as u32
+    }
+
+    /// Removes the tab page at the specified index (together with all of the controls from that page).
+    /// If the removed page is the current one, the next page (or the previous one if the last page was removed) becomes the current page.
+    /// Returns `true` if the page was removed or `false` if the index is out of bounds.
+    ///
+    /// Unlike closing a tab via its close button, this method does not trigger the `TabEvents::on_tab_closing` event.
+    pub fn remove_tab(&mut self, index: usize) -> bool {
+        if index >= self.base.children.len() {
+            return false;
+        }
+        let h = self.base.children.remove(index);
+        self.pages.remove(index);
+        RuntimeManager::get().request_remove(h);
+        let count = self.base.children.len();
+        let current = self.base.focused_child_index;
+        if current.is_valid() {
+            match current.index().cmp(&index) {
+                std::cmp::Ordering::Greater => self.base.focused_child_index = VectorIndex::with_value(current.index() - 1),
+                std::cmp::Ordering::Equal => {
+                    self.base.focused_child_index = VectorIndex::Invalid;
+                    if count > 0 {
+                        self.show_page(index.min(count - 1));
+                    }
+                }
+                std::cmp::Ordering::Less => {}
+            }
+        }
+        self.hovered_page_idx = None;
+        self.dragged_page_idx = None;
+        self.update_page_indexes();
+        self.request_update();
+        true
+    }
+
+    /// Moves the tab page from index `from` to index `to` (the current page remains the same).
+    /// Returns `true` if the page was moved or `false` if any of the indexes is out of bounds.
+    pub fn move_tab(&mut self, from: usize, to: usize) -> bool {
+        let count = self.base.children.len();
+        if (from >= count) || (to >= count) {
+            return false;
+        }
+        if from != to {
+            let current = self.current_tab().map(|idx| self.base.children[idx]);
+            let h = self.base.children.remove(from);
+            self.base.children.insert(to, h);
+            let page = self.pages.remove(from);
+            self.pages.insert(to, page);
+            if let Some(handle) = current {
+                if let Some(idx) = self.base.children.iter().position(|c| *c == handle) {
+                    self.base.focused_child_index = VectorIndex::with_value(idx);
+                }
+            }
+            self.update_page_indexes();
+            self.request_update();
+        }
+        true
+    }
+
+    /// Returns the number of tab pages.
+    #[inline(always)]
+    pub fn tab_count(&self) -> usize {
+        self.pages.len()
     }
     
     /// Ads a new control to a tab page that is specified by the index.
@@ -158,7 +281,7 @@ impl Tab {
                 self.raise_event(ControlEvent {
                     emitter: self.handle,
                     receiver: self.event_processor,
-                    data: ControlEventData::Tab(EventData {
+                    data: ControlEventData::Tab(EventData::Changed {
                         new_tab_index: index as u32,
                         old_tab_index: current_index as u32,
                     }),
@@ -187,7 +310,7 @@ impl Tab {
     #[inline]
     pub fn tab_caption(&self, index: usize) -> Option<&str> {
         if index < self.pages.len() {
-            Some(self.pages[index].text())
+            Some(self.pages[index].caption.text())
         } else {
             None
         }
@@ -198,8 +321,101 @@ impl Tab {
     /// If the index is out of bounds, the function does nothing.
     pub fn set_tab_caption(&mut self, index: usize, caption: &str) {
         if index < self.pages.len() {
-            self.pages[index].set_text(caption, ExtractHotKeyMethod::AltPlusKey);
+            self.pages[index].caption.set_text(caption, ExtractHotKeyMethod::AltPlusKey);
+        }
+    }
+
+    /// Returns `true` if the tab at the specified index is marked as dirty (e.g. a document with unsaved changes) or `false` otherwise (or if the index is out of bounds).
+    #[inline]
+    pub fn is_tab_dirty(&self, index: usize) -> bool {
+        self.pages.get(index).map(|p| p.dirty).unwrap_or(false)
+    }
+
+    /// Marks the tab at the specified index as dirty (a `●` indicator is drawn before its caption) or clean.
+    /// If the index is out of bounds, the function does nothing.
+    pub fn set_tab_dirty(&mut self, index: usize, dirty: bool) {
+        if let Some(page) = self.pages.get_mut(index) {
+            page.dirty = dirty;
+        }
+    }
+
+    // makes a page visible (and the current one) without emitting any event
+    fn show_page(&mut self, index: usize) {
+        let cm = RuntimeManager::get().get_controls_mut();
+        for (child_index, handle_child) in self.base.children.iter().enumerate() {
+            if let Some(control) = cm.get_mut(*handle_child) {
+                control.base_mut().set_visible(index == child_index);
+            }
+        }
+        self.base.focused_child_index = VectorIndex::with_value(index);
+        if self.has_focus() {
+            if let Some(control) = cm.get_mut(self.base.children[index]) {
+                control.base_mut().request_focus();
+            }
+        }
+    }
+
+    // the pages were inserted, removed or moved, so their index within the tab must be updated
+    fn update_page_indexes(&mut self) {
+        let cm = RuntimeManager::get().get_controls_mut();
+        for (child_index, handle_child) in self.base.children.iter().enumerate() {
+            if let Some(control) = cm.get_mut(*handle_child) {
+                control.base_mut().parent_index = VectorIndex::with_value(child_index);
+            }
+        }
+    }
+
+    fn request_close(&mut self, index: usize) {
+        if index < self.pages.len() {
+            self.raise_event(ControlEvent {
+                emitter: self.handle,
+                receiver: self.event_processor,
+                data: ControlEventData::Tab(EventData::Closing { index: index as u32 }),
+            });
+        }
+    }
+
+    fn show_overflow_menu(&mut self) {
+        if self.overflow_menu.is_none() {
+            self.overflow_menu = self.register_menu(Menu::new());
+        }
+        let sz = self.size();
+        match self.tab_type {
+            Type::OnTop => self.show_menu(self.overflow_menu, sz.width as i32 - 3, 1, None),
+            Type::OnBottom => self.show_menu(self.overflow_menu, sz.width as i32 - 3, sz.height as i32 - 1, None),
+            Type::OnLeft => self.show_menu(self.overflow_menu, 0, sz.height as i32, None),
+            Type::HiddenTabs => {}
+        }
+    }
+
+    // the number of tabs that can be displayed (if `overflow` is true, some space is reserved for the overflow button)
+    // the last column of a tab is only used as padding, so a tab fits even if that column is not visible
+    fn tabs_capacity(&self, overflow: bool) -> usize {
+        let sz = self.size();
+        match self.tab_type {
+            Type::HiddenTabs => usize::MAX,
+            Type::OnTop | Type::OnBottom if overflow => (sz.width as usize).saturating_sub(3) / ((self.tab_width as usize) + 1),
+            Type::OnTop | Type::OnBottom => (sz.width as usize + 1) / ((self.tab_width as usize) + 1),
+            Type::OnLeft if overflow => (sz.height as usize).saturating_sub(2),
+            Type::OnLeft => (sz.height as usize).saturating_sub(1),
+        }
+    }
+
+    #[inline(always)]
+    fn has_overflow(&self) -> bool {
+        self.pages.len() > self.tabs_capacity(false)
+    }
+
+    // the range of tabs that are displayed (the current tab is always visible)
+    fn visible_tabs(&self) -> std::ops::Range<usize> {
+        let count = self.pages.len();
+        if !self.has_overflow() {
+            return 0..count;
         }
+        let capacity = self.tabs_capacity(true);
+        let current = self.current_tab().unwrap_or(0);
+        let start = if current < capacity { 0 } else { current + 1 - capacity };
+        start..(start + capacity).min(count)
     }
     fn update_margins(&mut self) {
         match self.tab_type {
@@ -209,43 +425,44 @@ impl Tab {
             Type::OnLeft => self.base.set_margins(self.tab_width, 0, 0, 0),
         }
     }
-    fn mouse_position_to_index(&self, x: i32, y: i32) -> Option<usize> {
-        let count = self.base.children.len();
-        if count == 0 {
+    fn mouse_position_to_item(&self, x: i32, y: i32) -> Option<TabItem> {
+        if self.base.children.is_empty() {
             return None;
         }
-        match self.tab_type {
-            Type::HiddenTabs => None,
-            Type::OnTop => {
-                if (y != 0) || (x < 1) {
-                    return None;
-                }
-                let idx = (x as usize - 1) / ((self.tab_width as usize) + 1usize);
-                if idx >= count {
-                    return None;
-                }
-                Some(idx)
-            }
-            Type::OnBottom => {
-                if (y != self.size().height as i32 - 1) || (x < 1) {
+        let visible = self.visible_tabs();
+        let overflow = self.has_overflow();
+        let close_buttons = self.flags.contains(Flags::CloseButtons);
+        let tab_width = self.tab_width as i32;
+        let sz = self.size();
+        let (idx, ofs) = match self.tab_type {
+            Type::HiddenTabs => return None,
+            Type::OnTop | Type::OnBottom => {
+                let row = if self.tab_type == Type::OnTop { 0 } else { sz.height as i32 - 1 };
+                if (y != row) || (x < 1) {
                     return None;
                 }
-                let idx = (x as usize - 1) / ((self.tab_width as usize) + 1usize);
-                if idx >= count {
-                    return None;
+                if overflow && (x >= sz.width as i32 - 3) {
+                    return Some(TabItem::OverflowButton);
                 }
-                Some(idx)
+                (visible.start + ((x - 1) / (tab_width + 1)) as usize, (x - 1) % (tab_width + 1))
             }
             Type::OnLeft => {
-                if (x < 0) || (x > self.tab_width as i32) || (y < 1) {
+                if (x < 0) || (x > tab_width) || (y < 1) {
                     return None;
                 }
-                let idx = y as usize - 1;
-                if idx >= count {
-                    return None;
+                if overflow && (y == sz.height as i32 - 1) {
+                    return Some(TabItem::OverflowButton);
                 }
-                Some(idx)
+                (visible.start + (y - 1) as usize, x)
             }
+        };
+        if idx >= visible.end {
+            return None;
+        }
+        if close_buttons && (ofs == tab_width - 2) {
+            Some(TabItem::CloseButton(idx))
+        } else {
+            Some(TabItem::Tab(idx))
         }
     }
     #[inline(always)]
@@ -279,10 +496,35 @@ impl Tab {
             (theme.tab.text.normal, theme.tab.hotkey.normal)
         }
     }
+    // the close button and the dirty indicator of a tab that starts at (x,y)
+    fn paint_tab_decorations(&self, surface: &mut Surface, x: i32, y: i32, index: usize, attr: CharAttribute) {
+        if self.pages[index].dirty {
+            surface.write_char(x, y, Character::with_attributes(SpecialChar::CircleFilled, attr));
+        }
+        if self.flags.contains(Flags::CloseButtons) {
+            surface.write_char(x + self.tab_width as i32 - 2, y, Character::with_attributes('x', attr));
+        }
+    }
+    #[inline(always)]
+    fn caption_width(&self) -> u16 {
+        if self.flags.contains(Flags::CloseButtons) {
+            self.tab_width as u16 - 3
+        } else {
+            self.tab_width as u16 - 2
+        }
+    }
+    #[inline(always)]
+    fn overflow_button_attr(&self, theme: &Theme) -> CharAttribute {
+        if self.is_enabled() {
+            theme.tab.text.normal
+        } else {
+            theme.tab.text.inactive
+        }
+    }
     fn paint_horizontal_tab(&self, surface: &mut Surface, theme: &Theme, y: i32) {
         let mut format = TextFormatBuilder::new()
             .position(1, y)
-            .wrap_type(WrapType::SingleLineWrap(self.tab_width as u16 - 2))
+            .wrap_type(WrapType::SingleLineWrap(self.caption_width()))
             .align(TextAlignment::Center)
             .build();
 
@@ -300,21 +542,34 @@ impl Tab {
             surface.fill_horizontal_line_with_size(0, y, sz.width, Character::with_attributes(' ', self.get_tabsbarattr(theme)));
         }
 
-        let s1 = (self.tab_width as i32) >> 1;
-        let s2 = (self.tab_width as i32) - s1;
-        for (index, page) in self.pages.iter().enumerate() {
+        // the text is centered within the space left after the close button (if any)
+        let center = if self.flags.contains(Flags::CloseButtons) {
+            (self.tab_width as i32 - 1) >> 1
+        } else {
+            (self.tab_width as i32) >> 1
+        };
+        let mut x = 1;
+        for index in self.visible_tabs() {
+            let page = &self.pages[index].caption;
             let (text_attr, hotkey_attr) = self.get_tabattr(theme, index);
             format.set_attribute(text_attr);
             format.set_chars_count(page.chars_count() as u16);
             format.set_hotkey_from_caption(hotkey_attr, page);
 
             // fill the tab
-            surface.fill_horizontal_line_with_size(format.x, y, self.tab_width as u32, Character::with_attributes(' ', text_attr));
+            surface.fill_horizontal_line_with_size(x, y, self.tab_width as u32, Character::with_attributes(' ', text_attr));
 
             // print the text
-            format.x += s1;
+            format.x = x + center;
             surface.write_text(page.text(), &format);
-            format.x += s2 + 1;
+            self.paint_tab_decorations(surface, x, y, index, text_attr);
+            x += self.tab_width as i32 + 1;
+        }
+        if self.has_overflow() {
+            let attr = self.overflow_button_attr(theme);
+            let w = sz.width as i32;
+            surface.fill_horizontal_line(w - 3, y, w - 1, Character::with_attributes(' ', attr));
+            surface.write_char(w - 2, y, Character::with_attributes(SpecialChar::TriangleDown, attr));
         }
     }
     fn paint_leftside_tab(&self, surface: &mut Surface, theme: &Theme) {
@@ -332,11 +587,12 @@ impl Tab {
         }
         let mut format = TextFormatBuilder::new()
             .position(1, 1)
-            .wrap_type(WrapType::SingleLineWrap(self.tab_width as u16 - 2))
+            .wrap_type(WrapType::SingleLineWrap(self.caption_width()))
             .align(TextAlignment::Left)
             .build();
 
-        for (index, page) in self.pages.iter().enumerate() {
+        for index in self.visible_tabs() {
+            let page = &self.pages[index].caption;
             let (text_attr, hotkey_attr) = self.get_tabattr(theme, index);
             format.set_attribute(text_attr);
             format.set_chars_count(page.chars_count() as u16);
@@ -347,9 +603,16 @@ impl Tab {
 
             // write the text
             surface.write_text(page.text(), &format);
+            self.paint_tab_decorations(surface, 0, format.y, index, text_attr);
             // next pos
             format.y += 1;
         }
+        if self.has_overflow() {
+            let attr = self.overflow_button_attr(theme);
+            let y = sz.height as i32 - 1;
+            surface.fill_horizontal_line_with_size(0, y, self.tab_width as u32, Character::with_attributes(' ', attr));
+            surface.write_char((self.tab_width as i32) >> 1, y, Character::with_attributes(SpecialChar::TriangleDown, attr));
+        }
     }
     fn paint_hidden_tabs(&self, surface: &mut Surface, theme: &Theme) {
         if !self.flags.contains(Flags::TransparentBackground) {
@@ -381,7 +644,10 @@ impl OnMouseEvent for Tab {
                 }
             }
             MouseEvent::Over(ev) => {
-                let idx = self.mouse_position_to_index(ev.x, ev.y);
+                let idx = match self.mouse_position_to_item(ev.x, ev.y) {
+                    Some(TabItem::Tab(index)) | Some(TabItem::CloseButton(index)) => Some(index),
+                    _ => None,
+                };
                 if idx != self.hovered_page_idx {
                     self.hovered_page_idx = idx;
                     EventProcessStatus::Processed
@@ -389,22 +655,62 @@ impl OnMouseEvent for Tab {
                     EventProcessStatus::Ignored
                 }
             }
-            MouseEvent::Pressed(ev) => {
-                let idx = self.mouse_position_to_index(ev.x, ev.y);
-                if let Some(index) = idx {
+            MouseEvent::Pressed(ev) => match self.mouse_position_to_item(ev.x, ev.y) {
+                Some(TabItem::Tab(index)) => {
+                    if self.flags.contains(Flags::Reorderable) {
+                        self.dragged_page_idx = Some(index);
+                    }
                     if index != self.base.focused_child_index.index() {
                         self.internal_set_current_tab(index, true);
                         EventProcessStatus::Processed
                     } else {
                         EventProcessStatus::Ignored
                     }
+                }
+                Some(TabItem::CloseButton(index)) => {
+                    self.request_close(index);
+                    EventProcessStatus::Processed
+                }
+                Some(TabItem::OverflowButton) => {
+                    self.show_overflow_menu();
+                    EventProcessStatus::Processed
+                }
+                None => EventProcessStatus::Ignored,
+            },
+            MouseEvent::Released(_) => {
+                self.dragged_page_idx = None;
+                EventProcessStatus::Ignored
+            }
+            MouseEvent::DoubleClick(_) => EventProcessStatus::Ignored,
+            MouseEvent::Drag(ev) => {
+                let Some(from) = self.dragged_page_idx else {
+                    return EventProcessStatus::Ignored;
+                };
+                // only the position along the tabs matters
+                let (x, y) = match self.tab_type {
+                    Type::OnTop => (ev.x, 0),
+                    Type::OnBottom => (ev.x, self.size().height as i32 - 1),
+                    _ => (0, ev.y),
+                };
+                let to = match self.mouse_position_to_item(x, y) {
+                    Some(TabItem::Tab(index)) | Some(TabItem::CloseButton(index)) => index,
+                    _ => return EventProcessStatus::Ignored,
+                };
+                if (to != from) && self.move_tab(from, to) {
+                    self.dragged_page_idx = Some(to);
+                    self.raise_event(ControlEvent {
+                        emitter: self.handle,
+                        receiver: self.event_processor,
+                        data: ControlEventData::Tab(EventData::Moved {
+                            old_index: from as u32,
+                            new_index: to as u32,
+                        }),
+                    });
+                    EventProcessStatus::Processed
                 } else {
                     EventProcessStatus::Ignored
                 }
             }
-            MouseEvent::Released(_) => EventProcessStatus::Ignored,
-            MouseEvent::DoubleClick(_) => EventProcessStatus::Ignored,
-            MouseEvent::Drag(_) => EventProcessStatus::Ignored,
             MouseEvent::Wheel(_) => EventProcessStatus::Ignored,
         }
     }
@@ -424,12 +730,18 @@ impl OnKeyPressed for Tab {
                 self.internal_set_current_tab(idx.index(), true);
                 return EventProcessStatus::Processed;
             }
+            key!("Ctrl+F4") if self.flags.contains(Flags::CloseButtons) => {
+                if let Some(index) = self.current_tab() {
+                    self.request_close(index);
+                    return EventProcessStatus::Processed;
+                }
+            }
             _ => {}
         }
         if key.modifier.contains(KeyModifier::Alt) {
             // check if a new tab was selected
             for (index, elem) in self.pages.iter().enumerate() {
-                if elem.hotkey() == key {
+                if elem.caption.hotkey() == key {
                     self.internal_set_current_tab(index, true);
                     return EventProcessStatus::Processed;
                 }
@@ -438,3 +750,28 @@ impl OnKeyPressed for Tab {
         EventProcessStatus::Ignored
     }
 }
+impl OnSessionState for Tab {
+    fn save_state(&self, state: &mut SessionState) {
+        if let Some(index) = self.current_tab() {
+            state.set("tab", index);
+        }
+    }
+    fn restore_state(&mut self, state: &SessionState) {
+        if let Some(index) = state.get_as::<usize>("tab") {
+            self.set_current_tab(index);
+        }
+    }
+}
+impl MenuEvents for Tab {
+    fn on_menu_open(&self, menu: &mut Menu) {
+        // the overflow menu lists all tabs (the position of an item within the menu is the index of its tab)
+        menu.clear();
+        let current = self.current_tab();
+        for (index, page) in self.pages.iter().enumerate() {
+            menu.add(menu::SingleChoice::new(page.caption.text(), Key::None, tab::Commands::SelectTab, current == Some(index)));
+        }
+    }
+    fn on_select(&mut self, _menu: Handle<Menu>, item: Handle<menu::SingleChoice>, _command: tab::Commands) {
+        self.internal_set_current_tab(item.index(), true);
+    }
+}
@@ -749,4 +749,174 @@ fn check_events() {
     let mut a = App::debug(80, 20, script).build().unwrap();
     a.add_window(MyWin::new());
     a.run();
-}
\ No newline at end of file
+}
+fn create_tab_window(driver: &mut crate::testing::TestDriver, flags: tab::Flags, width: u32) -> Handle<Tab> {
+    let mut w = Window::new("Test", LayoutBuilder::new().x(0).y(0).width(width + 2).height(14).build(), window::Flags::None);
+    let mut tb = Tab::new(layout!("l:0,t:0,r:0,b:0"), flags);
+    for (index, name) in ["A", "B", "C", "D", "E"].iter().enumerate() {
+        let idx = tb.add_tab(name);
+        assert_eq!(idx, index as u32);
+        tb.add(idx, Button::new(&format!("Button {name}"), layout!("x:1,y:1,w:14"), button::Type::Flat));
+    }
+    let h = w.add(tb);
+    driver.add_window(w);
+    driver.settle();
+    h
+}
+
+fn captions(driver: &crate::testing::TestDriver, h: Handle<Tab>) -> Vec<String> {
+    let tb = driver.get(h).unwrap();
+    (0..tb.tab_count()).map(|i| tb.tab_caption(i).unwrap().to_string()).collect()
+}
+
+#[test]
+fn check_dynamic_pages() {
+    let mut driver = crate::testing::TestDriver::new(App::debug(80, 20, "")).unwrap();
+    let h = create_tab_window(&mut driver, tab::Flags::None, 70);
+    driver.get_mut(h).unwrap().set_current_tab(2);
+    driver.settle();
+    assert_eq!(driver.get(h).unwrap().current_tab(), Some(2));
+
+    // inserting a page before the current one does not change the current page
+    let idx = driver.get_mut(h).unwrap().insert_tab(0, "New");
+    let b = driver.get_mut(h).unwrap().add(idx, Button::new("Button New", layout!("x:1,y:1,w:14"), button::Type::Flat));
+    driver.settle();
+    assert_eq!(captions(&driver, h), ["New", "A", "B", "C", "D", "E"]);
+    assert_eq!(driver.get(h).unwrap().current_tab(), Some(3));
+    assert_eq!(driver.bounds(b), None);
+    assert!(driver.has_focus(driver.find::<Button>().caption("Button C").single()));
+
+    // removing the current page makes the next one current (and its controls are removed as well)
+    assert!(driver.get_mut(h).unwrap().remove_tab(3));
+    driver.settle();
+    assert_eq!(captions(&driver, h), ["New", "A", "B", "D", "E"]);
+    assert_eq!(driver.find::<Button>().caption("Button C").count(), 0);
+    assert_eq!(driver.get(h).unwrap().current_tab(), Some(3));
+    assert!(driver.has_focus(driver.find::<Button>().caption("Button D").single()));
+
+    // moving pages keeps the current page
+    assert!(driver.get_mut(h).unwrap().move_tab(3, 0));
+    driver.settle();
+    assert_eq!(captions(&driver, h), ["D", "New", "A", "B", "E"]);
+    assert_eq!(driver.get(h).unwrap().current_tab(), Some(0));
+    driver.press(key!("Ctrl+Tab"));
+    assert_eq!(driver.get(h).unwrap().current_tab(), Some(1));
+    assert!(driver.has_focus(b));
+
+    // invalid indexes
+    assert!(!driver.get_mut(h).unwrap().remove_tab(5));
+    assert!(!driver.get_mut(h).unwrap().move_tab(0, 5));
+    assert_eq!(driver.get_mut(h).unwrap().insert_tab(100, "Last"), 5);
+    assert_eq!(driver.get(h).unwrap().tab_count(), 6);
+}
+
+#[test]
+fn check_close_buttons() {
+    #[Window(events = TabEvents, internal = true)]
+    struct MyWin {
+        closing: Vec<u32>,
+    }
+    impl TabEvents for MyWin {
+        fn on_tab_closing(&mut self, handle: Handle<Tab>, index: u32) -> ActionRequest {
+            self.closing.push(index);
+            // the first tab can not be closed
+            if self.control(handle).and_then(|t| t.tab_caption(index as usize)) == Some("A") {
+                ActionRequest::Deny
+            } else {
+                ActionRequest::Allow
+            }
+        }
+    }
+
+    let mut driver = crate::testing::TestDriver::new(App::debug(80, 20, "")).unwrap();
+    let mut w = MyWin {
+        base: Window::new("Test", layout!("x:0,y:0,w:72,h:14"), window::Flags::None),
+        closing: Vec::new(),
+    };
+    let mut tb = Tab::new(layout!("l:0,t:0,r:0,b:0"), tab::Flags::CloseButtons);
+    for name in ["A", "B", "C"] {
+        let idx = tb.add_tab(name);
+        tb.add(idx, Button::new(&format!("Button {name}"), layout!("x:1,y:1,w:14"), button::Type::Flat));
+    }
+    let h = w.add(tb);
+    let wh = driver.add_window(w);
+    driver.settle();
+    let r = driver.bounds(h).unwrap();
+    // every tab is 12 characters wide (plus one separator) and the close button is on the 11th character
+    let close_x = |index: i32| r.left() + 1 + index * 13 + 10;
+    assert!(driver.screen_text().lines().nth(r.top() as usize).unwrap().contains("A    x"));
+
+    driver.click_at(close_x(1), r.top());
+    assert_eq!(captions(&driver, h), ["A", "C"]);
+    assert_eq!(driver.find::<Button>().caption("Button B").count(), 0);
+
+    // closing the first tab is denied
+    driver.click_at(close_x(0), r.top());
+    assert_eq!(captions(&driver, h), ["A", "C"]);
+    assert_eq!(driver.get(wh).unwrap().closing, [1, 0]);
+
+    // Ctrl+F4 closes the current tab
+    driver.get_mut(h).unwrap().set_current_tab(1);
+    driver.settle();
+    driver.press(key!("Ctrl+F4"));
+    assert_eq!(captions(&driver, h), ["A"]);
+    assert_eq!(driver.get(h).unwrap().current_tab(), Some(0));
+    assert!(driver.has_focus(driver.find::<Button>().caption("Button A").single()));
+}
+
+#[test]
+fn check_drag_to_reorder() {
+    let mut driver = crate::testing::TestDriver::new(App::debug(80, 20, "")).unwrap();
+    let h = create_tab_window(&mut driver, tab::Flags::Reorderable, 70);
+    let r = driver.bounds(h).unwrap();
+    let tab_x = |index: i32| r.left() + 1 + index * 13 + 5;
+    driver.drag(tab_x(0), r.top(), tab_x(2), r.top());
+    assert_eq!(captions(&driver, h), ["B", "C", "A", "D", "E"]);
+    assert_eq!(driver.get(h).unwrap().current_tab(), Some(2));
+    assert!(driver.has_focus(driver.find::<Button>().caption("Button A").single()));
+
+    // tabs can not be dragged if the Reorderable flag is not set
+    let h = create_tab_window(&mut driver, tab::Flags::None, 70);
+    let r = driver.bounds(h).unwrap();
+    driver.drag(tab_x(0), r.top(), tab_x(2), r.top());
+    assert_eq!(captions(&driver, h), ["A", "B", "C", "D", "E"]);
+}
+
+#[test]
+fn check_overflow_menu() {
+    let mut driver = crate::testing::TestDriver::new(App::debug(80, 20, "")).unwrap();
+    // only 2 tabs (of 13 characters) fit next to the overflow button
+    let h = create_tab_window(&mut driver, tab::Flags::None, 30);
+    let r = driver.bounds(h).unwrap();
+    let line = |driver: &crate::testing::TestDriver| driver.screen_text().lines().nth(r.top() as usize).unwrap().to_string();
+    assert!(line(&driver).contains('▼'));
+    assert!(line(&driver).contains('A') && line(&driver).contains('B') && !line(&driver).contains('C'));
+
+    // the current tab is always visible
+    driver.get_mut(h).unwrap().set_current_tab(4);
+    driver.settle();
+    assert!(line(&driver).contains('D') && line(&driver).contains('E') && !line(&driver).contains('A'));
+
+    // the overflow button opens a menu with all the tabs
+    driver.click_at(r.right() - 1, r.top());
+    let text = driver.screen_text();
+    assert!(["A", "B", "C", "D", "E"].iter().all(|c| text.contains(&format!("{c}  "))));
+    driver.press(key!("Home"));
+    driver.press(key!("Enter"));
+    assert_eq!(driver.get(h).unwrap().current_tab(), Some(0));
+    assert!(line(&driver).contains('A') && !line(&driver).contains('E'));
+}
+
+#[test]
+fn check_dirty_indicator() {
+    let mut driver = crate::testing::TestDriver::new(App::debug(80, 20, "")).unwrap();
+    let h = create_tab_window(&mut driver, tab::Flags::None, 70);
+    let r = driver.bounds(h).unwrap();
+    assert!(!driver.get(h).unwrap().is_tab_dirty(1));
+    driver.get_mut(h).unwrap().set_tab_dirty(1, true);
+    driver.settle();
+    assert!(driver.get(h).unwrap().is_tab_dirty(1));
+    assert!(!driver.get(h).unwrap().is_tab_dirty(100));
+    let line = driver.screen_text().lines().nth(r.top() as usize).unwrap().to_string();
+    assert_eq!(line.chars().nth((r.left() + 14) as usize), Some('●'));
+}
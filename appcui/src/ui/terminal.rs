@@ -0,0 +1,17 @@
+//! A terminal emulator UI control that runs a child process (a shell, `htop`, a build command, etc.) inside a window.
+//!
+//! The Terminal control spawns the child process on a pseudo-terminal (unix only), interprets its
+//! VT100 / xterm output (colors, cursor movement, alternate screen, scroll regions) and keeps the
+//! lines that leave the screen in a scrollback buffer. Keyboard and mouse input is forwarded to the
+//! child process and the pseudo-terminal is resized together with the control.
+
+mod encoder;
+pub mod events;
+mod parser;
+mod pty;
+mod screen;
+mod terminal;
+#[cfg(test)]
+mod tests;
+
+pub use self::terminal::Terminal;
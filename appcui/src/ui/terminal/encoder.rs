@@ -0,0 +1,150 @@
+use crate::input::{Key, KeyCode, KeyModifier, MouseButton};
+
+// the xterm modifier parameter (1 + Shift + 2*Alt + 4*Ctrl)
+fn modifier_param(modifier: KeyModifier) -> u8 {
+    let mut value = 1;
+    if modifier.contains(KeyModifier::Shift) {
+        value += 1;
+    }
+    if modifier.contains(KeyModifier::Alt) {
+        value += 2;
+    }
+    if modifier.contains(KeyModifier::Ctrl) {
+        value += 4;
+    }
+    value
+}
+
+// a key that is sent as `ESC [ <final>` or `ESC O <final>` (in application mode)
+fn cursor_key(output: &mut Vec<u8>, final_byte: char, modifier: KeyModifier, application_mode: bool) {
+    let m = modifier_param(modifier);
+    if m > 1 {
+        output.extend_from_slice(format!("\x1b[1;{m}{final_byte}").as_bytes());
+    } else if application_mode {
+        output.extend_from_slice(format!("\x1bO{final_byte}").as_bytes());
+    } else {
+        output.extend_from_slice(format!("\x1b[{final_byte}").as_bytes());
+    }
+}
+
+// a key that is sent as `ESC [ <code> ~`
+fn tilde_key(output: &mut Vec<u8>, code: u8, modifier: KeyModifier) {
+    let m = modifier_param(modifier);
+    if m > 1 {
+        output.extend_from_slice(format!("\x1b[{code};{m}~").as_bytes());
+    } else {
+        output.extend_from_slice(format!("\x1b[{code}~").as_bytes());
+    }
+}
+
+/// Converts a key (and the character associated with it) into the sequence of bytes an xterm compatible
+/// terminal sends to the child process. Returns `false` if the key has no representation.
+pub(super) fn encode_key(key: Key, character: char, application_cursor_keys: bool, output: &mut Vec<u8>) -> bool {
+    let modifier = key.modifier;
+    let alt = modifier.contains(KeyModifier::Alt);
+    let ctrl = modifier.contains(KeyModifier::Ctrl);
+    let start = output.len();
+    match key.code {
+        KeyCode::Up => cursor_key(output, 'A', modifier, application_cursor_keys),
+        KeyCode::Down => cursor_key(output, 'B', modifier, application_cursor_keys),
+        KeyCode::Right => cursor_key(output, 'C', modifier, application_cursor_keys),
+        KeyCode::Left => cursor_key(output, 'D', modifier, application_cursor_keys),
+        KeyCode::Home => cursor_key(output, 'H', modifier, application_cursor_keys),
+        KeyCode::End => cursor_key(output, 'F', modifier, application_cursor_keys),
+        KeyCode::F1 => cursor_key(output, 'P', modifier, true),
+        KeyCode::F2 => cursor_key(output, 'Q', modifier, true),
+        KeyCode::F3 => cursor_key(output, 'R', modifier, true),
+        KeyCode::F4 => cursor_key(output, 'S', modifier, true),
+        KeyCode::Insert => tilde_key(output, 2, modifier),
+        KeyCode::Delete => tilde_key(output, 3, modifier),
+        KeyCode::PageUp => tilde_key(output, 5, modifier),
+        KeyCode::PageDown => tilde_key(output, 6, modifier),
+        KeyCode::F5 => tilde_key(output, 15, modifier),
+        KeyCode::F6 => tilde_key(output, 17, modifier),
+        KeyCode::F7 => tilde_key(output, 18, modifier),
+        KeyCode::F8 => tilde_key(output, 19, modifier),
+        KeyCode::F9 => tilde_key(output, 20, modifier),
+        KeyCode::F10 => tilde_key(output, 21, modifier),
+        KeyCode::F11 => tilde_key(output, 23, modifier),
+        KeyCode::F12 => tilde_key(output, 24, modifier),
+        KeyCode::Tab if modifier.contains(KeyModifier::Shift) => output.extend_from_slice(b"\x1b[Z"),
+        _ => {
+            let byte = match key.code {
+                KeyCode::Enter => Some(b'\r'),
+                KeyCode::Escape => Some(0x1b),
+                KeyCode::Backspace => Some(if ctrl { 0x08 } else { 0x7f }),
+                KeyCode::Tab => Some(b'\t'),
+                KeyCode::Space if ctrl => Some(0),
+                code if ctrl && (code as u8 >= KeyCode::A as u8) && (code as u8 <= KeyCode::Z as u8) => Some(code as u8 - KeyCode::A as u8 + 1),
+                _ => None,
+            };
+            if let Some(byte) = byte {
+                output.push(byte);
+            } else if character != '\0' {
+                let mut buf = [0u8; 4];
+                output.extend_from_slice(character.encode_utf8(&mut buf).as_bytes());
+            } else if key.code == KeyCode::Space {
+                output.push(b' ');
+            } else if alt && (key.code as u8 >= KeyCode::A as u8) && (key.code as u8 <= KeyCode::Z as u8) {
+                output.push(b'a' + (key.code as u8 - KeyCode::A as u8));
+            } else if alt && (key.code as u8 >= KeyCode::N0 as u8) && (key.code as u8 <= KeyCode::N9 as u8) {
+                output.push(b'0' + (key.code as u8 - KeyCode::N0 as u8));
+            }
+            // Alt is sent as an ESC prefix
+            if alt && output.len() > start {
+                output.insert(start, 0x1b);
+            }
+        }
+    }
+    output.len() > start
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub(super) enum MouseAction {
+    Press,
+    Release,
+    Motion,
+    WheelUp,
+    WheelDown,
+}
+
+/// Converts a mouse event in the sequence of bytes expected by the child process (X10 or SGR encoding).
+/// The coordinates are relative to the terminal screen (0 based).
+pub(super) fn encode_mouse(action: MouseAction, button: MouseButton, x: i32, y: i32, modifier: KeyModifier, sgr: bool, output: &mut Vec<u8>) {
+    let mut code: u32 = match action {
+        MouseAction::WheelUp => 64,
+        MouseAction::WheelDown => 65,
+        _ => match button {
+            MouseButton::Left => 0,
+            MouseButton::Center => 1,
+            MouseButton::Right => 2,
+            MouseButton::None => 3,
+        },
+    };
+    if action == MouseAction::Motion {
+        code += 32;
+    }
+    if modifier.contains(KeyModifier::Shift) {
+        code += 4;
+    }
+    if modifier.contains(KeyModifier::Alt) {
+        code += 8;
+    }
+    if modifier.contains(KeyModifier::Ctrl) {
+        code += 16;
+    }
+    let (x, y) = (x.max(0) as u32 + 1, y.max(0) as u32 + 1);
+    if sgr {
+        let last = if action == MouseAction::Release { 'm' } else { 'M' };
+        output.extend_from_slice(format!("\x1b[<{code};{x};{y}{last}").as_bytes());
+    } else {
+        // the X10 encoding has no button information on release and is limited to 223 columns/rows
+        if action == MouseAction::Release {
+            code = 3 + (code & !3);
+        }
+        output.extend_from_slice(b"\x1b[M");
+        output.push((32 + code).min(255) as u8);
+        output.push((32 + x).min(255) as u8);
+        output.push((32 + y).min(255) as u8);
+    }
+}
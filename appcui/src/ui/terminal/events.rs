@@ -0,0 +1,20 @@
+use super::Terminal;
+use crate::{system::Handle, ui::common::traits::EventProcessStatus};
+
+pub trait TerminalEvents {
+    /// Called when the child process started by the terminal has ended. The `exit_code` is `None` if the
+    /// process was terminated by a signal (or killed via the `kill` method).
+    fn on_child_exited(&mut self, _handle: Handle<Terminal>, _exit_code: Option<i32>) -> EventProcessStatus {
+        EventProcessStatus::Ignored
+    }
+    /// Called when the child process changes the title of the terminal (via an OSC escape sequence).
+    fn on_title_changed(&mut self, _handle: Handle<Terminal>, _title: &str) -> EventProcessStatus {
+        EventProcessStatus::Ignored
+    }
+}
+
+#[derive(Copy, Clone)]
+pub(crate) enum EventData {
+    ChildExited { exit_code: Option<i32> },
+    TitleChanged,
+}
@@ -0,0 +1,237 @@
+// A VT100 / xterm escape sequence parser (based on the state machine described at https://vt100.net/emu/dec_ansi_parser).
+// The parser only splits the input in printable characters, control codes and escape sequences - their
+// meaning is given by the `Performer` (the terminal screen).
+
+const MAX_PARAMS: usize = 16;
+const MAX_OSC_LEN: usize = 4096;
+
+pub(super) trait Performer {
+    fn print(&mut self, ch: char);
+    fn execute(&mut self, byte: u8);
+    fn csi_dispatch(&mut self, params: &[u16], private: Option<u8>, intermediate: Option<u8>, action: u8);
+    fn esc_dispatch(&mut self, intermediate: Option<u8>, byte: u8);
+    fn osc_dispatch(&mut self, data: &[u8]);
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+enum State {
+    Ground,
+    Escape,
+    EscapeIntermediate,
+    Csi,
+    CsiIgnore,
+    Osc,
+    OscEscape,
+    // DCS, SOS, PM and APC strings are not supported (they are read and ignored)
+    IgnoreString,
+    IgnoreStringEscape,
+}
+
+pub(super) struct Parser {
+    state: State,
+    params: [u16; MAX_PARAMS],
+    params_count: usize,
+    current_param: Option<u32>,
+    private: Option<u8>,
+    intermediate: Option<u8>,
+    osc: Vec<u8>,
+    utf8: [u8; 4],
+    utf8_len: usize,
+    utf8_needed: usize,
+}
+
+impl Parser {
+    pub(super) fn new() -> Self {
+        Self {
+            state: State::Ground,
+            params: [0; MAX_PARAMS],
+            params_count: 0,
+            current_param: None,
+            private: None,
+            intermediate: None,
+            osc: Vec::new(),
+            utf8: [0; 4],
+            utf8_len: 0,
+            utf8_needed: 0,
+        }
+    }
+
+    pub(super) fn advance<P: Performer>(&mut self, performer: &mut P, bytes: &[u8]) {
+        for &byte in bytes {
+            self.advance_byte(performer, byte);
+        }
+    }
+
+    fn clear(&mut self) {
+        self.params_count = 0;
+        self.current_param = None;
+        self.private = None;
+        self.intermediate = None;
+    }
+
+    fn push_param(&mut self) {
+        if self.params_count < MAX_PARAMS {
+            self.params[self.params_count] = self.current_param.unwrap_or(0).min(u16::MAX as u32) as u16;
+            self.params_count += 1;
+        }
+        self.current_param = None;
+    }
+
+    fn advance_byte<P: Performer>(&mut self, performer: &mut P, byte: u8) {
+        // CAN and SUB cancel any sequence, ESC starts a new one (except for the strings that end with ESC \)
+        match byte {
+            0x18 | 0x1A => {
+                self.utf8_needed = 0;
+                self.state = State::Ground;
+                return;
+            }
+            0x1B if !matches!(self.state, State::Osc | State::IgnoreString) => {
+                self.utf8_needed = 0;
+                self.clear();
+                self.state = State::Escape;
+                return;
+            }
+            _ => {}
+        }
+        match self.state {
+            State::Ground => self.ground(performer, byte),
+            State::Escape => self.escape(performer, byte),
+            State::EscapeIntermediate => self.escape_intermediate(performer, byte),
+            State::Csi => self.csi(performer, byte),
+            State::CsiIgnore => {
+                if byte < 0x20 {
+                    performer.execute(byte);
+                } else if (0x40..=0x7E).contains(&byte) {
+                    self.state = State::Ground;
+                }
+            }
+            State::Osc => match byte {
+                0x07 => {
+                    performer.osc_dispatch(&self.osc);
+                    self.state = State::Ground;
+                }
+                0x1B => self.state = State::OscEscape,
+                _ => {
+                    if self.osc.len() < MAX_OSC_LEN {
+                        self.osc.push(byte);
+                    }
+                }
+            },
+            State::OscEscape => {
+                // ESC \ (string terminator) - any other character is ignored as well
+                performer.osc_dispatch(&self.osc);
+                self.state = State::Ground;
+            }
+            State::IgnoreString => {
+                if byte == 0x1B {
+                    self.state = State::IgnoreStringEscape;
+                } else if byte == 0x07 {
+                    self.state = State::Ground;
+                }
+            }
+            State::IgnoreStringEscape => self.state = State::Ground,
+        }
+    }
+
+    fn ground<P: Performer>(&mut self, performer: &mut P, byte: u8) {
+        if self.utf8_needed > 0 {
+            if byte & 0xC0 == 0x80 {
+                self.utf8[self.utf8_len] = byte;
+                self.utf8_len += 1;
+                if self.utf8_len == self.utf8_needed {
+                    self.utf8_needed = 0;
+                    let ch = std::str::from_utf8(&self.utf8[..self.utf8_len]).ok().and_then(|s| s.chars().next());
+                    performer.print(ch.unwrap_or(char::REPLACEMENT_CHARACTER));
+                }
+                return;
+            }
+            // incomplete sequence
+            self.utf8_needed = 0;
+            performer.print(char::REPLACEMENT_CHARACTER);
+        }
+        match byte {
+            0x00..=0x1F => performer.execute(byte),
+            0x20..=0x7E => performer.print(byte as char),
+            0x7F => {}
+            0xC2..=0xDF => self.start_utf8(byte, 2),
+            0xE0..=0xEF => self.start_utf8(byte, 3),
+            0xF0..=0xF4 => self.start_utf8(byte, 4),
+            _ => performer.print(char::REPLACEMENT_CHARACTER),
+        }
+    }
+
+    fn start_utf8(&mut self, byte: u8, len: usize) {
+        self.utf8[0] = byte;
+        self.utf8_len = 1;
+        self.utf8_needed = len;
+    }
+
+    fn escape<P: Performer>(&mut self, performer: &mut P, byte: u8) {
+        match byte {
+            0x00..=0x1F => performer.execute(byte),
+            b'[' => {
+                self.clear();
+                self.state = State::Csi;
+            }
+            b']' => {
+                self.osc.clear();
+                self.state = State::Osc;
+            }
+            b'P' | b'X' | b'^' | b'_' => self.state = State::IgnoreString,
+            0x20..=0x2F => {
+                self.intermediate = Some(byte);
+                self.state = State::EscapeIntermediate;
+            }
+            0x30..=0x7E => {
+                performer.esc_dispatch(None, byte);
+                self.state = State::Ground;
+            }
+            _ => self.state = State::Ground,
+        }
+    }
+
+    fn escape_intermediate<P: Performer>(&mut self, performer: &mut P, byte: u8) {
+        match byte {
+            0x00..=0x1F => performer.execute(byte),
+            0x20..=0x2F => {}
+            0x30..=0x7E => {
+                performer.esc_dispatch(self.intermediate, byte);
+                self.state = State::Ground;
+            }
+            _ => self.state = State::Ground,
+        }
+    }
+
+    fn csi<P: Performer>(&mut self, performer: &mut P, byte: u8) {
+        match byte {
+            0x00..=0x1F => performer.execute(byte),
+            b'0'..=b'9' => {
+                let value = self.current_param.unwrap_or(0);
+                self.current_param = Some(value.saturating_mul(10).saturating_add((byte - b'0') as u32));
+            }
+            b';' | b':' => {
+                // sub-parameters (e.g. 38:2:r:g:b) are treated as regular parameters
+                if self.current_param.is_none() {
+                    self.current_param = Some(0);
+                }
+                self.push_param();
+            }
+            b'<'..=b'?' => {
+                if self.params_count == 0 && self.current_param.is_none() && self.private.is_none() {
+                    self.private = Some(byte);
+                } else {
+                    self.state = State::CsiIgnore;
+                }
+            }
+            0x20..=0x2F => self.intermediate = Some(byte),
+            0x40..=0x7E => {
+                if self.current_param.is_some() {
+                    self.push_param();
+                }
+                performer.csi_dispatch(&self.params[..self.params_count], self.private, self.intermediate, byte);
+                self.state = State::Ground;
+            }
+            _ => self.state = State::CsiIgnore,
+        }
+    }
+}
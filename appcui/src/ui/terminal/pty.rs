@@ -0,0 +1,160 @@
+use crate::system::{Error, ErrorKind};
+#[cfg(target_family = "unix")]
+use std::sync::mpsc::{Receiver, TryRecvError};
+
+pub(super) enum Output {
+    Data(Vec<u8>),
+    Empty,
+    // the child process closed its side of the pseudo-terminal
+    Closed,
+}
+
+#[cfg(target_family = "unix")]
+pub(super) struct Pty {
+    master: std::fs::File,
+    child: std::process::Child,
+    output: Receiver<Vec<u8>>,
+}
+
+#[cfg(target_family = "unix")]
+impl Pty {
+    pub(super) fn spawn(command: &str, args: &[&str], cols: u16, rows: u16) -> Result<Pty, Error> {
+        use std::io::Read;
+        use std::os::fd::{FromRawFd, OwnedFd};
+        use std::os::unix::process::CommandExt;
+        use std::process::{Command, Stdio};
+
+        let mut master: libc::c_int = -1;
+        let mut slave: libc::c_int = -1;
+        let mut size = libc::winsize {
+            ws_row: rows,
+            ws_col: cols,
+            ws_xpixel: 0,
+            ws_ypixel: 0,
+        };
+        let os_error = |what: &str| Error::new(ErrorKind::InitializationFailure, format!("{what}: {}", std::io::Error::last_os_error()));
+        unsafe {
+            if libc::openpty(&mut master, &mut slave, std::ptr::null_mut(), std::ptr::null_mut(), std::ptr::addr_of_mut!(size)) != 0 {
+                return Err(os_error("Fail to create a pseudo-terminal"));
+            }
+            // the child process must not inherit the master side
+            libc::fcntl(master, libc::F_SETFD, libc::FD_CLOEXEC);
+        }
+        let master = unsafe { std::fs::File::from_raw_fd(master) };
+        let slave = unsafe { OwnedFd::from_raw_fd(slave) };
+        let stdio = |fd: &OwnedFd| {
+            fd.try_clone()
+                .map(Stdio::from)
+                .map_err(|e| Error::new(ErrorKind::InitializationFailure, format!("Fail to duplicate the pseudo-terminal: {e}")))
+        };
+        let mut cmd = Command::new(command);
+        cmd.args(args)
+            .stdin(stdio(&slave)?)
+            .stdout(stdio(&slave)?)
+            .stderr(stdio(&slave)?)
+            .env("TERM", "xterm-256color");
+        unsafe {
+            cmd.pre_exec(|| {
+                // a new session with the pseudo-terminal as its controlling terminal
+                if libc::setsid() < 0 || libc::ioctl(0, libc::TIOCSCTTY as _, 0) < 0 {
+                    return Err(std::io::Error::last_os_error());
+                }
+                Ok(())
+            });
+        }
+        let child = cmd
+            .spawn()
+            .map_err(|e| Error::new(ErrorKind::InitializationFailure, format!("Fail to start '{command}': {e}")))?;
+        // the parent keeps only the master side (the copies of the slave side are closed here)
+        drop(cmd);
+        drop(slave);
+
+        let mut reader = master
+            .try_clone()
+            .map_err(|e| Error::new(ErrorKind::InitializationFailure, format!("Fail to read from the pseudo-terminal: {e}")))?;
+        let (sender, output) = std::sync::mpsc::channel();
+        std::thread::spawn(move || {
+            let mut buffer = [0u8; 4096];
+            loop {
+                match reader.read(&mut buffer) {
+                    // EIO is returned once the child process (and all its descendants) closed the slave side
+                    Ok(0) | Err(_) => break,
+                    Ok(count) => {
+                        if sender.send(buffer[..count].to_vec()).is_err() {
+                            break;
+                        }
+                    }
+                }
+            }
+        });
+        Ok(Pty { master, child, output })
+    }
+
+    pub(super) fn write(&mut self, data: &[u8]) {
+        use std::io::Write;
+        let _ = self.master.write_all(data);
+    }
+
+    pub(super) fn resize(&self, cols: u16, rows: u16) {
+        use std::os::fd::AsRawFd;
+        let size = libc::winsize {
+            ws_row: rows,
+            ws_col: cols,
+            ws_xpixel: 0,
+            ws_ypixel: 0,
+        };
+        unsafe {
+            libc::ioctl(self.master.as_raw_fd(), libc::TIOCSWINSZ as _, &size);
+        }
+    }
+
+    pub(super) fn read(&self) -> Output {
+        match self.output.try_recv() {
+            Ok(data) => Output::Data(data),
+            Err(TryRecvError::Empty) => Output::Empty,
+            Err(TryRecvError::Disconnected) => Output::Closed,
+        }
+    }
+
+    // Some(exit code) if the child process has ended (the exit code is None if the process was killed by a signal)
+    pub(super) fn try_wait(&mut self) -> Option<Option<i32>> {
+        self.child.try_wait().ok().flatten().map(|status| status.code())
+    }
+
+    pub(super) fn kill(&mut self) {
+        if let Ok(None) = self.child.try_wait() {
+            let _ = self.child.kill();
+            let _ = self.child.wait();
+        }
+    }
+}
+
+#[cfg(target_family = "unix")]
+impl Drop for Pty {
+    fn drop(&mut self) {
+        self.kill();
+    }
+}
+
+// pseudo-terminals are only available on unix based systems
+#[cfg(not(target_family = "unix"))]
+pub(super) struct Pty {}
+
+#[cfg(not(target_family = "unix"))]
+impl Pty {
+    pub(super) fn spawn(_command: &str, _args: &[&str], _cols: u16, _rows: u16) -> Result<Pty, Error> {
+        Err(Error::new(
+            ErrorKind::InvalidFeature,
+            "Pseudo-terminals are only supported on unix based systems".to_string(),
+        ))
+    }
+    pub(super) fn write(&mut self, _data: &[u8]) {}
+    pub(super) fn resize(&self, _cols: u16, _rows: u16) {}
+    pub(super) fn read(&self) -> Output {
+        Output::Closed
+    }
+    pub(super) fn try_wait(&mut self) -> Option<Option<i32>> {
+        Some(None)
+    }
+    pub(super) fn kill(&mut self) {}
+}
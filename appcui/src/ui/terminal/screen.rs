@@ -0,0 +1,647 @@
+use super::parser::Performer;
+use crate::graphics::{CharFlags, Character, Color, Size, Surface};
+use std::collections::VecDeque;
+
+// the 16 ANSI colors (normal and bright)
+static ANSI_COLORS: [Color; 16] = [
+    Color::Black,
+    Color::DarkRed,
+    Color::DarkGreen,
+    Color::Olive,
+    Color::DarkBlue,
+    Color::Magenta,
+    Color::Teal,
+    Color::Silver,
+    Color::Gray,
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Pink,
+    Color::Aqua,
+    Color::White,
+];
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub(super) enum MouseTracking {
+    None,
+    // button press and release (1000)
+    Buttons,
+    // press, release and motion while a button is pressed (1002)
+    Drag,
+    // all events (1003)
+    Any,
+}
+
+#[derive(Copy, Clone)]
+struct Pen {
+    fore: Option<Color>,
+    back: Option<Color>,
+    flags: CharFlags,
+    reverse: bool,
+}
+impl Pen {
+    const DEFAULT: Pen = Pen {
+        fore: None,
+        back: None,
+        flags: CharFlags::None,
+        reverse: false,
+    };
+}
+
+#[derive(Copy, Clone)]
+struct SavedCursor {
+    x: i32,
+    y: i32,
+    pen: Pen,
+    line_drawing: bool,
+}
+
+pub(super) struct Screen {
+    surface: Surface,
+    // the main screen is saved here while the alternate screen is active
+    main_screen: Option<Surface>,
+    scrollback: VecDeque<Vec<Character>>,
+    scrollback_limit: usize,
+    default_fore: Color,
+    default_back: Color,
+    x: i32,
+    y: i32,
+    wrap_pending: bool,
+    pen: Pen,
+    saved_cursor: Option<SavedCursor>,
+    scroll_top: i32,
+    scroll_bottom: i32,
+    line_drawing: bool,
+    pub(super) cursor_visible: bool,
+    pub(super) application_cursor_keys: bool,
+    pub(super) mouse_tracking: MouseTracking,
+    pub(super) sgr_mouse: bool,
+    autowrap: bool,
+    title: String,
+    title_changed: bool,
+    // answers for the queries made by the child process (e.g. cursor position)
+    responses: Vec<u8>,
+}
+
+impl Screen {
+    pub(super) fn new(size: Size, scrollback_limit: usize, default_fore: Color, default_back: Color) -> Self {
+        let mut s = Self {
+            surface: Surface::new(size.width, size.height),
+            main_screen: None,
+            scrollback: VecDeque::new(),
+            scrollback_limit,
+            default_fore,
+            default_back,
+            x: 0,
+            y: 0,
+            wrap_pending: false,
+            pen: Pen::DEFAULT,
+            saved_cursor: None,
+            scroll_top: 0,
+            scroll_bottom: size.height as i32 - 1,
+            line_drawing: false,
+            cursor_visible: true,
+            application_cursor_keys: false,
+            mouse_tracking: MouseTracking::None,
+            sgr_mouse: false,
+            autowrap: true,
+            title: String::new(),
+            title_changed: false,
+            responses: Vec::new(),
+        };
+        let blank = s.blank();
+        s.surface.clear(blank);
+        s
+    }
+
+    #[inline(always)]
+    pub(super) fn size(&self) -> Size {
+        self.surface.size()
+    }
+    #[inline(always)]
+    pub(super) fn surface(&self) -> &Surface {
+        &self.surface
+    }
+    #[inline(always)]
+    pub(super) fn cursor(&self) -> (i32, i32) {
+        (self.x, self.y)
+    }
+    #[inline(always)]
+    pub(super) fn is_alternate_screen(&self) -> bool {
+        self.main_screen.is_some()
+    }
+    #[inline(always)]
+    pub(super) fn title(&self) -> &str {
+        &self.title
+    }
+    #[inline(always)]
+    pub(super) fn scrollback_len(&self) -> usize {
+        self.scrollback.len()
+    }
+    #[inline(always)]
+    pub(super) fn scrollback_line(&self, index: usize) -> Option<&[Character]> {
+        self.scrollback.get(index).map(|l| l.as_slice())
+    }
+    pub(super) fn set_scrollback_limit(&mut self, limit: usize) {
+        self.scrollback_limit = limit;
+        while self.scrollback.len() > limit {
+            self.scrollback.pop_front();
+        }
+    }
+    pub(super) fn clear_scrollback(&mut self) {
+        self.scrollback.clear();
+    }
+    pub(super) fn take_title_changed(&mut self) -> bool {
+        std::mem::replace(&mut self.title_changed, false)
+    }
+    pub(super) fn take_responses(&mut self) -> Vec<u8> {
+        std::mem::take(&mut self.responses)
+    }
+
+    // the text of a line from the screen (without the trailing spaces)
+    #[cfg(test)]
+    pub(super) fn line_text(&self, y: i32) -> String {
+        let w = self.surface.size().width as usize;
+        if y < 0 || y as usize >= self.surface.size().height as usize {
+            return String::new();
+        }
+        let start = y as usize * w;
+        self.surface.chars[start..start + w]
+            .iter()
+            .map(|c| c.code)
+            .collect::<String>()
+            .trim_end()
+            .to_string()
+    }
+
+    fn blank(&self) -> Character {
+        let back = if self.pen.reverse {
+            self.pen.fore.unwrap_or(self.default_fore)
+        } else {
+            self.pen.back.unwrap_or(self.default_back)
+        };
+        Character::new(' ', self.default_fore, back, CharFlags::None)
+    }
+
+    fn pen_character(&self, ch: char) -> Character {
+        let mut fore = self.pen.fore.unwrap_or(self.default_fore);
+        let mut back = self.pen.back.unwrap_or(self.default_back);
+        if self.pen.reverse {
+            std::mem::swap(&mut fore, &mut back);
+        }
+        Character::new(ch, fore, back, self.pen.flags)
+    }
+
+    #[inline(always)]
+    fn width(&self) -> i32 {
+        self.surface.size().width as i32
+    }
+    #[inline(always)]
+    fn height(&self) -> i32 {
+        self.surface.size().height as i32
+    }
+
+    fn fill(&mut self, from: (i32, i32), to: (i32, i32)) {
+        // fills (with blank characters) all cells from `from` to `to` (inclusive), in reading order
+        let w = self.width();
+        let start = (from.1 * w + from.0).max(0) as usize;
+        let end = ((to.1 * w + to.0 + 1).max(0) as usize).min(self.surface.chars.len());
+        let blank = self.blank();
+        if start < end {
+            self.surface.chars[start..end].fill(blank);
+        }
+    }
+
+    fn scroll_up(&mut self, count: i32) {
+        let w = self.width() as usize;
+        let (top, bottom) = (self.scroll_top as usize, self.scroll_bottom as usize);
+        let count = (count.max(0) as usize).min(bottom + 1 - top);
+        if count == 0 {
+            return;
+        }
+        // only the lines that leave the entire main screen are kept in the scrollback
+        if top == 0 && self.main_screen.is_none() && self.scrollback_limit > 0 {
+            for line in 0..count {
+                if self.scrollback.len() >= self.scrollback_limit {
+                    self.scrollback.pop_front();
+                }
+                self.scrollback.push_back(self.surface.chars[line * w..(line + 1) * w].to_vec());
+            }
+        }
+        self.surface.chars.copy_within((top + count) * w..(bottom + 1) * w, top * w);
+        let blank = self.blank();
+        self.surface.chars[(bottom + 1 - count) * w..(bottom + 1) * w].fill(blank);
+    }
+
+    fn scroll_down(&mut self, count: i32) {
+        let w = self.width() as usize;
+        let (top, bottom) = (self.scroll_top as usize, self.scroll_bottom as usize);
+        let count = (count.max(0) as usize).min(bottom + 1 - top);
+        if count == 0 {
+            return;
+        }
+        self.surface.chars.copy_within(top * w..(bottom + 1 - count) * w, (top + count) * w);
+        let blank = self.blank();
+        self.surface.chars[top * w..(top + count) * w].fill(blank);
+    }
+
+    fn line_feed(&mut self) {
+        self.wrap_pending = false;
+        if self.y == self.scroll_bottom {
+            self.scroll_up(1);
+        } else if self.y < self.height() - 1 {
+            self.y += 1;
+        }
+    }
+
+    fn reverse_index(&mut self) {
+        self.wrap_pending = false;
+        if self.y == self.scroll_top {
+            self.scroll_down(1);
+        } else if self.y > 0 {
+            self.y -= 1;
+        }
+    }
+
+    fn move_to(&mut self, x: i32, y: i32) {
+        self.wrap_pending = false;
+        self.x = x.clamp(0, self.width() - 1);
+        self.y = y.clamp(0, self.height() - 1);
+    }
+
+    fn save_cursor(&mut self) {
+        self.saved_cursor = Some(SavedCursor {
+            x: self.x,
+            y: self.y,
+            pen: self.pen,
+            line_drawing: self.line_drawing,
+        });
+    }
+
+    fn restore_cursor(&mut self) {
+        if let Some(saved) = self.saved_cursor {
+            self.pen = saved.pen;
+            self.line_drawing = saved.line_drawing;
+            self.move_to(saved.x, saved.y);
+        } else {
+            self.move_to(0, 0);
+        }
+    }
+
+    fn set_alternate_screen(&mut self, enabled: bool) {
+        if enabled == self.main_screen.is_some() {
+            return;
+        }
+        let size = self.surface.size();
+        if enabled {
+            let alternate = Surface::new(size.width, size.height);
+            self.main_screen = Some(std::mem::replace(&mut self.surface, alternate));
+            self.fill((0, 0), (self.width() - 1, self.height() - 1));
+        } else if let Some(main) = self.main_screen.take() {
+            self.surface = main;
+        }
+        self.scroll_top = 0;
+        self.scroll_bottom = self.height() - 1;
+    }
+
+    fn reset(&mut self) {
+        self.set_alternate_screen(false);
+        self.pen = Pen::DEFAULT;
+        self.saved_cursor = None;
+        self.line_drawing = false;
+        self.cursor_visible = true;
+        self.application_cursor_keys = false;
+        self.mouse_tracking = MouseTracking::None;
+        self.sgr_mouse = false;
+        self.autowrap = true;
+        self.scroll_top = 0;
+        self.scroll_bottom = self.height() - 1;
+        self.fill((0, 0), (self.width() - 1, self.height() - 1));
+        self.move_to(0, 0);
+    }
+
+    pub(super) fn resize(&mut self, size: Size) {
+        if size == self.surface.size() || size.width == 0 || size.height == 0 {
+            return;
+        }
+        // if the screen gets shorter, the lines from the top (above the cursor) are moved in the scrollback
+        let shift = (self.y + 1 - size.height as i32).max(0);
+        if shift > 0 && self.main_screen.is_none() {
+            self.scroll_top = 0;
+            self.scroll_bottom = self.height() - 1;
+            self.scroll_up(shift);
+        }
+        let blank = Character::new(' ', self.default_fore, self.default_back, CharFlags::None);
+        self.surface = Self::resized_surface(&self.surface, size, blank);
+        if let Some(main) = self.main_screen.as_ref() {
+            self.main_screen = Some(Self::resized_surface(main, size, blank));
+        }
+        self.scroll_top = 0;
+        self.scroll_bottom = size.height as i32 - 1;
+        let y = self.y - shift;
+        self.move_to(self.x, y);
+    }
+
+    fn resized_surface(source: &Surface, size: Size, blank: Character) -> Surface {
+        let mut s = Surface::new(size.width, size.height);
+        s.clear(blank);
+        let old = source.size();
+        let w = old.width.min(size.width) as usize;
+        for y in 0..old.height.min(size.height) as usize {
+            let src = y * old.width as usize;
+            let dst = y * size.width as usize;
+            s.chars[dst..dst + w].copy_from_slice(&source.chars[src..src + w]);
+        }
+        s
+    }
+
+    fn select_graphic_rendition(&mut self, params: &[u16]) {
+        if params.is_empty() {
+            self.pen = Pen::DEFAULT;
+            return;
+        }
+        let mut index = 0;
+        while index < params.len() {
+            match params[index] {
+                0 => self.pen = Pen::DEFAULT,
+                1 => self.pen.flags |= CharFlags::Bold,
+                3 => self.pen.flags |= CharFlags::Italic,
+                4 => self.pen.flags |= CharFlags::Underline,
+                7 => self.pen.reverse = true,
+                9 => self.pen.flags |= CharFlags::StrikeThrough,
+                21 => self.pen.flags |= CharFlags::DoubleUnderline,
+                22 => self.pen.flags.remove(CharFlags::Bold),
+                23 => self.pen.flags.remove(CharFlags::Italic),
+                24 => self.pen.flags.remove(CharFlags::Underline | CharFlags::DoubleUnderline),
+                27 => self.pen.reverse = false,
+                29 => self.pen.flags.remove(CharFlags::StrikeThrough),
+                value @ 30..=37 => self.pen.fore = Some(ANSI_COLORS[(value - 30) as usize]),
+                39 => self.pen.fore = None,
+                value @ 40..=47 => self.pen.back = Some(ANSI_COLORS[(value - 40) as usize]),
+                49 => self.pen.back = None,
+                value @ 90..=97 => self.pen.fore = Some(ANSI_COLORS[(value - 90 + 8) as usize]),
+                value @ 100..=107 => self.pen.back = Some(ANSI_COLORS[(value - 100 + 8) as usize]),
+                value @ (38 | 48) => {
+                    let (color, used) = Self::extended_color(&params[index + 1..]);
+                    index += used;
+                    if value == 38 {
+                        self.pen.fore = color.or(self.pen.fore);
+                    } else {
+                        self.pen.back = color.or(self.pen.back);
+                    }
+                }
+                _ => {}
+            }
+            index += 1;
+        }
+    }
+
+    // parses `5;n` (256 colors) or `2;r;g;b` (true colors) and returns the color and the number of parameters used
+    fn extended_color(params: &[u16]) -> (Option<Color>, usize) {
+        match params {
+            [5, n, ..] => (Some(Self::indexed_color(*n)), 2),
+            [2, r, g, b, ..] => (Some(Color::from_rgb(*r as u8, *g as u8, *b as u8)), 4),
+            _ => (None, params.len()),
+        }
+    }
+
+    fn indexed_color(index: u16) -> Color {
+        match index {
+            0..=15 => ANSI_COLORS[index as usize],
+            16..=231 => {
+                let value = index - 16;
+                let level = |v: u16| if v == 0 { 0 } else { (55 + v * 40) as u8 };
+                Color::from_rgb(level(value / 36), level((value / 6) % 6), level(value % 6))
+            }
+            _ => {
+                let gray = (8 + (index.min(255) - 232) * 10) as u8;
+                Color::from_rgb(gray, gray, gray)
+            }
+        }
+    }
+
+    fn set_mode(&mut self, params: &[u16], private: bool, enabled: bool) {
+        if !private {
+            // insert mode (4) and the other ANSI modes are not supported
+            return;
+        }
+        for mode in params {
+            match mode {
+                1 => self.application_cursor_keys = enabled,
+                7 => self.autowrap = enabled,
+                25 => self.cursor_visible = enabled,
+                47 | 1047 => self.set_alternate_screen(enabled),
+                1049 => {
+                    if enabled {
+                        self.save_cursor();
+                        self.set_alternate_screen(true);
+                    } else {
+                        self.set_alternate_screen(false);
+                        self.restore_cursor();
+                    }
+                }
+                1000 => self.mouse_tracking = if enabled { MouseTracking::Buttons } else { MouseTracking::None },
+                1002 => self.mouse_tracking = if enabled { MouseTracking::Drag } else { MouseTracking::None },
+                1003 => self.mouse_tracking = if enabled { MouseTracking::Any } else { MouseTracking::None },
+                1006 => self.sgr_mouse = enabled,
+                _ => {}
+            }
+        }
+    }
+
+    fn line_drawing_char(ch: char) -> char {
+        match ch {
+            '`' => '◆',
+            'a' => '▒',
+            'f' => '°',
+            'g' => '±',
+            'j' => '┘',
+            'k' => '┐',
+            'l' => '┌',
+            'm' => '└',
+            'n' => '┼',
+            'o' => '⎺',
+            'q' => '─',
+            's' => '⎽',
+            't' => '├',
+            'u' => '┤',
+            'v' => '┴',
+            'w' => '┬',
+            'x' => '│',
+            'y' => '≤',
+            'z' => '≥',
+            '{' => 'π',
+            '|' => '≠',
+            '}' => '£',
+            '~' => '·',
+            _ => ch,
+        }
+    }
+}
+
+impl Performer for Screen {
+    fn print(&mut self, ch: char) {
+        let ch = if self.line_drawing { Self::line_drawing_char(ch) } else { ch };
+        if self.wrap_pending {
+            self.x = 0;
+            self.line_feed();
+        }
+        let c = self.pen_character(ch);
+        self.surface.write_char(self.x, self.y, c);
+        if self.x + 1 < self.width() {
+            self.x += 1;
+        } else {
+            self.wrap_pending = self.autowrap;
+        }
+    }
+
+    fn execute(&mut self, byte: u8) {
+        match byte {
+            0x08 => {
+                self.wrap_pending = false;
+                self.x = (self.x - 1).max(0);
+            }
+            0x09 => {
+                self.wrap_pending = false;
+                self.x = ((self.x / 8 + 1) * 8).min(self.width() - 1);
+            }
+            0x0A..=0x0C => self.line_feed(),
+            0x0D => {
+                self.wrap_pending = false;
+                self.x = 0;
+            }
+            _ => {}
+        }
+    }
+
+    fn csi_dispatch(&mut self, params: &[u16], private: Option<u8>, intermediate: Option<u8>, action: u8) {
+        if intermediate.is_some() {
+            // DECSCUSR (cursor shape) and the other sequences with intermediates are not supported
+            return;
+        }
+        // the value of a parameter (0 or missing parameters are replaced with the default value)
+        let arg = |index: usize, default: u16| params.get(index).copied().filter(|v| *v != 0).unwrap_or(default) as i32;
+        let (w, h) = (self.width(), self.height());
+        match (private, action) {
+            (None, b'@') => {
+                let count = arg(0, 1).min(w - self.x) as usize;
+                let start = (self.y * w + self.x) as usize;
+                let end = ((self.y + 1) * w) as usize;
+                self.surface.chars.copy_within(start..end - count, start + count);
+                let blank = self.blank();
+                self.surface.chars[start..start + count].fill(blank);
+            }
+            (None, b'A') => self.move_to(
+                self.x,
+                (self.y - arg(0, 1)).max(if self.y >= self.scroll_top { self.scroll_top } else { 0 }),
+            ),
+            (None, b'B') | (None, b'e') => self.move_to(
+                self.x,
+                (self.y + arg(0, 1)).min(if self.y <= self.scroll_bottom { self.scroll_bottom } else { h - 1 }),
+            ),
+            (None, b'C') | (None, b'a') => self.move_to(self.x + arg(0, 1), self.y),
+            (None, b'D') => self.move_to(self.x - arg(0, 1), self.y),
+            (None, b'E') => self.move_to(0, self.y + arg(0, 1)),
+            (None, b'F') => self.move_to(0, self.y - arg(0, 1)),
+            (None, b'G') | (None, b'`') => self.move_to(arg(0, 1) - 1, self.y),
+            (None, b'H') | (None, b'f') => self.move_to(arg(1, 1) - 1, arg(0, 1) - 1),
+            (None, b'd') => self.move_to(self.x, arg(0, 1) - 1),
+            (_, b'J') => match params.first().copied().unwrap_or(0) {
+                0 => self.fill((self.x, self.y), (w - 1, h - 1)),
+                1 => self.fill((0, 0), (self.x, self.y)),
+                2 => self.fill((0, 0), (w - 1, h - 1)),
+                3 => self.scrollback.clear(),
+                _ => {}
+            },
+            (_, b'K') => match params.first().copied().unwrap_or(0) {
+                0 => self.fill((self.x, self.y), (w - 1, self.y)),
+                1 => self.fill((0, self.y), (self.x, self.y)),
+                2 => self.fill((0, self.y), (w - 1, self.y)),
+                _ => {}
+            },
+            (None, b'L') | (None, b'M') if self.y >= self.scroll_top && self.y <= self.scroll_bottom => {
+                let top = self.scroll_top;
+                self.scroll_top = self.y;
+                if action == b'L' {
+                    self.scroll_down(arg(0, 1));
+                } else {
+                    // deleted lines are not moved in the scrollback
+                    let limit = std::mem::replace(&mut self.scrollback_limit, 0);
+                    self.scroll_up(arg(0, 1));
+                    self.scrollback_limit = limit;
+                }
+                self.scroll_top = top;
+                self.move_to(0, self.y);
+            }
+            (None, b'P') => {
+                let count = arg(0, 1).min(w - self.x) as usize;
+                let start = (self.y * w + self.x) as usize;
+                let end = ((self.y + 1) * w) as usize;
+                self.surface.chars.copy_within(start + count..end, start);
+                let blank = self.blank();
+                self.surface.chars[end - count..end].fill(blank);
+            }
+            (None, b'S') => {
+                let limit = std::mem::replace(&mut self.scrollback_limit, 0);
+                self.scroll_up(arg(0, 1));
+                self.scrollback_limit = limit;
+            }
+            (None, b'T') => self.scroll_down(arg(0, 1)),
+            (None, b'X') => {
+                let count = arg(0, 1).min(w - self.x);
+                self.fill((self.x, self.y), (self.x + count - 1, self.y));
+            }
+            (None, b'm') => self.select_graphic_rendition(params),
+            (None, b'n') => match params.first() {
+                Some(5) => self.responses.extend_from_slice(b"\x1b[0n"),
+                Some(6) => self
+                    .responses
+                    .extend_from_slice(format!("\x1b[{};{}R", self.y + 1, self.x + 1).as_bytes()),
+                _ => {}
+            },
+            (None, b'c') => self.responses.extend_from_slice(b"\x1b[?1;2c"),
+            (None, b'r') => {
+                let top = arg(0, 1) - 1;
+                let bottom = arg(1, h as u16) - 1;
+                if top < bottom && bottom < h {
+                    self.scroll_top = top;
+                    self.scroll_bottom = bottom;
+                    self.move_to(0, 0);
+                }
+            }
+            (None, b's') => self.save_cursor(),
+            (None, b'u') => self.restore_cursor(),
+            (p, b'h') => self.set_mode(params, p == Some(b'?'), true),
+            (p, b'l') => self.set_mode(params, p == Some(b'?'), false),
+            _ => {}
+        }
+    }
+
+    fn esc_dispatch(&mut self, intermediate: Option<u8>, byte: u8) {
+        match (intermediate, byte) {
+            (None, b'7') => self.save_cursor(),
+            (None, b'8') => self.restore_cursor(),
+            (None, b'D') => self.line_feed(),
+            (None, b'E') => {
+                self.x = 0;
+                self.line_feed();
+            }
+            (None, b'M') => self.reverse_index(),
+            (None, b'c') => self.reset(),
+            (Some(b'('), b'0') => self.line_drawing = true,
+            (Some(b'('), _) => self.line_drawing = false,
+            _ => {}
+        }
+    }
+
+    fn osc_dispatch(&mut self, data: &[u8]) {
+        let text = String::from_utf8_lossy(data);
+        if let Some((command, value)) = text.split_once(';') {
+            if command == "0" || command == "2" {
+                self.title = value.to_string();
+                self.title_changed = true;
+            }
+        }
+    }
+}
@@ -0,0 +1,352 @@
+use super::encoder::{self, MouseAction};
+use super::events::EventData;
+use super::parser::Parser;
+use super::pty::{Output, Pty};
+use super::screen::{MouseTracking, Screen};
+use crate::prelude::*;
+use std::time::Duration;
+
+// how often the output of the child process is checked
+const REFRESH_INTERVAL: Duration = Duration::from_millis(20);
+// how many refreshes to wait for the remaining output after the child process has ended
+const EXIT_GRACE_TICKS: u32 = 5;
+const DEFAULT_SCROLLBACK_LIMIT: usize = 1000;
+const WHEEL_LINES: usize = 3;
+
+#[CustomControl(overwrite=OnPaint+OnKeyPressed+OnMouseEvent+OnResize, events=TimerEvents, internal=true)]
+pub struct Terminal {
+    screen: Screen,
+    parser: Parser,
+    pty: Option<Pty>,
+    // exit code of the child process and the number of refreshes since it ended
+    pending_exit: Option<(Option<i32>, u32)>,
+    exit_code: Option<i32>,
+    // number of scrollback lines the view is moved up
+    scroll_offset: usize,
+    pressed_button: MouseButton,
+    mouse_position: Point,
+}
+impl Terminal {
+    /// Creates a new (empty) terminal control with the specified layout. A child process can be
+    /// started in the terminal via the `spawn` method.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use appcui::prelude::*;
+    ///
+    /// let mut term = Terminal::new(layout!("d:f"));
+    /// if let Err(e) = term.spawn("/bin/bash", &["-i"]) {
+    ///     // the process could not be started (or pseudo-terminals are not supported)
+    ///     println!("{}", e);
+    /// }
+    /// ```
+    pub fn new(layout: Layout) -> Self {
+        Self {
+            base: ControlBase::with_status_flags(layout, StatusFlags::Visible | StatusFlags::Enabled | StatusFlags::AcceptInput),
+            screen: Screen::new(Size::new(80, 24), DEFAULT_SCROLLBACK_LIMIT, Color::Silver, Color::Black),
+            parser: Parser::new(),
+            pty: None,
+            pending_exit: None,
+            exit_code: None,
+            scroll_offset: 0,
+            pressed_button: MouseButton::None,
+            mouse_position: Point::ORIGIN,
+        }
+    }
+
+    /// Starts a child process (`command` with the specified arguments) on a new pseudo-terminal that has
+    /// the size of the control. If another process is running in the terminal, it is killed first.
+    /// The child process receives the `TERM=xterm-256color` environment variable.
+    ///
+    /// An error is returned if the pseudo-terminal can not be created, if the process can not be started
+    /// or if the current platform does not support pseudo-terminals (only unix based systems do).
+    pub fn spawn(&mut self, command: &str, args: &[&str]) -> Result<(), Error> {
+        self.kill();
+        let size = self.screen.size();
+        let pty = Pty::spawn(command, args, size.width as u16, size.height as u16)?;
+        self.pty = Some(pty);
+        self.pending_exit = None;
+        self.exit_code = None;
+        if let Some(timer) = self.timer() {
+            timer.start(REFRESH_INTERVAL);
+        }
+        Ok(())
+    }
+
+    /// Kills the child process (if any). The `on_child_exited` event is not sent in this case.
+    pub fn kill(&mut self) {
+        if let Some(mut pty) = self.pty.take() {
+            pty.kill();
+            if let Some(timer) = self.timer() {
+                timer.stop();
+            }
+        }
+    }
+
+    /// Returns `true` if a child process is running in the terminal.
+    #[inline(always)]
+    pub fn is_running(&self) -> bool {
+        self.pty.is_some()
+    }
+
+    /// Returns the exit code of the last child process that ended or `None` if the process is still running,
+    /// it was never started or it was terminated by a signal.
+    #[inline(always)]
+    pub fn exit_code(&self) -> Option<i32> {
+        self.exit_code
+    }
+
+    /// Sends the specified bytes to the child process (as if they were typed by the user).
+    pub fn write(&mut self, data: &[u8]) {
+        if let Some(pty) = self.pty.as_mut() {
+            pty.write(data);
+        }
+    }
+
+    /// Interprets the specified bytes as if they were written by the child process. This is useful to display
+    /// text with ANSI escape sequences (e.g. the saved output of a command) without starting a process.
+    pub fn feed(&mut self, data: &[u8]) {
+        self.parser.advance(&mut self.screen, data);
+        self.process_screen_changes();
+    }
+
+    /// Returns the title of the terminal (as it was set by the child process) or an empty string.
+    #[inline(always)]
+    pub fn title(&self) -> &str {
+        self.screen.title()
+    }
+
+    /// Returns the number of lines from the scrollback buffer.
+    #[inline(always)]
+    pub fn scrollback_len(&self) -> usize {
+        self.screen.scrollback_len()
+    }
+
+    /// Sets the maximum number of lines kept in the scrollback buffer (by default 1000). A value of `0`
+    /// disables the scrollback buffer.
+    pub fn set_scrollback_limit(&mut self, lines: usize) {
+        self.screen.set_scrollback_limit(lines);
+        self.scroll_offset = self.scroll_offset.min(self.screen.scrollback_len());
+    }
+
+    /// Removes all lines from the scrollback buffer.
+    pub fn clear_scrollback(&mut self) {
+        self.screen.clear_scrollback();
+        self.scroll_offset = 0;
+    }
+
+    /// Returns the number of scrollback lines the view is currently moved up (0 means that the screen of the
+    /// child process is displayed).
+    #[inline(always)]
+    pub fn scroll_offset(&self) -> usize {
+        self.scroll_offset
+    }
+
+    fn scroll_view(&mut self, lines: i32) {
+        let max = if self.screen.is_alternate_screen() {
+            0
+        } else {
+            self.screen.scrollback_len()
+        };
+        self.scroll_offset = (self.scroll_offset as i64 + lines as i64).clamp(0, max as i64) as usize;
+    }
+
+    fn process_screen_changes(&mut self) {
+        if self.screen.take_title_changed() {
+            self.raise_terminal_event(EventData::TitleChanged);
+        }
+        let responses = self.screen.take_responses();
+        if !responses.is_empty() {
+            self.write(&responses);
+        }
+    }
+
+    fn raise_terminal_event(&self, data: EventData) {
+        self.raise_event(ControlEvent {
+            emitter: self.handle,
+            receiver: self.event_processor,
+            data: ControlEventData::Terminal(data),
+        });
+    }
+
+    // reads the output of the child process and checks if the process has ended
+    fn refresh(&mut self) -> bool {
+        let Some(pty) = self.pty.as_mut() else {
+            return false;
+        };
+        let scrollback_len = self.screen.scrollback_len();
+        let mut changed = false;
+        let mut closed = false;
+        loop {
+            match pty.read() {
+                Output::Data(data) => {
+                    self.parser.advance(&mut self.screen, &data);
+                    changed = true;
+                }
+                Output::Empty => break,
+                Output::Closed => {
+                    closed = true;
+                    break;
+                }
+            }
+        }
+        if self.pending_exit.is_none() {
+            self.pending_exit = pty.try_wait().map(|code| (code, 0));
+        }
+        if changed {
+            // the view remains on the same lines if the user is looking at the scrollback
+            if self.scroll_offset > 0 {
+                let added = self.screen.scrollback_len().saturating_sub(scrollback_len);
+                self.scroll_offset = (self.scroll_offset + added).min(self.screen.scrollback_len());
+            }
+            self.process_screen_changes();
+        }
+        if let Some((exit_code, ticks)) = self.pending_exit {
+            // descendants of the child process might still keep the pseudo-terminal open
+            if closed || ticks >= EXIT_GRACE_TICKS {
+                self.pty = None;
+                self.pending_exit = None;
+                self.exit_code = exit_code;
+                if let Some(timer) = self.timer() {
+                    timer.stop();
+                }
+                self.raise_terminal_event(EventData::ChildExited { exit_code });
+                return true;
+            }
+            self.pending_exit = Some((exit_code, ticks + 1));
+        }
+        changed
+    }
+
+    fn forward_mouse(&mut self, action: MouseAction, button: MouseButton, x: i32, y: i32, modifier: KeyModifier) -> EventProcessStatus {
+        let accepted = match self.screen.mouse_tracking {
+            MouseTracking::None => false,
+            MouseTracking::Buttons => action != MouseAction::Motion,
+            MouseTracking::Drag => action != MouseAction::Motion || self.pressed_button != MouseButton::None,
+            MouseTracking::Any => true,
+        };
+        if !accepted || self.pty.is_none() {
+            return EventProcessStatus::Ignored;
+        }
+        let mut output = Vec::with_capacity(16);
+        encoder::encode_mouse(action, button, x, y, modifier, self.screen.sgr_mouse, &mut output);
+        self.write(&output);
+        EventProcessStatus::Processed
+    }
+}
+
+impl OnPaint for Terminal {
+    fn on_paint(&self, surface: &mut Surface, _theme: &Theme) {
+        let screen = self.screen.surface();
+        if self.scroll_offset == 0 {
+            surface.draw_surface(0, 0, screen);
+            let (x, y) = self.screen.cursor();
+            if self.has_focus() && self.screen.cursor_visible && self.pty.is_some() {
+                surface.set_cursor(x, y);
+            }
+            return;
+        }
+        // the top of the view is in the scrollback buffer
+        let first = self.screen.scrollback_len() - self.scroll_offset;
+        let width = screen.size().width as usize;
+        for y in 0..screen.size().height as usize {
+            let index = first + y;
+            let line = match self.screen.scrollback_line(index) {
+                Some(line) => line,
+                None => {
+                    let row = index - self.screen.scrollback_len();
+                    &screen.chars[row * width..(row + 1) * width]
+                }
+            };
+            for (x, ch) in line.iter().enumerate() {
+                surface.write_char(x as i32, y as i32, *ch);
+            }
+        }
+    }
+}
+
+impl OnKeyPressed for Terminal {
+    fn on_key_pressed(&mut self, key: Key, character: char) -> EventProcessStatus {
+        let page = self.screen.size().height as i32;
+        match key.value() {
+            key!("Shift+PageUp") => {
+                self.scroll_view(page);
+                return EventProcessStatus::Processed;
+            }
+            key!("Shift+PageDown") => {
+                self.scroll_view(-page);
+                return EventProcessStatus::Processed;
+            }
+            _ => {}
+        }
+        if self.pty.is_none() {
+            return EventProcessStatus::Ignored;
+        }
+        let mut output = Vec::with_capacity(8);
+        if encoder::encode_key(key, character, self.screen.application_cursor_keys, &mut output) {
+            self.scroll_offset = 0;
+            self.write(&output);
+            EventProcessStatus::Processed
+        } else {
+            EventProcessStatus::Ignored
+        }
+    }
+}
+
+impl OnMouseEvent for Terminal {
+    fn on_mouse_event(&mut self, event: &MouseEvent) -> EventProcessStatus {
+        match event {
+            MouseEvent::Over(p) => {
+                self.mouse_position = *p;
+                self.forward_mouse(MouseAction::Motion, MouseButton::None, p.x, p.y, KeyModifier::None)
+            }
+            MouseEvent::Pressed(data) => {
+                self.pressed_button = data.button;
+                self.forward_mouse(MouseAction::Press, data.button, data.x, data.y, data.modifier)
+            }
+            MouseEvent::Released(data) => {
+                let result = self.forward_mouse(MouseAction::Release, data.button, data.x, data.y, data.modifier);
+                self.pressed_button = MouseButton::None;
+                result
+            }
+            MouseEvent::Drag(data) => self.forward_mouse(MouseAction::Motion, self.pressed_button, data.x, data.y, data.modifier),
+            MouseEvent::Wheel(direction) => {
+                let (action, lines) = match direction {
+                    MouseWheelDirection::Up => (MouseAction::WheelUp, WHEEL_LINES as i32),
+                    MouseWheelDirection::Down => (MouseAction::WheelDown, -(WHEEL_LINES as i32)),
+                    _ => return EventProcessStatus::Ignored,
+                };
+                let (x, y) = (self.mouse_position.x, self.mouse_position.y);
+                if self.forward_mouse(action, MouseButton::None, x, y, KeyModifier::None) == EventProcessStatus::Processed {
+                    return EventProcessStatus::Processed;
+                }
+                self.scroll_view(lines);
+                EventProcessStatus::Processed
+            }
+            _ => EventProcessStatus::Ignored,
+        }
+    }
+}
+
+impl OnResize for Terminal {
+    fn on_resize(&mut self, _old_size: Size, new_size: Size) {
+        if new_size.width == 0 || new_size.height == 0 {
+            return;
+        }
+        self.screen.resize(new_size);
+        self.scroll_offset = self.scroll_offset.min(self.screen.scrollback_len());
+        if let Some(pty) = self.pty.as_ref() {
+            pty.resize(new_size.width as u16, new_size.height as u16);
+        }
+    }
+}
+
+impl TimerEvents for Terminal {
+    fn on_update(&mut self, _ticks: u64) -> EventProcessStatus {
+        if self.refresh() {
+            EventProcessStatus::Processed
+        } else {
+            EventProcessStatus::Ignored
+        }
+    }
+}
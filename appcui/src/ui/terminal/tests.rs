@@ -0,0 +1,250 @@
+use super::encoder::{self, MouseAction};
+use super::parser::Parser;
+use super::screen::{MouseTracking, Screen};
+use crate::prelude::*;
+use std::time::Duration;
+
+fn run(width: u32, height: u32, output: &str) -> Screen {
+    let mut screen = Screen::new(Size::new(width, height), 100, Color::Silver, Color::Black);
+    Parser::new().advance(&mut screen, output.as_bytes());
+    screen
+}
+
+fn lines(screen: &Screen) -> Vec<String> {
+    (0..screen.size().height as i32).map(|y| screen.line_text(y)).collect()
+}
+
+#[test]
+fn check_print_and_wrap() {
+    let screen = run(10, 3, "Hello\r\nThis line wraps\r\nX");
+    assert_eq!(lines(&screen), ["This line", "wraps", "X"]);
+    assert_eq!(screen.scrollback_len(), 1);
+    assert_eq!(screen.scrollback_line(0).unwrap()[0].code, 'H');
+    assert_eq!(screen.cursor(), (1, 2));
+}
+
+#[test]
+fn check_cursor_movement_and_erase() {
+    let screen = run(10, 3, "abcdefghij\x1b[2;3H123\x1b[1;5H\x1b[K\x1b[3;1HXYZ\x1b[2D\x1b[1P");
+    assert_eq!(lines(&screen), ["abcd", "  123", "XZ"]);
+    let screen = run(10, 3, "aaaa\r\nbbbb\r\ncccc\x1b[2;2H\x1b[1J");
+    assert_eq!(lines(&screen), ["", "  bb", "cccc"]);
+    let screen = run(10, 3, "aaaa\r\nbbbb\r\ncccc\x1b[2J");
+    assert_eq!(lines(&screen), ["", "", ""]);
+}
+
+#[test]
+fn check_colors() {
+    let screen = run(20, 2, "\x1b[31;44mR\x1b[0m \x1b[1;92mG\x1b[7mI\x1b[38;5;21mB\x1b[m");
+    let c = screen.surface().char(0, 0).unwrap();
+    assert_eq!((c.code, c.foreground, c.background), ('R', Color::DarkRed, Color::DarkBlue));
+    let c = screen.surface().char(1, 0).unwrap();
+    assert_eq!((c.foreground, c.background), (Color::Silver, Color::Black));
+    let c = screen.surface().char(2, 0).unwrap();
+    assert_eq!((c.foreground, c.flags), (Color::Green, CharFlags::Bold));
+    // reverse video
+    let c = screen.surface().char(3, 0).unwrap();
+    assert_eq!((c.foreground, c.background), (Color::Black, Color::Green));
+    let c = screen.surface().char(4, 0).unwrap();
+    assert_eq!(c.background, Color::from_rgb(0, 0, 255));
+}
+
+#[test]
+fn check_utf8_and_line_drawing() {
+    let mut screen = run(10, 2, "ăî→\x1b(0lqk\x1b(B");
+    Parser::new().advance(&mut screen, b"\xffq");
+    assert_eq!(screen.line_text(0), "ăî→┌─┐\u{FFFD}q");
+}
+
+#[test]
+fn check_scroll_region() {
+    let mut screen = run(5, 4, "1\r\n2\r\n3\r\n4\x1b[2;3r\x1b[3;1H\nX");
+    assert_eq!(lines(&screen), ["1", "3", "X", "4"]);
+    // lines scrolled inside a region are not kept in the scrollback
+    assert_eq!(screen.scrollback_len(), 0);
+    Parser::new().advance(&mut screen, b"\x1b[2;1H\x1bMY");
+    assert_eq!(lines(&screen), ["1", "Y", "3", "4"]);
+    Parser::new().advance(&mut screen, b"\x1b[r\x1b[1;1H\x1b[L");
+    assert_eq!(lines(&screen), ["", "1", "Y", "3"]);
+}
+
+#[test]
+fn check_alternate_screen() {
+    let mut screen = run(10, 3, "main\x1b[?1049h\x1b[?25l\x1b[Hfull screen app");
+    assert!(screen.is_alternate_screen());
+    assert!(!screen.cursor_visible);
+    assert_eq!(screen.line_text(0), "full scree");
+    Parser::new().advance(&mut screen, b"\x1b[?1049l\x1b[?25h");
+    assert!(!screen.is_alternate_screen());
+    assert_eq!(lines(&screen), ["main", "", ""]);
+    assert_eq!(screen.cursor(), (4, 0));
+}
+
+#[test]
+fn check_queries_and_title() {
+    let mut screen = run(10, 3, "ab\x1b[6n\x1b]0;My title\x07\x1b[c");
+    assert_eq!(screen.take_responses(), b"\x1b[1;3R\x1b[?1;2c");
+    assert!(screen.take_title_changed());
+    assert_eq!(screen.title(), "My title");
+    // OSC terminated by ESC \
+    Parser::new().advance(&mut screen, b"\x1b]2;Other\x1b\\");
+    assert_eq!(screen.title(), "Other");
+}
+
+#[test]
+fn check_sequences_split_across_reads() {
+    let mut screen = Screen::new(Size::new(10, 2), 10, Color::Silver, Color::Black);
+    let mut parser = Parser::new();
+    for chunk in [&b"\x1b"[..], b"[3", b"1m", b"\xc4", b"\x83", b"x"] {
+        parser.advance(&mut screen, chunk);
+    }
+    assert_eq!(screen.line_text(0), "ăx");
+    assert_eq!(screen.surface().char(0, 0).unwrap().foreground, Color::DarkRed);
+}
+
+#[test]
+fn check_resize() {
+    let mut screen = run(10, 4, "1\r\n2\r\n3\r\n4");
+    screen.resize(Size::new(5, 2));
+    // the lines above the cursor are moved in the scrollback
+    assert_eq!(lines(&screen), ["3", "4"]);
+    assert_eq!(screen.scrollback_len(), 2);
+    assert_eq!(screen.cursor(), (1, 1));
+    screen.resize(Size::new(8, 3));
+    assert_eq!(lines(&screen), ["3", "4", ""]);
+}
+
+#[test]
+fn check_scrollback_limit() {
+    let mut screen = Screen::new(Size::new(5, 2), 3, Color::Silver, Color::Black);
+    let text: String = (0..10).map(|i| format!("{i}\r\n")).collect();
+    Parser::new().advance(&mut screen, text.as_bytes());
+    assert_eq!(screen.scrollback_len(), 3);
+    assert_eq!(screen.scrollback_line(0).unwrap()[0].code, '6');
+    screen.set_scrollback_limit(1);
+    assert_eq!(screen.scrollback_line(0).unwrap()[0].code, '8');
+}
+
+#[test]
+fn check_key_encoding() {
+    let encode = |key: u16, ch: char, app: bool| {
+        let mut v = Vec::new();
+        encoder::encode_key(Key::from(key), ch, app, &mut v);
+        v
+    };
+    assert_eq!(encode(key!("A"), 'a', false), b"a");
+    assert_eq!(encode(key!("Shift+A"), 'A', false), b"A");
+    assert_eq!(encode(key!("Ctrl+C"), '\0', false), [3]);
+    assert_eq!(encode(key!("Alt+X"), '\0', false), b"\x1bx");
+    assert_eq!(encode(key!("Enter"), '\0', false), b"\r");
+    assert_eq!(encode(key!("Backspace"), '\0', false), [0x7f]);
+    assert_eq!(encode(key!("Up"), '\0', false), b"\x1b[A");
+    assert_eq!(encode(key!("Up"), '\0', true), b"\x1bOA");
+    assert_eq!(encode(key!("Ctrl+Right"), '\0', true), b"\x1b[1;5C");
+    assert_eq!(encode(key!("Delete"), '\0', false), b"\x1b[3~");
+    assert_eq!(encode(key!("Shift+F5"), '\0', false), b"\x1b[15;2~");
+    assert_eq!(encode(key!("F1"), '\0', false), b"\x1bOP");
+    assert_eq!(encode(key!("Shift+Tab"), '\0', false), b"\x1b[Z");
+    assert!(encode(Key::from(KeyCode::None).value(), '\0', false).is_empty());
+}
+
+#[test]
+fn check_mouse_encoding() {
+    let mut v = Vec::new();
+    encoder::encode_mouse(MouseAction::Press, MouseButton::Left, 4, 2, KeyModifier::None, true, &mut v);
+    encoder::encode_mouse(MouseAction::Release, MouseButton::Left, 4, 2, KeyModifier::None, true, &mut v);
+    assert_eq!(v, b"\x1b[<0;5;3M\x1b[<0;5;3m");
+    v.clear();
+    encoder::encode_mouse(MouseAction::WheelUp, MouseButton::None, 0, 0, KeyModifier::Ctrl, false, &mut v);
+    assert_eq!(v, [0x1b, b'[', b'M', 32 + 64 + 16, 33, 33]);
+    let screen = run(5, 2, "\x1b[?1002h\x1b[?1006h");
+    assert_eq!(screen.mouse_tracking, MouseTracking::Drag);
+    assert!(screen.sgr_mouse);
+}
+
+#[test]
+fn check_feed() {
+    let mut driver = crate::testing::TestDriver::new(App::debug(40, 10, "")).unwrap();
+    let mut w = Window::new("Term", layout!("x:0,y:0,w:22,h:6"), window::Flags::None);
+    let h = w.add(Terminal::new(layout!("d:f")));
+    driver.add_window(w);
+    driver.settle();
+    driver.get_mut(h).unwrap().feed(b"first\r\nsecond\r\nthird\r\nfourth\r\nfifth");
+    driver.settle();
+    let text = driver.screen_text();
+    assert!(!text.contains("first"));
+    assert!(text.contains("fifth"));
+    assert_eq!(driver.get(h).unwrap().scrollback_len(), 1);
+
+    // Shift+PageUp shows the scrollback buffer
+    driver.focus(h);
+    driver.press(key!("Shift+PageUp"));
+    assert_eq!(driver.get(h).unwrap().scroll_offset(), 1);
+    let text = driver.screen_text();
+    assert!(text.contains("first"));
+    assert!(!text.contains("fifth"));
+    driver.press(key!("Shift+PageDown"));
+    assert_eq!(driver.get(h).unwrap().scroll_offset(), 0);
+}
+
+#[cfg(target_family = "unix")]
+#[test]
+fn check_child_process() {
+    #[Window(events = TerminalEvents, internal = true)]
+    struct MyWin {
+        term: Handle<Terminal>,
+        exit_codes: Vec<Option<i32>>,
+        title: String,
+    }
+    impl TerminalEvents for MyWin {
+        fn on_child_exited(&mut self, _handle: Handle<Terminal>, exit_code: Option<i32>) -> EventProcessStatus {
+            self.exit_codes.push(exit_code);
+            EventProcessStatus::Processed
+        }
+        fn on_title_changed(&mut self, _handle: Handle<Terminal>, title: &str) -> EventProcessStatus {
+            self.title = title.to_string();
+            EventProcessStatus::Processed
+        }
+    }
+
+    let mut driver = crate::testing::TestDriver::new(App::debug(60, 12, "")).unwrap();
+    let mut w = MyWin {
+        base: Window::new("Term", layout!("x:0,y:0,w:42,h:8"), window::Flags::None),
+        term: Handle::None,
+        exit_codes: Vec::new(),
+        title: String::new(),
+    };
+    let mut term = Terminal::new(layout!("d:f"));
+    // the shell reads a line, prints the size of the terminal and exits
+    term.spawn(
+        "/bin/sh",
+        &["-c", "printf '\\033]0;sh\\007'; read line; echo \"got:$line\"; stty size; exit 3"],
+    )
+    .unwrap();
+    w.term = w.add(term);
+    let wh = driver.add_window(w);
+    driver.settle();
+    let h = driver.get(wh).unwrap().term;
+    assert!(driver.get(h).unwrap().is_running());
+    driver.focus(h);
+    driver.type_text("hello");
+    driver.press(key!("Enter"));
+    assert!(driver.wait_until(Duration::from_secs(5), |d| !d.get(wh).unwrap().exit_codes.is_empty()));
+    let text = driver.screen_text();
+    assert!(text.contains("got:hello"));
+    // the pseudo-terminal has the size of the control
+    assert!(text.contains("6 40"));
+    assert_eq!(driver.get(wh).unwrap().exit_codes, [Some(3)]);
+    assert_eq!(driver.get(wh).unwrap().title, "sh");
+    assert!(!driver.get(h).unwrap().is_running());
+    assert_eq!(driver.get(h).unwrap().exit_code(), Some(3));
+}
+
+#[test]
+fn check_spawn_failure() {
+    let mut driver = crate::testing::TestDriver::new(App::debug(60, 12, "")).unwrap();
+    let mut term = Terminal::new(layout!("d:f"));
+    assert!(term.spawn("/this/command/does/not/exist", &[]).is_err());
+    assert!(!term.is_running());
+    driver.settle();
+}
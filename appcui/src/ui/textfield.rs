@@ -1,11 +1,16 @@
 //! A textfield UI control for single-line text input and editing.
 //!
 //! The TextField control provides a way for users to enter and edit text.
-//! It supports features like text selection, clipboard operations, and input validation.
+//! It supports features like text selection, clipboard operations, input masks (e.g. `000.000.000.000`),
+//! inline validation and a list of suggestions (autocomplete) displayed while typing.
 
 mod textfield;
 mod initialization_flags;
 mod char_class;
+mod mask;
+mod validator;
+mod suggestions;
+mod suggestion_popup;
 pub mod selection;
 pub mod events;
 #[cfg(test)]
@@ -14,4 +19,7 @@ mod tests;
 use self::selection::Selection;
 pub use self::textfield::TextField;
 pub use self::initialization_flags::Flags;
+pub use self::mask::Mask;
+pub use self::validator::Validator;
+pub use self::suggestions::Suggestions;
 pub(crate) use self::char_class::CharClass;
\ No newline at end of file
@@ -3,8 +3,9 @@ use crate::{system::Handle, ui::common::traits::EventProcessStatus};
 
 #[derive(Copy, Clone, Eq, PartialEq)]
 pub(crate) enum TextFieldEventsType {
-    OnValidate,
-    OnTextChanged,
+    Validate,
+    TextChanged,
+    SuggestionsRequested,
 }
 
 pub trait TextFieldEvents {
@@ -14,6 +15,12 @@ pub trait TextFieldEvents {
     fn on_text_changed(&mut self, _handle: Handle<TextField>) -> EventProcessStatus {
         EventProcessStatus::Ignored
     }
+    /// Called for a TextField that uses `textfield::Suggestions::background(...)` after the user stops typing.
+    /// The suggestions for `text` should be computed (usually in a `BackgroundTask`) and sent back via
+    /// `TextField::set_suggestions(...)`.
+    fn on_suggestions_requested(&mut self, _handle: Handle<TextField>, _text: &str) -> EventProcessStatus {
+        EventProcessStatus::Ignored
+    }
 }
 #[derive(Copy, Clone)]
 pub(crate) struct EventData {
@@ -0,0 +1,310 @@
+const DEFAULT_PLACEHOLDER: char = '_';
+
+#[derive(Copy, Clone)]
+struct CustomClass {
+    symbol: char,
+    required: bool,
+    accepts: fn(char) -> bool,
+}
+
+#[derive(Copy, Clone)]
+pub(super) enum Slot {
+    Literal(char),
+    Input { accepts: fn(char) -> bool, required: bool },
+}
+
+/// An input mask for a `TextField`. A mask is a pattern where every character describes one position of the
+/// text: either a position where the user can type a character (and what characters are allowed there) or
+/// a literal character that is displayed as it is and skipped while typing.
+///
+/// The following characters are recognized by default:
+///
+/// | Character | Position                                    |
+/// | --------- | ------------------------------------------- |
+/// | `0`       | a digit (required)                          |
+/// | `9`       | a digit (optional)                          |
+/// | `L`       | a letter (required)                         |
+/// | `?`       | a letter (optional)                         |
+/// | `A`       | a letter or a digit (required)              |
+/// | `a`       | a letter or a digit (optional)              |
+/// | `&`       | any character (required)                    |
+/// | `C`       | any character (optional)                    |
+/// | `\`       | the next character is a literal             |
+///
+/// Every other character is a literal. New character classes (or different meanings for the characters from
+/// the previous table) can be added via the [`Mask::with_class`] method. The positions that were not filled
+/// are displayed using a placeholder character (`_` by default).
+///
+/// # Example
+///
+/// ```rust
+/// use appcui::prelude::*;
+///
+/// let ip = textfield::Mask::new("099.099.099.099");
+/// let phone = textfield::Mask::new("(999) 000-0000").with_placeholder(' ');
+/// // `H` is a hexadecimal digit
+/// let mac = textfield::Mask::new("HH:HH:HH:HH:HH:HH").with_class('H', true, |c| c.is_ascii_hexdigit());
+/// ```
+#[derive(Clone)]
+pub struct Mask {
+    pattern: String,
+    placeholder: char,
+    classes: Vec<CustomClass>,
+    slots: Vec<Slot>,
+}
+
+impl Mask {
+    /// Creates a new mask from a pattern (see the table from the [`Mask`] description for the meaning of
+    /// every character).
+    pub fn new(pattern: &str) -> Self {
+        let mut mask = Self {
+            pattern: pattern.to_string(),
+            placeholder: DEFAULT_PLACEHOLDER,
+            classes: Vec::new(),
+            slots: Vec::new(),
+        };
+        mask.parse();
+        mask
+    }
+
+    /// Sets the character that is displayed for the positions that were not filled yet.
+    pub fn with_placeholder(mut self, placeholder: char) -> Self {
+        self.placeholder = placeholder;
+        self
+    }
+
+    /// Adds a character class: every occurrence of `symbol` in the pattern will accept the characters for
+    /// which `accepts` returns `true`. If `required` is `true`, the value is not complete until that position
+    /// is filled. A custom class has priority over the default meaning of a character.
+    pub fn with_class(mut self, symbol: char, required: bool, accepts: fn(char) -> bool) -> Self {
+        self.classes.retain(|c| c.symbol != symbol);
+        self.classes.push(CustomClass { symbol, required, accepts });
+        self.parse();
+        self
+    }
+
+    /// Returns the pattern of the mask.
+    #[inline(always)]
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Returns the character that is displayed for the positions that were not filled yet.
+    #[inline(always)]
+    pub fn placeholder(&self) -> char {
+        self.placeholder
+    }
+
+    fn parse(&mut self) {
+        self.slots.clear();
+        let mut chars = self.pattern.chars();
+        while let Some(ch) = chars.next() {
+            if let Some(class) = self.classes.iter().find(|c| c.symbol == ch) {
+                self.slots.push(Slot::Input {
+                    accepts: class.accepts,
+                    required: class.required,
+                });
+                continue;
+            }
+            let slot = match ch {
+                '0' => Slot::Input {
+                    accepts: is_digit,
+                    required: true,
+                },
+                '9' => Slot::Input {
+                    accepts: is_digit,
+                    required: false,
+                },
+                'L' => Slot::Input {
+                    accepts: is_letter,
+                    required: true,
+                },
+                '?' => Slot::Input {
+                    accepts: is_letter,
+                    required: false,
+                },
+                'A' => Slot::Input {
+                    accepts: is_alphanumeric,
+                    required: true,
+                },
+                'a' => Slot::Input {
+                    accepts: is_alphanumeric,
+                    required: false,
+                },
+                '&' => Slot::Input {
+                    accepts: is_any,
+                    required: true,
+                },
+                'C' => Slot::Input {
+                    accepts: is_any,
+                    required: false,
+                },
+                '\\' => match chars.next() {
+                    Some(literal) => Slot::Literal(literal),
+                    None => Slot::Literal('\\'),
+                },
+                _ => Slot::Literal(ch),
+            };
+            self.slots.push(slot);
+        }
+    }
+}
+
+fn is_digit(ch: char) -> bool {
+    ch.is_ascii_digit()
+}
+fn is_letter(ch: char) -> bool {
+    ch.is_alphabetic()
+}
+fn is_alphanumeric(ch: char) -> bool {
+    ch.is_alphanumeric()
+}
+fn is_any(ch: char) -> bool {
+    !ch.is_control()
+}
+
+/// The content of a TextField that uses a mask (a value for every input position of the mask).
+pub(super) struct MaskedText {
+    mask: Mask,
+    values: Vec<Option<char>>,
+}
+
+impl MaskedText {
+    pub(super) fn new(mask: Mask) -> Self {
+        let values = vec![None; mask.slots.len()];
+        Self { mask, values }
+    }
+    #[inline(always)]
+    pub(super) fn mask(&self) -> &Mask {
+        &self.mask
+    }
+    #[inline(always)]
+    pub(super) fn len(&self) -> usize {
+        self.values.len()
+    }
+    #[inline(always)]
+    pub(super) fn is_placeholder(&self, pos: usize) -> bool {
+        matches!(self.mask.slots.get(pos), Some(Slot::Input { .. })) && self.values[pos].is_none()
+    }
+    fn is_input(&self, pos: usize) -> bool {
+        matches!(self.mask.slots.get(pos), Some(Slot::Input { .. }))
+    }
+
+    /// The text that is displayed (literals, the characters that were typed and placeholders)
+    pub(super) fn display_text(&self) -> String {
+        self.mask
+            .slots
+            .iter()
+            .zip(self.values.iter())
+            .map(|(slot, value)| match slot {
+                Slot::Literal(ch) => *ch,
+                Slot::Input { .. } => value.unwrap_or(self.mask.placeholder),
+            })
+            .collect()
+    }
+
+    /// The text without the positions that were not filled
+    pub(super) fn text_without_placeholders(&self) -> String {
+        self.mask
+            .slots
+            .iter()
+            .zip(self.values.iter())
+            .filter_map(|(slot, value)| match slot {
+                Slot::Literal(ch) => Some(*ch),
+                Slot::Input { .. } => *value,
+            })
+            .collect()
+    }
+
+    #[inline(always)]
+    pub(super) fn is_empty(&self) -> bool {
+        self.values.iter().all(|v| v.is_none())
+    }
+
+    /// `true` if all required positions were filled
+    pub(super) fn is_complete(&self) -> bool {
+        self.mask
+            .slots
+            .iter()
+            .zip(self.values.iter())
+            .all(|(slot, value)| !matches!(slot, Slot::Input { required: true, .. }) || value.is_some())
+    }
+
+    /// The first input position starting with `pos` (or the length of the mask if there is none)
+    pub(super) fn next_input(&self, pos: usize) -> usize {
+        (pos..self.len()).find(|p| self.is_input(*p)).unwrap_or(self.len())
+    }
+
+    /// The last input position before `pos`
+    pub(super) fn previous_input(&self, pos: usize) -> Option<usize> {
+        (0..pos.min(self.len())).rev().find(|p| self.is_input(*p))
+    }
+
+    /// The first input position that was not filled (or the length of the mask if all of them were filled)
+    pub(super) fn first_empty(&self) -> usize {
+        (0..self.len()).find(|p| self.is_placeholder(*p)).unwrap_or(self.len())
+    }
+
+    /// The position after the last input position that was filled (or 0 if none was filled)
+    pub(super) fn filled_end(&self) -> usize {
+        self.values.iter().rposition(|v| v.is_some()).map(|p| p + 1).unwrap_or(0)
+    }
+
+    /// Types a character at position `pos`. If the character is accepted by the next input position, that
+    /// position is filled. Otherwise, if the character is one of the next literals, the cursor is moved after
+    /// that literal (skipping the positions in between). Returns the new position of the cursor (right after
+    /// the filled position or the skipped literal) or `None` if the character can not be typed.
+    pub(super) fn type_char(&mut self, pos: usize, ch: char) -> Option<usize> {
+        let input = self.next_input(pos);
+        if let Some(Slot::Input { accepts, .. }) = self.mask.slots.get(input) {
+            if accepts(ch) {
+                self.values[input] = Some(ch);
+                return Some(input + 1);
+            }
+        }
+        let literal = (pos..self.len()).find(|p| matches!(self.mask.slots[*p], Slot::Literal(l) if l == ch))?;
+        Some(literal + 1)
+    }
+
+    /// Sets the content from a text. If the text has the same format as the one that is displayed (e.g. the
+    /// value returned by `display_text`) every character is placed on the same position. Otherwise, the
+    /// characters are typed one by one (the characters that can not be typed are ignored).
+    pub(super) fn set_text(&mut self, text: &str) {
+        self.values.iter_mut().for_each(|v| *v = None);
+        let chars: Vec<char> = text.chars().collect();
+        let aligned = chars.len() == self.len()
+            && self
+                .mask
+                .slots
+                .iter()
+                .zip(chars.iter())
+                .all(|(slot, ch)| !matches!(slot, Slot::Literal(l) if l != ch));
+        if aligned {
+            for (index, ch) in chars.iter().enumerate() {
+                if let Slot::Input { accepts, .. } = self.mask.slots[index] {
+                    if *ch != self.mask.placeholder && accepts(*ch) {
+                        self.values[index] = Some(*ch);
+                    }
+                }
+            }
+        } else {
+            self.type_text(0, text);
+        }
+    }
+
+    /// Types every character from `text` starting with position `pos` (the characters that can not be typed
+    /// are ignored). Returns the new position of the cursor.
+    pub(super) fn type_text(&mut self, pos: usize, text: &str) -> usize {
+        text.chars().fold(pos, |pos, ch| self.type_char(pos, ch).unwrap_or(pos))
+    }
+
+    /// Clears all input positions from the `start..end` range. Returns `true` if at least one was filled.
+    pub(super) fn clear(&mut self, start: usize, end: usize) -> bool {
+        let mut changed = false;
+        let end = end.min(self.len());
+        for value in self.values.iter_mut().take(end).skip(start) {
+            changed |= value.take().is_some();
+        }
+        changed
+    }
+}
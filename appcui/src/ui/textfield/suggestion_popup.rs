@@ -0,0 +1,209 @@
+use crate::prelude::*;
+use crate::utils::string_comparison::fuzzy_match;
+
+struct Match {
+    index: usize,
+    score: i32,
+    positions: Vec<usize>,
+}
+
+/// The list of suggestions displayed under (or above) a TextField while the control is expanded.
+pub(super) struct SuggestionPopup {
+    candidates: Vec<String>,
+    matches: Vec<Match>,
+    current: usize,
+    top: usize,
+    hovered: Option<usize>,
+    // the number of rows requested when the control was expanded and the number of rows that are actually
+    // visible (the space available on the screen can be smaller)
+    pub(super) requested_rows: usize,
+    rows: usize,
+    panel_y: i32,
+    // the vertical offset of the text (when the list is displayed above the TextField)
+    pub(super) text_y: i32,
+    // the list was closed with `Escape` (it is displayed again after the text changes)
+    pub(super) dismissed: bool,
+}
+
+impl SuggestionPopup {
+    pub(super) fn new() -> Self {
+        Self {
+            candidates: Vec::new(),
+            matches: Vec::new(),
+            current: 0,
+            top: 0,
+            hovered: None,
+            requested_rows: 0,
+            rows: 0,
+            panel_y: 1,
+            text_y: 0,
+            dismissed: false,
+        }
+    }
+
+    pub(super) fn set_candidates(&mut self, candidates: Vec<String>) {
+        self.candidates = candidates;
+    }
+
+    pub(super) fn clear(&mut self) {
+        self.candidates.clear();
+        self.matches.clear();
+        self.current = 0;
+        self.top = 0;
+        self.hovered = None;
+    }
+
+    /// Filters the candidates using fuzzy matching (the best matches first). An empty text does not match
+    /// anything and a candidate that is identical to the text is not displayed.
+    pub(super) fn refilter(&mut self, text: &str) {
+        self.matches.clear();
+        if !text.is_empty() {
+            let mut positions = Vec::new();
+            for (index, candidate) in self.candidates.iter().enumerate() {
+                if candidate == text {
+                    continue;
+                }
+                if let Some(score) = fuzzy_match(text, candidate, &mut positions) {
+                    self.matches.push(Match {
+                        index,
+                        score,
+                        positions: positions.clone(),
+                    });
+                }
+            }
+            // stable sort - candidates with the same score keep their order
+            self.matches.sort_by_key(|m| std::cmp::Reverse(m.score));
+        }
+        self.current = 0;
+        self.top = 0;
+        self.hovered = None;
+    }
+
+    #[inline(always)]
+    pub(super) fn is_empty(&self) -> bool {
+        self.matches.is_empty()
+    }
+
+    #[inline(always)]
+    pub(super) fn len(&self) -> usize {
+        self.matches.len()
+    }
+
+    /// The selected suggestion
+    pub(super) fn current(&self) -> Option<&str> {
+        self.matches.get(self.current).map(|m| self.candidates[m.index].as_str())
+    }
+
+    /// The part of the selected suggestion that completes `text` (if the suggestion starts with `text`)
+    pub(super) fn ghost(&self, text: &str) -> Option<&str> {
+        let suggestion = self.current()?;
+        let mut chars = suggestion.char_indices();
+        for ch in text.chars() {
+            let (_, c) = chars.next()?;
+            if !c.to_lowercase().eq(ch.to_lowercase()) {
+                return None;
+            }
+        }
+        let (offset, _) = chars.next()?;
+        Some(&suggestion[offset..])
+    }
+
+    pub(super) fn move_to(&mut self, index: usize) {
+        if self.matches.is_empty() {
+            return;
+        }
+        self.current = index.min(self.matches.len() - 1);
+        let rows = self.rows.max(1);
+        if self.current < self.top {
+            self.top = self.current;
+        } else if self.current >= self.top + rows {
+            self.top = self.current + 1 - rows;
+        }
+    }
+
+    #[inline(always)]
+    pub(super) fn current_index(&self) -> usize {
+        self.current
+    }
+
+    #[inline(always)]
+    pub(super) fn visible_rows(&self) -> usize {
+        self.rows.max(1)
+    }
+
+    pub(super) fn on_expand(&mut self, direction: ExpandedDirection, expanded_height: u32, height: u32) {
+        self.rows = expanded_height.saturating_sub(height + 2) as usize;
+        match direction {
+            ExpandedDirection::OnTop => {
+                self.panel_y = 0;
+                self.text_y = (expanded_height - height) as i32;
+            }
+            ExpandedDirection::OnBottom => {
+                self.panel_y = height as i32;
+                self.text_y = 0;
+            }
+        }
+        self.move_to(self.current);
+    }
+
+    pub(super) fn on_pack(&mut self) {
+        self.rows = 0;
+        self.text_y = 0;
+        self.hovered = None;
+    }
+
+    /// The index of the suggestion displayed at (`x`,`y`)
+    pub(super) fn hit_test(&self, x: i32, y: i32, width: u32) -> Option<usize> {
+        let row = y - (self.panel_y + 1);
+        if x < 1 || x >= (width as i32) - 1 || row < 0 || row >= self.rows as i32 {
+            return None;
+        }
+        let index = self.top + row as usize;
+        if index < self.matches.len() {
+            Some(index)
+        } else {
+            None
+        }
+    }
+
+    // returns `true` if the hovered item was changed
+    pub(super) fn set_hovered(&mut self, hovered: Option<usize>) -> bool {
+        if hovered != self.hovered {
+            self.hovered = hovered;
+            true
+        } else {
+            false
+        }
+    }
+
+    pub(super) fn paint(&self, surface: &mut Surface, theme: &Theme, width: u32) {
+        let rect = Rect::with_size(0, self.panel_y, width as u16, (self.rows + 2) as u16);
+        surface.fill_rect(rect, Character::with_attributes(' ', theme.menu.text.normal));
+        surface.draw_rect(rect, LineType::Single, theme.menu.text.normal);
+        let max_x = (width as i32) - 2;
+        for (row, m) in self.matches.iter().enumerate().skip(self.top).take(self.rows) {
+            let y = self.panel_y + 1 + (row - self.top) as i32;
+            let (attr, match_attr) = match () {
+                _ if row == self.current => (theme.menu.text.pressed_or_selectd, theme.menu.hotkey.pressed_or_selectd),
+                _ if Some(row) == self.hovered => (theme.menu.text.hovered, theme.menu.hotkey.hovered),
+                _ => (theme.menu.text.normal, theme.menu.hotkey.normal),
+            };
+            if (row == self.current) || (Some(row) == self.hovered) {
+                surface.fill_horizontal_line(1, y, max_x, Character::with_attributes(' ', attr));
+            }
+            // the suggestion (with the matched characters highlighted)
+            let mut next = 0;
+            for (index, ch) in self.candidates[m.index].chars().enumerate() {
+                let x = 2 + index as i32;
+                if x >= max_x {
+                    break;
+                }
+                let highlighted = m.positions.get(next) == Some(&index);
+                if highlighted {
+                    next += 1;
+                }
+                surface.write_char(x, y, Character::with_attributes(ch, if highlighted { match_attr } else { attr }));
+            }
+        }
+    }
+}
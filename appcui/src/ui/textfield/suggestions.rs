@@ -0,0 +1,78 @@
+use std::time::Duration;
+
+const DEFAULT_VISIBLE_ITEMS: u8 = 6;
+
+#[derive(Clone)]
+pub(super) enum Source {
+    List(Vec<String>),
+    Callback(fn(&str) -> Vec<String>),
+    Background(Duration),
+}
+
+/// The source of the suggestions (autocomplete) displayed by a `TextField` while the user types.
+///
+/// The suggestions can come from:
+/// * a static list ([`Suggestions::list`])
+/// * a callback that is called every time the text changes ([`Suggestions::callback`])
+/// * a background task ([`Suggestions::background`]) - after the user stops typing for a specific duration,
+///   the `TextFieldEvents::on_suggestions_requested` event is raised. The window can start a `BackgroundTask`
+///   that computes the suggestions and then send them to the TextField via the [`TextField::set_suggestions`](super::TextField::set_suggestions)
+///   method.
+///
+/// The suggestions are filtered using fuzzy matching (the typed characters must appear in the same order, but
+/// not necessarily one after another) and the matched characters are highlighted.
+///
+/// # Example
+///
+/// ```rust
+/// use appcui::prelude::*;
+/// use std::time::Duration;
+///
+/// let hosts = textfield::Suggestions::list(&["localhost", "example.com", "github.com"]);
+/// let commands = textfield::Suggestions::callback(|text| {
+///     ["build", "check", "clean", "run", "test"]
+///         .iter()
+///         .filter(|c| c.starts_with(text))
+///         .map(|c| c.to_string())
+///         .collect()
+/// });
+/// let files = textfield::Suggestions::background(Duration::from_millis(250)).with_visible_items(10);
+/// ```
+#[derive(Clone)]
+pub struct Suggestions {
+    pub(super) source: Source,
+    pub(super) visible_items: u8,
+}
+
+impl Suggestions {
+    /// Creates suggestions from a static list of items.
+    pub fn list(items: &[&str]) -> Self {
+        Self::with_source(Source::List(items.iter().map(|i| i.to_string()).collect()))
+    }
+
+    /// Creates suggestions that are obtained by calling `callback` (with the current text as a parameter)
+    /// every time the text changes.
+    pub fn callback(callback: fn(&str) -> Vec<String>) -> Self {
+        Self::with_source(Source::Callback(callback))
+    }
+
+    /// Creates suggestions that are computed asynchronously. The `TextFieldEvents::on_suggestions_requested`
+    /// event is raised only after the text was not changed for `debounce` (so that a slow lookup is not started
+    /// for every typed character). The results must be sent back via the `TextField::set_suggestions` method.
+    pub fn background(debounce: Duration) -> Self {
+        Self::with_source(Source::Background(debounce))
+    }
+
+    /// Sets the maximum number of suggestions that are visible at once (the list can be scrolled).
+    pub fn with_visible_items(mut self, count: u8) -> Self {
+        self.visible_items = count.max(1);
+        self
+    }
+
+    fn with_source(source: Source) -> Self {
+        Self {
+            source,
+            visible_items: DEFAULT_VISIBLE_ITEMS,
+        }
+    }
+}
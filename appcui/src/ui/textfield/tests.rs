@@ -1390,3 +1390,393 @@ fn check_text_changed_event_readonly() {
     a.add_window(MyWin::new());
     a.run();
 }
+
+mod masks_and_validation {
+    use super::super::{Mask, Validator};
+    use crate::prelude::*;
+    use crate::testing::TestDriver;
+
+    #[Window(events: TextFieldEvents, internal: true)]
+    struct ValidationWin {
+        validated: Vec<String>,
+    }
+    impl TextFieldEvents for ValidationWin {
+        fn on_validate(&mut self, _handle: Handle<TextField>, text: &str) -> EventProcessStatus {
+            self.validated.push(text.to_string());
+            EventProcessStatus::Processed
+        }
+    }
+
+    // a window with a text field (the text field has the focus and its text starts at (2,2) on the screen)
+    fn setup(create: impl FnOnce() -> TextField) -> (TestDriver, Handle<TextField>) {
+        let mut driver = TestDriver::new(App::debug(60, 10, "")).unwrap();
+        let textfield = create();
+        let mut w = ValidationWin {
+            base: window!("Test,x:0,y:0,w:40,h:6"),
+            validated: Vec::new(),
+        };
+        w.add(textfield);
+        w.add(button!("Ok,x:1,y:3,w:10"));
+        driver.add_window(w);
+        let h = driver.find::<TextField>().single();
+        driver.focus(h);
+        (driver, h)
+    }
+
+    fn masked(mask: Mask) -> TextField {
+        let mut t = TextField::new("", layout!("x:0,y:0,w:30"), textfield::Flags::ProcessEnter);
+        t.set_mask(mask);
+        t
+    }
+
+    fn text(driver: &TestDriver, h: Handle<TextField>) -> (String, String) {
+        let t = driver.get(h).unwrap();
+        (t.text().to_string(), t.text_without_placeholders())
+    }
+
+    #[test]
+    fn check_mask_typing() {
+        let (mut driver, h) = setup(|| masked(Mask::new("099.099.099.099")));
+        assert_eq!(text(&driver, h).0, "___.___.___.___");
+        // the dots skip the optional digits, letters are ignored
+        driver.type_text("192.16x8.1.1");
+        assert_eq!(text(&driver, h), ("192.168.1__.1__".to_string(), "192.168.1.1".to_string()));
+        assert!(driver.get(h).unwrap().is_mask_complete());
+    }
+
+    #[test]
+    fn check_mask_typing_full() {
+        let (mut driver, h) = setup(|| masked(Mask::new("(999) 000-0000")));
+        driver.type_text("555123456");
+        assert_eq!(text(&driver, h).0, "(555) 123-456_");
+        assert!(!driver.get(h).unwrap().is_mask_complete());
+        driver.type_text("7");
+        assert_eq!(text(&driver, h).0, "(555) 123-4567");
+        // the mask is full - nothing else can be typed
+        driver.type_text("8");
+        assert_eq!(text(&driver, h).0, "(555) 123-4567");
+    }
+
+    #[test]
+    fn check_mask_delete() {
+        let (mut driver, h) = setup(|| masked(Mask::new("(999) 000-0000")));
+        driver.type_text("5551234567");
+        // Back skips the literals
+        driver.press(key!("Home"));
+        driver.press(key!("Right"));
+        driver.press(key!("Right"));
+        driver.press(key!("Right"));
+        driver.press(key!("Right"));
+        driver.press(key!("Right"));
+        driver.press(key!("Back"));
+        assert_eq!(text(&driver, h).0, "(55_) 123-4567");
+        // Delete clears the next position (the cursor does not move)
+        driver.press(key!("Right"));
+        driver.press(key!("Delete"));
+        assert_eq!(text(&driver, h).0, "(55_) _23-4567");
+        driver.type_text("9");
+        assert_eq!(text(&driver, h).0, "(55_) 923-4567");
+        // typing over a selection
+        driver.press(key!("Ctrl+A"));
+        driver.type_text("1");
+        assert_eq!(text(&driver, h), ("(1__) ___-____".to_string(), "(1) -".to_string()));
+    }
+
+    #[test]
+    fn check_mask_set_text_and_custom_class() {
+        let _driver = TestDriver::new(App::debug(60, 10, "")).unwrap();
+        let mut t = masked(Mask::new("(999) 000-0000").with_placeholder(' '));
+        t.set_text("(555) 123-4567");
+        assert_eq!(t.text(), "(555) 123-4567");
+        t.set_text("555 12");
+        assert_eq!(t.text(), "(555) 12 -    ");
+        assert_eq!(t.mask().map(|m| m.pattern()), Some("(999) 000-0000"));
+        t.clear_mask();
+        assert_eq!(t.text(), "(555) 12-");
+
+        let mut t = masked(Mask::new("HH:HH:\\HH").with_class('H', true, |c| c.is_ascii_hexdigit()));
+        t.set_text("a1zb2");
+        assert_eq!(t.text(), "a1:b2:H_");
+        assert!(!t.is_mask_complete());
+        t.set_text("a1zb2fc");
+        assert_eq!(t.text(), "a1:b2:Hf");
+        assert!(t.is_mask_complete());
+    }
+
+    #[test]
+    fn check_incomplete_mask() {
+        let (mut driver, h) = setup(|| masked(Mask::new("00-00")));
+        // an empty masked text field is valid
+        assert!(driver.get(h).unwrap().is_valid());
+        driver.type_text("12");
+        assert!(driver.get(h).unwrap().error().is_none());
+        assert!(!driver.get(h).unwrap().is_valid());
+        // the error is reported when the focus is lost or when Enter is pressed
+        driver.press(key!("Tab"));
+        assert_eq!(driver.get(h).unwrap().error(), Some("The value is incomplete"));
+        driver.focus(h);
+        driver.press(key!("End"));
+        driver.press(key!("Enter"));
+        assert!(driver.screen_text().contains("The value is incomplete"));
+        let w = driver.find::<ValidationWin>().single();
+        assert!(driver.get(w).unwrap().validated.is_empty());
+        driver.type_text("34");
+        assert!(driver.get(h).unwrap().error().is_none());
+        driver.press(key!("Enter"));
+        assert_eq!(driver.get(w).unwrap().validated, vec!["12-34"]);
+    }
+
+    #[test]
+    fn check_regex_validator() {
+        let (mut driver, h) = setup(|| {
+            let mut t = TextField::new("", layout!("x:0,y:0,w:30"), textfield::Flags::None);
+            t.set_validator(Validator::regex("\\d+(\\.\\d+)?", "Not a number").unwrap());
+            t
+        });
+        driver.type_text("12.");
+        assert_eq!(driver.get(h).unwrap().error(), Some("Not a number"));
+        assert!(driver.screen_text().contains("Not a number"));
+        // the text is drawn with the error color
+        let error = RuntimeManager::get().theme().text.error.foreground;
+        assert_eq!(driver.screen().char(2, 1).unwrap().foreground, error);
+        driver.type_text("5");
+        assert!(driver.get(h).unwrap().error().is_none());
+        assert_ne!(driver.screen().char(2, 1).unwrap().foreground, error);
+        assert!(Validator::regex("(12", "").is_err());
+    }
+
+    #[test]
+    fn check_regex_validator_long_text() {
+        // a long (pasted) text must not overflow the stack of the regular expression engine
+        let long = format!("{}@example.com", "a".repeat(100_000));
+        let email = Validator::regex("[^@ ]+@.*\\..*", "Not an e-mail").unwrap();
+        assert!(email.check(&long).is_ok());
+        assert!(email.check(&long.replace('@', " ")).is_err());
+        assert!(Validator::regex("(a+)+b", "").unwrap().check(&"a".repeat(100_000)).is_err());
+        let (mut driver, h) = setup(|| {
+            let mut t = TextField::new("", layout!("x:0,y:0,w:30"), textfield::Flags::None);
+            t.set_validator(Validator::regex(".*@.*", "Missing '@'").unwrap());
+            t
+        });
+        driver.get_mut(h).unwrap().set_text(&long);
+        assert!(driver.get_mut(h).unwrap().validate());
+        driver.get_mut(h).unwrap().set_text(&"a".repeat(100_000));
+        assert!(!driver.get_mut(h).unwrap().validate());
+        assert_eq!(driver.get(h).unwrap().error(), Some("Missing '@'"));
+    }
+
+    #[test]
+    fn check_callback_validator_with_mask() {
+        let (mut driver, h) = setup(|| {
+            let mut t = masked(Mask::new("099.099.099.099"));
+            t.set_validator(Validator::new(|text| {
+                if text.split('.').all(|part| part.parse::<u8>().is_ok()) {
+                    Ok(())
+                } else {
+                    Err("Invalid IP address".to_string())
+                }
+            }));
+            t
+        });
+        driver.type_text("10.0.0.300");
+        assert_eq!(driver.get(h).unwrap().error(), Some("Invalid IP address"));
+        driver.press(key!("Back"));
+        assert!(driver.get(h).unwrap().is_valid());
+        driver.get_mut(h).unwrap().clear_validator();
+        assert!(driver.get_mut(h).unwrap().validate());
+    }
+
+    #[test]
+    fn check_mask_macro() {
+        let _driver = TestDriver::new(App::debug(60, 10, "")).unwrap();
+        let t = textfield!("'12345',mask:'00-00',x:0,y:0,w:20");
+        assert_eq!(t.text(), "12-34");
+    }
+}
+
+mod autocomplete {
+    use super::super::{Mask, Suggestions};
+    use crate::prelude::*;
+    use crate::testing::TestDriver;
+    use std::time::Duration;
+
+    #[Window(events: TextFieldEvents + BackgroundTaskEvents<Vec<String>, String>, internal: true)]
+    struct SuggestWin {
+        field: Handle<TextField>,
+        validated: Vec<String>,
+        changes: usize,
+        requests: Vec<String>,
+        query: String,
+    }
+    impl TextFieldEvents for SuggestWin {
+        fn on_validate(&mut self, _handle: Handle<TextField>, text: &str) -> EventProcessStatus {
+            self.validated.push(text.to_string());
+            EventProcessStatus::Processed
+        }
+        fn on_text_changed(&mut self, _handle: Handle<TextField>) -> EventProcessStatus {
+            self.changes += 1;
+            EventProcessStatus::Processed
+        }
+        fn on_suggestions_requested(&mut self, _handle: Handle<TextField>, text: &str) -> EventProcessStatus {
+            self.requests.push(text.to_string());
+            self.query = text.to_string();
+            BackgroundTask::<Vec<String>, String>::run(lookup, self.handle());
+            EventProcessStatus::Processed
+        }
+    }
+    impl BackgroundTaskEvents<Vec<String>, String> for SuggestWin {
+        fn on_update(&mut self, value: Vec<String>, _: &BackgroundTask<Vec<String>, String>) -> EventProcessStatus {
+            let (h, text) = (self.field, self.query.clone());
+            if let Some(field) = self.control_mut(h) {
+                field.set_suggestions(&text, value);
+            }
+            EventProcessStatus::Processed
+        }
+        fn on_query(&mut self, _: Vec<String>, _: &BackgroundTask<Vec<String>, String>) -> String {
+            self.query.clone()
+        }
+    }
+
+    fn lookup(conector: &BackgroundTaskConector<Vec<String>, String>) {
+        if let Some(text) = conector.query(Vec::new()) {
+            let hosts = ["alpha.example.com", "alpine.local", "beta.example.com"];
+            conector.notify(hosts.iter().filter(|h| h.starts_with(&text[..1])).map(|h| h.to_string()).collect());
+        }
+    }
+
+    // the text of the text field starts at (2,1) on the screen and the list of suggestions at (1,2)
+    fn setup(suggestions: Suggestions) -> (TestDriver, Handle<TextField>, Handle<SuggestWin>) {
+        let mut driver = TestDriver::new(App::debug(60, 12, "")).unwrap();
+        let mut field = TextField::new("", layout!("x:0,y:0,w:30"), textfield::Flags::ProcessEnter);
+        field.set_autocomplete(suggestions);
+        let mut w = SuggestWin {
+            base: window!("Test,x:0,y:0,w:40,h:8"),
+            field: Handle::None,
+            validated: Vec::new(),
+            changes: 0,
+            requests: Vec::new(),
+            query: String::new(),
+        };
+        w.field = w.add(field);
+        w.add(button!("Ok,x:1,y:5,w:10"));
+        let hw = driver.add_window(w);
+        let h = driver.find::<TextField>().single();
+        driver.focus(h);
+        (driver, h, hw)
+    }
+
+    fn line(driver: &TestDriver, y: usize) -> String {
+        driver.screen_text().lines().nth(y).unwrap_or_default().to_string()
+    }
+
+    fn hosts() -> Suggestions {
+        Suggestions::list(&["localhost", "example.com", "github.com", "gitlab.com"])
+    }
+
+    #[test]
+    fn check_list_navigation_and_ghost_text() {
+        let (mut driver, h, hw) = setup(hosts());
+        assert!(!driver.get(h).unwrap().is_expanded());
+        driver.type_text("git");
+        assert!(driver.get(h).unwrap().is_expanded());
+        assert!(line(&driver, 3).contains("github.com"));
+        assert!(line(&driver, 4).contains("gitlab.com"));
+        assert!(!driver.screen_text().contains("localhost"));
+        // the rest of the selected suggestion is displayed after the cursor
+        let inactive = RuntimeManager::get().theme().text.inactive.foreground;
+        assert_eq!(driver.screen().char(5, 1).unwrap().code, 'h');
+        assert_eq!(driver.screen().char(5, 1).unwrap().foreground, inactive);
+        assert_ne!(driver.screen().char(4, 1).unwrap().foreground, inactive);
+        driver.press(key!("Down"));
+        assert_eq!(driver.screen().char(5, 1).unwrap().code, 'l');
+        // the matched characters are highlighted
+        let theme = RuntimeManager::get().theme();
+        assert_eq!(driver.screen().char(3, 3).unwrap().foreground, theme.menu.hotkey.normal.foreground);
+        assert_eq!(driver.screen().char(6, 3).unwrap().foreground, theme.menu.text.normal.foreground);
+        driver.press(key!("Tab"));
+        assert_eq!(driver.get(h).unwrap().text(), "gitlab.com");
+        assert!(!driver.get(h).unwrap().is_expanded());
+        assert!(driver.has_focus(h));
+        assert_eq!(driver.get(hw).unwrap().changes, 4);
+    }
+
+    #[test]
+    fn check_enter_escape_and_mouse() {
+        let (mut driver, h, hw) = setup(hosts());
+        driver.type_text("ex");
+        // Enter accepts the suggestion (the text is not validated)
+        driver.press(key!("Enter"));
+        assert_eq!(driver.get(h).unwrap().text(), "example.com");
+        assert!(driver.get(hw).unwrap().validated.is_empty());
+        driver.press(key!("Enter"));
+        assert_eq!(driver.get(hw).unwrap().validated, vec!["example.com"]);
+
+        // Escape closes the list (it is displayed again after the text changes)
+        driver.press(key!("Back"));
+        assert!(driver.get(h).unwrap().is_expanded());
+        driver.press(key!("Escape"));
+        assert!(!driver.get(h).unwrap().is_expanded());
+        assert_eq!(driver.get(h).unwrap().text(), "example.co");
+
+        driver.press(key!("Ctrl+A"));
+        driver.type_text("g");
+        assert!(line(&driver, 4).contains("gitlab.com"));
+        driver.click_at(5, 4);
+        assert_eq!(driver.get(h).unwrap().text(), "gitlab.com");
+        assert!(!driver.get(h).unwrap().is_expanded());
+    }
+
+    #[test]
+    fn check_callback_suggestions() {
+        let (mut driver, h, _) = setup(Suggestions::callback(|text| {
+            ["build", "check", "clean", "run"]
+                .iter()
+                .filter(|c| c.starts_with(text))
+                .map(|c| c.to_string())
+                .collect()
+        }));
+        driver.type_text("c");
+        assert!(line(&driver, 3).contains("check"));
+        assert!(line(&driver, 4).contains("clean"));
+        // the list is resized when the number of suggestions changes
+        driver.type_text("l");
+        assert!(line(&driver, 3).contains("clean"));
+        assert!(!driver.screen_text().contains("check"));
+        assert!(line(&driver, 4).contains("└"));
+        assert_eq!(driver.screen().char(4, 1).unwrap().code, 'e');
+        driver.press(key!("Enter"));
+        assert_eq!(driver.get(h).unwrap().text(), "clean");
+        // nothing matches
+        driver.type_text("x");
+        assert!(!driver.get(h).unwrap().is_expanded());
+        // no suggestions for a text field with a mask
+        driver.get_mut(h).unwrap().set_mask(Mask::new("LLLL"));
+        driver.press(key!("Ctrl+A"));
+        driver.type_text("c");
+        assert!(!driver.get(h).unwrap().is_expanded());
+    }
+
+    #[test]
+    fn check_background_suggestions() {
+        let (mut driver, h, hw) = setup(Suggestions::background(Duration::from_millis(30)));
+        driver.type_text("alp");
+        // the suggestions are requested only after the user stops typing
+        assert!(driver.get(hw).unwrap().requests.is_empty());
+        assert!(driver.wait_until(Duration::from_secs(5), |d| d.get(h).unwrap().is_expanded()));
+        assert_eq!(driver.get(hw).unwrap().requests, vec!["alp"]);
+        assert!(line(&driver, 3).contains("alpha.example.com"));
+        assert!(line(&driver, 4).contains("alpine.local"));
+        assert!(!driver.screen_text().contains("beta"));
+        // the previous suggestions are filtered while the new ones are computed
+        driver.type_text("i");
+        assert!(line(&driver, 3).contains("alpine.local"));
+        assert!(!driver.screen_text().contains("alpha"));
+        // the suggestions for an older text are ignored
+        driver.get_mut(h).unwrap().set_suggestions("alp", vec!["alpaca".to_string()]);
+        driver.settle();
+        assert!(!driver.screen_text().contains("alpaca"));
+        assert!(driver.wait_until(Duration::from_secs(5), |d| d.get(hw).unwrap().requests.len() == 2));
+        driver.press(key!("Tab"));
+        assert_eq!(driver.get(h).unwrap().text(), "alpine.local");
+    }
+}
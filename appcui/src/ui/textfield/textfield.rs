@@ -1,6 +1,9 @@
 use super::{
     events::{EventData, TextFieldEventsType},
-    CharClass, Flags, Selection,
+    mask::MaskedText,
+    suggestion_popup::SuggestionPopup,
+    suggestions::Source,
+    CharClass, Flags, Mask, Selection, Suggestions, Validator,
 };
 use crate::prelude::*;
 use crate::utils::GlyphParser;
@@ -11,14 +14,21 @@ struct Cursor {
     end: usize,
 }
 
-#[CustomControl(overwrite=OnPaint+OnKeyPressed+OnMouseEvent+OnResize+OnFocus, internal=true)]
+#[CustomControl(overwrite=OnPaint+OnKeyPressed+OnMouseEvent+OnResize+OnFocus+OnExpand, events=TimerEvents, internal=true)]
 pub struct TextField {
     cursor: Cursor,
     selection: Selection,
     glyphs: String,
     drag_started: bool,
     flags: Flags,
+    masked: Option<MaskedText>,
+    validator: Option<Validator>,
+    error: Option<String>,
+    autocomplete: Option<Suggestions>,
+    popup: SuggestionPopup,
 }
+
+const INCOMPLETE_VALUE: &str = "The value is incomplete";
 impl TextField {
     /// Creates a new TextField control with the specified text, layout and flags.
     /// The flags can be a combination of the following values:
@@ -42,6 +52,11 @@ impl TextField {
             glyphs: String::from(text),
             drag_started: false,
             flags,
+            masked: None,
+            validator: None,
+            error: None,
+            autocomplete: None,
+            popup: SuggestionPopup::new(),
         };
         obj.set_size_bounds(3, 1, u16::MAX, u16::MAX);
         obj.cursor.pos = obj.glyphs.len();
@@ -54,20 +69,237 @@ impl TextField {
         self.flags.contains(Flags::Readonly)
     }
 
-    /// Returns the text of the TextField control.
+    /// Returns the text of the TextField control. If the TextField has a mask, the text is the one that is
+    /// displayed (including the literals of the mask and the placeholders for the positions that were not filled).
     #[inline(always)]
     pub fn text(&self) -> &str {
         &self.glyphs
     }
 
-    /// Sets the text of the TextField control.
+    /// Returns the text of the TextField control without the placeholders of the mask (e.g. `192.168.1.1`
+    /// instead of `192.168.1__.1__` for the `099.099.099.099` mask). If the TextField does not have a mask,
+    /// this is the same as the value returned by [`TextField::text`].
+    pub fn text_without_placeholders(&self) -> String {
+        match self.masked.as_ref() {
+            Some(masked) => masked.text_without_placeholders(),
+            None => self.glyphs.clone(),
+        }
+    }
+
+    /// Sets the text of the TextField control. If the TextField has a mask, the characters that can not be
+    /// placed in the mask are ignored.
     #[inline(always)]
     pub fn set_text(&mut self, text: &str) {
         self.cursor = Cursor { pos: 0, start: 0, end: 0 };
         self.selection = Selection::NONE;
         self.glyphs.clear();
-        self.glyphs.push_str(text);
-        self.move_cursor_to(self.glyphs.len(), false, true);
+        if let Some(masked) = self.masked.as_mut() {
+            masked.set_text(text);
+            self.glyphs.push_str(&masked.display_text());
+            let pos = self.glyphs.next_pos(0, masked.first_empty());
+            self.move_cursor_to(pos, false, true);
+        } else {
+            self.glyphs.push_str(text);
+            self.move_cursor_to(self.glyphs.len(), false, true);
+        }
+        self.update_error(false);
+        // the list of suggestions is displayed again only after the user changes the text
+        self.popup.dismissed = true;
+        self.update_popup();
+    }
+
+    /// Sets an input mask (e.g. `000.000.000.000` or `(999) 000-0000`). The current text is placed in the mask
+    /// (the characters that do not fit are ignored). See [`Mask`] for the available characters.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use appcui::prelude::*;
+    ///
+    /// let mut phone = TextField::new("", layout!("x:1,y:1,w:20"), textfield::Flags::None);
+    /// phone.set_mask(textfield::Mask::new("(999) 000-0000"));
+    /// ```
+    pub fn set_mask(&mut self, mask: Mask) {
+        let text = self.text_without_placeholders();
+        self.masked = Some(MaskedText::new(mask));
+        self.set_text(&text);
+    }
+
+    /// Removes the mask (the text will contain only the characters that were typed and the literals of the mask).
+    pub fn clear_mask(&mut self) {
+        if let Some(masked) = self.masked.take() {
+            self.set_text(&masked.text_without_placeholders());
+        }
+    }
+
+    /// Returns the mask of the TextField (if any).
+    #[inline(always)]
+    pub fn mask(&self) -> Option<&Mask> {
+        self.masked.as_ref().map(|m| m.mask())
+    }
+
+    /// Returns **true** if all required positions of the mask were filled (or if the TextField does not have a mask).
+    #[inline(always)]
+    pub fn is_mask_complete(&self) -> bool {
+        self.masked.as_ref().map(|m| m.is_complete()).unwrap_or(true)
+    }
+
+    /// Sets a validator that checks the text every time it changes. When the text is not valid, it is drawn with
+    /// the error colors of the theme and a tooltip explains the reason. For a TextField with a mask, the validator
+    /// receives the text without placeholders.
+    pub fn set_validator(&mut self, validator: Validator) {
+        self.validator = Some(validator);
+        self.update_error(false);
+    }
+
+    /// Removes the validator.
+    pub fn clear_validator(&mut self) {
+        self.validator = None;
+        self.update_error(false);
+    }
+
+    /// Returns **true** if the text is valid (the validator accepts it and all required positions of the mask
+    /// were filled).
+    pub fn is_valid(&self) -> bool {
+        self.compute_error(true).is_none()
+    }
+
+    /// Returns the reason why the text is not valid (or **None** if the text is valid). An incomplete mask is
+    /// reported only after the TextField loses the focus, after `Enter` is pressed or after [`TextField::validate`]
+    /// is called.
+    #[inline(always)]
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// Validates the text (including the required positions of the mask) and updates the error state.
+    /// Returns **true** if the text is valid.
+    pub fn validate(&mut self) -> bool {
+        self.update_error(true);
+        self.error.is_none()
+    }
+
+    /// Sets the source of the suggestions that are displayed (in a list under or above the TextField) while the
+    /// user types. The selected suggestion can be accepted with `Tab` or `Enter` and, if it starts with the typed
+    /// text, the rest of it is displayed after the cursor. Suggestions are not displayed for a TextField with a mask.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use appcui::prelude::*;
+    ///
+    /// let mut host = TextField::new("", layout!("x:1,y:1,w:30"), textfield::Flags::None);
+    /// host.set_autocomplete(textfield::Suggestions::list(&["localhost", "example.com", "github.com"]));
+    /// ```
+    pub fn set_autocomplete(&mut self, suggestions: Suggestions) {
+        self.popup.clear();
+        if let Source::List(items) = &suggestions.source {
+            self.popup.set_candidates(items.clone());
+        }
+        self.autocomplete = Some(suggestions);
+        self.update_popup();
+    }
+
+    /// Removes the source of the suggestions (the list of suggestions is closed if it is visible).
+    pub fn clear_autocomplete(&mut self) {
+        self.autocomplete = None;
+        self.popup.clear();
+        self.update_popup();
+    }
+
+    /// Sets the suggestions for `text` for a TextField that uses [`Suggestions::background`] (usually called when
+    /// a background task that computes the suggestions sends its results). The suggestions are ignored if the
+    /// text of the TextField was changed in the meantime.
+    pub fn set_suggestions(&mut self, text: &str, suggestions: Vec<String>) {
+        if self.autocomplete.is_none() || text != self.glyphs {
+            return;
+        }
+        self.popup.set_candidates(suggestions);
+        self.popup.refilter(&self.glyphs);
+        self.update_popup();
+    }
+
+    fn compute_error(&self, check_mask: bool) -> Option<String> {
+        let empty = match self.masked.as_ref() {
+            Some(masked) => {
+                if check_mask && !masked.is_empty() && !masked.is_complete() {
+                    return Some(INCOMPLETE_VALUE.to_string());
+                }
+                masked.is_empty()
+            }
+            None => self.glyphs.is_empty(),
+        };
+        if empty {
+            return None;
+        }
+        self.validator.as_ref()?.check(&self.text_without_placeholders()).err()
+    }
+
+    fn update_error(&mut self, check_mask: bool) {
+        let error = self.compute_error(check_mask);
+        if error != self.error {
+            if self.has_focus() {
+                match error.as_deref() {
+                    Some(message) => self.show_tooltip(message),
+                    None => self.hide_tooltip(),
+                }
+            }
+            self.error = error;
+        }
+    }
+
+    // recomputes the suggestions after the text was changed by the user
+    fn refresh_suggestions(&mut self) {
+        if self.masked.is_some() {
+            return;
+        }
+        let Some(autocomplete) = self.autocomplete.as_ref() else {
+            return;
+        };
+        let mut debounce = None;
+        match &autocomplete.source {
+            Source::List(_) => {}
+            Source::Callback(callback) => {
+                let candidates = if self.glyphs.is_empty() { Vec::new() } else { callback(&self.glyphs) };
+                self.popup.set_candidates(candidates);
+            }
+            Source::Background(duration) => debounce = Some(*duration),
+        }
+        // until the new suggestions are received, the previous ones are filtered with the new text
+        self.popup.dismissed = false;
+        self.popup.refilter(&self.glyphs);
+        if let Some(duration) = debounce {
+            if let Some(timer) = self.timer() {
+                timer.start(duration);
+            }
+        }
+        self.update_popup();
+    }
+
+    // expands the control if there are suggestions to display and packs it otherwise
+    fn update_popup(&mut self) {
+        let visible_items = self.autocomplete.as_ref().map(|a| a.visible_items as usize).unwrap_or(0);
+        if visible_items == 0 || self.masked.is_some() || !self.has_focus() || self.popup.dismissed || self.popup.is_empty() {
+            self.pack();
+            return;
+        }
+        // the list is resized (while expanded) if the number of suggestions changes
+        let rows = self.popup.len().min(visible_items);
+        if !self.is_expanded() || (rows != self.popup.requested_rows) {
+            self.popup.requested_rows = rows;
+            let size = self.size();
+            self.expand(
+                Size::new(size.width, size.height + 3),
+                Size::new(size.width, size.height + 2 + rows as u32),
+            );
+        }
+    }
+
+    // replaces the text with the selected suggestion; returns `true` if a suggestion was accepted
+    fn accept_suggestion(&mut self) -> bool {
+        let Some(suggestion) = self.popup.current().map(|s| s.to_string()) else {
+            return false;
+        };
+        self.set_text(&suggestion);
+        true
     }
 
     fn update_scroll_view(&mut self, force_end_update: bool) {
@@ -183,11 +415,84 @@ impl TextField {
                 .set_clipboard_text(&self.glyphs[self.selection.start..self.selection.end]);
         }
     }
+    #[inline(always)]
+    fn slot(&self, offset: usize) -> usize {
+        self.glyphs[..offset.min(self.glyphs.len())].count_glyphs()
+    }
+    // rebuilds the displayed text of a masked TextField and moves the cursor to the `pos` position of the mask
+    fn update_masked_text(&mut self, pos: usize) {
+        let Some(masked) = self.masked.as_ref() else {
+            return;
+        };
+        let start = self.slot(self.cursor.start);
+        self.glyphs = masked.display_text();
+        self.selection = Selection::NONE;
+        self.cursor.start = self.glyphs.next_pos(0, start);
+        self.cursor.pos = self.glyphs.next_pos(0, pos);
+        self.update_scroll_view(true);
+    }
+    // true if the text was changed, false otherwise
+    fn masked_type_text(&mut self, text: &str) -> bool {
+        let changed = self.masked_clear_selection();
+        let pos = self.slot(self.cursor.pos);
+        let Some(masked) = self.masked.as_mut() else {
+            return false;
+        };
+        let before = masked.display_text();
+        let new_pos = masked.type_text(pos, text);
+        let changed = changed || before != masked.display_text();
+        self.update_masked_text(new_pos);
+        changed
+    }
+    // true if the text was changed, false otherwise
+    fn masked_clear_selection(&mut self) -> bool {
+        if self.selection.is_empty() {
+            return false;
+        }
+        let start = self.slot(self.selection.start);
+        let end = self.slot(self.selection.end);
+        let Some(masked) = self.masked.as_mut() else {
+            return false;
+        };
+        let changed = masked.clear(start, end);
+        self.update_masked_text(start);
+        changed
+    }
+    // true if the text was changed, false otherwise
+    fn masked_delete(&mut self, backward: bool) -> bool {
+        if self.is_readonly() {
+            return false;
+        }
+        if !self.selection.is_empty() {
+            return self.masked_clear_selection();
+        }
+        let pos = self.slot(self.cursor.pos);
+        let Some(masked) = self.masked.as_mut() else {
+            return false;
+        };
+        let target = if backward {
+            masked.previous_input(pos)
+        } else {
+            Some(masked.next_input(pos)).filter(|p| *p < masked.len())
+        };
+        let Some(target) = target else {
+            return false;
+        };
+        let changed = masked.clear(target, target + 1);
+        self.update_masked_text(if backward { target } else { pos });
+        changed
+    }
     // true if the text was changed, false otherwise
     fn paste_text(&mut self) -> bool {
         if self.is_readonly() {
             return false;
         }
+        if self.masked.is_some() {
+            return match RuntimeManager::get().backend().clipboard_text() {
+                Some(txt) => self.masked_type_text(&txt),
+                None => false,
+            };
+        }
         let mut text_was_modified = false;
         if !self.selection.is_empty() {
             text_was_modified = self.delete_selection();
@@ -208,14 +513,18 @@ impl TextField {
             RuntimeManager::get()
                 .backend_mut()
                 .set_clipboard_text(&self.glyphs[self.selection.start..self.selection.end]);
-            self.delete_selection()
+            if self.masked.is_some() {
+                self.masked_clear_selection()
+            } else {
+                self.delete_selection()
+            }
         } else {
             false
         }
     }
     // true if the text was changed, false otherwise
     fn convert_selection_or_word(&mut self, callback: fn(text: &str) -> String) -> bool {
-        if self.is_readonly() {
+        if self.is_readonly() || self.masked.is_some() {
             return false;
         }
         if self.selection.is_empty() {
@@ -256,6 +565,9 @@ impl TextField {
     }
     // true if the text was changed, false otherwise
     fn delete_current_character(&mut self) -> bool {
+        if self.masked.is_some() {
+            return self.masked_delete(false);
+        }
         if self.is_readonly() {
             return false;
         }
@@ -273,6 +585,9 @@ impl TextField {
     }
     // true if the text was changed, false otherwise
     fn delete_previous_character(&mut self) -> bool {
+        if self.masked.is_some() {
+            return self.masked_delete(true);
+        }
         if self.is_readonly() {
             return false;
         }
@@ -293,6 +608,10 @@ impl TextField {
         if self.is_readonly() {
             return false;
         }
+        if self.masked.is_some() {
+            let mut buf = [0u8; 4];
+            return self.masked_type_text(character.encode_utf8(&mut buf));
+        }
         if !self.selection.is_empty() {
             self.delete_selection();
         }
@@ -322,12 +641,19 @@ impl TextField {
         }
     }
 
+    // the text was changed by the user (the suggestions are updated)
+    fn text_edited(&mut self) {
+        self.notify_text_changed();
+        self.refresh_suggestions();
+    }
+
     fn notify_text_changed(&mut self) {
+        self.update_error(false);
         self.raise_event(ControlEvent {
             emitter: self.handle,
             receiver: self.event_processor,
             data: ControlEventData::TextField(EventData {
-                evtype: TextFieldEventsType::OnTextChanged,
+                evtype: TextFieldEventsType::TextChanged,
             }),
         });
     }
@@ -354,6 +680,18 @@ impl OnPaint for TextField {
             _ => theme.editor.normal,
         };
         surface.clear(Character::with_attributes(' ', attr));
+        let expanded = self.is_expanded();
+        if expanded {
+            self.popup.paint(surface, theme, self.expanded_size().width);
+            surface.set_origin(0, self.popup.text_y);
+        }
+        // an invalid text is drawn with the error color and the placeholders of a mask with the inactive one
+        let text_attr = match self.error {
+            Some(_) if self.is_enabled() => CharAttribute::with_color(theme.text.error.foreground, attr.background),
+            _ => attr,
+        };
+        let placeholder_attr = CharAttribute::with_color(theme.text.inactive.foreground, attr.background);
+        let mut slot = if self.masked.is_some() { self.slot(self.cursor.start) } else { 0 };
         // paint
         let show_cursor = self.has_focus();
         let sz = self.size();
@@ -362,16 +700,19 @@ impl OnPaint for TextField {
         let mut pos = self.cursor.start;
         let mut x = 1;
         let mut y = 0;
-        let mut ch = Character::with_attributes(' ', attr);
+        let mut ch = Character::with_attributes(' ', text_attr);
         let mut ch_selected = Character::with_attributes(' ', theme.editor.pressed_or_selectd);
         while let Some((code, glyph_size)) = self.glyphs.glyph(pos) {
             if (show_cursor) && self.selection.contains(pos) {
                 ch_selected.code = code;
                 surface.write_char(x, y, ch_selected);
+            } else if self.masked.as_ref().map(|m| m.is_placeholder(slot)).unwrap_or(false) {
+                surface.write_char(x, y, Character::with_attributes(code, placeholder_attr));
             } else {
                 ch.code = code;
                 surface.write_char(x, y, ch);
             }
+            slot += 1;
             if show_cursor && (pos == self.cursor.pos) {
                 surface.set_cursor(x, y);
             }
@@ -386,6 +727,24 @@ impl OnPaint for TextField {
                 break;
             }
         }
+        // the rest of the selected suggestion is displayed after the cursor
+        if expanded && show_cursor && (pos == self.cursor.pos) && (pos == self.glyphs.len()) && self.selection.is_empty() {
+            if let Some(ghost) = self.popup.ghost(&self.glyphs) {
+                let ghost_attr = CharAttribute::with_color(theme.text.inactive.foreground, attr.background);
+                let (mut gx, mut gy) = (x, y);
+                for code in ghost.chars() {
+                    if gy >= sz.height as i32 {
+                        break;
+                    }
+                    surface.write_char(gx, gy, Character::with_attributes(code, ghost_attr));
+                    gx += 1;
+                    if gx >= w {
+                        gx = 1;
+                        gy += 1;
+                    }
+                }
+            }
+        }
         // if it is the last char
         if show_cursor && (pos == self.cursor.pos) {
             // if the cursor is located on the fist line outside the view --> put it on the last char but on previous line
@@ -399,6 +758,41 @@ impl OnPaint for TextField {
 }
 impl OnKeyPressed for TextField {
     fn on_key_pressed(&mut self, key: Key, character: char) -> EventProcessStatus {
+        // the control is expanded only when the list of suggestions is visible
+        if self.is_expanded() {
+            let rows = self.popup.visible_rows();
+            let current = self.popup.current_index();
+            match key.value() {
+                key!("Up") => {
+                    self.popup.move_to(current.saturating_sub(1));
+                    return EventProcessStatus::Processed;
+                }
+                key!("Down") => {
+                    self.popup.move_to(current + 1);
+                    return EventProcessStatus::Processed;
+                }
+                key!("PageUp") => {
+                    self.popup.move_to(current.saturating_sub(rows));
+                    return EventProcessStatus::Processed;
+                }
+                key!("PageDown") => {
+                    self.popup.move_to(current + rows);
+                    return EventProcessStatus::Processed;
+                }
+                key!("Tab") | key!("Enter") => {
+                    if self.accept_suggestion() {
+                        self.notify_text_changed();
+                    }
+                    return EventProcessStatus::Processed;
+                }
+                key!("Escape") => {
+                    self.popup.dismissed = true;
+                    self.update_popup();
+                    return EventProcessStatus::Processed;
+                }
+                _ => {}
+            }
+        }
         match key.value() {
             key!("Left") | key!("Shift+Left") => {
                 self.move_cursor_with(-1, key.modifier.contains(KeyModifier::Shift));
@@ -421,7 +815,12 @@ impl OnKeyPressed for TextField {
                 return EventProcessStatus::Processed;
             }
             key!("End") | key!("Shift+End") => {
-                self.move_cursor_to(self.glyphs.len(), key.modifier.contains(KeyModifier::Shift), false);
+                // for a mask, the cursor is moved after the last position that was filled
+                let end = match self.masked.as_ref() {
+                    Some(masked) => self.glyphs.next_pos(0, masked.filled_end()),
+                    None => self.glyphs.len(),
+                };
+                self.move_cursor_to(end, key.modifier.contains(KeyModifier::Shift), false);
                 return EventProcessStatus::Processed;
             }
             key!("Ctrl+Left") | key!("Ctrl+Shift+Left") => {
@@ -440,25 +839,25 @@ impl OnKeyPressed for TextField {
             // start checking if the text was changed
             key!("Ctrl+X") | key!("Shift+Del") => {
                 if self.cut_text() {
-                    self.notify_text_changed();
+                    self.text_edited();
                 }
                 return EventProcessStatus::Processed;
             }
             key!("Ctrl+V") | key!("Shift+Insert") => {
                 if self.paste_text() {
-                    self.notify_text_changed();
+                    self.text_edited();
                 }
                 return EventProcessStatus::Processed;
             }
             key!("Ctrl+Shift+U") => {
                 if self.convert_selection_or_word(|s| s.to_uppercase()) {
-                    self.notify_text_changed();
+                    self.text_edited();
                 }
                 return EventProcessStatus::Processed;
             }
             key!("Ctrl+U") => {
                 if self.convert_selection_or_word(|s| s.to_lowercase()) {
-                    self.notify_text_changed();
+                    self.text_edited();
                 }
                 return EventProcessStatus::Processed;
             }
@@ -468,23 +867,30 @@ impl OnKeyPressed for TextField {
             }
             key!("Delete") => {
                 if self.delete_current_character() {
-                    self.notify_text_changed();
+                    self.text_edited();
                 }
                 return EventProcessStatus::Processed;
             }
             key!("Back") => {
                 if self.delete_previous_character() {
-                    self.notify_text_changed();
+                    self.text_edited();
                 }
                 return EventProcessStatus::Processed;
             }
             key!("Enter") => {
                 if self.flags.contains(Flags::ProcessEnter) {
+                    // an invalid text is not sent for validation
+                    if !self.validate() {
+                        if let Some(message) = self.error.as_deref() {
+                            self.show_tooltip(message);
+                        }
+                        return EventProcessStatus::Processed;
+                    }
                     self.raise_event(ControlEvent {
                         emitter: self.handle,
                         receiver: self.event_processor,
                         data: ControlEventData::TextField(EventData {
-                            evtype: TextFieldEventsType::OnValidate,
+                            evtype: TextFieldEventsType::Validate,
                         }),
                     });
                     return EventProcessStatus::Processed;
@@ -495,7 +901,7 @@ impl OnKeyPressed for TextField {
         }
         if (character as u32) > 0 {
             if self.add_char(character) {
-                self.notify_text_changed();
+                self.text_edited();
             }
             return EventProcessStatus::Processed;
         }
@@ -508,17 +914,94 @@ impl OnFocus for TextField {
             self.select_all();
         }
     }
+    fn on_lose_focus(&mut self) {
+        self.update_error(true);
+    }
+}
+impl OnExpand for TextField {
+    fn on_expand(&mut self, direction: ExpandedDirection) {
+        self.popup.on_expand(direction, self.expanded_size().height, self.size().height);
+        // the suggestions could have been removed while the control was being expanded
+        if self.popup.is_empty() || self.popup.dismissed {
+            self.pack();
+        }
+    }
+    fn on_pack(&mut self) {
+        self.popup.on_pack();
+    }
+}
+impl TimerEvents for TextField {
+    fn on_update(&mut self, _ticks: u64) -> EventProcessStatus {
+        // the timer is used only to debounce the requests for suggestions
+        if let Some(timer) = self.timer() {
+            timer.pause();
+        }
+        if !self.glyphs.is_empty() && matches!(self.autocomplete.as_ref().map(|a| &a.source), Some(Source::Background(_))) {
+            self.raise_event(ControlEvent {
+                emitter: self.handle,
+                receiver: self.event_processor,
+                data: ControlEventData::TextField(EventData {
+                    evtype: TextFieldEventsType::SuggestionsRequested,
+                }),
+            });
+        }
+        EventProcessStatus::Ignored
+    }
 }
 impl OnMouseEvent for TextField {
     fn on_mouse_event(&mut self, event: &MouseEvent) -> EventProcessStatus {
+        if self.is_expanded() {
+            let width = self.expanded_size().width;
+            match event {
+                MouseEvent::Over(p) => {
+                    let hovered = self.popup.hit_test(p.x, p.y, width);
+                    return if self.popup.set_hovered(hovered) {
+                        EventProcessStatus::Processed
+                    } else {
+                        EventProcessStatus::Ignored
+                    };
+                }
+                MouseEvent::Leave => {
+                    self.popup.set_hovered(None);
+                }
+                MouseEvent::Pressed(data) => {
+                    if let Some(index) = self.popup.hit_test(data.x, data.y, width) {
+                        self.popup.move_to(index);
+                        if self.accept_suggestion() {
+                            self.notify_text_changed();
+                        }
+                        return EventProcessStatus::Processed;
+                    }
+                }
+                MouseEvent::Wheel(direction) => {
+                    let current = self.popup.current_index();
+                    match direction {
+                        MouseWheelDirection::Up => self.popup.move_to(current.saturating_sub(1)),
+                        MouseWheelDirection::Down => self.popup.move_to(current + 1),
+                        _ => return EventProcessStatus::Ignored,
+                    }
+                    return EventProcessStatus::Processed;
+                }
+                _ => {}
+            }
+        }
+        // the text is displayed under the list of suggestions if the list is displayed above the TextField
+        let text_y = self.popup.text_y;
         match event {
-            MouseEvent::Enter | MouseEvent::Leave => {
+            MouseEvent::Enter => {
+                self.drag_started = false;
+                if let Some(message) = self.error.as_deref() {
+                    self.show_tooltip(message);
+                }
+                EventProcessStatus::Processed
+            }
+            MouseEvent::Leave => {
                 self.drag_started = false;
                 EventProcessStatus::Processed
             }
             MouseEvent::Over(_) => EventProcessStatus::Ignored,
             MouseEvent::Pressed(data) => {
-                if let Some(new_pos) = self.mouse_pos_to_glyph_offset(data.x, data.y, true) {
+                if let Some(new_pos) = self.mouse_pos_to_glyph_offset(data.x, data.y - text_y, true) {
                     self.move_cursor_to(new_pos, false, false);
                     self.drag_started = true;
                 }
@@ -529,14 +1012,14 @@ impl OnMouseEvent for TextField {
                 EventProcessStatus::Processed
             }
             MouseEvent::DoubleClick(data) => {
-                if let Some(ofs) = self.mouse_pos_to_glyph_offset(data.x, data.y, true) {
+                if let Some(ofs) = self.mouse_pos_to_glyph_offset(data.x, data.y - text_y, true) {
                     self.select_word(ofs);
                 }
                 EventProcessStatus::Processed
             }
             MouseEvent::Drag(data) => {
                 if self.drag_started {
-                    if let Some(new_pos) = self.mouse_pos_to_glyph_offset(data.x, data.y, false) {
+                    if let Some(new_pos) = self.mouse_pos_to_glyph_offset(data.x, data.y - text_y, false) {
                         self.move_cursor_to(new_pos, true, true);
                     }
                 }
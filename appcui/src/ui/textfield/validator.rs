@@ -0,0 +1,73 @@
+use crate::utils::regex::Regex;
+
+#[derive(Clone)]
+enum Rule {
+    Regex { regex: Regex, message: String },
+    Callback(fn(&str) -> Result<(), String>),
+}
+
+/// Validates the content of a `TextField` while the user types. When the content is not valid, the text
+/// is drawn using the error colors of the theme and a tooltip with the reason is displayed.
+///
+/// A validator is not applied on an empty `TextField` (use a mask with required positions or check the
+/// text when the form is submitted if a value is mandatory).
+///
+/// # Example
+///
+/// ```rust
+/// use appcui::prelude::*;
+///
+/// // the whole text must match the regular expression
+/// let email = textfield::Validator::regex(r"[\w.]+@[\w]+\.[a-z]{2,}", "Invalid e-mail address").unwrap();
+/// // a custom validation (for a text field with the `099.099.099.099` mask)
+/// let ip = textfield::Validator::new(|text| {
+///     if text.split('.').all(|part| part.parse::<u8>().is_ok()) {
+///         Ok(())
+///     } else {
+///         Err("Every part of an IP address must be a number between 0 and 255".to_string())
+///     }
+/// });
+/// ```
+#[derive(Clone)]
+pub struct Validator {
+    rule: Rule,
+}
+
+impl Validator {
+    /// Creates a validator that calls `callback` every time the text is changed. The callback returns the
+    /// reason (displayed in the tooltip) if the text is not valid.
+    pub fn new(callback: fn(&str) -> Result<(), String>) -> Self {
+        Self {
+            rule: Rule::Callback(callback),
+        }
+    }
+
+    /// Creates a validator where the whole text must match a regular expression. If the text does not match,
+    /// `message` is displayed in the tooltip. Returns an error if the regular expression is not valid.
+    ///
+    /// The supported syntax: literals, `.`, character classes (`[a-z]`, `[^0-9]`, `\d`, `\w`, `\s` and their
+    /// negations), anchors (`^`, `$`, `\b`), groups, alternation (`|`), quantifiers (`*`, `+`, `?`, `{n}`,
+    /// `{n,}`, `{n,m}` and their lazy variants) and the `(?i)` prefix for case insensitive matching.
+    pub fn regex(pattern: &str, message: &str) -> Result<Self, String> {
+        Ok(Self {
+            rule: Rule::Regex {
+                regex: Regex::new(pattern)?,
+                message: message.to_string(),
+            },
+        })
+    }
+
+    pub(super) fn check(&self, text: &str) -> Result<(), String> {
+        match &self.rule {
+            Rule::Regex { regex, message } => {
+                let chars: Vec<char> = text.chars().collect();
+                if regex.is_full_match(&chars) {
+                    Ok(())
+                } else {
+                    Err(message.clone())
+                }
+            }
+            Rule::Callback(callback) => callback(text),
+        }
+    }
+}
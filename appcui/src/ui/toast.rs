@@ -0,0 +1,105 @@
+//! Non-modal toast notifications and the notification center.
+//!
+//! Unlike the notification dialogs from the [`dialogs`](crate::dialogs) module, a toast does not block the
+//! application: it is displayed in a corner of the desktop (on top of every window), stacked with the other
+//! active toasts, and it is dismissed automatically after a timeout or when the user closes it. A toast has a
+//! severity (that determines its colors) and can have an action button. When the action button is pressed, the
+//! `ToastEvents::on_toast_action` method of the control that has shown the toast is called.
+//!
+//! Dismissed toasts are kept in the notification center. The notification center can be opened via the
+//! [`show_notification_center`] function or by adding an [`appbar::NotificationButton`](crate::ui::appbar::NotificationButton)
+//! to the application bar (the button also displays the number of notifications that were not seen yet).
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use appcui::prelude::*;
+//!
+//! #[Window(events = ButtonEvents+ToastEvents)]
+//! struct MyWin {}
+//!
+//! impl ButtonEvents for MyWin {
+//!     fn on_pressed(&mut self, _: Handle<Button>) -> EventProcessStatus {
+//!         self.show_toast(toast::Toast::new(toast::Severity::Success, "Export", "The report was exported")
+//!                             .with_action("Open", 1));
+//!         EventProcessStatus::Processed
+//!     }
+//! }
+//!
+//! impl ToastEvents for MyWin {
+//!     fn on_toast_action(&mut self, _toast: u32, action: u32) -> EventProcessStatus {
+//!         // action is 1 (the "Open" button was pressed)
+//!         EventProcessStatus::Processed
+//!     }
+//! }
+//! ```
+
+mod corner;
+pub mod events;
+mod notification;
+mod notification_center;
+mod severity;
+#[cfg(test)]
+mod tests;
+mod toast;
+mod toast_manager;
+
+pub use self::corner::Corner;
+pub use self::notification::Notification;
+pub use self::severity::Severity;
+pub use self::toast::Toast;
+pub(crate) use self::toast_manager::ToastManager;
+
+use self::notification_center::NotificationCenter;
+use crate::system::RuntimeManager;
+use crate::ui::window::events::ModalWindowMethods;
+
+/// Sets the corner of the desktop where the toasts are displayed (by default, the bottom-right corner).
+pub fn set_corner(corner: Corner) {
+    let rm = RuntimeManager::get();
+    rm.get_toast_manager_mut().set_corner(corner);
+    rm.update_toasts();
+}
+
+/// Returns the corner of the desktop where the toasts are displayed.
+pub fn corner() -> Corner {
+    RuntimeManager::get().get_toast_manager().corner()
+}
+
+/// Dismisses (closes) a toast. The `id` is the value returned by `show_toast`. Returns `false` if the toast
+/// was already dismissed.
+pub fn dismiss(id: u32) -> bool {
+    RuntimeManager::get().dismiss_toast(id)
+}
+
+/// Returns the number of dismissed toasts that were not seen yet in the notification center.
+pub fn unread_count() -> usize {
+    RuntimeManager::get().get_toast_manager().unread_count()
+}
+
+/// Returns the dismissed toasts, in the order they were dismissed (the most recent one last).
+/// Only the last 100 notifications are kept.
+pub fn history() -> Vec<Notification> {
+    RuntimeManager::get().get_toast_manager().history().to_vec()
+}
+
+/// Removes all dismissed toasts from the notification center.
+pub fn clear_history() {
+    let rm = RuntimeManager::get();
+    rm.get_toast_manager_mut().clear_history();
+    rm.update_toasts();
+}
+
+/// Opens the notification center (a modal window with the list of the dismissed toasts). From the notification
+/// center a toast can be displayed again or the history can be cleared.
+pub fn show_notification_center() {
+    let rm = RuntimeManager::get();
+    rm.get_toast_manager_mut().mark_as_read();
+    rm.update_toasts();
+    let center = NotificationCenter::new(rm.get_toast_manager().history());
+    if let Some(index) = center.show() {
+        let rm = RuntimeManager::get();
+        rm.get_toast_manager_mut().reopen(index);
+        rm.update_toasts();
+    }
+}
@@ -0,0 +1,21 @@
+/// The corner of the desktop where the toasts are stacked. The most recent toast is always the closest one
+/// to the corner.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub enum Corner {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    #[default]
+    BottomRight,
+}
+
+impl Corner {
+    #[inline(always)]
+    pub(super) fn is_top(&self) -> bool {
+        matches!(self, Corner::TopLeft | Corner::TopRight)
+    }
+    #[inline(always)]
+    pub(super) fn is_left(&self) -> bool {
+        matches!(self, Corner::TopLeft | Corner::BottomLeft)
+    }
+}
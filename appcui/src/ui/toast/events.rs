@@ -0,0 +1,16 @@
+use crate::ui::common::traits::EventProcessStatus;
+
+pub trait ToastEvents {
+    /// Called when the action button of a toast is pressed. The event is sent to the control that has shown the
+    /// toast (via `show_toast`). `toast` is the id returned by `show_toast` and `action` is the id provided when
+    /// the action was added to the toast (via `Toast::with_action`). The toast is dismissed after the event is sent.
+    fn on_toast_action(&mut self, _toast: u32, _action: u32) -> EventProcessStatus {
+        EventProcessStatus::Ignored
+    }
+}
+
+#[derive(Copy, Clone)]
+pub(crate) struct EventData {
+    pub(crate) toast: u32,
+    pub(crate) action: u32,
+}
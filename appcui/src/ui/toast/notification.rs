@@ -0,0 +1,44 @@
+use super::{Severity, Toast};
+use crate::system::Handle;
+use chrono::NaiveDateTime;
+
+/// A dismissed toast, as it is kept in the notification center.
+#[derive(Clone, Debug)]
+pub struct Notification {
+    pub(super) id: u32,
+    pub(super) toast: Toast,
+    pub(super) receiver: Handle<()>,
+    pub(super) time: NaiveDateTime,
+}
+
+impl Notification {
+    /// Returns the id of the toast (the value returned by `show_toast`)
+    #[inline(always)]
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// Returns the severity of the toast
+    #[inline(always)]
+    pub fn severity(&self) -> Severity {
+        self.toast.severity
+    }
+
+    /// Returns the title of the toast
+    #[inline(always)]
+    pub fn title(&self) -> &str {
+        &self.toast.title
+    }
+
+    /// Returns the message of the toast
+    #[inline(always)]
+    pub fn message(&self) -> &str {
+        &self.toast.message
+    }
+
+    /// Returns the local time when the toast was shown
+    #[inline(always)]
+    pub fn time(&self) -> NaiveDateTime {
+        self.time
+    }
+}
@@ -0,0 +1,108 @@
+use super::Notification;
+use crate::prelude::*;
+
+#[derive(ListItem)]
+pub(super) struct Entry {
+    #[Column(name = "&Time", width = 10)]
+    time: String,
+    #[Column(name = "&Severity", width = 10)]
+    severity: &'static str,
+    #[Column(name = "T&itle", width = 20)]
+    title: String,
+    #[Column(name = "&Message", width = 40)]
+    message: String,
+    // index in the history list
+    index: usize,
+}
+
+/// A modal window that lists the dismissed toasts (the most recent one first). A toast can be reopened
+/// (the index of the notification in the history is returned) and the history can be cleared.
+#[ModalWindow(events: ButtonEvents+ListViewEvents<Entry>, response: usize, internal: true)]
+pub(super) struct NotificationCenter {
+    list: Handle<ListView<Entry>>,
+    b_reopen: Handle<Button>,
+    b_clear: Handle<Button>,
+    b_close: Handle<Button>,
+}
+
+impl NotificationCenter {
+    pub(super) fn new(history: &[Notification]) -> Self {
+        let mut w = Self {
+            base: ModalWindow::new("Notifications", layout!("a:c,w:80,h:18"), window::Flags::Sizeable),
+            list: Handle::None,
+            b_reopen: Handle::None,
+            b_clear: Handle::None,
+            b_close: Handle::None,
+        };
+        let mut lv = ListView::<Entry>::with_capacity(history.len(), layout!("l:1,t:1,r:1,b:3"), listview::Flags::ScrollBars);
+        lv.add_items(
+            history
+                .iter()
+                .enumerate()
+                .rev()
+                .map(|(index, n)| Entry {
+                    time: n.time().format("%H:%M:%S").to_string(),
+                    severity: n.severity().name(),
+                    title: n.title().to_string(),
+                    message: n.message().replace('\n', " "),
+                    index,
+                })
+                .collect(),
+        );
+        w.list = w.add(lv);
+        w.b_reopen = w.add(button!("&Reopen,r:29,b:0,w:13"));
+        w.b_clear = w.add(button!("C&lear,r:15,b:0,w:13"));
+        w.b_close = w.add(button!("&Close,r:1,b:0,w:13"));
+        w.update_buttons(!history.is_empty());
+        let h = w.list;
+        w.request_focus_for_control(h);
+        w
+    }
+
+    fn update_buttons(&mut self, has_items: bool) {
+        let (b_reopen, b_clear) = (self.b_reopen, self.b_clear);
+        if let Some(b) = self.control_mut(b_reopen) {
+            b.set_enabled(has_items);
+        }
+        if let Some(b) = self.control_mut(b_clear) {
+            b.set_enabled(has_items);
+        }
+    }
+
+    fn reopen(&mut self) {
+        let index = self.control(self.list).and_then(|lv| lv.current_item()).map(|e| e.index);
+        if let Some(index) = index {
+            self.exit_with(index);
+        }
+    }
+
+    fn clear(&mut self) {
+        RuntimeManager::get().get_toast_manager_mut().clear_history();
+        let h = self.list;
+        if let Some(lv) = self.control_mut(h) {
+            lv.clear();
+        }
+        self.update_buttons(false);
+        let h = self.b_close;
+        self.request_focus_for_control(h);
+    }
+}
+
+impl ButtonEvents for NotificationCenter {
+    fn on_pressed(&mut self, handle: Handle<Button>) -> EventProcessStatus {
+        match () {
+            _ if handle == self.b_reopen => self.reopen(),
+            _ if handle == self.b_clear => self.clear(),
+            _ if handle == self.b_close => self.exit(),
+            _ => return EventProcessStatus::Ignored,
+        }
+        EventProcessStatus::Processed
+    }
+}
+
+impl ListViewEvents<Entry> for NotificationCenter {
+    fn on_item_action(&mut self, _: Handle<ListView<Entry>>, _: usize) -> EventProcessStatus {
+        self.reopen();
+        EventProcessStatus::Processed
+    }
+}
@@ -0,0 +1,40 @@
+use crate::graphics::{CharAttribute, Character, SpecialChar};
+use crate::system::Theme;
+
+/// The severity of a toast notification. It determines the colors of the toast and the symbol displayed
+/// in front of its title.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub enum Severity {
+    #[default]
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl Severity {
+    pub(super) fn attribute(&self, theme: &Theme) -> CharAttribute {
+        match self {
+            Severity::Info => theme.window.info,
+            Severity::Success => theme.window.normal,
+            Severity::Warning => theme.window.warning,
+            Severity::Error => theme.window.error,
+        }
+    }
+    pub(super) fn symbol(&self, attr: CharAttribute) -> Character {
+        match self {
+            Severity::Info => Character::with_attributes('i', attr),
+            Severity::Success => Character::with_attributes(SpecialChar::CheckMark, attr),
+            Severity::Warning => Character::with_attributes('!', attr),
+            Severity::Error => Character::with_attributes('X', attr),
+        }
+    }
+    pub(super) fn name(&self) -> &'static str {
+        match self {
+            Severity::Info => "Info",
+            Severity::Success => "Success",
+            Severity::Warning => "Warning",
+            Severity::Error => "Error",
+        }
+    }
+}
@@ -0,0 +1,209 @@
+use super::toast_manager::wrap;
+use super::{Corner, Severity, Toast};
+use crate::prelude::*;
+use crate::testing::TestDriver;
+use std::time::Duration;
+
+#[Window(events: ToastEvents+AppBarEvents, internal: true)]
+struct ToastWin {
+    action: Option<(u32, u32)>,
+    button: Handle<appbar::NotificationButton>,
+}
+impl ToastWin {
+    fn new() -> Self {
+        let mut w = Self {
+            base: window!("Test,x:0,y:1,w:20,h:5"),
+            action: None,
+            button: Handle::None,
+        };
+        w.button = w.appbar().add(appbar::NotificationButton::new("Alerts", 0, appbar::Side::Right));
+        w
+    }
+}
+impl ToastEvents for ToastWin {
+    fn on_toast_action(&mut self, toast: u32, action: u32) -> EventProcessStatus {
+        self.action = Some((toast, action));
+        EventProcessStatus::Processed
+    }
+}
+impl AppBarEvents for ToastWin {
+    fn on_update(&self, appbar: &mut AppBar) {
+        appbar.show(self.button);
+    }
+}
+
+// a 60x12 screen with an app bar (the toasts have 40 columns and are displayed in the bottom-right corner: x = 20..59)
+fn setup() -> (TestDriver, Handle<ToastWin>) {
+    let mut driver = TestDriver::new(App::debug(60, 12, "").app_bar()).unwrap();
+    let h = driver.add_window(ToastWin::new());
+    driver.settle();
+    (driver, h)
+}
+
+fn show(driver: &mut TestDriver, h: Handle<ToastWin>, toast: Toast) -> u32 {
+    let id = driver.get_mut(h).unwrap().show_toast(toast);
+    driver.settle();
+    id
+}
+
+fn line(driver: &TestDriver, y: usize) -> String {
+    driver.screen_text().lines().nth(y).unwrap_or_default().to_string()
+}
+
+fn sticky(title: &str, message: &str) -> Toast {
+    Toast::new(Severity::Info, title, message).with_timeout(Duration::ZERO)
+}
+
+#[test]
+fn check_wrap() {
+    assert_eq!(wrap("one two three", 7, 4), vec!["one two", "three"]);
+    assert_eq!(wrap("abcdefghij", 4, 4), vec!["abcd", "efgh", "ij"]);
+    assert_eq!(wrap("first\nsecond", 20, 4), vec!["first", "second"]);
+    assert_eq!(wrap("a b c d e f", 3, 2), vec!["a b", "..."]);
+    assert_eq!(wrap("aa bb cc dd ee", 8, 2), vec!["aa bb cc", "dd ee"]);
+    assert_eq!(wrap("aa bb cc dd ee", 5, 2), vec!["aa bb", "cc..."]);
+    assert!(wrap("", 10, 4).is_empty());
+}
+
+#[test]
+fn check_toast_layout() {
+    let (mut driver, h) = setup();
+    show(&mut driver, h, sticky("Build", "Compilation finished"));
+    // top border (with the title and the close button), the message and the bottom border
+    assert_eq!(driver.screen().char(20, 9).unwrap().code, '┌');
+    assert_eq!(driver.screen().char(59, 11).unwrap().code, '┘');
+    assert!(line(&driver, 9).contains(" i Build "));
+    assert_eq!(driver.screen().char(56, 9).unwrap().code, 'x');
+    assert!(line(&driver, 10).contains("Compilation finished"));
+    assert!(!line(&driver, 8).contains('┌'));
+}
+
+#[test]
+fn check_auto_dismiss() {
+    let (mut driver, h) = setup();
+    let id = show(
+        &mut driver,
+        h,
+        Toast::new(Severity::Warning, "Disk", "Low space").with_timeout(Duration::from_millis(300)),
+    );
+    assert!(driver.screen_text().contains("Disk"));
+    assert!(driver.wait_until(Duration::from_secs(5), |d| !d.screen_text().contains("Disk")));
+    let history = toast::history();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].id(), id);
+    assert_eq!(history[0].title(), "Disk");
+    assert_eq!(history[0].severity(), Severity::Warning);
+    assert_eq!(toast::unread_count(), 1);
+}
+
+#[test]
+fn check_close_button() {
+    let (mut driver, h) = setup();
+    show(&mut driver, h, sticky("Build", "Compilation finished"));
+    // a toast without a timeout stays visible
+    driver.wait(Duration::from_millis(300));
+    assert!(driver.screen_text().contains("Build"));
+    // clicking the body does not close the toast
+    driver.click_at(30, 10);
+    assert!(driver.screen_text().contains("Build"));
+    driver.click_at(56, 9);
+    assert!(!driver.screen_text().contains("Build"));
+    assert_eq!(toast::history().len(), 1);
+}
+
+#[test]
+fn check_action_button() {
+    let (mut driver, h) = setup();
+    let id = show(&mut driver, h, sticky("Deleted", "3 files were deleted").with_action("Undo", 7));
+    // the toast has 4 lines: 8..11 (the action button is on the last line before the bottom border)
+    assert!(line(&driver, 8).contains("Deleted"));
+    assert!(line(&driver, 10).ends_with(" Undo  │"));
+    driver.click_at(54, 10);
+    assert_eq!(driver.get(h).unwrap().action, Some((id, 7)));
+    assert!(!driver.screen_text().contains("Deleted"));
+    // dismissing a toast from code
+    let id = show(&mut driver, h, sticky("Saved", "The file was saved"));
+    assert!(toast::dismiss(id));
+    assert!(!toast::dismiss(id));
+    driver.settle();
+    assert!(!driver.screen_text().contains("Saved"));
+}
+
+#[test]
+fn check_stacking_and_corner() {
+    let (mut driver, h) = setup();
+    toast::set_corner(Corner::TopLeft);
+    assert_eq!(toast::corner(), Corner::TopLeft);
+    show(&mut driver, h, sticky("First", "message"));
+    show(&mut driver, h, sticky("Second", "message"));
+    // the most recent toast is the closest one to the corner (the app bar is on the first line)
+    assert_eq!(driver.screen().char(0, 1).unwrap().code, '┌');
+    assert!(line(&driver, 1).contains("Second"));
+    assert!(line(&driver, 4).contains("First"));
+
+    toast::set_corner(Corner::BottomRight);
+    driver.settle();
+    assert!(line(&driver, 9).contains("Second"));
+    assert!(line(&driver, 6).contains("First"));
+}
+
+#[test]
+fn check_toasts_that_do_not_fit() {
+    let (mut driver, h) = setup();
+    // 10 lines are available and every toast needs 3 lines
+    for i in 0..4 {
+        show(&mut driver, h, sticky(&format!("Toast-{i}"), "message"));
+    }
+    assert!(!driver.screen_text().contains("Toast-0"));
+    assert!(driver.screen_text().contains("Toast-1"));
+    assert!(driver.screen_text().contains("Toast-3"));
+    // once a toast is closed, the hidden one is displayed
+    driver.click_at(56, 9);
+    assert!(!driver.screen_text().contains("Toast-3"));
+    assert!(driver.screen_text().contains("Toast-0"));
+}
+
+#[test]
+fn check_hover_pauses_dismissal() {
+    let (mut driver, h) = setup();
+    show(
+        &mut driver,
+        h,
+        Toast::new(Severity::Error, "Failed", "Connection lost").with_timeout(Duration::from_millis(200)),
+    );
+    driver.hover_at(30, 10);
+    driver.wait(Duration::from_millis(600));
+    assert!(driver.screen_text().contains("Failed"));
+    driver.hover_at(5, 10);
+    assert!(driver.wait_until(Duration::from_secs(5), |d| !d.screen_text().contains("Failed")));
+}
+
+#[test]
+fn check_notification_button() {
+    let (mut driver, h) = setup();
+    assert!(line(&driver, 0).ends_with("Alerts"));
+    let id = show(&mut driver, h, sticky("Build", "Compilation finished"));
+    toast::dismiss(id);
+    driver.settle();
+    assert!(line(&driver, 0).ends_with("Alerts [1]"));
+    let button = driver.get(h).unwrap().button;
+    assert_eq!(RuntimeManager::get().get_appbar().get(button).unwrap().unread_count(), 1);
+
+    // open the notification center and reopen the toast
+    driver.queue_key(key!("Enter"));
+    driver.click_at(57, 0);
+    assert!(line(&driver, 0).ends_with("Alerts"));
+    assert_eq!(toast::unread_count(), 0);
+    assert!(toast::history().is_empty());
+    assert!(driver.screen_text().contains("Compilation finished"));
+
+    // open the notification center and clear the history
+    toast::dismiss(id);
+    driver.settle();
+    assert_eq!(toast::history().len(), 1);
+    driver.queue_key(key!("Alt+L"));
+    driver.queue_key(key!("Escape"));
+    driver.click_at(55, 0);
+    assert!(toast::history().is_empty());
+    assert_eq!(toast::unread_count(), 0);
+}
@@ -0,0 +1,85 @@
+use super::Severity;
+use std::time::Duration;
+
+/// A non-modal notification displayed in a corner of the desktop. A toast is created with a severity, a title
+/// and a message and is shown via the `show_toast` method of a control. By default a toast is dismissed after
+/// 5 seconds (the countdown is paused while the mouse is over the toast).
+///
+/// # Example
+/// ```rust,no_run
+/// use appcui::prelude::*;
+/// use std::time::Duration;
+///
+/// let t = toast::Toast::new(toast::Severity::Warning, "Disk", "Less than 1GB of free space is available")
+///             .with_timeout(Duration::from_secs(10))
+///             .with_action("Clean", 1);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Toast {
+    pub(super) severity: Severity,
+    pub(super) title: String,
+    pub(super) message: String,
+    pub(super) timeout: Duration,
+    pub(super) action: Option<(String, u32)>,
+}
+
+impl Toast {
+    /// The time after which a toast is dismissed (if no other timeout is provided)
+    pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+
+    /// Creates a new toast with a severity, a title and a message. The message is wrapped on multiple
+    /// lines (if needed).
+    pub fn new(severity: Severity, title: &str, message: &str) -> Self {
+        Self {
+            severity,
+            title: title.to_string(),
+            message: message.to_string(),
+            timeout: Self::DEFAULT_TIMEOUT,
+            action: None,
+        }
+    }
+
+    /// Sets the time after which the toast is dismissed automatically. A zero duration means that the toast
+    /// stays visible until it is closed by the user.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Adds an action button to the toast. When the button is pressed, the `ToastEvents::on_toast_action`
+    /// method of the control that has shown the toast is called with the provided `id`.
+    pub fn with_action(mut self, caption: &str, id: u32) -> Self {
+        self.action = Some((caption.to_string(), id));
+        self
+    }
+
+    /// Returns the severity of the toast
+    #[inline(always)]
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    /// Returns the title of the toast
+    #[inline(always)]
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Returns the message of the toast
+    #[inline(always)]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the time after which the toast is dismissed (a zero duration if the toast is never dismissed automatically)
+    #[inline(always)]
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Returns the caption and the id of the action button (if any)
+    #[inline(always)]
+    pub fn action(&self) -> Option<(&str, u32)> {
+        self.action.as_ref().map(|(caption, id)| (caption.as_str(), *id))
+    }
+}
@@ -0,0 +1,359 @@
+use super::events::EventData;
+use super::{Corner, Notification, Toast};
+use crate::graphics::*;
+use crate::system::{Handle, Theme, Timer};
+use crate::ui::common::{ControlEvent, ControlEventData};
+use std::time::Duration;
+
+const TOAST_WIDTH: u32 = 40;
+const MIN_TOAST_WIDTH: u32 = 16;
+const MAX_MESSAGE_LINES: usize = 4;
+const MAX_HISTORY: usize = 100;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+enum Part {
+    Body,
+    Close,
+    Action,
+}
+
+struct ActiveToast {
+    notification: Notification,
+    // miliseconds until the toast is dismissed
+    remaining: u128,
+    lines: Vec<String>,
+    rect: Option<Rect>,
+}
+
+impl ActiveToast {
+    fn action_rect(&self) -> Option<Rect> {
+        let r = self.rect?;
+        let (caption, _) = self.notification.toast.action.as_ref()?;
+        let w = caption.chars().count() as i32 + 2;
+        Some(Rect::new(r.right() - 1 - w, r.bottom() - 1, r.right() - 2, r.bottom() - 1))
+    }
+    fn hit_test(&self, x: i32, y: i32) -> Option<Part> {
+        let r = self.rect?;
+        if !r.contains(Point::new(x, y)) {
+            return None;
+        }
+        if (y == r.top()) && (x >= r.right() - 4) && (x <= r.right() - 2) {
+            return Some(Part::Close);
+        }
+        if self.action_rect().map(|a| a.contains(Point::new(x, y))).unwrap_or(false) {
+            return Some(Part::Action);
+        }
+        Some(Part::Body)
+    }
+}
+
+/// Keeps the toasts that are currently displayed (the most recent one first) and the ones that were
+/// dismissed (the notification center history).
+pub(crate) struct ToastManager {
+    toasts: Vec<ActiveToast>,
+    history: Vec<Notification>,
+    corner: Corner,
+    hovered: Option<(u32, Part)>,
+    next_id: u32,
+    unread: usize,
+    timer: Handle<Timer>,
+    ticking: bool,
+}
+
+impl ToastManager {
+    pub(crate) const TICK: Duration = Duration::from_millis(100);
+
+    pub(crate) fn new() -> Self {
+        Self {
+            toasts: Vec::new(),
+            history: Vec::new(),
+            corner: Corner::BottomRight,
+            hovered: None,
+            next_id: 1,
+            unread: 0,
+            timer: Handle::None,
+            ticking: false,
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn corner(&self) -> Corner {
+        self.corner
+    }
+    #[inline(always)]
+    pub(crate) fn set_corner(&mut self, corner: Corner) {
+        self.corner = corner;
+    }
+    #[inline(always)]
+    pub(crate) fn unread_count(&self) -> usize {
+        self.unread
+    }
+    #[inline(always)]
+    pub(crate) fn mark_as_read(&mut self) {
+        self.unread = 0;
+    }
+    #[inline(always)]
+    pub(crate) fn history(&self) -> &[Notification] {
+        &self.history
+    }
+    pub(crate) fn clear_history(&mut self) {
+        self.history.clear();
+        self.unread = 0;
+    }
+    #[inline(always)]
+    pub(crate) fn is_empty(&self) -> bool {
+        self.toasts.is_empty()
+    }
+
+    pub(crate) fn show(&mut self, toast: Toast, receiver: Handle<()>) -> u32 {
+        let id = self.next_id;
+        self.next_id = self.next_id.wrapping_add(1).max(1);
+        self.push(Notification {
+            id,
+            toast,
+            receiver,
+            time: chrono::Local::now().naive_local(),
+        });
+        id
+    }
+
+    /// Shows again a toast from the history (the index is relative to the history list)
+    pub(crate) fn reopen(&mut self, index: usize) -> bool {
+        if index >= self.history.len() {
+            return false;
+        }
+        let notification = self.history.remove(index);
+        self.push(notification);
+        true
+    }
+
+    fn push(&mut self, notification: Notification) {
+        self.toasts.insert(
+            0,
+            ActiveToast {
+                remaining: notification.toast.timeout.as_millis(),
+                notification,
+                lines: Vec::new(),
+                rect: None,
+            },
+        );
+    }
+
+    pub(crate) fn dismiss(&mut self, id: u32) -> bool {
+        if let Some(index) = self.toasts.iter().position(|t| t.notification.id == id) {
+            let t = self.toasts.remove(index);
+            if self.hovered.map(|h| h.0) == Some(id) {
+                self.hovered = None;
+            }
+            if self.history.len() >= MAX_HISTORY {
+                self.history.remove(0);
+            }
+            self.history.push(t.notification);
+            self.unread = (self.unread + 1).min(self.history.len());
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns `true` if there is at least one toast that has to be dismissed automatically
+    #[inline(always)]
+    pub(crate) fn needs_timer(&self) -> bool {
+        self.toasts.iter().any(|t| !t.notification.toast.timeout.is_zero())
+    }
+    #[inline(always)]
+    pub(crate) fn timer(&self) -> Handle<Timer> {
+        self.timer
+    }
+    #[inline(always)]
+    pub(crate) fn set_timer(&mut self, timer: Handle<Timer>) {
+        self.timer = timer;
+    }
+    #[inline(always)]
+    pub(crate) fn is_ticking(&self) -> bool {
+        self.ticking
+    }
+    #[inline(always)]
+    pub(crate) fn set_ticking(&mut self, value: bool) {
+        self.ticking = value;
+    }
+
+    /// Decreases the remaining time of every toast (except for the hovered one) and dismisses the expired toasts.
+    /// Returns `true` if at least one toast was dismissed.
+    pub(crate) fn on_tick(&mut self, elapsed: Duration) -> bool {
+        let hovered = self.hovered.map(|h| h.0);
+        let mut expired = Vec::new();
+        for t in self.toasts.iter_mut() {
+            if t.notification.toast.timeout.is_zero() || hovered == Some(t.notification.id) {
+                continue;
+            }
+            t.remaining = t.remaining.saturating_sub(elapsed.as_millis());
+            if t.remaining == 0 {
+                expired.push(t.notification.id);
+            }
+        }
+        for id in expired.iter() {
+            self.dismiss(*id);
+        }
+        !expired.is_empty()
+    }
+
+    /// Computes the position of every toast within the desktop. The toasts that do not fit are not displayed
+    /// (they will be shown after other toasts are dismissed).
+    pub(crate) fn arrange(&mut self, desktop: Rect) {
+        let width = TOAST_WIDTH.min(desktop.width());
+        let mut top = desktop.top();
+        let mut bottom = desktop.bottom();
+        let mut full = width < MIN_TOAST_WIDTH;
+        let x = if self.corner.is_left() {
+            desktop.left()
+        } else {
+            desktop.right() + 1 - width as i32
+        };
+        for t in self.toasts.iter_mut() {
+            t.rect = None;
+            if full {
+                continue;
+            }
+            t.lines = wrap(&t.notification.toast.message, (width - 4) as usize, MAX_MESSAGE_LINES);
+            let height = 2 + t.lines.len() as i32 + if t.notification.toast.action.is_some() { 1 } else { 0 };
+            if bottom + 1 - top < height {
+                full = true;
+                continue;
+            }
+            let y = if self.corner.is_top() {
+                top += height;
+                top - height
+            } else {
+                bottom -= height;
+                bottom + 1
+            };
+            t.rect = Some(Rect::with_size(x, y, width as u16, height as u16));
+        }
+    }
+
+    pub(crate) fn paint(&self, surface: &mut Surface, theme: &Theme) {
+        // the most recent toast is painted last (on top)
+        for t in self.toasts.iter().rev() {
+            let Some(r) = t.rect else {
+                continue;
+            };
+            let toast = &t.notification.toast;
+            let hovered = self.hovered.filter(|h| h.0 == t.notification.id).map(|h| h.1);
+            surface.fill_rect(r, Character::with_attributes(' ', toast.severity.attribute(theme)));
+            surface.draw_rect(r, LineType::Single, theme.border.normal);
+
+            // title
+            let x = r.left() + 2;
+            let y = r.top();
+            let max_chars = (r.width() as i32 - 10).max(0) as usize;
+            surface.write_char(x, y, Character::with_char(' '));
+            surface.write_char(x + 1, y, toast.severity.symbol(theme.text.focused));
+            surface.write_char(x + 2, y, Character::with_char(' '));
+            let title: String = toast.title.chars().take(max_chars).collect();
+            surface.write_string(x + 3, y, &title, theme.text.focused, false);
+            surface.write_char(x + 3 + title.chars().count() as i32, y, Character::with_char(' '));
+
+            // close button
+            let close_attr = if hovered == Some(Part::Close) {
+                theme.symbol.hovered
+            } else {
+                theme.symbol.close
+            };
+            surface.write_string(r.right() - 4, y, "[ ]", theme.border.normal, false);
+            surface.write_char(r.right() - 3, y, Character::with_attributes('x', close_attr));
+
+            // message
+            for (index, line) in t.lines.iter().enumerate() {
+                surface.write_string(x, y + 1 + index as i32, line, theme.text.normal, false);
+            }
+
+            // action button
+            if let (Some(a), Some((caption, _))) = (t.action_rect(), toast.action.as_ref()) {
+                let attr = if hovered == Some(Part::Action) {
+                    theme.button.text.hovered
+                } else {
+                    theme.button.text.normal
+                };
+                surface.fill_horizontal_line_with_size(a.left(), a.top(), a.width(), Character::with_attributes(' ', attr));
+                surface.write_string(a.left() + 1, a.top(), caption, attr, false);
+            }
+        }
+    }
+
+    /// Processes a mouse click over the toasts. Returns `true` if the click was on a toast (in this case the
+    /// click should not be sent to other objects).
+    pub(crate) fn on_mouse_pressed(&mut self, x: i32, y: i32, events: &mut Vec<ControlEvent>) -> bool {
+        let Some((index, part)) = self.hit_test(x, y) else {
+            return false;
+        };
+        let id = self.toasts[index].notification.id;
+        match part {
+            Part::Body => {}
+            Part::Close => {
+                self.dismiss(id);
+            }
+            Part::Action => {
+                let n = &self.toasts[index].notification;
+                if let Some((_, action)) = n.toast.action {
+                    events.push(ControlEvent {
+                        emitter: Handle::None,
+                        receiver: n.receiver,
+                        data: ControlEventData::Toast(EventData { toast: id, action }),
+                    });
+                }
+                self.dismiss(id);
+            }
+        }
+        true
+    }
+
+    /// Updates the hovered toast. Returns `true` if the mouse is over a toast.
+    pub(crate) fn on_mouse_move(&mut self, x: i32, y: i32) -> bool {
+        self.hovered = self.hit_test(x, y).map(|(index, part)| (self.toasts[index].notification.id, part));
+        self.hovered.is_some()
+    }
+
+    fn hit_test(&self, x: i32, y: i32) -> Option<(usize, Part)> {
+        self.toasts.iter().enumerate().find_map(|(index, t)| t.hit_test(x, y).map(|p| (index, p)))
+    }
+}
+
+// splits a text into lines of at most `width` characters (words are not split unless they are longer than a line)
+pub(super) fn wrap(text: &str, width: usize, max_lines: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    for paragraph in text.lines() {
+        let mut line = String::new();
+        let mut count = 0;
+        for word in paragraph.split_whitespace() {
+            let len = word.chars().count();
+            if count > 0 && count + 1 + len > width {
+                lines.push(std::mem::take(&mut line));
+                count = 0;
+            }
+            if count > 0 {
+                line.push(' ');
+                count += 1;
+            }
+            for ch in word.chars() {
+                if count == width {
+                    lines.push(std::mem::take(&mut line));
+                    count = 0;
+                }
+                line.push(ch);
+                count += 1;
+            }
+        }
+        lines.push(line);
+    }
+    if lines.len() > max_lines {
+        lines.truncate(max_lines);
+        if let Some(last) = lines.last_mut() {
+            while last.chars().count() + 3 > width {
+                last.pop();
+            }
+            last.push_str("...");
+        }
+    }
+    lines
+}
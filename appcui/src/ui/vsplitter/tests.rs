@@ -476,4 +476,18 @@ fn check_mouse_wheel_and_doubleclicked() {
     w.add(vs);
     a.add_window(w);
     a.run();
-}
\ No newline at end of file
+}
+#[test]
+fn check_auto_min_width() {
+    let mut driver = crate::testing::TestDriver::new(App::debug(60, 10, "")).unwrap();
+    let mut w = window!("Test,a:c,w:50,h:10");
+    let mut vs = VSplitter::new(24, layout!("d:f"), vsplitter::ResizeBehavior::PreserveAspectRatio);
+    vs.set_min_width(vsplitter::Panel::Left, 10);
+    vs.set_min_width(vsplitter::Panel::Left, layout::Dimension::Auto);
+    let vs = w.add(vs);
+    driver.add_window(w);
+    driver.settle();
+    // Dimension::Auto means no minimum width
+    driver.press_on(vs, key!("Ctrl+Alt+Shift+Left"));
+    assert_eq!(driver.get(vs).unwrap().position(), 0);
+}
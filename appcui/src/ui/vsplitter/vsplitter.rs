@@ -16,7 +16,7 @@ enum State {
     Dragging,
 }
 
-#[CustomControl(overwrite=OnPaint + OnKeyPressed + OnMouseEvent + OnResize, internal = true)]
+#[CustomControl(overwrite=OnPaint + OnKeyPressed + OnMouseEvent + OnResize + OnSessionState, internal = true)]
 pub struct VSplitter {
     left: Handle<SplitterPanel>,
     right: Handle<SplitterPanel>,
@@ -91,7 +91,8 @@ impl VSplitter {
 
 
     /// Sets the minimum width for the left or right panel
-    /// The value can be a percentage (e.g. a float value) or an absolute value (e.g. an unsigned value)
+    /// The value can be a percentage (e.g. a float value) or an absolute value (e.g. an unsigned value).
+    /// A minimum size can not be computed from the content of a panel, so `Dimension::Auto` means no minimum size.
     /// 
     /// # Example
     /// ```rust, no_run
@@ -109,9 +110,13 @@ impl VSplitter {
     where
         Dimension: From<T>,
     {
+        let min_size = match min_size.into() {
+            Dimension::Auto => Dimension::Absolute(0),
+            value => value,
+        };
         match panel {
-            vsplitter::Panel::Left => self.min_left = min_size.into(),
-            vsplitter::Panel::Right => self.min_right = min_size.into(),
+            vsplitter::Panel::Left => self.min_left = min_size,
+            vsplitter::Panel::Right => self.min_right = min_size,
         }
     }
 
@@ -362,3 +367,24 @@ impl OnResize for VSplitter {
         }
     }
 }
+impl OnSessionState for VSplitter {
+    fn save_state(&self, state: &mut SessionState) {
+        // a position expressed as a percentage is kept as a percentage (so that it scales when the splitter is resized)
+        match self.pos {
+            Coordinate::Percentage(value) => state.set("percentage", value),
+            Coordinate::Absolute(_) => state.set("position", self.position()),
+        }
+    }
+    fn restore_state(&mut self, state: &SessionState) {
+        if let Some(value) = state.get_as::<f32>("percentage") {
+            self.set_position(value);
+            // `set_position` adjusts the percentage to the current size - the saved value is kept (if it was not clamped)
+            let saved = Coordinate::Percentage(value);
+            if saved.absolute(self.size().width.saturating_sub(1) as u16) == self.position() {
+                self.pos = saved;
+            }
+        } else if let Some(pos) = state.get_as::<i32>("position") {
+            self.set_position(pos);
+        }
+    }
+}
@@ -5,6 +5,7 @@ pub enum Flags {
     Sizeable      = 0x0001,
     NoCloseButton = 0x0002,
     FixedPosition = 0x0004,
+    Dockable      = 0x0008,
     //Maximized     = 0x0080,
     //ProcessReturn = 0x0200,
 }
@@ -66,6 +66,13 @@ impl<T> ModalWindow<T> {
 }
 impl<T: 'static> Control for ModalWindow<T> {}
 impl<T> OnThemeChanged for ModalWindow<T> {}
+impl<T> OnMeasure for ModalWindow<T> {}
+impl<T> OnSessionState for ModalWindow<T> {}
+impl<T> OnChildEvent for ModalWindow<T> {
+    fn notifies_child_events() -> bool {
+        false
+    }
+}
 impl<T> GenericMenuEvents for ModalWindow<T> {}
 impl<T> AppBarEvents for ModalWindow<T> {}
 impl<T> DesktopEvents for ModalWindow<T> {}
@@ -102,6 +109,14 @@ impl<T> TimerEvents for ModalWindow<T> {}
 impl<T> MarkdownEvents for ModalWindow<T> {}
 impl<T> AccordionEvents for ModalWindow<T> {}
 impl<T> TabEvents for ModalWindow<T> {}
+impl<T> WizardEvents for ModalWindow<T> {}
+impl<T> TerminalEvents for ModalWindow<T> {}
+impl<T> GenericSliderEvents for ModalWindow<T> {}
+impl<T> GenericRangeSliderEvents for ModalWindow<T> {}
+impl<T> TimePickerEvents for ModalWindow<T> {}
+impl<T> DateTimePickerEvents for ModalWindow<T> {}
+impl<T> DateRangePickerEvents for ModalWindow<T> {}
+impl<T> ToastEvents for ModalWindow<T> {}
 
 // events routed to base window
 impl<T> OnFocus for ModalWindow<T> {
@@ -722,8 +722,16 @@ impl Window {
             DragStatus::Move => {
                 let left = self.screen_clip.left;
                 let top = self.screen_clip.top;
+                let dock = RuntimeManager::get().get_dock_manager_mut();
+                // dragging a docked window makes it float again
+                if let Some(r) = dock.float(self.handle) {
+                    self.drag_start_point.x = self.drag_start_point.x.min(r.width() as i32 - 2).max(0);
+                }
                 let p = self.drag_start_point;
                 self.set_position(x + left - p.x, y + top - p.y);
+                if self.flags.contains(Flags::Dockable) {
+                    dock.update_drop_target(self.handle, Point::new(x + left, y + top));
+                }
                 EventProcessStatus::Processed
             }
             DragStatus::Resize => {
@@ -781,6 +789,9 @@ impl Window {
         self.base.set_key_input_before_children_flag(false);
 
         if self.drag_status != DragStatus::None {
+            if (self.drag_status == DragStatus::Move) && self.flags.contains(Flags::Dockable) {
+                RuntimeManager::get().get_dock_manager_mut().drop_window(self.handle);
+            }
             self.drag_status = DragStatus::None;
         } else {
             self.on_toolbar_item_clicked(self.toolbar.get_current_item_handle());
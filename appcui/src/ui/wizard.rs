@@ -0,0 +1,16 @@
+//! A wizard UI control that guides the user through a sequence of steps.
+//!
+//! The Wizard control displays one step (page) at a time together with a step indicator and
+//! the Back / Next / Finish / Cancel actions. Every step can be validated before the user moves
+//! forward and steps can be skipped based on the choices made in the previous ones.
+
+pub mod events;
+mod initialization_flags;
+mod step_page;
+#[cfg(test)]
+mod tests;
+mod wizard;
+
+pub use self::initialization_flags::Flags;
+use self::step_page::StepPage;
+pub use self::wizard::Wizard;
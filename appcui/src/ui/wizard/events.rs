@@ -0,0 +1,36 @@
+use super::Wizard;
+use crate::{system::Handle, ui::common::traits::ActionRequest, ui::common::traits::EventProcessStatus};
+
+pub trait WizardEvents {
+    /// Called when the user tries to leave a step (via `Next` or `Finish`). If `ActionRequest::Deny` is returned
+    /// the wizard remains on the same step (for example, if some of the values from that step are not valid).
+    fn on_validate_step(&mut self, _handle: Handle<Wizard>, _step: u32) -> ActionRequest {
+        ActionRequest::Allow
+    }
+    /// Called when moving forward, for every step that follows the current one. If `true` is returned the
+    /// step is skipped (for example, steps that do not apply for the choices made in the previous steps).
+    fn should_skip_step(&mut self, _handle: Handle<Wizard>, _step: u32) -> bool {
+        false
+    }
+    /// Called after the current step was changed (via `Next` or `Back`).
+    fn on_step_changed(&mut self, _handle: Handle<Wizard>, _new_step: u32, _old_step: u32) -> EventProcessStatus {
+        EventProcessStatus::Ignored
+    }
+    /// Called when the user finishes the wizard (after the last step was validated). A modal window usually
+    /// builds its result at this point and calls `exit_with(...)`.
+    fn on_finish(&mut self, _handle: Handle<Wizard>) -> EventProcessStatus {
+        EventProcessStatus::Ignored
+    }
+    /// Called when the user cancels the wizard. If `ActionRequest::Allow` is returned the window that contains
+    /// the wizard is closed (a modal window exits without a result).
+    fn on_cancel(&mut self, _handle: Handle<Wizard>) -> ActionRequest {
+        ActionRequest::Allow
+    }
+}
+#[derive(Copy, Clone)]
+pub(crate) enum EventData {
+    Next { step: u32 },
+    Back { new_step: u32, old_step: u32 },
+    Finish { step: u32 },
+    Cancel,
+}
@@ -0,0 +1,6 @@
+use EnumBitFlags::EnumBitFlags;
+
+#[EnumBitFlags(bits = 8)]
+pub enum Flags {
+    HideStepIndicator = 0x01,
+}
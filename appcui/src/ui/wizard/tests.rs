@@ -0,0 +1,288 @@
+use crate::prelude::*;
+
+#[Window(events = WizardEvents, internal = true)]
+struct SetupWin {
+    wizard: Handle<Wizard>,
+    // step 1 can not be left while this is false
+    step1_valid: bool,
+    skip_step2: bool,
+    log: Vec<String>,
+    cancel_allowed: bool,
+}
+impl SetupWin {
+    fn new() -> Self {
+        let mut w = Self {
+            base: Window::new("Setup", layout!("x:0,y:0,w:62,h:16"), window::Flags::None),
+            wizard: Handle::None,
+            step1_valid: true,
+            skip_step2: false,
+            log: Vec::new(),
+            cancel_allowed: true,
+        };
+        let mut wz = Wizard::new(layout!("d:f"), wizard::Flags::None);
+        for name in ["Welcome", "Options", "Extra", "Summary"] {
+            let idx = wz.add_step(name);
+            wz.add(idx, Button::new(&format!("Button {name}"), layout!("x:1,y:1,w:16"), button::Type::Flat));
+        }
+        w.wizard = w.add(wz);
+        w
+    }
+}
+impl WizardEvents for SetupWin {
+    fn on_validate_step(&mut self, _handle: Handle<Wizard>, step: u32) -> ActionRequest {
+        self.log.push(format!("validate {step}"));
+        if step == 1 && !self.step1_valid {
+            ActionRequest::Deny
+        } else {
+            ActionRequest::Allow
+        }
+    }
+    fn should_skip_step(&mut self, _handle: Handle<Wizard>, step: u32) -> bool {
+        step == 2 && self.skip_step2
+    }
+    fn on_step_changed(&mut self, _handle: Handle<Wizard>, new_step: u32, old_step: u32) -> EventProcessStatus {
+        self.log.push(format!("changed {old_step}->{new_step}"));
+        EventProcessStatus::Processed
+    }
+    fn on_finish(&mut self, _handle: Handle<Wizard>) -> EventProcessStatus {
+        self.log.push("finish".to_string());
+        EventProcessStatus::Processed
+    }
+    fn on_cancel(&mut self, _handle: Handle<Wizard>) -> ActionRequest {
+        self.log.push("cancel".to_string());
+        if self.cancel_allowed {
+            ActionRequest::Allow
+        } else {
+            ActionRequest::Deny
+        }
+    }
+}
+
+fn visible_button(driver: &crate::testing::TestDriver) -> Vec<String> {
+    driver
+        .find::<Button>()
+        .visible()
+        .all()
+        .into_iter()
+        .filter_map(|h| driver.caption(h))
+        .collect()
+}
+
+#[test]
+fn check_navigation() {
+    let mut driver = crate::testing::TestDriver::new(App::debug(80, 20, "")).unwrap();
+    let wh = driver.add_window(SetupWin::new());
+    driver.settle();
+    let h = driver.get(wh).unwrap().wizard;
+    assert_eq!(driver.get(h).unwrap().step_count(), 4);
+    assert_eq!(driver.get(h).unwrap().current_step(), Some(0));
+    assert_eq!(visible_button(&driver), ["Button Welcome"]);
+    let screen = driver.screen_text();
+    assert!(screen.contains("1. Welcome"));
+    assert!(screen.contains("4. Summary"));
+
+    driver.press(key!("Alt+N"));
+    assert_eq!(driver.get(h).unwrap().current_step(), Some(1));
+    assert_eq!(visible_button(&driver), ["Button Options"]);
+    driver.press(key!("Alt+N"));
+    driver.press(key!("Alt+N"));
+    assert_eq!(driver.get(h).unwrap().current_step(), Some(3));
+    // Next is disabled on the last step
+    driver.press(key!("Alt+N"));
+    assert_eq!(driver.get(h).unwrap().current_step(), Some(3));
+
+    driver.press(key!("Alt+B"));
+    assert_eq!(driver.get(h).unwrap().current_step(), Some(2));
+    assert_eq!(visible_button(&driver), ["Button Extra"]);
+    assert_eq!(
+        driver.get(wh).unwrap().log,
+        [
+            "validate 0",
+            "changed 0->1",
+            "validate 1",
+            "changed 1->2",
+            "validate 2",
+            "changed 2->3",
+            "changed 3->2"
+        ]
+    );
+}
+
+#[test]
+fn check_validation() {
+    let mut driver = crate::testing::TestDriver::new(App::debug(80, 20, "")).unwrap();
+    let mut w = SetupWin::new();
+    w.step1_valid = false;
+    let wh = driver.add_window(w);
+    driver.settle();
+    let h = driver.get(wh).unwrap().wizard;
+    driver.press(key!("Alt+N"));
+    driver.press(key!("Alt+N"));
+    // step 1 is not valid (the wizard remains on it)
+    assert_eq!(driver.get(h).unwrap().current_step(), Some(1));
+    driver.get_mut(wh).unwrap().step1_valid = true;
+    driver.press(key!("Alt+N"));
+    assert_eq!(driver.get(h).unwrap().current_step(), Some(2));
+}
+
+#[test]
+fn check_skipped_steps() {
+    let mut driver = crate::testing::TestDriver::new(App::debug(80, 20, "")).unwrap();
+    let mut w = SetupWin::new();
+    w.skip_step2 = true;
+    let wh = driver.add_window(w);
+    driver.settle();
+    let h = driver.get(wh).unwrap().wizard;
+    driver.press(key!("Alt+N"));
+    driver.press(key!("Alt+N"));
+    assert_eq!(driver.get(h).unwrap().current_step(), Some(3));
+    assert!(driver.get(h).unwrap().is_step_skipped(2));
+    assert_eq!(visible_button(&driver), ["Button Summary"]);
+
+    // going back returns to the last visited step (not to the skipped one)
+    driver.press(key!("Alt+B"));
+    assert_eq!(driver.get(h).unwrap().current_step(), Some(1));
+    assert!(!driver.get(h).unwrap().is_step_skipped(2));
+    driver.press(key!("Alt+B"));
+    assert_eq!(driver.get(h).unwrap().current_step(), Some(0));
+    // no more history
+    driver.press(key!("Alt+B"));
+    assert_eq!(driver.get(h).unwrap().current_step(), Some(0));
+}
+
+#[test]
+fn check_finish() {
+    let mut driver = crate::testing::TestDriver::new(App::debug(80, 20, "")).unwrap();
+    let wh = driver.add_window(SetupWin::new());
+    driver.settle();
+    let h = driver.get(wh).unwrap().wizard;
+    // Finish is disabled until the last step
+    driver.press(key!("Alt+F"));
+    assert!(driver.get(wh).unwrap().log.is_empty());
+    for _ in 0..3 {
+        driver.press(key!("Alt+N"));
+    }
+    driver.get_mut(wh).unwrap().log.clear();
+    let r = driver.bounds(h).unwrap();
+    // the actions are on the last line: Back, Next, Finish, Cancel (10 characters each, separated by a space)
+    driver.click_at(r.right() + 1 - 44 + 22 + 3, r.bottom());
+    assert_eq!(driver.get(wh).unwrap().log, ["validate 3", "finish"]);
+}
+
+#[test]
+fn check_cancel() {
+    let mut driver = crate::testing::TestDriver::new(App::debug(80, 20, "")).unwrap();
+    let mut w = SetupWin::new();
+    w.cancel_allowed = false;
+    let wh = driver.add_window(w);
+    driver.settle();
+    driver.press(key!("Alt+C"));
+    assert_eq!(driver.get(wh).unwrap().log, ["cancel"]);
+    assert!(driver.get(wh).is_some());
+
+    driver.get_mut(wh).unwrap().cancel_allowed = true;
+    driver.press(key!("Alt+C"));
+    assert!(driver.get(wh).is_none());
+}
+
+#[test]
+fn check_compact_step_indicator() {
+    let mut driver = crate::testing::TestDriver::new(App::debug(80, 20, "")).unwrap();
+    let mut w = Window::new("Setup", layout!("x:0,y:0,w:50,h:12"), window::Flags::None);
+    let mut wz = wizard!("d:f,steps:['Introduction','Configuration','Installation','Summary']");
+    wz.add(0, Label::new("Introduction text", layout!("x:1,y:1,w:20")));
+    w.add(wz);
+    driver.add_window(w);
+    driver.settle();
+    assert!(driver.screen_text().contains("Step 1 of 4: Introduction"));
+}
+
+#[test]
+fn check_hidden_step_indicator() {
+    let mut driver = crate::testing::TestDriver::new(App::debug(80, 20, "")).unwrap();
+    let mut w = Window::new("Setup", layout!("x:0,y:0,w:62,h:12"), window::Flags::None);
+    let mut wz = wizard!("d:f,steps:['First','Second'],flags:HideStepIndicator");
+    let b = wz.add(0, Button::new("Start", layout!("x:0,y:0,w:10"), button::Type::Flat));
+    let h = w.add(wz);
+    driver.add_window(w);
+    driver.settle();
+    assert!(!driver.screen_text().contains("1. First"));
+    // the controls of the step start right from the top of the wizard
+    assert_eq!(driver.bounds(b).unwrap().top(), driver.bounds(h).unwrap().top());
+}
+
+#[ModalWindow(events = WizardEvents, response = String, internal = true)]
+struct SetupDialog {
+    name: Handle<TextField>,
+}
+impl SetupDialog {
+    fn new() -> Self {
+        let mut w = Self {
+            base: ModalWindow::new("Setup", layout!("a:c,w:50,h:12"), window::Flags::None),
+            name: Handle::None,
+        };
+        let mut wz = wizard!("d:f,steps:['Welcome','Name']");
+        wz.add(0, Label::new("Welcome", layout!("x:1,y:1,w:20")));
+        w.name = wz.add(1, TextField::new("", layout!("x:1,y:1,w:20"), textfield::Flags::None));
+        w.add(wz);
+        w
+    }
+}
+impl WizardEvents for SetupDialog {
+    fn on_finish(&mut self, _handle: Handle<Wizard>) -> EventProcessStatus {
+        let h = self.name;
+        let name = self.control(h).map(|t| t.text().to_string()).unwrap_or_default();
+        self.exit_with(name);
+        EventProcessStatus::Processed
+    }
+}
+
+#[Window(events = ButtonEvents, internal = true)]
+struct LauncherWin {
+    result: Option<Option<String>>,
+}
+impl LauncherWin {
+    fn new() -> Self {
+        let mut w = Self {
+            base: Window::new("Launcher", layout!("x:0,y:0,w:30,h:6"), window::Flags::None),
+            result: None,
+        };
+        w.add(button!("Setup,x:1,y:1,w:12"));
+        w
+    }
+}
+impl ButtonEvents for LauncherWin {
+    fn on_pressed(&mut self, _handle: Handle<Button>) -> EventProcessStatus {
+        self.result = Some(SetupDialog::new().show());
+        EventProcessStatus::Processed
+    }
+}
+
+#[test]
+fn check_modal_finish_result() {
+    let mut driver = crate::testing::TestDriver::new(App::debug(80, 20, "")).unwrap();
+    let wh = driver.add_window(LauncherWin::new());
+    driver.settle();
+    let b = driver.find::<Button>().caption("Setup").single();
+    // the input for the modal window must be queued before it is opened
+    driver.queue_key(key!("Alt+N"));
+    driver.queue_text("John");
+    driver.queue_key(key!("Alt+F"));
+    driver.click(b);
+    assert_eq!(driver.get(wh).unwrap().result, Some(Some("John".to_string())));
+    assert_eq!(driver.find::<Window>().count(), 1);
+}
+
+#[test]
+fn check_modal_cancel_result() {
+    let mut driver = crate::testing::TestDriver::new(App::debug(80, 20, "")).unwrap();
+    let wh = driver.add_window(LauncherWin::new());
+    driver.settle();
+    let b = driver.find::<Button>().caption("Setup").single();
+    driver.queue_key(key!("Alt+N"));
+    driver.queue_text("John");
+    driver.queue_key(key!("Alt+C"));
+    driver.click(b);
+    assert_eq!(driver.get(wh).unwrap().result, Some(None));
+    assert_eq!(driver.find::<Window>().count(), 1);
+}
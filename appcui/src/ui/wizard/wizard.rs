@@ -0,0 +1,411 @@
+use super::events::{EventData, WizardEvents};
+use super::{Flags, StepPage};
+use crate::prelude::*;
+
+// the width of one action (Back / Next / Finish / Cancel) from the bottom of the wizard
+const ACTION_WIDTH: i32 = 10;
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Action {
+    Back = 0,
+    Next = 1,
+    Finish = 2,
+    Cancel = 3,
+}
+impl Action {
+    const ALL: [Action; 4] = [Action::Back, Action::Next, Action::Finish, Action::Cancel];
+}
+
+#[CustomControl(overwrite=OnPaint+OnMouseEvent+OnKeyPressed, internal=true)]
+pub struct Wizard {
+    flags: Flags,
+    steps: Vec<Caption>,
+    skipped: Vec<bool>,
+    // the steps visited before the current one (used to go back)
+    history: Vec<usize>,
+    current: Option<usize>,
+    actions: [Caption; 4],
+    hovered_action: Option<Action>,
+}
+impl Wizard {
+    /// Creates a new Wizard control with the specified layout and flags.
+    /// The flags can be a combination of the following values:
+    /// * `wizard::Flags::HideStepIndicator` - if set, the step indicator (the list of steps from the top of the control) is not displayed
+    ///
+    /// The events of the wizard (validation, skipped steps, finish and cancel) are sent to the window that contains it
+    /// via the `WizardEvents` trait.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use appcui::prelude::*;
+    ///
+    /// let mut wz = Wizard::new(layout!("d:f"), wizard::Flags::None);
+    /// let welcome = wz.add_step("Welcome");
+    /// wz.add(welcome, Label::new("This wizard will install the application", layout!("x:1,y:1,w:40")));
+    /// let options = wz.add_step("Options");
+    /// wz.add(options, CheckBox::new("Create a desktop shortcut", layout!("x:1,y:1,w:30"), true));
+    /// ```
+    pub fn new(layout: Layout, flags: Flags) -> Self {
+        let mut w = Self {
+            base: ControlBase::with_status_flags(layout, StatusFlags::Visible | StatusFlags::Enabled | StatusFlags::AcceptInput),
+            flags,
+            steps: Vec::with_capacity(4),
+            skipped: Vec::with_capacity(4),
+            history: Vec::new(),
+            current: None,
+            actions: [
+                Caption::new("&Back", ExtractHotKeyMethod::AltPlusKey),
+                Caption::new("&Next", ExtractHotKeyMethod::AltPlusKey),
+                Caption::new("&Finish", ExtractHotKeyMethod::AltPlusKey),
+                Caption::new("&Cancel", ExtractHotKeyMethod::AltPlusKey),
+            ],
+            hovered_action: None,
+        };
+        let top = if flags.contains(Flags::HideStepIndicator) { 0 } else { 2 };
+        w.set_margins(0, top, 0, 2);
+        w
+    }
+
+    /// Adds a new step with the specified caption (displayed in the step indicator).
+    /// The function returns the index of the newly created step.
+    pub fn add_step(&mut self, caption: &str) -> u32 {
+        let idx = self.base.children.len() as u32;
+        self.base.add_child(StepPage::new(idx == 0));
+        self.steps.push(Caption::new(caption, ExtractHotKeyMethod::NoHotKey));
+        self.skipped.push(false);
+        if idx == 0 {
+            self.current = Some(0);
+        }
+        idx
+    }
+
+    /// Adds a new control to the step specified by its index.
+    /// If the step index is out of bounds, the function returns `Handle::None`. Otherwise, it returns a handle to the newly created control.
+    pub fn add<T>(&mut self, step: u32, control: T) -> Handle<T>
+    where
+        T: Control + NotWindow + NotDesktop + 'static,
+    {
+        if (step as usize) < self.base.children.len() {
+            let h = self.base.children[step as usize];
+            if let Some(page) = RuntimeManager::get().get_controls_mut().get_mut(h) {
+                return page.base_mut().add_child(control);
+            }
+        }
+        Handle::None
+    }
+
+    /// Returns the index of the current step or `None` if the wizard has no steps.
+    #[inline(always)]
+    pub fn current_step(&self) -> Option<usize> {
+        self.current
+    }
+
+    /// Returns the number of steps.
+    #[inline(always)]
+    pub fn step_count(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Returns the caption of the step at the specified index or `None` if the index is out of bounds.
+    #[inline]
+    pub fn step_caption(&self, index: usize) -> Option<&str> {
+        self.steps.get(index).map(|s| s.text())
+    }
+
+    /// Sets the caption of the step at the specified index. If the index is out of bounds, the function does nothing.
+    pub fn set_step_caption(&mut self, index: usize, caption: &str) {
+        if let Some(step) = self.steps.get_mut(index) {
+            step.set_text(caption, ExtractHotKeyMethod::NoHotKey);
+        }
+    }
+
+    /// Returns `true` if the step at the specified index was skipped the last time the user moved past it.
+    #[inline]
+    pub fn is_step_skipped(&self, index: usize) -> bool {
+        self.skipped.get(index).copied().unwrap_or(false)
+    }
+
+    /// Moves to the next step, exactly as if the user pressed the `Next` action (the current step is validated
+    /// and the steps that have to be skipped are skipped). This is useful for steps that have their own way of
+    /// moving forward (e.g. a double click on an item from a list).
+    pub fn next(&mut self) {
+        if let Some(step) = self.current {
+            if step + 1 < self.steps.len() {
+                self.raise_wizard_event(EventData::Next { step: step as u32 });
+            }
+        }
+    }
+
+    /// Moves back to the previous visited step (the same as pressing the `Back` action). No validation is performed.
+    pub fn back(&mut self) {
+        if let (Some(old), Some(new)) = (self.current, self.history.pop()) {
+            // the steps after the new one will be checked again when moving forward
+            for skipped in self.skipped.iter_mut().skip(new) {
+                *skipped = false;
+            }
+            self.show_step(new);
+            self.raise_wizard_event(EventData::Back {
+                new_step: new as u32,
+                old_step: old as u32,
+            });
+        }
+    }
+
+    fn finish(&mut self) {
+        if let Some(step) = self.current {
+            self.raise_wizard_event(EventData::Finish { step: step as u32 });
+        }
+    }
+
+    fn cancel(&mut self) {
+        self.raise_wizard_event(EventData::Cancel);
+    }
+
+    fn raise_wizard_event(&self, data: EventData) {
+        self.raise_event(ControlEvent {
+            emitter: self.handle,
+            receiver: self.event_processor,
+            data: ControlEventData::Wizard(data),
+        });
+    }
+
+    fn show_step(&mut self, index: usize) {
+        let cm = RuntimeManager::get().get_controls_mut();
+        for (child_index, handle_child) in self.base.children.iter().enumerate() {
+            if let Some(control) = cm.get_mut(*handle_child) {
+                control.base_mut().set_visible(index == child_index);
+            }
+        }
+        self.current = Some(index);
+        self.base.focused_child_index = VectorIndex::with_value(index);
+        if let Some(control) = cm.get_mut(self.base.children[index]) {
+            control.base_mut().request_focus();
+        }
+        self.request_update();
+    }
+
+    // moves forward from `step` to `next` (all steps in between were skipped)
+    fn advance(&mut self, step: usize, next: usize) {
+        for (index, skipped) in self.skipped.iter_mut().enumerate() {
+            if index > step {
+                *skipped = index < next;
+            }
+        }
+        self.history.push(step);
+        self.show_step(next);
+    }
+
+    // processes the events raised by a wizard (the window that receives them decides if the wizard
+    // can move forward, which steps are skipped and what happens when the wizard is finished or canceled)
+    pub(crate) fn process_event(receiver: &mut dyn Control, handle: Handle<Wizard>, data: EventData) -> EventProcessStatus {
+        let Some(wizard) = RuntimeManager::get().get_control_mut(handle) else {
+            return EventProcessStatus::Ignored;
+        };
+        match data {
+            EventData::Next { step } => {
+                if (wizard.current != Some(step as usize)) || (WizardEvents::on_validate_step(receiver, handle, step) != ActionRequest::Allow) {
+                    return EventProcessStatus::Processed;
+                }
+                let count = wizard.step_count() as u32;
+                let mut next = step + 1;
+                while (next < count) && WizardEvents::should_skip_step(receiver, handle, next) {
+                    next += 1;
+                }
+                if next >= count {
+                    // all remaining steps were skipped
+                    return WizardEvents::on_finish(receiver, handle);
+                }
+                wizard.advance(step as usize, next as usize);
+                WizardEvents::on_step_changed(receiver, handle, next, step);
+                EventProcessStatus::Processed
+            }
+            EventData::Back { new_step, old_step } => WizardEvents::on_step_changed(receiver, handle, new_step, old_step),
+            EventData::Finish { step } => {
+                if (wizard.current == Some(step as usize)) && (WizardEvents::on_validate_step(receiver, handle, step) == ActionRequest::Allow) {
+                    WizardEvents::on_finish(receiver, handle)
+                } else {
+                    EventProcessStatus::Processed
+                }
+            }
+            EventData::Cancel => {
+                if WizardEvents::on_cancel(receiver, handle) == ActionRequest::Allow {
+                    let rm = RuntimeManager::get();
+                    let controls = rm.get_controls();
+                    // search the window that contains the wizard
+                    let mut window = wizard.base.parent;
+                    while let Some(control) = controls.get(window) {
+                        let base = control.base();
+                        if base.is_window_control() {
+                            match () {
+                                // a modal window exits without a result
+                                _ if base.is_modal_window() => rm.exit_execution_loop(),
+                                _ if base.is_singlewindow() => rm.close(),
+                                _ => rm.request_remove(window),
+                            }
+                            break;
+                        }
+                        window = base.parent;
+                    }
+                }
+                EventProcessStatus::Processed
+            }
+        }
+    }
+
+    fn is_action_enabled(&self, action: Action) -> bool {
+        if !self.is_enabled() {
+            return false;
+        }
+        let count = self.steps.len();
+        match action {
+            Action::Back => !self.history.is_empty(),
+            Action::Next => self.current.map(|c| c + 1 < count).unwrap_or(false),
+            Action::Finish => self.current.map(|c| c + 1 == count).unwrap_or(false),
+            Action::Cancel => true,
+        }
+    }
+
+    fn execute(&mut self, action: Action) -> EventProcessStatus {
+        if !self.is_action_enabled(action) {
+            return EventProcessStatus::Ignored;
+        }
+        match action {
+            Action::Back => self.back(),
+            Action::Next => self.next(),
+            Action::Finish => self.finish(),
+            Action::Cancel => self.cancel(),
+        }
+        EventProcessStatus::Processed
+    }
+
+    // the actions are aligned to the right side of the last line of the wizard
+    #[inline(always)]
+    fn action_x(&self, action: Action) -> i32 {
+        self.size().width as i32 - 4 * (ACTION_WIDTH + 1) + (action as i32) * (ACTION_WIDTH + 1)
+    }
+
+    fn mouse_position_to_action(&self, x: i32, y: i32) -> Option<Action> {
+        if y != self.size().height as i32 - 1 {
+            return None;
+        }
+        Action::ALL.iter().copied().find(|a| {
+            let left = self.action_x(*a);
+            (x >= left) && (x < left + ACTION_WIDTH)
+        })
+    }
+
+    fn paint_step_indicator(&self, surface: &mut Surface, theme: &Theme) {
+        let width = self.size().width as i32;
+        let line_attr = if self.is_enabled() { theme.lines.normal } else { theme.lines.inactive };
+        surface.draw_horizontal_line_with_size(0, 1, width as u32, LineType::Single, line_attr);
+        let attr_for = |index: usize| match () {
+            _ if !self.is_enabled() => theme.tab.text.inactive,
+            _ if self.current == Some(index) => theme.tab.text.pressed_or_selectd,
+            _ if self.skipped[index] => theme.tab.text.inactive,
+            _ => theme.tab.text.normal,
+        };
+        let labels: Vec<String> = self.steps.iter().enumerate().map(|(i, s)| format!(" {}. {} ", i + 1, s.text())).collect();
+        let total: i32 = labels.iter().map(|l| l.chars().count() as i32 + 1).sum::<i32>() + 1;
+        if total <= width {
+            let mut x = 1;
+            for (index, label) in labels.iter().enumerate() {
+                if index > 0 {
+                    surface.write_char(x - 1, 0, Character::with_attributes(SpecialChar::TriangleRight, line_attr));
+                }
+                surface.write_string(x, 0, label, attr_for(index), false);
+                x += label.chars().count() as i32 + 1;
+            }
+        } else if let Some(current) = self.current {
+            // not enough space for all steps (only the current one is displayed)
+            let text = format!(" Step {} of {}: {} ", current + 1, self.steps.len(), self.steps[current].text());
+            surface.write_string(1, 0, &text, attr_for(current), false);
+        }
+    }
+
+    fn paint_actions(&self, surface: &mut Surface, theme: &Theme) {
+        let sz = self.size();
+        let y = sz.height as i32 - 1;
+        let line_attr = if self.is_enabled() { theme.lines.normal } else { theme.lines.inactive };
+        surface.draw_horizontal_line_with_size(0, y - 1, sz.width, LineType::Single, line_attr);
+        let mut format = TextFormatBuilder::new()
+            .position(0, y)
+            .wrap_type(WrapType::SingleLineWrap(ACTION_WIDTH as u16))
+            .align(TextAlignment::Center)
+            .build();
+        let default_action = if self.is_action_enabled(Action::Finish) {
+            Action::Finish
+        } else {
+            Action::Next
+        };
+        for action in Action::ALL {
+            let (text_attr, hotkey_attr) = match () {
+                _ if !self.is_action_enabled(action) => (theme.button.text.inactive, theme.button.hotkey.inactive),
+                _ if self.hovered_action == Some(action) => (theme.button.text.hovered, theme.button.hotkey.hovered),
+                _ if (action == default_action) && self.has_focus() => (theme.button.text.focused, theme.button.hotkey.focused),
+                _ => (theme.button.text.normal, theme.button.hotkey.normal),
+            };
+            let caption = &self.actions[action as usize];
+            let x = self.action_x(action);
+            surface.fill_horizontal_line_with_size(x, y, ACTION_WIDTH as u32, Character::with_attributes(' ', text_attr));
+            format.x = x + ACTION_WIDTH / 2;
+            format.set_attribute(text_attr);
+            format.set_chars_count(caption.chars_count() as u16);
+            format.set_hotkey_from_caption(hotkey_attr, caption);
+            surface.write_text(caption.text(), &format);
+        }
+    }
+}
+
+impl OnPaint for Wizard {
+    fn on_paint(&self, surface: &mut Surface, theme: &Theme) {
+        if !self.flags.contains(Flags::HideStepIndicator) {
+            self.paint_step_indicator(surface, theme);
+        }
+        self.paint_actions(surface, theme);
+    }
+}
+
+impl OnMouseEvent for Wizard {
+    fn on_mouse_event(&mut self, event: &MouseEvent) -> EventProcessStatus {
+        match event {
+            MouseEvent::Leave => {
+                if self.hovered_action.is_some() {
+                    self.hovered_action = None;
+                    EventProcessStatus::Processed
+                } else {
+                    EventProcessStatus::Ignored
+                }
+            }
+            MouseEvent::Over(ev) => {
+                let action = self.mouse_position_to_action(ev.x, ev.y).filter(|a| self.is_action_enabled(*a));
+                if action != self.hovered_action {
+                    self.hovered_action = action;
+                    EventProcessStatus::Processed
+                } else {
+                    EventProcessStatus::Ignored
+                }
+            }
+            MouseEvent::Pressed(ev) => {
+                if let Some(action) = self.mouse_position_to_action(ev.x, ev.y) {
+                    self.execute(action)
+                } else {
+                    EventProcessStatus::Ignored
+                }
+            }
+            _ => EventProcessStatus::Ignored,
+        }
+    }
+}
+
+impl OnKeyPressed for Wizard {
+    fn on_key_pressed(&mut self, key: Key, _character: char) -> EventProcessStatus {
+        if key.modifier.contains(KeyModifier::Alt) {
+            for action in Action::ALL {
+                if self.actions[action as usize].hotkey() == key {
+                    return self.execute(action);
+                }
+            }
+        }
+        EventProcessStatus::Ignored
+    }
+}
@@ -0,0 +1,12 @@
+//! A container that arranges its children in lines, wrapping them when there is no more space.
+//!
+//! The WrapPanel control positions its children one after another (like a StackPanel) and starts a new line
+//! (or a new column) when the next child does not fit. Weights and alignment are applied within each line.
+
+#[cfg(test)]
+mod tests;
+mod wrappanel;
+
+pub use self::wrappanel::WrapPanel;
+pub use super::stackpanel::Alignment;
+pub use super::stackpanel::Orientation;
@@ -0,0 +1,62 @@
+use crate::prelude::*;
+use crate::testing::TestDriver;
+
+fn label(text: &str, width: u16, height: u16) -> Label {
+    Label::new(
+        text,
+        LayoutBuilder::new()
+            .alignment(layout::Alignment::TopLeft)
+            .width(width)
+            .height(height)
+            .build(),
+    )
+}
+
+#[test]
+fn check_horizontal_wrap() {
+    let mut driver = TestDriver::new(App::debug(60, 20, "")).unwrap();
+    // window client area: (1,1) - 38x10
+    let mut w = Window::new("Test", layout!("x:0,y:0,w:40,h:12"), window::Flags::Sizeable);
+    let mut wp = WrapPanel::new(layout!("d:f"), wrappanel::Orientation::Horizontal);
+    wp.set_spacing(1, 1);
+    let l1 = wp.add(label("1", 12, 1));
+    let l2 = wp.add_with_options(label("2", 12, 2), 0, wrappanel::Alignment::Start);
+    let l3 = wp.add_with_options(label("3", 12, 1), 0, wrappanel::Alignment::End);
+    let l4 = wp.add_with_options(label("4", 12, 1), 1, wrappanel::Alignment::Stretch);
+    w.add(wp);
+    let w = driver.add_window(w);
+    driver.settle();
+    // 12 + 1 + 12 + 1 + 12 = 38 => three children on the first row
+    assert_eq!(driver.bounds(l1), Some(Rect::new(1, 1, 12, 1)));
+    assert_eq!(driver.bounds(l2), Some(Rect::new(14, 1, 25, 2)));
+    assert_eq!(driver.bounds(l3), Some(Rect::new(27, 2, 38, 2)));
+    // the second row (after the line spacing) - the weighted child fills the entire row
+    assert_eq!(driver.bounds(l4), Some(Rect::new(1, 4, 38, 4)));
+
+    // a smaller window --> only two children per row
+    driver.get_mut(w).unwrap().set_size(30, 12);
+    driver.settle();
+    assert_eq!(driver.bounds(l1), Some(Rect::new(1, 1, 12, 1)));
+    assert_eq!(driver.bounds(l2), Some(Rect::new(14, 1, 25, 2)));
+    assert_eq!(driver.bounds(l3), Some(Rect::new(1, 4, 12, 4)));
+    assert_eq!(driver.bounds(l4), Some(Rect::new(14, 4, 28, 4)));
+}
+
+#[test]
+fn check_vertical_wrap() {
+    let mut driver = TestDriver::new(App::debug(60, 20, "")).unwrap();
+    let mut w = Window::new("Test", layout!("x:0,y:0,w:40,h:7"), window::Flags::None);
+    let mut wp = WrapPanel::new(layout!("d:f"), wrappanel::Orientation::Vertical);
+    wp.set_spacing(0, 2);
+    wp.set_padding(1, 0, 0, 0);
+    let l1 = wp.add(label("1", 6, 2));
+    let l2 = wp.add(label("2", 8, 2));
+    let l3 = wp.add(label("3", 4, 3));
+    w.add(wp);
+    driver.add_window(w);
+    driver.settle();
+    // client height is 5 => two children in the first column (its width is 8)
+    assert_eq!(driver.bounds(l1), Some(Rect::new(2, 1, 7, 2)));
+    assert_eq!(driver.bounds(l2), Some(Rect::new(2, 3, 9, 4)));
+    assert_eq!(driver.bounds(l3), Some(Rect::new(12, 1, 15, 3)));
+}
@@ -0,0 +1,231 @@
+use super::{Alignment, Orientation};
+use crate::prelude::*;
+use crate::ui::stackpanel::{distribute, FlowItem};
+
+#[CustomControl(overwrite=OnResize+OnMeasure, internal = true)]
+pub struct WrapPanel {
+    items: Vec<FlowItem>,
+    orientation: Orientation,
+    spacing: u16,
+    line_spacing: u16,
+}
+impl WrapPanel {
+    /// Creates a new WrapPanel control with the specified layout and orientation.
+    /// For `Orientation::Horizontal` the children are positioned from left to right and a new row is started when
+    /// a child does not fit in the current one. For `Orientation::Vertical` the children are positioned from top to
+    /// bottom and a new column is started when a child does not fit. The size of every child is computed from its own layout.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use appcui::prelude::*;
+    ///
+    /// let mut wp = WrapPanel::new(layout!("d:f"), wrappanel::Orientation::Horizontal);
+    /// wp.set_spacing(1, 0);
+    /// for name in ["Red", "Green", "Blue", "Yellow"] {
+    ///     wp.add(Button::new(name, layout!("a:c,w:12"), button::Type::Flat));
+    /// }
+    /// ```
+    pub fn new(layout: Layout, orientation: Orientation) -> Self {
+        Self {
+            base: ControlBase::with_status_flags(layout, StatusFlags::Visible | StatusFlags::Enabled),
+            items: Vec::with_capacity(8),
+            orientation,
+            spacing: 0,
+            line_spacing: 0,
+        }
+    }
+
+    /// Adds a new control to the panel (with no weight and a `Start` alignment) and returns a handle towards it.
+    #[inline(always)]
+    pub fn add<T>(&mut self, control: T) -> Handle<T>
+    where
+        T: Control + NotWindow + NotDesktop + 'static,
+    {
+        self.add_with_options(control, 0, Alignment::Start)
+    }
+
+    /// Adds a new control to the panel with a specific weight and alignment.
+    /// * `weight` - the remaining space of a line is split between the children with a weight bigger than 0 (proportional with their weight)
+    ///   and added to their preferred size.
+    /// * `alignment` - the alignment of the child within its line (one of `Start`, `Center`, `End` or `Stretch`)
+    pub fn add_with_options<T>(&mut self, control: T, weight: u16, alignment: Alignment) -> Handle<T>
+    where
+        T: Control + NotWindow + NotDesktop + 'static,
+    {
+        let h = self.add_child(control);
+        if !h.is_none() {
+            self.items.push(FlowItem::new(h, weight, alignment));
+            self.reflow();
+        }
+        h
+    }
+
+    /// Sets the weight of a child (see `add_with_options` for more details).
+    pub fn set_weight<T>(&mut self, handle: Handle<T>, weight: u16) {
+        let h: Handle<()> = handle.cast();
+        if let Some(item) = self.items.iter_mut().find(|i| i.handle() == h) {
+            item.weight = weight;
+            self.reflow();
+        }
+    }
+
+    /// Returns the weight of a child or `None` if the handle is not a child of the panel.
+    pub fn weight<T>(&self, handle: Handle<T>) -> Option<u16> {
+        let h: Handle<()> = handle.cast();
+        self.items.iter().find(|i| i.handle() == h).map(|i| i.weight)
+    }
+
+    /// Sets the alignment of a child within its line.
+    pub fn set_alignment<T>(&mut self, handle: Handle<T>, alignment: Alignment) {
+        let h: Handle<()> = handle.cast();
+        if let Some(item) = self.items.iter_mut().find(|i| i.handle() == h) {
+            item.alignment = alignment;
+            self.reflow();
+        }
+    }
+
+    /// Returns the alignment of a child or `None` if the handle is not a child of the panel.
+    pub fn alignment<T>(&self, handle: Handle<T>) -> Option<Alignment> {
+        let h: Handle<()> = handle.cast();
+        self.items.iter().find(|i| i.handle() == h).map(|i| i.alignment)
+    }
+
+    /// Sets the space (in characters) between two consecutive children of the same line and the space between two lines.
+    pub fn set_spacing(&mut self, spacing: u16, line_spacing: u16) {
+        self.spacing = spacing;
+        self.line_spacing = line_spacing;
+        self.reflow();
+    }
+
+    /// Returns the space (in characters) between two consecutive children of the same line.
+    #[inline(always)]
+    pub fn spacing(&self) -> u16 {
+        self.spacing
+    }
+
+    /// Returns the space (in characters) between two lines.
+    #[inline(always)]
+    pub fn line_spacing(&self) -> u16 {
+        self.line_spacing
+    }
+
+    /// Sets the padding (the empty space between the margins of the panel and its children).
+    pub fn set_padding(&mut self, left: u8, top: u8, right: u8, bottom: u8) {
+        self.base.set_margins(left, top, right, bottom);
+        self.reflow();
+    }
+
+    /// Sets the orientation of the panel.
+    pub fn set_orientation(&mut self, orientation: Orientation) {
+        self.orientation = orientation;
+        self.reflow();
+    }
+
+    /// Returns the orientation of the panel.
+    #[inline(always)]
+    pub fn orientation(&self) -> Orientation {
+        self.orientation
+    }
+
+    /// Re-computes the position and size of all children. This is done automatically when the panel is resized or
+    /// when its properties are changed, but it needs to be called if the visibility of a child was changed
+    /// (hidden children do not occupy any space).
+    pub fn reflow(&mut self) {
+        let client = self.client_size();
+        if (client.width == 0) || (client.height == 0) {
+            return;
+        }
+        let vertical = self.orientation == Orientation::Vertical;
+        let main = if vertical { client.height } else { client.width };
+        let visible: Vec<&FlowItem> = self.items.iter().filter(|i| i.is_visible()).collect();
+        let preferred: Vec<(u32, u32)> = visible
+            .iter()
+            .map(|i| {
+                let sz = i.preferred_size(client);
+                if vertical {
+                    (sz.height.min(main), sz.width)
+                } else {
+                    (sz.width.min(main), sz.height)
+                }
+            })
+            .collect();
+        let spacing = self.spacing as u32;
+        let mut line_offset = 0i32;
+        let mut start = 0;
+        while start < visible.len() {
+            // find the children that fit in the current line
+            let mut end = start + 1;
+            let mut used = preferred[start].0;
+            while (end < visible.len()) && (used + spacing + preferred[end].0 <= main) {
+                used += spacing + preferred[end].0;
+                end += 1;
+            }
+            let line_size = preferred[start..end].iter().map(|p| p.1).max().unwrap_or(0);
+            let weights: Vec<u16> = visible[start..end].iter().map(|i| i.weight).collect();
+            let shares = distribute(main - used, &weights);
+            let mut pos = 0i32;
+            for index in start..end {
+                let item = visible[index];
+                let main_size = preferred[index].0 + shares[index - start];
+                let (cross_pos, cross_size) = item.cross_axis(preferred[index].1, line_size);
+                if vertical {
+                    item.place(line_offset + cross_pos, pos, cross_size, main_size);
+                } else {
+                    item.place(pos, line_offset + cross_pos, main_size, cross_size);
+                }
+                pos += main_size as i32 + spacing as i32;
+            }
+            line_offset += line_size as i32 + self.line_spacing as i32;
+            start = end;
+        }
+    }
+}
+
+impl OnResize for WrapPanel {
+    fn on_resize(&mut self, _old_size: Size, _new_size: Size) {
+        self.reflow();
+    }
+    fn on_children_layout_changed(&mut self) {
+        self.reflow();
+    }
+}
+
+impl OnMeasure for WrapPanel {
+    fn preferred_size(&self, available: Size) -> Option<Size> {
+        let vertical = self.orientation == Orientation::Vertical;
+        let margins = &self.base.margins;
+        let horizontal_margins = margins.left as u32 + margins.right as u32;
+        let vertical_margins = margins.top as u32 + margins.bottom as u32;
+        // the children are wrapped at the size of the parent (or not at all if the parent has no size)
+        let limit = if vertical {
+            available.height.saturating_sub(vertical_margins)
+        } else {
+            available.width.saturating_sub(horizontal_margins)
+        };
+        let limit = if limit == 0 { u32::MAX } else { limit };
+        let spacing = self.spacing as u32;
+        let mut main = 0u32;
+        let mut cross = 0u32;
+        let mut line_main = 0u32;
+        let mut line_cross = 0u32;
+        let mut first_in_line = true;
+        for item in self.items.iter().filter(|i| i.is_visible()) {
+            let sz = item.measured_size(available);
+            let (m, c) = if vertical { (sz.height, sz.width) } else { (sz.width, sz.height) };
+            if !first_in_line && (line_main.saturating_add(spacing + m) > limit) {
+                main = main.max(line_main);
+                cross += line_cross + self.line_spacing as u32;
+                line_main = 0;
+                line_cross = 0;
+                first_in_line = true;
+            }
+            line_main += if first_in_line { m } else { spacing + m };
+            line_cross = line_cross.max(c);
+            first_in_line = false;
+        }
+        main = main.max(line_main);
+        cross += line_cross;
+        let (width, height) = if vertical { (cross, main) } else { (main, cross) };
+        Some(Size::new(width + horizontal_margins, height + vertical_margins))
+    }
+}
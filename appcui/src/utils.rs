@@ -10,6 +10,7 @@ pub (crate) mod log;
 pub (crate) mod fs;
 pub (crate) mod temp_buffer;
 pub (crate) mod string_comparison;
+pub (crate) mod regex;
 #[cfg(test)]
 mod tests;
 
@@ -0,0 +1,498 @@
+use std::ops::Range;
+
+// a (small) regular expression engine (used by the filters of the log view and by the text field validators).
+// The pattern is compiled into a list of instructions that is executed by a Pike VM: all the possible paths are
+// followed at the same time (without backtracking and without recursion), so the time needed to match a text is
+// linear in the length of the text (regardless of the pattern) and long lines can not overflow the stack. It supports:
+// literals, `.`, character classes (`[a-z]`, `[^0-9]`, `\d`, `\w`, `\s` and their negations), anchors
+// (`^`, `$`, `\b`), groups (`(...)`, `(?:...)`), alternation (`|`), greedy and lazy quantifiers
+// (`*`, `+`, `?`, `{n}`, `{n,}`, `{n,m}`) and the `(?i)` prefix for case insensitive matching.
+
+#[derive(Clone, Debug)]
+enum Node {
+    Char(char),
+    Any,
+    Class {
+        ranges: Vec<(char, char)>,
+        negated: bool,
+    },
+    Start,
+    End,
+    WordBoundary(bool),
+    Group(Vec<Vec<Node>>),
+    Repeat {
+        node: Box<Node>,
+        min: usize,
+        max: Option<usize>,
+        greedy: bool,
+    },
+}
+
+const DIGIT: &[(char, char)] = &[('0', '9')];
+const WORD: &[(char, char)] = &[('a', 'z'), ('A', 'Z'), ('0', '9'), ('_', '_')];
+const SPACE: &[(char, char)] = &[(' ', ' '), ('\t', '\r')];
+const MAX_REPEAT: usize = 1000;
+const MAX_NESTING: usize = 64;
+const MAX_INSTRUCTIONS: usize = 20_000;
+
+#[derive(Clone, Debug)]
+enum Assertion {
+    Start,
+    End,
+    WordBoundary(bool),
+}
+
+#[derive(Clone, Debug)]
+enum Inst {
+    Char(char),
+    Any,
+    Class { ranges: Vec<(char, char)>, negated: bool },
+    Assert(Assertion),
+    // both branches are followed, the first one has a higher priority
+    Split(usize, usize),
+    Jump(usize),
+    Match,
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct Regex {
+    program: Vec<Inst>,
+    ignore_case: bool,
+}
+
+struct Parser<'a> {
+    chars: &'a [char],
+    pos: usize,
+    depth: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek();
+        self.pos += 1;
+        c
+    }
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn alternatives(&mut self) -> Result<Vec<Vec<Node>>, String> {
+        let mut result = vec![self.sequence()?];
+        while self.eat('|') {
+            result.push(self.sequence()?);
+        }
+        Ok(result)
+    }
+
+    fn sequence(&mut self) -> Result<Vec<Node>, String> {
+        let mut nodes = Vec::new();
+        while let Some(c) = self.peek() {
+            if c == '|' || c == ')' {
+                break;
+            }
+            let atom = self.atom()?;
+            nodes.push(self.quantifier(atom)?);
+        }
+        Ok(nodes)
+    }
+
+    fn atom(&mut self) -> Result<Node, String> {
+        let Some(c) = self.next() else {
+            return Err("unexpected end of pattern".to_string());
+        };
+        Ok(match c {
+            '.' => Node::Any,
+            '^' => Node::Start,
+            '$' => Node::End,
+            '(' => {
+                if self.eat('?') && !self.eat(':') {
+                    return Err("unsupported group type".to_string());
+                }
+                if self.depth >= MAX_NESTING {
+                    return Err("too many nested groups".to_string());
+                }
+                self.depth += 1;
+                let group = self.alternatives()?;
+                self.depth -= 1;
+                if !self.eat(')') {
+                    return Err("missing ')'".to_string());
+                }
+                Node::Group(group)
+            }
+            '[' => self.class()?,
+            '\\' => self.escape()?,
+            '*' | '+' | '?' => return Err(format!("nothing to repeat before '{c}'")),
+            _ => Node::Char(c),
+        })
+    }
+
+    fn escape(&mut self) -> Result<Node, String> {
+        let Some(c) = self.next() else {
+            return Err("incomplete escape sequence".to_string());
+        };
+        let class = |ranges: &[(char, char)], negated: bool| Node::Class {
+            ranges: ranges.to_vec(),
+            negated,
+        };
+        Ok(match c {
+            'd' => class(DIGIT, false),
+            'D' => class(DIGIT, true),
+            'w' => class(WORD, false),
+            'W' => class(WORD, true),
+            's' => class(SPACE, false),
+            'S' => class(SPACE, true),
+            'b' => Node::WordBoundary(true),
+            'B' => Node::WordBoundary(false),
+            _ => Node::Char(escaped_char(c)),
+        })
+    }
+
+    fn class(&mut self) -> Result<Node, String> {
+        let negated = self.eat('^');
+        let mut ranges = Vec::new();
+        let mut first = true;
+        loop {
+            let Some(c) = self.next() else {
+                return Err("missing ']'".to_string());
+            };
+            if c == ']' && !first {
+                break;
+            }
+            first = false;
+            let start = if c == '\\' {
+                match self.next() {
+                    Some('d') => {
+                        ranges.extend_from_slice(DIGIT);
+                        continue;
+                    }
+                    Some('w') => {
+                        ranges.extend_from_slice(WORD);
+                        continue;
+                    }
+                    Some('s') => {
+                        ranges.extend_from_slice(SPACE);
+                        continue;
+                    }
+                    Some(c) => escaped_char(c),
+                    None => return Err("incomplete escape sequence".to_string()),
+                }
+            } else {
+                c
+            };
+            if self.peek() == Some('-') && self.chars.get(self.pos + 1).is_some_and(|c| *c != ']') {
+                self.pos += 1;
+                let end = match self.next() {
+                    Some('\\') => self.next().map(escaped_char).ok_or("incomplete escape sequence")?,
+                    Some(c) => c,
+                    None => return Err("missing ']'".to_string()),
+                };
+                if end < start {
+                    return Err(format!("invalid range '{start}-{end}'"));
+                }
+                ranges.push((start, end));
+            } else {
+                ranges.push((start, start));
+            }
+        }
+        Ok(Node::Class { ranges, negated })
+    }
+
+    fn number(&mut self) -> Option<usize> {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        self.chars[start..self.pos].iter().collect::<String>().parse().ok()
+    }
+
+    fn quantifier(&mut self, node: Node) -> Result<Node, String> {
+        let (min, max) = match self.peek() {
+            Some('*') => (0, None),
+            Some('+') => (1, None),
+            Some('?') => (0, Some(1)),
+            Some('{') => {
+                let start = self.pos;
+                self.pos += 1;
+                let Some(min) = self.number() else {
+                    // not a quantifier (e.g. "{abc}") - the brace is a literal character
+                    self.pos = start;
+                    return Ok(node);
+                };
+                let max = if self.eat(',') { self.number() } else { Some(min) };
+                if !self.eat('}') {
+                    return Err("invalid repetition".to_string());
+                }
+                if max.is_some_and(|max| max < min) || min > MAX_REPEAT || max.is_some_and(|max| max > MAX_REPEAT) {
+                    return Err("invalid repetition range".to_string());
+                }
+                self.pos -= 1;
+                (min, max)
+            }
+            _ => return Ok(node),
+        };
+        self.pos += 1;
+        if matches!(node, Node::Start | Node::End | Node::WordBoundary(_)) {
+            return Err("nothing to repeat".to_string());
+        }
+        let greedy = !self.eat('?');
+        Ok(Node::Repeat {
+            node: Box::new(node),
+            min,
+            max,
+            greedy,
+        })
+    }
+}
+
+fn escaped_char(c: char) -> char {
+    match c {
+        'n' => '\n',
+        't' => '\t',
+        'r' => '\r',
+        _ => c,
+    }
+}
+
+fn is_word(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+struct Compiler {
+    program: Vec<Inst>,
+}
+
+impl Compiler {
+    fn emit(&mut self, inst: Inst) -> Result<usize, String> {
+        if self.program.len() >= MAX_INSTRUCTIONS {
+            return Err("the pattern is too large".to_string());
+        }
+        self.program.push(inst);
+        Ok(self.program.len() - 1)
+    }
+
+    fn alternatives(&mut self, alternatives: &[Vec<Node>]) -> Result<(), String> {
+        let mut jumps = Vec::new();
+        for (index, alt) in alternatives.iter().enumerate() {
+            if index + 1 == alternatives.len() {
+                self.sequence(alt)?;
+            } else {
+                let split = self.emit(Inst::Split(0, 0))?;
+                self.sequence(alt)?;
+                jumps.push(self.emit(Inst::Jump(0))?);
+                self.program[split] = Inst::Split(split + 1, self.program.len());
+            }
+        }
+        let end = self.program.len();
+        for jump in jumps {
+            self.program[jump] = Inst::Jump(end);
+        }
+        Ok(())
+    }
+
+    fn sequence(&mut self, nodes: &[Node]) -> Result<(), String> {
+        nodes.iter().try_for_each(|node| self.node(node))
+    }
+
+    fn node(&mut self, node: &Node) -> Result<(), String> {
+        let inst = match node {
+            Node::Char(c) => Inst::Char(*c),
+            Node::Any => Inst::Any,
+            Node::Class { ranges, negated } => Inst::Class {
+                ranges: ranges.clone(),
+                negated: *negated,
+            },
+            Node::Start => Inst::Assert(Assertion::Start),
+            Node::End => Inst::Assert(Assertion::End),
+            Node::WordBoundary(expected) => Inst::Assert(Assertion::WordBoundary(*expected)),
+            Node::Group(alternatives) => return self.alternatives(alternatives),
+            Node::Repeat { node, min, max, greedy } => return self.repeat(node, *min, *max, *greedy),
+        };
+        self.emit(inst).map(|_| ())
+    }
+
+    fn split(&mut self, at: usize, body: usize, exit: usize, greedy: bool) {
+        self.program[at] = if greedy { Inst::Split(body, exit) } else { Inst::Split(exit, body) };
+    }
+
+    fn repeat(&mut self, node: &Node, min: usize, max: Option<usize>, greedy: bool) -> Result<(), String> {
+        for _ in 0..min {
+            self.node(node)?;
+        }
+        match max {
+            None => {
+                let start = self.emit(Inst::Split(0, 0))?;
+                self.node(node)?;
+                self.emit(Inst::Jump(start))?;
+                self.split(start, start + 1, self.program.len(), greedy);
+            }
+            Some(max) => {
+                // every optional iteration can skip to the end of the repetition
+                let mut splits = Vec::new();
+                for _ in min..max {
+                    splits.push(self.emit(Inst::Split(0, 0))?);
+                    self.node(node)?;
+                }
+                let end = self.program.len();
+                for split in splits {
+                    self.split(split, split + 1, end, greedy);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+// the threads that are active at a specific position (in the order of their priority), with the position
+// where each of them started matching. `visited` keeps (for every instruction) the last position + 1 where
+// the instruction was reached, so that it does not have to be cleared for every position.
+struct Threads {
+    list: Vec<(usize, usize)>,
+    visited: Vec<usize>,
+}
+
+impl Threads {
+    fn new(size: usize) -> Self {
+        Self {
+            list: Vec::new(),
+            visited: vec![0; size],
+        }
+    }
+}
+
+impl Regex {
+    /// Compiles a pattern. The error contains a short description of the problem.
+    pub(crate) fn new(pattern: &str) -> Result<Self, String> {
+        let (pattern, ignore_case) = match pattern.strip_prefix("(?i)") {
+            Some(rest) => (rest, true),
+            None => (pattern, false),
+        };
+        let chars: Vec<char> = pattern.chars().collect();
+        let mut parser = Parser {
+            chars: &chars,
+            pos: 0,
+            depth: 0,
+        };
+        let alternatives = parser.alternatives()?;
+        if parser.pos < chars.len() {
+            return Err("unmatched ')'".to_string());
+        }
+        let mut compiler = Compiler { program: Vec::new() };
+        compiler.alternatives(&alternatives)?;
+        compiler.emit(Inst::Match)?;
+        Ok(Self {
+            program: compiler.program,
+            ignore_case,
+        })
+    }
+
+    /// Returns the range (in characters) of the first match in `text` that starts at or after `from`
+    pub(crate) fn find(&self, text: &[char], from: usize) -> Option<Range<usize>> {
+        self.run(text, from, false)
+    }
+
+    pub(crate) fn is_match(&self, text: &[char]) -> bool {
+        self.find(text, 0).is_some()
+    }
+
+    /// Returns `true` if the whole `text` (not only a part of it) matches the pattern
+    pub(crate) fn is_full_match(&self, text: &[char]) -> bool {
+        self.run(text, 0, true).is_some()
+    }
+
+    fn same_char(&self, a: char, b: char) -> bool {
+        a == b || (self.ignore_case && a.to_lowercase().eq(b.to_lowercase()))
+    }
+
+    fn in_class(&self, c: char, ranges: &[(char, char)]) -> bool {
+        let contains = |c: char| ranges.iter().any(|(start, end)| (*start..=*end).contains(&c));
+        contains(c) || (self.ignore_case && (c.to_lowercase().any(contains) || c.to_uppercase().any(contains)))
+    }
+
+    fn accepts(&self, inst: &Inst, c: char) -> bool {
+        match inst {
+            Inst::Char(ch) => self.same_char(c, *ch),
+            Inst::Any => c != '\n',
+            Inst::Class { ranges, negated } => self.in_class(c, ranges) != *negated,
+            _ => false,
+        }
+    }
+
+    // adds the thread that reaches instruction `pc` and all the threads that follow from it without consuming
+    // a character (jumps, splits and assertions). An explicit stack is used so that the order of the threads
+    // is the same as the one of a recursive (depth first) walk.
+    fn add_thread(&self, threads: &mut Threads, stack: &mut Vec<usize>, pc: usize, start: usize, text: &[char], pos: usize) {
+        stack.push(pc);
+        while let Some(pc) = stack.pop() {
+            if threads.visited[pc] == pos + 1 {
+                continue;
+            }
+            threads.visited[pc] = pos + 1;
+            match &self.program[pc] {
+                Inst::Jump(target) => stack.push(*target),
+                Inst::Split(first, second) => {
+                    stack.push(*second);
+                    stack.push(*first);
+                }
+                Inst::Assert(assertion) => {
+                    let ok = match assertion {
+                        Assertion::Start => pos == 0,
+                        Assertion::End => pos == text.len(),
+                        Assertion::WordBoundary(expected) => {
+                            let before = pos > 0 && is_word(text[pos - 1]);
+                            let after = text.get(pos).is_some_and(|c| is_word(*c));
+                            (before != after) == *expected
+                        }
+                    };
+                    if ok {
+                        stack.push(pc + 1);
+                    }
+                }
+                _ => threads.list.push((pc, start)),
+            }
+        }
+    }
+
+    // the leftmost match (from the matches that start at the same position, the one that the priority of the
+    // alternatives and of the quantifiers selects). If `full` is set, only a match of the whole text is accepted.
+    fn run(&self, text: &[char], from: usize, full: bool) -> Option<Range<usize>> {
+        let mut current = Threads::new(self.program.len());
+        let mut next = Threads::new(self.program.len());
+        let mut stack = Vec::new();
+        let mut result = None;
+        for pos in from..=text.len() {
+            // a new match can start here (with the lowest priority) as long as no match was found
+            if result.is_none() && (!full || pos == from) {
+                self.add_thread(&mut current, &mut stack, 0, pos, text, pos);
+            }
+            if current.list.is_empty() {
+                if result.is_some() || full {
+                    break;
+                }
+                continue;
+            }
+            for &(pc, start) in &current.list {
+                let inst = &self.program[pc];
+                if let Inst::Match = inst {
+                    if !full || pos == text.len() {
+                        // the threads with a lower priority are no longer needed
+                        result = Some(start..pos);
+                        break;
+                    }
+                } else if text.get(pos).is_some_and(|c| self.accepts(inst, *c)) {
+                    self.add_thread(&mut next, &mut stack, pc + 1, start, text, pos + 1);
+                }
+            }
+            current.list.clear();
+            std::mem::swap(&mut current, &mut next);
+        }
+        result
+    }
+}
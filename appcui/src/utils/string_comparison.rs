@@ -20,4 +20,105 @@ pub(crate) fn starts_with_ignore_case(text1: &str, text2: &str) -> bool {
         }
     }
     true
-}
\ No newline at end of file
+}
+
+const SCORE_MATCH: i32 = 16;
+const BONUS_FIRST_CHAR: i32 = 8;
+const BONUS_WORD_START: i32 = 8;
+const BONUS_CONSECUTIVE: i32 = 4;
+
+#[inline(always)]
+fn fuzzy_eq(c1: char, c2: char) -> bool {
+    c1 == c2 || c1.to_lowercase().eq(c2.to_lowercase())
+}
+
+#[inline(always)]
+fn is_word_start(prev: Option<char>, current: char) -> bool {
+    match prev {
+        None => true,
+        Some(p) => (!p.is_alphanumeric() && current.is_alphanumeric()) || (p.is_lowercase() && current.is_uppercase()),
+    }
+}
+
+/// Checks if all characters from `pattern` (white spaces are ignored) can be found, in the same order and
+/// ignoring the case, in `text`. If they can, the index (in characters) of every matched character is added
+/// to `positions` and a score is returned (a higher score means a better match: characters that are found at
+/// the beginning of a word or right after another matched character increase the score, while the gaps between
+/// the matched characters decrease it). An empty pattern matches any text (with a score of 0).
+pub(crate) fn fuzzy_match(pattern: &str, text: &str, positions: &mut Vec<usize>) -> Option<i32> {
+    const NONE: i32 = i32::MIN;
+    positions.clear();
+    let p: Vec<char> = pattern.chars().filter(|c| !c.is_whitespace()).collect();
+    if p.is_empty() {
+        return Some(0);
+    }
+    let t: Vec<char> = text.chars().collect();
+    let (m, n) = (p.len(), t.len());
+    if m > n {
+        return None;
+    }
+    let bonus = |j: usize| {
+        let mut value = SCORE_MATCH;
+        if j == 0 {
+            value += BONUS_FIRST_CHAR;
+        }
+        if is_word_start(if j > 0 { Some(t[j - 1]) } else { None }, t[j]) {
+            value += BONUS_WORD_START;
+        }
+        value
+    };
+    // score[i * n + j] = the best score for matching p[0..=i] with p[i] matched on t[j]
+    // from[i * n + j] = the position where p[i - 1] was matched (for that score)
+    let mut score = vec![NONE; m * n];
+    let mut from = vec![0usize; m * n];
+    for j in 0..n {
+        if fuzzy_eq(t[j], p[0]) {
+            score[j] = bonus(j);
+        }
+    }
+    for i in 1..m {
+        let prev = (i - 1) * n;
+        // best value of (score[i-1][k] + k + 1) for k <= j - 2 (the gap penalty is j - k - 1)
+        let mut best = NONE;
+        let mut best_k = 0;
+        for j in i..n {
+            if j >= 2 && score[prev + j - 2] != NONE && score[prev + j - 2] + (j as i32) - 1 > best {
+                best = score[prev + j - 2] + (j as i32) - 1;
+                best_k = j - 2;
+            }
+            if !fuzzy_eq(t[j], p[i]) {
+                continue;
+            }
+            let mut value = NONE;
+            let mut k = 0;
+            if score[prev + j - 1] != NONE {
+                value = score[prev + j - 1] + BONUS_CONSECUTIVE;
+                k = j - 1;
+            }
+            if best != NONE && best - (j as i32) > value {
+                value = best - (j as i32);
+                k = best_k;
+            }
+            if value != NONE {
+                score[i * n + j] = value + bonus(j);
+                from[i * n + j] = k;
+            }
+        }
+    }
+    // the best match for the last character of the pattern (the first one if there are more with the same score)
+    let last = (m - 1) * n;
+    let mut end = None;
+    for j in (m - 1)..n {
+        if score[last + j] != NONE && end.map(|e| score[last + j] > score[last + e]).unwrap_or(true) {
+            end = Some(j);
+        }
+    }
+    let end = end?;
+    let mut j = end;
+    for i in (0..m).rev() {
+        positions.push(j);
+        j = from[i * n + j];
+    }
+    positions.reverse();
+    Some(score[last + end])
+}
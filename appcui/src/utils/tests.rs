@@ -7,6 +7,7 @@ use super::Strategy;
 use super::TempBuffer;
 use super::TempString;
 use super::VectorIndex;
+use super::string_comparison::fuzzy_match;
 use crate::input::Key;
 use crate::input::KeyCode;
 use crate::input::KeyModifier;
@@ -1852,3 +1853,32 @@ fn check_handle_manager_invalid_operations() {
     assert_eq!(m.element(0), None);
     assert_eq!(m.element_mut(0), None);
 }
+
+#[test]
+fn check_fuzzy_match() {
+    let mut p = Vec::new();
+    assert_eq!(fuzzy_match("", "Save", &mut p), Some(0));
+    assert!(p.is_empty());
+    assert!(fuzzy_match("sv", "Save", &mut p).is_some());
+    assert_eq!(p, vec![0, 2]);
+    // case is ignored and white spaces from the pattern are skipped
+    assert!(fuzzy_match("f S", "File > Save", &mut p).is_some());
+    assert_eq!(p, vec![0, 7]);
+    assert_eq!(fuzzy_match("xyz", "File > Save", &mut p), None);
+    assert_eq!(fuzzy_match("saves", "Save", &mut p), None);
+    // the shortest sequence that contains the pattern is selected
+    assert!(fuzzy_match("ab", "xa-a-b", &mut p).is_some());
+    assert_eq!(p, vec![3, 5]);
+    assert!(fuzzy_match("ps", "Paste Special", &mut p).is_some());
+    assert_eq!(p, vec![0, 6]);
+    // consecutive characters and word starts are better than scattered characters
+    let s1 = fuzzy_match("open", "Open File", &mut p).unwrap();
+    let s2 = fuzzy_match("open", "Go to pending", &mut p).unwrap();
+    assert!(s1 > s2);
+    let s1 = fuzzy_match("ps", "Paste Special", &mut p).unwrap();
+    let s2 = fuzzy_match("ps", "Paste", &mut p).unwrap();
+    assert!(s1 > s2);
+    let s1 = fuzzy_match("sa", "saveAll", &mut p).unwrap();
+    let s2 = fuzzy_match("sa", "disable", &mut p).unwrap();
+    assert!(s1 > s2);
+}